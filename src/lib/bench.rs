@@ -0,0 +1,258 @@
+//! # bench
+//!
+//! Stores the benchmark results per git ref and compares them against the results of a
+//! baseline git ref, failing in case the regressions exceed the configured threshold.
+//!
+
+#[cfg(test)]
+#[path = "bench_test.rs"]
+mod bench_test;
+
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::types::BenchConfig;
+use fsio::file::{read_text_file, write_text_file};
+use fsio::path::from_path::FromPath;
+use glob::glob;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+static DEFAULT_DIRECTORY: &str = ".cargo-make/bench";
+static DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// Holds the benchmark results of a single git ref
+struct BenchResults {
+    /// The git commit hash the results were collected for
+    git_ref: String,
+    /// The benchmark times (in nanoseconds) by benchmark name
+    benchmarks: IndexMap<String, f64>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Regression {
+    name: String,
+    baseline: f64,
+    current: f64,
+    /// The slowdown in percents
+    change: f64,
+}
+
+/// Parses the cargo bench (libtest) output lines, for example:<br>
+/// test bench_parse ... bench:       1,234 ns/iter (+/- 56)
+fn parse_libtest_output(output: &str) -> IndexMap<String, f64> {
+    let regex = Regex::new(r"^test (\S+)\s+\.\.\. bench:\s+([\d,.]+) ns/iter").unwrap();
+
+    let mut benchmarks = IndexMap::new();
+    for line in output.lines() {
+        if let Some(captures) = regex.captures(line.trim()) {
+            if let Ok(value) = captures[2].replace(',', "").parse::<f64>() {
+                benchmarks.insert(captures[1].to_string(), value);
+            }
+        }
+    }
+
+    benchmarks
+}
+
+fn get_criterion_benchmark_name(criterion_directory: &Path, benchmark_directory: &Path) -> String {
+    let benchmark_file = benchmark_directory.join("new").join("benchmark.json");
+    let full_id = read_text_file(&benchmark_file)
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+        .and_then(|value| value["full_id"].as_str().map(|id| id.to_string()));
+
+    match full_id {
+        Some(full_id) => full_id,
+        None => match benchmark_directory.strip_prefix(criterion_directory) {
+            Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+            Err(_) => FromPath::from_path(benchmark_directory),
+        },
+    }
+}
+
+/// Loads the latest criterion results (mean estimates) found in the target directory
+fn load_criterion_results(target_directory: &Path) -> IndexMap<String, f64> {
+    let criterion_directory = target_directory.join("criterion");
+    let glob_pattern: String = FromPath::from_path(
+        &criterion_directory
+            .join("**")
+            .join("new")
+            .join("estimates.json"),
+    );
+
+    let mut benchmarks = IndexMap::new();
+    let paths = match glob(&glob_pattern) {
+        Ok(paths) => paths,
+        Err(_) => return benchmarks,
+    };
+    for estimates_file in paths.filter_map(|entry| entry.ok()) {
+        let mean = read_text_file(&estimates_file)
+            .ok()
+            .and_then(|text| serde_json::from_str::<Value>(&text).ok())
+            .and_then(|value| value["mean"]["point_estimate"].as_f64());
+
+        if let (Some(mean), Some(benchmark_directory)) = (
+            mean,
+            estimates_file
+                .parent()
+                .and_then(|directory| directory.parent()),
+        ) {
+            let name = get_criterion_benchmark_name(&criterion_directory, benchmark_directory);
+            benchmarks.insert(name, mean);
+        }
+    }
+
+    benchmarks
+}
+
+fn get_results_file(directory: &Path, git_ref: &str) -> PathBuf {
+    directory.join(format!("{}.json", git_ref))
+}
+
+fn save_results(directory: &Path, results: &BenchResults) -> Result<(), CargoMakeError> {
+    let file = get_results_file(directory, &results.git_ref);
+    let text = serde_json::to_string_pretty(results).unwrap_or_default();
+    write_text_file(&file, &text)?;
+
+    debug!("Bench results written to: {:?}", &file);
+    Ok(())
+}
+
+fn load_results(directory: &Path, git_ref: &str) -> Option<BenchResults> {
+    let file = get_results_file(directory, git_ref);
+
+    read_text_file(&file)
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+}
+
+/// Returns the benchmarks which are slower than the baseline by more than the threshold
+fn find_regressions(
+    baseline: &BenchResults,
+    current: &BenchResults,
+    threshold: f64,
+) -> Vec<Regression> {
+    let mut regressions = vec![];
+    for (name, current_value) in &current.benchmarks {
+        if let Some(baseline_value) = baseline.benchmarks.get(name) {
+            if *baseline_value <= 0.0 {
+                continue;
+            }
+
+            let change = (current_value - baseline_value) / baseline_value * 100.0;
+            if change > threshold {
+                regressions.push(Regression {
+                    name: name.to_string(),
+                    baseline: *baseline_value,
+                    current: *current_value,
+                    change,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+fn get_current_ref() -> Option<String> {
+    match envmnt::get_or("CARGO_MAKE_GIT_SHA", "") {
+        value if value.is_empty() => environment::get_git_output(&["rev-parse", "HEAD"]),
+        value => Some(value),
+    }
+}
+
+fn resolve_ref(name: &str) -> Option<String> {
+    environment::get_git_output(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{}^{{commit}}", name),
+    ])
+    .filter(|git_ref| !git_ref.is_empty())
+}
+
+/// Stores the benchmark results found in the cargo bench output and the criterion reports for
+/// the current git ref, and compares them against the results stored for the baseline git ref.<br>
+/// The CARGO_MAKE_BENCH_BASELINE and CARGO_MAKE_BENCH_THRESHOLD env vars override the bench
+/// section values.
+pub(crate) fn record(
+    bench_config: Option<&BenchConfig>,
+    output: &str,
+) -> Result<(), CargoMakeError> {
+    let default_config = BenchConfig::new();
+    let bench_config = bench_config.unwrap_or(&default_config);
+
+    let directory = PathBuf::from(
+        bench_config
+            .directory
+            .clone()
+            .unwrap_or(DEFAULT_DIRECTORY.to_string()),
+    );
+    let threshold = envmnt::get_or("CARGO_MAKE_BENCH_THRESHOLD", "")
+        .parse::<f64>()
+        .ok()
+        .or(bench_config.threshold)
+        .unwrap_or(DEFAULT_THRESHOLD);
+    let baseline = match envmnt::get_or("CARGO_MAKE_BENCH_BASELINE", "") {
+        value if value.is_empty() => bench_config.baseline.clone(),
+        value => Some(value),
+    };
+
+    let mut benchmarks = parse_libtest_output(output);
+    let target_directory = envmnt::get_or("CARGO_MAKE_CRATE_TARGET_DIRECTORY", "target");
+    benchmarks.extend(load_criterion_results(Path::new(&target_directory)));
+    if benchmarks.is_empty() {
+        warn!("No benchmark results found.");
+        return Ok(());
+    }
+
+    let current = BenchResults {
+        git_ref: get_current_ref().unwrap_or_default(),
+        benchmarks,
+    };
+    if current.git_ref.is_empty() {
+        warn!("Unable to resolve the current git ref, bench results are not stored.");
+    } else {
+        save_results(&directory, &current)?;
+    }
+
+    let baseline = match baseline {
+        Some(baseline) => baseline,
+        None => return Ok(()),
+    };
+    let baseline_results = match resolve_ref(&baseline) {
+        Some(ref baseline_ref) if baseline_ref == &current.git_ref => {
+            info!("Current git ref is the bench baseline: {}", &baseline);
+            return Ok(());
+        }
+        Some(ref baseline_ref) => load_results(&directory, baseline_ref),
+        None => None,
+    };
+    let baseline_results = match baseline_results {
+        Some(baseline_results) => baseline_results,
+        None => {
+            warn!("No stored bench results found for baseline: {}", &baseline);
+            return Ok(());
+        }
+    };
+
+    let regressions = find_regressions(&baseline_results, &current, threshold);
+    if regressions.is_empty() {
+        info!(
+            "No bench regressions above {}% found compared to baseline: {}",
+            threshold, &baseline
+        );
+        return Ok(());
+    }
+
+    for regression in &regressions {
+        warn!(
+            "Bench regression: {} {:.2} ns -> {:.2} ns (+{:.2}%)",
+            &regression.name, regression.baseline, regression.current, regression.change
+        );
+    }
+    Err(CargoMakeError::BenchRegression(regressions.len(), baseline))
+}
@@ -0,0 +1,150 @@
+use super::*;
+use crate::test;
+
+fn create_results(git_ref: &str, benchmarks: &[(&str, f64)]) -> BenchResults {
+    BenchResults {
+        git_ref: git_ref.to_string(),
+        benchmarks: benchmarks
+            .iter()
+            .map(|(name, value)| (name.to_string(), *value))
+            .collect(),
+    }
+}
+
+#[test]
+fn parse_libtest_output_empty() {
+    let benchmarks = parse_libtest_output("");
+
+    assert!(benchmarks.is_empty());
+}
+
+#[test]
+fn parse_libtest_output_benchmarks() {
+    let benchmarks = parse_libtest_output(
+        r#"
+running 3 tests
+test tests::unit ... ignored
+test bench_parse  ... bench:       1,234 ns/iter (+/- 56)
+test bench_format ... bench:          12.50 ns/iter (+/- 0.30)
+
+test result: ok. 0 passed; 0 failed; 1 ignored; 2 measured; 0 filtered out
+"#,
+    );
+
+    assert_eq!(benchmarks.len(), 2);
+    assert_eq!(*benchmarks.get("bench_parse").unwrap(), 1234.0);
+    assert_eq!(*benchmarks.get("bench_format").unwrap(), 12.5);
+}
+
+#[test]
+fn load_criterion_results_not_found() {
+    let directory = test::get_temp_test_directory("load_criterion_results_not_found");
+
+    let benchmarks = load_criterion_results(&directory);
+
+    assert!(benchmarks.is_empty());
+}
+
+#[test]
+fn load_criterion_results_found() {
+    let directory = test::get_temp_test_directory("load_criterion_results_found");
+    let with_id = directory.join("criterion/group/parse/new");
+    write_text_file(
+        &with_id.join("estimates.json"),
+        r#"{"mean": {"point_estimate": 250.5}}"#,
+    )
+    .unwrap();
+    write_text_file(
+        &with_id.join("benchmark.json"),
+        r#"{"full_id": "group/parse"}"#,
+    )
+    .unwrap();
+    write_text_file(
+        &directory.join("criterion/format/new/estimates.json"),
+        r#"{"mean": {"point_estimate": 10.0}}"#,
+    )
+    .unwrap();
+
+    let benchmarks = load_criterion_results(&directory);
+
+    assert_eq!(benchmarks.len(), 2);
+    assert_eq!(*benchmarks.get("group/parse").unwrap(), 250.5);
+    assert_eq!(*benchmarks.get("format").unwrap(), 10.0);
+}
+
+#[test]
+fn save_and_load_results() {
+    let directory = test::get_temp_test_directory("bench_save_and_load_results");
+    let results = create_results("abc123", &[("parse", 100.0), ("format", 20.0)]);
+
+    save_results(&directory, &results).unwrap();
+    let loaded = load_results(&directory, "abc123").unwrap();
+
+    assert_eq!(loaded, results);
+    assert!(load_results(&directory, "def456").is_none());
+}
+
+#[test]
+fn find_regressions_none() {
+    let baseline = create_results("base", &[("parse", 100.0), ("format", 20.0)]);
+    let current = create_results("head", &[("parse", 105.0), ("format", 10.0)]);
+
+    let regressions = find_regressions(&baseline, &current, 10.0);
+
+    assert!(regressions.is_empty());
+}
+
+#[test]
+fn find_regressions_above_threshold() {
+    let baseline = create_results("base", &[("parse", 100.0), ("format", 0.0)]);
+    let current = create_results(
+        "head",
+        &[("parse", 125.0), ("format", 20.0), ("new", 1000.0)],
+    );
+
+    let regressions = find_regressions(&baseline, &current, 10.0);
+
+    assert_eq!(
+        regressions,
+        vec![Regression {
+            name: "parse".to_string(),
+            baseline: 100.0,
+            current: 125.0,
+            change: 25.0,
+        }]
+    );
+}
+
+#[test]
+#[ignore]
+fn record_with_regression() {
+    let directory = test::get_temp_test_directory("bench_record_with_regression");
+    let baseline_ref = environment::get_git_output(&["rev-parse", "HEAD~1"]).unwrap();
+    save_results(
+        &directory,
+        &create_results(&baseline_ref, &[("bench_parse", 100.0)]),
+    )
+    .unwrap();
+    envmnt::set("CARGO_MAKE_BENCH_BASELINE", "HEAD~1");
+    envmnt::set(
+        "CARGO_MAKE_CRATE_TARGET_DIRECTORY",
+        directory.join("target"),
+    );
+
+    let mut bench_config = BenchConfig::new();
+    bench_config.directory = Some(FromPath::from_path(&directory));
+    bench_config.threshold = Some(5.0);
+    let output = "test bench_parse ... bench:         110 ns/iter (+/- 2)";
+    let result = record(Some(&bench_config), output);
+
+    envmnt::remove("CARGO_MAKE_BENCH_BASELINE");
+    envmnt::remove("CARGO_MAKE_CRATE_TARGET_DIRECTORY");
+
+    match result {
+        Err(CargoMakeError::BenchRegression(count, baseline)) => {
+            assert_eq!(count, 1);
+            assert_eq!(baseline, "HEAD~1");
+        }
+        _ => panic!("expected bench regression"),
+    }
+}
@@ -0,0 +1,162 @@
+//! # binary_lookup
+//!
+//! Memoizes the binary lookups (toolchain cargo paths, installed cargo commands and
+//! installation checks) done during the flow, since the same binaries are probed per task.<br>
+//! Toolchain cargo paths can also be persisted across runs by setting the
+//! CARGO_MAKE_BINARY_LOOKUP_CACHE env var to true.
+//!
+
+#[cfg(test)]
+#[path = "binary_lookup_test.rs"]
+mod binary_lookup_test;
+
+use crate::cache;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+#[derive(Debug, Default)]
+struct LookupCache {
+    /// The cargo binary path per toolchain
+    cargo_paths: HashMap<String, Option<String>>,
+    /// The cargo --list output per toolchain
+    cargo_commands: HashMap<String, String>,
+    /// The installation check result per toolchain, binary and test args
+    installed: HashMap<String, bool>,
+}
+
+static LOOKUP_CACHE: Lazy<Mutex<LookupCache>> = Lazy::new(|| Mutex::new(LookupCache::default()));
+
+/// Returns true if the lookup results should be persisted across runs (CARGO_MAKE_BINARY_LOOKUP_CACHE env var)
+pub(crate) fn is_persistent() -> bool {
+    envmnt::is("CARGO_MAKE_BINARY_LOOKUP_CACHE")
+}
+
+fn load_persisted_cargo_path(key: &str) -> Option<String> {
+    let cache_data = cache::load();
+
+    match cache_data.cargo_paths {
+        // the toolchain might have been removed since
+        Some(ref cargo_paths) => match cargo_paths.get(key) {
+            Some(path) if Path::new(path.trim()).exists() => Some(path.to_string()),
+            _ => None,
+        },
+        None => None,
+    }
+}
+
+fn persist_cargo_path(key: &str, path: &str) {
+    let mut cache_data = cache::load();
+
+    let mut cargo_paths = cache_data.cargo_paths.unwrap_or_default();
+    cargo_paths.insert(key.to_string(), path.to_string());
+    cache_data.cargo_paths = Some(cargo_paths);
+
+    cache::store(&cache_data);
+}
+
+/// Returns the cargo binary path for the provided toolchain, invoking the lookup only if
+/// the toolchain was not looked up before.
+pub(crate) fn get_cargo_binary_path<F>(toolchain: &str, lookup: F) -> Option<String>
+where
+    F: FnOnce() -> Option<String>,
+{
+    if let Some(path) = LOOKUP_CACHE.lock().unwrap().cargo_paths.get(toolchain) {
+        debug!(
+            "Cargo binary path for toolchain: {} found in cache.",
+            toolchain
+        );
+        return path.clone();
+    }
+
+    let persistent = is_persistent();
+    let persisted_path = if persistent {
+        load_persisted_cargo_path(toolchain)
+    } else {
+        None
+    };
+
+    let path = match persisted_path {
+        Some(path) => Some(path),
+        None => {
+            let path = lookup();
+
+            if persistent {
+                if let Some(ref value) = path {
+                    persist_cargo_path(toolchain, value);
+                }
+            }
+
+            path
+        }
+    };
+
+    LOOKUP_CACHE
+        .lock()
+        .unwrap()
+        .cargo_paths
+        .insert(toolchain.to_string(), path.clone());
+
+    path
+}
+
+/// Returns the installed cargo commands list output for the provided toolchain.<br>
+/// Failed lookups are not cached.
+pub(crate) fn get_cargo_commands<F, E>(toolchain: &str, lookup: F) -> Result<Option<String>, E>
+where
+    F: FnOnce() -> Result<Option<String>, E>,
+{
+    if let Some(output) = LOOKUP_CACHE.lock().unwrap().cargo_commands.get(toolchain) {
+        debug!(
+            "Cargo commands for toolchain: {} found in cache.",
+            toolchain
+        );
+        return Ok(Some(output.clone()));
+    }
+
+    let output = lookup()?;
+
+    if let Some(ref value) = output {
+        LOOKUP_CACHE
+            .lock()
+            .unwrap()
+            .cargo_commands
+            .insert(toolchain.to_string(), value.clone());
+    }
+
+    Ok(output)
+}
+
+/// Returns the installation check result for the provided binary, invoking the check only
+/// if the binary was not checked before with the same toolchain and test args.
+pub(crate) fn is_installed<F>(toolchain: &str, binary: &str, test_args: &[String], check: F) -> bool
+where
+    F: FnOnce() -> bool,
+{
+    let key = format!("{}\n{}\n{}", toolchain, binary, test_args.join(" "));
+
+    if let Some(installed) = LOOKUP_CACHE.lock().unwrap().installed.get(&key) {
+        debug!("Installation check for binary: {} found in cache.", binary);
+        return *installed;
+    }
+
+    let installed = check();
+
+    LOOKUP_CACHE
+        .lock()
+        .unwrap()
+        .installed
+        .insert(key, installed);
+
+    installed
+}
+
+/// Clears the in memory lookup results (cargo paths are kept).<br>
+/// Should be invoked after installing crates or components.
+pub(crate) fn invalidate() {
+    let mut lookup_cache = LOOKUP_CACHE.lock().unwrap();
+
+    lookup_cache.cargo_commands.clear();
+    lookup_cache.installed.clear();
+}
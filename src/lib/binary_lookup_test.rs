@@ -0,0 +1,91 @@
+use super::*;
+use std::cell::Cell;
+
+#[test]
+fn get_cargo_binary_path_cached() {
+    let calls = Cell::new(0);
+    let lookup = || {
+        calls.set(calls.get() + 1);
+        Some("/test/cargo".to_string())
+    };
+
+    let path = get_cargo_binary_path("get_cargo_binary_path_cached", lookup);
+    assert_eq!(path.unwrap(), "/test/cargo");
+
+    let path = get_cargo_binary_path("get_cargo_binary_path_cached", lookup);
+    assert_eq!(path.unwrap(), "/test/cargo");
+
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn get_cargo_binary_path_not_found_cached() {
+    let calls = Cell::new(0);
+    let lookup = || {
+        calls.set(calls.get() + 1);
+        None
+    };
+
+    assert!(get_cargo_binary_path("get_cargo_binary_path_not_found_cached", lookup).is_none());
+    assert!(get_cargo_binary_path("get_cargo_binary_path_not_found_cached", lookup).is_none());
+
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn get_cargo_commands_cached() {
+    let calls = Cell::new(0);
+    let lookup = || -> Result<Option<String>, ()> {
+        calls.set(calls.get() + 1);
+        Ok(Some("build\ntest".to_string()))
+    };
+
+    let output = get_cargo_commands("get_cargo_commands_cached", lookup).unwrap();
+    assert_eq!(output.unwrap(), "build\ntest");
+
+    let output = get_cargo_commands("get_cargo_commands_cached", lookup).unwrap();
+    assert_eq!(output.unwrap(), "build\ntest");
+
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn get_cargo_commands_error_not_cached() {
+    let calls = Cell::new(0);
+    let lookup = || -> Result<Option<String>, ()> {
+        calls.set(calls.get() + 1);
+        Err(())
+    };
+
+    assert!(get_cargo_commands("get_cargo_commands_error_not_cached", lookup).is_err());
+    assert!(get_cargo_commands("get_cargo_commands_error_not_cached", lookup).is_err());
+
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn is_installed_cached_per_args() {
+    let calls = Cell::new(0);
+    let check = || {
+        calls.set(calls.get() + 1);
+        true
+    };
+
+    let args = vec!["--version".to_string()];
+    assert!(is_installed(
+        "",
+        "is_installed_cached_per_args",
+        &args,
+        check
+    ));
+    assert!(is_installed(
+        "",
+        "is_installed_cached_per_args",
+        &args,
+        check
+    ));
+    assert_eq!(calls.get(), 1);
+
+    assert!(is_installed("", "is_installed_cached_per_args", &[], check));
+    assert_eq!(calls.get(), 2);
+}
@@ -0,0 +1,203 @@
+//! # ci_output
+//!
+//! Emits CI vendor specific log markers (github log groups and error annotations,
+//! gitlab collapsible sections).
+//!
+
+#[cfg(test)]
+#[path = "ci_output_test.rs"]
+mod ci_output_test;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static GROUP_OPEN: AtomicBool = AtomicBool::new(false);
+static ERROR_ANNOTATED: AtomicBool = AtomicBool::new(false);
+static SECTION_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GroupVendor {
+    GitHubActions,
+    GitLabCI,
+}
+
+pub(crate) fn is_github_actions() -> bool {
+    envmnt::is_equal("GITHUB_ACTIONS", "true")
+}
+
+pub(crate) fn is_gitlab_ci() -> bool {
+    envmnt::is_equal("GITLAB_CI", "true")
+}
+
+fn get_group_vendor() -> Option<GroupVendor> {
+    if is_github_actions() {
+        Some(GroupVendor::GitHubActions)
+    } else if is_gitlab_ci() {
+        Some(GroupVendor::GitLabCI)
+    } else {
+        None
+    }
+}
+
+fn get_timestamp() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_) => 0,
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace("%", "%25")
+        .replace("\r", "%0D")
+        .replace("\n", "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(":", "%3A").replace(",", "%2C")
+}
+
+/// Returns the 1 based line number of the provided offset in the text
+pub(crate) fn get_line_number(text: &str, offset: usize) -> usize {
+    let end = if offset > text.len() {
+        text.len()
+    } else {
+        offset
+    };
+
+    text.as_bytes()[..end]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
+fn create_group_start(name: &str) -> String {
+    format!("::group::{}", escape_data(name))
+}
+
+fn create_group_end() -> String {
+    "::endgroup::".to_string()
+}
+
+/// Gitlab section names may only contain letters, numbers and the '_', '.' and '-' characters.<br>
+/// The process id and the section index make the name unique, also when nested cargo-make
+/// processes (for example workspace members) run tasks with the same name.
+fn create_section_name(name: &str, process_id: u32, index: usize) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric()
+                || character == '_'
+                || character == '.'
+                || character == '-'
+            {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("task_{}_{}_{}", sanitized, process_id, index)
+}
+
+fn create_section_start(section_name: &str, header: &str, timestamp: u64) -> String {
+    format!(
+        "\x1b[0Ksection_start:{}:{}\r\x1b[0K{}",
+        timestamp, section_name, header
+    )
+}
+
+fn create_section_end(section_name: &str, timestamp: u64) -> String {
+    format!("\x1b[0Ksection_end:{}:{}\r\x1b[0K", timestamp, section_name)
+}
+
+fn create_error_annotation(message: &str, file: Option<&str>, line: Option<usize>) -> String {
+    let mut properties = vec![];
+    if let Some(file_value) = file {
+        properties.push(format!("file={}", escape_property(file_value)));
+
+        if let Some(line_value) = line {
+            properties.push(format!("line={}", line_value));
+        }
+    }
+
+    if properties.is_empty() {
+        format!("::error::{}", escape_data(message))
+    } else {
+        format!("::error {}::{}", properties.join(","), escape_data(message))
+    }
+}
+
+fn end_group() {
+    if GROUP_OPEN.swap(false, Ordering::SeqCst) {
+        println!("{}", create_group_end());
+    }
+}
+
+/// Wraps the output of a single task in a collapsible log group/section.<br>
+/// The group is closed once this value is dropped.
+pub(crate) struct TaskGroup {
+    vendor: Option<GroupVendor>,
+    section_name: String,
+}
+
+impl TaskGroup {
+    pub(crate) fn start(name: &str) -> TaskGroup {
+        let vendor = get_group_vendor();
+        let mut section_name = String::new();
+
+        match vendor {
+            Some(GroupVendor::GitHubActions) => {
+                // github does not support nested groups so we close the parent group
+                end_group();
+
+                println!("{}", create_group_start(name));
+                GROUP_OPEN.store(true, Ordering::SeqCst);
+            }
+            Some(GroupVendor::GitLabCI) => {
+                let index = SECTION_COUNTER.fetch_add(1, Ordering::SeqCst);
+                section_name = create_section_name(name, std::process::id(), index);
+
+                println!(
+                    "{}",
+                    create_section_start(
+                        &section_name,
+                        &format!("Task: {}", name),
+                        get_timestamp()
+                    )
+                );
+            }
+            None => (),
+        }
+
+        TaskGroup {
+            vendor,
+            section_name,
+        }
+    }
+}
+
+impl Drop for TaskGroup {
+    fn drop(&mut self) {
+        match self.vendor {
+            Some(GroupVendor::GitHubActions) => end_group(),
+            Some(GroupVendor::GitLabCI) => println!(
+                "{}",
+                create_section_end(&self.section_name, get_timestamp())
+            ),
+            None => (),
+        }
+    }
+}
+
+/// Emits an error annotation which is shown in the github checks UI.<br>
+/// Only the first error is annotated as the following errors are usually a result of it.
+pub(crate) fn annotate_error(message: &str, file: Option<&str>, line: Option<usize>) {
+    if is_github_actions() && !ERROR_ANNOTATED.swap(true, Ordering::SeqCst) {
+        end_group();
+
+        println!("{}", create_error_annotation(message, file, line));
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+#[test]
+fn get_line_number_start() {
+    assert_eq!(get_line_number("a\nb\nc", 0), 1);
+}
+
+#[test]
+fn get_line_number_middle() {
+    assert_eq!(get_line_number("a\nb\nc", 2), 2);
+}
+
+#[test]
+fn get_line_number_out_of_range() {
+    assert_eq!(get_line_number("a\nb\nc", 100), 3);
+}
+
+#[test]
+fn create_group_start_escaped() {
+    assert_eq!(create_group_start("test\n100%"), "::group::test%0A100%25");
+}
+
+#[test]
+fn create_group_end_value() {
+    assert_eq!(create_group_end(), "::endgroup::");
+}
+
+#[test]
+fn create_error_annotation_no_location() {
+    assert_eq!(
+        create_error_annotation("some error", None, None),
+        "::error::some error"
+    );
+}
+
+#[test]
+fn create_error_annotation_file_only() {
+    assert_eq!(
+        create_error_annotation("some error", Some("Makefile.toml"), None),
+        "::error file=Makefile.toml::some error"
+    );
+}
+
+#[test]
+fn create_error_annotation_file_and_line() {
+    assert_eq!(
+        create_error_annotation("line1\nline2", Some("c:\\a,b.toml"), Some(12)),
+        "::error file=c%3A\\a%2Cb.toml,line=12::line1%0Aline2"
+    );
+}
+
+#[test]
+fn create_error_annotation_line_without_file() {
+    assert_eq!(
+        create_error_annotation("some error", None, Some(12)),
+        "::error::some error"
+    );
+}
+
+#[test]
+fn create_section_name_valid() {
+    assert_eq!(
+        create_section_name("build-1.0_x", 42, 3),
+        "task_build-1.0_x_42_3"
+    );
+}
+
+#[test]
+fn create_section_name_invalid_characters() {
+    assert_eq!(
+        create_section_name("ns::build task", 42, 0),
+        "task_ns__build_task_42_0"
+    );
+}
+
+#[test]
+fn create_section_name_nested_processes() {
+    assert_ne!(
+        create_section_name("build", 100, 0),
+        create_section_name("build", 101, 0)
+    );
+}
+
+#[test]
+fn create_section_start_value() {
+    assert_eq!(
+        create_section_start("task_build_0", "Task: build", 100),
+        "\x1b[0Ksection_start:100:task_build_0\r\x1b[0KTask: build"
+    );
+}
+
+#[test]
+fn create_section_end_value() {
+    assert_eq!(
+        create_section_end("task_build_0", 200),
+        "\x1b[0Ksection_end:200:task_build_0\r\x1b[0K"
+    );
+}
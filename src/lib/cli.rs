@@ -8,20 +8,30 @@
 mod cli_test;
 
 use crate::cli_commands;
+use crate::cli_overrides;
 use crate::cli_parser;
 use crate::config;
+use crate::daemon;
 use crate::descriptor;
+use crate::diagnostics;
 use crate::environment;
 use crate::error::CargoMakeError;
+use crate::exit_codes::{self, FailureClass};
 use crate::logger;
 use crate::logger::LoggerOptions;
+use crate::perf;
+use crate::policy;
 use crate::profile;
 use crate::recursion_level;
+use crate::run_lock;
 use crate::runner;
+use crate::task_args;
+use crate::task_tags;
 use crate::time_summary;
 use crate::toolchain;
-use crate::types::{CliArgs, GlobalConfig};
+use crate::types::{CliArgs, GlobalConfig, PrintStepsOptions};
 use crate::version;
+use std::collections::BTreeMap;
 use std::time::SystemTime;
 
 pub(crate) static VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -31,6 +41,7 @@ pub(crate) static DEFAULT_TOML: &str = "Makefile.toml";
 pub(crate) static DEFAULT_LOG_LEVEL: &str = "info";
 pub(crate) static DEFAULT_TASK_NAME: &str = "default";
 pub(crate) static DEFAULT_OUTPUT_FORMAT: &str = "default";
+pub(crate) static DEFAULT_ERROR_FORMAT: &str = "human";
 
 pub fn run(
     cli_args: &CliArgs,
@@ -52,13 +63,28 @@ pub fn run(
         debug!("Written By {}", &AUTHOR);
     }
 
+    perf::init(cli_args);
+    policy::init(global_config);
+    exit_codes::init(&global_config.exit_codes);
+    diagnostics::init(cli_args);
+
     debug!("Cli Args {:#?}", &cli_args);
     debug!("Global Configuration {:#?}", &global_config);
 
     if version::should_check(&cli_args, &global_config) {
-        version::check();
+        version::check(global_config);
+    } else {
+        version::check_cached(cli_args);
     }
 
+    // the env is exported relative to the process env before any cargo-make modifications
+    let initial_env =
+        if cli_args.export_env.is_some() || cli_args.query.is_some() || cli_args.task == "repl" {
+            cli_commands::export_env::get_env()
+        } else {
+            BTreeMap::new()
+        };
+
     // clear env vars (see https://github.com/rust-lang/rustup/issues/3029)
     toolchain::remove_rust_env_vars();
 
@@ -84,7 +110,18 @@ pub fn run(
     };
     let home = environment::setup_cwd(cwd);
 
+    if cli_args.daemon {
+        return daemon::run();
+    }
+
     let force_makefile = cli_args.build_file.is_some();
+    // the additional makefiles are also passed to the forked cargo-make processes
+    match cli_args.additional_build_files {
+        Some(ref additional_build_files) => {
+            envmnt::set_list("CARGO_MAKE_ADDITIONAL_MAKEFILES", additional_build_files)
+        }
+        None => envmnt::remove("CARGO_MAKE_ADDITIONAL_MAKEFILES"),
+    };
     let build_file = &cli_args
         .build_file
         .clone()
@@ -95,13 +132,115 @@ pub fn run(
         .clone()
         .unwrap_or_else(profile::default_profile);
     let normalized_profile_name = profile::set(&profile_name);
+    let mut profile_names = vec![normalized_profile_name.clone()];
+    if let Some(ref additional_profiles) = cli_args.additional_profiles {
+        profile_names.extend(additional_profiles.iter().cloned());
+    }
+    profile::set_all(&profile_names);
 
-    environment::load_env_file(cli_args.env_file.clone());
+    if let Some(ref env_files) = cli_args.env_file {
+        for env_file in env_files {
+            environment::load_env_file(Some(env_file.to_string()));
+        }
+    }
 
     let env = cli_args.env.clone();
 
+    if cli_args.strict {
+        envmnt::set_bool("CARGO_MAKE_STRICT", true);
+    }
+
+    if cli_args.assume_yes {
+        envmnt::set_bool("CARGO_MAKE_ASSUME_YES", true);
+    }
+
+    if cli_args.dry_run {
+        envmnt::set_bool("CARGO_MAKE_DRY_RUN", true);
+    }
+
     let experimental = cli_args.experimental;
-    let config = descriptor::load(&build_file, force_makefile, env, experimental)?;
+    // listing and filtering the tasks require all tasks
+    let all_tasks_required = cli_args.list_all_steps
+        || cli_args.list_category_steps.is_some()
+        || cli_args.list_category_tree
+        || cli_args.query.is_some()
+        || cli_args.tags.is_some()
+        || cli_args.skip_tags.is_some()
+        || cli_commands::requires_all_tasks(task);
+    // the validate command reports the invalid task names and references itself
+    if task == "validate" {
+        descriptor::skip_references_report();
+    }
+    // the makefiles defining the profile env blocks are only tracked when loading the makefiles
+    if task == "profiles" {
+        envmnt::set_bool("CARGO_MAKE_DESCRIPTOR_CACHE", false);
+    }
+    let mut config = perf::measure("Load Descriptor", || {
+        exit_codes::run_as(FailureClass::DescriptorError, || {
+            descriptor::load(
+                build_file,
+                force_makefile,
+                env,
+                experimental,
+                if all_tasks_required { None } else { Some(task) },
+            )
+        })
+    })?;
+    logger::set_colors(&config.config.colors);
+    exit_codes::init(&config.config.exit_codes);
+
+    if let Some(ref overrides) = cli_args.overrides {
+        cli_overrides::apply(&mut config, overrides)?;
+    }
+
+    // the active profiles may define their own default task
+    let profile_default_task = if cli_args.default_task {
+        config
+            .config
+            .profile_default_tasks
+            .as_ref()
+            .and_then(profile::get_value_for_profiles)
+    } else {
+        None
+    };
+    let task = match profile_default_task {
+        Some(ref profile_default_task) => profile_default_task,
+        None => task,
+    };
+
+    // tasks defined in the makefiles take precedence over built in commands
+    if !config.tasks.contains_key(task) {
+        if task == "validate" {
+            return cli_commands::validate::run(&config);
+        } else if task == "migrate-deprecated" {
+            return cli_commands::migrate_deprecated::run(&config, build_file);
+        } else if task == "export" {
+            return cli_commands::export::run(&config, &cli_args.arguments);
+        } else if task == "task-docs" {
+            return cli_commands::task_docs::run(&config, &cli_args.arguments);
+        } else if task == "profiles" {
+            return cli_commands::profiles::run(&config);
+        } else if task == "self-update" {
+            return cli_commands::self_update::run(&cli_args.arguments, global_config);
+        } else if task == "stats" {
+            return cli_commands::stats::run(&cli_args.arguments);
+        }
+    }
+
+    if task_args::is_help_requested(&cli_args.arguments) {
+        if let Some(task_config) = config.tasks.get(task) {
+            if task_args::has_help(task_config) {
+                print!("{}", task_args::get_help(task, task_config));
+                return Ok(());
+            }
+        }
+    }
+
+    let tagged_task = task_tags::apply(&mut config, &cli_args.tags, &cli_args.skip_tags)?;
+    let task = match tagged_task {
+        Some(ref tagged_task) => tagged_task,
+        None => task,
+    };
 
     let mut time_summary_vec = vec![];
     time_summary::add(
@@ -111,13 +250,33 @@ pub fn run(
     );
     let step_time = SystemTime::now();
 
+    // profiles provided by repeating the profile cli argument are also loaded as additional profiles
+    let cli_additional_profiles: Vec<String> = profile::get_all().into_iter().skip(1).collect();
+    if !cli_additional_profiles.is_empty() {
+        let mut additional_profiles = config.config.additional_profiles.clone().unwrap_or(vec![]);
+        for profile_name in cli_additional_profiles {
+            if !additional_profiles.contains(&profile_name) {
+                additional_profiles.push(profile_name);
+            }
+        }
+        config.config.additional_profiles = Some(additional_profiles);
+    }
+
     match config.config.additional_profiles {
         Some(ref profiles) => profile::set_additional(profiles),
         None => profile::set_additional(&vec![]),
     };
 
-    let env_info = environment::setup_env(&cli_args, &config, &task, home, &mut time_summary_vec)?;
+    // the repl sets up the env itself, so it can be set up again when switching profiles
+    if task == "repl" && !config.tasks.contains_key(task) {
+        return cli_commands::repl::run(&config, cli_args, home, &initial_env);
+    }
+
+    let env_info = perf::measure("Setup Env", || {
+        environment::setup_env(cli_args, &config, task, home, &mut time_summary_vec)
+    })?;
     time_summary::add(&mut time_summary_vec, "[Setup Env]", step_time);
+    task_args::setup_env(&config, task, &cli_args.arguments)?;
 
     let crate_name = envmnt::get_or("CARGO_MAKE_CRATE_NAME", "");
     info!("");
@@ -126,21 +285,32 @@ pub fn run(
     }
     info!("Build File: {}", &build_file);
     info!("Task: {}", &task);
-    info!("Profile: {}", &normalized_profile_name);
+    info!("Profile: {}", profile::get_all().join(", "));
 
     // ensure profile env was not overridden
     profile::set(&normalized_profile_name);
 
-    if cli_args.list_all_steps || cli_args.list_category_steps.is_some() {
+    let result = if cli_args.list_all_steps || cli_args.list_category_steps.is_some() {
         cli_commands::list_steps::run(
             &config,
             &cli_args.output_format,
             &cli_args.output_file,
             &cli_args.list_category_steps,
             cli_args.hide_uninteresting,
+            cli_args.log_level == "verbose",
+        )
+    } else if cli_args.list_category_tree {
+        cli_commands::list_steps::run_category_tree(
+            &config,
+            &cli_args.output_file,
+            cli_args.hide_uninteresting,
         )
+    } else if let Some(ref query) = cli_args.query {
+        cli_commands::query::run(&config, query, &initial_env)
+    } else if let Some(ref export_env_file) = cli_args.export_env {
+        cli_commands::export_env::run(&config, task, export_env_file, &initial_env)
     } else if cli_args.diff_execution_plan {
-        let default_config = descriptor::load_internal_descriptors(true, experimental, None)?;
+        let default_config = descriptor::load_internal_descriptors(true, experimental, None, None)?;
         cli_commands::diff_steps::run(
             &default_config,
             &config,
@@ -153,13 +323,19 @@ pub fn run(
             &mut std::io::stdout(),
             &config,
             &task,
-            &cli_args.output_format,
-            cli_args.disable_workspace,
-            &cli_args.skip_tasks_pattern,
             &env_info.crate_info,
-            cli_args.skip_init_end_tasks,
+            &PrintStepsOptions {
+                output_format: &cli_args.output_format,
+                disable_workspace: cli_args.disable_workspace,
+                skip_tasks_pattern: cli_args.skip_tasks_pattern.as_deref(),
+                skip_init_end_tasks: cli_args.skip_init_end_tasks,
+                from_step: cli_args.from_step.as_deref(),
+                skip_steps: cli_args.skip_steps.as_deref(),
+            },
         )
     } else {
+        let _run_lock = run_lock::acquire(&config, cli_args, task)?;
+
         runner::run(
             config,
             &task,
@@ -168,9 +344,11 @@ pub fn run(
             start_time,
             time_summary_vec,
         )
-    }?;
+    };
+
+    perf::print();
 
-    Ok(())
+    result
 }
 
 /// Handles the command line arguments and executes the runner.
@@ -179,6 +357,8 @@ pub fn run_cli(command_name: String, sub_command: bool) -> Result<CliArgs, Cargo
 
     let cli_args = cli_parser::parse(&global_config, &command_name, sub_command)?;
 
-    run(&cli_args, &global_config, None)?;
+    run(&cli_args, &global_config, None)
+        .map_err(exit_codes::map_error)
+        .map_err(diagnostics::map_error)?;
     Ok(cli_args)
 }
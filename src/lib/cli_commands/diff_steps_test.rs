@@ -11,6 +11,12 @@ fn run_same() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -38,6 +44,12 @@ fn run_different() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -54,6 +66,12 @@ fn run_different() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -79,6 +97,12 @@ fn run_different_with_skip() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -95,6 +119,12 @@ fn run_different_with_skip() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -117,6 +147,12 @@ fn run_missing_task_in_first_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -129,6 +165,12 @@ fn run_missing_task_in_first_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -155,6 +197,12 @@ fn run_missing_task_in_second_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -168,6 +216,12 @@ fn run_missing_task_in_second_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -0,0 +1,360 @@
+//! # export
+//!
+//! Exports the tasks into other build/CI formats (GitHub Actions workflows, Makefiles and
+//! justfiles) which invoke cargo-make.
+//!
+
+#[cfg(test)]
+#[path = "export_test.rs"]
+mod export_test;
+
+use crate::error::CargoMakeError;
+use crate::execution_plan::ExecutionPlanBuilder;
+use crate::io;
+use crate::types::{Config, Task};
+
+static PLATFORMS: [(&str, &str); 3] = [
+    ("linux", "ubuntu-latest"),
+    ("windows", "windows-latest"),
+    ("mac", "macos-latest"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum ExportFormat {
+    /// GitHub Actions workflow
+    GitHubActions,
+    /// POSIX Makefile
+    Makefile,
+    /// justfile
+    Justfile,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ExportOptions {
+    /// The output format
+    format: ExportFormat,
+    /// The exported task (flow)
+    task: String,
+    /// Optional output file, if not provided the output is printed
+    output_file: Option<String>,
+}
+
+fn get_format(value: &str) -> Result<ExportFormat, CargoMakeError> {
+    match value {
+        "github-actions" => Ok(ExportFormat::GitHubActions),
+        "makefile" => Ok(ExportFormat::Makefile),
+        "justfile" => Ok(ExportFormat::Justfile),
+        _ => Err(CargoMakeError::InvalidArgument(format!(
+            "Unsupported export format: {}, supported formats: github-actions, makefile, justfile",
+            value
+        ))),
+    }
+}
+
+fn parse_arguments(arguments: &Option<Vec<String>>) -> Result<ExportOptions, CargoMakeError> {
+    let mut format = None;
+    let mut task = "default".to_string();
+    let mut output_file = None;
+
+    let arguments = arguments.clone().unwrap_or_default();
+    let mut iterator = arguments.iter();
+    while let Some(argument) = iterator.next() {
+        let value = match argument.as_str() {
+            "--format" | "--task" | "--output-file" => match iterator.next() {
+                Some(value) => value.to_string(),
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(format!(
+                        "Missing value for export argument: {}",
+                        argument
+                    )))
+                }
+            },
+            _ => {
+                return Err(CargoMakeError::InvalidArgument(format!(
+                    "Unknown export argument: {}",
+                    argument
+                )))
+            }
+        };
+
+        match argument.as_str() {
+            "--format" => format = Some(get_format(&value)?),
+            "--task" => task = value,
+            _ => output_file = Some(value),
+        };
+    }
+
+    match format {
+        Some(format) => Ok(ExportOptions {
+            format,
+            task,
+            output_file,
+        }),
+        None => Err(CargoMakeError::InvalidArgument(
+            "Missing export format, for example: --format github-actions".to_string(),
+        )),
+    }
+}
+
+/// Returns the names of the platforms which have a specific override or alias in the task
+fn get_override_platforms(task: &Task) -> Vec<&'static str> {
+    let overrides = [
+        task.linux.is_some() || task.linux_alias.is_some(),
+        task.windows.is_some() || task.windows_alias.is_some(),
+        task.mac.is_some() || task.mac_alias.is_some(),
+    ];
+
+    PLATFORMS
+        .iter()
+        .zip(overrides.iter())
+        .filter(|(_, defined)| **defined)
+        .map(|((platform, _), _)| *platform)
+        .collect()
+}
+
+/// Returns the task dependencies (direct and indirect) which are part of the task own plan
+fn get_dependencies(config: &Config, task: &str) -> Result<Vec<String>, CargoMakeError> {
+    let execution_plan = ExecutionPlanBuilder {
+        disable_workspace: true,
+        allow_private: true,
+        skip_init_end_tasks: true,
+        ..ExecutionPlanBuilder::new(config, task)
+    }
+    .build()?;
+
+    Ok(execution_plan
+        .steps
+        .into_iter()
+        .map(|step| step.name)
+        .filter(|name| name != task)
+        .collect())
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn get_job_name(task: &str) -> String {
+    let job_name: String = task
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() || character == '-' || character == '_' {
+                character
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    match job_name.chars().next() {
+        Some(character) if character.is_ascii_alphabetic() || character == '_' => job_name,
+        _ => format!("_{}", job_name),
+    }
+}
+
+/// Returns the cargo-make command which runs only the provided task, as its dependencies
+/// are exported as previous steps.
+fn get_task_command(config: &Config, task: &str) -> Result<String, CargoMakeError> {
+    let dependencies = get_dependencies(config, task)?;
+
+    let mut command = "cargo make --no-workspace --allow-private".to_string();
+    if !dependencies.is_empty() {
+        let names: Vec<String> = dependencies
+            .iter()
+            .map(|name| regex::escape(name))
+            .collect();
+        command.push_str(&format!(" --skip-tasks '^(?:{})$'", names.join("|")));
+    }
+    command.push(' ');
+    command.push_str(task);
+
+    Ok(command)
+}
+
+/// Creates a GitHub Actions workflow with a step per task in the flow
+pub(crate) fn create_github_actions_workflow(
+    config: &Config,
+    task: &str,
+) -> Result<String, CargoMakeError> {
+    let execution_plan = ExecutionPlanBuilder {
+        disable_workspace: true,
+        skip_init_end_tasks: true,
+        ..ExecutionPlanBuilder::new(config, task)
+    }
+    .build()?;
+
+    let mut platforms = vec![];
+    let mut steps = vec![];
+    for step in &execution_plan.steps {
+        match config.tasks.get(&step.name) {
+            Some(task_config) => {
+                for platform in get_override_platforms(task_config) {
+                    if !platforms.contains(&platform) {
+                        platforms.push(platform);
+                    }
+                }
+
+                steps.push((step.name.clone(), get_task_command(config, &step.name)?));
+            }
+            None => warn!(
+                "Step: {} is not a makefile task (for example an external dependency) and is not exported.",
+                &step.name
+            ),
+        }
+    }
+
+    let mut workflow = String::new();
+    workflow.push_str(&format!(
+        "# Generated by cargo-make from the {} task, regenerate instead of editing manually.\n",
+        task
+    ));
+    workflow.push_str(&format!("name: {}\n\n", quote(task)));
+    workflow.push_str("on: [push, pull_request]\n\n");
+    workflow.push_str("jobs:\n");
+    workflow.push_str(&format!("  {}:\n", get_job_name(task)));
+
+    if platforms.is_empty() {
+        workflow.push_str("    runs-on: ubuntu-latest\n");
+    } else {
+        // tasks with platform overrides behave differently per platform
+        let mut runners = vec!["ubuntu-latest"];
+        for (platform, runner) in PLATFORMS.iter() {
+            if platforms.contains(platform) && !runners.contains(runner) {
+                runners.push(runner);
+            }
+        }
+
+        workflow.push_str("    runs-on: ${{ matrix.os }}\n");
+        workflow.push_str("    strategy:\n");
+        workflow.push_str("      fail-fast: false\n");
+        workflow.push_str("      matrix:\n");
+        workflow.push_str(&format!("        os: [{}]\n", runners.join(", ")));
+    }
+
+    workflow.push_str("    steps:\n");
+    workflow.push_str("      - uses: actions/checkout@v4\n");
+    workflow.push_str("      - uses: dtolnay/rust-toolchain@stable\n");
+    workflow.push_str("      - name: Install cargo-make\n");
+    workflow.push_str("        run: cargo install --locked cargo-make\n");
+    for (name, command) in &steps {
+        workflow.push_str(&format!("      - name: {}\n", quote(name)));
+        workflow.push_str(&format!("        run: {}\n", quote(command)));
+    }
+
+    Ok(workflow)
+}
+
+/// Returns the public tasks which can be used as recipe names (the default task first)
+fn get_recipe_tasks(config: &Config) -> Vec<(&String, &Task)> {
+    let mut tasks: Vec<(&String, &Task)> = config
+        .tasks
+        .iter()
+        .filter(|(name, task)| {
+            let public = !task.private.unwrap_or(false) && task.deprecated.is_none();
+            let valid = is_valid_recipe_name(name);
+
+            if public && !valid {
+                warn!(
+                    "Task: {} is not a valid recipe name and is not exported.",
+                    name
+                );
+            }
+
+            public && valid
+        })
+        .collect();
+
+    // the first recipe is invoked when no recipe is provided
+    if let Some(index) = tasks.iter().position(|(name, _)| *name == "default") {
+        let default_task = tasks.remove(index);
+        tasks.insert(0, default_task);
+    }
+
+    tasks
+}
+
+fn is_valid_recipe_name(name: &str) -> bool {
+    match name.chars().next() {
+        Some(character) if character.is_ascii_alphabetic() || character == '_' => {
+            name.chars().all(|character| {
+                character.is_ascii_alphanumeric() || character == '-' || character == '_'
+            })
+        }
+        _ => false,
+    }
+}
+
+fn get_recipe_description(task: &Task) -> Option<String> {
+    task.description
+        .as_ref()
+        .and_then(|description| description.lines().next())
+        .map(|description| description.trim().to_string())
+        .filter(|description| !description.is_empty())
+}
+
+/// Creates a POSIX Makefile with a target per public task which invokes cargo-make
+pub(crate) fn create_makefile(config: &Config) -> String {
+    let tasks = get_recipe_tasks(config);
+
+    let mut makefile = String::new();
+    makefile.push_str("# Generated by cargo-make, regenerate instead of editing manually.\n");
+    makefile.push_str("# Additional task arguments can be provided via ARGS, for example: make build ARGS=\"--release\"\n\n");
+
+    let names: Vec<&str> = tasks.iter().map(|(name, _)| name.as_str()).collect();
+    makefile.push_str(&format!(".PHONY: {}\n", names.join(" ")));
+
+    for (name, task) in &tasks {
+        makefile.push('\n');
+        if let Some(description) = get_recipe_description(task) {
+            makefile.push_str(&format!("# {}\n", description));
+        }
+        makefile.push_str(&format!("{}:\n", name));
+        makefile.push_str(&format!("\tcargo make {} $(ARGS)\n", name));
+    }
+
+    makefile
+}
+
+/// Creates a justfile with a recipe per public task which invokes cargo-make
+pub(crate) fn create_justfile(config: &Config) -> String {
+    let tasks = get_recipe_tasks(config);
+
+    let mut justfile = String::new();
+    justfile.push_str("# Generated by cargo-make, regenerate instead of editing manually.\n");
+
+    for (name, task) in &tasks {
+        justfile.push('\n');
+        if let Some(description) = get_recipe_description(task) {
+            justfile.push_str(&format!("# {}\n", description));
+        }
+        justfile.push_str(&format!("{} *args:\n", name));
+        justfile.push_str(&format!("    cargo make {} {{{{args}}}}\n", name));
+    }
+
+    justfile
+}
+
+fn create_export(config: &Config, options: &ExportOptions) -> Result<String, CargoMakeError> {
+    match options.format {
+        ExportFormat::GitHubActions => create_github_actions_workflow(config, &options.task),
+        ExportFormat::Makefile => Ok(create_makefile(config)),
+        ExportFormat::Justfile => Ok(create_justfile(config)),
+    }
+}
+
+/// Exports the task based on the export command line arguments
+pub(crate) fn run(config: &Config, arguments: &Option<Vec<String>>) -> Result<(), CargoMakeError> {
+    let options = parse_arguments(arguments)?;
+
+    let output = create_export(config, &options)?;
+
+    match options.output_file {
+        Some(ref file) => {
+            io::write_text_file(file, &output);
+            info!("Exported to: {}", file);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! # export_env
+//!
+//! Resolves the environment of a task (global env, profiles, env files and the task env)
+//! without invoking it and writes the env vars defined by cargo-make to a dotenv or JSON file.
+//!
+
+#[cfg(test)]
+#[path = "export_env_test.rs"]
+mod export_env_test;
+
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::profile;
+use crate::types::Config;
+use std::collections::BTreeMap;
+use std::env;
+
+/// Returns the current process env
+pub(crate) fn get_env() -> BTreeMap<String, String> {
+    env::vars_os()
+        .map(|(key, value)| {
+            (
+                key.to_string_lossy().to_string(),
+                value.to_string_lossy().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Restores the process env to the provided snapshot (as returned by get_env)
+pub(crate) fn restore_env(snapshot: &BTreeMap<String, String>) {
+    for key in get_env().keys() {
+        if !snapshot.contains_key(key) {
+            envmnt::remove(key);
+        }
+    }
+
+    for (key, value) in snapshot {
+        envmnt::set(key, value);
+    }
+}
+
+/// Returns the env vars which were added or modified compared to the initial env
+pub(crate) fn get_modified_env(
+    initial_env: &BTreeMap<String, String>,
+    env: BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    env.into_iter()
+        .filter(|(key, value)| initial_env.get(key) != Some(value))
+        .collect()
+}
+
+fn is_json_file(file: &str) -> bool {
+    file.to_lowercase().ends_with(".json")
+}
+
+fn quote_dotenv_value(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('$', "\\$");
+
+    format!("\"{}\"", escaped)
+}
+
+fn create_dotenv(env: &BTreeMap<String, String>) -> String {
+    let mut text = String::new();
+
+    for (key, value) in env {
+        text.push_str(&format!("{}={}\n", key, quote_dotenv_value(value)));
+    }
+
+    text
+}
+
+fn create_json(env: &BTreeMap<String, String>) -> String {
+    let mut text = serde_json::to_string_pretty(env).unwrap_or_default();
+    text.push('\n');
+
+    text
+}
+
+/// Applies the task env (as done when invoking it) and returns the actual task name and the env
+/// vars defined by cargo-make (compared to the initial process env)
+pub(crate) fn get_task_env(
+    config: &Config,
+    task: &str,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<(String, BTreeMap<String, String>), CargoMakeError> {
+    let actual_task_name = execution_plan::get_actual_task_name(config, task)?;
+    let task_config = execution_plan::get_normalized_task(config, &actual_task_name, true)?;
+
+    if let Some(ref env) = task_config.env {
+        environment::set_current_task_meta_info_env(env.clone());
+    }
+    envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &actual_task_name);
+
+    let profile_name = profile::get();
+    let _env_files = environment::TaskEnvFiles::load(&task_config.env_files);
+    if let Some(ref env) = task_config.env {
+        environment::set_env(env.clone());
+    }
+    profile::set(&profile_name);
+
+    let env = get_modified_env(initial_env, get_env());
+
+    Ok((actual_task_name, env))
+}
+
+/// Applies the task env (as done when invoking it) and writes the env vars defined by
+/// cargo-make (compared to the initial process env) to the file
+pub(crate) fn run(
+    config: &Config,
+    task: &str,
+    file: &str,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<(), CargoMakeError> {
+    let (actual_task_name, env) = get_task_env(config, task, initial_env)?;
+
+    let text = if is_json_file(file) {
+        create_json(&env)
+    } else {
+        create_dotenv(&env)
+    };
+
+    fsio::file::write_text_file(file, &text)?;
+    info!(
+        "Exported {} env vars of task: {} to: {}",
+        env.len(),
+        &actual_task_name,
+        file
+    );
+
+    Ok(())
+}
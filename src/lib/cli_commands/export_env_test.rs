@@ -0,0 +1,133 @@
+use super::*;
+use crate::types::{ConfigSection, EnvValue, Task};
+use indexmap::IndexMap;
+use std::fs;
+use uuid::Uuid;
+
+fn create_config(task: Task) -> Config {
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), task);
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+#[test]
+fn get_modified_env_new_and_changed() {
+    let mut initial_env = BTreeMap::new();
+    initial_env.insert("SAME".to_string(), "1".to_string());
+    initial_env.insert("CHANGED".to_string(), "1".to_string());
+    initial_env.insert("REMOVED".to_string(), "1".to_string());
+
+    let mut env = BTreeMap::new();
+    env.insert("SAME".to_string(), "1".to_string());
+    env.insert("CHANGED".to_string(), "2".to_string());
+    env.insert("NEW".to_string(), "3".to_string());
+
+    let modified_env = get_modified_env(&initial_env, env);
+
+    assert_eq!(
+        modified_env.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("CHANGED".to_string(), "2".to_string()),
+            ("NEW".to_string(), "3".to_string())
+        ]
+    );
+}
+
+#[test]
+fn is_json_file_by_extension() {
+    assert!(is_json_file("env.json"));
+    assert!(is_json_file("./target/ENV.JSON"));
+    assert!(!is_json_file(".env"));
+    assert!(!is_json_file("build.env"));
+}
+
+#[test]
+fn create_dotenv_escaped() {
+    let mut env = BTreeMap::new();
+    env.insert("B".to_string(), "line1\nline2".to_string());
+    env.insert("A".to_string(), "say \"hi\" to $USER\\".to_string());
+
+    let text = create_dotenv(&env);
+
+    assert_eq!(
+        text,
+        "A=\"say \\\"hi\\\" to \\$USER\\\\\"\nB=\"line1\\nline2\"\n"
+    );
+}
+
+#[test]
+fn create_json_sorted() {
+    let mut env = BTreeMap::new();
+    env.insert("B".to_string(), "2".to_string());
+    env.insert("A".to_string(), "1".to_string());
+
+    let text = create_json(&env);
+
+    assert_eq!(text, "{\n  \"A\": \"1\",\n  \"B\": \"2\"\n}\n");
+}
+
+#[test]
+#[ignore]
+fn run_writes_task_env() {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_TEST_EXPORT_ENV".to_string(),
+        EnvValue::Value("exported".to_string()),
+    );
+    let mut task = Task::new();
+    task.env = Some(env);
+    let config = create_config(task);
+
+    let directory =
+        std::env::temp_dir().join(format!("cargo-make-test-export-env-{}", Uuid::new_v4()));
+    fs::create_dir_all(&directory).unwrap();
+    let dotenv_file = directory.join("build.env");
+    let json_file = directory.join("build.json");
+
+    let initial_env = get_env();
+    run(
+        &config,
+        "build",
+        &dotenv_file.to_string_lossy(),
+        &initial_env,
+    )
+    .unwrap();
+    run(&config, "build", &json_file.to_string_lossy(), &initial_env).unwrap();
+
+    let dotenv_text = fs::read_to_string(&dotenv_file).unwrap();
+    let json_value: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&json_file).unwrap()).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(dotenv_text.contains("CARGO_MAKE_TEST_EXPORT_ENV=\"exported\"\n"));
+    assert!(dotenv_text.contains("CARGO_MAKE_CURRENT_TASK_NAME=\"build\"\n"));
+    assert_eq!(json_value["CARGO_MAKE_TEST_EXPORT_ENV"], "exported");
+}
+
+#[test]
+fn restore_env_removes_and_resets() {
+    envmnt::set("EXPORT_ENV_TEST_RESTORE_CHANGED", "1");
+    envmnt::remove("EXPORT_ENV_TEST_RESTORE_ADDED");
+    let snapshot = get_env();
+
+    envmnt::set("EXPORT_ENV_TEST_RESTORE_CHANGED", "2");
+    envmnt::set("EXPORT_ENV_TEST_RESTORE_ADDED", "1");
+    restore_env(&snapshot);
+
+    assert_eq!(envmnt::get_or_panic("EXPORT_ENV_TEST_RESTORE_CHANGED"), "1");
+    assert!(!envmnt::exists("EXPORT_ENV_TEST_RESTORE_ADDED"));
+}
@@ -0,0 +1,173 @@
+use super::*;
+use crate::types::ConfigSection;
+use indexmap::IndexMap;
+
+fn create_config() -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut format = Task::new();
+    format.command = Some("cargo".to_string());
+    config.tasks.insert("format".to_string(), format);
+
+    let mut build = Task::new();
+    build.command = Some("cargo".to_string());
+    build.dependencies = Some(vec!["format".into()]);
+    config.tasks.insert("build".to_string(), build);
+
+    let mut test = Task::new();
+    test.command = Some("cargo".to_string());
+    test.dependencies = Some(vec!["build".into()]);
+    config.tasks.insert("test".to_string(), test);
+
+    let mut flow = Task::new();
+    flow.dependencies = Some(vec!["test".into()]);
+    config.tasks.insert("ci-flow".to_string(), flow);
+
+    config
+}
+
+fn to_arguments(arguments: Vec<&str>) -> Option<Vec<String>> {
+    Some(arguments.iter().map(|value| value.to_string()).collect())
+}
+
+#[test]
+fn parse_arguments_valid() {
+    let options = parse_arguments(&to_arguments(vec![
+        "--format",
+        "github-actions",
+        "--task",
+        "ci-flow",
+        "--output-file",
+        "ci.yml",
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        options,
+        ExportOptions {
+            format: ExportFormat::GitHubActions,
+            task: "ci-flow".to_string(),
+            output_file: Some("ci.yml".to_string()),
+        }
+    );
+}
+
+#[test]
+fn parse_arguments_default_task() {
+    let options = parse_arguments(&to_arguments(vec!["--format", "github-actions"])).unwrap();
+
+    assert_eq!(options.task, "default");
+    assert!(options.output_file.is_none());
+}
+
+#[test]
+fn parse_arguments_invalid() {
+    assert!(parse_arguments(&None).is_err());
+    assert!(parse_arguments(&to_arguments(vec!["--format", "bad"])).is_err());
+    assert!(parse_arguments(&to_arguments(vec!["--format"])).is_err());
+    assert!(parse_arguments(&to_arguments(vec!["--format", "github-actions", "--bad"])).is_err());
+}
+
+#[test]
+fn get_job_name_sanitized() {
+    assert_eq!(get_job_name("ci-flow"), "ci-flow");
+    assert_eq!(get_job_name("ns::build"), "ns--build");
+    assert_eq!(get_job_name("1build"), "_1build");
+}
+
+#[test]
+fn get_task_command_dependencies_skipped() {
+    let config = create_config();
+
+    assert_eq!(
+        get_task_command(&config, "format").unwrap(),
+        "cargo make --no-workspace --allow-private format"
+    );
+    assert_eq!(
+        get_task_command(&config, "test").unwrap(),
+        "cargo make --no-workspace --allow-private --skip-tasks '^(?:format|build)$' test"
+    );
+}
+
+#[test]
+fn create_github_actions_workflow_steps() {
+    let config = create_config();
+
+    let workflow = create_github_actions_workflow(&config, "ci-flow").unwrap();
+
+    assert!(workflow.contains("  ci-flow:\n    runs-on: ubuntu-latest\n"));
+    assert!(!workflow.contains("matrix"));
+
+    let format_index = workflow.find("- name: \"format\"").unwrap();
+    let build_index = workflow.find("- name: \"build\"").unwrap();
+    let test_index = workflow.find("- name: \"test\"").unwrap();
+    let flow_index = workflow.find("- name: \"ci-flow\"").unwrap();
+    assert!(format_index < build_index);
+    assert!(build_index < test_index);
+    assert!(test_index < flow_index);
+}
+
+#[test]
+fn create_github_actions_workflow_platform_matrix() {
+    let mut config = create_config();
+    config
+        .tasks
+        .insert("format-windows".to_string(), Task::new());
+    config.tasks.get_mut("format").unwrap().windows_alias = Some("format-windows".to_string());
+
+    let workflow = create_github_actions_workflow(&config, "ci-flow").unwrap();
+
+    assert!(workflow.contains("    runs-on: ${{ matrix.os }}\n"));
+    assert!(workflow.contains("        os: [ubuntu-latest, windows-latest]\n"));
+}
+
+#[test]
+fn is_valid_recipe_name_values() {
+    assert!(is_valid_recipe_name("build"));
+    assert!(is_valid_recipe_name("ci-flow_2"));
+    assert!(!is_valid_recipe_name("ns::build"));
+    assert!(!is_valid_recipe_name("-build"));
+    assert!(!is_valid_recipe_name(""));
+}
+
+#[test]
+fn create_makefile_public_tasks() {
+    let mut config = create_config();
+    config.tasks.get_mut("format").unwrap().private = Some(true);
+    config.tasks.get_mut("build").unwrap().description = Some("Builds the crate".to_string());
+    config.tasks.insert("default".to_string(), Task::new());
+
+    let makefile = create_makefile(&config);
+
+    assert!(makefile.contains(".PHONY: default build test ci-flow\n"));
+    assert!(makefile.contains("\n# Builds the crate\nbuild:\n\tcargo make build $(ARGS)\n"));
+    assert!(!makefile.contains("format"));
+}
+
+#[test]
+fn create_justfile_public_tasks() {
+    let mut config = create_config();
+    config.tasks.get_mut("format").unwrap().private = Some(true);
+    config.tasks.get_mut("build").unwrap().description = Some("Builds the crate".to_string());
+
+    let justfile = create_justfile(&config);
+
+    assert!(
+        justfile.contains("\n# Builds the crate\nbuild *args:\n    cargo make build {{args}}\n")
+    );
+    assert!(justfile.contains("\ntest *args:\n"));
+    assert!(!justfile.contains("format"));
+}
@@ -20,29 +20,55 @@ pub fn run(
     output_file: &Option<String>,
     category: &Option<String>,
     hide_uninteresting: bool,
+    show_examples: bool,
 ) -> Result<(), CargoMakeError> {
-    let output = create_list(&config, output_format, category, hide_uninteresting)?;
+    let output = create_list(
+        config,
+        output_format,
+        category,
+        hide_uninteresting,
+        show_examples,
+    )?;
 
+    write_output(&output, output_file);
+    Ok(())
+}
+
+/// Lists all tasks as a tree grouped by category and namespace
+pub(crate) fn run_category_tree(
+    config: &Config,
+    output_file: &Option<String>,
+    hide_uninteresting: bool,
+) -> Result<(), CargoMakeError> {
+    let output = create_category_tree(config, hide_uninteresting)?;
+
+    write_output(&output, output_file);
+    Ok(())
+}
+
+fn write_output(output: &str, output_file: &Option<String>) {
     match output_file {
         Some(file) => {
-            io::write_text_file(&file, &output);
+            io::write_text_file(&file, output);
             ()
         }
         None => print!("{}", output),
     };
-    Ok(())
 }
 
-pub(crate) fn create_list(
+/// category -> actual_task -> (description, examples)
+type Categories = BTreeMap<String, BTreeMap<String, (String, Vec<String>)>>;
+/// actual_task -> aliases
+type Aliases = BTreeMap<String, BTreeSet<String>>;
+
+fn collect_tasks(
     config: &Config,
-    output_format: &str,
     category_filter: &Option<String>,
     hide_uninteresting: bool,
-) -> Result<String, CargoMakeError> {
-    // category -> actual_task -> description
-    let mut categories: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
-    // actual_task -> aliases
-    let mut aliases: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    show_examples: bool,
+) -> Result<(Categories, Aliases), CargoMakeError> {
+    let mut categories: Categories = BTreeMap::new();
+    let mut aliases: Aliases = BTreeMap::new();
 
     // iterate over all tasks to build categories and aliases
     for key in config.tasks.keys() {
@@ -107,6 +133,20 @@ pub(crate) fn create_list(
                         buffer.push_str(message);
                         buffer.push_str(")");
 
+                        buffer
+                    }
+                    DeprecationInfo::Details(ref details) => {
+                        let mut buffer = " (deprecated".to_string();
+                        if let Some(ref message) = details.message {
+                            buffer.push_str(" - ");
+                            buffer.push_str(message);
+                        }
+                        if let Some(ref replaced_by) = details.replaced_by {
+                            buffer.push_str(", replaced by: ");
+                            buffer.push_str(replaced_by);
+                        }
+                        buffer.push(')');
+
                         buffer
                     }
                 },
@@ -116,13 +156,32 @@ pub(crate) fn create_list(
             let mut text = String::from(description);
             text.push_str(&deprecated_message);
 
+            let examples = if show_examples {
+                task.examples.unwrap_or_default()
+            } else {
+                vec![]
+            };
+
             categories
                 .entry(category)
                 .or_default()
-                .insert(key.clone(), text);
+                .insert(key.clone(), (text, examples));
         }
     }
 
+    Ok((categories, aliases))
+}
+
+pub(crate) fn create_list(
+    config: &Config,
+    output_format: &str,
+    category_filter: &Option<String>,
+    hide_uninteresting: bool,
+    show_examples: bool,
+) -> Result<String, CargoMakeError> {
+    let (categories, mut aliases) =
+        collect_tasks(config, category_filter, hide_uninteresting, show_examples)?;
+
     // build the task list output string
     let single_page_markdown = output_format == "markdown-single-page";
     let markdown = single_page_markdown
@@ -154,7 +213,7 @@ pub(crate) fn create_list(
             }
         }
 
-        for (key, description) in tasks {
+        for (key, (description, examples)) in tasks {
             if markdown {
                 buffer.push_str(&format!("* **"));
             }
@@ -179,6 +238,14 @@ pub(crate) fn create_list(
                     "{}{} - {}{}\n",
                     &key, &post_key, &description, aliases
                 ));
+
+                for example in examples {
+                    if markdown {
+                        buffer.push_str(&format!("    * `{}`\n", example));
+                    } else {
+                        buffer.push_str(&format!("    Example: {}\n", example));
+                    }
+                }
             }
         }
 
@@ -189,3 +256,89 @@ pub(crate) fn create_list(
 
     Ok(buffer)
 }
+
+#[derive(Debug, Default)]
+struct TreeNode {
+    /// task name (without the namespace) -> description
+    tasks: BTreeMap<String, String>,
+    /// namespace -> the namespace tasks
+    namespaces: BTreeMap<String, TreeNode>,
+}
+
+fn add_tree_task(node: &mut TreeNode, name: &str, description: String) {
+    match name.split_once("::") {
+        Some((namespace, sub_name)) if !namespace.is_empty() && !sub_name.is_empty() => {
+            let namespace_node = node.namespaces.entry(namespace.to_string()).or_default();
+            add_tree_task(namespace_node, sub_name, description);
+        }
+        _ => {
+            node.tasks.insert(name.to_string(), description);
+        }
+    }
+}
+
+fn write_tree(buffer: &mut String, node: &TreeNode, prefix: &str) {
+    let count = node.tasks.len() + node.namespaces.len();
+    let mut index = 0;
+
+    for (name, description) in &node.tasks {
+        index += 1;
+        let connector = if index == count {
+            "└── "
+        } else {
+            "├── "
+        };
+
+        buffer.push_str(&format!(
+            "{}{}{} - {}\n",
+            prefix, connector, name, description
+        ));
+    }
+
+    for (namespace, namespace_node) in &node.namespaces {
+        index += 1;
+        let (connector, child_prefix) = if index == count {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        buffer.push_str(&format!("{}{}{}::\n", prefix, connector, namespace));
+        write_tree(
+            buffer,
+            namespace_node,
+            &format!("{}{}", prefix, child_prefix),
+        );
+    }
+}
+
+/// Creates the task tree, tasks are grouped by category and nested by their namespaces
+pub(crate) fn create_category_tree(
+    config: &Config,
+    hide_uninteresting: bool,
+) -> Result<String, CargoMakeError> {
+    let (categories, mut aliases) = collect_tasks(config, &None, hide_uninteresting, false)?;
+
+    let mut buffer = String::new();
+    for (category, tasks) in &categories {
+        let mut root = TreeNode::default();
+        for (key, (description, _)) in tasks {
+            let description = match aliases.remove(key) {
+                Some(aliases) => format!(
+                    "{} [aliases: {}]",
+                    description,
+                    aliases.into_iter().collect::<Vec<String>>().join(", ")
+                ),
+                None => description.to_string(),
+            };
+
+            add_tree_task(&mut root, key, description);
+        }
+
+        buffer.push_str(&format!("{}\n", category));
+        write_tree(&mut buffer, &root, "");
+        buffer.push('\n');
+    }
+
+    Ok(buffer)
+}
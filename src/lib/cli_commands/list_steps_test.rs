@@ -1,6 +1,6 @@
 use super::*;
 
-use crate::types::{ConfigSection, EnvValue, Task};
+use crate::types::{ConfigSection, DeprecationDetails, EnvValue, Task};
 use expect_test::{expect, Expect};
 use indexmap::IndexMap;
 use std::path::PathBuf;
@@ -21,6 +21,7 @@ fn check(
                 output_file,
                 &category,
                 hide_uninteresting,
+                false,
             )
             .unwrap();
 
@@ -34,7 +35,7 @@ fn check(
         }
         None => {
             let actual =
-                create_list(&config, output_format, &category, hide_uninteresting).unwrap();
+                create_list(&config, output_format, &category, hide_uninteresting, false).unwrap();
             expect.assert_eq(&actual);
         }
     }
@@ -52,6 +53,12 @@ fn run_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -77,6 +84,12 @@ fn run_all_public() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -118,6 +131,12 @@ fn run_all_public_hide_uninteresting() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -164,6 +183,12 @@ fn run_aliases() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -202,6 +227,12 @@ fn run_all_public_markdown() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -240,6 +271,12 @@ fn run_all_public_markdown_sub_section() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -278,6 +315,12 @@ fn run_all_public_markdown_single_page() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -320,6 +363,12 @@ fn run_all_private() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -354,6 +403,12 @@ fn run_mixed() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -373,6 +428,58 @@ fn run_mixed() {
     );
 }
 
+#[test]
+fn run_deprecated_replaced_by() {
+    let config_section = ConfigSection::new();
+    let env = IndexMap::<String, EnvValue>::new();
+
+    let mut tasks = IndexMap::<String, Task>::new();
+    let mut task1 = Task::new();
+    task1.description = Some("1".to_string());
+    task1.deprecated = Some(DeprecationInfo::Details(DeprecationDetails {
+        message: Some("test".to_string()),
+        replaced_by: Some("2".to_string()),
+    }));
+    tasks.insert("1".to_string(), task1);
+    let mut task2 = Task::new();
+    task2.description = Some("2".to_string());
+    task2.deprecated = Some(DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: Some("3".to_string()),
+    }));
+    tasks.insert("2".to_string(), task2);
+
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env,
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    check(
+        &config,
+        "default",
+        &None,
+        None,
+        false,
+        expect![[r#"
+        No Category
+        ----------
+        1 - 1 (deprecated - test, replaced by: 2)
+        2 - 2 (deprecated, replaced by: 3)
+
+    "#]],
+    );
+}
+
 #[test]
 fn run_write_to_file() {
     let config_section = ConfigSection::new();
@@ -393,6 +500,12 @@ fn run_write_to_file() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let file = "./target/_temp/tasklist.md";
@@ -440,6 +553,12 @@ fn run_category_public() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     check(
@@ -457,3 +576,165 @@ fn run_category_public() {
         "#]],
     );
 }
+
+fn create_examples_config() -> Config {
+    let mut tasks = IndexMap::<String, Task>::new();
+    let mut task = Task::new();
+    task.description = Some("Release".to_string());
+    task.examples = Some(vec![
+        "cargo make release --minor".to_string(),
+        "cargo make release --major".to_string(),
+    ]);
+    tasks.insert("release".to_string(), task);
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+#[test]
+fn create_list_examples_hidden() {
+    let config = create_examples_config();
+
+    let actual = create_list(&config, "default", &None, false, false).unwrap();
+
+    expect![[r#"
+        No Category
+        ----------
+        release - Release
+
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn create_list_examples() {
+    let config = create_examples_config();
+
+    let actual = create_list(&config, "default", &None, false, true).unwrap();
+
+    expect![[r#"
+        No Category
+        ----------
+        release - Release
+            Example: cargo make release --minor
+            Example: cargo make release --major
+
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn create_list_examples_markdown() {
+    let config = create_examples_config();
+
+    let actual = create_list(&config, "markdown", &None, false, true).unwrap();
+
+    expect![[r#"
+        #### No Category
+
+        * **release** - Release
+            * `cargo make release --minor`
+            * `cargo make release --major`
+
+    "#]]
+    .assert_eq(&actual);
+}
+
+fn create_tree_task(category: &str, description: &str) -> Task {
+    let mut task = Task::new();
+    task.category = Some(category.to_string());
+    task.description = Some(description.to_string());
+
+    task
+}
+
+#[test]
+fn create_category_tree_namespaces() {
+    let mut tasks = IndexMap::<String, Task>::new();
+    tasks.insert("build".to_string(), create_tree_task("Build", "build"));
+    tasks.insert(
+        "core::build".to_string(),
+        create_tree_task("Build", "core build"),
+    );
+    tasks.insert(
+        "core::docs::build".to_string(),
+        create_tree_task("Build", "core docs build"),
+    );
+    tasks.insert(
+        "core::release".to_string(),
+        create_tree_task("Build", "core release"),
+    );
+    tasks.insert("test".to_string(), create_tree_task("Test", "test"));
+    let mut alias_task = Task::new();
+    alias_task.alias = Some("test".to_string());
+    tasks.insert("t".to_string(), alias_task);
+    let mut private_task = create_tree_task("Test", "private");
+    private_task.private = Some(true);
+    tasks.insert("private".to_string(), private_task);
+    tasks.insert("init".to_string(), create_tree_task("Hooks", "init"));
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let actual = create_category_tree(&config, true).unwrap();
+
+    expect![[r#"
+        Build
+        ├── build - build
+        └── core::
+            ├── build - core build
+            ├── release - core release
+            └── docs::
+                └── build - core docs build
+
+        Test
+        └── test - test [aliases: t]
+
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn create_category_tree_empty() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let actual = create_category_tree(&config, false).unwrap();
+
+    assert!(actual.is_empty());
+}
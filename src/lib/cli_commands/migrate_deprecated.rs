@@ -0,0 +1,172 @@
+//! # migrate_deprecated
+//!
+//! Rewrites the dependencies and aliases in the makefile which reference deprecated tasks
+//! to their replacement tasks.
+//!
+
+#[cfg(test)]
+#[path = "migrate_deprecated_test.rs"]
+mod migrate_deprecated_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::Config;
+use indexmap::IndexMap;
+use std::path::PathBuf;
+use toml_edit::{DocumentMut, TableLike, Value};
+
+static ALIAS_ATTRIBUTES: [&str; 4] = ["alias", "linux_alias", "windows_alias", "mac_alias"];
+static OVERRIDES: [&str; 4] = ["linux", "windows", "mac", "ci"];
+
+/// Returns the deprecated task names mapped to their (final) replacement task names
+fn get_replacements(config: &Config) -> IndexMap<String, String> {
+    let mut direct = IndexMap::new();
+    for (name, task) in &config.tasks {
+        if let Some(ref deprecated) = task.deprecated {
+            if let Some(replaced_by) = deprecated.get_replaced_by() {
+                direct.insert(name.to_string(), replaced_by);
+            }
+        }
+    }
+
+    let mut replacements = IndexMap::new();
+    for (name, replaced_by) in &direct {
+        // the replacement task might be deprecated as well
+        let mut target = replaced_by.to_string();
+        let mut hops = 0;
+        while let Some(next) = direct.get(&target) {
+            hops += 1;
+            if hops > direct.len() || next == name {
+                break;
+            }
+            target = next.to_string();
+        }
+
+        replacements.insert(name.to_string(), target);
+    }
+
+    replacements
+}
+
+/// Replaces the string value (keeping its formatting) and returns the old value if replaced
+fn replace_value(value: &mut Value, replacements: &IndexMap<String, String>) -> Option<String> {
+    let old_name = value.as_str()?.to_string();
+    let new_name = replacements.get(&old_name)?;
+
+    let decor = value.decor().clone();
+    *value = Value::from(new_name.as_str());
+    *value.decor_mut() = decor;
+
+    Some(old_name)
+}
+
+fn migrate_task(
+    task_name: &str,
+    task: &mut dyn TableLike,
+    replacements: &IndexMap<String, String>,
+    changes: &mut Vec<String>,
+) {
+    let mut add_change = |attribute: &str, old_name: String| {
+        changes.push(format!(
+            "Task: {} {}: {} replaced by: {}",
+            task_name, attribute, &old_name, &replacements[&old_name]
+        ));
+    };
+
+    if let Some(dependencies) = task
+        .get_mut("dependencies")
+        .and_then(|item| item.as_array_mut())
+    {
+        for dependency in dependencies.iter_mut() {
+            let replaced = match dependency {
+                Value::InlineTable(ref mut table) => {
+                    // tasks from other makefiles are not migrated
+                    if table.contains_key("path") {
+                        None
+                    } else {
+                        match table.get_mut("name") {
+                            Some(name) => replace_value(name, replacements),
+                            None => None,
+                        }
+                    }
+                }
+                _ => replace_value(dependency, replacements),
+            };
+
+            if let Some(old_name) = replaced {
+                add_change("dependency", old_name);
+            }
+        }
+    }
+
+    for attribute in ALIAS_ATTRIBUTES {
+        if let Some(alias) = task.get_mut(attribute).and_then(|item| item.as_value_mut()) {
+            if let Some(old_name) = replace_value(alias, replacements) {
+                add_change(attribute, old_name);
+            }
+        }
+    }
+}
+
+/// Migrates the makefile content and returns the updated content and the list of changes
+fn migrate(
+    descriptor_string: &str,
+    file: &str,
+    replacements: &IndexMap<String, String>,
+) -> Result<(String, Vec<String>), CargoMakeError> {
+    let mut document = match descriptor_string.parse::<DocumentMut>() {
+        Ok(document) => document,
+        Err(error) => {
+            return Err(CargoMakeError::ParseFileFailed(
+                file.to_string(),
+                error.to_string(),
+            ))
+        }
+    };
+
+    let mut changes = vec![];
+
+    if let Some(tasks) = document
+        .get_mut("tasks")
+        .and_then(|item| item.as_table_like_mut())
+    {
+        for (task_name, task_item) in tasks.iter_mut() {
+            if let Some(task) = task_item.as_table_like_mut() {
+                migrate_task(&task_name, task, replacements, &mut changes);
+
+                for platform in OVERRIDES {
+                    if let Some(platform_task) = task
+                        .get_mut(platform)
+                        .and_then(|item| item.as_table_like_mut())
+                    {
+                        let name = format!("{} ({})", task_name, platform);
+                        migrate_task(&name, platform_task, replacements, &mut changes);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((document.to_string(), changes))
+}
+
+/// Rewrites the makefile to use the replacement tasks instead of the deprecated tasks
+pub(crate) fn run(config: &Config, build_file: &str) -> Result<(), CargoMakeError> {
+    let replacements = get_replacements(config);
+
+    let descriptor_string = io::read_text_file(&PathBuf::from(build_file))?;
+    let (updated_descriptor, changes) = migrate(&descriptor_string, build_file, &replacements)?;
+
+    if changes.is_empty() {
+        info!("No deprecated task references found in: {}", build_file);
+    } else {
+        for change in &changes {
+            info!("{}", change);
+        }
+
+        fsio::file::write_text_file(build_file, &updated_descriptor)?;
+        info!("Updated {} references in: {}", changes.len(), build_file);
+    }
+
+    Ok(())
+}
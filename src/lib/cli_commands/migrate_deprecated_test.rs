@@ -0,0 +1,143 @@
+use super::*;
+use crate::types::{ConfigSection, DeprecationDetails, DeprecationInfo, Task};
+
+fn create_deprecated_task(replaced_by: Option<&str>) -> Task {
+    let mut task = Task::new();
+    task.deprecated = Some(DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: replaced_by.map(|value| value.to_string()),
+    }));
+
+    task
+}
+
+fn create_config() -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    config
+        .tasks
+        .insert("old".to_string(), create_deprecated_task(Some("new")));
+    config
+        .tasks
+        .insert("older".to_string(), create_deprecated_task(Some("old")));
+    config
+        .tasks
+        .insert("no-replacement".to_string(), create_deprecated_task(None));
+    config.tasks.insert("new".to_string(), Task::new());
+
+    config
+}
+
+#[test]
+fn get_replacements_chain() {
+    let config = create_config();
+
+    let replacements = get_replacements(&config);
+
+    assert_eq!(replacements.len(), 2);
+    assert_eq!(replacements["old"], "new");
+    assert_eq!(replacements["older"], "new");
+}
+
+#[test]
+fn get_replacements_cycle() {
+    let mut config = create_config();
+    config
+        .tasks
+        .insert("new".to_string(), create_deprecated_task(Some("old")));
+
+    let replacements = get_replacements(&config);
+
+    assert_eq!(replacements["old"], "new");
+    assert_eq!(replacements["new"], "old");
+}
+
+#[test]
+fn migrate_no_changes() {
+    let config = create_config();
+    let descriptor = r#"
+[tasks.test]
+dependencies = ["new", "no-replacement"]
+"#;
+
+    let (output, changes) =
+        migrate(descriptor, "Makefile.toml", &get_replacements(&config)).unwrap();
+
+    assert!(changes.is_empty());
+    assert_eq!(output, descriptor);
+}
+
+#[test]
+fn migrate_with_changes() {
+    let config = create_config();
+    let descriptor = r#"
+# some comment
+[tasks.test]
+dependencies = [
+    "old", # keep me
+    { name = "older" },
+    { name = "old", path = "other" },
+]
+
+[tasks.test.linux]
+dependencies = ["older"]
+
+[tasks.alias]
+alias = "old"
+mac_alias = "other"
+"#;
+
+    let (output, changes) =
+        migrate(descriptor, "Makefile.toml", &get_replacements(&config)).unwrap();
+
+    assert_eq!(
+        changes,
+        vec![
+            "Task: test dependency: old replaced by: new".to_string(),
+            "Task: test dependency: older replaced by: new".to_string(),
+            "Task: test (linux) dependency: older replaced by: new".to_string(),
+            "Task: alias alias: old replaced by: new".to_string(),
+        ]
+    );
+    assert_eq!(
+        output,
+        r#"
+# some comment
+[tasks.test]
+dependencies = [
+    "new", # keep me
+    { name = "new" },
+    { name = "old", path = "other" },
+]
+
+[tasks.test.linux]
+dependencies = ["new"]
+
+[tasks.alias]
+alias = "new"
+mac_alias = "other"
+"#
+    );
+}
+
+#[test]
+fn migrate_invalid() {
+    let config = create_config();
+
+    let result = migrate("[tasks.test", "Makefile.toml", &get_replacements(&config));
+
+    assert!(result.is_err());
+}
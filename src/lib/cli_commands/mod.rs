@@ -3,6 +3,71 @@
 //! Wrappers for each CLI sub command.
 //!
 
+#[cfg(test)]
+#[path = "mod_test.rs"]
+mod mod_test;
+
 pub(crate) mod diff_steps;
+pub(crate) mod export;
+pub(crate) mod export_env;
 pub mod list_steps;
+pub(crate) mod migrate_deprecated;
 pub mod print_steps;
+pub(crate) mod profiles;
+pub(crate) mod query;
+pub(crate) mod repl;
+pub(crate) mod self_update;
+pub(crate) mod stats;
+pub(crate) mod task_docs;
+pub(crate) mod validate;
+
+/// A built in command, invoked by its name unless a task with the same name is defined
+pub(crate) struct BuiltInCommand {
+    /// The command (task) name
+    pub(crate) name: &'static str,
+    /// True if the command requires all the tasks, so the core tasks are not lazily loaded
+    pub(crate) requires_all_tasks: bool,
+}
+
+/// All the built in commands
+pub(crate) static BUILT_IN_COMMANDS: [BuiltInCommand; 8] = [
+    BuiltInCommand {
+        name: "validate",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "migrate-deprecated",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "export",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "task-docs",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "profiles",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "repl",
+        requires_all_tasks: true,
+    },
+    BuiltInCommand {
+        name: "self-update",
+        requires_all_tasks: false,
+    },
+    BuiltInCommand {
+        name: "stats",
+        requires_all_tasks: false,
+    },
+];
+
+/// Returns true if the task is a built in command which requires all the tasks
+pub(crate) fn requires_all_tasks(task: &str) -> bool {
+    BUILT_IN_COMMANDS
+        .iter()
+        .any(|command| command.name == task && command.requires_all_tasks)
+}
@@ -0,0 +1,15 @@
+use super::*;
+
+#[test]
+fn requires_all_tasks_listing_commands() {
+    assert!(requires_all_tasks("validate"));
+    assert!(requires_all_tasks("export"));
+    assert!(requires_all_tasks("repl"));
+}
+
+#[test]
+fn requires_all_tasks_other_commands() {
+    assert!(!requires_all_tasks("stats"));
+    assert!(!requires_all_tasks("self-update"));
+    assert!(!requires_all_tasks("build"));
+}
@@ -11,35 +11,35 @@ use crate::error::CargoMakeError;
 use std::io;
 
 use crate::execution_plan::ExecutionPlanBuilder;
-use crate::types::{Config, CrateInfo, ExecutionPlan};
+use crate::types::{Config, CrateInfo, ExecutionPlan, PrintStepsOptions, Step};
 use regex::Regex;
+use serde_json::{Map, Value};
 
-#[derive(Debug)]
+/// Env vars added to every task while loading the makefiles, the defining file is printed instead
+static LOCATION_ENV_KEYS: [&str; 2] = [
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE",
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY",
+];
+
+#[derive(Debug, PartialEq)]
 enum PrintFormat {
     /// The default format
     Default,
     /// Prints a short description of the task
     ShortDescription,
-}
-
-impl PartialEq for PrintFormat {
-    fn eq(&self, other: &PrintFormat) -> bool {
-        match self {
-            PrintFormat::Default => match other {
-                PrintFormat::Default => true,
-                _ => false,
-            },
-            PrintFormat::ShortDescription => match other {
-                PrintFormat::ShortDescription => true,
-                _ => false,
-            },
-        }
-    }
+    /// Prints the steps as JSON
+    Json,
+    /// Prints the steps as YAML
+    Yaml,
 }
 
 fn get_format_type(output_format: &str) -> PrintFormat {
     if output_format == "short-description" {
         PrintFormat::ShortDescription
+    } else if output_format == "json" {
+        PrintFormat::Json
+    } else if output_format == "yaml" {
+        PrintFormat::Yaml
     } else {
         PrintFormat::Default
     }
@@ -74,19 +74,181 @@ fn print_default(
     writeln!(output_buffer, "{:#?}", &execution_plan)
 }
 
+/// Returns the value without the unset (null) object attributes
+fn remove_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, remove_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(remove_nulls).collect()),
+        _ => value,
+    }
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Value {
+    remove_nulls(serde_json::to_value(value).unwrap_or(Value::Null))
+}
+
+/// Creates the structured step info (the defining file, description, condition and env)
+fn create_step_value(step: &Step) -> Value {
+    let task = &step.config;
+    let mut map = Map::new();
+
+    map.insert("name".to_string(), Value::String(step.name.clone()));
+    map.insert(
+        "makefile".to_string(),
+        task.get_makefile_path()
+            .map(Value::String)
+            .unwrap_or(Value::Null),
+    );
+    if let Some(ref description) = task.description {
+        map.insert(
+            "description".to_string(),
+            Value::String(description.clone()),
+        );
+    }
+    if let Some(ref category) = task.category {
+        map.insert("category".to_string(), Value::String(category.clone()));
+    }
+    if let Some(ref condition) = task.condition {
+        map.insert("condition".to_string(), to_value(condition));
+    }
+    if let Some(ref condition_script) = task.condition_script {
+        map.insert("condition_script".to_string(), to_value(condition_script));
+    }
+    if let Some(ref env) = task.env {
+        let env: Map<String, Value> = env
+            .iter()
+            .filter(|(key, _)| !LOCATION_ENV_KEYS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), to_value(value)))
+            .collect();
+
+        if !env.is_empty() {
+            map.insert("env".to_string(), Value::Object(env));
+        }
+    }
+    if let Some(ref command) = task.command {
+        map.insert("command".to_string(), Value::String(command.clone()));
+    }
+    if let Some(ref args) = task.args {
+        map.insert("args".to_string(), to_value(args));
+    }
+    if let Some(ref script) = task.script {
+        map.insert("script".to_string(), to_value(script));
+    }
+
+    Value::Object(map)
+}
+
+fn create_value(execution_plan: &ExecutionPlan, task: &str) -> Value {
+    let mut map = Map::new();
+    map.insert("task".to_string(), Value::String(task.to_string()));
+    map.insert(
+        "steps".to_string(),
+        Value::Array(execution_plan.steps.iter().map(create_step_value).collect()),
+    );
+
+    Value::Object(map)
+}
+
+fn print_json(
+    output_buffer: &mut impl io::Write,
+    execution_plan: &ExecutionPlan,
+    task: &str,
+) -> io::Result<()> {
+    let value = create_value(execution_plan, task);
+    writeln!(
+        output_buffer,
+        "{}",
+        serde_json::to_string_pretty(&value).unwrap_or_default()
+    )
+}
+
+fn to_yaml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || "_-./".contains(character))
+    {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Returns the inline value, or None for non empty objects and arrays which are written as blocks
+fn to_yaml_scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::Object(map) if map.is_empty() => Some("{}".to_string()),
+        Value::Array(values) if values.is_empty() => Some("[]".to_string()),
+        Value::Object(_) | Value::Array(_) => None,
+        // JSON strings are valid YAML double quoted strings
+        _ => Some(value.to_string()),
+    }
+}
+
+fn write_yaml(yaml: &mut String, value: &Value, indent: usize) {
+    let prefix = " ".repeat(indent);
+
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match to_yaml_scalar(value) {
+                    Some(scalar) => {
+                        yaml.push_str(&format!("{}{}: {}\n", prefix, to_yaml_key(key), scalar))
+                    }
+                    None => {
+                        yaml.push_str(&format!("{}{}:\n", prefix, to_yaml_key(key)));
+                        write_yaml(yaml, value, indent + 2);
+                    }
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                match to_yaml_scalar(value) {
+                    Some(scalar) => yaml.push_str(&format!("{}- {}\n", prefix, scalar)),
+                    None => {
+                        // the first line of the nested block starts after the dash
+                        let mut item = String::new();
+                        write_yaml(&mut item, value, indent + 2);
+                        yaml.push_str(&format!("{}- {}", prefix, &item[indent + 2..]));
+                    }
+                }
+            }
+        }
+        _ => {
+            if let Some(scalar) = to_yaml_scalar(value) {
+                yaml.push_str(&format!("{}{}\n", prefix, scalar));
+            }
+        }
+    }
+}
+
+fn print_yaml(
+    output_buffer: &mut impl io::Write,
+    execution_plan: &ExecutionPlan,
+    task: &str,
+) -> io::Result<()> {
+    let mut yaml = String::new();
+    write_yaml(&mut yaml, &create_value(execution_plan, task), 0);
+
+    write!(output_buffer, "{}", yaml)
+}
+
 /// Only prints the execution plan
 pub fn print(
     output_buffer: &mut impl io::Write,
     config: &Config,
     task: &str,
-    output_format: &str,
-    disable_workspace: bool,
-    skip_tasks_pattern: &Option<String>,
     crateinfo: &CrateInfo,
-    skip_init_end_tasks: bool,
+    options: &PrintStepsOptions,
 ) -> Result<(), CargoMakeError> {
-    let skip_tasks_pattern_regex = match skip_tasks_pattern {
-        Some(ref pattern) => match Regex::new(pattern) {
+    let skip_tasks_pattern_regex = match options.skip_tasks_pattern {
+        Some(pattern) => match Regex::new(pattern) {
             Ok(reg) => Some(reg),
             Err(_) => {
                 warn!("Invalid skip tasks pattern provided: {}", pattern);
@@ -98,18 +260,22 @@ pub fn print(
 
     let execution_plan = ExecutionPlanBuilder {
         crate_info: Some(crateinfo),
-        disable_workspace,
+        disable_workspace: options.disable_workspace,
         skip_tasks_pattern: skip_tasks_pattern_regex.as_ref(),
-        skip_init_end_tasks,
+        skip_init_end_tasks: options.skip_init_end_tasks,
+        from_step: options.from_step,
+        skip_steps: options.skip_steps,
         ..ExecutionPlanBuilder::new(&config, &task)
     }
     .build()?;
     debug!("Created execution plan: {:#?}", &execution_plan);
 
-    let print_format = get_format_type(&output_format);
+    let print_format = get_format_type(options.output_format);
 
     match print_format {
         PrintFormat::ShortDescription => print_short_description(output_buffer, &execution_plan)?,
+        PrintFormat::Json => print_json(output_buffer, &execution_plan, task)?,
+        PrintFormat::Yaml => print_yaml(output_buffer, &execution_plan, task)?,
         PrintFormat::Default => print_default(output_buffer, &execution_plan)?,
     };
     Ok(())
@@ -1,5 +1,5 @@
 use super::*;
-use crate::types::{ConfigSection, Step, Task};
+use crate::types::{ConfigSection, EnvValue, Step, Task, TaskCondition};
 use indexmap::IndexMap;
 
 #[test]
@@ -20,6 +20,18 @@ fn get_format_type_short_description() {
     assert_eq!(output, PrintFormat::ShortDescription);
 }
 
+#[test]
+fn get_format_type_json() {
+    let output = get_format_type("json");
+    assert_eq!(output, PrintFormat::Json);
+}
+
+#[test]
+fn get_format_type_yaml() {
+    let output = get_format_type("yaml");
+    assert_eq!(output, PrintFormat::Yaml);
+}
+
 #[test]
 fn print_default_format() {
     let mut config = Config {
@@ -29,6 +41,12 @@ fn print_default_format() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -39,11 +57,11 @@ fn print_default_format() {
         &mut std::io::stdout(),
         &config,
         "test",
-        "default",
-        false,
-        &None,
         &CrateInfo::new(),
-        false,
+        &PrintStepsOptions {
+            output_format: "default",
+            ..PrintStepsOptions::default()
+        },
     )
     .expect("print should succeed");
 }
@@ -58,6 +76,12 @@ fn print_task_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -67,11 +91,11 @@ fn print_task_not_found() {
         &mut std::io::stdout(),
         &config,
         "test",
-        "default",
-        false,
-        &None,
         &CrateInfo::new(),
-        false,
+        &PrintStepsOptions {
+            output_format: "default",
+            ..PrintStepsOptions::default()
+        },
     )
     .expect("print should succeed");
 }
@@ -85,6 +109,12 @@ fn print_task_not_found_but_skipped() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -94,11 +124,12 @@ fn print_task_not_found_but_skipped() {
         &mut std::io::stdout(),
         &config,
         "test",
-        "default",
-        false,
-        &Some("test".to_string()),
         &CrateInfo::new(),
-        false,
+        &PrintStepsOptions {
+            output_format: "default",
+            skip_tasks_pattern: Some("test"),
+            ..PrintStepsOptions::default()
+        },
     )
     .expect("print should succeed");
 }
@@ -151,6 +182,12 @@ fn print_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut output_bytes = Vec::<u8>::new();
@@ -158,11 +195,12 @@ fn print_skip_init_end_tasks() {
         &mut output_bytes,
         &config,
         "entry",
-        "default",
-        false,
-        &None,
         &CrateInfo::new(),
-        true,
+        &PrintStepsOptions {
+            output_format: "default",
+            skip_init_end_tasks: true,
+            ..PrintStepsOptions::default()
+        },
     )
     .expect("print should succeed");
     let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
@@ -179,3 +217,103 @@ fn print_skip_init_end_tasks() {
         end_task_name
     );
 }
+
+fn create_structured_execution_plan() -> ExecutionPlan {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("/project/Makefile.toml".to_string()),
+    );
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY".to_string(),
+        EnvValue::Value("/project".to_string()),
+    );
+    env.insert("RUST_LOG".to_string(), EnvValue::Value("debug".to_string()));
+
+    let mut task = Task::new();
+    task.description = Some("Builds the \"project\"".to_string());
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["build".to_string(), "--release".to_string()]);
+    task.env = Some(env);
+    task.condition = Some(TaskCondition {
+        profiles: Some(vec!["ci".to_string()]),
+        ..TaskCondition::default()
+    });
+
+    ExecutionPlan {
+        steps: vec![
+            Step {
+                name: "init".to_string(),
+                config: Task::new(),
+            },
+            Step {
+                name: "build".to_string(),
+                config: task,
+            },
+        ],
+    }
+}
+
+#[test]
+fn print_json_valid() {
+    let execution_plan = create_structured_execution_plan();
+
+    let mut output_bytes = Vec::<u8>::new();
+    print_json(&mut output_bytes, &execution_plan, "build").expect("print should succeed");
+    let output: Value = serde_json::from_slice(&output_bytes).unwrap();
+
+    assert_eq!(output["task"], "build");
+    let steps = output["steps"].as_array().unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0]["name"], "init");
+    assert!(steps[0]["makefile"].is_null());
+    assert_eq!(steps[1]["name"], "build");
+    assert_eq!(steps[1]["makefile"], "/project/Makefile.toml");
+    assert_eq!(
+        steps[1]["condition"],
+        serde_json::json!({"profiles": ["ci"]})
+    );
+    assert_eq!(steps[1]["env"], serde_json::json!({"RUST_LOG": "debug"}));
+    assert_eq!(steps[1]["args"], serde_json::json!(["build", "--release"]));
+}
+
+#[test]
+fn print_yaml_valid() {
+    let execution_plan = create_structured_execution_plan();
+
+    let mut output_bytes = Vec::<u8>::new();
+    print_yaml(&mut output_bytes, &execution_plan, "build").expect("print should succeed");
+    let output = std::str::from_utf8(&output_bytes).unwrap();
+
+    assert_eq!(
+        output,
+        concat!(
+            "steps:\n",
+            "  - makefile: null\n",
+            "    name: \"init\"\n",
+            "  - args:\n",
+            "      - \"build\"\n",
+            "      - \"--release\"\n",
+            "    command: \"cargo\"\n",
+            "    condition:\n",
+            "      profiles:\n",
+            "        - \"ci\"\n",
+            "    description: \"Builds the \\\"project\\\"\"\n",
+            "    env:\n",
+            "      RUST_LOG: \"debug\"\n",
+            "    makefile: \"/project/Makefile.toml\"\n",
+            "    name: \"build\"\n",
+            "task: \"build\"\n"
+        )
+    );
+}
+
+#[test]
+fn write_yaml_nested_arrays_and_keys() {
+    let value = serde_json::json!({"my key": [[1, 2], []], "empty": {}});
+
+    let mut yaml = String::new();
+    write_yaml(&mut yaml, &value, 0);
+
+    assert_eq!(yaml, "empty: {}\n\"my key\":\n  - - 1\n    - 2\n  - []\n");
+}
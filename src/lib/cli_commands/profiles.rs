@@ -0,0 +1,337 @@
+//! # profiles
+//!
+//! Lists all profiles defined or referenced in the merged descriptor and reports the
+//! profiles which are referenced but never defined.
+//!
+
+#[cfg(test)]
+#[path = "profiles_test.rs"]
+mod profiles_test;
+
+use crate::descriptor;
+use crate::error::CargoMakeError;
+use crate::profile;
+use crate::types::{Config, EnvFile, EnvValue, RunTaskInfo, Task, TaskCondition};
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+static CORE_MAKEFILE_LOCATION: &str = "internal core makefile";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ProfileInfo {
+    /// The env blocks and env files defining the profile
+    definitions: Vec<String>,
+    /// The conditions and attributes referencing the profile
+    references: Vec<String>,
+}
+
+fn add_entry(entries: &mut Vec<String>, source: &str, location: Option<&str>) {
+    let entry = match location {
+        Some(location) => format!("{} in {}", source, location),
+        None => source.to_string(),
+    };
+
+    if !entries.contains(&entry) {
+        entries.push(entry);
+    }
+}
+
+fn add_definition(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    name: &str,
+    source: &str,
+    location: Option<&str>,
+) {
+    let info = profiles.entry(name.to_string()).or_default();
+    add_entry(&mut info.definitions, source, location);
+}
+
+fn add_reference(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    name: &str,
+    source: &str,
+    location: Option<&str>,
+) {
+    let info = profiles.entry(name.to_string()).or_default();
+    add_entry(&mut info.references, source, location);
+}
+
+fn add_condition_references(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    source: &str,
+    location: Option<&str>,
+    condition: &Option<TaskCondition>,
+) {
+    if let Some(ref condition) = condition {
+        if let Some(ref names) = condition.profiles {
+            for name in names {
+                add_reference(profiles, name, &format!("{} condition", source), location);
+            }
+        }
+    }
+}
+
+fn add_env_file_definitions(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    source: &str,
+    location: Option<&str>,
+    env_files: &[EnvFile],
+) {
+    for env_file in env_files {
+        if let EnvFile::Info(ref info) = env_file {
+            if let Some(ref name) = info.profile {
+                // global env files hold the directory of the makefile defining them
+                let location = match location {
+                    Some(_) => location,
+                    None => info.base_path.as_deref(),
+                };
+
+                add_definition(
+                    profiles,
+                    name,
+                    &format!("{} {}", source, &info.path),
+                    location,
+                );
+            }
+        }
+    }
+}
+
+fn add_env_value_references(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    source: &str,
+    location: Option<&str>,
+    value: &EnvValue,
+) {
+    let condition = match value {
+        EnvValue::Script(ref info) => &info.condition,
+        EnvValue::Decode(ref info) => &info.condition,
+        EnvValue::Conditional(ref info) => &info.condition,
+        _ => return,
+    };
+
+    add_condition_references(profiles, source, location, condition);
+}
+
+/// Adds the profile env blocks (defined in the provided locations) and the profiles referenced
+/// in the env (table is the env table path, for example: tasks.build.env)
+fn add_env_profiles(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    table: &str,
+    location: Option<&str>,
+    env: &IndexMap<String, EnvValue>,
+    get_profile_locations: &dyn Fn(&str) -> Vec<String>,
+) {
+    for (key, value) in env {
+        match value {
+            EnvValue::Profile(ref sub_env) => {
+                let profile_table = format!("{}.{}", table, key);
+                let profile_locations = get_profile_locations(key);
+
+                for profile_location in &profile_locations {
+                    add_definition(
+                        profiles,
+                        key,
+                        &format!("[{}]", &profile_table),
+                        Some(profile_location),
+                    );
+                }
+
+                let profile_location = profile_locations.first().map(|value| value.as_str());
+                for (sub_key, sub_value) in sub_env {
+                    let source = format!("[{}] {}", &profile_table, sub_key);
+
+                    match sub_value {
+                        EnvValue::Value(ref base_profile_name) if sub_key == "extends" => {
+                            add_reference(profiles, base_profile_name, &source, profile_location)
+                        }
+                        _ => {
+                            add_env_value_references(profiles, &source, profile_location, sub_value)
+                        }
+                    }
+                }
+            }
+            _ => {
+                add_env_value_references(profiles, &format!("[{}] {}", table, key), location, value)
+            }
+        }
+    }
+}
+
+fn add_run_task_references(
+    profiles: &mut BTreeMap<String, ProfileInfo>,
+    source: &str,
+    location: Option<&str>,
+    run_task: &Option<RunTaskInfo>,
+) {
+    if let Some(RunTaskInfo::Routing(ref routes)) = run_task {
+        for route in routes {
+            add_condition_references(
+                profiles,
+                &format!("{} run_task", source),
+                location,
+                &route.condition,
+            );
+        }
+    }
+}
+
+fn add_task_profiles(profiles: &mut BTreeMap<String, ProfileInfo>, name: &str, task: &Task) {
+    let location = task
+        .get_makefile_path()
+        .unwrap_or(CORE_MAKEFILE_LOCATION.to_string());
+    let source = format!("[tasks.{}]", name);
+
+    add_condition_references(profiles, &source, Some(&location), &task.condition);
+    add_run_task_references(profiles, &source, Some(&location), &task.run_task);
+
+    if let Some(ref env) = task.env {
+        add_env_profiles(
+            profiles,
+            &format!("tasks.{}.env", name),
+            Some(&location),
+            env,
+            &|_| vec![location.clone()],
+        );
+    }
+
+    if let Some(ref env_files) = task.env_files {
+        add_env_file_definitions(
+            profiles,
+            &format!("{} env_files", &source),
+            Some(&location),
+            env_files,
+        );
+    }
+
+    for (platform, override_task) in [
+        ("linux", &task.linux),
+        ("windows", &task.windows),
+        ("mac", &task.mac),
+        ("ci", &task.ci),
+    ] {
+        if let Some(ref override_task) = override_task {
+            let override_source = format!("[tasks.{}.{}]", name, platform);
+
+            add_condition_references(
+                profiles,
+                &override_source,
+                Some(&location),
+                &override_task.condition,
+            );
+            add_run_task_references(
+                profiles,
+                &override_source,
+                Some(&location),
+                &override_task.run_task,
+            );
+        }
+    }
+}
+
+/// Returns all profiles defined or referenced in the descriptor (sorted by name)
+fn collect(config: &Config) -> BTreeMap<String, ProfileInfo> {
+    let mut profiles = BTreeMap::new();
+
+    add_definition(
+        &mut profiles,
+        &profile::default_profile(),
+        "default profile",
+        None,
+    );
+
+    add_env_profiles(&mut profiles, "env", None, &config.env, &|name| {
+        let locations = descriptor::get_env_profile_locations(name);
+
+        if locations.is_empty() {
+            vec![CORE_MAKEFILE_LOCATION.to_string()]
+        } else {
+            locations
+        }
+    });
+    add_env_file_definitions(&mut profiles, "env_files", None, &config.env_files);
+
+    if let Some(ref names) = config.config.additional_profiles {
+        for name in names {
+            add_reference(&mut profiles, name, "[config] additional_profiles", None);
+        }
+    }
+
+    for (attribute, profile_tasks) in [
+        (
+            "profile_on_error_tasks",
+            &config.config.profile_on_error_tasks,
+        ),
+        (
+            "profile_default_tasks",
+            &config.config.profile_default_tasks,
+        ),
+    ] {
+        if let Some(ref profile_tasks) = profile_tasks {
+            for name in profile_tasks.keys() {
+                add_reference(
+                    &mut profiles,
+                    name,
+                    &format!("[config] {}", attribute),
+                    None,
+                );
+            }
+        }
+    }
+
+    for (name, task) in &config.tasks {
+        add_task_profiles(&mut profiles, name, task);
+    }
+
+    profiles
+}
+
+/// Returns the profiles which are referenced but never defined
+fn find_undefined(profiles: &BTreeMap<String, ProfileInfo>) -> Vec<String> {
+    profiles
+        .iter()
+        .filter(|(_, info)| info.definitions.is_empty())
+        .map(|(name, info)| {
+            format!(
+                "Profile: {} is referenced but never defined, referenced by: {}",
+                name,
+                info.references.join(", ")
+            )
+        })
+        .collect()
+}
+
+fn create_report(profiles: &BTreeMap<String, ProfileInfo>) -> String {
+    let mut report = String::new();
+
+    for (name, info) in profiles {
+        report.push_str(&format!("{}\n", name));
+
+        for definition in &info.definitions {
+            report.push_str(&format!("    defined by: {}\n", definition));
+        }
+        for reference in &info.references {
+            report.push_str(&format!("    referenced by: {}\n", reference));
+        }
+    }
+
+    report
+}
+
+/// Prints all profiles and fails if any referenced profile is never defined
+pub(crate) fn run(config: &Config) -> Result<(), CargoMakeError> {
+    let profiles = collect(config);
+
+    print!("{}", create_report(&profiles));
+
+    let issues = find_undefined(&profiles);
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        for issue in &issues {
+            warn!("{}", issue);
+        }
+
+        Err(CargoMakeError::ValidationFailed(issues.len()))
+    }
+}
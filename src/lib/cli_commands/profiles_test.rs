@@ -0,0 +1,192 @@
+use super::*;
+use crate::types::{
+    ConfigSection, EnvFileInfo, PlatformOverrideTask, RunTaskName, RunTaskRoutingInfo,
+};
+
+fn create_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn create_condition(profiles: &[&str]) -> Option<TaskCondition> {
+    Some(TaskCondition {
+        profiles: Some(profiles.iter().map(|name| name.to_string()).collect()),
+        ..TaskCondition::default()
+    })
+}
+
+fn create_task(makefile: &str) -> Task {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value(makefile.to_string()),
+    );
+
+    let mut task = Task::new();
+    task.env = Some(env);
+
+    task
+}
+
+#[test]
+fn collect_empty() {
+    let config = create_config();
+
+    let profiles = collect(&config);
+
+    assert_eq!(profiles.len(), 1);
+    let info = profiles.get(&profile::default_profile()).unwrap();
+    assert_eq!(info.definitions, vec!["default profile".to_string()]);
+    assert!(info.references.is_empty());
+    assert!(find_undefined(&profiles).is_empty());
+}
+
+#[test]
+fn collect_internal_descriptors() {
+    let config = descriptor::load_internal_descriptors(true, false, None, None).unwrap();
+
+    let profiles = collect(&config);
+
+    let info = profiles.get("ci-coverage-tasks").unwrap();
+    assert_eq!(
+        info.definitions,
+        vec!["[env.ci-coverage-tasks] in internal core makefile".to_string()]
+    );
+    assert!(find_undefined(&profiles).is_empty());
+}
+
+#[test]
+fn collect_definitions_and_references() {
+    let mut config = create_config();
+
+    let mut ci_env = IndexMap::new();
+    ci_env.insert("extends".to_string(), EnvValue::Value("base".to_string()));
+    ci_env.insert("CI".to_string(), EnvValue::Boolean(true));
+    config
+        .env
+        .insert("ci".to_string(), EnvValue::Profile(ci_env));
+
+    let mut env_file = EnvFileInfo::new("./production.env".to_string());
+    env_file.base_path = Some("/project".to_string());
+    env_file.profile = Some("production".to_string());
+    config.env_files.push(EnvFile::Info(env_file));
+
+    config.config.additional_profiles = Some(vec!["ci".to_string()]);
+    let mut profile_on_error_tasks = IndexMap::new();
+    profile_on_error_tasks.insert("ci".to_string(), "upload-logs".to_string());
+    config.config.profile_on_error_tasks = Some(profile_on_error_tasks);
+    let mut profile_default_tasks = IndexMap::new();
+    profile_default_tasks.insert("ci".to_string(), "ci-flow".to_string());
+    config.config.profile_default_tasks = Some(profile_default_tasks);
+
+    let mut task = create_task("/project/Makefile.toml");
+    task.condition = create_condition(&["ci", "gpu"]);
+    task.run_task = Some(RunTaskInfo::Routing(vec![RunTaskRoutingInfo {
+        name: RunTaskName::Single("deploy".to_string()),
+        fork: None,
+        parallel: None,
+        cleanup_task: None,
+        condition: create_condition(&["production"]),
+        condition_script: None,
+        condition_script_runner_args: None,
+    }]));
+    let mut override_task: PlatformOverrideTask = serde_json::from_str("{}").unwrap();
+    override_task.condition = create_condition(&["gpu"]);
+    task.linux = Some(override_task);
+    let mut gpu_env = IndexMap::new();
+    gpu_env.insert("GPU".to_string(), EnvValue::Boolean(true));
+    task.env
+        .as_mut()
+        .unwrap()
+        .insert("gpu".to_string(), EnvValue::Profile(gpu_env));
+    config.tasks.insert("build".to_string(), task);
+
+    let profiles = collect(&config);
+
+    assert_eq!(
+        profiles.get("ci").unwrap(),
+        &ProfileInfo {
+            definitions: vec!["[env.ci] in internal core makefile".to_string()],
+            references: vec![
+                "[config] additional_profiles".to_string(),
+                "[config] profile_on_error_tasks".to_string(),
+                "[config] profile_default_tasks".to_string(),
+                "[tasks.build] condition in /project/Makefile.toml".to_string(),
+            ],
+        }
+    );
+    assert_eq!(
+        profiles.get("gpu").unwrap(),
+        &ProfileInfo {
+            definitions: vec!["[tasks.build.env.gpu] in /project/Makefile.toml".to_string()],
+            references: vec![
+                "[tasks.build] condition in /project/Makefile.toml".to_string(),
+                "[tasks.build.linux] condition in /project/Makefile.toml".to_string(),
+            ],
+        }
+    );
+    assert_eq!(
+        profiles.get("production").unwrap(),
+        &ProfileInfo {
+            definitions: vec!["env_files ./production.env in /project".to_string()],
+            references: vec![
+                "[tasks.build] run_task condition in /project/Makefile.toml".to_string()
+            ],
+        }
+    );
+
+    let issues = find_undefined(&profiles);
+    assert_eq!(
+        issues,
+        vec![
+            "Profile: base is referenced but never defined, referenced by: [env.ci] extends in internal core makefile"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn create_report_multiple() {
+    let mut profiles = BTreeMap::new();
+    add_definition(&mut profiles, "ci", "[env.ci]", Some("./Makefile.toml"));
+    add_reference(&mut profiles, "ci", "[tasks.build] condition", None);
+    add_reference(&mut profiles, "ci", "[tasks.build] condition", None);
+    add_reference(&mut profiles, "gpu", "[tasks.train] condition", None);
+
+    let report = create_report(&profiles);
+
+    assert_eq!(
+        report,
+        concat!(
+            "ci\n",
+            "    defined by: [env.ci] in ./Makefile.toml\n",
+            "    referenced by: [tasks.build] condition\n",
+            "gpu\n",
+            "    referenced by: [tasks.train] condition\n"
+        )
+    );
+}
+
+#[test]
+fn run_undefined() {
+    let mut config = create_config();
+    let mut task = create_task("./Makefile.toml");
+    task.condition = create_condition(&["gpu"]);
+    config.tasks.insert("train".to_string(), task);
+
+    let result = run(&config);
+
+    assert!(matches!(result, Err(CargoMakeError::ValidationFailed(1))));
+}
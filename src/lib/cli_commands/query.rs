@@ -0,0 +1,222 @@
+//! # query
+//!
+//! Machine interface for editor/IDE integrations and shell completion plugins.<br>
+//! Answers JSON requests (task list, task metadata, resolved env and validation results) with
+//! JSON responses written to stdout, one line per response.
+//!
+
+#[cfg(test)]
+#[path = "query_test.rs"]
+mod query_test;
+
+use crate::cli_commands::{export_env, validate};
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::types::{Config, DeprecationInfo};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+/// The query value which reads the requests from stdin (one per line) until it is closed
+static STDIN_QUERY: &str = "-";
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "query", rename_all = "snake_case")]
+enum Query {
+    /// Lists the tasks
+    Tasks {
+        /// Only list the tasks of this category
+        category: Option<String>,
+        /// Also list the private tasks
+        #[serde(default)]
+        include_private: bool,
+    },
+    /// Returns the normalized task definition
+    Task { name: String },
+    /// Returns the env vars defined by cargo-make (optionally with the task env applied)
+    Env { task: Option<String> },
+    /// Returns the validation issues of the makefiles
+    Validate,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct QueryRequest {
+    /// Optional request identifier which is returned in the response
+    id: Option<Value>,
+    #[serde(flatten)]
+    query: Query,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct QueryResponse {
+    id: Option<Value>,
+    result: Option<Value>,
+    error: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct TaskInfo {
+    name: String,
+    description: Option<String>,
+    category: Option<String>,
+    /// The task this name is an alias of
+    alias_of: Option<String>,
+    private: bool,
+    deprecated: bool,
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value, CargoMakeError> {
+    let value = serde_json::to_value(value).map_err(io::Error::from)?;
+
+    Ok(value)
+}
+
+fn get_tasks(
+    config: &Config,
+    category: &Option<String>,
+    include_private: bool,
+) -> Result<Vec<TaskInfo>, CargoMakeError> {
+    let mut tasks = vec![];
+
+    for name in config.tasks.keys() {
+        let actual_task_name = execution_plan::get_actual_task_name(config, name)?;
+        let task = execution_plan::get_normalized_task(config, &actual_task_name, true)?;
+
+        let private = task.private.unwrap_or(false);
+        if (private && !include_private)
+            || (category.is_some() && task.category.as_ref() != category.as_ref())
+        {
+            continue;
+        }
+
+        let deprecated = match task.deprecated {
+            Some(DeprecationInfo::Boolean(value)) => value,
+            Some(_) => true,
+            None => false,
+        };
+
+        tasks.push(TaskInfo {
+            name: name.to_string(),
+            description: task.description,
+            category: task.category,
+            alias_of: if &actual_task_name != name {
+                Some(actual_task_name)
+            } else {
+                None
+            },
+            private,
+            deprecated,
+        });
+    }
+
+    Ok(tasks)
+}
+
+fn get_task(config: &Config, name: &str) -> Result<Value, CargoMakeError> {
+    let actual_task_name = execution_plan::get_actual_task_name(config, name)?;
+    let task = execution_plan::get_normalized_task(config, &actual_task_name, true)?;
+
+    let mut value = serde_json::Map::new();
+    value.insert("name".to_string(), Value::String(name.to_string()));
+    value.insert("actual_name".to_string(), Value::String(actual_task_name));
+    value.insert("task".to_string(), to_value(&task)?);
+
+    Ok(Value::Object(value))
+}
+
+fn get_env(
+    config: &Config,
+    task: &Option<String>,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, CargoMakeError> {
+    let snapshot = export_env::get_env();
+
+    let result = match task {
+        Some(ref task) => export_env::get_task_env(config, task, initial_env).map(|(_, env)| env),
+        None => Ok(export_env::get_modified_env(initial_env, snapshot.clone())),
+    };
+
+    // the env queries must not affect each other
+    export_env::restore_env(&snapshot);
+
+    result
+}
+
+fn run_query(
+    config: &Config,
+    query: &Query,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<Value, CargoMakeError> {
+    match query {
+        Query::Tasks {
+            category,
+            include_private,
+        } => to_value(&get_tasks(config, category, *include_private)?),
+        Query::Task { name } => get_task(config, name),
+        Query::Env { task } => to_value(&get_env(config, task, initial_env)?),
+        Query::Validate => {
+            let issues = validate::validate(config);
+
+            let mut value = serde_json::Map::new();
+            value.insert("valid".to_string(), Value::Bool(issues.is_empty()));
+            value.insert("issues".to_string(), to_value(&issues)?);
+
+            Ok(Value::Object(value))
+        }
+    }
+}
+
+/// Returns the JSON response (single line) of the provided JSON request
+fn create_response(
+    config: &Config,
+    request: &str,
+    initial_env: &BTreeMap<String, String>,
+) -> String {
+    let response = match serde_json::from_str::<QueryRequest>(request) {
+        Ok(request) => match run_query(config, &request.query, initial_env) {
+            Ok(result) => QueryResponse {
+                id: request.id,
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => QueryResponse {
+                id: request.id,
+                result: None,
+                error: Some(error.to_string()),
+            },
+        },
+        Err(error) => QueryResponse {
+            id: None,
+            result: None,
+            error: Some(format!("Invalid query request, {}", error)),
+        },
+    };
+
+    serde_json::to_string(&response).unwrap_or_default()
+}
+
+/// Answers the provided JSON request, or all requests read from stdin in case the request is
+/// '-', writing the JSON responses to stdout
+pub(crate) fn run(
+    config: &Config,
+    request: &str,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<(), CargoMakeError> {
+    let mut stdout = io::stdout();
+
+    if request == STDIN_QUERY {
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            writeln!(stdout, "{}", create_response(config, &line, initial_env))?;
+            stdout.flush()?;
+        }
+    } else {
+        writeln!(stdout, "{}", create_response(config, request, initial_env))?;
+    }
+
+    Ok(())
+}
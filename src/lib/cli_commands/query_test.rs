@@ -0,0 +1,138 @@
+use super::*;
+use crate::types::{ConfigSection, EnvValue, Task};
+use indexmap::IndexMap;
+
+fn create_config() -> Config {
+    let mut build = Task::new();
+    build.description = Some("Builds the crate".to_string());
+    build.category = Some("Build".to_string());
+    let mut internal = Task::new();
+    internal.private = Some(true);
+    let mut compile = Task::new();
+    compile.alias = Some("build".to_string());
+    let mut test = Task::new();
+    test.category = Some("Test".to_string());
+    let mut env = IndexMap::new();
+    env.insert(
+        "QUERY_TEST_TASK_ENV".to_string(),
+        EnvValue::Value("test".to_string()),
+    );
+    test.env = Some(env);
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), build);
+    tasks.insert("internal".to_string(), internal);
+    tasks.insert("compile".to_string(), compile);
+    tasks.insert("test".to_string(), test);
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn query(request: &str) -> Value {
+    serde_json::from_str(&create_response(
+        &create_config(),
+        request,
+        &BTreeMap::new(),
+    ))
+    .unwrap()
+}
+
+#[test]
+fn parse_request_tasks() {
+    let request: QueryRequest =
+        serde_json::from_str(r#"{"id":1,"query":"tasks","category":"Build"}"#).unwrap();
+
+    assert_eq!(request.id, Some(Value::from(1)));
+    assert_eq!(
+        request.query,
+        Query::Tasks {
+            category: Some("Build".to_string()),
+            include_private: false
+        }
+    );
+}
+
+#[test]
+fn get_tasks_public() {
+    let tasks = get_tasks(&create_config(), &None, false).unwrap();
+
+    let names: Vec<&str> = tasks.iter().map(|task| task.name.as_str()).collect();
+    assert_eq!(names, vec!["build", "compile", "test"]);
+    assert_eq!(tasks[0].description, Some("Builds the crate".to_string()));
+    assert!(tasks[0].alias_of.is_none());
+    assert_eq!(tasks[1].alias_of, Some("build".to_string()));
+}
+
+#[test]
+fn get_tasks_include_private() {
+    let tasks = get_tasks(&create_config(), &None, true).unwrap();
+
+    assert_eq!(tasks.len(), 4);
+    assert!(tasks[1].private);
+}
+
+#[test]
+fn get_tasks_category() {
+    let tasks = get_tasks(&create_config(), &Some("Test".to_string()), false).unwrap();
+
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].name, "test");
+}
+
+#[test]
+fn create_response_task() {
+    let response = query(r#"{"id":"a","query":"task","name":"compile"}"#);
+
+    assert_eq!(response["id"], "a");
+    assert!(response["error"].is_null());
+    assert_eq!(response["result"]["actual_name"], "build");
+    assert_eq!(response["result"]["task"]["category"], "Build");
+}
+
+#[test]
+fn create_response_task_not_found() {
+    let response = query(r#"{"query":"task","name":"missing"}"#);
+
+    assert!(response["result"].is_null());
+    assert!(response["error"].is_string());
+}
+
+#[test]
+fn create_response_env_restored() {
+    let response = query(r#"{"query":"env","task":"test"}"#);
+
+    assert_eq!(response["result"]["QUERY_TEST_TASK_ENV"], "test");
+    assert!(!envmnt::exists("QUERY_TEST_TASK_ENV"));
+}
+
+#[test]
+fn create_response_validate() {
+    let response = query(r#"{"query":"validate"}"#);
+
+    assert_eq!(response["result"]["valid"], true);
+    assert_eq!(response["result"]["issues"], Value::Array(vec![]));
+}
+
+#[test]
+fn create_response_invalid_request() {
+    let response = query(r#"{"query":"unknown"}"#);
+
+    assert!(response["id"].is_null());
+    assert!(response["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid query request"));
+}
@@ -0,0 +1,295 @@
+//! # repl
+//!
+//! Interactive prompt which runs tasks repeatedly, inspects and sets env vars and switches
+//! profiles, reusing the already loaded descriptor between commands.
+//!
+
+#[cfg(test)]
+#[path = "repl_test.rs"]
+mod repl_test;
+
+use crate::cli_commands::{export_env, list_steps};
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::profile;
+use crate::run_lock;
+use crate::runner;
+use crate::task_args;
+use crate::types::{CliArgs, Config, EnvInfo};
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+static HELP: &str = r#"Commands:
+    <task> [args...]      Runs the task (same as: run <task> [args...])
+    run <task> [args...]  Runs the task
+    rerun                 Runs the last failed task again
+    tasks                 Lists the tasks
+    env [NAME]            Prints the env vars defined by cargo-make or the value of the env var
+    set NAME=VALUE        Sets the env var for the next runs
+    unset NAME            Removes the env var for the next runs
+    profile [NAME]        Prints the current profile or switches to another profile
+    reload                Sets up the env again (for example after modifying env files)
+    help                  Prints this help
+    exit                  Exits the repl
+"#;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ReplCommand {
+    Empty,
+    Help,
+    Tasks,
+    Run(String, Vec<String>),
+    Rerun,
+    Env(Option<String>),
+    Set(String, String),
+    Unset(String),
+    Profile(Option<String>),
+    Reload,
+    Exit,
+}
+
+fn parse_command(line: &str) -> Result<ReplCommand, String> {
+    let mut words = line.split_whitespace().map(|word| word.to_string());
+    let command = match words.next() {
+        Some(command) => command,
+        None => return Ok(ReplCommand::Empty),
+    };
+    let arguments: Vec<String> = words.collect();
+
+    let repl_command = match command.as_str() {
+        "help" => ReplCommand::Help,
+        "tasks" => ReplCommand::Tasks,
+        "run" => match arguments.split_first() {
+            Some((task, task_arguments)) => {
+                ReplCommand::Run(task.to_string(), task_arguments.to_vec())
+            }
+            None => return Err("Missing task name.".to_string()),
+        },
+        "rerun" => ReplCommand::Rerun,
+        "env" => ReplCommand::Env(arguments.first().cloned()),
+        "set" => match arguments.first().and_then(|value| value.split_once('=')) {
+            Some((key, value)) if !key.is_empty() => {
+                ReplCommand::Set(key.to_string(), value.to_string())
+            }
+            _ => return Err("Usage: set NAME=VALUE".to_string()),
+        },
+        "unset" => match arguments.first() {
+            Some(key) => ReplCommand::Unset(key.to_string()),
+            None => return Err("Usage: unset NAME".to_string()),
+        },
+        "profile" => ReplCommand::Profile(arguments.first().cloned()),
+        "reload" => ReplCommand::Reload,
+        "exit" | "quit" => ReplCommand::Exit,
+        _ => ReplCommand::Run(command, arguments),
+    };
+
+    Ok(repl_command)
+}
+
+struct ReplSession<'a> {
+    config: &'a Config,
+    cli_args: &'a CliArgs,
+    home: Option<PathBuf>,
+    /// The working directory, restored after each run
+    cwd: Option<PathBuf>,
+    /// The process env before the cargo-make modifications
+    initial_env: &'a BTreeMap<String, String>,
+    /// The process env when the repl started, restored before setting up the env again
+    base_env: BTreeMap<String, String>,
+    /// The process env after the env setup, restored before each run
+    session_env: BTreeMap<String, String>,
+    /// The env vars set (or removed if None) by the user
+    user_env: IndexMap<String, Option<String>>,
+    profile: String,
+    env_info: Option<EnvInfo>,
+    last_failed: Option<(String, Vec<String>)>,
+}
+
+impl<'a> ReplSession<'a> {
+    fn setup_env(&mut self) -> Result<(), CargoMakeError> {
+        self.env_info = None;
+        export_env::restore_env(&self.base_env);
+        profile::set(&self.profile);
+
+        self.env_info = Some(environment::setup_env(
+            self.cli_args,
+            self.config,
+            &self.cli_args.task,
+            self.home.clone(),
+            &mut vec![],
+        )?);
+
+        // ensure profile env was not overridden
+        profile::set(&self.profile);
+        for (key, value) in &self.user_env {
+            envmnt::set_or_remove(key, value);
+        }
+        self.session_env = export_env::get_env();
+
+        Ok(())
+    }
+
+    fn set_user_env(&mut self, key: &str, value: Option<String>) {
+        envmnt::set_or_remove(key, &value);
+        self.user_env.insert(key.to_string(), value);
+        self.session_env = export_env::get_env();
+    }
+
+    fn run_task(&mut self, task: &str, arguments: &[String]) -> Result<(), CargoMakeError> {
+        let env_info = match self.env_info {
+            Some(ref env_info) => env_info.clone(),
+            None => {
+                return Err(CargoMakeError::NotFound(
+                    "The env is not set up, use reload to set it up again.".to_string(),
+                ))
+            }
+        };
+
+        let mut cli_args = self.cli_args.clone();
+        cli_args.task = task.to_string();
+        cli_args.arguments = Some(arguments.to_vec());
+
+        envmnt::set("CARGO_MAKE_TASK", task);
+        envmnt::set_list("CARGO_MAKE_TASK_ARGS", &arguments.to_vec());
+        task_args::setup_env(self.config, task, &cli_args.arguments)?;
+
+        let _run_lock = run_lock::acquire(self.config, &cli_args, task)?;
+
+        runner::run(
+            self.config.clone(),
+            task,
+            env_info,
+            &cli_args,
+            SystemTime::now(),
+            vec![],
+        )
+    }
+
+    fn run_and_track_task(&mut self, task: &str, arguments: &[String]) {
+        let result = self.run_task(task, arguments);
+
+        // the task env and working directory must not leak into the next runs
+        export_env::restore_env(&self.session_env);
+        if let Some(ref cwd) = self.cwd {
+            env::set_current_dir(cwd).unwrap_or(());
+        }
+
+        let task_info = (task.to_string(), arguments.to_vec());
+        match result {
+            Ok(_) => {
+                if self.last_failed.as_ref() == Some(&task_info) {
+                    self.last_failed = None;
+                }
+            }
+            Err(error) => {
+                warn!("Task: {} failed - {}", task, error);
+                self.last_failed = Some(task_info);
+            }
+        }
+    }
+
+    fn print_env(&self, key: &Option<String>) {
+        match key {
+            Some(ref key) => match env::var(key) {
+                Ok(value) => println!("{}={}", key, value),
+                Err(_) => println!("{} is not defined.", key),
+            },
+            None => {
+                let env = export_env::get_modified_env(self.initial_env, export_env::get_env());
+                for (key, value) in env {
+                    println!("{}={}", key, value);
+                }
+            }
+        }
+    }
+
+    /// Runs the command and returns false if the repl should exit
+    fn run_command(&mut self, command: ReplCommand) -> Result<bool, CargoMakeError> {
+        match command {
+            ReplCommand::Empty => (),
+            ReplCommand::Help => print!("{}", HELP),
+            ReplCommand::Tasks => print!(
+                "{}",
+                list_steps::create_list(self.config, "default", &None, true, false)?
+            ),
+            ReplCommand::Run(task, arguments) => self.run_and_track_task(&task, &arguments),
+            ReplCommand::Rerun => match self.last_failed.clone() {
+                Some((task, arguments)) => self.run_and_track_task(&task, &arguments),
+                None => println!("No failed task to run again."),
+            },
+            ReplCommand::Env(key) => self.print_env(&key),
+            ReplCommand::Set(key, value) => self.set_user_env(&key, Some(value)),
+            ReplCommand::Unset(key) => self.set_user_env(&key, None),
+            ReplCommand::Profile(None) => println!("{}", &self.profile),
+            ReplCommand::Profile(Some(profile_name)) => {
+                self.profile = profile::set(&profile_name);
+                self.setup_env()?;
+                println!("Switched to profile: {}", &self.profile);
+            }
+            ReplCommand::Reload => {
+                self.setup_env()?;
+                println!("Env reloaded.");
+            }
+            ReplCommand::Exit => return Ok(false),
+        }
+
+        Ok(true)
+    }
+}
+
+/// Runs the interactive prompt until stdin is closed or the exit command is entered
+pub(crate) fn run(
+    config: &Config,
+    cli_args: &CliArgs,
+    home: Option<PathBuf>,
+    initial_env: &BTreeMap<String, String>,
+) -> Result<(), CargoMakeError> {
+    let mut session = ReplSession {
+        config,
+        cli_args,
+        home,
+        cwd: env::current_dir().ok(),
+        initial_env,
+        base_env: export_env::get_env(),
+        session_env: BTreeMap::new(),
+        user_env: IndexMap::new(),
+        profile: profile::get(),
+        env_info: None,
+        last_failed: None,
+    };
+    session.setup_env()?;
+
+    println!("Type help for the list of commands.");
+
+    let stdin = io::stdin();
+    loop {
+        print!("cargo make [{}]> ", &session.profile);
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            println!();
+            break;
+        }
+
+        let command = match parse_command(&line) {
+            Ok(command) => command,
+            Err(message) => {
+                println!("{}", message);
+                continue;
+            }
+        };
+
+        match session.run_command(command) {
+            Ok(true) => (),
+            Ok(false) => break,
+            Err(error) => warn!("{}", error),
+        }
+    }
+
+    Ok(())
+}
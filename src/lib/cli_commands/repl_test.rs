@@ -0,0 +1,86 @@
+use super::*;
+
+#[test]
+fn parse_command_empty() {
+    assert_eq!(parse_command("  \n").unwrap(), ReplCommand::Empty);
+}
+
+#[test]
+fn parse_command_builtins() {
+    assert_eq!(parse_command("help").unwrap(), ReplCommand::Help);
+    assert_eq!(parse_command("tasks").unwrap(), ReplCommand::Tasks);
+    assert_eq!(parse_command("rerun").unwrap(), ReplCommand::Rerun);
+    assert_eq!(parse_command("reload").unwrap(), ReplCommand::Reload);
+    assert_eq!(parse_command("exit").unwrap(), ReplCommand::Exit);
+    assert_eq!(parse_command("quit\n").unwrap(), ReplCommand::Exit);
+}
+
+#[test]
+fn parse_command_run() {
+    assert_eq!(
+        parse_command("run build --release").unwrap(),
+        ReplCommand::Run("build".to_string(), vec!["--release".to_string()])
+    );
+}
+
+#[test]
+fn parse_command_run_missing_task() {
+    assert!(parse_command("run").is_err());
+}
+
+#[test]
+fn parse_command_task_name() {
+    assert_eq!(
+        parse_command(" test  unit \n").unwrap(),
+        ReplCommand::Run("test".to_string(), vec!["unit".to_string()])
+    );
+}
+
+#[test]
+fn parse_command_env() {
+    assert_eq!(parse_command("env").unwrap(), ReplCommand::Env(None));
+    assert_eq!(
+        parse_command("env CARGO_MAKE_PROFILE").unwrap(),
+        ReplCommand::Env(Some("CARGO_MAKE_PROFILE".to_string()))
+    );
+}
+
+#[test]
+fn parse_command_set() {
+    assert_eq!(
+        parse_command("set RUST_LOG=debug=1").unwrap(),
+        ReplCommand::Set("RUST_LOG".to_string(), "debug=1".to_string())
+    );
+    assert_eq!(
+        parse_command("set EMPTY=").unwrap(),
+        ReplCommand::Set("EMPTY".to_string(), "".to_string())
+    );
+}
+
+#[test]
+fn parse_command_set_invalid() {
+    assert!(parse_command("set").is_err());
+    assert!(parse_command("set NAME").is_err());
+    assert!(parse_command("set =value").is_err());
+}
+
+#[test]
+fn parse_command_unset() {
+    assert_eq!(
+        parse_command("unset NAME").unwrap(),
+        ReplCommand::Unset("NAME".to_string())
+    );
+    assert!(parse_command("unset").is_err());
+}
+
+#[test]
+fn parse_command_profile() {
+    assert_eq!(
+        parse_command("profile").unwrap(),
+        ReplCommand::Profile(None)
+    );
+    assert_eq!(
+        parse_command("profile production").unwrap(),
+        ReplCommand::Profile(Some("production".to_string()))
+    );
+}
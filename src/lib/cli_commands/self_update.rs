@@ -0,0 +1,399 @@
+//! # self_update
+//!
+//! Replaces the running executable (and the other cargo-make executable installed next to it)
+//! with the latest published release binaries of the requested channel (stable or pre-release)
+//! after verifying the downloaded archive checksum.
+//!
+
+#[cfg(test)]
+#[path = "self_update_test.rs"]
+mod self_update_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::GlobalConfig;
+use semver::Version;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use uuid::Uuid;
+
+static RELEASES_URL: &str = "https://api.github.com/repos/sagiegurari/cargo-make/releases";
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The executables published in the release archives
+static EXECUTABLE_NAMES: [&str; 2] = ["cargo-make", "makers"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Channel {
+    /// Only full releases
+    Stable,
+    /// Full releases and pre-releases
+    PreRelease,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ReleaseInfo {
+    /// The release version (without the v prefix)
+    version: String,
+    /// The archive file name
+    archive_name: String,
+    /// The archive download url
+    archive_url: String,
+    /// The download url of the file holding the archive sha256 checksum
+    checksum_url: String,
+}
+
+fn parse_channel(value: &str) -> Result<Channel, CargoMakeError> {
+    match value {
+        "stable" => Ok(Channel::Stable),
+        "pre-release" => Ok(Channel::PreRelease),
+        _ => Err(CargoMakeError::InvalidArgument(format!(
+            "Unknown self-update channel: {}, supported channels: stable, pre-release",
+            value
+        ))),
+    }
+}
+
+fn parse_arguments(arguments: &Option<Vec<String>>) -> Result<Channel, CargoMakeError> {
+    let mut channel = Channel::Stable;
+
+    let arguments = arguments.clone().unwrap_or_default();
+    let mut iterator = arguments.iter();
+    while let Some(argument) = iterator.next() {
+        match argument.as_str() {
+            "--channel" => match iterator.next() {
+                Some(value) => channel = parse_channel(value)?,
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(
+                        "Missing value for self-update argument: --channel".to_string(),
+                    ))
+                }
+            },
+            _ => match argument.strip_prefix("--channel=") {
+                Some(value) => channel = parse_channel(value)?,
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(format!(
+                        "Unknown self-update argument: {}",
+                        argument
+                    )))
+                }
+            },
+        }
+    }
+
+    Ok(channel)
+}
+
+/// Returns the releases API url, the global config may point to a mirror with the same format
+fn get_releases_url(global_config: &GlobalConfig) -> String {
+    global_config
+        .self_update_url
+        .clone()
+        .unwrap_or(RELEASES_URL.to_string())
+}
+
+/// Returns the target triple used in the release archive names of the current platform
+fn get_target() -> String {
+    let platform = if cfg!(target_os = "windows") {
+        "pc-windows-msvc"
+    } else if cfg!(target_os = "macos") {
+        "apple-darwin"
+    } else if cfg!(target_env = "musl") {
+        "unknown-linux-musl"
+    } else {
+        "unknown-linux-gnu"
+    };
+
+    format!("{}-{}", env::consts::ARCH, platform)
+}
+
+fn get_asset_url<'a>(assets: &'a [Value], name: &str) -> Option<&'a str> {
+    assets
+        .iter()
+        .find(|asset| asset["name"].as_str() == Some(name))
+        .and_then(|asset| asset["browser_download_url"].as_str())
+}
+
+/// Returns the newest release of the channel (the releases are sorted from newest to oldest)
+fn find_release(
+    releases: &Value,
+    channel: Channel,
+    target: &str,
+) -> Result<ReleaseInfo, CargoMakeError> {
+    let release = releases
+        .as_array()
+        .and_then(|releases| {
+            releases.iter().find(|release| {
+                let draft = release["draft"].as_bool().unwrap_or(false);
+                let prerelease = release["prerelease"].as_bool().unwrap_or(false);
+
+                !draft && (channel == Channel::PreRelease || !prerelease)
+            })
+        })
+        .ok_or_else(|| {
+            CargoMakeError::SelfUpdateFailed("no published release found".to_string())
+        })?;
+
+    let tag_name = release["tag_name"].as_str().unwrap_or_default();
+    let version = tag_name.trim_start_matches('v').to_string();
+
+    let archive_suffix = format!("-{}.zip", target);
+    let assets = release["assets"].as_array().cloned().unwrap_or_default();
+    let archive_name = assets
+        .iter()
+        .filter_map(|asset| asset["name"].as_str())
+        .find(|name| name.ends_with(&archive_suffix))
+        .ok_or_else(|| {
+            CargoMakeError::SelfUpdateFailed(format!(
+                "release: {} has no binary for platform: {}",
+                tag_name, target
+            ))
+        })?
+        .to_string();
+
+    let archive_url = get_asset_url(&assets, &archive_name).unwrap_or_default();
+    let checksum_url =
+        get_asset_url(&assets, &format!("{}.sha256", &archive_name)).ok_or_else(|| {
+            CargoMakeError::SelfUpdateFailed(format!(
+                "release: {} has no checksum for: {}",
+                tag_name, &archive_name
+            ))
+        })?;
+
+    Ok(ReleaseInfo {
+        version,
+        archive_url: archive_url.to_string(),
+        checksum_url: checksum_url.to_string(),
+        archive_name,
+    })
+}
+
+/// Returns true if the release version is newer than the current version
+fn is_newer_release(current: &str, release: &str) -> bool {
+    match (Version::parse(current), Version::parse(release)) {
+        (Ok(current), Ok(release)) => release > current,
+        _ => current != release,
+    }
+}
+
+/// Returns the checksum from the checksum file content (the digest may be followed by the file name)
+fn parse_checksum(text: &str) -> Option<String> {
+    let checksum = text.split_whitespace().next()?.to_lowercase();
+
+    if checksum.len() == 64
+        && checksum
+            .chars()
+            .all(|character| character.is_ascii_hexdigit())
+    {
+        Some(checksum)
+    } else {
+        None
+    }
+}
+
+fn get_file_checksum(file: &Path) -> Result<String, CargoMakeError> {
+    let content = fs::read(file)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(&content);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn run_tool(command: &str, args: &[&str]) -> Result<Vec<u8>, CargoMakeError> {
+    debug!("Execute Command: {} {:?}", command, args);
+
+    let output = Command::new(command).args(args).output().map_err(|error| {
+        CargoMakeError::SelfUpdateFailed(format!("unable to run: {}, {}", command, error))
+    })?;
+
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(CargoMakeError::SelfUpdateFailed(format!(
+            "{} failed, {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+fn download(url: &str, file: Option<&Path>) -> Result<Vec<u8>, CargoMakeError> {
+    let mut args = vec!["--proto", "=https", "--tlsv1.2", "-sSfL"];
+    let file_string = file.map(|file| file.to_string_lossy().to_string());
+    if let Some(ref file_string) = file_string {
+        args.push("-o");
+        args.push(file_string);
+    }
+    args.push(url);
+
+    run_tool("curl", &args)
+}
+
+fn extract(archive: &Path, directory: &Path) -> Result<(), CargoMakeError> {
+    let archive = archive.to_string_lossy();
+    let directory = directory.to_string_lossy();
+
+    if cfg!(windows) {
+        run_tool("tar", &["-xf", &archive, "-C", &directory])?;
+    } else {
+        run_tool("unzip", &["-o", "-q", &archive, "-d", &directory])?;
+    }
+
+    Ok(())
+}
+
+/// Searches the extracted archive directory for the executable with the provided file name
+fn find_executable(directory: &Path, file_name: &str) -> Option<PathBuf> {
+    for entry in fs::read_dir(directory).ok()?.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            if let Some(executable) = find_executable(&path, file_name) {
+                return Some(executable);
+            }
+        } else if path.file_name().and_then(|name| name.to_str()) == Some(file_name) {
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+/// Replaces the executable, the new binary is staged next to it so the final rename is atomic
+fn replace_executable(executable: &Path, new_executable: &Path) -> Result<(), CargoMakeError> {
+    let file_name = get_file_name(executable);
+    let staged = executable.with_file_name(format!("{}.new", &file_name));
+
+    fs::copy(new_executable, &staged)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+    }
+
+    // a running executable can not be overwritten on windows but it can be renamed
+    if cfg!(windows) {
+        let backup = executable.with_file_name(format!("{}.old", &file_name));
+        io::delete_file(&backup.to_string_lossy());
+        fs::rename(executable, &backup)?;
+    }
+
+    fs::rename(&staged, executable)?;
+
+    Ok(())
+}
+
+fn get_file_name(executable: &Path) -> String {
+    executable
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Returns the executables to replace, the running executable and the other release executables
+/// installed next to it
+fn get_executables(executable: &Path) -> Vec<PathBuf> {
+    let mut executables = vec![executable.to_path_buf()];
+
+    for name in EXECUTABLE_NAMES {
+        let path = executable.with_file_name(format!("{}{}", name, env::consts::EXE_SUFFIX));
+        if path != executable && path.is_file() {
+            executables.push(path);
+        }
+    }
+
+    executables
+}
+
+fn update(release: &ReleaseInfo, directory: &Path) -> Result<(), CargoMakeError> {
+    let executables = get_executables(&env::current_exe()?);
+
+    info!("Downloading: {}", &release.archive_url);
+    let archive = directory.join(&release.archive_name);
+    download(&release.archive_url, Some(&archive))?;
+
+    let checksum_text = download(&release.checksum_url, None)?;
+    let expected_checksum =
+        parse_checksum(&String::from_utf8_lossy(&checksum_text)).ok_or_else(|| {
+            CargoMakeError::SelfUpdateFailed(format!(
+                "invalid checksum file: {}",
+                &release.checksum_url
+            ))
+        })?;
+    let checksum = get_file_checksum(&archive)?;
+    if checksum != expected_checksum {
+        return Err(CargoMakeError::SelfUpdateFailed(format!(
+            "checksum mismatch for: {}, expected: {} actual: {}",
+            &release.archive_name, expected_checksum, checksum
+        )));
+    }
+
+    let extract_directory = directory.join("extracted");
+    fs::create_dir_all(&extract_directory)?;
+    extract(&archive, &extract_directory)?;
+
+    // all new executables are located before replacing any, so a partial update is avoided
+    let mut replacements = vec![];
+    for (index, executable) in executables.into_iter().enumerate() {
+        let file_name = get_file_name(&executable);
+        match find_executable(&extract_directory, &file_name) {
+            Some(new_executable) => replacements.push((executable, new_executable)),
+            None if index == 0 => {
+                return Err(CargoMakeError::SelfUpdateFailed(format!(
+                    "executable: {} not found in: {}",
+                    &file_name, &release.archive_name
+                )))
+            }
+            None => warn!(
+                "Executable: {} not found in: {}, skipping it.",
+                &file_name, &release.archive_name
+            ),
+        }
+    }
+
+    for (executable, new_executable) in replacements {
+        debug!("Replacing executable: {:?}", &executable);
+        replace_executable(&executable, &new_executable)?;
+    }
+
+    Ok(())
+}
+
+/// Updates the running executable to the latest release of the requested channel
+pub(crate) fn run(
+    arguments: &Option<Vec<String>>,
+    global_config: &GlobalConfig,
+) -> Result<(), CargoMakeError> {
+    let channel = parse_arguments(arguments)?;
+
+    let releases_text = download(&get_releases_url(global_config), None)?;
+    let releases: Value = serde_json::from_slice(&releases_text).map_err(|error| {
+        CargoMakeError::SelfUpdateFailed(format!("unable to parse releases, {}", error))
+    })?;
+    let release = find_release(&releases, channel, &get_target())?;
+
+    if !is_newer_release(VERSION, &release.version) {
+        info!("cargo-make is up to date, version: {}", VERSION);
+        return Ok(());
+    }
+
+    let directory = env::temp_dir().join(format!("cargo-make-self-update-{}", Uuid::new_v4()));
+    fs::create_dir_all(&directory)?;
+
+    let result = update(&release, &directory);
+    if let Err(error) = fs::remove_dir_all(&directory) {
+        debug!("Unable to delete directory: {:?} {:#?}", &directory, error);
+    }
+    result?;
+
+    info!(
+        "Updated cargo-make from {} to {}",
+        VERSION, &release.version
+    );
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use super::*;
+use std::io::Write;
+
+fn create_releases() -> Value {
+    serde_json::json!([
+        {
+            "tag_name": "0.38.0-beta.1",
+            "draft": false,
+            "prerelease": true,
+            "assets": [
+                {
+                    "name": "cargo-make-v0.38.0-beta.1-x86_64-unknown-linux-musl.zip",
+                    "browser_download_url": "https://example.com/beta.zip"
+                },
+                {
+                    "name": "cargo-make-v0.38.0-beta.1-x86_64-unknown-linux-musl.zip.sha256",
+                    "browser_download_url": "https://example.com/beta.zip.sha256"
+                }
+            ]
+        },
+        {
+            "tag_name": "0.37.99",
+            "draft": false,
+            "prerelease": false,
+            "assets": [
+                {
+                    "name": "cargo-make-v0.37.99-x86_64-apple-darwin.zip",
+                    "browser_download_url": "https://example.com/mac.zip"
+                },
+                {
+                    "name": "cargo-make-v0.37.99-x86_64-unknown-linux-musl.zip",
+                    "browser_download_url": "https://example.com/stable.zip"
+                },
+                {
+                    "name": "cargo-make-v0.37.99-x86_64-unknown-linux-musl.zip.sha256",
+                    "browser_download_url": "https://example.com/stable.zip.sha256"
+                }
+            ]
+        }
+    ])
+}
+
+#[test]
+fn parse_arguments_default() {
+    let channel = parse_arguments(&None).unwrap();
+
+    assert_eq!(channel, Channel::Stable);
+}
+
+#[test]
+fn parse_arguments_channel() {
+    let channel = parse_arguments(&Some(vec![
+        "--channel".to_string(),
+        "pre-release".to_string(),
+    ]))
+    .unwrap();
+    assert_eq!(channel, Channel::PreRelease);
+
+    let channel = parse_arguments(&Some(vec!["--channel=stable".to_string()])).unwrap();
+    assert_eq!(channel, Channel::Stable);
+}
+
+#[test]
+fn parse_arguments_invalid() {
+    let result = parse_arguments(&Some(vec!["--channel".to_string(), "nightly".to_string()]));
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+
+    let result = parse_arguments(&Some(vec!["--channel".to_string()]));
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+
+    let result = parse_arguments(&Some(vec!["--force".to_string()]));
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
+
+#[test]
+fn get_target_current_platform() {
+    let target = get_target();
+
+    assert!(target.starts_with(env::consts::ARCH));
+}
+
+#[test]
+fn get_releases_url_default() {
+    let url = get_releases_url(&GlobalConfig::new());
+
+    assert_eq!(url, RELEASES_URL);
+}
+
+#[test]
+fn get_releases_url_from_global_config() {
+    let mut global_config = GlobalConfig::new();
+    global_config.self_update_url = Some("https://mirror.example.com/releases".to_string());
+
+    let url = get_releases_url(&global_config);
+
+    assert_eq!(url, "https://mirror.example.com/releases");
+}
+
+#[test]
+fn find_release_stable() {
+    let release = find_release(
+        &create_releases(),
+        Channel::Stable,
+        "x86_64-unknown-linux-musl",
+    )
+    .unwrap();
+
+    assert_eq!(
+        release,
+        ReleaseInfo {
+            version: "0.37.99".to_string(),
+            archive_name: "cargo-make-v0.37.99-x86_64-unknown-linux-musl.zip".to_string(),
+            archive_url: "https://example.com/stable.zip".to_string(),
+            checksum_url: "https://example.com/stable.zip.sha256".to_string(),
+        }
+    );
+}
+
+#[test]
+fn find_release_pre_release() {
+    let release = find_release(
+        &create_releases(),
+        Channel::PreRelease,
+        "x86_64-unknown-linux-musl",
+    )
+    .unwrap();
+
+    assert_eq!(release.version, "0.38.0-beta.1");
+    assert_eq!(release.archive_url, "https://example.com/beta.zip");
+}
+
+#[test]
+fn find_release_no_checksum() {
+    let result = find_release(&create_releases(), Channel::Stable, "x86_64-apple-darwin");
+
+    assert!(matches!(result, Err(CargoMakeError::SelfUpdateFailed(_))));
+}
+
+#[test]
+fn find_release_no_platform_binary() {
+    let result = find_release(
+        &create_releases(),
+        Channel::Stable,
+        "riscv64-unknown-linux-gnu",
+    );
+
+    assert!(matches!(result, Err(CargoMakeError::SelfUpdateFailed(_))));
+}
+
+#[test]
+fn is_newer_release_versions() {
+    assert!(is_newer_release("0.37.24", "0.37.25"));
+    assert!(is_newer_release("0.37.24", "0.38.0-beta.1"));
+    assert!(!is_newer_release("0.38.0", "0.38.0-beta.1"));
+    assert!(!is_newer_release("0.37.24", "0.37.24"));
+}
+
+#[test]
+fn parse_checksum_with_file_name() {
+    let checksum = "A".repeat(64);
+
+    assert_eq!(
+        parse_checksum(&format!("{}  cargo-make.zip\n", checksum)).unwrap(),
+        "a".repeat(64)
+    );
+}
+
+#[test]
+fn parse_checksum_invalid() {
+    assert!(parse_checksum("").is_none());
+    assert!(parse_checksum("not-a-checksum").is_none());
+}
+
+#[test]
+fn get_file_checksum_valid() {
+    let directory = env::temp_dir().join(format!("cargo-make-test-checksum-{}", Uuid::new_v4()));
+    fs::create_dir_all(&directory).unwrap();
+    let file = directory.join("archive.zip");
+    fs::File::create(&file)
+        .unwrap()
+        .write_all(b"hello")
+        .unwrap();
+
+    let checksum = get_file_checksum(&file).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        checksum,
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+}
+
+#[test]
+fn find_executable_nested() {
+    let directory = env::temp_dir().join(format!("cargo-make-test-find-exe-{}", Uuid::new_v4()));
+    let nested = directory.join("cargo-make-v0.37.99-x86_64-unknown-linux-musl");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(nested.join("makers"), "").unwrap();
+    fs::write(nested.join("cargo-make"), "").unwrap();
+
+    let executable = find_executable(&directory, "cargo-make");
+    let missing = find_executable(&directory, "other");
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(executable.unwrap(), nested.join("cargo-make"));
+    assert!(missing.is_none());
+}
+
+#[test]
+fn replace_executable_valid() {
+    let directory = env::temp_dir().join(format!("cargo-make-test-replace-{}", Uuid::new_v4()));
+    fs::create_dir_all(&directory).unwrap();
+    let executable = directory.join("cargo-make");
+    let new_executable = directory.join("cargo-make-downloaded");
+    fs::write(&executable, "old").unwrap();
+    fs::write(&new_executable, "new").unwrap();
+
+    replace_executable(&executable, &new_executable).unwrap();
+    let content = fs::read_to_string(&executable).unwrap();
+    let staged_exists = directory.join("cargo-make.new").exists();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(content, "new");
+    assert!(!staged_exists);
+}
+
+#[test]
+fn get_executables_side_by_side() {
+    let directory = env::temp_dir().join(format!("cargo-make-test-executables-{}", Uuid::new_v4()));
+    fs::create_dir_all(&directory).unwrap();
+    let executable = directory.join(format!("cargo-make{}", env::consts::EXE_SUFFIX));
+    let makers = directory.join(format!("makers{}", env::consts::EXE_SUFFIX));
+    fs::write(&executable, "").unwrap();
+
+    let single = get_executables(&executable);
+    fs::write(&makers, "").unwrap();
+    let both = get_executables(&executable);
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(single, vec![executable.clone()]);
+    assert_eq!(both, vec![executable, makers]);
+}
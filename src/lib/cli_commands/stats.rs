@@ -0,0 +1,218 @@
+//! # stats
+//!
+//! Prints the task execution statistics (slowest tasks, failure rates, duration trends and
+//! flaky tasks) based on the local task history of the project.
+//!
+
+#[cfg(test)]
+#[path = "stats_test.rs"]
+mod stats_test;
+
+use crate::error::CargoMakeError;
+use crate::history::{self, FlakyTask, HistoryEntry};
+use indexmap::IndexMap;
+use std::cmp::Reverse;
+
+/// The amount of latest runs compared with the runs preceding them for the duration trend
+static TREND_RUNS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+struct StatsOptions {
+    /// The maximum amount of tasks to print
+    limit: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TaskStats {
+    runs: usize,
+    failures: usize,
+    /// The average duration in milliseconds
+    average: u128,
+    /// The maximum duration in milliseconds
+    max: u128,
+    /// The average duration change (in percents) of the latest runs compared to the runs
+    /// preceding them, not available if the task did not run enough times
+    trend: Option<f64>,
+}
+
+fn parse_arguments(arguments: &Option<Vec<String>>) -> Result<StatsOptions, CargoMakeError> {
+    let mut options = StatsOptions { limit: 20 };
+
+    let arguments = arguments.clone().unwrap_or_default();
+    let mut iterator = arguments.iter();
+    while let Some(argument) = iterator.next() {
+        match argument.as_str() {
+            "--limit" => match iterator.next().and_then(|value| value.parse().ok()) {
+                Some(limit) => options.limit = limit,
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(
+                        "Missing or invalid value for stats argument: --limit".to_string(),
+                    ))
+                }
+            },
+            _ => {
+                return Err(CargoMakeError::InvalidArgument(format!(
+                    "Unknown stats argument: {}",
+                    argument
+                )))
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn get_average(durations: &[u128]) -> u128 {
+    if durations.is_empty() {
+        0
+    } else {
+        durations.iter().sum::<u128>() / durations.len() as u128
+    }
+}
+
+fn get_trend(durations: &[u128]) -> Option<f64> {
+    if durations.len() < TREND_RUNS * 2 {
+        return None;
+    }
+
+    let recent_start = durations.len() - TREND_RUNS;
+    let previous = get_average(&durations[recent_start - TREND_RUNS..recent_start]);
+    if previous == 0 {
+        return None;
+    }
+    let recent = get_average(&durations[recent_start..]);
+
+    Some((recent as f64 - previous as f64) * 100.0 / previous as f64)
+}
+
+/// Returns the stats of each task (sorted from the slowest task), the entries are expected
+/// to be sorted by execution time
+fn collect(entries: &[HistoryEntry]) -> Vec<(String, TaskStats)> {
+    let mut durations: IndexMap<String, Vec<u128>> = IndexMap::new();
+    let mut failures: IndexMap<String, usize> = IndexMap::new();
+    for entry in entries {
+        durations
+            .entry(entry.task.to_string())
+            .or_default()
+            .push(entry.duration);
+        if !entry.success {
+            *failures.entry(entry.task.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut stats: Vec<(String, TaskStats)> = durations
+        .into_iter()
+        .map(|(task, durations)| {
+            let task_stats = TaskStats {
+                runs: durations.len(),
+                failures: failures.get(&task).copied().unwrap_or(0),
+                average: get_average(&durations),
+                max: durations.iter().copied().max().unwrap_or(0),
+                trend: get_trend(&durations),
+            };
+
+            (task, task_stats)
+        })
+        .collect();
+    stats.sort_by_key(|(_, task_stats)| Reverse(task_stats.average));
+
+    stats
+}
+
+fn format_duration(duration: u128) -> String {
+    format!("{:.2}s", duration as f64 / 1000.0)
+}
+
+fn format_trend(trend: Option<f64>) -> String {
+    match trend {
+        Some(trend) => format!("{:+.1}%", trend),
+        None => "-".to_string(),
+    }
+}
+
+fn create_report(stats: &[(String, TaskStats)], limit: usize) -> String {
+    let rows: Vec<[String; 6]> = stats
+        .iter()
+        .take(limit)
+        .map(|(task, task_stats)| {
+            [
+                task.to_string(),
+                task_stats.runs.to_string(),
+                format!(
+                    "{:.1}%",
+                    task_stats.failures as f64 * 100.0 / task_stats.runs as f64
+                ),
+                format_duration(task_stats.average),
+                format_duration(task_stats.max),
+                format_trend(task_stats.trend),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Task".to_string(),
+        "Runs".to_string(),
+        "Failures".to_string(),
+        "Average".to_string(),
+        "Max".to_string(),
+        "Trend".to_string(),
+    ];
+    let mut widths = header.clone().map(|title| title.len());
+    for row in &rows {
+        for (index, value) in row.iter().enumerate() {
+            widths[index] = widths[index].max(value.len());
+        }
+    }
+
+    let mut report = String::new();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(index, value)| format!("{:width$}", value, width = widths[index]))
+            .collect();
+        report.push_str(line.join("  ").trim_end());
+        report.push('\n');
+    }
+
+    report
+}
+
+fn create_flaky_report(flaky_tasks: &[FlakyTask]) -> String {
+    let mut report = String::new();
+
+    if !flaky_tasks.is_empty() {
+        report.push_str("\nFlaky tasks:\n");
+        for flaky_task in flaky_tasks {
+            report.push_str(&format!(
+                "    {} (failed {} out of {} runs with identical inputs)\n",
+                &flaky_task.name, flaky_task.failures, flaky_task.runs
+            ));
+        }
+    }
+
+    report
+}
+
+/// Prints the slowest tasks, their failure rates and duration trends and the flaky tasks based
+/// on the task history
+pub(crate) fn run(arguments: &Option<Vec<String>>) -> Result<(), CargoMakeError> {
+    let options = parse_arguments(arguments)?;
+
+    let entries = match history::get_history_file() {
+        Some(file) => history::load(&file),
+        None => vec![],
+    };
+
+    if entries.is_empty() {
+        info!("No task history found, set task_history = true in the [config] section to record the task executions.");
+    } else {
+        print!("{}", create_report(&collect(&entries), options.limit));
+        print!(
+            "{}",
+            create_flaky_report(&history::find_flaky_tasks(&entries))
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use super::*;
+
+fn create_entry(task: &str, duration: u128, success: bool) -> HistoryEntry {
+    HistoryEntry {
+        task: task.to_string(),
+        timestamp: 1000,
+        duration,
+        success,
+        profile: "development".to_string(),
+        git_sha: None,
+    }
+}
+
+#[test]
+fn parse_arguments_none() {
+    let options = parse_arguments(&None).unwrap();
+
+    assert_eq!(options, StatsOptions { limit: 20 });
+}
+
+#[test]
+fn parse_arguments_limit() {
+    let options = parse_arguments(&Some(vec!["--limit".to_string(), "5".to_string()])).unwrap();
+
+    assert_eq!(options, StatsOptions { limit: 5 });
+}
+
+#[test]
+fn parse_arguments_invalid_limit() {
+    let result = parse_arguments(&Some(vec!["--limit".to_string(), "abc".to_string()]));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_arguments_unknown() {
+    let result = parse_arguments(&Some(vec!["--bad".to_string()]));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_trend_not_enough_runs() {
+    assert!(get_trend(&[100, 100, 100, 100, 100, 200, 200, 200, 200]).is_none());
+}
+
+#[test]
+fn get_trend_slower() {
+    let trend = get_trend(&[50, 100, 100, 100, 100, 100, 150, 150, 150, 150, 150]).unwrap();
+
+    assert_eq!(trend, 50.0);
+}
+
+#[test]
+fn get_trend_faster() {
+    let trend = get_trend(&[200, 200, 200, 200, 200, 100, 100, 100, 100, 100]).unwrap();
+
+    assert_eq!(trend, -50.0);
+}
+
+#[test]
+fn collect_sorted_by_average() {
+    let stats = collect(&[
+        create_entry("build", 100, true),
+        create_entry("test", 500, false),
+        create_entry("build", 300, false),
+        create_entry("test", 700, true),
+    ]);
+
+    assert_eq!(
+        stats,
+        vec![
+            (
+                "test".to_string(),
+                TaskStats {
+                    runs: 2,
+                    failures: 1,
+                    average: 600,
+                    max: 700,
+                    trend: None,
+                }
+            ),
+            (
+                "build".to_string(),
+                TaskStats {
+                    runs: 2,
+                    failures: 1,
+                    average: 200,
+                    max: 300,
+                    trend: None,
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn create_report_values() {
+    let stats = collect(&[
+        create_entry("build", 1500, true),
+        create_entry("test", 250, false),
+        create_entry("test", 250, true),
+    ]);
+
+    let report = create_report(&stats, 20);
+
+    assert_eq!(
+        report,
+        r#"Task   Runs  Failures  Average  Max    Trend
+build  1     0.0%      1.50s    1.50s  -
+test   2     50.0%     0.25s    0.25s  -
+"#
+    );
+}
+
+#[test]
+fn create_report_limit() {
+    let stats = collect(&[
+        create_entry("build", 1500, true),
+        create_entry("test", 250, false),
+    ]);
+
+    let report = create_report(&stats, 1);
+
+    assert_eq!(report.lines().count(), 2);
+    assert!(report.contains("build"));
+    assert!(!report.contains("test"));
+}
+
+#[test]
+fn create_flaky_report_empty() {
+    assert!(create_flaky_report(&[]).is_empty());
+}
+
+#[test]
+fn create_flaky_report_values() {
+    let report = create_flaky_report(&[FlakyTask {
+        name: "test".to_string(),
+        runs: 4,
+        failures: 2,
+    }]);
+
+    assert_eq!(
+        report,
+        "\nFlaky tasks:\n    test (failed 2 out of 4 runs with identical inputs)\n"
+    );
+}
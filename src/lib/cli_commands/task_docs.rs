@@ -0,0 +1,244 @@
+//! # task_docs
+//!
+//! Generates a markdown reference of all public tasks (grouped by category) with their
+//! descriptions, arguments, dependencies and example invocations.
+//!
+
+#[cfg(test)]
+#[path = "task_docs_test.rs"]
+mod task_docs_test;
+
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::io;
+use crate::task_args;
+use crate::types::{Config, DeprecationInfo, Task};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, PartialEq)]
+struct TaskDocsOptions {
+    /// Optional output file, if not provided the output is printed
+    output_file: Option<String>,
+    /// True to include the tasks defined in the internal core makefiles
+    include_core: bool,
+}
+
+fn parse_arguments(arguments: &Option<Vec<String>>) -> Result<TaskDocsOptions, CargoMakeError> {
+    let mut options = TaskDocsOptions {
+        output_file: None,
+        include_core: false,
+    };
+
+    let arguments = arguments.clone().unwrap_or_default();
+    let mut iterator = arguments.iter();
+    while let Some(argument) = iterator.next() {
+        match argument.as_str() {
+            "--output-file" => match iterator.next() {
+                Some(value) => options.output_file = Some(value.to_string()),
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(
+                        "Missing value for task-docs argument: --output-file".to_string(),
+                    ))
+                }
+            },
+            "--include-core" => options.include_core = true,
+            _ => {
+                return Err(CargoMakeError::InvalidArgument(format!(
+                    "Unknown task-docs argument: {}",
+                    argument
+                )))
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn get_deprecation_text(deprecated: &Option<DeprecationInfo>) -> Option<String> {
+    match deprecated {
+        Some(DeprecationInfo::Boolean(true)) => Some("This task is deprecated.".to_string()),
+        Some(DeprecationInfo::Message(ref message)) => {
+            Some(format!("This task is deprecated - {}", message))
+        }
+        Some(DeprecationInfo::Details(ref details)) => {
+            let mut text = "This task is deprecated".to_string();
+            if let Some(ref message) = details.message {
+                text.push_str(&format!(" - {}", message));
+            }
+            if let Some(ref replaced_by) = details.replaced_by {
+                text.push_str(&format!(" (replaced by: `{}`)", replaced_by));
+            }
+
+            Some(text)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the example invocation which provides all positional and required arguments
+fn get_example(name: &str, task: &Task) -> String {
+    let mut example = format!("cargo make {}", name);
+
+    if let Some(ref schema) = task.args_schema {
+        for (argument_name, argument) in schema {
+            if task_args::is_positional(argument) || argument.required.unwrap_or(false) {
+                example.push(' ');
+                example.push_str(&task_args::get_usage(argument_name, argument));
+            }
+        }
+    }
+
+    example
+}
+
+fn get_arguments_table(task: &Task) -> Option<String> {
+    let schema = match task.args_schema {
+        Some(ref schema) if !schema.is_empty() => schema,
+        _ => return None,
+    };
+
+    let mut table = String::new();
+    table.push_str("| Argument | Type | Default | Env | Description |\n");
+    table.push_str("| --- | --- | --- | --- | --- |\n");
+    for (name, argument) in schema {
+        let default = match argument.default {
+            Some(ref default) => format!("`{}`", escape_table_cell(default)),
+            None if argument.required.unwrap_or(false) => "required".to_string(),
+            None => "".to_string(),
+        };
+
+        table.push_str(&format!(
+            "| `{}` | {} | {} | `{}` | {} |\n",
+            escape_table_cell(&task_args::get_usage(name, argument)),
+            task_args::get_type_name(argument),
+            default,
+            task_args::get_env_name(name, argument),
+            escape_table_cell(argument.help.as_deref().unwrap_or(""))
+        ));
+    }
+
+    Some(table)
+}
+
+fn create_task_section(name: &str, task: &Task, aliases: Option<&BTreeSet<String>>) -> String {
+    let mut section = format!("### {}\n\n", name);
+
+    match task.description {
+        Some(ref description) => section.push_str(&format!("{}\n\n", description.trim())),
+        None => section.push_str("No Description.\n\n"),
+    }
+
+    if let Some(text) = get_deprecation_text(&task.deprecated) {
+        section.push_str(&format!("**Deprecated:** {}\n\n", text));
+    }
+
+    if let Some(aliases) = aliases {
+        let names: Vec<String> = aliases.iter().map(|alias| format!("`{}`", alias)).collect();
+        section.push_str(&format!("**Aliases:** {}\n\n", names.join(", ")));
+    }
+
+    if let Some(ref dependencies) = task.dependencies {
+        if !dependencies.is_empty() {
+            let names: Vec<String> = dependencies
+                .iter()
+                .map(|dependency| format!("`{}`", dependency.name()))
+                .collect();
+            section.push_str(&format!("**Dependencies:** {}\n\n", names.join(", ")));
+        }
+    }
+
+    if let Some(table) = get_arguments_table(task) {
+        section.push_str("**Arguments:**\n\n");
+        section.push_str(&table);
+        section.push('\n');
+    }
+
+    // the task examples take precedence over the generated example
+    let examples = match task.examples {
+        Some(ref examples) if !examples.is_empty() => examples.clone(),
+        _ => vec![get_example(name, task)],
+    };
+    if examples.len() == 1 {
+        section.push_str("**Example:**\n\n");
+    } else {
+        section.push_str("**Examples:**\n\n");
+    }
+    section.push_str("```console\n");
+    section.push_str(&examples.join("\n"));
+    section.push_str("\n```\n\n");
+
+    section
+}
+
+/// Creates the markdown document of all public tasks grouped by category
+pub(crate) fn create_docs(config: &Config, include_core: bool) -> Result<String, CargoMakeError> {
+    // category -> task name -> normalized task
+    let mut categories: BTreeMap<String, BTreeMap<String, Task>> = BTreeMap::new();
+    // actual task -> aliases
+    let mut aliases: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+    for (name, task) in &config.tasks {
+        if !include_core && task.get_makefile_path().is_none() {
+            continue;
+        }
+
+        let actual_task_name = execution_plan::get_actual_task_name(config, name)?;
+        let normalized_task = execution_plan::get_normalized_task(config, &actual_task_name, true)?;
+
+        if normalized_task.private.unwrap_or(false) {
+            continue;
+        }
+
+        if &actual_task_name != name {
+            aliases
+                .entry(actual_task_name)
+                .or_default()
+                .insert(name.to_string());
+            continue;
+        }
+
+        let category = normalized_task
+            .category
+            .clone()
+            .unwrap_or("No Category".to_string());
+        categories
+            .entry(category)
+            .or_default()
+            .insert(name.to_string(), normalized_task);
+    }
+
+    let mut docs = String::new();
+    docs.push_str("# Tasks\n\n");
+    docs.push_str("Generated by cargo-make, regenerate instead of editing manually.\n\n");
+
+    for (category, tasks) in &categories {
+        docs.push_str(&format!("## {}\n\n", category));
+
+        for (name, task) in tasks {
+            docs.push_str(&create_task_section(name, task, aliases.get(name)));
+        }
+    }
+
+    Ok(docs)
+}
+
+/// Generates the tasks markdown reference based on the task-docs command line arguments
+pub(crate) fn run(config: &Config, arguments: &Option<Vec<String>>) -> Result<(), CargoMakeError> {
+    let options = parse_arguments(arguments)?;
+
+    let output = create_docs(config, options.include_core)?;
+
+    match options.output_file {
+        Some(ref file) => {
+            io::write_text_file(file, &output);
+            info!("Tasks documentation written to: {}", file);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use super::*;
+use crate::types::{ConfigSection, DeprecationDetails, EnvValue, TaskArgument, TaskArgumentType};
+use indexmap::IndexMap;
+
+fn create_task(description: Option<&str>, category: Option<&str>) -> Task {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./Makefile.toml".to_string()),
+    );
+
+    let mut task = Task::new();
+    task.description = description.map(|value| value.to_string());
+    task.category = category.map(|value| value.to_string());
+    task.env = Some(env);
+    task
+}
+
+fn create_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn create_schema() -> IndexMap<String, TaskArgument> {
+    let mut schema = IndexMap::new();
+    schema.insert(
+        "environment".to_string(),
+        TaskArgument {
+            positional: Some(true),
+            required: Some(true),
+            help: Some("The target environment".to_string()),
+            ..Default::default()
+        },
+    );
+    schema.insert(
+        "dry-run".to_string(),
+        TaskArgument {
+            arg_type: Some(TaskArgumentType::Bool),
+            help: Some("Only print | the changes".to_string()),
+            ..Default::default()
+        },
+    );
+    schema.insert(
+        "replicas".to_string(),
+        TaskArgument {
+            arg_type: Some(TaskArgumentType::Number),
+            default: Some("2".to_string()),
+            env: Some("REPLICAS".to_string()),
+            ..Default::default()
+        },
+    );
+    schema
+}
+
+fn to_arguments(arguments: Vec<&str>) -> Option<Vec<String>> {
+    Some(arguments.iter().map(|value| value.to_string()).collect())
+}
+
+#[test]
+fn parse_arguments_empty() {
+    let options = parse_arguments(&None).unwrap();
+
+    assert_eq!(
+        options,
+        TaskDocsOptions {
+            output_file: None,
+            include_core: false,
+        }
+    );
+}
+
+#[test]
+fn parse_arguments_valid() {
+    let options = parse_arguments(&to_arguments(vec![
+        "--output-file",
+        "TASKS.md",
+        "--include-core",
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        options,
+        TaskDocsOptions {
+            output_file: Some("TASKS.md".to_string()),
+            include_core: true,
+        }
+    );
+}
+
+#[test]
+fn parse_arguments_invalid() {
+    assert!(parse_arguments(&to_arguments(vec!["--output-file"])).is_err());
+    assert!(parse_arguments(&to_arguments(vec!["--bad"])).is_err());
+}
+
+#[test]
+fn get_example_no_schema() {
+    let task = Task::new();
+
+    assert_eq!(get_example("build", &task), "cargo make build");
+}
+
+#[test]
+fn get_example_with_schema() {
+    let mut task = Task::new();
+    task.args_schema = Some(create_schema());
+
+    assert_eq!(
+        get_example("deploy", &task),
+        "cargo make deploy <environment>"
+    );
+}
+
+#[test]
+fn get_arguments_table_no_schema() {
+    assert!(get_arguments_table(&Task::new()).is_none());
+}
+
+#[test]
+fn get_arguments_table_with_schema() {
+    let mut task = Task::new();
+    task.args_schema = Some(create_schema());
+
+    let table = get_arguments_table(&task).unwrap();
+
+    assert_eq!(
+        table,
+        concat!(
+            "| Argument | Type | Default | Env | Description |\n",
+            "| --- | --- | --- | --- | --- |\n",
+            "| `<environment>` | string | required | `CARGO_MAKE_TASK_ARG_ENVIRONMENT` | The target environment |\n",
+            "| `--dry-run` | bool |  | `CARGO_MAKE_TASK_ARG_DRY_RUN` | Only print \\| the changes |\n",
+            "| `--replicas <value>` | number | `2` | `REPLICAS` |  |\n",
+        )
+    );
+}
+
+#[test]
+fn create_docs_empty() {
+    let config = create_config();
+
+    let docs = create_docs(&config, false).unwrap();
+
+    assert_eq!(
+        docs,
+        "# Tasks\n\nGenerated by cargo-make, regenerate instead of editing manually.\n\n"
+    );
+}
+
+#[test]
+fn create_docs_with_tasks() {
+    let mut config = create_config();
+
+    let mut deploy = create_task(Some("Deploys the application."), Some("Release"));
+    deploy.dependencies = Some(vec!["build".into()]);
+    deploy.args_schema = Some(create_schema());
+    config.tasks.insert("deploy".to_string(), deploy);
+
+    config
+        .tasks
+        .insert("build".to_string(), create_task(Some("Builds."), None));
+
+    let mut legacy = create_task(None, Some("Release"));
+    legacy.deprecated = Some(DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: Some("deploy".to_string()),
+    }));
+    config.tasks.insert("legacy".to_string(), legacy);
+
+    let mut alias = create_task(None, None);
+    alias.alias = Some("build".to_string());
+    config.tasks.insert("b".to_string(), alias);
+
+    let mut private = create_task(None, None);
+    private.private = Some(true);
+    config.tasks.insert("internal".to_string(), private);
+
+    config.tasks.insert("core".to_string(), Task::new());
+
+    let docs = create_docs(&config, false).unwrap();
+
+    assert_eq!(
+        docs,
+        concat!(
+            "# Tasks\n\n",
+            "Generated by cargo-make, regenerate instead of editing manually.\n\n",
+            "## No Category\n\n",
+            "### build\n\n",
+            "Builds.\n\n",
+            "**Aliases:** `b`\n\n",
+            "**Example:**\n\n",
+            "```console\ncargo make build\n```\n\n",
+            "## Release\n\n",
+            "### deploy\n\n",
+            "Deploys the application.\n\n",
+            "**Dependencies:** `build`\n\n",
+            "**Arguments:**\n\n",
+            "| Argument | Type | Default | Env | Description |\n",
+            "| --- | --- | --- | --- | --- |\n",
+            "| `<environment>` | string | required | `CARGO_MAKE_TASK_ARG_ENVIRONMENT` | The target environment |\n",
+            "| `--dry-run` | bool |  | `CARGO_MAKE_TASK_ARG_DRY_RUN` | Only print \\| the changes |\n",
+            "| `--replicas <value>` | number | `2` | `REPLICAS` |  |\n",
+            "\n",
+            "**Example:**\n\n",
+            "```console\ncargo make deploy <environment>\n```\n\n",
+            "### legacy\n\n",
+            "No Description.\n\n",
+            "**Deprecated:** This task is deprecated (replaced by: `deploy`)\n\n",
+            "**Example:**\n\n",
+            "```console\ncargo make legacy\n```\n\n",
+        )
+    );
+}
+
+#[test]
+fn create_task_section_with_examples() {
+    let mut task = create_task(Some("Release."), None);
+    task.examples = Some(vec![
+        "cargo make release --minor".to_string(),
+        "cargo make release --major".to_string(),
+    ]);
+
+    let section = create_task_section("release", &task, None);
+
+    assert_eq!(
+        section,
+        concat!(
+            "### release\n\n",
+            "Release.\n\n",
+            "**Examples:**\n\n",
+            "```console\ncargo make release --minor\ncargo make release --major\n```\n\n",
+        )
+    );
+}
+
+#[test]
+fn create_docs_include_core() {
+    let mut config = create_config();
+    config.tasks.insert("core".to_string(), Task::new());
+
+    let docs = create_docs(&config, false).unwrap();
+    assert!(!docs.contains("### core"));
+
+    let docs = create_docs(&config, true).unwrap();
+    assert!(docs.contains("### core"));
+}
@@ -0,0 +1,563 @@
+//! # validate
+//!
+//! Validates the merged descriptor without invoking any task.
+//!
+
+#[cfg(test)]
+#[path = "validate_test.rs"]
+mod validate_test;
+
+use crate::diagnostics;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::logger;
+use crate::types::{
+    ConditionType, Config, DependencyIdentifier, EnvValue, RunTaskInfo, RunTaskName, TaskCondition,
+};
+use crate::validator;
+use crate::version;
+use indexmap::IndexMap;
+
+static PLATFORMS: [&str; 3] = ["linux", "windows", "mac"];
+static CHANNELS: [&str; 3] = ["stable", "beta", "nightly"];
+
+fn validate_task_reference(
+    config: &Config,
+    source: &str,
+    attribute: &str,
+    name: &str,
+    issues: &mut Vec<String>,
+) {
+    if !config.tasks.contains_key(name) && !execution_plan::is_forwarded_task_name(config, name) {
+        issues.push(format!(
+            "{}: {} references undefined task: {}",
+            source, attribute, name
+        ));
+    }
+}
+
+fn validate_private_reference(config: &Config, source: &str, name: &str, issues: &mut Vec<String>) {
+    let is_private = match config.tasks.get(name) {
+        Some(task) => task.private.unwrap_or(false),
+        None => false,
+    };
+
+    // the source may hold a platform suffix, for example: "build (linux)"
+    let source_name = source.split(' ').next().unwrap_or(source);
+
+    if is_private
+        && execution_plan::get_namespace(source_name) != execution_plan::get_namespace(name)
+    {
+        issues.push(format!(
+            "{}: dependency references private task: {} from a different namespace",
+            source, name
+        ));
+    }
+}
+
+fn validate_dependencies(
+    config: &Config,
+    source: &str,
+    dependencies: &Option<Vec<DependencyIdentifier>>,
+    issues: &mut Vec<String>,
+) {
+    if let Some(ref dependencies) = dependencies {
+        for dependency in dependencies {
+            // dependencies from other makefiles are resolved only when running the flow
+            let external = match dependency {
+                DependencyIdentifier::Definition(identifier) => identifier.path.is_some(),
+                DependencyIdentifier::Name(_) => false,
+            };
+
+            if !external {
+                validate_task_reference(config, source, "dependency", dependency.name(), issues);
+                validate_private_reference(config, source, dependency.name(), issues);
+            }
+        }
+    }
+}
+
+fn validate_run_task_name(
+    config: &Config,
+    source: &str,
+    name: &RunTaskName,
+    cleanup_task: &Option<String>,
+    issues: &mut Vec<String>,
+) {
+    match name {
+        RunTaskName::Single(ref value) => {
+            validate_task_reference(config, source, "run_task", value, issues)
+        }
+        RunTaskName::Multiple(ref values) => {
+            for value in values {
+                validate_task_reference(config, source, "run_task", value, issues);
+            }
+        }
+    }
+
+    if let Some(ref value) = cleanup_task {
+        validate_task_reference(config, source, "cleanup_task", value, issues);
+    }
+}
+
+fn validate_run_task(
+    config: &Config,
+    source: &str,
+    run_task: &Option<RunTaskInfo>,
+    issues: &mut Vec<String>,
+) {
+    match run_task {
+        Some(RunTaskInfo::Name(ref name)) => {
+            validate_task_reference(config, source, "run_task", name, issues)
+        }
+        Some(RunTaskInfo::Details(ref details)) => {
+            validate_run_task_name(config, source, &details.name, &details.cleanup_task, issues)
+        }
+        Some(RunTaskInfo::Routing(ref routes)) => {
+            for route in routes {
+                validate_run_task_name(config, source, &route.name, &route.cleanup_task, issues);
+                validate_condition(source, &route.condition, issues);
+            }
+        }
+        Some(RunTaskInfo::Pipe(ref pipe)) => {
+            for name in &pipe.pipe {
+                validate_task_reference(config, source, "run_task", name, issues);
+            }
+        }
+        None => (),
+    }
+}
+
+fn validate_alias(
+    config: &Config,
+    source: &str,
+    attribute: &str,
+    alias: &Option<String>,
+    issues: &mut Vec<String>,
+) {
+    if let Some(ref target) = alias {
+        validate_task_reference(config, source, attribute, target, issues);
+    }
+}
+
+fn find_overlap<'a>(first: &'a Option<Vec<String>>, second: &Option<Vec<String>>) -> Vec<&'a str> {
+    match (first, second) {
+        (Some(first_values), Some(second_values)) => first_values
+            .iter()
+            .filter(|value| second_values.contains(value))
+            .map(|value| value.as_str())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn find_map_overlap<'a>(
+    first: &'a Option<IndexMap<String, String>>,
+    second: &Option<IndexMap<String, String>>,
+) -> Vec<&'a str> {
+    match (first, second) {
+        (Some(first_values), Some(second_values)) => first_values
+            .iter()
+            .filter(|(key, value)| second_values.get(*key) == Some(*value))
+            .map(|(key, _)| key.as_str())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn validate_version(
+    source: &str,
+    attribute: &str,
+    value: &Option<String>,
+    issues: &mut Vec<String>,
+) {
+    if let Some(ref version_string) = value {
+        if version::parse(version_string, true).is_err() {
+            issues.push(format!(
+                "{}: condition rust_version.{} is not a valid version: {}",
+                source, attribute, version_string
+            ));
+        }
+    }
+}
+
+fn validate_rust_version_range(
+    source: &str,
+    min: &Option<String>,
+    max: &Option<String>,
+    equal: &Option<String>,
+    issues: &mut Vec<String>,
+) {
+    if let (Some(ref min_value), Some(ref max_value)) = (min, max) {
+        if version::is_newer(max_value, min_value, true, false) {
+            issues.push(format!(
+                "{}: condition rust_version.min ({}) is newer than rust_version.max ({})",
+                source, min_value, max_value
+            ));
+        }
+    }
+
+    if let Some(ref equal_value) = equal {
+        let below_min = match min {
+            Some(ref min_value) => version::is_newer(equal_value, min_value, true, false),
+            None => false,
+        };
+        let above_max = match max {
+            Some(ref max_value) => version::is_newer(max_value, equal_value, true, false),
+            None => false,
+        };
+
+        if below_min || above_max {
+            issues.push(format!(
+                "{}: condition rust_version.equal ({}) is outside of the min/max range",
+                source, equal_value
+            ));
+        }
+    }
+}
+
+fn validate_condition(source: &str, condition: &Option<TaskCondition>, issues: &mut Vec<String>) {
+    let condition = match condition {
+        Some(ref value) => value,
+        None => return,
+    };
+
+    if let Some(ref platforms) = condition.platforms {
+        for platform in platforms {
+            if !PLATFORMS.contains(&platform.as_str()) {
+                issues.push(format!(
+                    "{}: condition platforms contains unknown platform: {} (valid values: {})",
+                    source,
+                    platform,
+                    PLATFORMS.join(", ")
+                ));
+            }
+        }
+    }
+
+    if let Some(ref channels) = condition.channels {
+        for channel in channels {
+            if !CHANNELS.contains(&channel.as_str()) {
+                issues.push(format!(
+                    "{}: condition channels contains unknown channel: {} (valid values: {})",
+                    source,
+                    channel,
+                    CHANNELS.join(", ")
+                ));
+            }
+        }
+    }
+
+    if let Some(ref rust_version) = condition.rust_version {
+        let issues_count = issues.len();
+        validate_version(source, "min", &rust_version.min, issues);
+        validate_version(source, "max", &rust_version.max, issues);
+        validate_version(source, "equal", &rust_version.equal, issues);
+
+        if issues_count == issues.len() {
+            validate_rust_version_range(
+                source,
+                &rust_version.min,
+                &rust_version.max,
+                &rust_version.equal,
+                issues,
+            );
+        }
+    }
+
+    // in OR based conditions, opposite checks are valid alternatives
+    if condition.get_condition_type() == ConditionType::And {
+        let contradictions = vec![
+            (
+                "env_set",
+                "env_not_set",
+                find_overlap(&condition.env_set, &condition.env_not_set),
+            ),
+            (
+                "env_true",
+                "env_false",
+                find_overlap(&condition.env_true, &condition.env_false),
+            ),
+            (
+                "env",
+                "env_not",
+                find_map_overlap(&condition.env, &condition.env_not),
+            ),
+            (
+                "files_exist",
+                "files_not_exist",
+                find_overlap(&condition.files_exist, &condition.files_not_exist),
+            ),
+        ];
+
+        for (first, second, values) in contradictions {
+            for value in values {
+                issues.push(format!(
+                    "{}: condition can never be met, {} is defined in both {} and {}",
+                    source, value, first, second
+                ));
+            }
+        }
+    }
+}
+
+fn validate_env_value(
+    source: &str,
+    key: &str,
+    value: &EnvValue,
+    in_profile: bool,
+    issues: &mut Vec<String>,
+) {
+    match value {
+        EnvValue::Script(ref info) => {
+            if info.script.iter().all(|line| line.trim().is_empty()) {
+                issues.push(format!("{}: env {} script is empty", source, key));
+            }
+            validate_condition(source, &info.condition, issues);
+        }
+        EnvValue::PathGlob(ref info) => {
+            if let Err(error) = glob::Pattern::new(&info.glob) {
+                issues.push(format!(
+                    "{}: env {} has an invalid glob: {} ({})",
+                    source, key, info.glob, error
+                ));
+            }
+        }
+        EnvValue::Decode(ref info) => validate_condition(source, &info.condition, issues),
+        EnvValue::Conditional(ref info) => validate_condition(source, &info.condition, issues),
+        EnvValue::Profile(ref values) => {
+            if in_profile {
+                issues.push(format!(
+                    "{}: env profile {} is nested in another profile",
+                    source, key
+                ));
+            } else {
+                validate_env(source, values, true, issues);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn is_valid_env_name(key: &str) -> bool {
+    !key.is_empty() && !key.contains('=') && !key.contains(char::is_whitespace)
+}
+
+fn validate_env(
+    source: &str,
+    env: &IndexMap<String, EnvValue>,
+    in_profile: bool,
+    issues: &mut Vec<String>,
+) {
+    for (key, value) in env {
+        if !is_valid_env_name(key) {
+            issues.push(format!("{}: invalid env variable name: {:?}", source, key));
+        }
+
+        validate_env_value(source, key, value, in_profile, issues);
+    }
+}
+
+/// Validates the entire descriptor and returns all found issues (empty if valid)
+pub(crate) fn validate(config: &Config) -> Vec<String> {
+    let mut issues = vec![];
+
+    validate_env("[env]", &config.env, false, &mut issues);
+
+    let config_section = &config.config;
+    for (attribute, value) in [
+        ("init_task", &config_section.init_task),
+        ("end_task", &config_section.end_task),
+        ("on_error_task", &config_section.on_error_task),
+    ] {
+        if let Some(ref name) = value {
+            validate_task_reference(config, "[config]", attribute, name, &mut issues);
+        }
+    }
+    for (attribute, profile_tasks) in [
+        (
+            "profile_on_error_tasks",
+            &config_section.profile_on_error_tasks,
+        ),
+        (
+            "profile_default_tasks",
+            &config_section.profile_default_tasks,
+        ),
+    ] {
+        if let Some(ref profile_tasks) = profile_tasks {
+            for (profile_name, name) in profile_tasks {
+                let attribute = format!("{}.{}", attribute, profile_name);
+                validate_task_reference(config, "[config]", &attribute, name, &mut issues);
+            }
+        }
+    }
+
+    // tasks which are not overridden by any makefile
+    let core_task_names: Vec<&str> = config
+        .tasks
+        .iter()
+        .filter(|(_, task)| task.get_makefile_path().is_none())
+        .map(|(name, _)| name.as_str())
+        .collect();
+
+    // the core tasks are always validated with the default policy
+    let default_task_name_policy = validator::ValidationPolicy::default();
+    let task_name_policy = match config_section.task_name_validation {
+        Some(ref task_name_validation) => {
+            validator::ValidationPolicy::from_config(task_name_validation)
+        }
+        None => default_task_name_policy.clone(),
+    };
+
+    for (name, task) in &config.tasks {
+        let user_task = task.get_makefile_path().is_some();
+        let policy = if user_task {
+            &task_name_policy
+        } else {
+            &default_task_name_policy
+        };
+
+        if let Err(error) = validator::validate_task_name_with_policy(name, policy) {
+            issues.push(format!("{}: invalid task name, {}", name, error));
+        } else if user_task {
+            if let Err(error) = validator::validate_task_name_conflicts(name, &core_task_names) {
+                issues.push(format!("{}: invalid task name, {}", name, error));
+            }
+        }
+
+        if !task.is_valid() {
+            issues.push(format!(
+                "{}: only one of command, script, run_task or watch can be defined",
+                name
+            ));
+        }
+
+        validate_alias(config, name, "alias", &task.alias, &mut issues);
+        validate_alias(config, name, "linux_alias", &task.linux_alias, &mut issues);
+        validate_alias(
+            config,
+            name,
+            "windows_alias",
+            &task.windows_alias,
+            &mut issues,
+        );
+        validate_alias(config, name, "mac_alias", &task.mac_alias, &mut issues);
+
+        validate_dependencies(config, name, &task.dependencies, &mut issues);
+        validate_run_task(config, name, &task.run_task, &mut issues);
+        if let Some(ref on_error_task) = task.on_error_task {
+            validate_task_reference(config, name, "on_error_task", on_error_task, &mut issues);
+        }
+        validate_condition(name, &task.condition, &mut issues);
+
+        if let Some(ref env) = task.env {
+            validate_env(name, env, false, &mut issues);
+        }
+
+        if let Some(ref env_capture) = task.env_capture {
+            if !is_valid_env_name(env_capture) {
+                issues.push(format!(
+                    "{}: invalid env_capture variable name: {:?}",
+                    name, env_capture
+                ));
+            }
+            if task.run_task.is_some() {
+                issues.push(format!(
+                    "{}: env_capture is not supported for run_task, only for command and script",
+                    name
+                ));
+            }
+        }
+
+        if let Some(ref output) = task.output {
+            for (attribute, value) in [("stdout", &output.stdout), ("stderr", &output.stderr)] {
+                if value.as_ref().is_some_and(|value| value.trim().is_empty()) {
+                    issues.push(format!("{}: output.{} is empty", name, attribute));
+                }
+            }
+            if task.run_task.is_some() {
+                issues.push(format!(
+                    "{}: output is not supported for run_task, only for command and script",
+                    name
+                ));
+            }
+        }
+
+        if let Some(ref log_level) = task.log_level {
+            if logger::get_task_level(log_level).is_none() {
+                issues.push(format!(
+                    "{}: invalid log_level: {:?}, expected quiet, verbose, info, error, silent or off",
+                    name, log_level
+                ));
+            }
+        }
+
+        for (platform, override_task) in [
+            ("linux", &task.linux),
+            ("windows", &task.windows),
+            ("mac", &task.mac),
+            ("ci", &task.ci),
+        ] {
+            if let Some(ref override_task) = override_task {
+                let source = format!("{} ({})", name, platform);
+
+                validate_dependencies(config, &source, &override_task.dependencies, &mut issues);
+                validate_run_task(config, &source, &override_task.run_task, &mut issues);
+                if let Some(ref on_error_task) = override_task.on_error_task {
+                    validate_task_reference(
+                        config,
+                        &source,
+                        "on_error_task",
+                        on_error_task,
+                        &mut issues,
+                    );
+                }
+                validate_condition(&source, &override_task.condition, &mut issues);
+
+                if let Some(ref env) = override_task.env {
+                    validate_env(&source, env, false, &mut issues);
+                }
+            }
+        }
+    }
+
+    for cycle in execution_plan::find_dependency_cycles(config) {
+        issues.push(format!(
+            "{}: dependency cycle {}",
+            &cycle[0],
+            execution_plan::format_dependency_cycle(config, &cycle)
+        ));
+    }
+
+    issues
+}
+
+/// Validates the descriptor and fails if any issue was found
+pub(crate) fn run(config: &Config) -> Result<(), CargoMakeError> {
+    let issues = validate(config);
+
+    if issues.is_empty() {
+        info!("Descriptor is valid, {} tasks checked.", config.tasks.len());
+        Ok(())
+    } else {
+        let error = CargoMakeError::ValidationFailed(issues.len());
+        for issue in &issues {
+            warn!("{}", issue);
+
+            // issues are prefixed with the name of the invalid task
+            let task = issue
+                .split_once(": ")
+                .map(|(name, _)| name)
+                .filter(|name| config.tasks.contains_key(*name));
+            diagnostics::report(
+                &diagnostics::get_error_code(&error),
+                issue,
+                None,
+                None,
+                task,
+            );
+        }
+
+        Err(error)
+    }
+}
@@ -0,0 +1,556 @@
+use super::*;
+use crate::descriptor;
+use crate::types::{
+    ConfigSection, EnvValuePathGlob, EnvValueScript, PlatformOverrideTask, RunTaskDetails,
+    RunTaskPipe, RustVersionCondition, ScriptValue, Task, TaskIdentifier, TaskNameValidationConfig,
+    TaskOutputRedirection,
+};
+
+fn create_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+#[test]
+fn validate_empty() {
+    let config = create_config();
+
+    let issues = validate(&config);
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn validate_internal_descriptors() {
+    let config = descriptor::load_internal_descriptors(true, false, None, None).unwrap();
+
+    let issues = validate(&config);
+
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
+
+#[test]
+fn validate_invalid_task_name() {
+    let mut config = create_config();
+    config.tasks.insert("-bad".to_string(), Task::new());
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].starts_with("-bad: invalid task name"));
+}
+
+#[test]
+fn validate_reserved_task_names() {
+    let mut config = descriptor::load_internal_descriptors(true, false, None, None).unwrap();
+
+    let mut user_task = Task::new();
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./Makefile.toml".to_string()),
+    );
+    user_task.env = Some(env);
+
+    for name in ["build", "ci_flow", "print-steps", "CARGO_MAKE_TEST"] {
+        config.tasks.insert(name.to_string(), user_task.clone());
+    }
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 3, "{:#?}", issues);
+    assert!(issues[0].starts_with("ci_flow: invalid task name, "));
+    assert!(issues[1].starts_with("print-steps: invalid task name, "));
+    assert!(issues[2].starts_with("CARGO_MAKE_TEST: invalid task name, "));
+}
+
+#[test]
+fn validate_task_name_policy() {
+    let mut config = descriptor::load_internal_descriptors(true, false, None, None).unwrap();
+    config.config.task_name_validation = Some(TaskNameValidationConfig {
+        max_length: Some(10),
+        allowed_extra_characters: Some("-".to_string()),
+        ..TaskNameValidationConfig::default()
+    });
+
+    let mut user_task = Task::new();
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./Makefile.toml".to_string()),
+    );
+    user_task.env = Some(env);
+
+    for name in ["my-task", "my_task", "my-long-task"] {
+        config.tasks.insert(name.to_string(), user_task.clone());
+    }
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 2, "{:#?}", issues);
+    assert!(issues[0].starts_with("my_task: invalid task name, "));
+    assert!(issues[1].starts_with("my-long-task: invalid task name, "));
+}
+
+#[test]
+fn validate_multiple_actions() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.run_task = Some(RunTaskInfo::Name("other".to_string()));
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("other".to_string(), Task::new());
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].contains("only one of"));
+}
+
+#[test]
+fn validate_dependencies_missing() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec![
+        "exists".into(),
+        "missing".into(),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "external".to_string(),
+            path: Some("other".to_string()),
+            args: None,
+        }),
+    ]);
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("exists".to_string(), Task::new());
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec!["test: dependency references undefined task: missing".to_string()]
+    );
+}
+
+#[test]
+fn validate_dependencies_private() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["ns::helper".into()]);
+    config.tasks.insert("test".to_string(), task);
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["ns::helper".into()]);
+    config.tasks.insert("ns::test".to_string(), task);
+    let mut task = Task::new();
+    task.private = Some(true);
+    config.tasks.insert("ns::helper".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "test: dependency references private task: ns::helper from a different namespace"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn validate_run_task_missing() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(vec!["exists".to_string(), "missing".to_string()]),
+        fork: None,
+        parallel: None,
+        cleanup_task: Some("missing_cleanup".to_string()),
+        args: None,
+    }));
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("exists".to_string(), Task::new());
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "test: run_task references undefined task: missing".to_string(),
+            "test: cleanup_task references undefined task: missing_cleanup".to_string()
+        ]
+    );
+}
+
+#[test]
+fn validate_run_task_pipe_missing() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Pipe(RunTaskPipe {
+        pipe: vec!["exists".to_string(), "missing".to_string()],
+    }));
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("exists".to_string(), Task::new());
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec!["test: run_task references undefined task: missing".to_string()]
+    );
+}
+
+#[test]
+fn validate_alias_missing() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.alias = Some("missing".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec!["test: alias references undefined task: missing".to_string()]
+    );
+}
+
+#[test]
+fn validate_dependency_cycle() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["b".into()]);
+    config.tasks.insert("a".to_string(), task);
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["a".into()]);
+    config.tasks.insert("b".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "a: dependency cycle a -> b -> a (defined by: a -> b in internal, b -> a in internal)"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn validate_platform_override_dependencies_missing() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    let mut override_task: PlatformOverrideTask =
+        serde_json::from_str("{\"dependencies\": [\"missing\"]}").unwrap();
+    override_task.clear = Some(true);
+    task.windows = Some(override_task);
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec!["test (windows): dependency references undefined task: missing".to_string()]
+    );
+}
+
+#[test]
+fn validate_config_section_tasks_missing() {
+    let mut config = create_config();
+    config.config.init_task = Some("missing".to_string());
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec!["[config]: init_task references undefined task: missing".to_string()]
+    );
+}
+
+#[test]
+fn validate_profile_tasks_missing() {
+    let mut config = create_config();
+    let mut profile_on_error_tasks = IndexMap::new();
+    profile_on_error_tasks.insert("ci".to_string(), "missing1".to_string());
+    config.config.profile_on_error_tasks = Some(profile_on_error_tasks);
+    let mut profile_default_tasks = IndexMap::new();
+    profile_default_tasks.insert("ci".to_string(), "missing3".to_string());
+    config.config.profile_default_tasks = Some(profile_default_tasks);
+    let mut task = Task::new();
+    task.on_error_task = Some("missing2".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "[config]: profile_on_error_tasks.ci references undefined task: missing1".to_string(),
+            "[config]: profile_default_tasks.ci references undefined task: missing3".to_string(),
+            "test: on_error_task references undefined task: missing2".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn validate_condition_unknown_values() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        platforms: Some(vec!["linux".to_string(), "solaris".to_string()]),
+        channels: Some(vec!["stable".to_string(), "alpha".to_string()]),
+        ..Default::default()
+    });
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues[0].contains("unknown platform: solaris"));
+    assert!(issues[1].contains("unknown channel: alpha"));
+}
+
+#[test]
+fn validate_condition_rust_version_invalid_range() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        rust_version: Some(RustVersionCondition {
+            min: Some("1.70.0".to_string()),
+            max: Some("1.60.0".to_string()),
+            equal: Some("1.80.0".to_string()),
+        }),
+        ..Default::default()
+    });
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues[0].contains("is newer than rust_version.max"));
+    assert!(issues[1].contains("outside of the min/max range"));
+}
+
+#[test]
+fn validate_condition_rust_version_invalid_version() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        rust_version: Some(RustVersionCondition {
+            min: Some("abc".to_string()),
+            max: None,
+            equal: None,
+        }),
+        ..Default::default()
+    });
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].contains("rust_version.min is not a valid version"));
+}
+
+#[test]
+fn validate_condition_contradiction_and() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    let mut env = IndexMap::new();
+    env.insert("A".to_string(), "1".to_string());
+    let mut env_not = IndexMap::new();
+    env_not.insert("A".to_string(), "1".to_string());
+    task.condition = Some(TaskCondition {
+        env_set: Some(vec!["B".to_string()]),
+        env_not_set: Some(vec!["B".to_string()]),
+        env: Some(env),
+        env_not: Some(env_not),
+        ..Default::default()
+    });
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "test: condition can never be met, B is defined in both env_set and env_not_set"
+                .to_string(),
+            "test: condition can never be met, A is defined in both env and env_not".to_string()
+        ]
+    );
+}
+
+#[test]
+fn validate_condition_contradiction_or() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.condition = Some(TaskCondition {
+        condition_type: Some(ConditionType::Or),
+        env_true: Some(vec!["B".to_string()]),
+        env_false: Some(vec!["B".to_string()]),
+        ..Default::default()
+    });
+    config.tasks.insert("test".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn validate_env_errors() {
+    let mut config = create_config();
+    config
+        .env
+        .insert("BAD NAME".to_string(), EnvValue::Value("1".to_string()));
+    config.env.insert(
+        "SCRIPT".to_string(),
+        EnvValue::Script(EnvValueScript {
+            script: vec!["".to_string()],
+            multi_line: None,
+            condition: None,
+            depends_on: None,
+        }),
+    );
+    config.env.insert(
+        "GLOB".to_string(),
+        EnvValue::PathGlob(EnvValuePathGlob {
+            glob: "a/[".to_string(),
+            include_files: None,
+            include_dirs: None,
+            ignore_type: None,
+        }),
+    );
+    let mut nested = IndexMap::new();
+    nested.insert("inner".to_string(), EnvValue::Profile(IndexMap::new()));
+    config
+        .env
+        .insert("profile".to_string(), EnvValue::Profile(nested));
+
+    let issues = validate(&config);
+
+    assert_eq!(issues.len(), 4);
+    assert!(issues[0].starts_with("[env]: invalid env variable name"));
+    assert_eq!(issues[1], "[env]: env SCRIPT script is empty");
+    assert!(issues[2].starts_with("[env]: env GLOB has an invalid glob"));
+    assert_eq!(
+        issues[3],
+        "[env]: env profile inner is nested in another profile"
+    );
+}
+
+#[test]
+fn validate_env_capture_errors() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::SingleLine("echo 1".to_string()));
+    task.env_capture = Some("VALID_NAME".to_string());
+    config.tasks.insert("valid".to_string(), task);
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.env_capture = Some("BAD NAME".to_string());
+    config.tasks.insert("bad_name".to_string(), task);
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Name("valid".to_string()));
+    task.env_capture = Some("RESULT".to_string());
+    config.tasks.insert("sub_task".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "bad_name: invalid env_capture variable name: \"BAD NAME\"",
+            "sub_task: env_capture is not supported for run_task, only for command and script"
+        ]
+    );
+}
+
+#[test]
+fn validate_output_errors() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.output = Some(TaskOutputRedirection {
+        stdout: Some("target/logs/build.log".to_string()),
+        stderr: Some("inherit".to_string()),
+        append: None,
+    });
+    config.tasks.insert("valid".to_string(), task);
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.output = Some(TaskOutputRedirection {
+        stdout: None,
+        stderr: Some(" ".to_string()),
+        append: None,
+    });
+    config.tasks.insert("empty".to_string(), task);
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Name("valid".to_string()));
+    task.output = Some(TaskOutputRedirection {
+        stdout: Some("null".to_string()),
+        stderr: None,
+        append: None,
+    });
+    config.tasks.insert("sub_task".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "empty: output.stderr is empty",
+            "sub_task: output is not supported for run_task, only for command and script"
+        ]
+    );
+}
+
+#[test]
+fn validate_log_level_invalid() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.log_level = Some("quiet".to_string());
+    config.tasks.insert("valid".to_string(), task);
+    let mut task = Task::new();
+    task.log_level = Some("loud".to_string());
+    config.tasks.insert("invalid".to_string(), task);
+
+    let issues = validate(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "invalid: invalid log_level: \"loud\", expected quiet, verbose, info, error, silent or off"
+        ]
+    );
+}
+
+#[test]
+fn run_valid() {
+    let mut config = create_config();
+    config.tasks.insert("test".to_string(), Task::new());
+
+    run(&config).unwrap();
+}
+
+#[test]
+fn run_invalid() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["missing".into()]);
+    config.tasks.insert("test".to_string(), task);
+
+    let error = run(&config).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::ValidationFailed(1)));
+}
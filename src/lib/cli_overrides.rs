@@ -0,0 +1,147 @@
+//! # cli_overrides
+//!
+//! Applies the task attribute overrides provided via the --set cli argument
+//! (for example: tasks.build.args=["--release"]) on the loaded descriptor.
+//!
+
+#[cfg(test)]
+#[path = "cli_overrides_test.rs"]
+mod cli_overrides_test;
+
+use crate::error::CargoMakeError;
+use crate::types::{Config, Task};
+use toml::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+struct TaskOverride {
+    /// The task name
+    task: String,
+    /// The attribute path within the task (for example: env.RUST_LOG)
+    path: Vec<String>,
+    /// The new attribute value
+    value: Value,
+}
+
+/// Parses the value as a TOML value, values which are not valid TOML are used as plain strings
+/// so simple values (for example: tasks.build.command=cargo) do not require quoting
+fn parse_value(value: &str) -> Value {
+    match toml::from_str::<toml::Table>(&format!("value = {}", value)) {
+        Ok(mut table) => table
+            .remove("value")
+            .unwrap_or(Value::String(value.to_string())),
+        Err(_) => Value::String(value.to_string()),
+    }
+}
+
+fn parse_override(value: &str) -> Result<TaskOverride, CargoMakeError> {
+    let invalid = || {
+        CargoMakeError::InvalidArgument(format!(
+            "Invalid override: {}, expected format: tasks.<task>.<attribute>=<value>",
+            value
+        ))
+    };
+
+    let (key, attribute_value) = value.split_once('=').ok_or_else(invalid)?;
+    let mut path: Vec<String> = key.trim().split('.').map(|part| part.to_string()).collect();
+
+    if path.len() < 3 || path[0] != "tasks" || path.iter().any(|part| part.is_empty()) {
+        return Err(invalid());
+    }
+
+    path.remove(0);
+    let task = path.remove(0);
+
+    Ok(TaskOverride {
+        task,
+        path,
+        value: parse_value(attribute_value.trim()),
+    })
+}
+
+fn set_value(table: &mut toml::Table, path: &[String], value: Value) -> bool {
+    match path {
+        [] => false,
+        [key] => {
+            table.insert(key.to_string(), value);
+            true
+        }
+        [key, sub_path @ ..] => {
+            let entry = table
+                .entry(key.to_string())
+                .or_insert_with(|| Value::Table(toml::Table::new()));
+
+            match entry {
+                Value::Table(ref mut sub_table) => set_value(sub_table, sub_path, value),
+                _ => false,
+            }
+        }
+    }
+}
+
+fn has_value(table: &toml::Table, path: &[String]) -> bool {
+    match path {
+        [] => false,
+        [key] => table.contains_key(key),
+        [key, sub_path @ ..] => match table.get(key) {
+            Some(Value::Table(ref sub_table)) => has_value(sub_table, sub_path),
+            _ => false,
+        },
+    }
+}
+
+fn to_table(task: &Task) -> Result<toml::Table, String> {
+    match Value::try_from(task) {
+        Ok(Value::Table(table)) => Ok(table),
+        Ok(_) => Err("task is not a table".to_string()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+fn apply_override(task: &Task, task_override: &TaskOverride) -> Result<Task, CargoMakeError> {
+    let attribute = format!(
+        "tasks.{}.{}",
+        &task_override.task,
+        task_override.path.join(".")
+    );
+    let invalid = |reason: String| {
+        CargoMakeError::InvalidArgument(format!("Invalid override: {}, {}", &attribute, reason))
+    };
+
+    let mut table = to_table(task).map_err(invalid)?;
+    if !set_value(&mut table, &task_override.path, task_override.value.clone()) {
+        return Err(invalid("attribute is not a table".to_string()));
+    }
+
+    let updated_task: Task = Value::Table(table)
+        .try_into()
+        .map_err(|error: toml::de::Error| invalid(error.message().to_string()))?;
+
+    // unknown attributes are dropped while deserializing the task
+    let updated_table = to_table(&updated_task).map_err(invalid)?;
+    if has_value(&updated_table, &task_override.path) {
+        Ok(updated_task)
+    } else {
+        Err(invalid("unknown task attribute".to_string()))
+    }
+}
+
+/// Applies the overrides (in order) on the descriptor tasks
+pub(crate) fn apply(config: &mut Config, overrides: &[String]) -> Result<(), CargoMakeError> {
+    for value in overrides {
+        let task_override = parse_override(value)?;
+
+        let task = config.tasks.get(&task_override.task).ok_or_else(|| {
+            CargoMakeError::NotFound(format!(
+                "Task {} provided in override: {} not found",
+                &task_override.task, value
+            ))
+        })?;
+
+        let updated_task = apply_override(task, &task_override)?;
+        debug!("Task: {} overridden by: {}", &task_override.task, value);
+
+        config.tasks.insert(task_override.task, updated_task);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::types::{ConfigSection, EnvValue};
+use indexmap::IndexMap;
+
+fn create_config() -> Config {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["build".to_string()]);
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), task);
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+#[test]
+fn parse_value_array() {
+    let value = parse_value("[\"--release\", \"--locked\"]");
+
+    assert_eq!(
+        value,
+        Value::Array(vec![
+            Value::String("--release".to_string()),
+            Value::String("--locked".to_string())
+        ])
+    );
+}
+
+#[test]
+fn parse_value_boolean() {
+    assert_eq!(parse_value("true"), Value::Boolean(true));
+}
+
+#[test]
+fn parse_value_unquoted_string() {
+    assert_eq!(parse_value("nightly"), Value::String("nightly".to_string()));
+}
+
+#[test]
+fn parse_override_valid() {
+    let task_override = parse_override("tasks.build.env.RUST_LOG = debug").unwrap();
+
+    assert_eq!(
+        task_override,
+        TaskOverride {
+            task: "build".to_string(),
+            path: vec!["env".to_string(), "RUST_LOG".to_string()],
+            value: Value::String("debug".to_string()),
+        }
+    );
+}
+
+#[test]
+fn parse_override_missing_value() {
+    let result = parse_override("tasks.build.args");
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
+
+#[test]
+fn parse_override_not_task() {
+    let result = parse_override("config.skip_core_tasks=true");
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
+
+#[test]
+fn parse_override_missing_attribute() {
+    let result = parse_override("tasks.build=true");
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
+
+#[test]
+fn apply_args() {
+    let mut config = create_config();
+
+    apply(
+        &mut config,
+        &["tasks.build.args=[\"build\", \"--release\", \"--locked\"]".to_string()],
+    )
+    .unwrap();
+
+    let task = config.tasks.get("build").unwrap();
+    assert_eq!(task.command.clone().unwrap(), "cargo");
+    assert_eq!(
+        task.args.clone().unwrap(),
+        vec![
+            "build".to_string(),
+            "--release".to_string(),
+            "--locked".to_string()
+        ]
+    );
+}
+
+#[test]
+fn apply_multiple_in_order() {
+    let mut config = create_config();
+
+    apply(
+        &mut config,
+        &[
+            "tasks.build.toolchain=beta".to_string(),
+            "tasks.build.toolchain=nightly".to_string(),
+            "tasks.build.env.RUST_LOG=debug".to_string(),
+        ],
+    )
+    .unwrap();
+
+    let task = config.tasks.get("build").unwrap();
+    assert_eq!(task.toolchain.clone().unwrap().to_string(), "nightly");
+    match task.env.clone().unwrap().get("RUST_LOG").unwrap() {
+        EnvValue::Value(ref value) => assert_eq!(value, "debug"),
+        _ => panic!("invalid env value"),
+    };
+}
+
+#[test]
+fn apply_task_not_found() {
+    let mut config = create_config();
+
+    let result = apply(&mut config, &["tasks.test.args=[]".to_string()]);
+
+    assert!(matches!(result, Err(CargoMakeError::NotFound(_))));
+}
+
+#[test]
+fn apply_unknown_attribute() {
+    let mut config = create_config();
+
+    let result = apply(&mut config, &["tasks.build.argz=[]".to_string()]);
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
+
+#[test]
+fn apply_invalid_type() {
+    let mut config = create_config();
+
+    let result = apply(&mut config, &["tasks.build.args=true".to_string()]);
+
+    assert!(matches!(result, Err(CargoMakeError::InvalidArgument(_))));
+}
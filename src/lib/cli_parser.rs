@@ -10,8 +10,10 @@ mod cli_parser_test;
 use crate::completion::generate_completions;
 
 use crate::cli::{
-    AUTHOR, DEFAULT_LOG_LEVEL, DEFAULT_OUTPUT_FORMAT, DEFAULT_TASK_NAME, DESCRIPTION, VERSION,
+    AUTHOR, DEFAULT_ERROR_FORMAT, DEFAULT_LOG_LEVEL, DEFAULT_OUTPUT_FORMAT, DEFAULT_TASK_NAME,
+    DESCRIPTION, VERSION,
 };
+use crate::logger;
 use crate::profile;
 use crate::types::{CliArgs, GlobalConfig};
 use cliparser::types::{
@@ -20,6 +22,9 @@ use cliparser::types::{
 };
 
 use crate::error::CargoMakeError;
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 
 fn get_args(
     cli_parsed: &CliParsed,
@@ -39,9 +44,17 @@ fn get_args(
 
     cli_args.env = to_owned_vec(cli_parsed.argument_values.get("env"));
 
-    cli_args.build_file = match cli_parsed.get_first_value("makefile") {
-        Some(value) => Some(value),
-        None => None,
+    let mut build_files =
+        to_owned_vec(cli_parsed.argument_values.get("makefile")).unwrap_or(vec![]);
+    cli_args.build_file = if build_files.is_empty() {
+        None
+    } else {
+        Some(build_files.remove(0))
+    };
+    cli_args.additional_build_files = if build_files.is_empty() {
+        None
+    } else {
+        Some(build_files)
     };
 
     cli_args.completion = match cli_parsed.get_first_value("completion") {
@@ -74,21 +87,30 @@ fn get_args(
     };
     cli_args.disable_color = cli_parsed.arguments.contains("no-color")
         || envmnt::is("CARGO_MAKE_DISABLE_COLOR")
-        || default_disable_color;
+        || (!logger::is_color_forced_by_env()
+            && (logger::is_color_disabled_by_env() || default_disable_color));
 
     cli_args.print_time_summary = cli_parsed.arguments.contains("time-summary")
         || envmnt::is("CARGO_MAKE_PRINT_TIME_SUMMARY");
+    cli_args.print_status_summary = cli_parsed.arguments.contains("status-summary")
+        || envmnt::is("CARGO_MAKE_PRINT_STATUS_SUMMARY");
 
-    cli_args.env_file = match cli_parsed.get_first_value("envfile") {
-        Some(value) => Some(value.to_string()),
-        None => None,
-    };
+    cli_args.env_file = to_owned_vec(cli_parsed.argument_values.get("envfile"));
 
     cli_args.output_format = cli_parsed
         .get_first_value("output-format")
         .unwrap_or(DEFAULT_OUTPUT_FORMAT.to_string())
         .to_string();
 
+    // forked cargo-make processes report errors in the format of the parent process
+    cli_args.error_format = cli_parsed
+        .get_first_value("error-format")
+        .unwrap_or(envmnt::get_or(
+            "CARGO_MAKE_ERROR_FORMAT",
+            DEFAULT_ERROR_FORMAT,
+        ))
+        .to_string();
+
     cli_args.list_category_steps = match cli_parsed.get_first_value("list-category-steps") {
         Some(value) => Some(value.to_string()),
         None => None,
@@ -99,19 +121,50 @@ fn get_args(
         None => None,
     };
 
-    let profile_name = cli_parsed
-        .get_first_value("profile")
-        .unwrap_or_else(profile::default_profile);
-    cli_args.profile = Some(profile_name.to_string());
+    cli_args.export_env = cli_parsed
+        .get_first_value("export-env")
+        .map(|value| value.to_string());
+
+    cli_args.query = cli_parsed
+        .get_first_value("query")
+        .map(|value| value.to_string());
+    // the query responses are written to stdout, so only errors are logged
+    if cli_args.query.is_some() && cli_args.log_level != "off" {
+        cli_args.log_level = "error".to_string();
+    }
+
+    let mut profile_names =
+        to_owned_vec(cli_parsed.argument_values.get("profile")).unwrap_or(vec![]);
+    cli_args.profile = if profile_names.is_empty() {
+        Some(profile::default_profile())
+    } else {
+        Some(profile_names.remove(0))
+    };
+    cli_args.additional_profiles = if profile_names.is_empty() {
+        None
+    } else {
+        Some(profile_names)
+    };
 
     cli_args.disable_check_for_updates = cli_parsed.arguments.contains("disable-check-for-updates");
     cli_args.experimental = cli_parsed.arguments.contains("experimental");
+    cli_args.strict = cli_parsed.arguments.contains("strict");
+    cli_args.assume_yes = cli_parsed.arguments.contains("yes");
+    cli_args.progress = cli_parsed.arguments.contains("progress");
+    cli_args.ui = cli_parsed.arguments.contains("ui");
+    cli_args.perf = cli_parsed.arguments.contains("perf");
+    cli_args.force = cli_parsed.arguments.contains("force");
+    cli_args.wait_for_run_lock = cli_parsed.arguments.contains("wait");
+    cli_args.disable_run_lock = cli_parsed.arguments.contains("no-lock");
+    cli_args.daemon = cli_parsed.arguments.contains("daemon");
     cli_args.print_only = cli_parsed.arguments.contains("print-steps");
+    cli_args.dry_run = cli_parsed.arguments.contains("dry-run");
     cli_args.disable_workspace = cli_parsed.arguments.contains("no-workspace");
     cli_args.disable_on_error = cli_parsed.arguments.contains("no-on-error");
     cli_args.allow_private = cli_parsed.arguments.contains("allow-private");
     cli_args.skip_init_end_tasks = cli_parsed.arguments.contains("skip-init-end-tasks");
     cli_args.list_all_steps = cli_parsed.arguments.contains("list-steps");
+    cli_args.list_category_tree = cli_parsed.arguments.contains("list-category-tree");
     cli_args.diff_execution_plan = cli_parsed.arguments.contains("diff-steps");
     cli_args.hide_uninteresting = cli_parsed.arguments.contains("hide-uninteresting");
 
@@ -120,13 +173,20 @@ fn get_args(
         None => None,
     };
 
+    cli_args.tags = to_owned_vec(cli_parsed.argument_values.get("tag"));
+    cli_args.skip_tags = to_owned_vec(cli_parsed.argument_values.get("skip-tag"));
+    cli_args.overrides = to_owned_vec(cli_parsed.argument_values.get("set"));
+
+    cli_args.from_step = cli_parsed.get_first_value("from");
+    cli_args.skip_steps = to_owned_vec(cli_parsed.argument_values.get("skip-step"));
+
     let default_task_name = match global_config.default_task_name {
         Some(ref value) => value.to_string(),
         None => DEFAULT_TASK_NAME.to_string(),
     };
     let task = cli_parsed
         .get_first_value("task")
-        .unwrap_or(default_task_name);
+        .unwrap_or(default_task_name.clone());
     let task_cmd = to_owned_vec(cli_parsed.argument_values.get("TASK_CMD")).unwrap_or(vec![]);
     let task_cmd_slice = task_cmd.as_slice();
     let (task, arguments) = match task_cmd_slice {
@@ -136,6 +196,7 @@ fn get_args(
             (task_name.to_string(), Some(args_strings))
         }
     };
+    cli_args.default_task = task_cmd.is_empty() && task == default_task_name;
     cli_args.task = task;
     cli_args.arguments = arguments;
 
@@ -170,6 +231,18 @@ pub fn create_cli(global_config: &GlobalConfig, mut spec: CliSpec, default_meta:
     add_arguments(spec, default_task_name, default_log_level)
 }
 
+/// Returns the names of all the CLI flags (long keys without the leading --)
+pub(crate) fn get_flag_names() -> Vec<String> {
+    let spec = create_cli(&GlobalConfig::new(), CliSpec::new(), false);
+
+    spec.arguments
+        .iter()
+        .flat_map(|argument| argument.key.iter())
+        .filter_map(|key| key.strip_prefix("--"))
+        .map(|name| name.to_string())
+        .collect()
+}
+
 fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str) -> CliSpec {
     spec
         .add_argument(Argument {
@@ -191,11 +264,11 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
         .add_argument(Argument {
             name: "makefile".to_string(),
             key: vec!["--makefile".to_string()],
-            argument_occurrence: ArgumentOccurrence::Single,
+            argument_occurrence: ArgumentOccurrence::Multiple,
             value_type: ArgumentValueType::Single,
             default_value: None,
             help: Some(ArgumentHelp::TextAndParam(
-                "The optional toml file containing the tasks definitions".to_string(),
+                "The optional toml file containing the tasks definitions (- to read it from stdin, can be provided multiple times)".to_string(),
                 "FILE".to_string(),
             )),
         })
@@ -213,11 +286,12 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
         .add_argument(Argument {
             name: "profile".to_string(),
             key: vec!["--profile".to_string(), "-p".to_string()],
-            argument_occurrence: ArgumentOccurrence::Single,
+            argument_occurrence: ArgumentOccurrence::Multiple,
             value_type: ArgumentValueType::Single,
             default_value: Some(profile::default_profile()),
             help: Some(ArgumentHelp::TextAndParam(
-                "The profile name (will be converted to lower case)".to_string(),
+                "The profile name (will be converted to lower case, can be provided multiple times)"
+                    .to_string(),
                 "PROFILE".to_string(),
             )),
         })
@@ -270,7 +344,8 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
             value_type: ArgumentValueType::None,
             default_value: None,
             help: Some(ArgumentHelp::Text(
-                "Allow invocation of private tasks".to_string(),
+                "Allow invocation of private tasks (directly or from a different namespace)"
+                    .to_string(),
             )),
         })
         .add_argument(Argument {
@@ -294,14 +369,70 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "SKIP_TASK_PATTERNS".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "tag".to_string(),
+            key: vec!["--tag".to_string()],
+            argument_occurrence: ArgumentOccurrence::Multiple,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Run all tasks with the provided tag (in dependency order)".to_string(),
+                "TAG".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "skip-tag".to_string(),
+            key: vec!["--skip-tag".to_string()],
+            argument_occurrence: ArgumentOccurrence::Multiple,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Skip all tasks with the provided tag".to_string(),
+                "TAG".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "set".to_string(),
+            key: vec!["--set".to_string()],
+            argument_occurrence: ArgumentOccurrence::Multiple,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Override a task attribute after loading the makefiles (example: tasks.build.args=[\"--release\"])".to_string(),
+                "ATTRIBUTE=VALUE".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "from".to_string(),
+            key: vec!["--from".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Start the flow at the provided step (skipping all previous steps)".to_string(),
+                "STEP".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "skip-step".to_string(),
+            key: vec!["--skip-step".to_string()],
+            argument_occurrence: ArgumentOccurrence::Multiple,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Skip the provided flow step".to_string(),
+                "STEP".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "envfile".to_string(),
             key: vec!["--env-file".to_string()],
-            argument_occurrence: ArgumentOccurrence::Single,
+            argument_occurrence: ArgumentOccurrence::Multiple,
             value_type: ArgumentValueType::Single,
             default_value: None,
             help: Some(ArgumentHelp::TextAndParam(
-                "Set environment variables from provided file".to_string(),
+                "Set environment variables from provided file (can be provided multiple times)"
+                    .to_string(),
                 "FILE".to_string(),
             )),
         })
@@ -377,6 +508,108 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "Print task level time summary at end of flow".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "status-summary".to_string(),
+            key: vec!["--status-summary".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Print the status and duration of each step at end of flow".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "progress".to_string(),
+            key: vec!["--progress".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Show a progress line with the running task name and elapsed time (TTY only)"
+                    .to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "ui".to_string(),
+            key: vec!["--ui".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Show a live dashboard while parallel tasks are running (TTY only)".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "force".to_string(),
+            key: vec!["--force".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Run the tasks even if they ran within their minimal interval".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "wait".to_string(),
+            key: vec!["--wait".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Wait for the running flow to finish in case the project run lock is held"
+                    .to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "no-lock".to_string(),
+            key: vec!["--no-lock".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Disable the project run lock even if defined in config sections".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "daemon".to_string(),
+            key: vec!["--daemon".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Run a daemon serving the loaded makefiles to later invocations".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "perf".to_string(),
+            key: vec!["--perf".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Print the time spent in the internal cargo-make phases at end of flow".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "strict".to_string(),
+            key: vec!["--strict".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Fail when the makefiles contain unknown keys".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "yes".to_string(),
+            key: vec!["--yes".to_string(), "-y".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Automatically confirm the task confirmation prompts".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "experimental".to_string(),
             key: vec!["--experimental".to_string()],
@@ -404,10 +637,21 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
             value_type: ArgumentValueType::Single,
             default_value: None,
             help: Some(ArgumentHelp::TextAndParam(
-                "The print/list steps format (some operations do not support all formats) (default, short-description, markdown, markdown-single-page, markdown-sub-section, autocomplete)".to_string(),
+                "The print/list steps format (some operations do not support all formats) (default, short-description, markdown, markdown-single-page, markdown-sub-section, autocomplete, json, yaml)".to_string(),
                 "OUTPUT FORMAT".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "error-format".to_string(),
+            key: vec!["--error-format".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "The format of the reported errors (human, json)".to_string(),
+                "ERROR FORMAT".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "output-file".to_string(),
             key: vec!["--output-file".to_string()],
@@ -419,6 +663,28 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "OUTPUT_FILE".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "export-env".to_string(),
+            key: vec!["--export-env".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Export the resolved task env to a dotenv file (JSON for .json files) without invoking the task".to_string(),
+                "FILE".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "query".to_string(),
+            key: vec!["--query".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Answer the JSON query request (or the requests read from stdin if -) with JSON responses".to_string(),
+                "REQUEST".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "hide-uninteresting".to_string(),
             key: vec!["--hide-uninteresting".to_string()],
@@ -439,6 +705,16 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "Only prints the steps of the build in the order they will be invoked but without invoking them".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "dry-run".to_string(),
+            key: vec!["--dry-run".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Prints the resolved commands and scripts of the flow but without invoking them".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "list-steps".to_string(),
             key: vec!["--list-all-steps".to_string()],
@@ -460,6 +736,16 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "CATEGORY".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "list-category-tree".to_string(),
+            key: vec!["--list-category-tree".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Lists all known steps as a tree grouped by category and namespace".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "diff-steps".to_string(),
             key: vec!["--diff-steps".to_string()],
@@ -519,16 +805,62 @@ pub fn parse_args(
     }
 }
 
+/// Returns true if the file starts with a shebang line (the makefile is executed directly)
+fn is_shebang_makefile(file: &str) -> bool {
+    match File::open(file) {
+        Ok(file) => {
+            let mut first_line = String::new();
+            match BufReader::new(file).read_line(&mut first_line) {
+                Ok(_) => first_line.starts_with("#!"),
+                Err(_) => false,
+            }
+        }
+        Err(_) => false,
+    }
+}
+
+/// In case cargo-make is the interpreter of a makefile (#!/usr/bin/env cargo-make), the OS invokes
+/// it with the makefile path as the first argument followed by the user arguments.<br>
+/// This returns the equivalent command line which loads that makefile and invokes the task
+/// provided after it.
+fn get_shebang_command_line(
+    command_line: &[String],
+    command_name: &str,
+    sub_command: bool,
+) -> Option<Vec<String>> {
+    let makefile = command_line.get(1)?;
+
+    if (sub_command && makefile == command_name) || !is_shebang_makefile(makefile) {
+        return None;
+    }
+
+    let mut shebang_command_line = vec![command_line[0].clone()];
+    if sub_command {
+        shebang_command_line.push(command_name.to_string());
+    }
+    shebang_command_line.push("--makefile".to_string());
+    shebang_command_line.push(makefile.to_string());
+    shebang_command_line.extend_from_slice(&command_line[2..]);
+
+    Some(shebang_command_line)
+}
+
 pub fn parse(
     global_config: &GlobalConfig,
     command_name: &str,
     sub_command: bool,
 ) -> Result<CliArgs, CargoMakeError> {
+    let command_line: Vec<String> = env::args().collect();
+    let shebang_command_line = get_shebang_command_line(&command_line, command_name, sub_command);
+    let args = shebang_command_line
+        .as_ref()
+        .map(|command_line| command_line.iter().map(|arg| arg.as_str()).collect());
+
     parse_args(
         global_config,
         command_name,
         sub_command,
-        None,
+        args,
         create_cli(&global_config, CliSpec::new(), true),
     )
 }
@@ -32,8 +32,13 @@ fn assert_cli_args(cli_args_ref1: &CliArgs, cli_args_ref2: &CliArgs) {
 
     assert_eq!(cli_args1.command, cli_args2.command);
     assert_eq!(cli_args1.build_file, cli_args2.build_file);
+    assert_eq!(
+        cli_args1.additional_build_files,
+        cli_args2.additional_build_files
+    );
     assert_eq!(cli_args1.task, cli_args2.task);
     assert_eq!(cli_args1.profile.unwrap(), cli_args2.profile.unwrap());
+    assert_eq!(cli_args1.additional_profiles, cli_args2.additional_profiles);
     assert_eq!(cli_args1.log_level, cli_args2.log_level);
     assert_eq!(cli_args1.disable_color, cli_args2.disable_color);
     assert_eq!(cli_args1.cwd, cli_args2.cwd);
@@ -44,18 +49,39 @@ fn assert_cli_args(cli_args_ref1: &CliArgs, cli_args_ref2: &CliArgs) {
     assert_eq!(cli_args1.allow_private, cli_args2.allow_private);
     assert_eq!(cli_args1.skip_init_end_tasks, cli_args2.skip_init_end_tasks);
     assert_eq!(cli_args1.skip_tasks_pattern, cli_args2.skip_tasks_pattern);
+    assert_eq!(cli_args1.tags, cli_args2.tags);
+    assert_eq!(cli_args1.skip_tags, cli_args2.skip_tags);
+    assert_eq!(cli_args1.from_step, cli_args2.from_step);
+    assert_eq!(cli_args1.skip_steps, cli_args2.skip_steps);
+    assert_eq!(cli_args1.assume_yes, cli_args2.assume_yes);
     assert_eq!(
         cli_args1.disable_check_for_updates,
         cli_args2.disable_check_for_updates
     );
     assert_eq!(cli_args1.print_only, cli_args2.print_only);
+    assert_eq!(cli_args1.dry_run, cli_args2.dry_run);
     assert_eq!(cli_args1.list_all_steps, cli_args2.list_all_steps);
     assert_eq!(cli_args1.diff_execution_plan, cli_args2.diff_execution_plan);
     assert_eq!(cli_args1.experimental, cli_args2.experimental);
     assert_eq!(cli_args1.arguments, cli_args2.arguments);
     assert_eq!(cli_args1.output_format, cli_args2.output_format);
+    assert_eq!(cli_args1.error_format, cli_args2.error_format);
     assert_eq!(cli_args1.output_file, cli_args2.output_file);
+    assert_eq!(cli_args1.export_env, cli_args2.export_env);
+    assert_eq!(cli_args1.query, cli_args2.query);
     assert_eq!(cli_args1.print_time_summary, cli_args2.print_time_summary);
+    assert_eq!(
+        cli_args1.print_status_summary,
+        cli_args2.print_status_summary
+    );
+    assert_eq!(cli_args1.progress, cli_args2.progress);
+    assert_eq!(cli_args1.ui, cli_args2.ui);
+    assert_eq!(cli_args1.perf, cli_args2.perf);
+    assert_eq!(cli_args1.force, cli_args2.force);
+    assert_eq!(cli_args1.wait_for_run_lock, cli_args2.wait_for_run_lock);
+    assert_eq!(cli_args1.disable_run_lock, cli_args2.disable_run_lock);
+    assert_eq!(cli_args1.daemon, cli_args2.daemon);
+    assert_eq!(cli_args1.strict, cli_args2.strict);
 }
 
 #[test]
@@ -73,6 +99,7 @@ fn parse_args_makers() {
     let expected = default_parsed_cli_args();
 
     assert_cli_args(&cli_args, &expected);
+    assert!(cli_args.default_task);
 }
 
 #[test]
@@ -158,6 +185,30 @@ fn parse_args_makefile() {
     assert_cli_args(&cli_args.unwrap(), &expected);
 }
 
+#[test]
+fn parse_args_makefile_multiple() {
+    let cli_args = default_parse_cli_args(vec![
+        "--makefile",
+        "./base.toml",
+        "--makefile",
+        "./overrides.toml",
+        "--makefile=./ci.toml",
+        "taskname",
+    ])
+    .unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.build_file = Some("./base.toml".to_string());
+    expected.additional_build_files = Some(vec![
+        "./overrides.toml".to_string(),
+        "./ci.toml".to_string(),
+    ]);
+    expected.task = "taskname".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_task() {
     let mut cli_args = default_parse_cli_args(vec!["--task", "sometask"]).unwrap();
@@ -166,13 +217,16 @@ fn parse_args_task() {
     expected.task = "sometask".to_string();
 
     assert_cli_args(&cli_args, &expected);
+    assert!(!cli_args.default_task);
 
     cli_args = default_parse_cli_args(vec!["-t", "sometask"]).unwrap();
     assert_cli_args(&cli_args, &expected);
+    assert!(!cli_args.default_task);
 
     cli_args = default_parse_cli_args(vec!["sometask"]).unwrap();
     expected.arguments = Some(vec![]);
     assert_cli_args(&cli_args, &expected);
+    assert!(!cli_args.default_task);
 }
 
 #[test]
@@ -193,6 +247,27 @@ fn parse_args_profile() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_profile_multiple() {
+    let cli_args = default_parse_cli_args(vec![
+        "--profile",
+        "ci",
+        "-p",
+        "gpu",
+        "--profile=verbose",
+        "taskname",
+    ])
+    .unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.profile = Some("ci".to_string());
+    expected.additional_profiles = Some(vec!["gpu".to_string(), "verbose".to_string()]);
+    expected.task = "taskname".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_cwd() {
     let cli_args = default_parse_cli_args(vec!["--cwd", "./mydir/subdir/"]).unwrap();
@@ -248,12 +323,50 @@ fn parse_args_skip_tasks() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_tags() {
+    let mut cli_args = default_parse_cli_args(vec!["--tag", "ci"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.tags = Some(vec!["ci".to_string()]);
+
+    assert_cli_args(&cli_args, &expected);
+
+    cli_args =
+        default_parse_cli_args(vec!["--tag", "ci", "--tag", "lint", "--skip-tag", "slow"]).unwrap();
+    expected.tags = Some(vec!["ci".to_string(), "lint".to_string()]);
+    expected.skip_tags = Some(vec!["slow".to_string()]);
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_from_and_skip_steps() {
+    let mut cli_args = default_parse_cli_args(vec!["--from", "test"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.from_step = Some("test".to_string());
+
+    assert_cli_args(&cli_args, &expected);
+
+    cli_args = default_parse_cli_args(vec![
+        "--from",
+        "test",
+        "--skip-step",
+        "audit",
+        "--skip-step",
+        "lint",
+    ])
+    .unwrap();
+    expected.skip_steps = Some(vec!["audit".to_string(), "lint".to_string()]);
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_env_file() {
     let mut cli_args = default_parse_cli_args(vec!["--env-file", "./.env"]).unwrap();
 
     let mut expected = default_parsed_cli_args();
-    expected.env_file = Some("./.env".to_string());
+    expected.env_file = Some(vec!["./.env".to_string()]);
 
     assert_cli_args(&cli_args, &expected);
 
@@ -269,6 +382,24 @@ fn parse_args_env_file() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_env_file_multiple() {
+    let cli_args = default_parse_cli_args(vec![
+        "--env-file",
+        "./.env",
+        "--env-file=./local.env",
+        "taskname",
+    ])
+    .unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.env_file = Some(vec!["./.env".to_string(), "./local.env".to_string()]);
+    expected.task = "taskname".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_env() {
     let mut cli_args = default_parse_cli_args(vec!["--env", "K=V"]).unwrap();
@@ -360,6 +491,111 @@ fn parse_args_time_summary() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_status_summary() {
+    let cli_args = default_parse_cli_args(vec!["--status-summary"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_status_summary = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_progress() {
+    let cli_args = default_parse_cli_args(vec!["--progress"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.progress = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_ui() {
+    let cli_args = default_parse_cli_args(vec!["--ui"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.ui = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_force() {
+    let cli_args = default_parse_cli_args(vec!["--force"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.force = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_wait() {
+    let cli_args = default_parse_cli_args(vec!["--wait"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.wait_for_run_lock = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_no_lock() {
+    let cli_args = default_parse_cli_args(vec!["--no-lock"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.disable_run_lock = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_daemon() {
+    let cli_args = default_parse_cli_args(vec!["--daemon"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.daemon = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_perf() {
+    let cli_args = default_parse_cli_args(vec!["--perf"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.perf = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_strict() {
+    let cli_args = default_parse_cli_args(vec!["--strict"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.strict = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_yes() {
+    let mut cli_args = default_parse_cli_args(vec!["--yes"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.assume_yes = true;
+
+    assert_cli_args(&cli_args, &expected);
+
+    cli_args = default_parse_cli_args(vec!["-y", "taskname"]).unwrap();
+    expected.task = "taskname".to_string();
+    expected.arguments = Some(vec![]);
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_experimental() {
     let cli_args = default_parse_cli_args(vec!["--experimental"]).unwrap();
@@ -380,6 +616,16 @@ fn parse_args_disable_check_for_updates() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_error_format() {
+    let cli_args = default_parse_cli_args(vec!["--error-format", "json"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.error_format = "json".to_string();
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_output_format() {
     let mut cli_args = default_parse_cli_args(vec!["--output-format", "autocomplete"]).unwrap();
@@ -410,6 +656,40 @@ fn parse_args_output_file() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_export_env() {
+    let cli_args = default_parse_cli_args(vec!["--export-env", "build.env", "taskname"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.export_env = Some("build.env".to_string());
+    expected.task = "taskname".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_query() {
+    let cli_args = default_parse_cli_args(vec!["--query", r#"{"query":"tasks"}"#]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.query = Some(r#"{"query":"tasks"}"#.to_string());
+    expected.log_level = "error".to_string();
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_query_silent() {
+    let cli_args = default_parse_cli_args(vec!["--silent", "--query", "-"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.query = Some("-".to_string());
+    expected.log_level = "off".to_string();
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_print_steps() {
     let cli_args = default_parse_cli_args(vec!["--print-steps"]).unwrap();
@@ -420,6 +700,16 @@ fn parse_args_print_steps() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_dry_run() {
+    let cli_args = default_parse_cli_args(vec!["--dry-run"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.dry_run = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_list_all_steps() {
     let cli_args = default_parse_cli_args(vec!["--list-all-steps"]).unwrap();
@@ -475,3 +765,126 @@ fn parse_args_task_cmd() {
     cli_args = default_parse_cli_args(vec!["--", "task1", "arg1", "arg2"]).unwrap();
     assert_cli_args(&cli_args, &expected);
 }
+
+#[test]
+fn is_shebang_makefile_with_shebang() {
+    assert!(is_shebang_makefile("./examples/executable_makefile.toml"));
+}
+
+#[test]
+fn is_shebang_makefile_without_shebang() {
+    assert!(!is_shebang_makefile("./examples/min_version.toml"));
+}
+
+#[test]
+fn is_shebang_makefile_not_found() {
+    assert!(!is_shebang_makefile("./examples/not_found.toml"));
+}
+
+#[test]
+fn get_shebang_command_line_cargo_make() {
+    let command_line = vec![
+        "cargo-make".to_string(),
+        "./examples/executable_makefile.toml".to_string(),
+        "hello".to_string(),
+        "world".to_string(),
+    ];
+
+    let shebang_command_line = get_shebang_command_line(&command_line, "make", true).unwrap();
+
+    assert_eq!(
+        shebang_command_line,
+        vec![
+            "cargo-make",
+            "make",
+            "--makefile",
+            "./examples/executable_makefile.toml",
+            "hello",
+            "world"
+        ]
+    );
+}
+
+#[test]
+fn get_shebang_command_line_makers() {
+    let command_line = vec![
+        "makers".to_string(),
+        "./examples/executable_makefile.toml".to_string(),
+    ];
+
+    let shebang_command_line = get_shebang_command_line(&command_line, "makers", false).unwrap();
+
+    assert_eq!(
+        shebang_command_line,
+        vec![
+            "makers",
+            "--makefile",
+            "./examples/executable_makefile.toml"
+        ]
+    );
+}
+
+#[test]
+fn get_shebang_command_line_sub_command() {
+    let command_line = vec![
+        "cargo-make".to_string(),
+        "make".to_string(),
+        "./examples/executable_makefile.toml".to_string(),
+    ];
+
+    let shebang_command_line = get_shebang_command_line(&command_line, "make", true);
+
+    assert!(shebang_command_line.is_none());
+}
+
+#[test]
+fn get_shebang_command_line_task() {
+    let command_line = vec!["makers".to_string(), "build".to_string()];
+
+    let shebang_command_line = get_shebang_command_line(&command_line, "makers", false);
+
+    assert!(shebang_command_line.is_none());
+}
+
+#[test]
+fn parse_args_shebang_makefile() {
+    let command_line = vec![
+        "cargo-make".to_string(),
+        "./examples/executable_makefile.toml".to_string(),
+        "hello".to_string(),
+        "world".to_string(),
+    ];
+    let shebang_command_line = get_shebang_command_line(&command_line, "make", true).unwrap();
+    let args = shebang_command_line
+        .iter()
+        .map(|arg| arg.as_str())
+        .collect();
+
+    let global_config = GlobalConfig::new();
+    let cli_args = parse_args(
+        &global_config,
+        "make",
+        true,
+        Some(args),
+        create_cli(&global_config, CliSpec::new(), true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        cli_args.build_file.unwrap(),
+        "./examples/executable_makefile.toml"
+    );
+    assert_eq!(cli_args.task, "hello");
+    assert_eq!(cli_args.arguments.unwrap(), vec!["world".to_string()]);
+}
+
+#[test]
+fn get_flag_names_long_keys() {
+    let names = get_flag_names();
+
+    assert!(names.contains(&"help".to_string()));
+    assert!(names.contains(&"env-file".to_string()));
+    assert!(names.contains(&"list-all-steps".to_string()));
+    assert!(!names.contains(&"h".to_string()));
+    assert!(names.iter().all(|name| !name.starts_with('-')));
+}
@@ -14,8 +14,11 @@ fn run_makefile_not_found() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("bad.toml".to_string()),
+            additional_build_files: None,
             task: "empty".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -27,17 +30,37 @@ fn run_makefile_not_found() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -54,8 +77,11 @@ fn run_empty_task() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: None,
+            additional_build_files: None,
             task: "empty".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -67,17 +93,37 @@ fn run_empty_task() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -94,8 +140,11 @@ fn print_empty_task() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: None,
+            additional_build_files: None,
             task: "empty".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -107,17 +156,37 @@ fn print_empty_task() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: true,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -134,8 +203,11 @@ fn list_empty_task() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: None,
+            additional_build_files: None,
             task: "empty".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -147,17 +219,37 @@ fn list_empty_task() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: true,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -174,8 +266,11 @@ fn run_file_and_task() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -187,17 +282,37 @@ fn run_file_and_task() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -217,8 +332,11 @@ fn run_cwd_with_file() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -230,17 +348,37 @@ fn run_cwd_with_file() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -258,8 +396,11 @@ fn run_file_not_go_to_project_root() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -271,17 +412,37 @@ fn run_file_not_go_to_project_root() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -299,8 +460,11 @@ fn run_cwd_go_to_project_root_current_dir() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -312,17 +476,37 @@ fn run_cwd_go_to_project_root_current_dir() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -343,8 +527,11 @@ fn run_cwd_go_to_project_root_child_dir() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -356,17 +543,37 @@ fn run_cwd_go_to_project_root_child_dir() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
@@ -387,8 +594,11 @@ fn run_cwd_task_not_found() {
         &CliArgs {
             command: "cargo make".to_string(),
             build_file: Some("./dependencies.toml".to_string()),
+            additional_build_files: None,
             task: "A".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "error".to_string(),
             disable_color: true,
             completion: None,
@@ -400,17 +610,37 @@ fn run_cwd_task_not_found() {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             disable_check_for_updates: true,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         },
         &global_config,
         None,
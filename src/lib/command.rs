@@ -7,16 +7,250 @@
 #[path = "command_test.rs"]
 mod command_test;
 
+use crate::container;
 use crate::error::CargoMakeError;
+use crate::exit_codes::{self, FailureClass};
 use crate::logger;
+use crate::nix;
+use crate::output;
+use crate::status_summary;
 use crate::toolchain;
-use crate::types::{CommandSpec, Step, UnstableFeature};
+use crate::types::{CommandSpec, Step, TaskCommand, TaskOutputRedirection, UnstableFeature};
 use run_script::{IoOptions, ScriptError, ScriptOptions};
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::fs::{File, OpenOptions};
 use std::io;
-use std::io::{Error, ErrorKind, Read};
-use std::process::{Command, ExitStatus, Output, Stdio};
+use std::io::{Error, ErrorKind, PipeReader, PipeWriter, Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Once;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// The target of a redirected process output
+enum OutputTarget {
+    /// Discards the output
+    Null,
+    /// The stdin of the next run_task pipe stage
+    Pipe(PipeWriter),
+    File(File),
+}
+
+impl OutputTarget {
+    fn try_clone(&self) -> io::Result<OutputTarget> {
+        match self {
+            OutputTarget::Null => Ok(OutputTarget::Null),
+            OutputTarget::Pipe(writer) => Ok(OutputTarget::Pipe(writer.try_clone()?)),
+            OutputTarget::File(file) => Ok(OutputTarget::File(file.try_clone()?)),
+        }
+    }
+
+    fn into_stdio(self) -> Stdio {
+        match self {
+            OutputTarget::Null => Stdio::null(),
+            OutputTarget::Pipe(writer) => Stdio::from(writer),
+            OutputTarget::File(file) => Stdio::from(file),
+        }
+    }
+
+    fn into_writer(self) -> Box<dyn Write + Send> {
+        match self {
+            OutputTarget::Null => Box::new(io::sink()),
+            OutputTarget::Pipe(writer) => Box::new(writer),
+            OutputTarget::File(file) => Box::new(file),
+        }
+    }
+}
+
+/// The stdin/stdout/stderr of the invoked commands/scripts (None to use the default)
+struct TaskIo {
+    stdin: Option<PipeReader>,
+    stdout: Option<OutputTarget>,
+    stderr: Option<OutputTarget>,
+}
+
+impl TaskIo {
+    fn is_empty(&self) -> bool {
+        self.stdin.is_none() && self.stdout.is_none() && self.stderr.is_none()
+    }
+
+    fn try_clone(&self) -> io::Result<TaskIo> {
+        Ok(TaskIo {
+            stdin: self
+                .stdin
+                .as_ref()
+                .map(|stdin| stdin.try_clone())
+                .transpose()?,
+            stdout: self
+                .stdout
+                .as_ref()
+                .map(|stdout| stdout.try_clone())
+                .transpose()?,
+            stderr: self
+                .stderr
+                .as_ref()
+                .map(|stderr| stderr.try_clone())
+                .transpose()?,
+        })
+    }
+}
+
+thread_local! {
+    /// The stdout captured for the task env_capture attribute (None if not capturing)
+    static CAPTURED_STDOUT: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// The stdin/stdout/stderr overrides of the current thread (pipe stage and task output)
+    static TASK_IO: RefCell<TaskIo> = const {
+        RefCell::new(TaskIo {
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        })
+    };
+    /// The timeout of the scripts invoked by the current thread (None if there is no timeout)
+    static SCRIPT_TIMEOUT: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+fn is_capturing_stdout() -> bool {
+    CAPTURED_STDOUT.with(|captured| captured.borrow().is_some())
+}
+
+fn append_captured_stdout(stdout: &str) {
+    CAPTURED_STDOUT.with(|captured| {
+        if let Some(ref mut value) = *captured.borrow_mut() {
+            value.push_str(stdout);
+        }
+    });
+}
+
+/// Runs the provided function while capturing the stdout of the invoked commands/scripts
+/// (instead of printing it) and returns the function result with the captured stdout
+pub(crate) fn capture_stdout<T, F: FnOnce() -> T>(function: F) -> (T, String) {
+    let previous = CAPTURED_STDOUT.with(|captured| captured.replace(Some(String::new())));
+
+    let result = function();
+
+    let stdout = CAPTURED_STDOUT.with(|captured| captured.replace(previous));
+    (result, stdout.unwrap_or_default())
+}
+
+/// Runs the provided function with the provided stdin/stdout/stderr overrides (None keeps
+/// the current value) and returns the function result.<br>
+/// The overrides are closed once done, so the next pipe stage reads the end of its input.
+fn with_task_io<T, F: FnOnce() -> T>(
+    stdin: Option<PipeReader>,
+    stdout: Option<OutputTarget>,
+    stderr: Option<OutputTarget>,
+    function: F,
+) -> T {
+    let previous = TASK_IO.with(|task_io| {
+        let mut task_io = task_io.borrow_mut();
+        (
+            stdin.map(|stdin| task_io.stdin.replace(stdin)),
+            stdout.map(|stdout| task_io.stdout.replace(stdout)),
+            stderr.map(|stderr| task_io.stderr.replace(stderr)),
+        )
+    });
+
+    let result = function();
+
+    TASK_IO.with(|task_io| {
+        let mut task_io = task_io.borrow_mut();
+        if let Some(stdin) = previous.0 {
+            task_io.stdin = stdin;
+        }
+        if let Some(stdout) = previous.1 {
+            task_io.stdout = stdout;
+        }
+        if let Some(stderr) = previous.2 {
+            task_io.stderr = stderr;
+        }
+    });
+
+    result
+}
+
+/// Runs the provided function while connecting the stdin/stdout of the invoked
+/// commands/scripts to the provided pipe ends and returns the function result.
+pub(crate) fn pipe_io<T, F: FnOnce() -> T>(
+    input: Option<PipeReader>,
+    output: Option<PipeWriter>,
+    function: F,
+) -> T {
+    with_task_io(input, output.map(OutputTarget::Pipe), None, function)
+}
+
+fn open_output_target(value: &Option<String>, append: bool) -> io::Result<Option<OutputTarget>> {
+    match value.as_deref() {
+        None | Some("inherit") => Ok(None),
+        Some("null") => Ok(Some(OutputTarget::Null)),
+        Some(file) => {
+            let path = Path::new(file);
+            if let Some(directory) = path.parent() {
+                if !directory.as_os_str().is_empty() {
+                    fs::create_dir_all(directory)?;
+                }
+            }
+
+            let file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(append)
+                .truncate(!append)
+                .open(path)?;
+
+            Ok(Some(OutputTarget::File(file)))
+        }
+    }
+}
+
+/// Runs the provided function while redirecting the stdout/stderr of the invoked
+/// commands/scripts based on the task output redirection and returns the function result.
+pub(crate) fn redirect_output<T, F: FnOnce() -> T>(
+    redirection: &TaskOutputRedirection,
+    function: F,
+) -> Result<T, CargoMakeError> {
+    let append = redirection.append.unwrap_or(false);
+
+    let stdout = open_output_target(&redirection.stdout, append)?;
+    let stderr = if redirection.stderr.is_some() && redirection.stderr == redirection.stdout {
+        // both outputs are written to the same file (sharing the file position)
+        stdout
+            .as_ref()
+            .map(|stdout| stdout.try_clone())
+            .transpose()?
+    } else {
+        open_output_target(&redirection.stderr, append)?
+    };
+
+    Ok(with_task_io(None, stdout, stderr, function))
+}
+
+/// Runs the provided function while killing the invoked scripts in case they are still running
+/// after the timeout and returns the function result.
+pub(crate) fn with_script_timeout<T, F: FnOnce() -> T>(timeout: Duration, function: F) -> T {
+    let previous = SCRIPT_TIMEOUT.with(|current| current.replace(Some(timeout)));
+
+    let result = function();
+
+    SCRIPT_TIMEOUT.with(|current| current.set(previous));
+
+    result
+}
+
+/// Returns copies of the current stdin/stdout/stderr overrides (None if there are none)
+fn get_task_io() -> io::Result<Option<TaskIo>> {
+    TASK_IO.with(|task_io| {
+        let task_io = task_io.borrow();
+        if task_io.is_empty() {
+            Ok(None)
+        } else {
+            task_io.try_clone().map(Some)
+        }
+    })
+}
 
 /// Returns the exit code (-1 if no exit code found)
 pub(crate) fn get_exit_code(exit_status: Result<ExitStatus, Error>, force: bool) -> i32 {
@@ -117,6 +351,12 @@ pub(crate) fn run_script_get_output(
     print_commands: Option<bool>,
 ) -> Result<(i32, String, String), ScriptError> {
     let silent = is_silent();
+    let capture_stdout = !capture_output && is_capturing_stdout();
+    let timeout = if capture_output {
+        None
+    } else {
+        SCRIPT_TIMEOUT.with(|current| current.get())
+    };
     let mut options = ScriptOptions::new();
     options.runner = script_runner.clone();
     options.output_redirection = if silent {
@@ -132,14 +372,88 @@ pub(crate) fn run_script_get_output(
         None => should_print_commands_by_default(),
     };
 
+    let task_io = if capture_output {
+        None
+    } else {
+        get_task_io().map_err(ScriptError::IOError)?
+    };
+
     if is_silent() {
         options.output_redirection = IoOptions::Pipe;
         options.print_commands = false;
+    } else if capture_stdout {
+        options.output_redirection = IoOptions::Pipe;
     } else if !capture_output && envmnt::is("CARGO_MAKE_SCRIPT_FORCE_PIPE_STDIN") {
         options.input_redirection = IoOptions::Pipe;
     }
 
-    run_script::run(script_lines.join("\n").as_str(), cli_arguments, &options)
+    // the timeout is enforced while waiting for the spawned process, which captures the stdout
+    let task_io = match task_io {
+        None if capture_stdout && timeout.is_some() => Some(TaskIo {
+            stdin: None,
+            stdout: None,
+            stderr: None,
+        }),
+        task_io => task_io,
+    };
+
+    if let Some(task_io) = task_io {
+        options.output_redirection = IoOptions::Pipe;
+        if task_io.stdin.is_some() {
+            options.input_redirection = IoOptions::Pipe;
+        }
+
+        let process = run_script::spawn(script_lines.join("\n").as_str(), cli_arguments, &options)?;
+        let status = wait_with_task_io(process, task_io, capture_stdout, silent, timeout)
+            .map_err(ScriptError::IOError)?;
+
+        Ok((
+            get_exit_code(Ok(status), false),
+            "".to_string(),
+            "".to_string(),
+        ))
+    } else if capture_stdout {
+        let (exit_code, stdout, stderr) =
+            run_script::run(script_lines.join("\n").as_str(), cli_arguments, &options)?;
+
+        append_captured_stdout(&stdout);
+        // stdout and stderr are piped together, so stderr is printed once the script is done
+        if !silent {
+            for line in stderr.lines() {
+                output::write_line(line, true);
+            }
+        }
+
+        Ok((exit_code, "".to_string(), "".to_string()))
+    } else if !silent && !capture_output && output::is_forwarding_enabled() {
+        options.output_redirection = IoOptions::Pipe;
+
+        let process = run_script::spawn(script_lines.join("\n").as_str(), cli_arguments, &options)?;
+        let status = wait_with_line_prefix(process, timeout).map_err(ScriptError::IOError)?;
+
+        Ok((
+            get_exit_code(Ok(status), false),
+            "".to_string(),
+            "".to_string(),
+        ))
+    } else if timeout.is_some() {
+        if silent {
+            // the output is not read, so it is discarded instead of piped
+            options.output_redirection = IoOptions::Null;
+        }
+
+        let mut process =
+            run_script::spawn(script_lines.join("\n").as_str(), cli_arguments, &options)?;
+        let status = wait_for_process(&mut process, timeout).map_err(ScriptError::IOError)?;
+
+        Ok((
+            get_exit_code(Ok(status), false),
+            "".to_string(),
+            "".to_string(),
+        ))
+    } else {
+        run_script::run(script_lines.join("\n").as_str(), cli_arguments, &options)
+    }
 }
 
 /// Runs the requested script text and panics in case of any script error.
@@ -171,6 +485,9 @@ pub(crate) fn run_command_get_output(
 ) -> io::Result<Output> {
     let ctrl_c_handling = UnstableFeature::CtrlCHandling.is_env_set();
     let silent = is_silent();
+    let capture_stdout = !capture_output && is_capturing_stdout();
+    let task_io = if capture_output { None } else { get_task_io()? };
+    let redirected = task_io.is_some();
 
     debug!("Execute Command: {}", &command_string);
     let mut command = Command::new(&command_string);
@@ -184,7 +501,38 @@ pub(crate) fn run_command_get_output(
 
     command.stdin(Stdio::inherit());
 
-    if silent {
+    if let Some(task_io) = task_io {
+        let default_output = || {
+            if silent {
+                Stdio::null()
+            } else {
+                Stdio::inherit()
+            }
+        };
+
+        if let Some(stdin) = task_io.stdin {
+            command.stdin(stdin);
+        }
+        // the captured stdout takes precedence over the stdout redirection
+        let stdout = if capture_stdout {
+            Stdio::piped()
+        } else {
+            task_io
+                .stdout
+                .map_or_else(default_output, OutputTarget::into_stdio)
+        };
+        command.stdout(stdout).stderr(
+            task_io
+                .stderr
+                .map_or_else(default_output, OutputTarget::into_stdio),
+        );
+    } else if capture_stdout {
+        command.stdout(Stdio::piped()).stderr(if silent {
+            Stdio::null()
+        } else {
+            Stdio::inherit()
+        });
+    } else if silent {
         command.stdout(Stdio::null()).stderr(Stdio::null());
     } else if ctrl_c_handling {
         if capture_output {
@@ -196,8 +544,19 @@ pub(crate) fn run_command_get_output(
 
     info!("Execute Command: {:?}", &command);
 
-    let output = if ctrl_c_handling {
+    let output = if capture_stdout {
+        let output = command.output();
+        if let Ok(ref output_struct) = output {
+            append_captured_stdout(&String::from_utf8_lossy(&output_struct.stdout));
+        }
+
+        output
+    } else if redirected {
+        command.output()
+    } else if ctrl_c_handling {
         spawn_command(command)
+    } else if !silent && !capture_output && output::is_forwarding_enabled() {
+        spawn_command_with_line_prefix(command)
     } else {
         command.output()
     };
@@ -261,6 +620,141 @@ fn spawn_command(mut command: Command) -> io::Result<Output> {
     })
 }
 
+/// Waits for the process to end, the process is killed in case it is still running after the
+/// timeout (if provided) and a timed out error is returned
+fn wait_for_process(process: &mut Child, timeout: Option<Duration>) -> io::Result<ExitStatus> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return process.wait(),
+    };
+
+    // the process stdin is closed (same as wait) so it does not wait for more input
+    drop(process.stdin.take());
+
+    let start_time = Instant::now();
+    loop {
+        if let Some(status) = process.try_wait()? {
+            return Ok(status);
+        }
+
+        if start_time.elapsed() >= timeout {
+            warn!("Script timed out after {} seconds.", timeout.as_secs());
+            exit_codes::set_failure_class(FailureClass::Timeout);
+            if process.kill().is_err() {
+                debug!("Unable to kill script process.");
+            }
+            process.wait().ok();
+
+            return Err(Error::new(ErrorKind::TimedOut, "Script timed out."));
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Waits for the process to end while forwarding its output via the output writer
+fn wait_with_line_prefix(mut process: Child, timeout: Option<Duration>) -> io::Result<ExitStatus> {
+    let stdout_forwarder = process
+        .stdout
+        .take()
+        .map(|reader| output::forward_lines(reader, false));
+    let stderr_forwarder = process
+        .stderr
+        .take()
+        .map(|reader| output::forward_lines(reader, true));
+
+    // on timeout the forwarders are not joined, as child processes of the killed process may
+    // still hold its output open
+    let status = wait_for_process(&mut process, timeout)?;
+
+    for forwarder in [stdout_forwarder, stderr_forwarder].into_iter().flatten() {
+        if forwarder.join().is_err() {
+            debug!("Unable to forward process output.");
+        }
+    }
+
+    Ok(status)
+}
+
+/// Copies the reader content to the output target in a new thread
+fn copy_output<R: Read + Send + 'static>(mut reader: R, target: OutputTarget) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut writer = target.into_writer();
+        io::copy(&mut reader, &mut writer).unwrap_or(0);
+    })
+}
+
+/// Waits for the process to end while copying the stdin override to the process stdin and the
+/// process stdout/stderr to their overrides (or the console)
+fn wait_with_task_io(
+    mut process: Child,
+    task_io: TaskIo,
+    capture_stdout: bool,
+    silent: bool,
+    timeout: Option<Duration>,
+) -> io::Result<ExitStatus> {
+    if let (Some(mut input), Some(mut stdin)) = (task_io.stdin, process.stdin.take()) {
+        // not joined as the process may end before reading all of its input
+        thread::spawn(move || {
+            io::copy(&mut input, &mut stdin).unwrap_or(0);
+        });
+    }
+
+    let mut forwarders = vec![];
+    let mut stdout_reader = None;
+    if let Some(reader) = process.stderr.take() {
+        forwarders.push(match task_io.stderr {
+            Some(target) => copy_output(reader, target),
+            None if silent => copy_output(reader, OutputTarget::Null),
+            None => output::forward_lines(reader, true),
+        });
+    }
+    if let Some(mut reader) = process.stdout.take() {
+        match task_io.stdout {
+            // the captured stdout takes precedence over the stdout redirection
+            // read in a new thread so the process timeout is still enforced
+            _ if capture_stdout => {
+                stdout_reader = Some(thread::spawn(move || {
+                    let mut stdout = vec![];
+                    reader.read_to_end(&mut stdout).map(|_| stdout)
+                }))
+            }
+            Some(target) => forwarders.push(copy_output(reader, target)),
+            None if silent => forwarders.push(copy_output(reader, OutputTarget::Null)),
+            None => forwarders.push(output::forward_lines(reader, false)),
+        }
+    }
+
+    let status = wait_for_process(&mut process, timeout)?;
+
+    if let Some(stdout_reader) = stdout_reader {
+        match stdout_reader.join() {
+            Ok(stdout) => append_captured_stdout(&String::from_utf8_lossy(&stdout?)),
+            Err(_) => debug!("Unable to capture process output."),
+        }
+    }
+    for forwarder in forwarders {
+        if forwarder.join().is_err() {
+            debug!("Unable to forward process output.");
+        }
+    }
+
+    Ok(status)
+}
+
+fn spawn_command_with_line_prefix(mut command: Command) -> io::Result<Output> {
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let process = command.spawn()?;
+    let status = wait_with_line_prefix(process, None)?;
+
+    Ok(Output {
+        status,
+        stdout: vec![],
+        stderr: vec![],
+    })
+}
+
 /// Runs the requested command and panics in case of any error.
 pub(crate) fn run_command(
     command_string: &str,
@@ -300,10 +794,18 @@ pub(crate) fn run_command_get_output_string(
     }
 }
 
-/// Runs the given task command.
-pub(crate) fn run(step: &Step) -> Result<(), CargoMakeError> {
-    let validate = !step.config.should_ignore_errors();
+/// Returns the step which invokes the provided command of a multi command task
+pub(crate) fn create_command_step(step: &Step, task_command: &TaskCommand) -> Step {
+    let mut command_step = step.clone();
+    command_step.config.command = Some(task_command.command.clone());
+    command_step.config.args = task_command.args.clone();
+    command_step.config.commands = None;
 
+    command_step
+}
+
+/// Returns the task command wrapped by the toolchain, nix and container (if defined)
+pub(crate) fn get_command_spec(step: &Step) -> Option<CommandSpec> {
     match step.config.command {
         Some(ref command_string) => {
             let command_spec = match step.config.toolchain {
@@ -315,8 +817,32 @@ pub(crate) fn run(step: &Step) -> Result<(), CargoMakeError> {
                     args: step.config.args.clone(),
                 },
             };
+            let command_spec = match nix::get_flake(&step.config) {
+                Some(flake) => nix::wrap_command(&flake, command_spec),
+                None => command_spec,
+            };
+            let command_spec = match step.config.container {
+                Some(ref container_info) => container::wrap_command(container_info, command_spec),
+                None => command_spec,
+            };
 
-            run_command(&command_spec.command, &command_spec.args, validate)?;
+            Some(command_spec)
+        }
+        None => None,
+    }
+}
+
+/// Runs the given task command.
+pub(crate) fn run(step: &Step) -> Result<(), CargoMakeError> {
+    let validate = !step.config.should_ignore_errors();
+
+    match get_command_spec(step) {
+        Some(command_spec) => {
+            let exit_code = run_command(&command_spec.command, &command_spec.args, validate)?;
+
+            if exit_code != 0 {
+                status_summary::set_ignored_error();
+            }
         }
         None => debug!("No command defined."),
     };
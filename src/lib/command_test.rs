@@ -2,6 +2,7 @@ use super::*;
 use crate::test;
 use crate::types::Task;
 use cfg_if::cfg_if;
+use std::io::Write;
 
 #[test]
 fn validate_exit_code_unable_to_fetch() {
@@ -250,3 +251,251 @@ fn run_script_get_exit_code_cli_args_error() {
     )
     .unwrap();
 }
+
+#[test]
+fn create_command_step_replaces_command() {
+    let mut task = Task::new();
+    task.cwd = Some("sub".to_string());
+    task.commands = Some(vec![]);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let command_step = create_command_step(
+        &step,
+        &TaskCommand {
+            command: "cargo".to_string(),
+            args: Some(vec!["build".to_string()]),
+            env: None,
+        },
+    );
+
+    assert_eq!(command_step.name, "test");
+    assert_eq!(command_step.config.command.unwrap(), "cargo");
+    assert_eq!(command_step.config.args.unwrap(), vec!["build"]);
+    assert!(command_step.config.commands.is_none());
+    assert_eq!(command_step.config.cwd.unwrap(), "sub");
+}
+
+#[test]
+fn capture_stdout_script() {
+    let (exit_code, stdout) = capture_stdout(|| {
+        run_script_get_exit_code(&vec!["echo captured".to_string()], None, &vec![], true)
+    });
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(stdout.trim(), "captured");
+    assert!(!is_capturing_stdout());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn with_script_timeout_capture_stdout() {
+    let (exit_code, stdout) = capture_stdout(|| {
+        with_script_timeout(Duration::from_secs(10), || {
+            run_script_get_exit_code(&vec!["echo captured".to_string()], None, &vec![], true)
+        })
+    });
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(stdout.trim(), "captured");
+    assert!(SCRIPT_TIMEOUT.with(|current| current.get()).is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn with_script_timeout_expired() {
+    let (exit_code, _) = capture_stdout(|| {
+        with_script_timeout(Duration::from_secs(1), || {
+            run_script_get_exit_code(
+                &vec!["echo started".to_string(), "sleep 5".to_string()],
+                None,
+                &vec![],
+                false,
+            )
+        })
+    });
+
+    assert_eq!(exit_code.unwrap(), -1);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn capture_stdout_command() {
+    let (exit_code, stdout) = capture_stdout(|| {
+        super::run_command("echo", &Some(vec!["first".to_string()]), true)?;
+        super::run_command("echo", &Some(vec!["second".to_string()]), true)
+    });
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(stdout, "first\nsecond\n");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn capture_stdout_command_error() {
+    let (result, stdout) = capture_stdout(|| super::run_command("false", &None, true));
+
+    assert!(result.is_err());
+    assert!(stdout.is_empty());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn capture_stdout_nested() {
+    let ((inner_stdout, _), outer_stdout) = capture_stdout(|| {
+        let inner = capture_stdout(|| {
+            run_script_get_exit_code(&vec!["echo inner".to_string()], None, &vec![], true)
+        });
+        run_script_get_exit_code(&vec!["echo outer".to_string()], None, &vec![], true).unwrap();
+
+        (inner.1, inner.0)
+    });
+
+    assert_eq!(inner_stdout.trim(), "inner");
+    assert_eq!(outer_stdout.trim(), "outer");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn pipe_io_command() {
+    let (mut reader, writer) = io::pipe().unwrap();
+
+    let exit_code = pipe_io(None, Some(writer), || {
+        super::run_command("echo", &Some(vec!["piped".to_string()]), true)
+    });
+
+    let mut output = String::new();
+    reader.read_to_string(&mut output).unwrap();
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(output, "piped\n");
+    assert!(get_task_io().unwrap().is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn pipe_io_script() {
+    let (input_reader, mut input_writer) = io::pipe().unwrap();
+    let (mut output_reader, output_writer) = io::pipe().unwrap();
+    input_writer.write_all(b"value\n").unwrap();
+    drop(input_writer);
+
+    let exit_code = pipe_io(Some(input_reader), Some(output_writer), || {
+        run_script_get_exit_code(
+            &vec!["read line".to_string(), "echo \"read: $line\"".to_string()],
+            None,
+            &vec![],
+            true,
+        )
+    });
+
+    let mut output = String::new();
+    output_reader.read_to_string(&mut output).unwrap();
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(output, "read: value\n");
+}
+
+#[test]
+fn open_output_target_inherit() {
+    assert!(open_output_target(&None, false).unwrap().is_none());
+    assert!(open_output_target(&Some("inherit".to_string()), false)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn redirect_output_command_stdout_file() {
+    let file = "./target/_temp/command_output/command.log";
+    let redirection = TaskOutputRedirection {
+        stdout: Some(file.to_string()),
+        stderr: None,
+        append: None,
+    };
+
+    let exit_code = redirect_output(&redirection, || {
+        super::run_command("echo", &Some(vec!["first".to_string()]), true)?;
+        super::run_command("echo", &Some(vec!["second".to_string()]), true)
+    })
+    .unwrap();
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(fs::read_to_string(file).unwrap(), "first\nsecond\n");
+    assert!(get_task_io().unwrap().is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn redirect_output_script_same_file() {
+    let file = "./target/_temp/command_output/script.log";
+    let redirection = TaskOutputRedirection {
+        stdout: Some(file.to_string()),
+        stderr: Some(file.to_string()),
+        append: None,
+    };
+
+    let exit_code = redirect_output(&redirection, || {
+        run_script_get_exit_code(
+            &vec!["echo out".to_string(), "echo err 1>&2".to_string()],
+            None,
+            &vec![],
+            true,
+        )
+    })
+    .unwrap();
+
+    assert_eq!(exit_code.unwrap(), 0);
+    let text = fs::read_to_string(file).unwrap();
+    assert!(text.contains("out\n"));
+    assert!(text.contains("err\n"));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn redirect_output_append() {
+    let file = "./target/_temp/command_output/append.log";
+    let redirection = TaskOutputRedirection {
+        stdout: Some(file.to_string()),
+        stderr: Some("null".to_string()),
+        append: Some(true),
+    };
+    fs::create_dir_all("./target/_temp/command_output").unwrap();
+    fs::write(file, "existing\n").unwrap();
+
+    let exit_code = redirect_output(&redirection, || {
+        super::run_command("echo", &Some(vec!["new".to_string()]), true)
+    })
+    .unwrap();
+
+    assert_eq!(exit_code.unwrap(), 0);
+    assert_eq!(fs::read_to_string(file).unwrap(), "existing\nnew\n");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn redirect_output_stderr_with_capture_stdout() {
+    let file = "./target/_temp/command_output/capture.log";
+    let redirection = TaskOutputRedirection {
+        stdout: Some("null".to_string()),
+        stderr: Some(file.to_string()),
+        append: None,
+    };
+
+    let (exit_code, stdout) = capture_stdout(|| {
+        redirect_output(&redirection, || {
+            run_script_get_exit_code(
+                &vec!["echo out".to_string(), "echo err 1>&2".to_string()],
+                None,
+                &vec![],
+                true,
+            )
+        })
+    });
+
+    assert_eq!(exit_code.unwrap().unwrap(), 0);
+    assert_eq!(stdout, "out\n");
+    assert_eq!(fs::read_to_string(file).unwrap(), "err\n");
+}
@@ -213,17 +213,17 @@ fn validate_profile(condition: &TaskCondition) -> bool {
     let profiles = condition.profiles.clone();
     match profiles {
         Some(profile_names) => {
-            let profile_name = profile::get();
+            let current_profile_names = profile::get_all();
 
             let index = profile_names
                 .iter()
-                .position(|value| *value == profile_name);
+                .position(|value| current_profile_names.contains(value));
 
             match index {
                 None => {
                     debug!(
                         "Failed profile condition, current profile: {}",
-                        &profile_name
+                        current_profile_names.join(", ")
                     );
                     false
                 }
@@ -2358,6 +2358,12 @@ fn validate_channel_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -2373,6 +2379,8 @@ fn validate_channel_valid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2469,6 +2477,12 @@ fn validate_channel_invalid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -2484,6 +2498,8 @@ fn validate_channel_invalid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3143,6 +3159,12 @@ fn validate_criteria_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3158,6 +3180,8 @@ fn validate_criteria_empty() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3196,6 +3220,12 @@ fn validate_criteria_valid_os() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3211,6 +3241,8 @@ fn validate_criteria_valid_os() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3253,6 +3285,12 @@ fn validate_criteria_invalid_os() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3268,6 +3306,8 @@ fn validate_criteria_invalid_os() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3306,6 +3346,12 @@ fn validate_criteria_valid_platform() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3321,6 +3367,8 @@ fn validate_criteria_valid_platform() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3363,6 +3411,12 @@ fn validate_criteria_invalid_platform() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3378,6 +3432,8 @@ fn validate_criteria_invalid_platform() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3417,6 +3473,12 @@ fn validate_criteria_valid_profile() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3432,6 +3494,8 @@ fn validate_criteria_valid_profile() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3471,6 +3535,12 @@ fn validate_criteria_invalid_profile() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3486,6 +3556,8 @@ fn validate_criteria_invalid_profile() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3524,6 +3596,12 @@ fn validate_criteria_valid_channel() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -3539,6 +3617,8 @@ fn validate_criteria_valid_channel() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3642,6 +3722,12 @@ fn validate_criteria_invalid_channel() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let mut flow_info = FlowInfo {
         config,
@@ -3657,6 +3743,8 @@ fn validate_criteria_invalid_channel() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3696,6 +3784,12 @@ fn validate_criteria_valid_file_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3711,6 +3805,8 @@ fn validate_criteria_valid_file_exists() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3751,6 +3847,12 @@ fn validate_criteria_invalid_file_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3766,6 +3868,8 @@ fn validate_criteria_invalid_file_exists() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3806,6 +3910,12 @@ fn validate_criteria_valid_file_not_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3821,6 +3931,8 @@ fn validate_criteria_valid_file_not_exists() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3861,6 +3973,12 @@ fn validate_criteria_invalid_file_not_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3876,6 +3994,8 @@ fn validate_criteria_invalid_file_not_exists() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3921,6 +4041,12 @@ fn validate_condition_for_step_both_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3936,6 +4062,8 @@ fn validate_condition_for_step_both_valid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -3983,6 +4111,12 @@ fn validate_condition_for_step_valid_script_invalid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -3998,6 +4132,8 @@ fn validate_condition_for_step_valid_script_invalid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4045,6 +4181,12 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4060,6 +4202,8 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4107,6 +4251,12 @@ fn validate_condition_for_step_invalid_script_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4122,6 +4272,8 @@ fn validate_condition_for_step_invalid_script_valid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4166,6 +4318,12 @@ fn validate_condition_for_step_invalid_env_set() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4181,6 +4339,8 @@ fn validate_condition_for_step_invalid_env_set() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4225,6 +4385,12 @@ fn validate_condition_for_step_invalid_env_not_set() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4240,6 +4406,8 @@ fn validate_condition_for_step_invalid_env_not_set() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4286,6 +4454,12 @@ fn validate_condition_for_step_valid_env() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4301,6 +4475,8 @@ fn validate_condition_for_step_valid_env() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4352,6 +4528,12 @@ fn validate_condition_for_step_invalid_env_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4367,6 +4549,8 @@ fn validate_condition_for_step_invalid_env_not_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4415,6 +4599,12 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4430,6 +4620,8 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4481,6 +4673,12 @@ fn validate_condition_for_step_valid_env_not() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4496,6 +4694,8 @@ fn validate_condition_for_step_valid_env_not() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4547,6 +4747,12 @@ fn validate_condition_for_step_valid_env_not_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4562,6 +4768,8 @@ fn validate_condition_for_step_valid_env_not_not_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4610,6 +4818,12 @@ fn validate_condition_for_step_invalid_env_not_not_equal() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4625,6 +4839,8 @@ fn validate_condition_for_step_invalid_env_not_not_equal() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4676,6 +4892,12 @@ fn validate_condition_for_step_valid_env_contains_same() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4691,6 +4913,8 @@ fn validate_condition_for_step_valid_env_contains_same() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4742,6 +4966,12 @@ fn validate_condition_for_step_valid_env_contains() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4757,6 +4987,8 @@ fn validate_condition_for_step_valid_env_contains() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4808,6 +5040,12 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4823,6 +5061,8 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4871,6 +5111,12 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4886,6 +5132,8 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -4936,6 +5184,12 @@ fn validate_condition_for_step_valid_rust_version() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -4951,6 +5205,8 @@ fn validate_condition_for_step_valid_rust_version() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5000,6 +5256,12 @@ fn validate_condition_for_step_invalid_rust_version() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5015,6 +5277,8 @@ fn validate_condition_for_step_invalid_rust_version() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5065,6 +5329,12 @@ fn validate_condition_for_step_both_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5080,6 +5350,8 @@ fn validate_condition_for_step_both_valid_and() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5127,6 +5399,12 @@ fn validate_condition_for_step_value_partial_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5142,6 +5420,8 @@ fn validate_condition_for_step_value_partial_valid_and() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5192,6 +5472,12 @@ fn validate_condition_for_step_group_partial_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5207,6 +5493,8 @@ fn validate_condition_for_step_group_partial_valid_and() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5254,6 +5542,12 @@ fn validate_condition_for_step_both_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5269,6 +5563,8 @@ fn validate_condition_for_step_both_valid_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5316,6 +5612,12 @@ fn validate_condition_for_step_value_partial_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5331,6 +5633,8 @@ fn validate_condition_for_step_value_partial_valid_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5381,6 +5685,12 @@ fn validate_condition_for_step_group_partial_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5396,6 +5706,8 @@ fn validate_condition_for_step_group_partial_valid_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5443,6 +5755,12 @@ fn validate_condition_for_step_both_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5458,6 +5776,8 @@ fn validate_condition_for_step_both_valid_group_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5505,6 +5825,12 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5520,6 +5846,8 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -5570,6 +5898,12 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -5585,6 +5919,8 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -0,0 +1,87 @@
+//! # container
+//!
+//! Wraps task commands and scripts with a container engine (docker/podman) run invocation,
+//! so they run inside the task container with the workspace mounted.
+//!
+
+#[cfg(test)]
+#[path = "container_test.rs"]
+mod container_test;
+
+use crate::types::{CommandSpec, ContainerInfo};
+use std::env;
+
+/// Returns the container engine binary
+fn get_engine(container_info: &ContainerInfo) -> String {
+    match container_info.engine {
+        Some(ref engine) => engine.to_string(),
+        None => envmnt::get_or("CARGO_MAKE_CONTAINER_ENGINE", "docker"),
+    }
+}
+
+fn to_string(path: &std::path::Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// Returns the container run arguments (without the command)
+fn get_run_args(container_info: &ContainerInfo) -> Vec<String> {
+    let working_directory = match env::current_dir() {
+        Ok(directory) => to_string(&directory),
+        Err(_) => ".".to_string(),
+    };
+    // mount the entire workspace so members can access the root files
+    let workspace_directory =
+        envmnt::get_or("CARGO_MAKE_WORKSPACE_WORKING_DIRECTORY", &working_directory);
+    // scripts are written to the temporary directory before invoked
+    let temp_directory = to_string(&env::temp_dir());
+
+    let mut args = vec![
+        "run".to_string(),
+        "--rm".to_string(),
+        "-v".to_string(),
+        format!("{}:{}", &workspace_directory, &workspace_directory),
+        "-v".to_string(),
+        format!("{}:{}:ro", &temp_directory, &temp_directory),
+        "-w".to_string(),
+        working_directory,
+    ];
+
+    if let Some(ref mounts) = container_info.mounts {
+        for mount in mounts {
+            args.push("-v".to_string());
+            args.push(mount.to_string());
+        }
+    }
+
+    if let Some(ref env_passthrough) = container_info.env_passthrough {
+        for name in env_passthrough {
+            args.push("-e".to_string());
+            args.push(name.to_string());
+        }
+    }
+
+    if let Some(ref run_args) = container_info.run_args {
+        args.extend(run_args.iter().cloned());
+    }
+
+    args.push(container_info.image.to_string());
+
+    args
+}
+
+/// Wraps the command (and its args) with the container engine run command
+pub(crate) fn wrap_command(
+    container_info: &ContainerInfo,
+    command_spec: CommandSpec,
+) -> CommandSpec {
+    let mut args = get_run_args(container_info);
+    args.push(command_spec.command);
+    if let Some(command_args) = command_spec.args {
+        args.extend(command_args);
+    }
+
+    CommandSpec {
+        command: get_engine(container_info),
+        args: Some(args),
+    }
+}
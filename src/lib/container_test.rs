@@ -0,0 +1,61 @@
+use super::*;
+
+fn create_container_info() -> ContainerInfo {
+    ContainerInfo {
+        image: "rust:1.80".to_string(),
+        engine: Some("podman".to_string()),
+        mounts: Some(vec!["/cache:/usr/local/cargo/registry".to_string()]),
+        env_passthrough: Some(vec!["CI".to_string()]),
+        run_args: Some(vec!["--network=none".to_string()]),
+    }
+}
+
+#[test]
+fn get_engine_defined() {
+    assert_eq!(get_engine(&create_container_info()), "podman");
+}
+
+#[test]
+fn get_engine_default() {
+    let mut container_info = create_container_info();
+    container_info.engine = None;
+
+    envmnt::remove("CARGO_MAKE_CONTAINER_ENGINE");
+    assert_eq!(get_engine(&container_info), "docker");
+}
+
+#[test]
+fn wrap_command_with_args() {
+    let command_spec = wrap_command(
+        &create_container_info(),
+        CommandSpec {
+            command: "cargo".to_string(),
+            args: Some(vec!["build".to_string()]),
+        },
+    );
+
+    assert_eq!(command_spec.command, "podman");
+
+    let args = command_spec.args.unwrap();
+    assert_eq!(args[0], "run");
+    assert_eq!(args[1], "--rm");
+
+    let working_directory = to_string(&env::current_dir().unwrap());
+    let workdir_index = args.iter().position(|arg| arg == "-w").unwrap();
+    assert_eq!(args[workdir_index + 1], working_directory);
+
+    let image_index = args.iter().position(|arg| arg == "rust:1.80").unwrap();
+    assert_eq!(
+        args[image_index - 5..],
+        vec![
+            "-v",
+            "/cache:/usr/local/cargo/registry",
+            "-e",
+            "CI",
+            "--network=none",
+            "rust:1.80",
+            "cargo",
+            "build"
+        ]
+    );
+}
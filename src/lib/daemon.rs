@@ -0,0 +1,259 @@
+//! # daemon
+//!
+//! Keeps a resident process which holds the loaded (parsed and merged) external makefiles of the
+//! project in memory and serves them to later cargo-make invocations over a local socket.<br>
+//! The core tasks and the env are still resolved by each invocation as they depend on its
+//! cli arguments and shell.<br>
+//! The makefiles are validated by their content hashes on every request, so any modification
+//! causes them to be loaded again.
+//!
+
+#[cfg(test)]
+#[path = "daemon_test.rs"]
+mod daemon_test;
+
+use crate::descriptor;
+use crate::error::CargoMakeError;
+use crate::history;
+use crate::logger;
+use crate::storage;
+use fsio::file::{read_text_file, write_text_file};
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+static DAEMON_DIRECTORY: &str = "daemon";
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The maximum time to wait for the daemon before falling back to loading the makefiles
+static TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Holds the connection details of the running daemon
+struct DaemonInfo {
+    /// The daemon process id
+    pid: u32,
+    /// The local port the daemon listens on
+    port: u16,
+    /// The secret which must be sent with each request
+    token: String,
+    /// The cargo-make version of the daemon
+    version: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct DaemonRequest {
+    token: String,
+    /// The working directory of the invoking process
+    cwd: String,
+    /// The makefile to load
+    makefile: String,
+    /// True if the makefile must exist
+    force: bool,
+    /// True if the makefiles are parsed in strict mode
+    strict: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct DaemonResponse {
+    token: String,
+    /// The serialized descriptor
+    descriptor: Option<String>,
+    /// The reason the descriptor is not provided
+    error: Option<String>,
+}
+
+fn get_current_directory() -> String {
+    env::current_dir()
+        .map(|directory| directory.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Returns the daemon info file of the current directory, so each project has its own daemon
+fn get_info_file() -> Option<PathBuf> {
+    storage::get_storage_directory(dirs_next::data_local_dir(), DAEMON_DIRECTORY, false).map(
+        |storage_directory| {
+            storage_directory.join(DAEMON_DIRECTORY).join(format!(
+                "{}.json",
+                history::get_directory_key(&get_current_directory())
+            ))
+        },
+    )
+}
+
+fn load_info(file: &PathBuf) -> Option<DaemonInfo> {
+    read_text_file(file)
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+}
+
+fn send_request(port: u16, request: &DaemonRequest) -> Result<DaemonResponse, CargoMakeError> {
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+    let mut stream = TcpStream::connect_timeout(&address, TIMEOUT)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut line = serde_json::to_string(request).map_err(io::Error::from)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes())?;
+
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response)?;
+
+    let response = serde_json::from_str(&response).map_err(io::Error::from)?;
+
+    Ok(response)
+}
+
+/// Returns true if the invocations request the makefiles from the daemon (CARGO_MAKE_DAEMON env var)
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is("CARGO_MAKE_DAEMON")
+}
+
+/// Returns the serialized descriptor of the provided makefile from the daemon of the current
+/// directory (None if the daemon is not enabled, not running or unable to provide it)
+pub(crate) fn request_descriptor(file_name: &str, force: bool) -> Option<String> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let info_file = get_info_file()?;
+    let info = load_info(&info_file)?;
+    if info.version != VERSION {
+        debug!("Daemon runs another cargo-make version: {}", &info.version);
+        return None;
+    }
+
+    let request = DaemonRequest {
+        token: info.token.clone(),
+        cwd: get_current_directory(),
+        makefile: file_name.to_string(),
+        force,
+        strict: envmnt::is("CARGO_MAKE_STRICT"),
+    };
+
+    match send_request(info.port, &request) {
+        Ok(response) if response.token != info.token => {
+            debug!("Invalid daemon response token, ignoring.");
+            None
+        }
+        Ok(response) => {
+            if let Some(ref error) = response.error {
+                debug!("Daemon did not provide the descriptor, {}", error);
+            }
+
+            response.descriptor
+        }
+        Err(error) => {
+            // the daemon is no longer running
+            debug!("Unable to connect to daemon, {}", error);
+            fs::remove_file(&info_file).unwrap_or(());
+            None
+        }
+    }
+}
+
+fn create_response(info: &DaemonInfo, cwd: &str, request: &str) -> DaemonResponse {
+    let mut response = DaemonResponse {
+        token: info.token.clone(),
+        descriptor: None,
+        error: None,
+    };
+
+    let request: DaemonRequest = match serde_json::from_str(request) {
+        Ok(request) => request,
+        Err(error) => {
+            response.error = Some(format!("Invalid request, {}", error));
+            return response;
+        }
+    };
+
+    if request.token != info.token {
+        response.error = Some("Invalid token.".to_string());
+    } else if request.cwd != cwd {
+        response.error = Some(format!("Daemon serves directory: {}", cwd));
+    } else {
+        // the strict mode of the invoking process applies to the parsing of the makefiles
+        if request.strict {
+            envmnt::set_bool("CARGO_MAKE_STRICT", true);
+        } else {
+            envmnt::remove("CARGO_MAKE_STRICT");
+        }
+
+        // a bad makefile must not stop the daemon, the error is returned to the client instead
+        match logger::run_without_error_exit(|| {
+            descriptor::load_for_daemon(&request.makefile, request.force)
+        }) {
+            Ok(Some(descriptor)) => response.descriptor = Some(descriptor),
+            Ok(None) => response.error = Some("Descriptor is not cacheable.".to_string()),
+            Err(error) => response.error = Some(error.to_string()),
+        }
+    }
+
+    response
+}
+
+fn handle_connection(
+    info: &DaemonInfo,
+    cwd: &str,
+    stream: TcpStream,
+) -> Result<(), CargoMakeError> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut request = String::new();
+    let mut reader = BufReader::new(stream);
+    reader.read_line(&mut request)?;
+
+    let response = create_response(info, cwd, &request);
+
+    let mut line = serde_json::to_string(&response).map_err(io::Error::from)?;
+    line.push('\n');
+    reader.get_mut().write_all(line.as_bytes())?;
+
+    Ok(())
+}
+
+/// Runs the daemon of the current directory until the process is stopped
+pub(crate) fn run() -> Result<(), CargoMakeError> {
+    let info_file = match get_info_file() {
+        Some(file) => file,
+        None => {
+            return Err(CargoMakeError::NotFound(
+                "Unable to find the daemon storage directory.".to_string(),
+            ))
+        }
+    };
+
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+    let info = DaemonInfo {
+        pid: process::id(),
+        port: listener.local_addr()?.port(),
+        token: uuid::Uuid::new_v4().to_string(),
+        version: VERSION.to_string(),
+    };
+    write_text_file(
+        &info_file,
+        &serde_json::to_string_pretty(&info).map_err(io::Error::from)?,
+    )?;
+
+    let cwd = get_current_directory();
+    info!(
+        "Daemon listening on port: {} for directory: {}, press Ctrl+C to stop.",
+        info.port, &cwd
+    );
+
+    for stream in listener.incoming() {
+        let result = match stream {
+            Ok(stream) => handle_connection(&info, &cwd, stream),
+            Err(error) => Err(error.into()),
+        };
+
+        if let Err(error) = result {
+            warn!("Daemon request failed, {}", error);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use super::*;
+use std::thread;
+
+fn create_info() -> DaemonInfo {
+    DaemonInfo {
+        pid: 1,
+        port: 0,
+        token: "secret".to_string(),
+        version: VERSION.to_string(),
+    }
+}
+
+fn create_request(token: &str, cwd: &str) -> DaemonRequest {
+    DaemonRequest {
+        token: token.to_string(),
+        cwd: cwd.to_string(),
+        makefile: "./target/_temp/daemon/missing.toml".to_string(),
+        force: false,
+        strict: false,
+    }
+}
+
+#[test]
+fn get_info_file_json() {
+    let file = get_info_file().unwrap();
+
+    assert!(file.parent().unwrap().ends_with(DAEMON_DIRECTORY));
+    assert_eq!(file.extension().unwrap(), "json");
+}
+
+#[test]
+fn create_response_invalid_request() {
+    let response = create_response(&create_info(), "cwd", "not json");
+
+    assert_eq!(response.token, "secret");
+    assert!(response.descriptor.is_none());
+    assert!(response.error.unwrap().starts_with("Invalid request"));
+}
+
+#[test]
+fn create_response_invalid_token() {
+    let request = serde_json::to_string(&create_request("other", "cwd")).unwrap();
+
+    let response = create_response(&create_info(), "cwd", &request);
+
+    assert!(response.descriptor.is_none());
+    assert_eq!(response.error.unwrap(), "Invalid token.");
+}
+
+#[test]
+fn create_response_other_directory() {
+    let request = serde_json::to_string(&create_request("secret", "other")).unwrap();
+
+    let response = create_response(&create_info(), "cwd", &request);
+
+    assert!(response.descriptor.is_none());
+    assert!(response.error.unwrap().contains("cwd"));
+}
+
+#[test]
+fn send_request_roundtrip() {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let cwd = get_current_directory();
+
+    let server_cwd = cwd.clone();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        handle_connection(&create_info(), &server_cwd, stream).unwrap();
+    });
+
+    let response = send_request(port, &create_request("secret", &cwd)).unwrap();
+    server.join().unwrap();
+
+    assert_eq!(response.token, "secret");
+    assert!(response.error.is_none());
+    assert!(response.descriptor.is_some());
+}
+
+#[test]
+fn create_response_invalid_makefile() {
+    let makefile = "./target/_temp/daemon/invalid.toml";
+    write_text_file(makefile, "[tasks.bad\n").unwrap();
+
+    let mut request = create_request("secret", "cwd");
+    request.makefile = makefile.to_string();
+    request.force = true;
+    let request = serde_json::to_string(&request).unwrap();
+
+    let response = create_response(&create_info(), "cwd", &request);
+
+    assert!(response.descriptor.is_none());
+    assert!(response.error.unwrap().contains("invalid.toml"));
+}
+
+#[test]
+fn request_descriptor_disabled() {
+    envmnt::remove("CARGO_MAKE_DAEMON");
+
+    assert!(request_descriptor("./Makefile.toml", false).is_none());
+}
@@ -0,0 +1,439 @@
+//! # dashboard
+//!
+//! Renders an opt-in live terminal dashboard while parallel tasks are running: the status,
+//! duration and steps completion of each parallel task and a tail of its output.<br>
+//! The output of the parallel tasks is captured while the dashboard is shown and printed
+//! (grouped by task) once all of them are done.
+//!
+
+#[cfg(test)]
+#[path = "dashboard_test.rs"]
+mod dashboard_test;
+
+use crate::recursion_level;
+use crate::types::CliArgs;
+use once_cell::sync::Lazy;
+use std::cell::Cell;
+use std::io::{stderr, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+static REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+/// The amount of latest output lines shown for each task
+static TAIL_LINES: usize = 5;
+/// The maximum amount of output lines kept for each task
+static MAX_OUTPUT_LINES: usize = 10000;
+/// The maximum rendered line length (longer lines are truncated)
+static MAX_LINE_LENGTH: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl Status {
+    fn get_marker(&self) -> &'static str {
+        match self {
+            Status::Pending => "·",
+            Status::Running => "●",
+            Status::Succeeded => "✓",
+            Status::Failed => "✗",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Branch {
+    /// The parallel task name
+    name: String,
+    status: Status,
+    start_time: Instant,
+    duration: Option<Duration>,
+    /// The steps of the task flow (in execution order) and their status
+    steps: Vec<(String, Status)>,
+    output: Vec<String>,
+    /// The amount of output lines dropped due to the output lines limit
+    dropped_lines: usize,
+}
+
+#[derive(Debug, Default)]
+struct DashboardState {
+    branches: Vec<Branch>,
+}
+
+struct ActiveDashboard {
+    state: DashboardState,
+    start_time: Instant,
+    worker: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+}
+
+static ACTIVE: Lazy<Mutex<Option<ActiveDashboard>>> = Lazy::new(|| Mutex::new(None));
+
+thread_local! {
+    /// The parallel task (branch index) the current thread runs for
+    static CURRENT_BRANCH: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.ui {
+        envmnt::set_bool("CARGO_MAKE_UI", true);
+    }
+}
+
+fn should_render() -> bool {
+    // forked cargo-make processes are rendered by the top level process
+    envmnt::is("CARGO_MAKE_UI") && recursion_level::is_top() && stderr().is_terminal()
+}
+
+/// Returns true while the dashboard is shown
+pub(crate) fn is_active() -> bool {
+    match ACTIVE.lock() {
+        Ok(active) => active.is_some(),
+        Err(_) => false,
+    }
+}
+
+pub(crate) fn get_current_branch() -> Option<usize> {
+    CURRENT_BRANCH.with(|branch| branch.get())
+}
+
+pub(crate) fn set_current_branch(branch: Option<usize>) {
+    CURRENT_BRANCH.with(|current| current.set(branch));
+}
+
+fn truncate_line(line: &str) -> String {
+    if line.chars().count() > MAX_LINE_LENGTH {
+        // reset the colors in case a color sequence was truncated
+        let truncated: String = line.chars().take(MAX_LINE_LENGTH).collect();
+        format!("{}...\x1b[0m", truncated)
+    } else {
+        line.to_string()
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f64())
+}
+
+impl DashboardState {
+    fn new(names: &[String]) -> DashboardState {
+        let start_time = Instant::now();
+
+        DashboardState {
+            branches: names
+                .iter()
+                .map(|name| Branch {
+                    name: name.to_string(),
+                    status: Status::Running,
+                    start_time,
+                    duration: None,
+                    steps: vec![],
+                    output: vec![],
+                    dropped_lines: 0,
+                })
+                .collect(),
+        }
+    }
+
+    /// Sets the steps of the task flow, only the first (top level) execution plan of each
+    /// task is used
+    fn add_steps(&mut self, branch: usize, names: &[String]) {
+        if let Some(branch) = self.branches.get_mut(branch) {
+            if branch.steps.is_empty() {
+                branch.steps = names
+                    .iter()
+                    .map(|name| (name.to_string(), Status::Pending))
+                    .collect();
+            }
+        }
+    }
+
+    fn step_started(&mut self, branch: usize, name: &str) {
+        if let Some(branch) = self.branches.get_mut(branch) {
+            match branch
+                .steps
+                .iter_mut()
+                .find(|(step, status)| step == name && *status == Status::Pending)
+            {
+                Some(step) => step.1 = Status::Running,
+                // sub flow steps are not part of the top level execution plan
+                None => branch.steps.push((name.to_string(), Status::Running)),
+            }
+        }
+    }
+
+    fn step_finished(&mut self, branch: usize, name: &str, success: bool) {
+        if let Some(branch) = self.branches.get_mut(branch) {
+            if let Some(step) = branch
+                .steps
+                .iter_mut()
+                .rev()
+                .find(|(step, status)| step == name && *status == Status::Running)
+            {
+                step.1 = if success {
+                    Status::Succeeded
+                } else {
+                    Status::Failed
+                };
+            }
+        }
+    }
+
+    fn branch_finished(&mut self, branch: usize, success: bool) {
+        if let Some(branch) = self.branches.get_mut(branch) {
+            branch.status = if success {
+                Status::Succeeded
+            } else {
+                Status::Failed
+            };
+            branch.duration = Some(branch.start_time.elapsed());
+        }
+    }
+
+    fn add_line(&mut self, branch: usize, line: &str) {
+        if let Some(branch) = self.branches.get_mut(branch) {
+            if branch.output.len() >= MAX_OUTPUT_LINES {
+                branch.output.remove(0);
+                branch.dropped_lines += 1;
+            }
+            branch.output.push(line.to_string());
+        }
+    }
+
+    fn create_frame(&self, elapsed: Duration) -> String {
+        let done = self
+            .branches
+            .iter()
+            .filter(|branch| branch.status != Status::Running)
+            .count();
+        let failed = self
+            .branches
+            .iter()
+            .filter(|branch| branch.status == Status::Failed)
+            .count();
+
+        let mut frame = format!(
+            "cargo-make - {} parallel tasks ({} done, {} failed) - {}\n",
+            self.branches.len(),
+            done,
+            failed,
+            format_duration(elapsed)
+        );
+
+        let name_width = self
+            .branches
+            .iter()
+            .map(|branch| branch.name.len())
+            .max()
+            .unwrap_or(0);
+        for branch in &self.branches {
+            let completed = branch
+                .steps
+                .iter()
+                .filter(|(_, status)| *status == Status::Succeeded)
+                .count();
+            let steps: Vec<String> = branch
+                .steps
+                .iter()
+                .map(|(name, status)| format!("{} {}", status.get_marker(), name))
+                .collect();
+
+            frame.push_str(&truncate_line(&format!(
+                "\n{} {:width$}  {:>8}  {}/{} steps  {}",
+                branch.status.get_marker(),
+                &branch.name,
+                format_duration(
+                    branch
+                        .duration
+                        .unwrap_or_else(|| branch.start_time.elapsed())
+                ),
+                completed,
+                branch.steps.len(),
+                steps.join(" "),
+                width = name_width
+            )));
+            frame.push('\n');
+
+            let tail_start = branch.output.len().saturating_sub(TAIL_LINES);
+            for line in &branch.output[tail_start..] {
+                frame.push_str(&truncate_line(&format!("    {}", line)));
+                frame.push('\n');
+            }
+        }
+
+        frame
+    }
+}
+
+fn render(text: &str) {
+    let mut output = stderr();
+    // errors are ignored as the dashboard is purely cosmetic
+    if write!(output, "{}", text).is_ok() {
+        output.flush().unwrap_or(());
+    }
+}
+
+fn run_worker(stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::SeqCst) {
+        let frame = match ACTIVE.lock() {
+            Ok(active) => active
+                .as_ref()
+                .map(|active| active.state.create_frame(active.start_time.elapsed())),
+            Err(_) => None,
+        };
+
+        // move to the top left corner and clear the screen before drawing the frame
+        if let Some(frame) = frame {
+            render(&format!("\x1b[H\x1b[J{}", frame));
+        }
+
+        thread::park_timeout(REFRESH_INTERVAL);
+    }
+}
+
+/// Runs the provided function on the state of the current thread branch (if the dashboard is
+/// shown) and returns true if it was invoked
+fn update<F: FnOnce(&mut DashboardState, usize)>(function: F) -> bool {
+    let branch = match get_current_branch() {
+        Some(branch) => branch,
+        None => return false,
+    };
+
+    match ACTIVE.lock() {
+        Ok(mut active) => match active.as_mut() {
+            Some(active) => {
+                function(&mut active.state, branch);
+                true
+            }
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
+/// Sets the steps of the execution plan created for the current thread branch
+pub(crate) fn add_steps(names: &[String]) {
+    update(|state, branch| state.add_steps(branch, names));
+}
+
+pub(crate) fn step_started(name: &str) {
+    update(|state, branch| state.step_started(branch, name));
+}
+
+pub(crate) fn step_finished(name: &str, success: bool) {
+    update(|state, branch| state.step_finished(branch, name, success));
+}
+
+pub(crate) fn branch_finished(branch: usize, success: bool) {
+    if let Ok(mut active) = ACTIVE.lock() {
+        if let Some(active) = active.as_mut() {
+            active.state.branch_finished(branch, success);
+        }
+    }
+}
+
+/// Captures the output line of the current thread branch, returns false if the line is not
+/// captured and should be printed
+pub(crate) fn capture_line(line: &str) -> bool {
+    update(|state, branch| state.add_line(branch, line))
+}
+
+/// Shows the dashboard for the provided parallel tasks until this value is dropped.
+pub(crate) struct Dashboard {}
+
+impl Dashboard {
+    /// Starts the dashboard (if enabled), the parallel tasks are identified by their index
+    /// in the provided names
+    pub(crate) fn start(names: &[String]) -> Option<Dashboard> {
+        if !should_render() {
+            return None;
+        }
+
+        let mut active = ACTIVE.lock().ok()?;
+        // nested parallel tasks are shown as part of their parent task
+        if active.is_some() {
+            return None;
+        }
+
+        // switch to the alternate screen and hide the cursor
+        render("\x1b[?1049h\x1b[?25l");
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = stop.clone();
+        let handle = thread::spawn(move || run_worker(worker_stop));
+        *active = Some(ActiveDashboard {
+            state: DashboardState::new(names),
+            start_time: Instant::now(),
+            worker: Some((stop, handle)),
+        });
+
+        Some(Dashboard {})
+    }
+}
+
+/// Stops the dashboard (if shown), restores the terminal and prints the captured output
+fn close() {
+    let worker = match ACTIVE.lock() {
+        Ok(mut active) => active.as_mut().and_then(|active| active.worker.take()),
+        Err(_) => None,
+    };
+    if let Some((stop, handle)) = worker {
+        stop.store(true, Ordering::SeqCst);
+        handle.thread().unpark();
+        handle.join().unwrap_or(());
+    }
+
+    let state = match ACTIVE.lock() {
+        Ok(mut active) => active.take().map(|active| active.state),
+        Err(_) => None,
+    };
+
+    // the captured output is printed once the dashboard is no longer shown
+    if let Some(state) = state {
+        // restore the screen and cursor
+        render("\x1b[?25h\x1b[?1049l");
+
+        for branch in state.branches {
+            let status = match branch.status {
+                Status::Failed => "failed",
+                Status::Running => "did not finish",
+                _ => "done",
+            };
+            info!(
+                "Parallel Task: {} {} in {}",
+                &branch.name,
+                status,
+                format_duration(
+                    branch
+                        .duration
+                        .unwrap_or_else(|| branch.start_time.elapsed())
+                )
+            );
+
+            if branch.dropped_lines > 0 {
+                println!("... {} output lines dropped", branch.dropped_lines);
+            }
+            for line in &branch.output {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+/// Closes the dashboard before the process exits on an error, so the terminal is restored and
+/// the captured output and the error are shown
+pub(crate) fn abort() {
+    close();
+}
+
+impl Drop for Dashboard {
+    fn drop(&mut self) {
+        close();
+    }
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+fn create_state() -> DashboardState {
+    DashboardState::new(&["lint".to_string(), "test".to_string()])
+}
+
+#[test]
+fn new_branches_running() {
+    let state = create_state();
+
+    assert_eq!(state.branches.len(), 2);
+    assert_eq!(state.branches[1].name, "test");
+    assert_eq!(state.branches[1].status, Status::Running);
+    assert!(state.branches[1].duration.is_none());
+}
+
+#[test]
+fn add_steps_first_plan_only() {
+    let mut state = create_state();
+
+    state.add_steps(0, &["format".to_string(), "clippy".to_string()]);
+    state.add_steps(0, &["other".to_string()]);
+    state.add_steps(5, &["invalid".to_string()]);
+
+    assert_eq!(
+        state.branches[0].steps,
+        vec![
+            ("format".to_string(), Status::Pending),
+            ("clippy".to_string(), Status::Pending)
+        ]
+    );
+    assert!(state.branches[1].steps.is_empty());
+}
+
+#[test]
+fn step_started_and_finished() {
+    let mut state = create_state();
+    state.add_steps(0, &["format".to_string(), "clippy".to_string()]);
+
+    state.step_started(0, "format");
+    state.step_finished(0, "format", true);
+    state.step_started(0, "clippy");
+    state.step_started(0, "sub-task");
+    state.step_finished(0, "sub-task", false);
+
+    assert_eq!(
+        state.branches[0].steps,
+        vec![
+            ("format".to_string(), Status::Succeeded),
+            ("clippy".to_string(), Status::Running),
+            ("sub-task".to_string(), Status::Failed)
+        ]
+    );
+}
+
+#[test]
+fn branch_finished_status() {
+    let mut state = create_state();
+
+    state.branch_finished(0, true);
+    state.branch_finished(1, false);
+
+    assert_eq!(state.branches[0].status, Status::Succeeded);
+    assert_eq!(state.branches[1].status, Status::Failed);
+    assert!(state.branches[1].duration.is_some());
+}
+
+#[test]
+fn add_line_limit() {
+    let mut state = create_state();
+
+    for index in 0..MAX_OUTPUT_LINES + 2 {
+        state.add_line(1, &index.to_string());
+    }
+
+    assert_eq!(state.branches[1].output.len(), MAX_OUTPUT_LINES);
+    assert_eq!(state.branches[1].output[0], "2");
+    assert_eq!(state.branches[1].dropped_lines, 2);
+    assert!(state.branches[0].output.is_empty());
+}
+
+#[test]
+fn create_frame_values() {
+    let mut state = create_state();
+    state.add_steps(0, &["format".to_string(), "clippy".to_string()]);
+    state.step_started(0, "format");
+    state.step_finished(0, "format", true);
+    state.step_started(0, "clippy");
+    for index in 0..10 {
+        state.add_line(0, &format!("line {}", index));
+    }
+    state.branch_finished(1, false);
+
+    let frame = state.create_frame(Duration::from_millis(1500));
+
+    assert!(frame.starts_with("cargo-make - 2 parallel tasks (1 done, 1 failed) - 1.50s\n"));
+    assert!(frame.contains("● lint"));
+    assert!(frame.contains("1/2 steps  ✓ format ● clippy"));
+    assert!(frame.contains("✗ test"));
+    assert!(frame.contains("    line 9\n"));
+    assert!(frame.contains("    line 5\n"));
+    assert!(!frame.contains("line 4"));
+}
+
+#[test]
+fn truncate_line_short() {
+    assert_eq!(truncate_line("short"), "short");
+}
+
+#[test]
+fn truncate_line_long() {
+    let line = "a".repeat(MAX_LINE_LENGTH + 10);
+
+    let truncated = truncate_line(&line);
+
+    assert!(truncated.starts_with(&"a".repeat(MAX_LINE_LENGTH)));
+    assert!(truncated.ends_with("...\x1b[0m"));
+}
+
+#[test]
+fn capture_line_not_active() {
+    set_current_branch(Some(0));
+
+    assert!(!capture_line("line"));
+
+    set_current_branch(None);
+}
+
+#[test]
+fn abort_not_active() {
+    abort();
+
+    assert!(!is_active());
+}
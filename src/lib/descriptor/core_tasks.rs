@@ -0,0 +1,187 @@
+//! # core_tasks
+//!
+//! Selects and filters the loaded core tasks based on the config core_tasks section.
+//!
+
+#[cfg(test)]
+#[path = "core_tasks_test.rs"]
+mod core_tasks_test;
+
+use crate::descriptor::references;
+use crate::execution_plan;
+use crate::types::{Config, ConfigSection, ModifyConfig, Task};
+use indexmap::IndexMap;
+use regex::Regex;
+
+static DEFAULT_NAMESPACE: &str = "core";
+
+fn get_namespace(config: &ConfigSection) -> Option<String> {
+    match config.core_tasks {
+        Some(ref core_tasks) => match core_tasks.namespace {
+            Some(ref namespace) => Some(namespace.to_string()),
+            None if core_tasks.namespaced.unwrap_or(false) => Some(DEFAULT_NAMESPACE.to_string()),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Returns the core tasks modifications, where the core_tasks namespace is used in case
+/// the modify_core_tasks namespace is not defined
+pub(crate) fn get_modify_config(config: &ConfigSection) -> Option<ModifyConfig> {
+    let namespace = get_namespace(config);
+
+    match config.modify_core_tasks {
+        Some(ref modify_config) => match modify_config.namespace {
+            Some(_) => Some(modify_config.clone()),
+            None => Some(ModifyConfig {
+                private: modify_config.private,
+                namespace,
+            }),
+        },
+        None => namespace.map(|namespace| ModifyConfig {
+            private: None,
+            namespace: Some(namespace),
+        }),
+    }
+}
+
+/// Returns the core task groups to load (all groups are loaded if not defined)
+pub(crate) fn get_groups(config: &ConfigSection) -> Option<Vec<String>> {
+    config
+        .core_tasks
+        .as_ref()
+        .and_then(|core_tasks| core_tasks.groups.clone())
+}
+
+fn get_skip_patterns(config: &ConfigSection) -> Vec<Regex> {
+    let patterns = match config.core_tasks {
+        Some(ref core_tasks) => match core_tasks.skip_patterns {
+            Some(ref patterns) => patterns,
+            None => return vec![],
+        },
+        None => return vec![],
+    };
+
+    let mut regexes = vec![];
+    for pattern in patterns {
+        match Regex::new(pattern) {
+            Ok(regex) => regexes.push(regex),
+            Err(_) => warn!("Invalid core tasks skip pattern provided: {}", pattern),
+        }
+    }
+
+    regexes
+}
+
+fn is_resolved(
+    core_config: &Config,
+    external_tasks: Option<&IndexMap<String, Task>>,
+    name: &str,
+) -> bool {
+    core_config.tasks.contains_key(name)
+        || external_tasks.is_some_and(|tasks| tasks.contains_key(name))
+        || execution_plan::is_forwarded_task_name(core_config, name)
+}
+
+/// Removes the core tasks which reference tasks that are neither core nor external tasks,
+/// for example flows invoking tasks of core task groups which were not loaded.
+fn remove_unresolved(core_config: &mut Config, external_tasks: Option<&IndexMap<String, Task>>) {
+    loop {
+        let unresolved: Vec<String> = core_config
+            .tasks
+            .iter()
+            .filter(|(_, task)| {
+                references::get_references(task)
+                    .iter()
+                    .any(|(_, target)| !is_resolved(core_config, external_tasks, target))
+            })
+            .map(|(name, _)| name.to_string())
+            .collect();
+
+        if unresolved.is_empty() {
+            return;
+        }
+
+        for name in unresolved {
+            debug!("Skipping core task with unresolved references: {}", &name);
+            core_config.tasks.shift_remove(&name);
+        }
+    }
+}
+
+/// Removes the core tasks matching any of the config core_tasks skip patterns.<br>
+/// The patterns are matched against the task names without the core tasks namespace.<br>
+/// In case only some core task groups are loaded, the core tasks referencing tasks of the
+/// other groups are removed as well.
+pub(crate) fn filter(
+    core_config: &mut Config,
+    config: &ConfigSection,
+    external_tasks: Option<&IndexMap<String, Task>>,
+) {
+    if get_groups(config).is_some() {
+        remove_unresolved(core_config, external_tasks);
+    }
+
+    let skip_patterns = get_skip_patterns(config);
+    if skip_patterns.is_empty() {
+        return;
+    }
+
+    let prefix = match get_modify_config(config) {
+        Some(ref modify_config) => modify_config.get_namespace_prefix(),
+        None => "".to_string(),
+    };
+
+    core_config.tasks.retain(|name, _| {
+        let core_name = name.strip_prefix(&prefix).unwrap_or(name);
+        let skip = skip_patterns
+            .iter()
+            .any(|pattern| pattern.is_match(core_name));
+
+        if skip {
+            debug!("Skipping core task: {}", name);
+        }
+
+        !skip
+    });
+}
+
+/// Adds aliases with the original core task names for the namespaced core tasks, in case the
+/// config core_tasks aliases attribute is set.<br>
+/// Tasks defined with the original names are not replaced.
+pub(crate) fn add_aliases(config: &mut Config) {
+    let enabled = config
+        .config
+        .core_tasks
+        .as_ref()
+        .and_then(|core_tasks| core_tasks.aliases)
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+
+    let prefix = match get_modify_config(&config.config) {
+        Some(ref modify_config) => modify_config.get_namespace_prefix(),
+        None => "".to_string(),
+    };
+    if prefix.is_empty() {
+        return;
+    }
+
+    let mut aliases = vec![];
+    for name in config.tasks.keys() {
+        if let Some(core_name) = name.strip_prefix(&prefix) {
+            if !core_name.is_empty() && !config.tasks.contains_key(core_name) {
+                aliases.push((core_name.to_string(), name.to_string()));
+            }
+        }
+    }
+
+    for (alias_name, name) in aliases {
+        debug!("Creating core task alias: {} -> {}", &alias_name, &name);
+        let mut task = Task::new();
+        task.alias = Some(name);
+        config.tasks.insert(alias_name, task);
+    }
+}
@@ -0,0 +1,282 @@
+use super::*;
+use crate::types::{CoreTasksConfig, DependencyIdentifier, RunTaskInfo, Task};
+use indexmap::IndexMap;
+
+fn create_config_section(
+    skip_patterns: Option<Vec<&str>>,
+    namespace: Option<&str>,
+) -> ConfigSection {
+    let mut config = ConfigSection::new();
+    config.core_tasks = Some(CoreTasksConfig {
+        groups: None,
+        skip_patterns: skip_patterns
+            .map(|patterns| patterns.iter().map(|pattern| pattern.to_string()).collect()),
+        namespace: namespace.map(|namespace| namespace.to_string()),
+        namespaced: None,
+        aliases: None,
+    });
+
+    config
+}
+
+fn create_core_config(names: &[&str]) -> Config {
+    let mut tasks = IndexMap::new();
+    for name in names {
+        tasks.insert(name.to_string(), Task::new());
+    }
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn create_flow_task(dependencies: &[&str]) -> Task {
+    let mut task = Task::new();
+    task.dependencies = Some(
+        dependencies
+            .iter()
+            .map(|name| DependencyIdentifier::Name(name.to_string()))
+            .collect(),
+    );
+
+    task
+}
+
+fn get_names(config: &Config) -> Vec<&str> {
+    config.tasks.keys().map(|name| name.as_str()).collect()
+}
+
+#[test]
+fn get_modify_config_none() {
+    let modify_config = get_modify_config(&ConfigSection::new());
+
+    assert!(modify_config.is_none());
+}
+
+#[test]
+fn get_modify_config_core_tasks_namespace() {
+    let modify_config = get_modify_config(&create_config_section(None, Some("core"))).unwrap();
+
+    assert!(modify_config.private.is_none());
+    assert_eq!(modify_config.namespace.unwrap(), "core");
+}
+
+#[test]
+fn get_modify_config_modify_core_tasks_without_namespace() {
+    let mut config = create_config_section(None, Some("core"));
+    config.modify_core_tasks = Some(ModifyConfig {
+        private: Some(true),
+        namespace: None,
+    });
+
+    let modify_config = get_modify_config(&config).unwrap();
+
+    assert!(modify_config.private.unwrap());
+    assert_eq!(modify_config.namespace.unwrap(), "core");
+}
+
+#[test]
+fn get_modify_config_modify_core_tasks_namespace_first() {
+    let mut config = create_config_section(None, Some("core"));
+    config.modify_core_tasks = Some(ModifyConfig {
+        private: None,
+        namespace: Some("default".to_string()),
+    });
+
+    let modify_config = get_modify_config(&config).unwrap();
+
+    assert_eq!(modify_config.namespace.unwrap(), "default");
+}
+
+#[test]
+fn get_modify_config_namespaced() {
+    let mut config = create_config_section(None, None);
+    config.core_tasks.as_mut().unwrap().namespaced = Some(true);
+
+    let modify_config = get_modify_config(&config).unwrap();
+
+    assert!(modify_config.private.is_none());
+    assert_eq!(modify_config.namespace.unwrap(), "core");
+}
+
+#[test]
+fn get_modify_config_namespaced_custom_namespace() {
+    let mut config = create_config_section(None, Some("default"));
+    config.core_tasks.as_mut().unwrap().namespaced = Some(true);
+
+    let modify_config = get_modify_config(&config).unwrap();
+
+    assert_eq!(modify_config.namespace.unwrap(), "default");
+}
+
+#[test]
+fn get_modify_config_not_namespaced() {
+    let mut config = create_config_section(None, None);
+    config.core_tasks.as_mut().unwrap().namespaced = Some(false);
+
+    assert!(get_modify_config(&config).is_none());
+}
+
+#[test]
+fn get_groups_none() {
+    assert!(get_groups(&ConfigSection::new()).is_none());
+    assert!(get_groups(&create_config_section(None, None)).is_none());
+}
+
+#[test]
+fn get_groups_defined() {
+    let mut config = create_config_section(None, None);
+    config.core_tasks.as_mut().unwrap().groups = Some(vec!["build".to_string()]);
+
+    assert_eq!(get_groups(&config).unwrap(), vec!["build".to_string()]);
+}
+
+#[test]
+fn filter_no_patterns() {
+    let mut core_config = create_core_config(&["build", "wasm-pack-base"]);
+
+    filter(&mut core_config, &create_config_section(None, None), None);
+
+    assert_eq!(get_names(&core_config), vec!["build", "wasm-pack-base"]);
+}
+
+#[test]
+fn filter_skip_patterns() {
+    let mut core_config = create_core_config(&[
+        "build",
+        "wasm-pack-base",
+        "wasm-pack-test",
+        "coverage",
+        "coverage-kcov",
+    ]);
+
+    filter(
+        &mut core_config,
+        &create_config_section(Some(vec!["^wasm-", "^coverage-kcov$"]), None),
+        None,
+    );
+
+    assert_eq!(get_names(&core_config), vec!["build", "coverage"]);
+}
+
+#[test]
+fn filter_skip_patterns_with_namespace() {
+    let mut core_config =
+        create_core_config(&["core::build", "core::wasm-pack-base", "core::coverage-kcov"]);
+
+    filter(
+        &mut core_config,
+        &create_config_section(Some(vec!["^wasm-", "^coverage-kcov$"]), Some("core")),
+        None,
+    );
+
+    assert_eq!(get_names(&core_config), vec!["core::build"]);
+}
+
+#[test]
+fn filter_invalid_pattern_ignored() {
+    let mut core_config = create_core_config(&["build", "wasm-pack-base"]);
+
+    filter(
+        &mut core_config,
+        &create_config_section(Some(vec!["^wasm-(", "^build$"]), None),
+        None,
+    );
+
+    assert_eq!(get_names(&core_config), vec!["wasm-pack-base"]);
+}
+
+#[test]
+fn filter_groups_unresolved_references() {
+    let mut core_config = create_core_config(&["build", "test"]);
+    core_config
+        .tasks
+        .insert("ci-flow".to_string(), create_flow_task(&["build", "test"]));
+    core_config
+        .tasks
+        .insert("dev-flow".to_string(), create_flow_task(&["docs"]));
+    core_config
+        .tasks
+        .insert("release-flow".to_string(), create_flow_task(&["dev-flow"]));
+    let mut run_task = Task::new();
+    run_task.run_task = Some(RunTaskInfo::Name("coverage".to_string()));
+    core_config
+        .tasks
+        .insert("ci-coverage".to_string(), run_task);
+    let mut external_tasks = IndexMap::new();
+    external_tasks.insert("coverage".to_string(), Task::new());
+
+    let mut config = create_config_section(None, None);
+    config.core_tasks.as_mut().unwrap().groups = Some(vec!["build".to_string()]);
+
+    filter(&mut core_config, &config, Some(&external_tasks));
+
+    assert_eq!(
+        get_names(&core_config),
+        vec!["build", "test", "ci-flow", "ci-coverage"]
+    );
+}
+
+#[test]
+fn filter_no_groups_unresolved_references_kept() {
+    let mut core_config = create_core_config(&["build"]);
+    core_config
+        .tasks
+        .insert("dev-flow".to_string(), create_flow_task(&["docs"]));
+
+    filter(&mut core_config, &create_config_section(None, None), None);
+
+    assert_eq!(get_names(&core_config), vec!["build", "dev-flow"]);
+}
+
+#[test]
+fn add_aliases_disabled() {
+    let mut config = create_core_config(&["core::build", "core::test"]);
+    config.config = create_config_section(None, Some("core"));
+
+    add_aliases(&mut config);
+
+    assert_eq!(get_names(&config), vec!["core::build", "core::test"]);
+}
+
+#[test]
+fn add_aliases_no_namespace() {
+    let mut config = create_core_config(&["build", "test"]);
+    config.config = create_config_section(None, None);
+    config.config.core_tasks.as_mut().unwrap().aliases = Some(true);
+
+    add_aliases(&mut config);
+
+    assert_eq!(get_names(&config), vec!["build", "test"]);
+}
+
+#[test]
+fn add_aliases_namespaced() {
+    let mut config = create_core_config(&["core::build", "core::test", "test", "custom"]);
+    config.config = create_config_section(None, None);
+    config.config.core_tasks.as_mut().unwrap().namespaced = Some(true);
+    config.config.core_tasks.as_mut().unwrap().aliases = Some(true);
+
+    add_aliases(&mut config);
+
+    assert_eq!(
+        get_names(&config),
+        vec!["core::build", "core::test", "test", "custom", "build"]
+    );
+    assert_eq!(
+        config.tasks.get("build").unwrap().alias.clone().unwrap(),
+        "core::build"
+    );
+    assert!(config.tasks.get("test").unwrap().alias.is_none());
+}
@@ -0,0 +1,82 @@
+//! # coverage
+//!
+//! Converts the makefile coverage section to the env vars consumed by the core coverage tasks.
+//!
+
+#[cfg(test)]
+#[path = "coverage_test.rs"]
+mod coverage_test;
+
+use crate::types::{CoverageConfig, CoverageFormat, CoverageProvider, EnvValue};
+use indexmap::IndexMap;
+
+static DEFAULT_OUTPUT_DIRECTORY: &str = "target/coverage";
+
+pub(crate) fn merge_coverage_config(
+    base: Option<CoverageConfig>,
+    extended: Option<CoverageConfig>,
+) -> Option<CoverageConfig> {
+    match base {
+        Some(mut base_coverage) => match extended {
+            Some(ref extended_coverage) => {
+                base_coverage.extend(extended_coverage);
+                Some(base_coverage)
+            }
+            None => Some(base_coverage),
+        },
+        None => extended,
+    }
+}
+
+fn get_provider_name(provider: CoverageProvider) -> String {
+    match provider {
+        CoverageProvider::LlvmCov => "llvm-cov".to_string(),
+        CoverageProvider::Tarpaulin => "tarpaulin".to_string(),
+        CoverageProvider::Grcov => "grcov".to_string(),
+        CoverageProvider::Kcov => "kcov".to_string(),
+    }
+}
+
+fn get_format_name(format: CoverageFormat) -> String {
+    match format {
+        CoverageFormat::Lcov => "lcov".to_string(),
+        CoverageFormat::Html => "html".to_string(),
+        CoverageFormat::Cobertura => "cobertura".to_string(),
+    }
+}
+
+/// Returns the coverage env vars (provider, report formats and output directory)
+pub(crate) fn create_env(coverage_config: &CoverageConfig) -> IndexMap<String, EnvValue> {
+    let mut env = IndexMap::new();
+
+    if let Some(provider) = coverage_config.provider {
+        env.insert(
+            "CARGO_MAKE_COVERAGE_PROVIDER".to_string(),
+            EnvValue::Value(get_provider_name(provider)),
+        );
+    }
+
+    let formats = match coverage_config.formats {
+        Some(ref formats) if !formats.is_empty() => formats
+            .iter()
+            .map(|format| get_format_name(*format))
+            .collect(),
+        _ => vec![get_format_name(CoverageFormat::Lcov)],
+    };
+    env.insert(
+        "CARGO_MAKE_COVERAGE_FORMATS".to_string(),
+        EnvValue::Value(formats.join(";")),
+    );
+
+    env.insert(
+        "CARGO_MAKE_COVERAGE_REPORT_DIRECTORY".to_string(),
+        EnvValue::Value(
+            coverage_config
+                .output_directory
+                .clone()
+                .unwrap_or(DEFAULT_OUTPUT_DIRECTORY.to_string()),
+        ),
+    );
+
+    env
+}
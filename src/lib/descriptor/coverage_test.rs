@@ -0,0 +1,96 @@
+use super::*;
+
+fn get_env_value(env: &IndexMap<String, EnvValue>, key: &str) -> String {
+    match env.get(key).unwrap() {
+        EnvValue::Value(ref value) => value.to_string(),
+        _ => panic!("invalid env value"),
+    }
+}
+
+#[test]
+fn merge_coverage_config_both_none() {
+    let coverage_config = merge_coverage_config(None, None);
+
+    assert!(coverage_config.is_none());
+}
+
+#[test]
+fn merge_coverage_config_base_only() {
+    let mut base = CoverageConfig::new();
+    base.provider = Some(CoverageProvider::Tarpaulin);
+
+    let coverage_config = merge_coverage_config(Some(base.clone()), None).unwrap();
+
+    assert_eq!(coverage_config, base);
+}
+
+#[test]
+fn merge_coverage_config_extended_only() {
+    let mut extended = CoverageConfig::new();
+    extended.provider = Some(CoverageProvider::Grcov);
+
+    let coverage_config = merge_coverage_config(None, Some(extended.clone())).unwrap();
+
+    assert_eq!(coverage_config, extended);
+}
+
+#[test]
+fn merge_coverage_config_both_defined() {
+    let mut base = CoverageConfig::new();
+    base.provider = Some(CoverageProvider::Tarpaulin);
+    base.output_directory = Some("reports".to_string());
+    let mut extended = CoverageConfig::new();
+    extended.provider = Some(CoverageProvider::LlvmCov);
+    extended.formats = Some(vec![CoverageFormat::Html]);
+
+    let coverage_config = merge_coverage_config(Some(base), Some(extended)).unwrap();
+
+    assert_eq!(coverage_config.provider.unwrap(), CoverageProvider::LlvmCov);
+    assert_eq!(coverage_config.formats.unwrap(), vec![CoverageFormat::Html]);
+    assert_eq!(coverage_config.output_directory.unwrap(), "reports");
+}
+
+#[test]
+fn create_env_defaults() {
+    let env = create_env(&CoverageConfig::new());
+
+    assert!(env.get("CARGO_MAKE_COVERAGE_PROVIDER").is_none());
+    assert_eq!(get_env_value(&env, "CARGO_MAKE_COVERAGE_FORMATS"), "lcov");
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_COVERAGE_REPORT_DIRECTORY"),
+        "target/coverage"
+    );
+}
+
+#[test]
+fn create_env_all_values() {
+    let mut coverage_config = CoverageConfig::new();
+    coverage_config.provider = Some(CoverageProvider::LlvmCov);
+    coverage_config.formats = Some(vec![CoverageFormat::Lcov, CoverageFormat::Cobertura]);
+    coverage_config.output_directory = Some("reports".to_string());
+
+    let env = create_env(&coverage_config);
+
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_COVERAGE_PROVIDER"),
+        "llvm-cov"
+    );
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_COVERAGE_FORMATS"),
+        "lcov;cobertura"
+    );
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_COVERAGE_REPORT_DIRECTORY"),
+        "reports"
+    );
+}
+
+#[test]
+fn create_env_empty_formats() {
+    let mut coverage_config = CoverageConfig::new();
+    coverage_config.formats = Some(vec![]);
+
+    let env = create_env(&coverage_config);
+
+    assert_eq!(get_env_value(&env, "CARGO_MAKE_COVERAGE_FORMATS"), "lcov");
+}
@@ -0,0 +1,163 @@
+//! # cross
+//!
+//! Dynamically creates the per target cross compilation tasks based on the makefile cross section.
+//!
+
+#[cfg(test)]
+#[path = "cross_test.rs"]
+mod cross_test;
+
+use crate::types::{
+    CrossConfig, CrossMode, DependencyIdentifier, EnvValue, RunTaskDetails, RunTaskInfo,
+    RunTaskName, Task,
+};
+use indexmap::IndexMap;
+
+static DEFAULT_ARTIFACTS_DIRECTORY: &str = "target/cross";
+static CATEGORY: &str = "Cross";
+
+pub(crate) fn merge_cross_config(
+    base: Option<CrossConfig>,
+    extended: Option<CrossConfig>,
+) -> Option<CrossConfig> {
+    match base {
+        Some(mut base_cross) => match extended {
+            Some(ref extended_cross) => {
+                base_cross.extend(extended_cross);
+                Some(base_cross)
+            }
+            None => Some(base_cross),
+        },
+        None => extended,
+    }
+}
+
+fn get_mode_name(mode: CrossMode) -> String {
+    match mode {
+        CrossMode::Auto => "auto".to_string(),
+        CrossMode::Cross => "cross".to_string(),
+        CrossMode::Cargo => "cargo".to_string(),
+    }
+}
+
+fn create_env(cross_config: &CrossConfig, target: &str) -> IndexMap<String, EnvValue> {
+    let release = cross_config.release.unwrap_or(false);
+    let (profile_flag, profile_directory) = if release {
+        ("--release", "release")
+    } else {
+        ("", "debug")
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CROSS_TARGET".to_string(),
+        EnvValue::Value(target.to_string()),
+    );
+    env.insert(
+        "CARGO_MAKE_CROSS_MODE".to_string(),
+        EnvValue::Value(get_mode_name(cross_config.mode.unwrap_or(CrossMode::Auto))),
+    );
+    env.insert(
+        "CARGO_MAKE_CROSS_PROFILE_FLAG".to_string(),
+        EnvValue::Value(profile_flag.to_string()),
+    );
+    env.insert(
+        "CARGO_MAKE_CROSS_PROFILE_DIRECTORY".to_string(),
+        EnvValue::Value(profile_directory.to_string()),
+    );
+    env.insert(
+        "CARGO_MAKE_CROSS_ARTIFACTS_DIRECTORY".to_string(),
+        EnvValue::Value(
+            cross_config
+                .artifacts_directory
+                .clone()
+                .unwrap_or(DEFAULT_ARTIFACTS_DIRECTORY.to_string()),
+        ),
+    );
+
+    env
+}
+
+fn create_target_task(
+    cross_config: &CrossConfig,
+    target: &str,
+    description: String,
+    core_tasks: Vec<String>,
+) -> Task {
+    let mut task = Task::new();
+    task.description = Some(description);
+    task.category = Some(CATEGORY.to_string());
+    task.env = Some(create_env(cross_config, target));
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(core_tasks),
+        fork: None,
+        parallel: None,
+        cleanup_task: None,
+        args: None,
+    }));
+
+    task
+}
+
+fn create_flow_task(description: &str, task_names: Vec<String>) -> Task {
+    let mut task = Task::new();
+    task.description = Some(description.to_string());
+    task.category = Some(CATEGORY.to_string());
+    task.dependencies = Some(
+        task_names
+            .into_iter()
+            .map(DependencyIdentifier::Name)
+            .collect(),
+    );
+
+    task
+}
+
+/// Returns the build/test tasks of each target defined in the cross section and the flow tasks
+/// which invoke them for all targets.<br>
+/// The core prefix is the namespace prefix of the core tasks invoked by the target tasks.
+pub(crate) fn create_tasks(cross_config: &CrossConfig, core_prefix: &str) -> Vec<(String, Task)> {
+    let targets = match cross_config.targets {
+        Some(ref targets) if !targets.is_empty() => targets,
+        _ => return vec![],
+    };
+
+    let mut tasks = vec![];
+    let mut build_tasks = vec![];
+    let mut test_tasks = vec![];
+    for target in targets {
+        let build_task_name = format!("cross-build-{}", target);
+        let build_task = create_target_task(
+            cross_config,
+            target,
+            format!("Builds the crate for the {} target.", target),
+            vec![
+                format!("{}cross-target-build", core_prefix),
+                format!("{}cross-target-artifacts", core_prefix),
+            ],
+        );
+        tasks.push((build_task_name.clone(), build_task));
+        build_tasks.push(build_task_name);
+
+        let test_task_name = format!("cross-test-{}", target);
+        let test_task = create_target_task(
+            cross_config,
+            target,
+            format!("Runs the tests for the {} target.", target),
+            vec![format!("{}cross-target-test", core_prefix)],
+        );
+        tasks.push((test_task_name.clone(), test_task));
+        test_tasks.push(test_task_name);
+    }
+
+    tasks.push((
+        "cross-build".to_string(),
+        create_flow_task("Builds the crate for all the cross targets.", build_tasks),
+    ));
+    tasks.push((
+        "cross-test".to_string(),
+        create_flow_task("Runs the tests for all the cross targets.", test_tasks),
+    ));
+
+    tasks
+}
@@ -0,0 +1,198 @@
+use super::*;
+
+fn create_cross_config() -> CrossConfig {
+    let mut cross_config = CrossConfig::new();
+    cross_config.targets = Some(vec![
+        "aarch64-unknown-linux-gnu".to_string(),
+        "x86_64-pc-windows-gnu".to_string(),
+    ]);
+
+    cross_config
+}
+
+fn get_env_value(task: &Task, key: &str) -> String {
+    match task.env.as_ref().unwrap().get(key).unwrap() {
+        EnvValue::Value(ref value) => value.to_string(),
+        _ => panic!("invalid env value"),
+    }
+}
+
+#[test]
+fn merge_cross_config_both_none() {
+    let cross_config = merge_cross_config(None, None);
+
+    assert!(cross_config.is_none());
+}
+
+#[test]
+fn merge_cross_config_base_only() {
+    let cross_config = merge_cross_config(Some(create_cross_config()), None).unwrap();
+
+    assert_eq!(cross_config, create_cross_config());
+}
+
+#[test]
+fn merge_cross_config_extended_only() {
+    let cross_config = merge_cross_config(None, Some(create_cross_config())).unwrap();
+
+    assert_eq!(cross_config, create_cross_config());
+}
+
+#[test]
+fn merge_cross_config_both_defined() {
+    let mut base = create_cross_config();
+    base.release = Some(true);
+    let mut extended = CrossConfig::new();
+    extended.targets = Some(vec!["wasm32-unknown-unknown".to_string()]);
+    extended.mode = Some(CrossMode::Cargo);
+
+    let cross_config = merge_cross_config(Some(base), Some(extended)).unwrap();
+
+    assert_eq!(
+        cross_config.targets.unwrap(),
+        vec!["wasm32-unknown-unknown".to_string()]
+    );
+    assert_eq!(cross_config.mode.unwrap(), CrossMode::Cargo);
+    assert!(cross_config.release.unwrap());
+    assert!(cross_config.artifacts_directory.is_none());
+}
+
+#[test]
+fn create_tasks_no_targets() {
+    assert!(create_tasks(&CrossConfig::new(), "").is_empty());
+
+    let mut cross_config = CrossConfig::new();
+    cross_config.targets = Some(vec![]);
+    assert!(create_tasks(&cross_config, "").is_empty());
+}
+
+#[test]
+fn create_tasks_names() {
+    let tasks = create_tasks(&create_cross_config(), "");
+
+    let names: Vec<&str> = tasks.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "cross-build-aarch64-unknown-linux-gnu",
+            "cross-test-aarch64-unknown-linux-gnu",
+            "cross-build-x86_64-pc-windows-gnu",
+            "cross-test-x86_64-pc-windows-gnu",
+            "cross-build",
+            "cross-test",
+        ]
+    );
+}
+
+#[test]
+fn create_tasks_default_env() {
+    let tasks = create_tasks(&create_cross_config(), "");
+
+    let task = &tasks[0].1;
+    assert_eq!(task.category.clone().unwrap(), "Cross");
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_TARGET"),
+        "aarch64-unknown-linux-gnu"
+    );
+    assert_eq!(get_env_value(task, "CARGO_MAKE_CROSS_MODE"), "auto");
+    assert_eq!(get_env_value(task, "CARGO_MAKE_CROSS_PROFILE_FLAG"), "");
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_PROFILE_DIRECTORY"),
+        "debug"
+    );
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_ARTIFACTS_DIRECTORY"),
+        "target/cross"
+    );
+}
+
+#[test]
+fn create_tasks_custom_env() {
+    let mut cross_config = create_cross_config();
+    cross_config.mode = Some(CrossMode::Cross);
+    cross_config.release = Some(true);
+    cross_config.artifacts_directory = Some("dist".to_string());
+
+    let tasks = create_tasks(&cross_config, "");
+
+    let task = &tasks[1].1;
+    assert_eq!(get_env_value(task, "CARGO_MAKE_CROSS_MODE"), "cross");
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_PROFILE_FLAG"),
+        "--release"
+    );
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_PROFILE_DIRECTORY"),
+        "release"
+    );
+    assert_eq!(
+        get_env_value(task, "CARGO_MAKE_CROSS_ARTIFACTS_DIRECTORY"),
+        "dist"
+    );
+}
+
+#[test]
+fn create_tasks_run_core_tasks() {
+    let tasks = create_tasks(&create_cross_config(), "");
+
+    match tasks[0].1.run_task {
+        Some(RunTaskInfo::Details(ref details)) => match details.name {
+            RunTaskName::Multiple(ref names) => assert_eq!(
+                names,
+                &vec![
+                    "cross-target-build".to_string(),
+                    "cross-target-artifacts".to_string()
+                ]
+            ),
+            _ => panic!("invalid run task name"),
+        },
+        _ => panic!("invalid run task"),
+    };
+
+    match tasks[1].1.run_task {
+        Some(RunTaskInfo::Details(ref details)) => match details.name {
+            RunTaskName::Multiple(ref names) => {
+                assert_eq!(names, &vec!["cross-target-test".to_string()])
+            }
+            _ => panic!("invalid run task name"),
+        },
+        _ => panic!("invalid run task"),
+    };
+}
+
+#[test]
+fn create_tasks_flow_dependencies() {
+    let tasks = create_tasks(&create_cross_config(), "");
+
+    let (_, build_flow) = &tasks[4];
+    let dependencies: Vec<&str> = build_flow
+        .dependencies
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|dependency| dependency.name())
+        .collect();
+    assert_eq!(
+        dependencies,
+        vec![
+            "cross-build-aarch64-unknown-linux-gnu",
+            "cross-build-x86_64-pc-windows-gnu"
+        ]
+    );
+    assert!(build_flow.run_task.is_none());
+}
+
+#[test]
+fn create_tasks_namespaced_core_tasks() {
+    let tasks = create_tasks(&create_cross_config(), "core::");
+
+    match tasks[1].1.run_task {
+        Some(RunTaskInfo::Details(ref details)) => match details.name {
+            RunTaskName::Multiple(ref names) => {
+                assert_eq!(names, &vec!["core::cross-target-test".to_string()])
+            }
+            _ => panic!("invalid run task name"),
+        },
+        _ => panic!("invalid run task"),
+    };
+}
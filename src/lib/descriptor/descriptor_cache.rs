@@ -0,0 +1,293 @@
+//! # descriptor_cache
+//!
+//! Caches the loaded external descriptor (after all extended makefiles are merged but before
+//! the core tasks and env are merged) keyed by the content hashes of all the makefiles involved.
+//!
+
+#[cfg(test)]
+#[path = "descriptor_cache_test.rs"]
+mod descriptor_cache_test;
+
+use crate::descriptor::descriptor_deserializer;
+use crate::storage;
+use crate::types::ExternalConfig;
+use fsio::file::{read_text_file, write_text_file};
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::env;
+use std::path::{Path, PathBuf};
+
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+static CACHE_DIRECTORY: &str = "descriptors";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Holds a single cached descriptor
+struct CacheEntry {
+    /// The cargo-make version which created the entry
+    version: String,
+    /// The absolute path of the loaded makefile
+    makefile_path: Option<String>,
+    /// All involved makefile paths and their content hash (empty for not found makefiles)
+    files: IndexMap<String, String>,
+    /// The loaded descriptor
+    config: ExternalConfig,
+}
+
+#[derive(Debug, Default)]
+struct LoadedFiles {
+    files: IndexMap<String, String>,
+    cacheable: bool,
+}
+
+thread_local! {
+    /// The makefiles loaded since the last start call (None if not tracking)
+    static LOADED_FILES: RefCell<Option<LoadedFiles>> = const { RefCell::new(None) };
+    /// The descriptors held in memory by the daemon process
+    static MEMORY_ENTRIES: RefCell<IndexMap<String, CacheEntry>> = RefCell::new(IndexMap::new());
+}
+
+/// Returns true if the descriptor cache is enabled (CARGO_MAKE_DESCRIPTOR_CACHE env var)
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is("CARGO_MAKE_DESCRIPTOR_CACHE")
+}
+
+fn get_hash(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+fn get_file_hash(file: &str) -> String {
+    let file_path = Path::new(file);
+
+    if file_path.is_file() {
+        match read_text_file(file) {
+            Ok(content) => get_hash(&content),
+            // never matches a valid hash
+            Err(_) => "unreadable".to_string(),
+        }
+    } else {
+        "".to_string()
+    }
+}
+
+/// Starts tracking the loaded makefiles
+pub(crate) fn start() {
+    LOADED_FILES.with(|loaded_files| {
+        *loaded_files.borrow_mut() = Some(LoadedFiles {
+            files: IndexMap::new(),
+            cacheable: true,
+        })
+    });
+}
+
+fn stop() -> Option<LoadedFiles> {
+    LOADED_FILES.with(|loaded_files| loaded_files.borrow_mut().take())
+}
+
+/// Tracks a loaded makefile (content is None if the makefile was not found)
+pub(crate) fn add_file(file: &str, content: Option<&str>) {
+    LOADED_FILES.with(|loaded_files| {
+        if let Some(ref mut loaded_files) = *loaded_files.borrow_mut() {
+            let hash = match content {
+                Some(value) => get_hash(value),
+                None => "".to_string(),
+            };
+
+            loaded_files.files.insert(file.to_string(), hash);
+        }
+    });
+}
+
+/// Marks the currently loaded descriptor as not cacheable (for example due to a load script)
+pub(crate) fn set_not_cacheable() {
+    LOADED_FILES.with(|loaded_files| {
+        if let Some(ref mut loaded_files) = *loaded_files.borrow_mut() {
+            loaded_files.cacheable = false;
+        }
+    });
+}
+
+fn get_cache_file(directory: &Path, file_name: &str) -> PathBuf {
+    // relative makefile paths depend on the current directory
+    let current_directory = match env::current_dir() {
+        Ok(value) => value.to_string_lossy().into_owned(),
+        Err(_) => "".to_string(),
+    };
+    let key = get_hash(&format!("{}\n{}", current_directory, file_name));
+
+    directory.join(format!("{}.json", key))
+}
+
+fn get_cache_directory() -> Option<PathBuf> {
+    storage::get_storage_directory(dirs_next::cache_dir(), CACHE_DIRECTORY, false)
+        .map(|directory| directory.join(CACHE_DIRECTORY))
+}
+
+/// Returns true if the entry was created by this version and none of its makefiles were modified
+fn is_valid(entry: &CacheEntry) -> bool {
+    if entry.version != VERSION {
+        debug!("Descriptor cache created by another version, ignoring.");
+        return false;
+    }
+
+    for (file, hash) in &entry.files {
+        if get_file_hash(file) != *hash {
+            debug!("Makefile: {} modified, ignoring descriptor cache.", file);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Restores the side effects of loading the makefiles and returns the cached descriptor
+fn restore(entry: CacheEntry) -> ExternalConfig {
+    if let Some(ref makefile_path) = entry.makefile_path {
+        envmnt::set("CARGO_MAKE_MAKEFILE_PATH", makefile_path);
+    }
+    if let Some(ref config) = entry.config.config {
+        if config.strict.unwrap_or(false) {
+            descriptor_deserializer::set_makefile_strict(true);
+        }
+    }
+
+    entry.config
+}
+
+fn load_from_directory(directory: &Path, file_name: &str) -> Option<ExternalConfig> {
+    let cache_file = get_cache_file(directory, file_name);
+    if !cache_file.is_file() {
+        return None;
+    }
+
+    let entry: CacheEntry = match read_text_file(&cache_file) {
+        Ok(value) => match serde_json::from_str(&value) {
+            Ok(entry) => entry,
+            Err(error) => {
+                debug!("Unable to parse descriptor cache file, {}", error);
+                return None;
+            }
+        },
+        Err(error) => {
+            debug!("Unable to read descriptor cache file, {}", error);
+            return None;
+        }
+    };
+
+    if is_valid(&entry) {
+        Some(restore(entry))
+    } else {
+        None
+    }
+}
+
+/// Returns the cache entry of the descriptor with all makefiles tracked since the start call
+/// (None if the descriptor is not cacheable)
+fn create_entry(config: &ExternalConfig) -> Option<CacheEntry> {
+    let loaded_files = stop()?;
+
+    // duplicate tasks are tracked while merging the makefiles
+    let report_duplicate_tasks = match config.config {
+        Some(ref config_section) => config_section.report_duplicate_tasks.unwrap_or(false),
+        None => false,
+    };
+    if !loaded_files.cacheable || report_duplicate_tasks {
+        debug!("Descriptor is not cacheable.");
+        return None;
+    }
+
+    let makefile_path = match loaded_files.files.first() {
+        Some((file, hash)) if !hash.is_empty() => Some(file.to_string()),
+        _ => None,
+    };
+
+    Some(CacheEntry {
+        version: VERSION.to_string(),
+        makefile_path,
+        files: loaded_files.files,
+        config: config.clone(),
+    })
+}
+
+fn store_to_directory(directory: &Path, file_name: &str, config: &ExternalConfig) {
+    let entry = match create_entry(config) {
+        Some(entry) => entry,
+        None => return,
+    };
+
+    match serde_json::to_string(&entry) {
+        Ok(value) => {
+            if let Err(error) = fsio::directory::create(&directory.to_path_buf()) {
+                debug!("Unable to create descriptor cache directory, {}", error);
+                return;
+            }
+
+            let cache_file = get_cache_file(directory, file_name);
+            if let Err(error) = write_text_file(&cache_file, &value) {
+                debug!("Unable to write descriptor cache file, {}", error);
+            }
+        }
+        Err(error) => debug!("Unable to serialize descriptor cache, {}", error),
+    }
+}
+
+/// Returns the cached descriptor if none of the involved makefiles were modified
+pub(crate) fn load(file_name: &str) -> Option<ExternalConfig> {
+    match get_cache_directory() {
+        Some(directory) => load_from_directory(&directory, file_name),
+        None => None,
+    }
+}
+
+/// Stores the loaded descriptor with all makefiles tracked since the start call
+pub(crate) fn store(file_name: &str, config: &ExternalConfig) {
+    match get_cache_directory() {
+        Some(directory) => store_to_directory(&directory, file_name, config),
+        None => {
+            stop();
+        }
+    }
+}
+
+/// Returns the descriptor held in memory (serialized for the daemon clients) if none of the
+/// involved makefiles were modified
+pub(crate) fn load_from_memory(key: &str) -> Option<String> {
+    MEMORY_ENTRIES.with(|entries| {
+        let mut entries = entries.borrow_mut();
+
+        match entries.get(key) {
+            Some(entry) if is_valid(entry) => serde_json::to_string(entry).ok(),
+            Some(_) => {
+                entries.shift_remove(key);
+                None
+            }
+            None => None,
+        }
+    })
+}
+
+/// Holds the loaded descriptor in memory with all makefiles tracked since the start call and
+/// returns it serialized for the daemon clients (None if the descriptor is not cacheable)
+pub(crate) fn store_in_memory(key: &str, config: &ExternalConfig) -> Option<String> {
+    let entry = create_entry(config)?;
+    let value = serde_json::to_string(&entry).ok()?;
+
+    MEMORY_ENTRIES.with(|entries| entries.borrow_mut().insert(key.to_string(), entry));
+
+    Some(value)
+}
+
+/// Returns the descriptor serialized by the daemon if none of the involved makefiles were
+/// modified since
+pub(crate) fn load_serialized(value: &str) -> Option<ExternalConfig> {
+    match serde_json::from_str::<CacheEntry>(value) {
+        Ok(entry) if is_valid(&entry) => Some(restore(entry)),
+        Ok(_) => None,
+        Err(error) => {
+            debug!("Unable to parse daemon descriptor, {}", error);
+            None
+        }
+    }
+}
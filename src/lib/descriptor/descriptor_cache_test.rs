@@ -0,0 +1,212 @@
+use super::*;
+use crate::descriptor::descriptor_deserializer;
+
+fn create_test_directory(name: &str) -> PathBuf {
+    let directory = PathBuf::from("./target/_temp/descriptor_cache").join(name);
+    fsio::directory::delete(&directory).unwrap();
+    fsio::directory::create(&directory).unwrap();
+
+    directory
+}
+
+fn create_makefile(directory: &Path, content: &str) -> String {
+    let file = directory.join("Makefile.toml");
+    write_text_file(&file, content).unwrap();
+
+    file.to_string_lossy().into_owned()
+}
+
+fn load_config(content: &str) -> ExternalConfig {
+    descriptor_deserializer::load_external_config(content, "Makefile.toml").unwrap()
+}
+
+#[test]
+fn store_and_load_valid() {
+    let directory = create_test_directory("valid");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+    let missing_file = directory.join("missing.toml");
+
+    start();
+    add_file(&makefile, Some(content));
+    add_file(&missing_file.to_string_lossy(), None);
+    store_to_directory(&directory, &makefile, &load_config(content));
+
+    let config = load_from_directory(&directory, &makefile).unwrap();
+    let tasks = config.tasks.unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks["test"].command.clone().unwrap(), "echo");
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_MAKEFILE_PATH"), makefile);
+}
+
+#[test]
+fn store_and_load_file_modified() {
+    let directory = create_test_directory("modified");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    store_to_directory(&directory, &makefile, &load_config(content));
+
+    create_makefile(&directory, "[tasks.test]\ncommand = \"echo2\"\n");
+
+    assert!(load_from_directory(&directory, &makefile).is_none());
+}
+
+#[test]
+fn store_and_load_missing_file_created() {
+    let directory = create_test_directory("created");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let missing_file = directory.join("Makefile.toml");
+
+    start();
+    add_file(&missing_file.to_string_lossy(), None);
+    store_to_directory(&directory, "Makefile.toml", &ExternalConfig::new());
+
+    assert!(load_from_directory(&directory, "Makefile.toml").is_some());
+
+    create_makefile(&directory, content);
+
+    assert!(load_from_directory(&directory, "Makefile.toml").is_none());
+}
+
+#[test]
+fn store_not_cacheable() {
+    let directory = create_test_directory("not_cacheable");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    set_not_cacheable();
+    store_to_directory(&directory, &makefile, &load_config(content));
+
+    assert!(!get_cache_file(&directory, &makefile).exists());
+}
+
+#[test]
+fn store_report_duplicate_tasks() {
+    let directory = create_test_directory("duplicate_tasks");
+    let content = "[config]\nreport_duplicate_tasks = true\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    store_to_directory(&directory, &makefile, &load_config(content));
+
+    assert!(!get_cache_file(&directory, &makefile).exists());
+}
+
+#[test]
+fn store_not_started() {
+    let directory = create_test_directory("not_started");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    stop();
+    add_file(&makefile, Some(content));
+    store_to_directory(&directory, &makefile, &load_config(content));
+
+    assert!(!get_cache_file(&directory, &makefile).exists());
+}
+
+#[test]
+fn load_other_version() {
+    let directory = create_test_directory("other_version");
+    let entry = CacheEntry {
+        version: "0.0.1".to_string(),
+        makefile_path: None,
+        files: IndexMap::new(),
+        config: ExternalConfig::new(),
+    };
+    write_text_file(
+        &get_cache_file(&directory, "Makefile.toml"),
+        &serde_json::to_string(&entry).unwrap(),
+    )
+    .unwrap();
+
+    assert!(load_from_directory(&directory, "Makefile.toml").is_none());
+}
+
+#[test]
+fn load_invalid_content() {
+    let directory = create_test_directory("invalid_content");
+    write_text_file(&get_cache_file(&directory, "Makefile.toml"), "{").unwrap();
+
+    assert!(load_from_directory(&directory, "Makefile.toml").is_none());
+}
+
+#[test]
+fn cache_entry_serialization() {
+    let content = read_text_file("./Makefile.toml").unwrap();
+    let config = load_config(&content);
+    let entry = CacheEntry {
+        version: VERSION.to_string(),
+        makefile_path: None,
+        files: IndexMap::new(),
+        config: config.clone(),
+    };
+
+    let serialized = serde_json::to_string(&entry).unwrap();
+    let deserialized: CacheEntry = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(
+        format!("{:?}", deserialized.config),
+        format!("{:?}", config)
+    );
+}
+
+#[test]
+fn store_and_load_memory_valid() {
+    let directory = create_test_directory("memory_valid");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    let stored = store_in_memory("memory_valid", &load_config(content)).unwrap();
+
+    let loaded = load_from_memory("memory_valid").unwrap();
+    assert_eq!(loaded, stored);
+
+    let config = load_serialized(&loaded).unwrap();
+    let tasks = config.tasks.unwrap();
+    assert_eq!(tasks["test"].command.clone().unwrap(), "echo");
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_MAKEFILE_PATH"), makefile);
+}
+
+#[test]
+fn store_and_load_memory_file_modified() {
+    let directory = create_test_directory("memory_modified");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    let stored = store_in_memory("memory_modified", &load_config(content)).unwrap();
+
+    create_makefile(&directory, "[tasks.test]\ncommand = \"echo2\"\n");
+
+    assert!(load_from_memory("memory_modified").is_none());
+    assert!(load_serialized(&stored).is_none());
+}
+
+#[test]
+fn store_in_memory_not_cacheable() {
+    let directory = create_test_directory("memory_not_cacheable");
+    let content = "[tasks.test]\ncommand = \"echo\"\n";
+    let makefile = create_makefile(&directory, content);
+
+    start();
+    add_file(&makefile, Some(content));
+    set_not_cacheable();
+
+    assert!(store_in_memory("memory_not_cacheable", &load_config(content)).is_none());
+    assert!(load_from_memory("memory_not_cacheable").is_none());
+}
+
+#[test]
+fn load_serialized_invalid_content() {
+    assert!(load_serialized("not json").is_none());
+}
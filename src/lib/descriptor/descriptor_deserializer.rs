@@ -6,8 +6,15 @@
 #[path = "descriptor_deserializer_test.rs"]
 mod descriptor_deserializer_test;
 
+use crate::ci_output;
+use crate::diagnostics;
 use crate::error::CargoMakeError;
 use crate::types::{Config, ExternalConfig};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// True if one of the loaded makefiles enabled strict mode, kept in the loader state instead of
+/// the environment so forked/child cargo-make processes do not inherit it
+static MAKEFILE_STRICT: AtomicBool = AtomicBool::new(false);
 
 pub(crate) fn load_config(
     descriptor_string: &str,
@@ -38,22 +45,77 @@ pub(crate) fn load_config(
     Ok(config)
 }
 
+/// Sets the strict mode defined by the loaded makefiles
+pub(crate) fn set_makefile_strict(strict: bool) {
+    MAKEFILE_STRICT.store(strict, Ordering::SeqCst);
+}
+
+/// Returns true if strict mode is enabled by the --strict CLI flag or by a loaded makefile
+pub(crate) fn is_strict() -> bool {
+    envmnt::is("CARGO_MAKE_STRICT") || MAKEFILE_STRICT.load(Ordering::SeqCst)
+}
+
 pub(crate) fn load_external_config(
     descriptor_string: &str,
     file: &str,
 ) -> Result<ExternalConfig, CargoMakeError> {
     let deserializer = toml::de::Deserializer::new(descriptor_string);
+    let mut unknown_keys = vec![];
 
     match serde_ignored::deserialize(deserializer, |path| {
         warn!("Found unknown key: {} in file: {}", path, file);
+        unknown_keys.push(path.to_string());
     }) {
-        Ok(value) => Ok(value),
+        Ok(value) => {
+            let external_config: ExternalConfig = value;
+
+            let strict = is_strict()
+                || match external_config.config {
+                    Some(ref config) => config.strict.unwrap_or(false),
+                    None => false,
+                };
+
+            if strict && !unknown_keys.is_empty() {
+                let message = format!("Found unknown keys: {}", unknown_keys.join(", "));
+                ci_output::annotate_error(&message, Some(file), None);
+                let error = CargoMakeError::ParseFileFailed(String::from(file), message.clone());
+                diagnostics::report_error(
+                    &diagnostics::get_error_code(&error),
+                    &message,
+                    Some(file),
+                    None,
+                    None,
+                );
+
+                error!("Unable to parse external file: {:#?}, {}", &file, &message);
+                return Err(error);
+            }
+
+            Ok(external_config)
+        }
         Err(error) => {
+            let line = error
+                .span()
+                .map(|span| ci_output::get_line_number(descriptor_string, span.start));
+            ci_output::annotate_error(
+                &format!("Unable to parse external file: {}", error.message()),
+                Some(file),
+                line,
+            );
+            let parse_error =
+                CargoMakeError::ParseFileFailed(String::from(file), error.to_string());
+            diagnostics::report_error(
+                &diagnostics::get_error_code(&parse_error),
+                error.message(),
+                Some(file),
+                error
+                    .span()
+                    .map(|span| diagnostics::get_span(descriptor_string, span.start)),
+                None,
+            );
+
             error!("Unable to parse external file: {:#?}, {}", &file, error);
-            return Err(CargoMakeError::ParseFileFailed(
-                String::from(file),
-                error.to_string(),
-            ));
+            Err(parse_error)
         }
     }
 }
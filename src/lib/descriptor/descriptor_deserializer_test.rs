@@ -77,3 +77,72 @@ category2 = "Tools"
 
     assert!(config.tasks.unwrap().contains_key("empty"));
 }
+
+#[test]
+fn load_external_config_unknown_key_not_strict() {
+    let config = load_external_config(
+        r#"
+[tasks.empty]
+description = "Empty Task"
+dependancies = ["other"]
+    "#,
+        "./Makefile.toml",
+    )
+    .unwrap();
+
+    assert!(config
+        .tasks
+        .unwrap()
+        .get("empty")
+        .unwrap()
+        .dependencies
+        .is_none());
+}
+
+#[test]
+#[should_panic]
+fn load_external_config_unknown_key_strict() {
+    load_external_config(
+        r#"
+[config]
+strict = true
+
+[tasks.empty]
+description = "Empty Task"
+scrip = "echo test"
+    "#,
+        "./Makefile.toml",
+    )
+    .unwrap();
+}
+
+#[test]
+fn load_external_config_strict_no_unknown_keys() {
+    let config = load_external_config(
+        r#"
+[config]
+strict = true
+
+[tasks.empty]
+description = "Empty Task"
+    "#,
+        "./Makefile.toml",
+    )
+    .unwrap();
+
+    assert!(config.config.unwrap().strict.unwrap());
+}
+
+#[test]
+#[ignore]
+fn set_makefile_strict_not_exported() {
+    envmnt::remove("CARGO_MAKE_STRICT");
+
+    set_makefile_strict(true);
+    let strict = is_strict();
+    set_makefile_strict(false);
+
+    assert!(strict);
+    assert!(!is_strict());
+    assert!(!envmnt::exists("CARGO_MAKE_STRICT"));
+}
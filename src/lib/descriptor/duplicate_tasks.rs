@@ -0,0 +1,85 @@
+//! # duplicate_tasks
+//!
+//! Tracks the makefiles defining each task while the descriptors are merged and
+//! reports the tasks which are defined in multiple makefiles.
+//!
+
+#[cfg(test)]
+#[path = "duplicate_tasks_test.rs"]
+mod duplicate_tasks_test;
+
+use crate::error::CargoMakeError;
+use crate::types::Task;
+use indexmap::IndexMap;
+use std::cell::RefCell;
+
+thread_local! {
+    /// Task name to the makefiles defining it (the used definition is first)
+    static DEFINITIONS: RefCell<IndexMap<String, Vec<String>>> = RefCell::new(IndexMap::new());
+}
+
+pub(crate) fn get_location(task: &Task) -> String {
+    task.get_makefile_path()
+        .unwrap_or("internal core makefile".to_string())
+}
+
+/// Clears all previously tracked definitions (called before loading the descriptors)
+pub(crate) fn clear() {
+    DEFINITIONS.with(|definitions| definitions.borrow_mut().clear());
+}
+
+/// Tracks a task which is defined in both the base and the extending descriptors
+pub(crate) fn add(name: &str, base: &Task, extended: &Task) {
+    let used = get_location(extended);
+    let overridden = get_location(base);
+
+    if used == overridden {
+        return;
+    }
+
+    DEFINITIONS.with(|definitions| {
+        let mut definitions = definitions.borrow_mut();
+        let locations = definitions.entry(name.to_string()).or_insert(vec![]);
+
+        locations.retain(|location| *location != used);
+        locations.insert(0, used);
+
+        if !locations.contains(&overridden) {
+            locations.push(overridden);
+        }
+    });
+}
+
+fn create_message(name: &str, locations: &[String]) -> String {
+    format!(
+        "Task: {} is defined in multiple makefiles: {} (used), {}",
+        name,
+        &locations[0],
+        locations[1..].join(", ")
+    )
+}
+
+/// Prints all tasks defined in multiple makefiles and fails in strict mode
+pub(crate) fn report(strict: bool) -> Result<(), CargoMakeError> {
+    let messages: Vec<String> = DEFINITIONS.with(|definitions| {
+        definitions
+            .borrow()
+            .iter()
+            .map(|(name, locations)| create_message(name, locations))
+            .collect()
+    });
+
+    for message in &messages {
+        warn!("{}", message);
+    }
+
+    if strict && !messages.is_empty() {
+        error!(
+            "Found {} task(s) defined in multiple makefiles (strict mode).",
+            messages.len()
+        );
+        return Err(CargoMakeError::DuplicateTasks(messages.len()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::types::EnvValue;
+
+fn create_task(makefile: Option<&str>) -> Task {
+    let mut task = Task::new();
+
+    if let Some(path) = makefile {
+        let mut env = IndexMap::new();
+        env.insert(
+            "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+            EnvValue::Value(path.to_string()),
+        );
+        task.env = Some(env);
+    }
+
+    task
+}
+
+fn get_locations(name: &str) -> Option<Vec<String>> {
+    DEFINITIONS.with(|definitions| definitions.borrow().get(name).cloned())
+}
+
+#[test]
+fn add_same_location() {
+    clear();
+
+    add("test", &create_task(None), &create_task(None));
+
+    assert!(get_locations("test").is_none());
+    report(true).unwrap();
+}
+
+#[test]
+fn add_multiple_layers() {
+    clear();
+
+    add(
+        "test",
+        &create_task(Some("./grandparent.toml")),
+        &create_task(Some("./parent.toml")),
+    );
+    add(
+        "test",
+        &create_task(Some("./parent.toml")),
+        &create_task(Some("./Makefile.toml")),
+    );
+    add(
+        "test",
+        &create_task(None),
+        &create_task(Some("./Makefile.toml")),
+    );
+
+    assert_eq!(
+        get_locations("test").unwrap(),
+        vec![
+            "./Makefile.toml".to_string(),
+            "./parent.toml".to_string(),
+            "./grandparent.toml".to_string(),
+            "internal core makefile".to_string()
+        ]
+    );
+    report(false).unwrap();
+
+    clear();
+    assert!(get_locations("test").is_none());
+}
+
+#[test]
+#[should_panic]
+fn report_strict() {
+    clear();
+
+    add(
+        "test",
+        &create_task(Some("./parent.toml")),
+        &create_task(Some("./Makefile.toml")),
+    );
+
+    report(true).unwrap();
+}
+
+#[test]
+fn create_message_value() {
+    let message = create_message(
+        "build",
+        &["./Makefile.toml".to_string(), "./base.toml".to_string()],
+    );
+
+    assert_eq!(
+        message,
+        "Task: build is defined in multiple makefiles: ./Makefile.toml (used), ./base.toml"
+    );
+}
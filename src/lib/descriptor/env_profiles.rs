@@ -0,0 +1,46 @@
+//! # env_profiles
+//!
+//! Tracks the makefiles defining each profile env block while the descriptors are loaded.
+//!
+
+#[cfg(test)]
+#[path = "env_profiles_test.rs"]
+mod env_profiles_test;
+
+use crate::types::EnvValue;
+use indexmap::IndexMap;
+use std::cell::RefCell;
+
+thread_local! {
+    /// Profile name to the makefiles defining its env block (in load order)
+    static LOCATIONS: RefCell<IndexMap<String, Vec<String>>> = RefCell::new(IndexMap::new());
+}
+
+/// Clears all previously tracked locations (called before loading the descriptors)
+pub(crate) fn clear() {
+    LOCATIONS.with(|locations| locations.borrow_mut().clear());
+}
+
+/// Tracks the profile env blocks defined in the provided makefile env
+pub(crate) fn add(file: &str, env: &Option<IndexMap<String, EnvValue>>) {
+    if let Some(ref env) = env {
+        LOCATIONS.with(|locations| {
+            let mut locations = locations.borrow_mut();
+
+            for (key, value) in env {
+                if let EnvValue::Profile(_) = value {
+                    let files = locations.entry(key.to_string()).or_insert(vec![]);
+
+                    if !files.contains(&file.to_string()) {
+                        files.push(file.to_string());
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Returns the makefiles defining the profile env block (empty for the internal core makefiles)
+pub(crate) fn get(name: &str) -> Vec<String> {
+    LOCATIONS.with(|locations| locations.borrow().get(name).cloned().unwrap_or_default())
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+fn create_env(profiles: &[&str]) -> Option<IndexMap<String, EnvValue>> {
+    let mut env = IndexMap::new();
+    env.insert("VALUE".to_string(), EnvValue::Value("value".to_string()));
+
+    for profile in profiles {
+        env.insert(profile.to_string(), EnvValue::Profile(IndexMap::new()));
+    }
+
+    Some(env)
+}
+
+#[test]
+fn add_none() {
+    clear();
+
+    add("./Makefile.toml", &None);
+
+    assert!(get("ci").is_empty());
+}
+
+#[test]
+fn add_multiple_files() {
+    clear();
+
+    add("./base.toml", &create_env(&["ci", "gpu"]));
+    add("./Makefile.toml", &create_env(&["ci"]));
+    add("./Makefile.toml", &create_env(&["ci"]));
+
+    assert_eq!(
+        get("ci"),
+        vec!["./base.toml".to_string(), "./Makefile.toml".to_string()]
+    );
+    assert_eq!(get("gpu"), vec!["./base.toml".to_string()]);
+    assert!(get("VALUE").is_empty());
+}
@@ -402,7 +402,7 @@ fn load_env_reorder() {
     let toml_file = "./src/lib/test/makefiles/env-reorder.toml";
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let config = load(toml_file, true, None, false, None).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("ENV4", "--defined yes"));
@@ -419,7 +419,7 @@ fn load_env_reorder_extend() {
     let toml_file = "./src/lib/test/makefiles/env-reorder-extended.toml";
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4", "ENV5", "ENV6"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let config = load(toml_file, true, None, false, None).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("ENV6", "--verbose"));
@@ -0,0 +1,420 @@
+//! # justfile
+//!
+//! Dynamically creates tasks based on the recipes defined in a justfile.
+//!
+
+#[cfg(test)]
+#[path = "justfile_test.rs"]
+mod justfile_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::{DependencyIdentifier, ScriptValue, Task};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Parameter {
+    /// The parameter name
+    name: String,
+    /// The default value (if defined)
+    default_value: Option<String>,
+    /// True for the last variadic parameter (*name or +name)
+    variadic: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Recipe {
+    name: String,
+    parameters: Vec<Parameter>,
+    dependencies: Vec<String>,
+    body: Vec<String>,
+    description: Option<String>,
+    private: bool,
+}
+
+#[derive(Debug, Default)]
+struct Justfile {
+    recipes: Vec<Recipe>,
+    /// The alias names mapped to the recipe names
+    aliases: Vec<(String, String)>,
+    /// The string literal variables
+    variables: HashMap<String, String>,
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+
+    if value.len() >= 2
+        && ((value.starts_with('"') && value.ends_with('"'))
+            || (value.starts_with('\'') && value.ends_with('\'')))
+    {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn is_indented(line: &str) -> bool {
+    line.starts_with(' ') || line.starts_with('\t')
+}
+
+/// Splits the recipe header to the name with parameters and the dependencies parts
+fn split_header(line: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+
+    for (index, character) in line.char_indices() {
+        match quote {
+            Some(current) if current == character => quote = None,
+            Some(_) => (),
+            None => match character {
+                '"' | '\'' => quote = Some(character),
+                // skip := assignments
+                ':' if !line[index + 1..].starts_with('=') => {
+                    return Some((&line[..index], &line[index + 1..]))
+                }
+                _ => (),
+            },
+        }
+    }
+
+    None
+}
+
+fn parse_parameters(tokens: &[&str]) -> Vec<Parameter> {
+    tokens
+        .iter()
+        .map(|token| {
+            let (variadic, token) = match token.strip_prefix(['*', '+']) {
+                Some(value) => (true, value),
+                None => (false, *token),
+            };
+            let token = token.trim_start_matches('$');
+
+            match token.split_once('=') {
+                Some((name, default_value)) => Parameter {
+                    name: name.to_string(),
+                    default_value: Some(unquote(default_value)),
+                    variadic,
+                },
+                None => Parameter {
+                    name: token.to_string(),
+                    default_value: None,
+                    variadic,
+                },
+            }
+        })
+        .collect()
+}
+
+fn parse_dependencies(value: &str) -> Vec<String> {
+    // dependencies after && run after the recipe and are not supported
+    let value = match value.split_once("&&") {
+        Some((before, _)) => {
+            warn!("Justfile subsequent dependencies (&&) are not supported and are ignored.");
+            before
+        }
+        None => value,
+    };
+
+    let mut dependencies = vec![];
+    let mut in_arguments = false;
+    for token in value.split_whitespace() {
+        if in_arguments {
+            in_arguments = !token.ends_with(')');
+        } else if let Some(name) = token.strip_prefix('(') {
+            // dependency with arguments, the arguments are not passed
+            warn!(
+                "Justfile dependency arguments are not supported and are ignored for: {}",
+                name
+            );
+            dependencies.push(name.trim_end_matches(')').to_string());
+            in_arguments = !token.ends_with(')');
+        } else {
+            dependencies.push(token.to_string());
+        }
+    }
+
+    dependencies
+}
+
+/// Removes the common indentation of the body lines and the trailing empty lines
+fn dedent(lines: &[&str]) -> Vec<String> {
+    let mut lines = lines.to_vec();
+    while let Some(line) = lines.last() {
+        if line.trim().is_empty() {
+            lines.pop();
+        } else {
+            break;
+        }
+    }
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| {
+            if line.len() >= indent {
+                line[indent..].to_string()
+            } else {
+                "".to_string()
+            }
+        })
+        .collect()
+}
+
+fn parse(text: &str) -> Justfile {
+    let lines: Vec<&str> = text.lines().collect();
+
+    let mut justfile = Justfile::default();
+    let mut comment: Option<String> = None;
+    let mut private = false;
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index].trim_end();
+        index += 1;
+
+        if line.trim().is_empty() {
+            comment = None;
+            continue;
+        }
+
+        if is_indented(line) {
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix('#') {
+            if !line.starts_with("#!") {
+                comment = Some(value.trim().to_string());
+            }
+            continue;
+        }
+
+        if line.starts_with('[') {
+            if line.contains("private") {
+                private = true;
+            }
+            continue;
+        }
+
+        if line.starts_with("set ") || line.starts_with("import ") || line.starts_with("mod ") {
+            debug!("Ignoring justfile line: {}", line);
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("alias ") {
+            if let Some((name, target)) = value.split_once(":=") {
+                justfile
+                    .aliases
+                    .push((name.trim().to_string(), target.trim().to_string()));
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(":=") {
+            let name = name.trim().trim_start_matches("export ").trim();
+            let value = value.trim();
+
+            if value.starts_with('"') || value.starts_with('\'') {
+                justfile.variables.insert(name.to_string(), unquote(value));
+            } else {
+                warn!(
+                    "Justfile variable: {} is not a string literal and is not supported.",
+                    name
+                );
+            }
+            continue;
+        }
+
+        match split_header(line) {
+            Some((signature, dependencies)) => {
+                let tokens: Vec<&str> = signature.split_whitespace().collect();
+                if tokens.is_empty() {
+                    continue;
+                }
+
+                let name = tokens[0].trim_start_matches('@').to_string();
+
+                let start = index;
+                while index < lines.len()
+                    && (is_indented(lines[index]) || lines[index].trim().is_empty())
+                {
+                    index += 1;
+                }
+
+                justfile.recipes.push(Recipe {
+                    private: private || name.starts_with('_'),
+                    name,
+                    parameters: parse_parameters(&tokens[1..]),
+                    dependencies: parse_dependencies(dependencies),
+                    body: dedent(&lines[start..index]),
+                    description: comment.take(),
+                });
+            }
+            None => warn!("Unsupported justfile line: {}", line),
+        }
+
+        comment = None;
+        private = false;
+    }
+
+    justfile
+}
+
+fn interpolate(line: &str, recipe: &Recipe, variables: &HashMap<String, String>) -> String {
+    let mut output = line.to_string();
+
+    for parameter in &recipe.parameters {
+        output = output.replace(
+            &format!("{{{{{}}}}}", parameter.name),
+            &format!("${{{}}}", parameter.name),
+        );
+        output = output.replace(
+            &format!("{{{{ {} }}}}", parameter.name),
+            &format!("${{{}}}", parameter.name),
+        );
+    }
+
+    for (name, value) in variables {
+        output = output.replace(&format!("{{{{{}}}}}", name), value);
+        output = output.replace(&format!("{{{{ {} }}}}", name), value);
+    }
+
+    if output.contains("{{") {
+        warn!(
+            "Justfile recipe: {} contains unsupported interpolation: {}",
+            &recipe.name, line
+        );
+    }
+
+    output
+}
+
+/// Maps the task arguments to the recipe parameters
+fn create_parameters_script(recipe: &Recipe) -> Vec<String> {
+    let mut script = vec![];
+
+    for (index, parameter) in recipe.parameters.iter().enumerate() {
+        if parameter.variadic {
+            script.push(format!(
+                "if [ \"$#\" -gt {0} ]; then shift {0}; else shift \"$#\"; fi",
+                index
+            ));
+            script.push(format!("{}=\"$*\"", parameter.name));
+        } else {
+            let value = match parameter.default_value {
+                Some(ref default_value) => format!("${{{}:-{}}}", index + 1, default_value),
+                None => format!(
+                    "${{{}:?missing recipe argument: {}}}",
+                    index + 1,
+                    parameter.name
+                ),
+            };
+            script.push(format!("{}=\"{}\"", parameter.name, value));
+        }
+    }
+
+    script
+}
+
+fn create_script(recipe: &Recipe, variables: &HashMap<String, String>) -> Option<ScriptValue> {
+    if recipe.body.is_empty() {
+        return None;
+    }
+
+    // shebang recipes are invoked as is
+    if recipe.body[0].starts_with("#!") {
+        if !recipe.parameters.is_empty() {
+            warn!(
+                "Justfile shebang recipe: {} parameters are passed as script arguments.",
+                &recipe.name
+            );
+        }
+
+        let lines = recipe
+            .body
+            .iter()
+            .map(|line| interpolate(line, recipe, variables))
+            .collect();
+        return Some(ScriptValue::Text(lines));
+    }
+
+    let mut lines = vec!["set -e".to_string()];
+    lines.extend(create_parameters_script(recipe));
+
+    for line in &recipe.body {
+        let line = line.trim_start_matches('@');
+        let line = match line.strip_prefix('-') {
+            // errors are ignored
+            Some(value) => format!("{} || true", interpolate(value, recipe, variables)),
+            None => interpolate(line, recipe, variables),
+        };
+        lines.push(line);
+    }
+
+    Some(ScriptValue::Text(lines))
+}
+
+fn create_task(recipe: &Recipe, variables: &HashMap<String, String>) -> Task {
+    let mut task = Task::new();
+    task.category = Some("Just".to_string());
+    task.description = recipe.description.clone();
+    if recipe.private {
+        task.private = Some(true);
+    }
+    if !recipe.dependencies.is_empty() {
+        task.dependencies = Some(
+            recipe
+                .dependencies
+                .iter()
+                .map(|name| DependencyIdentifier::Name(name.to_string()))
+                .collect(),
+        );
+    }
+    task.script = create_script(recipe, variables);
+
+    task
+}
+
+fn load_from_text(text: &str) -> Vec<(String, Task)> {
+    let justfile = parse(text);
+
+    let mut tasks = vec![];
+    for recipe in &justfile.recipes {
+        tasks.push((
+            recipe.name.clone(),
+            create_task(recipe, &justfile.variables),
+        ));
+    }
+
+    for (name, target) in justfile.aliases {
+        let mut task = Task::new();
+        task.category = Some("Just".to_string());
+        task.alias = Some(target);
+
+        tasks.push((name, task));
+    }
+
+    tasks
+}
+
+pub(crate) fn load(file: &str) -> Result<Vec<(String, Task)>, CargoMakeError> {
+    let file_path = Path::new(file);
+
+    if file_path.is_file() {
+        let text = io::read_text_file(&file_path.to_path_buf())?;
+
+        Ok(load_from_text(&text))
+    } else {
+        Err(CargoMakeError::NotFound(format!(
+            "Justfile: {} not found.",
+            file
+        )))
+    }
+}
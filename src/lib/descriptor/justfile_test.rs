@@ -0,0 +1,171 @@
+use super::*;
+
+fn get_task<'a>(tasks: &'a [(String, Task)], name: &str) -> &'a Task {
+    &tasks
+        .iter()
+        .find(|(task_name, _)| task_name == name)
+        .unwrap()
+        .1
+}
+
+fn get_script(task: &Task) -> Vec<String> {
+    match task.script {
+        Some(ScriptValue::Text(ref lines)) => lines.clone(),
+        _ => panic!("Invalid script: {:#?}", task.script),
+    }
+}
+
+#[test]
+fn load_not_found() {
+    assert!(load("./src/lib/test/justfile/not_found").is_err());
+}
+
+#[test]
+fn parse_header() {
+    assert_eq!(split_header("build:"), Some(("build", "")));
+    assert_eq!(
+        split_header("test mode=\"a:b\": build"),
+        Some(("test mode=\"a:b\"", " build"))
+    );
+    assert_eq!(split_header("version := \"1\""), None);
+    assert_eq!(split_header("echo"), None);
+}
+
+#[test]
+fn parse_parameters_all_types() {
+    let parameters = parse_parameters(&["target", "mode='debug'", "$port=\"80\"", "*rest"]);
+
+    assert_eq!(
+        parameters,
+        vec![
+            Parameter {
+                name: "target".to_string(),
+                default_value: None,
+                variadic: false,
+            },
+            Parameter {
+                name: "mode".to_string(),
+                default_value: Some("debug".to_string()),
+                variadic: false,
+            },
+            Parameter {
+                name: "port".to_string(),
+                default_value: Some("80".to_string()),
+                variadic: false,
+            },
+            Parameter {
+                name: "rest".to_string(),
+                default_value: None,
+                variadic: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_dependencies_with_arguments() {
+    assert_eq!(
+        parse_dependencies(" build (test \"unit\" \"fast\") lint && notify"),
+        vec!["build".to_string(), "test".to_string(), "lint".to_string()]
+    );
+}
+
+#[test]
+fn load_from_text_recipes() {
+    let tasks = load_from_text(
+        r#"
+set shell := ["bash", "-c"]
+version := "1.0"
+
+alias b := build
+
+# Builds the project
+build mode="debug": format
+    @echo {{version}}
+    cargo build --profile {{ mode }}
+
+format:
+    -cargo fmt
+
+[private]
+helper:
+    echo helper
+
+_hidden:
+
+run *args:
+    cargo run -- {{args}}
+
+python:
+    #!/usr/bin/env python3
+    print("hello")
+"#,
+    );
+
+    let names: Vec<&str> = tasks.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["build", "format", "helper", "_hidden", "run", "python", "b"]
+    );
+
+    let build = get_task(&tasks, "build");
+    assert_eq!(build.description, Some("Builds the project".to_string()));
+    assert_eq!(build.category, Some("Just".to_string()));
+    assert_eq!(
+        build.dependencies,
+        Some(vec![DependencyIdentifier::Name("format".to_string())])
+    );
+    assert!(build.private.is_none());
+    assert_eq!(
+        get_script(build),
+        vec![
+            "set -e".to_string(),
+            "mode=\"${1:-debug}\"".to_string(),
+            "echo 1.0".to_string(),
+            "cargo build --profile ${mode}".to_string(),
+        ]
+    );
+
+    assert_eq!(
+        get_script(get_task(&tasks, "format")),
+        vec!["set -e".to_string(), "cargo fmt || true".to_string()]
+    );
+
+    assert!(get_task(&tasks, "helper").private.unwrap());
+    assert!(get_task(&tasks, "_hidden").private.unwrap());
+    assert!(get_task(&tasks, "_hidden").script.is_none());
+
+    assert_eq!(
+        get_script(get_task(&tasks, "run")),
+        vec![
+            "set -e".to_string(),
+            "if [ \"$#\" -gt 0 ]; then shift 0; else shift \"$#\"; fi".to_string(),
+            "args=\"$*\"".to_string(),
+            "cargo run -- ${args}".to_string(),
+        ]
+    );
+
+    assert_eq!(
+        get_script(get_task(&tasks, "python")),
+        vec![
+            "#!/usr/bin/env python3".to_string(),
+            "print(\"hello\")".to_string(),
+        ]
+    );
+
+    assert_eq!(get_task(&tasks, "b").alias, Some("build".to_string()));
+}
+
+#[test]
+fn load_from_text_required_parameter() {
+    let tasks = load_from_text("deploy target:\n    echo {{target}}\n");
+
+    assert_eq!(
+        get_script(get_task(&tasks, "deploy")),
+        vec![
+            "set -e".to_string(),
+            "target=\"${1:?missing recipe argument: target}\"".to_string(),
+            "echo ${target}".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,222 @@
+//! # lazy_core_tasks
+//!
+//! Loads only the core tasks which can be reached from the invoked task, instead of
+//! deserializing and merging all the core tasks.
+//!
+
+#[cfg(test)]
+#[path = "lazy_core_tasks_test.rs"]
+mod lazy_core_tasks_test;
+
+use crate::descriptor::{core_tasks, makefiles, merge_tasks, references};
+use crate::error::CargoMakeError;
+use crate::scriptengine;
+use crate::types::{Config, ConfigSection, ScriptValue, Task};
+use indexmap::IndexMap;
+use std::collections::HashSet;
+use toml::{Table, Value};
+
+/// A core descriptor with its raw (not yet deserialized) tasks
+struct CoreDescriptor {
+    config: Config,
+    raw_tasks: IndexMap<String, Value>,
+}
+
+/// The raw core descriptor, the tasks are kept in the order they are defined
+#[derive(Deserialize)]
+struct RawDescriptor {
+    #[serde(default)]
+    tasks: IndexMap<String, Value>,
+    #[serde(flatten)]
+    other: Table,
+}
+
+fn parse_descriptor(descriptor: &str) -> Result<CoreDescriptor, CargoMakeError> {
+    let raw_descriptor: RawDescriptor = match toml::from_str(descriptor) {
+        Ok(value) => value,
+        Err(error) => {
+            error!("Unable to parse internal descriptor: {}", error);
+            return Err(CargoMakeError::DescriptorParseFailed(error.to_string()));
+        }
+    };
+
+    let mut table = raw_descriptor.other;
+    table.insert("tasks".to_string(), Value::Table(Table::new()));
+
+    match Value::Table(table).try_into::<Config>() {
+        Ok(config) => Ok(CoreDescriptor {
+            config,
+            raw_tasks: raw_descriptor.tasks,
+        }),
+        Err(error) => {
+            error!("Unable to parse internal descriptor: {}", error);
+            Err(CargoMakeError::DescriptorParseFailed(error.to_string()))
+        }
+    }
+}
+
+fn deserialize_task(name: &str, value: &Value) -> Result<Task, CargoMakeError> {
+    match value.clone().try_into::<Task>() {
+        Ok(task) => Ok(task),
+        Err(error) => {
+            error!("Unable to parse internal task: {}, {}", name, error);
+            Err(CargoMakeError::DescriptorParseFailed(error.to_string()))
+        }
+    }
+}
+
+fn add_config_tasks(config: &ConfigSection, roots: &mut Vec<String>) {
+    for task in [
+        &config.init_task,
+        &config.end_task,
+        &config.on_error_task,
+        &config.legacy_migration_task,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        roots.push(task.to_string());
+    }
+
+    let profile_tasks = [
+        &config.profile_on_error_tasks,
+        &config.profile_default_tasks,
+    ];
+    for profile_tasks in profile_tasks.into_iter().flatten() {
+        roots.extend(profile_tasks.values().cloned());
+    }
+}
+
+/// Returns the words of the inline task scripts, so tasks invoked from the scripts (for example
+/// via the duckscript cm_run_task command) are loaded as well.<br>
+/// Script files and task names which are only known at runtime (for example from env vars)
+/// are not detected.
+fn get_script_words(task: &Task) -> Vec<String> {
+    let mut scripts = vec![&task.script];
+    for override_task in [&task.linux, &task.windows, &task.mac, &task.ci]
+        .into_iter()
+        .flatten()
+    {
+        scripts.push(&override_task.script);
+    }
+
+    let mut words = vec![];
+    for script in scripts.into_iter().flatten() {
+        if let ScriptValue::File(_) = script {
+            continue;
+        }
+
+        if let Ok(lines) = scriptengine::get_script_text(script) {
+            for line in lines {
+                words.extend(
+                    line.split(|character: char| {
+                        character.is_whitespace() || ['"', '\'', ',', ';'].contains(&character)
+                    })
+                    .filter(|word| !word.is_empty())
+                    .map(|word| word.to_string()),
+                );
+            }
+        }
+    }
+
+    words
+}
+
+/// Deserializes all tasks which can be reached from the roots, looking up the references
+/// of both the external and the core task definitions.
+fn load_reachable_tasks(
+    external_tasks: &IndexMap<String, Task>,
+    descriptors: &[CoreDescriptor],
+    roots: Vec<String>,
+) -> Result<Vec<IndexMap<String, Task>>, CargoMakeError> {
+    let mut loaded_tasks = vec![IndexMap::new(); descriptors.len()];
+    let mut visited = HashSet::new();
+    let mut queue = roots;
+
+    while let Some(name) = queue.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+
+        let mut definitions = vec![];
+        if let Some(task) = external_tasks.get(&name) {
+            definitions.push(task.clone());
+        }
+        for (index, descriptor) in descriptors.iter().enumerate() {
+            if let Some(value) = descriptor.raw_tasks.get(&name) {
+                let task = deserialize_task(&name, value)?;
+                loaded_tasks[index].insert(name.clone(), task.clone());
+                definitions.push(task);
+            }
+        }
+
+        for task in &definitions {
+            for (_, target) in references::get_references(task) {
+                if !visited.contains(target) {
+                    queue.push(target.to_string());
+                }
+            }
+            for word in get_script_words(task) {
+                if !visited.contains(&word) {
+                    queue.push(word);
+                }
+            }
+        }
+    }
+
+    Ok(loaded_tasks)
+}
+
+/// Loads the core descriptors with only the tasks reachable from the invoked task
+/// and the init/end/error tasks.
+pub(crate) fn load_internal_descriptors(
+    experimental: bool,
+    external_config: &Config,
+    task: &str,
+) -> Result<Config, CargoMakeError> {
+    debug!("Loading core tasks reachable from task: {}", task);
+
+    let core_groups = core_tasks::get_groups(&external_config.config);
+    let mut descriptors = vec![parse_descriptor(&makefiles::get_stable(
+        core_groups.as_deref(),
+    ))?];
+    if experimental {
+        descriptors.push(parse_descriptor(makefiles::BETA)?);
+    }
+
+    let mut roots = vec![task.to_string()];
+    add_config_tasks(&external_config.config, &mut roots);
+    for descriptor in &descriptors {
+        add_config_tasks(&descriptor.config.config, &mut roots);
+    }
+
+    let loaded_tasks = load_reachable_tasks(&external_config.tasks, &descriptors, roots)?;
+
+    let mut configs = vec![];
+    for (mut descriptor, mut tasks) in descriptors.into_iter().zip(loaded_tasks) {
+        // keep the original tasks order
+        for name in descriptor.raw_tasks.keys() {
+            if let Some(task) = tasks.shift_remove(name) {
+                descriptor.config.tasks.insert(name.to_string(), task);
+            }
+        }
+
+        configs.push(descriptor.config);
+    }
+
+    let mut base_config = configs.remove(0);
+    if let Some(mut experimental_config) = configs.pop() {
+        base_config.tasks = merge_tasks(
+            &mut base_config.tasks,
+            &mut experimental_config.tasks,
+            false,
+        );
+    }
+    debug!("Loaded {} reachable core tasks.", base_config.tasks.len());
+
+    // reset
+    envmnt::set("CARGO_MAKE_CORE_TASK_NAMESPACE", "");
+    envmnt::set("CARGO_MAKE_CORE_TASK_NAMESPACE_PREFIX", "");
+
+    Ok(base_config)
+}
@@ -0,0 +1,160 @@
+use super::*;
+use crate::descriptor::load_internal_descriptors as load_all_internal_descriptors;
+use crate::types::{CoreTasksConfig, DependencyIdentifier, FileScriptValue};
+
+fn create_external_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn assert_same_tasks(lazy_config: &Config, full_config: &Config) {
+    for (name, task) in &lazy_config.tasks {
+        assert_eq!(
+            format!("{:?}", task),
+            format!("{:?}", full_config.tasks[name]),
+            "task: {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn load_internal_descriptors_reachable_only() {
+    let full_config = load_all_internal_descriptors(true, false, None, None).unwrap();
+    let config = load_internal_descriptors(false, &create_external_config(), "ci-flow").unwrap();
+
+    assert!(config.tasks.len() < full_config.tasks.len());
+    assert!(config.tasks.contains_key("ci-flow"));
+    assert!(config.tasks.contains_key("build"));
+    assert!(config.tasks.contains_key("test"));
+    assert!(config.tasks.contains_key("init"));
+    assert!(config.tasks.contains_key("end"));
+    assert!(!config.tasks.contains_key("publish"));
+    assert_same_tasks(&config, &full_config);
+
+    // all references of the loaded tasks are loaded as well
+    for task in config.tasks.values() {
+        for (_, target) in references::get_references(task) {
+            assert!(config.tasks.contains_key(target), "missing: {}", target);
+        }
+    }
+
+    assert_eq!(
+        format!("{:?}", config.config),
+        format!("{:?}", full_config.config)
+    );
+    // decode mappings are hash maps, so the env is not compared via its debug output
+    assert_eq!(
+        serde_json::to_value(&config.env).unwrap(),
+        serde_json::to_value(&full_config.env).unwrap()
+    );
+}
+
+#[test]
+fn load_internal_descriptors_external_references() {
+    let mut external_config = create_external_config();
+    let mut task = Task::new();
+    task.dependencies = Some(vec![DependencyIdentifier::Name("format".to_string())]);
+    external_config.tasks.insert("custom".to_string(), task);
+
+    let config = load_internal_descriptors(false, &external_config, "custom").unwrap();
+
+    assert!(!config.tasks.contains_key("custom"));
+    assert!(config.tasks.contains_key("format"));
+}
+
+#[test]
+fn load_internal_descriptors_unknown_task() {
+    let full_config = load_all_internal_descriptors(true, false, None, None).unwrap();
+    let config =
+        load_internal_descriptors(false, &create_external_config(), "unknown-task").unwrap();
+
+    assert!(config.tasks.contains_key("init"));
+    assert!(config.tasks.contains_key("end"));
+    assert!(!config.tasks.contains_key("unknown-task"));
+    assert_same_tasks(&config, &full_config);
+}
+
+#[test]
+fn load_internal_descriptors_experimental() {
+    let full_config = load_all_internal_descriptors(true, true, None, None).unwrap();
+    let config = load_internal_descriptors(true, &create_external_config(), "ci-flow").unwrap();
+
+    assert!(config.tasks.contains_key("ci-flow"));
+    assert_same_tasks(&config, &full_config);
+}
+
+#[test]
+fn load_internal_descriptors_groups() {
+    let mut external_config = create_external_config();
+    external_config.config.core_tasks = Some(CoreTasksConfig {
+        groups: Some(vec!["build".to_string()]),
+        skip_patterns: None,
+        namespace: None,
+        namespaced: None,
+        aliases: None,
+    });
+
+    let config = load_internal_descriptors(false, &external_config, "build-flow").unwrap();
+
+    assert!(config.tasks.contains_key("build-flow"));
+    assert!(config.tasks.contains_key("build"));
+    assert!(config.tasks.contains_key("init"));
+
+    let config = load_internal_descriptors(false, &external_config, "test-flow").unwrap();
+
+    assert!(!config.tasks.contains_key("test-flow"));
+    assert!(!config.tasks.contains_key("test"));
+}
+
+#[test]
+fn get_script_words_inline_scripts() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "cm_run_task format".to_string(),
+        "cm_run_task --async \"clippy\"".to_string(),
+    ]));
+
+    let words = get_script_words(&task);
+
+    assert_eq!(
+        words,
+        vec!["cm_run_task", "format", "cm_run_task", "--async", "clippy"]
+    );
+}
+
+#[test]
+fn get_script_words_file_script() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::File(FileScriptValue {
+        file: "format".to_string(),
+        absolute_path: None,
+    }));
+
+    assert!(get_script_words(&task).is_empty());
+}
+
+#[test]
+fn load_internal_descriptors_script_references() {
+    let mut external_config = create_external_config();
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::SingleLine("cm_run_task format".to_string()));
+    external_config.tasks.insert("custom".to_string(), task);
+
+    let config = load_internal_descriptors(false, &external_config, "custom").unwrap();
+
+    assert!(config.tasks.contains_key("format"));
+}
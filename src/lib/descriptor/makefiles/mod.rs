@@ -15,8 +15,61 @@ pub(crate) static STABLE: &str = concat!(
     include_str!("github.toml"),
     include_str!("toml.toml"),
     include_str!("rust.toml"),
+    include_str!("rust-build.toml"),
+    include_str!("rust-test.toml"),
+    include_str!("rust-docs.toml"),
+    include_str!("rust-publish.toml"),
     include_str!("rust-coverage.toml"),
     include_str!("rust-wasm.toml"),
     include_str!("deprecated.toml")
 );
 pub(crate) static BETA: &str = include_str!("beta.toml");
+
+/// The stable makefiles which are always loaded, regardless of the selected core task groups
+static STABLE_CORE: &str = concat!(
+    include_str!("stable.toml"),
+    include_str!("build-file.toml"),
+    include_str!("toml.toml"),
+    include_str!("rust.toml"),
+    include_str!("deprecated.toml")
+);
+/// The stable makefiles of each core task group
+static STABLE_GROUPS: [(&str, &str); 7] = [
+    ("build", include_str!("rust-build.toml")),
+    ("test", include_str!("rust-test.toml")),
+    ("docs", include_str!("rust-docs.toml")),
+    ("coverage", include_str!("rust-coverage.toml")),
+    (
+        "publish",
+        concat!(
+            include_str!("rust-publish.toml"),
+            include_str!("github.toml")
+        ),
+    ),
+    ("git", include_str!("git.toml")),
+    ("wasm", include_str!("rust-wasm.toml")),
+];
+
+/// Returns the stable makefile containing only the provided core task groups (all of them if
+/// no groups are provided).
+pub(crate) fn get_stable(groups: Option<&[String]>) -> String {
+    let groups = match groups {
+        Some(groups) => groups,
+        None => return STABLE.to_string(),
+    };
+
+    for group in groups {
+        if !STABLE_GROUPS.iter().any(|(name, _)| name == group) {
+            warn!("Unknown core tasks group: {}", group);
+        }
+    }
+
+    let mut descriptor = STABLE_CORE.to_string();
+    for (name, group_descriptor) in STABLE_GROUPS.iter() {
+        if groups.iter().any(|group| group == name) {
+            descriptor.push_str(group_descriptor);
+        }
+    }
+
+    descriptor
+}
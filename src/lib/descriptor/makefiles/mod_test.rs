@@ -12,7 +12,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 fn load_descriptor() -> Result<Config, CargoMakeError> {
-    descriptor::load_internal_descriptors(true, false, None)
+    descriptor::load_internal_descriptors(true, false, None, None)
 }
 
 fn get_task(name: &str, config: &Config) -> Task {
@@ -37,6 +37,8 @@ fn create_flow_info(config: &Config) -> FlowInfo {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     }
 }
@@ -131,6 +133,21 @@ fn makefile_coverage_kcov_test() {
     makefile_task_enabled_test("coverage-kcov", true, false);
 }
 
+#[test]
+fn makefile_coverage_llvm_cov_test() {
+    makefile_task_script_engine_test("coverage-llvm-cov", EngineType::Duckscript);
+}
+
+#[test]
+fn makefile_coverage_grcov_test() {
+    makefile_task_script_engine_test("coverage-grcov", EngineType::Duckscript);
+}
+
+#[test]
+fn makefile_bench_compare_test() {
+    makefile_task_script_engine_test("bench-compare", EngineType::Duckscript);
+}
+
 #[test]
 fn makefile_copy_apidocs_test() {
     makefile_task_script_engine_test("do-copy-apidocs", EngineType::Duckscript);
@@ -249,3 +266,49 @@ fn makefile_build_file_increment_panic_invalid_data_test() {
 
     runner::run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
 }
+
+fn load_stable(groups: Option<&[String]>) -> Config {
+    descriptor::descriptor_deserializer::load_config(&super::get_stable(groups), false).unwrap()
+}
+
+#[test]
+fn get_stable_all_groups() {
+    assert_eq!(super::get_stable(None), super::STABLE);
+
+    let groups: Vec<String> = [
+        "build", "test", "docs", "coverage", "publish", "git", "wasm",
+    ]
+    .iter()
+    .map(|group| group.to_string())
+    .collect();
+    let config = load_stable(Some(&groups));
+    let all_config = load_stable(None);
+
+    let mut names: Vec<&String> = config.tasks.keys().collect();
+    let mut all_names: Vec<&String> = all_config.tasks.keys().collect();
+    names.sort();
+    all_names.sort();
+    assert_eq!(names, all_names);
+}
+
+#[test]
+fn get_stable_no_groups() {
+    let config = load_stable(Some(&[]));
+
+    assert!(config.tasks.get("init").is_some());
+    assert!(config.tasks.get("clean").is_some());
+    assert!(config.tasks.get("build").is_none());
+    assert!(config.tasks.get("test").is_none());
+    assert!(config.tasks.get("git-commit").is_none());
+    assert!(config.tasks.get("wasm-pack-base").is_none());
+}
+
+#[test]
+fn get_stable_selected_groups() {
+    let config = load_stable(Some(&["build".to_string(), "unknown".to_string()]));
+
+    assert!(config.tasks.get("init").is_some());
+    assert!(config.tasks.get("build").is_some());
+    assert!(config.tasks.get("test").is_none());
+    assert!(config.tasks.get("docs").is_none());
+}
@@ -11,19 +11,35 @@
 mod mod_test;
 
 mod cargo_alias;
+mod core_tasks;
+mod coverage;
+mod cross;
+mod descriptor_cache;
 pub(crate) mod descriptor_deserializer;
+mod duplicate_tasks;
 mod env;
+mod env_profiles;
+mod justfile;
+mod lazy_core_tasks;
 mod makefiles;
+mod references;
+mod stdin_makefile;
+mod tools;
 
+use crate::daemon;
+use crate::descriptor::coverage::merge_coverage_config;
+use crate::descriptor::cross::merge_cross_config;
 use crate::descriptor::env::{merge_env, merge_env_files, merge_env_scripts};
+use crate::descriptor::tools::{merge_audit_config, merge_bench_config, merge_clippy_config};
 use crate::environment;
 use crate::error::CargoMakeError;
+use crate::notification::merge_notifications_config;
 use crate::plugin::descriptor::merge_plugins_config;
 use crate::types::{
     Config, ConfigSection, EnvFile, EnvFileInfo, EnvValue, Extend, ExternalConfig, ModifyConfig,
     Task,
 };
-use crate::{io, scriptengine, version};
+use crate::{io, perf, scriptengine, version};
 use fsio::path::as_path::AsPath;
 use fsio::path::from_path::FromPath;
 use indexmap::IndexMap;
@@ -55,6 +71,8 @@ fn merge_tasks(
 
         task = match base.get(key) {
             Some(ref value) => {
+                duplicate_tasks::add(&key_str, value, &task);
+
                 let mut merged_task = Task::new();
 
                 merged_task.extend(value);
@@ -86,7 +104,7 @@ fn merge_tasks(
 }
 
 fn add_file_location_info(
-    mut external_config: ExternalConfig,
+    external_config: ExternalConfig,
     file_path_string: &str,
 ) -> ExternalConfig {
     let file_path = file_path_string.as_path();
@@ -95,6 +113,14 @@ fn add_file_location_info(
         None => "".to_string(),
     };
 
+    add_location_info(external_config, file_path_string, &base_directory)
+}
+
+fn add_location_info(
+    mut external_config: ExternalConfig,
+    file_path_string: &str,
+    base_directory: &str,
+) -> ExternalConfig {
     match external_config.env_files {
         Some(env_files) => {
             let mut modified_env_files = vec![];
@@ -103,13 +129,13 @@ fn add_file_location_info(
                 match env_file {
                     EnvFile::Path(path) => {
                         let mut info = EnvFileInfo::new(path);
-                        info.base_path = Some(base_directory.clone());
+                        info.base_path = Some(base_directory.to_string());
 
                         modified_env_files.push(EnvFile::Info(info));
                     }
                     EnvFile::Info(mut info) => {
                         if info.base_path.is_none() {
-                            info.base_path = Some(base_directory.clone());
+                            info.base_path = Some(base_directory.to_string());
                         }
 
                         modified_env_files.push(EnvFile::Info(info));
@@ -237,6 +263,13 @@ fn merge_external_configs(
     }
 
     let plugins = merge_plugins_config(parent_config.plugins, config.plugins);
+    let cross = merge_cross_config(parent_config.cross, config.cross);
+    let coverage = merge_coverage_config(parent_config.coverage, config.coverage);
+    let clippy = merge_clippy_config(parent_config.clippy, config.clippy);
+    let audit = merge_audit_config(parent_config.audit, config.audit);
+    let bench = merge_bench_config(parent_config.bench, config.bench);
+    let notifications =
+        merge_notifications_config(parent_config.notifications, config.notifications);
 
     let config = ExternalConfig {
         extend: None,
@@ -246,6 +279,12 @@ fn merge_external_configs(
         env_scripts: Some(all_env_scripts),
         tasks: Some(all_tasks),
         plugins,
+        cross,
+        coverage,
+        clippy,
+        audit,
+        bench,
+        notifications,
     };
 
     Ok(config)
@@ -323,6 +362,60 @@ fn check_makefile_min_version(external_descriptor: &str) -> Result<(), CargoMake
     Ok(())
 }
 
+/// Applies the loaded makefile (already holding its location info) settings and loads the
+/// makefiles it extends, relative to the provided parent path
+fn apply_external_config(
+    file_config: ExternalConfig,
+    file_path_string: &str,
+    parent_path: &str,
+) -> Result<ExternalConfig, CargoMakeError> {
+    // strict mode defined in a makefile also applies to the makefiles it extends
+    if let Some(ref config) = file_config.config {
+        if config.strict.unwrap_or(false) {
+            descriptor_deserializer::set_makefile_strict(true);
+        }
+    }
+
+    env_profiles::add(file_path_string, &file_config.env);
+
+    if run_load_script(&file_config)? {
+        // load scripts may have side effects which the cache will skip
+        descriptor_cache::set_not_cacheable();
+    }
+
+    match file_config.extend {
+        Some(ref extend_struct) => {
+            debug!("External config parent path: {}", &parent_path);
+
+            let base_file_config = perf::measure("Load Descriptor - Extend", || {
+                load_descriptor_extended_makefiles(parent_path, extend_struct)
+            })?;
+
+            merge_external_configs(file_config.clone(), base_file_config)
+        }
+        None => Ok(file_config),
+    }
+}
+
+/// Loads the makefile read from stdin, its location (for env files and extended makefiles) is
+/// the current working directory
+fn load_stdin_external_descriptor() -> Result<ExternalConfig, CargoMakeError> {
+    let external_descriptor = stdin_makefile::read()?;
+
+    check_makefile_min_version(&external_descriptor)?;
+
+    let mut file_config = descriptor_deserializer::load_external_config(
+        &external_descriptor,
+        stdin_makefile::FILE_NAME,
+    )?;
+    debug!("Loaded external config from stdin: {:#?}", &file_config);
+
+    let base_directory = io::canonicalize_to_string(".");
+    file_config = add_location_info(file_config, stdin_makefile::FILE_NAME, &base_directory);
+
+    apply_external_config(file_config, stdin_makefile::FILE_NAME, &base_directory)
+}
+
 fn load_external_descriptor(
     base_path: &str,
     file_name: &str,
@@ -382,6 +475,7 @@ fn load_external_descriptor(
         }
 
         let external_descriptor = io::read_text_file(&file_path)?;
+        descriptor_cache::add_file(&absolute_file_path, Some(&external_descriptor));
 
         check_makefile_min_version(&external_descriptor)?;
 
@@ -391,25 +485,14 @@ fn load_external_descriptor(
 
         file_config = add_file_location_info(file_config, &absolute_file_path);
 
-        run_load_script(&file_config)?;
+        let parent_path_buf = Path::new(&file_path_string).join("..");
+        let parent_path = file_path
+            .parent()
+            .unwrap_or(&parent_path_buf)
+            .to_str()
+            .unwrap_or(".");
 
-        match file_config.extend {
-            Some(ref extend_struct) => {
-                let parent_path_buf = Path::new(&file_path_string).join("..");
-                let parent_path = file_path
-                    .parent()
-                    .unwrap_or(&parent_path_buf)
-                    .to_str()
-                    .unwrap_or(".");
-                debug!("External config parent path: {}", &parent_path);
-
-                let base_file_config =
-                    load_descriptor_extended_makefiles(&parent_path, extend_struct)?;
-
-                merge_external_configs(file_config.clone(), base_file_config)
-            }
-            None => Ok(file_config),
-        }
+        apply_external_config(file_config, &absolute_file_path, parent_path)
     } else if force {
         error!("Descriptor file: {:#?} not found.", &file_path);
         Err(CargoMakeError::NotFound(format!(
@@ -419,6 +502,10 @@ fn load_external_descriptor(
     } else {
         debug!("External file not found or is not a file, skipping.");
 
+        // the cache is invalidated once the file is created
+        let file_path_string: String = FromPath::from_path(&file_path);
+        descriptor_cache::add_file(&file_path_string, None);
+
         Ok(ExternalConfig::new())
     }
 }
@@ -427,13 +514,14 @@ pub(crate) fn load_internal_descriptors(
     stable: bool,
     experimental: bool,
     modify_config: Option<ModifyConfig>,
+    core_groups: Option<&[String]>,
 ) -> Result<Config, CargoMakeError> {
     debug!("Loading base tasks.");
 
     let base_descriptor = if stable {
-        makefiles::STABLE
+        makefiles::get_stable(core_groups)
     } else {
-        makefiles::BASE
+        makefiles::BASE.to_string()
     };
 
     let mut base_config = descriptor_deserializer::load_config(&base_descriptor, false)?;
@@ -541,6 +629,13 @@ fn merge_base_config_and_external_config(
     config_section.extend(&mut external_config.config.unwrap_or(ConfigSection::new()));
 
     let plugins = merge_plugins_config(base_config.plugins, external_config.plugins);
+    let cross = merge_cross_config(base_config.cross, external_config.cross);
+    let coverage = merge_coverage_config(base_config.coverage, external_config.coverage);
+    let clippy = merge_clippy_config(base_config.clippy, external_config.clippy);
+    let audit = merge_audit_config(base_config.audit, external_config.audit);
+    let bench = merge_bench_config(base_config.bench, external_config.bench);
+    let notifications =
+        merge_notifications_config(base_config.notifications, external_config.notifications);
 
     let config = Config {
         config: config_section,
@@ -549,6 +644,12 @@ fn merge_base_config_and_external_config(
         env_scripts,
         tasks: all_tasks,
         plugins,
+        cross,
+        coverage,
+        clippy,
+        audit,
+        bench,
+        notifications,
     };
 
     Ok(config)
@@ -561,6 +662,87 @@ fn split_once(value: &str, delimiter: char) -> Option<(&str, &str)> {
     Some((part1, part2))
 }
 
+/// Loads the external descriptor and all the descriptors it extends (using the
+/// descriptor cache if enabled).
+fn load_root_external_descriptor(
+    file_name: &str,
+    force: bool,
+) -> Result<ExternalConfig, CargoMakeError> {
+    // the stdin content is not tracked by the cache (it can not be compared to a file)
+    if stdin_makefile::is_stdin(file_name) {
+        return load_stdin_external_descriptor();
+    }
+
+    if let Some(external_config) = daemon::request_descriptor(file_name, force)
+        .and_then(|value| descriptor_cache::load_serialized(&value))
+    {
+        debug!("Loaded external config from daemon.");
+        return Ok(external_config);
+    }
+
+    if !descriptor_cache::is_enabled() {
+        return load_external_descriptor(".", file_name, force, true, RelativeTo::Makefile);
+    }
+
+    match descriptor_cache::load(file_name) {
+        Some(external_config) => {
+            debug!("Loaded external config from descriptor cache.");
+            Ok(external_config)
+        }
+        None => {
+            descriptor_cache::start();
+            let external_config =
+                load_external_descriptor(".", file_name, force, true, RelativeTo::Makefile)?;
+            descriptor_cache::store(file_name, &external_config);
+
+            Ok(external_config)
+        }
+    }
+}
+
+/// Skips the report of the invalid task names and references when loading the descriptor, as
+/// the validate command reports them itself
+pub(crate) fn skip_references_report() {
+    references::skip_report();
+}
+
+/// Loads the external descriptor and all the descriptors it extends for the daemon clients
+/// (held in memory until any of the makefiles is modified) and returns it serialized.<br>
+/// None is returned if the descriptor is not cacheable.
+pub(crate) fn load_for_daemon(
+    file_name: &str,
+    force: bool,
+) -> Result<Option<String>, CargoMakeError> {
+    let key = format!(
+        "{}\n{}\n{}",
+        file_name,
+        force,
+        envmnt::is("CARGO_MAKE_STRICT")
+    );
+    if let Some(value) = descriptor_cache::load_from_memory(&key) {
+        debug!("Loaded external config from memory.");
+        return Ok(Some(value));
+    }
+
+    // the daemon loads the makefiles of many requests, the strict mode of a previous one
+    // must not apply
+    descriptor_deserializer::set_makefile_strict(false);
+    descriptor_cache::start();
+    let external_config =
+        load_external_descriptor(".", file_name, force, true, RelativeTo::Makefile)?;
+
+    Ok(descriptor_cache::store_in_memory(&key, &external_config))
+}
+
+/// Loads an additional makefile which is merged over the root external descriptor
+fn load_additional_external_descriptor(file_name: &str) -> Result<ExternalConfig, CargoMakeError> {
+    if stdin_makefile::is_stdin(file_name) {
+        load_stdin_external_descriptor()
+    } else {
+        load_external_descriptor(".", file_name, true, false, RelativeTo::Makefile)
+    }
+}
+
 /// Loads the tasks descriptor.<br>
 /// It will first load the default descriptor which is defined in cargo-make
 /// internally and afterwards tries to find the external descriptor and load it
@@ -575,11 +757,23 @@ fn load_descriptors(
     stable: bool,
     experimental: bool,
     modify_core_tasks: Option<ModifyConfig>,
+    core_groups: Option<&[String]>,
 ) -> Result<Config, CargoMakeError> {
-    let default_config = load_internal_descriptors(stable, experimental, modify_core_tasks)?;
+    duplicate_tasks::clear();
+    env_profiles::clear();
 
-    let mut external_config =
-        load_external_descriptor(".", file_name, force, true, RelativeTo::Makefile)?;
+    let mut default_config =
+        load_internal_descriptors(stable, experimental, modify_core_tasks, core_groups)?;
+
+    let mut external_config = load_root_external_descriptor(file_name, force)?;
+
+    // additional makefiles (repeated --makefile cli argument) are merged in order, same as extend
+    if let Some(additional_file_names) = envmnt::get_list("CARGO_MAKE_ADDITIONAL_MAKEFILES") {
+        for additional_file_name in additional_file_names {
+            let additional_config = load_additional_external_descriptor(&additional_file_name)?;
+            external_config = merge_external_configs(additional_config, external_config)?;
+        }
+    }
 
     external_config = match std::env::var("CARGO_MAKE_WORKSPACE_MAKEFILE") {
         Ok(workspace_makefile) => {
@@ -611,6 +805,16 @@ fn load_descriptors(
         _ => external_config,
     };
 
+    if stable {
+        if let Some(ref config_section) = external_config.config {
+            core_tasks::filter(
+                &mut default_config,
+                config_section,
+                external_config.tasks.as_ref(),
+            );
+        }
+    }
+
     let config =
         merge_base_config_and_external_config(default_config, external_config, env_map, false)?;
 
@@ -637,25 +841,104 @@ fn load_cargo_aliases(config: &mut Config) -> Result<(), CargoMakeError> {
     Ok(())
 }
 
+fn load_just_recipes(config: &mut Config) -> Result<(), CargoMakeError> {
+    if let Some(ref file) = config.config.import_just {
+        let recipe_tasks = justfile::load(file)?;
+        for (name, task) in recipe_tasks {
+            match config.tasks.get(&name) {
+                None => {
+                    debug!("Creating justfile recipe task: {}", &name);
+                    config.tasks.insert(name, task);
+                }
+                Some(_) => debug!("Ignoring justfile recipe task: {}", &name),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn load_cross_tasks(config: &mut Config) {
+    if let Some(ref cross_config) = config.cross {
+        // the cross tasks invoke the (possibly namespaced) core tasks
+        let core_prefix = match core_tasks::get_modify_config(&config.config) {
+            Some(ref modify_config) => modify_config.get_namespace_prefix(),
+            None => "".to_string(),
+        };
+        let cross_tasks = cross::create_tasks(cross_config, &core_prefix);
+        for (name, task) in cross_tasks {
+            match config.tasks.get(&name) {
+                None => {
+                    debug!("Creating cross task: {}", &name);
+                    config.tasks.insert(name, task);
+                }
+                Some(_) => debug!("Ignoring cross task: {}", &name),
+            }
+        }
+    }
+}
+
+fn load_coverage_env(config: &mut Config) {
+    if let Some(ref coverage_config) = config.coverage {
+        // defined first so the env block can reference it (explicitly defined env vars are kept)
+        let mut env = IndexMap::new();
+        for (key, value) in coverage::create_env(coverage_config) {
+            match config.env.get(&key) {
+                None => {
+                    debug!("Setting coverage env: {}", &key);
+                    env.insert(key, value);
+                }
+                Some(_) => debug!("Ignoring coverage env: {}", &key),
+            }
+        }
+
+        env.extend(config.env.drain(..));
+        config.env = env;
+    }
+}
+
+fn load_tools_env(config: &mut Config, external_env_keys: &[String]) {
+    for (key, value) in tools::create_env(config) {
+        if external_env_keys.contains(&key) {
+            debug!("Ignoring tool section env: {}", &key);
+        } else {
+            debug!("Setting tool section env: {}", &key);
+            config.env.insert(key, value);
+        }
+    }
+}
+
+/// Returns the makefiles defining the profile env block (empty for the internal core makefiles)
+pub(crate) fn get_env_profile_locations(name: &str) -> Vec<String> {
+    env_profiles::get(name)
+}
+
 /// Loads the tasks descriptor.<br>
 /// It will first load the default descriptor which is defined in cargo-make
 /// internally and afterwards tries to find the external descriptor and load it
 /// as well.<br> If an external descriptor exists, it will be loaded and extend
 /// the default descriptor. <br> If one of the descriptor requires a newer
 /// version of cargo-make, returns an error with the minimum version required by
-/// the descriptor.
+/// the descriptor.<br>
+/// If a task is provided and the **lazy_load_core_tasks** config attribute is set,
+/// only the core tasks reachable from that task are loaded.
 pub fn load(
     file_name: &str,
     force: bool,
     env_map: Option<Vec<String>>,
     experimental: bool,
+    task: Option<&str>,
 ) -> Result<Config, CargoMakeError> {
     // load extended descriptor only
-    let mut config = load_descriptors(&file_name, force, env_map.clone(), false, false, None)?;
+    let mut config = load_descriptors(file_name, force, env_map.clone(), false, false, None, None)?;
+    // the makefile and cli env vars are not replaced by the tool sections arguments
+    let external_env_keys: Vec<String> = config.env.keys().cloned().collect();
+    // the cross tasks are created before loading the core tasks they invoke (see lazy loading)
+    load_cross_tasks(&mut config);
 
     // need to load core tasks as well
     if !config.config.skip_core_tasks.unwrap_or(false) {
-        let modify_core_tasks = config.config.modify_core_tasks.clone();
+        let modify_core_tasks = core_tasks::get_modify_config(&config.config);
+        let core_groups = core_tasks::get_groups(&config.config);
 
         match modify_core_tasks {
             Some(modify_config) => {
@@ -668,11 +951,25 @@ pub fn load(
                         true,
                         experimental,
                         Some(modify_config),
+                        core_groups.as_deref(),
                     )?;
+                    load_cross_tasks(&mut config);
                 }
             }
             None => {
-                let core_config = load_internal_descriptors(true, experimental, modify_core_tasks)?;
+                let lazy_load = config.config.lazy_load_core_tasks.unwrap_or(false);
+                let mut core_config = match task {
+                    Some(name) if lazy_load => {
+                        lazy_core_tasks::load_internal_descriptors(experimental, &config, name)?
+                    }
+                    _ => load_internal_descriptors(
+                        true,
+                        experimental,
+                        modify_core_tasks,
+                        core_groups.as_deref(),
+                    )?,
+                };
+                core_tasks::filter(&mut core_config, &config.config, Some(&config.tasks));
                 let external_config = ExternalConfig {
                     extend: None,
                     config: Some(config.config),
@@ -681,6 +978,12 @@ pub fn load(
                     env_scripts: Some(config.env_scripts),
                     tasks: Some(config.tasks),
                     plugins: config.plugins,
+                    cross: config.cross,
+                    coverage: config.coverage,
+                    clippy: config.clippy,
+                    audit: config.audit,
+                    bench: config.bench,
+                    notifications: config.notifications,
                 };
 
                 config = merge_base_config_and_external_config(
@@ -693,7 +996,18 @@ pub fn load(
         };
     }
 
+    core_tasks::add_aliases(&mut config);
+    load_coverage_env(&mut config);
+    load_tools_env(&mut config, &external_env_keys);
+
+    if config.config.report_duplicate_tasks.unwrap_or(false) {
+        duplicate_tasks::report(descriptor_deserializer::is_strict())?;
+    }
+
     load_cargo_aliases(&mut config)?;
+    load_just_recipes(&mut config)?;
+
+    references::report(&config, descriptor_deserializer::is_strict())?;
 
     if let Some(unstable_features) = &config.config.unstable_features {
         for feature in unstable_features {
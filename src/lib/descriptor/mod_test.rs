@@ -1,7 +1,10 @@
 use super::*;
 use crate::environment;
 use crate::environment::setup_cwd;
-use crate::types::{ExtendOptions, InstallCrate, ScriptValue};
+use crate::types::{
+    CoverageProvider, CrossMode, ExtendOptions, InstallCrate, NotificationTrigger, ScriptValue,
+    WebhookFormat,
+};
 
 #[test]
 fn merge_tasks_both_empty() {
@@ -177,7 +180,7 @@ fn load_descriptors_load_workspace_makefile() {
         "CARGO_MAKE_WORKSPACE_MAKEFILE",
         "./examples/workspace/Makefile.toml",
     );
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None, None).unwrap();
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
 
     let task = config.tasks.get("workspace-echo");
@@ -191,7 +194,7 @@ fn load_descriptors_load_workspace_makefile_no_exists() {
         "CARGO_MAKE_WORKSPACE_MAKEFILE",
         "./examples/workspace/Makefile2.toml",
     );
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None, None).unwrap();
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
 
     let task = config.tasks.get("workspace-echo");
@@ -202,7 +205,7 @@ fn load_descriptors_load_workspace_makefile_no_exists() {
 #[ignore]
 fn load_descriptors_no_load_workspace_makefile() {
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None, None).unwrap();
 
     let task = config.tasks.get("workspace-echo");
     assert!(task.is_none());
@@ -214,7 +217,7 @@ fn load_env_and_plugins_extended_override() {
     let toml_file = "./src/lib/test/makefiles/env-extended.toml";
 
     envmnt::remove_all(&vec!["IF_UNDEFINED", "COMPOSITE_OF_MAPPED"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let config = load(toml_file, true, None, false, None).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("IF_UNDEFINED", "EXTENDED"));
@@ -249,7 +252,7 @@ fn load_env_override_and_plugins() {
     let toml_file = "./src/lib/test/makefiles/env.toml";
 
     envmnt::remove_all(&vec!["IF_UNDEFINED", "COMPOSITE_OF_MAPPED"]);
-    let mut config = load(toml_file, true, None, false).unwrap();
+    let mut config = load(toml_file, true, None, false, None).unwrap();
     let mut env = IndexMap::<String, EnvValue>::new();
     env.insert(
         "IF_UNDEFINED".to_string(),
@@ -276,6 +279,7 @@ fn load_env_override_and_plugins() {
         true,
         Some(vec!["IF_UNDEFINED=test".to_string()]),
         false,
+        None,
     )
     .unwrap();
     env = IndexMap::<String, EnvValue>::new();
@@ -314,10 +318,36 @@ fn load_env_override_and_plugins() {
     );
 }
 
+#[test]
+#[ignore]
+fn load_cross_tasks() {
+    let config = load("./examples/cross.toml", true, None, false, None).unwrap();
+
+    let cross_config = config.cross.unwrap();
+    assert_eq!(cross_config.targets.unwrap().len(), 2);
+    assert_eq!(cross_config.mode.unwrap(), CrossMode::Auto);
+    assert!(cross_config.release.unwrap());
+
+    assert!(config
+        .tasks
+        .contains_key("cross-build-aarch64-unknown-linux-gnu"));
+    assert!(config
+        .tasks
+        .contains_key("cross-test-x86_64-pc-windows-gnu"));
+    assert!(config.tasks.contains_key("cross-target-build"));
+
+    let task = config.tasks.get("cross-build").unwrap();
+    assert_eq!(task.dependencies.as_ref().unwrap().len(), 2);
+
+    // tasks defined in the makefile are not replaced by the generated tasks
+    let task = config.tasks.get("cross-test").unwrap();
+    assert_eq!(task.dependencies.as_ref().unwrap().len(), 1);
+}
+
 #[test]
 #[ignore]
 fn load_no_stable() {
-    let config = load("./examples/skip_core_tasks.toml", true, None, false).unwrap();
+    let config = load("./examples/skip_core_tasks.toml", true, None, false, None).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_none());
 
@@ -341,7 +371,7 @@ fn load_no_stable() {
 #[test]
 #[ignore]
 fn load_with_stable() {
-    let config = load("./examples/simple-example.toml", true, None, false).unwrap();
+    let config = load("./examples/simple-example.toml", true, None, false, None).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_some());
 
@@ -366,7 +396,7 @@ fn load_with_stable() {
 #[test]
 #[ignore]
 fn load_with_modify() {
-    let config = load("./examples/modify_core_tasks.toml", true, None, false).unwrap();
+    let config = load("./examples/modify_core_tasks.toml", true, None, false, None).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_some());
 
@@ -379,15 +409,137 @@ fn load_with_modify() {
     assert!(task.is_some());
 }
 
+#[test]
+#[ignore]
+fn load_with_core_tasks_filter() {
+    let config = load("./examples/core_tasks_filter.toml", true, None, false, None).unwrap();
+
+    assert!(config.tasks.get("build").is_none());
+    assert!(config.tasks.get("core::build").is_some());
+    assert!(config.tasks.get("core::coverage").is_some());
+    assert!(config.tasks.get("core::coverage-kcov").is_none());
+    assert!(config.tasks.get("core::wasm-pack-base").is_none());
+    assert!(!config
+        .tasks
+        .keys()
+        .any(|name| name.starts_with("core::wasm-")));
+    assert!(config.tasks.get("ci").is_some());
+}
+
+#[test]
+#[ignore]
+fn load_with_core_tasks_groups() {
+    let config = load("./examples/core_tasks_groups.toml", true, None, false, None).unwrap();
+
+    assert!(config.tasks.get("init").is_some());
+    assert!(config.tasks.get("build").is_some());
+    assert!(config.tasks.get("test").is_some());
+    assert!(config.tasks.get("docs").is_none());
+    assert!(config.tasks.get("coverage").is_none());
+    assert!(config.tasks.get("git-commit").is_none());
+    assert!(config.tasks.get("wasm-pack-base").is_none());
+    assert!(config.tasks.get("custom").is_some());
+    assert!(references::find_issues(&config).is_empty());
+}
+
+#[test]
+#[ignore]
+fn load_with_core_tasks_namespaced() {
+    let config = load(
+        "./examples/core_tasks_namespaced.toml",
+        true,
+        None,
+        false,
+        None,
+    )
+    .unwrap();
+
+    assert!(config.tasks.get("core::build").is_some());
+    assert!(config.tasks.get("core::ci-flow").is_some());
+    assert!(config.tasks.get("build").unwrap().alias.is_none());
+    assert_eq!(
+        config.tasks.get("ci-flow").unwrap().alias.clone().unwrap(),
+        "core::ci-flow"
+    );
+    assert!(references::find_issues(&config).is_empty());
+}
+
+#[test]
+fn load_with_coverage() {
+    let config = load("./examples/coverage.toml", true, None, false, None).unwrap();
+
+    assert_eq!(
+        config.coverage.unwrap().provider.unwrap(),
+        CoverageProvider::LlvmCov
+    );
+    let keys: Vec<&String> = config.env.keys().take(3).collect();
+    assert_eq!(
+        keys,
+        vec![
+            "CARGO_MAKE_COVERAGE_PROVIDER",
+            "CARGO_MAKE_COVERAGE_FORMATS",
+            "CARGO_MAKE_COVERAGE_REPORT_DIRECTORY"
+        ]
+    );
+    match config.env.get("CARGO_MAKE_COVERAGE_FORMATS").unwrap() {
+        EnvValue::Value(ref value) => assert_eq!(value, "lcov;html"),
+        _ => panic!("invalid env value"),
+    }
+    assert!(config.env.get("CODECOV_FILE").is_some());
+}
+
+#[test]
+fn load_with_tool_sections() {
+    let config = load("./examples/tools.toml", true, None, false, None).unwrap();
+
+    match config.env.get("CARGO_MAKE_CLIPPY_ARGS").unwrap() {
+        EnvValue::Value(ref value) => assert_eq!(
+            value,
+            "--all-targets -- -D warnings -A clippy::module_name_repetitions"
+        ),
+        _ => panic!("invalid env value"),
+    }
+    match config.env.get("CARGO_MAKE_AUDIT_ARGS").unwrap() {
+        EnvValue::Value(ref value) => assert_eq!(value, "--ignore RUSTSEC-2021-0145"),
+        _ => panic!("invalid env value"),
+    }
+}
+
+#[test]
+fn load_with_bench() {
+    let config = load("./examples/bench.toml", true, None, false, None).unwrap();
+    let bench_config = config.bench.unwrap();
+
+    assert_eq!(bench_config.baseline.unwrap(), "main");
+    assert_eq!(bench_config.threshold.unwrap(), 5.0);
+    assert!(config.tasks.get("bench-compare").is_some());
+}
+
+#[test]
+fn load_with_notifications() {
+    let config = load("./examples/notifications.toml", true, None, false, None).unwrap();
+    let notifications = config.notifications.unwrap();
+
+    assert_eq!(notifications.on, Some(NotificationTrigger::Always));
+    assert_eq!(notifications.min_duration, Some(60));
+    assert_eq!(notifications.desktop, Some(true));
+    let webhooks = notifications.webhooks.unwrap();
+    assert_eq!(webhooks.len(), 2);
+    assert_eq!(webhooks[0].url, "${SLACK_WEBHOOK_URL}");
+    assert_eq!(webhooks[0].format, Some(WebhookFormat::Slack));
+    assert_eq!(webhooks[0].on, Some(NotificationTrigger::Failure));
+    assert!(webhooks[1].format.is_none());
+}
+
 #[test]
 #[should_panic]
 fn load_not_found() {
-    load("./examples/not-found.toml", true, None, false).unwrap();
+    load("./examples/not-found.toml", true, None, false, None).unwrap();
 }
 
 #[test]
 fn load_internal_descriptors_no_stable() {
-    let config = load_internal_descriptors(false, false, None).unwrap();
+    let config = load_internal_descriptors(false, false, None, None).unwrap();
 
     let mut task = config.tasks.get("empty");
     assert!(task.is_some());
@@ -397,7 +549,7 @@ fn load_internal_descriptors_no_stable() {
 
 #[test]
 fn load_internal_descriptors_with_stable() {
-    let config = load_internal_descriptors(true, false, None).unwrap();
+    let config = load_internal_descriptors(true, false, None, None).unwrap();
 
     let mut task = config.tasks.get("empty");
     assert!(task.is_some());
@@ -407,7 +559,7 @@ fn load_internal_descriptors_with_stable() {
 
 #[test]
 fn load_internal_descriptors_no_experimental() {
-    let config = load_internal_descriptors(true, false, None).unwrap();
+    let config = load_internal_descriptors(true, false, None, None).unwrap();
 
     let mut task = config.tasks.get("ci-flow");
     assert!(task.is_some());
@@ -417,7 +569,7 @@ fn load_internal_descriptors_no_experimental() {
 
 #[test]
 fn load_internal_descriptors_with_experimental() {
-    let config = load_internal_descriptors(true, true, None).unwrap();
+    let config = load_internal_descriptors(true, true, None, None).unwrap();
 
     let mut task = config.tasks.get("ci-flow");
     assert!(task.is_some());
@@ -434,6 +586,7 @@ fn load_internal_descriptors_modify_empty() {
             private: None,
             namespace: None,
         }),
+        None,
     )
     .unwrap();
 
@@ -454,6 +607,7 @@ fn load_internal_descriptors_modify_private() {
             private: Some(true),
             namespace: None,
         }),
+        None,
     )
     .unwrap();
 
@@ -474,6 +628,7 @@ fn load_internal_descriptors_modify_namespace() {
             private: None,
             namespace: Some("default".to_string()),
         }),
+        None,
     )
     .unwrap();
 
@@ -1047,7 +1202,7 @@ fn check_makefile_min_version_same_min_version() {
 
 #[test]
 fn load_cargo_aliases_no_file() {
-    let mut config = load_internal_descriptors(false, false, None).unwrap();
+    let mut config = load_internal_descriptors(false, false, None, None).unwrap();
     let count = config.tasks.len();
 
     load_cargo_aliases(&mut config).unwrap();
@@ -1058,7 +1213,7 @@ fn load_cargo_aliases_no_file() {
 #[test]
 #[ignore]
 fn load_cargo_aliases_found() {
-    let mut config = load_internal_descriptors(false, false, None).unwrap();
+    let mut config = load_internal_descriptors(false, false, None, None).unwrap();
     let count = config.tasks.len();
 
     setup_cwd(Some("src/lib/test/workspace1/member1"));
@@ -1067,3 +1222,49 @@ fn load_cargo_aliases_found() {
 
     assert_eq!(count, config.tasks.len());
 }
+
+#[test]
+fn add_location_info_stdin() {
+    let mut external_config = ExternalConfig::new();
+    external_config.env_files = Some(vec![EnvFile::Path("./stdin.env".to_string())]);
+    let mut tasks = IndexMap::new();
+    tasks.insert("build".to_string(), Task::new());
+    external_config.tasks = Some(tasks);
+
+    let external_config = add_location_info(external_config, "-", "/project");
+
+    match external_config.env_files.unwrap()[0] {
+        EnvFile::Info(ref info) => assert_eq!(info.base_path.clone().unwrap(), "/project"),
+        _ => panic!("env file location not set"),
+    };
+    let tasks = external_config.tasks.unwrap();
+    let env = tasks.get("build").unwrap().env.clone().unwrap();
+    match env.get("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE").unwrap() {
+        EnvValue::Value(ref value) => assert_eq!(value, "-"),
+        _ => panic!("makefile location not set"),
+    };
+    match env
+        .get("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY")
+        .unwrap()
+    {
+        EnvValue::Value(ref value) => assert_eq!(value, "/project"),
+        _ => panic!("makefile directory not set"),
+    };
+}
+
+#[test]
+#[ignore]
+fn load_additional_makefiles() {
+    envmnt::set_list(
+        "CARGO_MAKE_ADDITIONAL_MAKEFILES",
+        &vec!["./examples/makefile_overrides.toml".to_string()],
+    );
+    let config = load("./examples/makefile_base.toml", true, None, false, None);
+    envmnt::remove("CARGO_MAKE_ADDITIONAL_MAKEFILES");
+    let config = config.unwrap();
+
+    let task = config.tasks.get("echo").unwrap();
+    assert_eq!(task.command.clone().unwrap(), "echo");
+    assert_eq!(task.args.clone().unwrap(), vec!["overridden".to_string()]);
+    assert!(config.tasks.contains_key("ci-only"));
+}
@@ -0,0 +1,219 @@
+//! # references
+//!
+//! Validates the task names and the task references (dependencies, aliases, run_task,
+//! extend and on_error_task) of the loaded descriptor, so invalid references are
+//! reported before any task is invoked.
+//!
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod references_test;
+
+use crate::descriptor::duplicate_tasks;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::types::{Config, DependencyIdentifier, RunTaskInfo, RunTaskName, Task};
+use crate::validator;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// True if the issues are reported by the caller (see the validate command)
+static REPORT_SKIPPED: AtomicBool = AtomicBool::new(false);
+
+fn add_dependencies<'a>(
+    prefix: &str,
+    dependencies: &'a Option<Vec<DependencyIdentifier>>,
+    references: &mut Vec<(String, &'a str)>,
+) {
+    if let Some(ref dependencies) = dependencies {
+        for dependency in dependencies {
+            // dependencies from other makefiles are resolved only when running the flow
+            let external = match dependency {
+                DependencyIdentifier::Definition(identifier) => identifier.path.is_some(),
+                DependencyIdentifier::Name(_) => false,
+            };
+
+            if !external {
+                references.push((format!("{}dependency", prefix), dependency.name()));
+            }
+        }
+    }
+}
+
+fn add_run_task_name<'a>(
+    prefix: &str,
+    name: &'a RunTaskName,
+    cleanup_task: &'a Option<String>,
+    references: &mut Vec<(String, &'a str)>,
+) {
+    match name {
+        RunTaskName::Single(ref value) => references.push((format!("{}run_task", prefix), value)),
+        RunTaskName::Multiple(ref values) => {
+            for value in values {
+                references.push((format!("{}run_task", prefix), value));
+            }
+        }
+    }
+
+    if let Some(ref value) = cleanup_task {
+        references.push((format!("{}cleanup_task", prefix), value));
+    }
+}
+
+fn add_run_task<'a>(
+    prefix: &str,
+    run_task: &'a Option<RunTaskInfo>,
+    references: &mut Vec<(String, &'a str)>,
+) {
+    match run_task {
+        Some(RunTaskInfo::Name(ref name)) => {
+            references.push((format!("{}run_task", prefix), name));
+        }
+        Some(RunTaskInfo::Details(ref details)) => {
+            add_run_task_name(prefix, &details.name, &details.cleanup_task, references);
+        }
+        Some(RunTaskInfo::Routing(ref routes)) => {
+            for route in routes {
+                add_run_task_name(prefix, &route.name, &route.cleanup_task, references);
+            }
+        }
+        Some(RunTaskInfo::Pipe(ref pipe)) => {
+            for name in &pipe.pipe {
+                references.push((format!("{}run_task", prefix), name));
+            }
+        }
+        None => (),
+    }
+}
+
+fn add_optional<'a>(
+    attribute: String,
+    value: &'a Option<String>,
+    references: &mut Vec<(String, &'a str)>,
+) {
+    if let Some(ref value) = value {
+        references.push((attribute, value));
+    }
+}
+
+/// Returns all task names referenced by the task as (attribute, task name) pairs
+pub(crate) fn get_references(task: &Task) -> Vec<(String, &str)> {
+    let mut references = vec![];
+
+    add_dependencies("", &task.dependencies, &mut references);
+    add_run_task("", &task.run_task, &mut references);
+    add_optional("extend".to_string(), &task.extend, &mut references);
+    add_optional("alias".to_string(), &task.alias, &mut references);
+    add_optional(
+        "linux_alias".to_string(),
+        &task.linux_alias,
+        &mut references,
+    );
+    add_optional(
+        "windows_alias".to_string(),
+        &task.windows_alias,
+        &mut references,
+    );
+    add_optional("mac_alias".to_string(), &task.mac_alias, &mut references);
+    add_optional(
+        "on_error_task".to_string(),
+        &task.on_error_task,
+        &mut references,
+    );
+
+    for (platform, override_task) in [
+        ("linux", &task.linux),
+        ("windows", &task.windows),
+        ("mac", &task.mac),
+        ("ci", &task.ci),
+    ] {
+        if let Some(ref override_task) = override_task {
+            let prefix = format!("{}.", platform);
+
+            add_dependencies(&prefix, &override_task.dependencies, &mut references);
+            add_run_task(&prefix, &override_task.run_task, &mut references);
+            add_optional(
+                format!("{}extend", prefix),
+                &override_task.extend,
+                &mut references,
+            );
+            add_optional(
+                format!("{}on_error_task", prefix),
+                &override_task.on_error_task,
+                &mut references,
+            );
+        }
+    }
+
+    references
+}
+
+/// Returns all invalid task names and references found in the descriptor
+pub(crate) fn find_issues(config: &Config) -> Vec<String> {
+    let mut issues = vec![];
+
+    // the core tasks are always validated with the default policy
+    let default_task_name_policy = validator::ValidationPolicy::default();
+    let task_name_policy = match config.config.task_name_validation {
+        Some(ref task_name_validation) => {
+            validator::ValidationPolicy::from_config(task_name_validation)
+        }
+        None => default_task_name_policy.clone(),
+    };
+
+    for (name, task) in &config.tasks {
+        let location = duplicate_tasks::get_location(task);
+        let policy = if task.get_makefile_path().is_some() {
+            &task_name_policy
+        } else {
+            &default_task_name_policy
+        };
+
+        if let Err(error) = validator::validate_task_name_with_policy(name, policy) {
+            issues.push(format!(
+                "Task: {} (defined in: {}) has an invalid name, {}",
+                name, &location, error
+            ));
+        }
+
+        for (attribute, target) in get_references(task) {
+            if !config.tasks.contains_key(target)
+                && !execution_plan::is_forwarded_task_name(config, target)
+            {
+                issues.push(format!(
+                    "Task: {} (defined in: {}) {} references undefined task: {}",
+                    name, &location, attribute, target
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// Skips the report of the invalid task names and references when loading the descriptor
+pub(crate) fn skip_report() {
+    REPORT_SKIPPED.store(true, Ordering::SeqCst);
+}
+
+/// Prints all invalid task names and references and fails in strict mode
+pub(crate) fn report(config: &Config, strict: bool) -> Result<(), CargoMakeError> {
+    if REPORT_SKIPPED.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let issues = find_issues(config);
+
+    for issue in &issues {
+        warn!("{}", issue);
+    }
+
+    if strict && !issues.is_empty() {
+        error!(
+            "Found {} invalid task name(s) or reference(s) (strict mode).",
+            issues.len()
+        );
+        return Err(CargoMakeError::ValidationFailed(issues.len()));
+    }
+
+    Ok(())
+}
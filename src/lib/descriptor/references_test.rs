@@ -0,0 +1,194 @@
+use super::*;
+use crate::types::{
+    ConfigSection, EnvValue, ForwardAlias, PlatformOverrideTask, RunTaskDetails, TaskIdentifier,
+    TaskNameValidationConfig,
+};
+use indexmap::IndexMap;
+
+fn create_config() -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn create_task(makefile: &str) -> Task {
+    let mut task = Task::new();
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value(makefile.to_string()),
+    );
+    task.env = Some(env);
+
+    task
+}
+
+#[test]
+fn find_issues_empty() {
+    let config = create_config();
+
+    let issues = find_issues(&config);
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn find_issues_valid() {
+    let mut config = create_config();
+
+    let mut task = create_task("./Makefile.toml");
+    task.dependencies = Some(vec![
+        DependencyIdentifier::Name("base".to_string()),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "external".to_string(),
+            path: Some("./other".to_string()),
+            args: None,
+        }),
+    ]);
+    task.run_task = Some(RunTaskInfo::Name("base".to_string()));
+    task.extend = Some("base".to_string());
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("base".to_string(), Task::new());
+
+    let issues = find_issues(&config);
+
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
+
+#[test]
+fn find_issues_forward_alias() {
+    let mut config = create_config();
+
+    let mut task = create_task("./Makefile.toml");
+    task.dependencies = Some(vec![DependencyIdentifier::Name("old".to_string())]);
+    config.tasks.insert("test".to_string(), task);
+    let mut task = Task::new();
+    task.forward_alias = Some(ForwardAlias {
+        name: "old".to_string(),
+        until: None,
+    });
+    config.tasks.insert("new".to_string(), task);
+
+    let issues = find_issues(&config);
+
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
+
+#[test]
+fn find_issues_task_name_policy() {
+    let mut config = create_config();
+    config.config.task_name_validation = Some(TaskNameValidationConfig {
+        allow_unicode: Some(true),
+        ..Default::default()
+    });
+    config
+        .tasks
+        .insert("tâche".to_string(), create_task("./Makefile.toml"));
+
+    let issues = find_issues(&config);
+    assert!(issues.is_empty(), "{:#?}", issues);
+
+    // core tasks are validated with the default policy
+    config.tasks.insert("cœur".to_string(), Task::new());
+
+    let issues = find_issues(&config);
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0]
+        .starts_with("Task: cœur (defined in: internal core makefile) has an invalid name"));
+}
+
+#[test]
+fn find_issues_all_reported() {
+    let mut config = create_config();
+
+    let mut task = create_task("./Makefile.toml");
+    task.dependencies = Some(vec![DependencyIdentifier::Name("missing1".to_string())]);
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Multiple(vec!["test".to_string(), "missing2".to_string()]),
+        fork: Some(true),
+        parallel: None,
+        cleanup_task: Some("missing3".to_string()),
+        args: None,
+    }));
+    task.extend = Some("missing4".to_string());
+    task.on_error_task = Some("missing7".to_string());
+    let override_task: PlatformOverrideTask =
+        serde_json::from_str("{\"dependencies\": [\"missing5\"]}").unwrap();
+    task.linux = Some(override_task);
+    config.tasks.insert("test".to_string(), task);
+
+    let mut task = Task::new();
+    task.alias = Some("missing6".to_string());
+    config.tasks.insert("bad name".to_string(), task);
+
+    let issues = find_issues(&config);
+
+    assert_eq!(
+        issues,
+        vec![
+            "Task: test (defined in: ./Makefile.toml) dependency references undefined task: missing1",
+            "Task: test (defined in: ./Makefile.toml) run_task references undefined task: missing2",
+            "Task: test (defined in: ./Makefile.toml) cleanup_task references undefined task: missing3",
+            "Task: test (defined in: ./Makefile.toml) extend references undefined task: missing4",
+            "Task: test (defined in: ./Makefile.toml) on_error_task references undefined task: missing7",
+            "Task: test (defined in: ./Makefile.toml) linux.dependency references undefined task: missing5",
+            "Task: bad name (defined in: internal core makefile) has an invalid name, Invalid character ' ' at position 3 (only ASCII alphanumeric, hyphens, underscores, and '::' are allowed)",
+            "Task: bad name (defined in: internal core makefile) alias references undefined task: missing6",
+        ]
+    );
+}
+
+#[test]
+fn report_valid_strict() {
+    let config = create_config();
+
+    report(&config, true).unwrap();
+}
+
+#[test]
+fn report_invalid_not_strict() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.alias = Some("missing".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    report(&config, false).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn report_invalid_strict() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.alias = Some("missing".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    report(&config, true).unwrap();
+}
+
+#[test]
+#[ignore]
+fn report_skipped_invalid_strict() {
+    let mut config = create_config();
+    let mut task = Task::new();
+    task.alias = Some("missing".to_string());
+    config.tasks.insert("test".to_string(), task);
+
+    skip_report();
+    let result = report(&config, true);
+    REPORT_SKIPPED.store(false, Ordering::SeqCst);
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,43 @@
+//! # stdin_makefile
+//!
+//! Reads the makefile provided via stdin (--makefile -).<br>
+//! The stdin can only be consumed once, so the content is kept for any later descriptor reload.
+//!
+
+#[cfg(test)]
+#[path = "stdin_makefile_test.rs"]
+mod stdin_makefile_test;
+
+use crate::error::CargoMakeError;
+use once_cell::sync::Lazy;
+use std::io::{self, Read};
+use std::sync::Mutex;
+
+/// The makefile name which reads the makefile from stdin
+pub(crate) static FILE_NAME: &str = "-";
+
+static CONTENT: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+/// Returns true if the makefile should be read from stdin
+pub(crate) fn is_stdin(file_name: &str) -> bool {
+    file_name == FILE_NAME
+}
+
+fn read_content(reader: &mut dyn Read) -> Result<String, CargoMakeError> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+
+    Ok(content)
+}
+
+/// Returns the makefile content read from stdin (stdin is only read on the first invocation)
+pub(crate) fn read() -> Result<String, CargoMakeError> {
+    let mut content = CONTENT.lock().unwrap();
+
+    if content.is_none() {
+        debug!("Reading makefile from stdin.");
+        *content = Some(read_content(&mut io::stdin())?);
+    }
+
+    Ok(content.clone().unwrap_or_default())
+}
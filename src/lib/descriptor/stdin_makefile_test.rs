@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn is_stdin_dash() {
+    assert!(is_stdin("-"));
+}
+
+#[test]
+fn is_stdin_file() {
+    assert!(!is_stdin("./Makefile.toml"));
+}
+
+#[test]
+fn read_content_valid() {
+    let mut reader = "[tasks.build]\ncommand = \"cargo\"\n".as_bytes();
+
+    let content = read_content(&mut reader).unwrap();
+
+    assert_eq!(content, "[tasks.build]\ncommand = \"cargo\"\n");
+}
+
+#[test]
+fn read_content_empty() {
+    let mut reader = "".as_bytes();
+
+    let content = read_content(&mut reader).unwrap();
+
+    assert!(content.is_empty());
+}
@@ -0,0 +1,114 @@
+//! # tools
+//!
+//! Merges the makefile tool sections (clippy, audit and bench) and converts the clippy and audit
+//! sections to the arguments env vars consumed by the core tasks.
+//!
+
+#[cfg(test)]
+#[path = "tools_test.rs"]
+mod tools_test;
+
+use crate::types::{AuditConfig, BenchConfig, ClippyConfig, Config, EnvValue};
+use indexmap::IndexMap;
+
+pub(crate) fn merge_clippy_config(
+    base: Option<ClippyConfig>,
+    extended: Option<ClippyConfig>,
+) -> Option<ClippyConfig> {
+    match base {
+        Some(mut base_clippy) => match extended {
+            Some(ref extended_clippy) => {
+                base_clippy.extend(extended_clippy);
+                Some(base_clippy)
+            }
+            None => Some(base_clippy),
+        },
+        None => extended,
+    }
+}
+
+pub(crate) fn merge_audit_config(
+    base: Option<AuditConfig>,
+    extended: Option<AuditConfig>,
+) -> Option<AuditConfig> {
+    match base {
+        Some(mut base_audit) => match extended {
+            Some(ref extended_audit) => {
+                base_audit.extend(extended_audit);
+                Some(base_audit)
+            }
+            None => Some(base_audit),
+        },
+        None => extended,
+    }
+}
+
+pub(crate) fn merge_bench_config(
+    base: Option<BenchConfig>,
+    extended: Option<BenchConfig>,
+) -> Option<BenchConfig> {
+    match base {
+        Some(mut base_bench) => match extended {
+            Some(ref extended_bench) => {
+                base_bench.extend(extended_bench);
+                Some(base_bench)
+            }
+            None => Some(base_bench),
+        },
+        None => extended,
+    }
+}
+
+fn add_flags(args: &mut Vec<String>, flag: &str, values: &Option<Vec<String>>) {
+    if let Some(ref values) = values {
+        for value in values {
+            args.push(flag.to_string());
+            args.push(value.to_string());
+        }
+    }
+}
+
+/// Returns the cargo clippy arguments, where the lint levels are passed after the -- separator
+fn get_clippy_args(clippy_config: &ClippyConfig) -> Vec<String> {
+    let mut args = clippy_config.args.clone().unwrap_or_default();
+
+    let mut lint_args = vec![];
+    add_flags(&mut lint_args, "-D", &clippy_config.deny);
+    add_flags(&mut lint_args, "-W", &clippy_config.warn);
+    add_flags(&mut lint_args, "-A", &clippy_config.allow);
+    if !lint_args.is_empty() {
+        args.push("--".to_string());
+        args.extend(lint_args);
+    }
+
+    args
+}
+
+fn get_audit_args(audit_config: &AuditConfig) -> Vec<String> {
+    let mut args = vec![];
+    add_flags(&mut args, "--ignore", &audit_config.ignore);
+    add_flags(&mut args, "--deny", &audit_config.deny);
+
+    args
+}
+
+/// Returns the core tasks arguments env vars of the defined tool sections
+pub(crate) fn create_env(config: &Config) -> IndexMap<String, EnvValue> {
+    let mut env = IndexMap::new();
+
+    if let Some(ref clippy_config) = config.clippy {
+        env.insert(
+            "CARGO_MAKE_CLIPPY_ARGS".to_string(),
+            EnvValue::Value(get_clippy_args(clippy_config).join(" ")),
+        );
+    }
+
+    if let Some(ref audit_config) = config.audit {
+        env.insert(
+            "CARGO_MAKE_AUDIT_ARGS".to_string(),
+            EnvValue::Value(get_audit_args(audit_config).join(" ")),
+        );
+    }
+
+    env
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+fn get_env_value(env: &IndexMap<String, EnvValue>, key: &str) -> String {
+    match env.get(key).unwrap() {
+        EnvValue::Value(ref value) => value.to_string(),
+        _ => panic!("invalid env value"),
+    }
+}
+
+#[test]
+fn merge_clippy_config_both_none() {
+    let clippy_config = merge_clippy_config(None, None);
+
+    assert!(clippy_config.is_none());
+}
+
+#[test]
+fn merge_clippy_config_both_defined() {
+    let mut base = ClippyConfig::new();
+    base.args = Some(vec!["--all-targets".to_string()]);
+    base.deny = Some(vec!["warnings".to_string()]);
+    let mut extended = ClippyConfig::new();
+    extended.deny = Some(vec!["clippy::pedantic".to_string()]);
+
+    let clippy_config = merge_clippy_config(Some(base), Some(extended)).unwrap();
+
+    assert_eq!(clippy_config.args.unwrap(), vec!["--all-targets"]);
+    assert_eq!(clippy_config.deny.unwrap(), vec!["clippy::pedantic"]);
+}
+
+#[test]
+fn merge_audit_config_extended_only() {
+    let mut extended = AuditConfig::new();
+    extended.ignore = Some(vec!["RUSTSEC-2020-0071".to_string()]);
+
+    let audit_config = merge_audit_config(None, Some(extended.clone())).unwrap();
+
+    assert_eq!(audit_config, extended);
+}
+
+#[test]
+fn get_clippy_args_empty() {
+    let args = get_clippy_args(&ClippyConfig::new());
+
+    assert!(args.is_empty());
+}
+
+#[test]
+fn get_clippy_args_no_lints() {
+    let mut clippy_config = ClippyConfig::new();
+    clippy_config.args = Some(vec!["--all-targets".to_string()]);
+
+    let args = get_clippy_args(&clippy_config);
+
+    assert_eq!(args, vec!["--all-targets"]);
+}
+
+#[test]
+fn get_clippy_args_all_values() {
+    let clippy_config = ClippyConfig {
+        args: Some(vec!["--all-targets".to_string()]),
+        deny: Some(vec!["warnings".to_string()]),
+        warn: Some(vec!["clippy::pedantic".to_string()]),
+        allow: Some(vec!["clippy::module_name_repetitions".to_string()]),
+    };
+
+    let args = get_clippy_args(&clippy_config);
+
+    assert_eq!(
+        args,
+        vec![
+            "--all-targets",
+            "--",
+            "-D",
+            "warnings",
+            "-W",
+            "clippy::pedantic",
+            "-A",
+            "clippy::module_name_repetitions"
+        ]
+    );
+}
+
+#[test]
+fn get_audit_args_all_values() {
+    let audit_config = AuditConfig {
+        ignore: Some(vec![
+            "RUSTSEC-2020-0071".to_string(),
+            "RUSTSEC-2021-0145".to_string(),
+        ]),
+        deny: Some(vec!["warnings".to_string()]),
+    };
+
+    let args = get_audit_args(&audit_config);
+
+    assert_eq!(
+        args,
+        vec![
+            "--ignore",
+            "RUSTSEC-2020-0071",
+            "--ignore",
+            "RUSTSEC-2021-0145",
+            "--deny",
+            "warnings"
+        ]
+    );
+}
+
+#[test]
+fn create_env_no_sections() {
+    let env = create_env(&Config::default());
+
+    assert!(env.is_empty());
+}
+
+#[test]
+fn create_env_all_sections() {
+    let mut config = Config::default();
+    let mut clippy_config = ClippyConfig::new();
+    clippy_config.deny = Some(vec!["warnings".to_string()]);
+    config.clippy = Some(clippy_config);
+    let mut audit_config = AuditConfig::new();
+    audit_config.ignore = Some(vec!["RUSTSEC-2020-0071".to_string()]);
+    config.audit = Some(audit_config);
+
+    let env = create_env(&config);
+
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_CLIPPY_ARGS"),
+        "-- -D warnings"
+    );
+    assert_eq!(
+        get_env_value(&env, "CARGO_MAKE_AUDIT_ARGS"),
+        "--ignore RUSTSEC-2020-0071"
+    );
+}
@@ -0,0 +1,220 @@
+//! # diagnostics
+//!
+//! Reports the descriptor errors, validation issues and task failures as structured JSON
+//! diagnostics (one per line) on the stderr, so they can be consumed by IDE problem matchers
+//! and bots (see the --error-format CLI argument).
+//!
+
+#[cfg(test)]
+#[path = "diagnostics_test.rs"]
+mod diagnostics_test;
+
+use crate::error::{CargoMakeError, CargoMakeErrorDiscriminants};
+use crate::types::CliArgs;
+use std::env;
+use std::io::{stderr, Write};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ERROR_FORMAT_ENV_VAR_NAME: &str = "CARGO_MAKE_ERROR_FORMAT";
+
+static ERROR_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// The code of errors reported via the error log which have no specific error code
+static ERROR_LOG_CODE: &str = "Error";
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+/// The 1 based location of the diagnostic in the file
+pub(crate) struct Span {
+    /// The line number
+    line: usize,
+    /// The column number
+    column: usize,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+/// A single reported error
+struct Diagnostic {
+    /// The error code
+    code: String,
+    /// The error message
+    message: String,
+    /// The file in which the error was found
+    file: Option<String>,
+    /// The error location in the file
+    span: Option<Span>,
+    /// The task which failed
+    task: Option<String>,
+}
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    match cli_args.error_format.as_str() {
+        "human" | "json" => envmnt::set(ERROR_FORMAT_ENV_VAR_NAME, &cli_args.error_format),
+        _ => {
+            warn!(
+                "Unsupported error format: {}, using human instead.",
+                &cli_args.error_format
+            );
+            envmnt::set(ERROR_FORMAT_ENV_VAR_NAME, "human");
+        }
+    }
+}
+
+pub(crate) fn is_json() -> bool {
+    envmnt::is_equal(ERROR_FORMAT_ENV_VAR_NAME, "json")
+}
+
+/// Returns the 1 based line and column of the provided offset in the text
+pub(crate) fn get_span(text: &str, offset: usize) -> Span {
+    let mut end = offset.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let prefix = &text[..end];
+
+    let line_start = match prefix.rfind('\n') {
+        Some(index) => index + 1,
+        None => 0,
+    };
+
+    Span {
+        line: prefix.matches('\n').count() + 1,
+        column: prefix[line_start..].chars().count() + 1,
+    }
+}
+
+/// Returns the diagnostic code of the error (the error variant name)
+pub(crate) fn get_error_code(error: &CargoMakeError) -> String {
+    format!("{:?}", CargoMakeErrorDiscriminants::from(error))
+}
+
+fn create_diagnostic_line(
+    code: &str,
+    message: &str,
+    file: Option<&str>,
+    span: Option<Span>,
+    task: Option<&str>,
+) -> String {
+    let diagnostic = Diagnostic {
+        code: code.to_string(),
+        message: message.to_string(),
+        file: file.map(|value| value.to_string()),
+        span,
+        task: task.map(|value| value.to_string()),
+    };
+
+    match serde_json::to_string(&diagnostic) {
+        Ok(line) => line,
+        Err(_) => format!("{{\"code\":\"{}\"}}", code),
+    }
+}
+
+fn get_current_task() -> Option<String> {
+    match env::var("CARGO_MAKE_CURRENT_TASK_NAME") {
+        Ok(name) if !name.is_empty() => Some(name),
+        _ => None,
+    }
+}
+
+fn write_line<W: Write>(output: &mut W, line: &str) {
+    if writeln!(output, "{}", line).is_err() {
+        debug!("Unable to write diagnostic.");
+    }
+}
+
+/// Writes a diagnostic to the stderr (only in json error format)
+pub(crate) fn report(
+    code: &str,
+    message: &str,
+    file: Option<&str>,
+    span: Option<Span>,
+    task: Option<&str>,
+) {
+    if is_json() {
+        ERROR_REPORTED.store(true, Ordering::SeqCst);
+
+        let line = create_diagnostic_line(code, message, file, span, task);
+        write_line(&mut stderr().lock(), &line);
+    }
+}
+
+/// Writes a diagnostic for the error to the stderr (only in json error format).<br>
+/// Only the first error is reported as the following errors are usually a result of it.
+pub(crate) fn report_error(
+    code: &str,
+    message: &str,
+    file: Option<&str>,
+    span: Option<Span>,
+    task: Option<&str>,
+) {
+    if is_json() && !ERROR_REPORTED.load(Ordering::SeqCst) {
+        report(code, message, file, span, task);
+    }
+}
+
+/// Reports the error log message (the failed task is taken from the current task name)
+pub(crate) fn report_error_log(message: &str) {
+    report_error(
+        ERROR_LOG_CODE,
+        message,
+        None,
+        None,
+        get_current_task().as_deref(),
+    );
+}
+
+/// Writes the diagnostic of the error to the output, unless an error was already reported
+fn write_error<W: Write>(output: &mut W, error: &CargoMakeError) {
+    if !ERROR_REPORTED.swap(true, Ordering::SeqCst) {
+        let message = error.to_string();
+        let line = create_diagnostic_line(
+            &get_error_code(error),
+            &message,
+            None,
+            None,
+            get_current_task().as_deref(),
+        );
+        write_line(output, &line);
+    }
+}
+
+/// Prints the error which ends the process (as a diagnostic in json error format)
+pub(crate) fn print_error(error: &CargoMakeError) {
+    if is_json() {
+        write_error(&mut stderr().lock(), error);
+    } else {
+        eprintln!("{}", error);
+    }
+}
+
+/// Returns the process exit code of the error, clamped the same way as the error
+/// termination report, without printing anything
+fn get_exit_code(error: &CargoMakeError) -> ExitCode {
+    match error {
+        CargoMakeError::ExitCode(exit_code) => *exit_code,
+        _ => match u8::try_from(error.discriminant()) {
+            Ok(exit_code) => ExitCode::from(exit_code),
+            Err(_) => ExitCode::FAILURE,
+        },
+    }
+}
+
+fn map_error_to_output<W: Write>(error: CargoMakeError, output: &mut W) -> CargoMakeError {
+    match error {
+        CargoMakeError::ExitCode(_) => error,
+        _ => {
+            write_error(output, &error);
+            CargoMakeError::ExitCode(get_exit_code(&error))
+        }
+    }
+}
+
+/// In json error format, reports the error as a diagnostic and converts it to its exit code,
+/// so nothing but the diagnostics is written to the stderr
+pub(crate) fn map_error(error: CargoMakeError) -> CargoMakeError {
+    if is_json() {
+        map_error_to_output(error, &mut stderr().lock())
+    } else {
+        error
+    }
+}
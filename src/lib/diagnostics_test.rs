@@ -0,0 +1,130 @@
+use super::*;
+
+#[test]
+fn get_span_start() {
+    assert_eq!(get_span("a\nb\nc", 0), Span { line: 1, column: 1 });
+}
+
+#[test]
+fn get_span_middle_of_line() {
+    assert_eq!(get_span("ab\ncde\nf", 5), Span { line: 2, column: 3 });
+}
+
+#[test]
+fn get_span_multi_byte() {
+    assert_eq!(get_span("ab\néx", 6), Span { line: 2, column: 3 });
+}
+
+#[test]
+fn get_span_out_of_range() {
+    assert_eq!(get_span("a\nb\nc", 100), Span { line: 3, column: 2 });
+}
+
+#[test]
+fn create_diagnostic_line_all_values() {
+    let line = create_diagnostic_line(
+        "ParseFileFailed",
+        "invalid table header",
+        Some("./Makefile.toml"),
+        Some(Span { line: 3, column: 9 }),
+        Some("build"),
+    );
+
+    assert_eq!(
+        line,
+        r#"{"code":"ParseFileFailed","message":"invalid table header","file":"./Makefile.toml","span":{"line":3,"column":9},"task":"build"}"#
+    );
+}
+
+#[test]
+fn create_diagnostic_line_no_location() {
+    let line = create_diagnostic_line("ExitCodeError", "test \"1\"", None, None, None);
+
+    assert_eq!(
+        line,
+        r#"{"code":"ExitCodeError","message":"test \"1\"","file":null,"span":null,"task":null}"#
+    );
+}
+
+#[test]
+#[ignore]
+fn init_from_cli_args() {
+    let mut cli_args = CliArgs::new();
+
+    init(&cli_args);
+    assert!(!is_json());
+
+    cli_args.error_format = "json".to_string();
+    init(&cli_args);
+    assert!(is_json());
+
+    cli_args.error_format = "bad".to_string();
+    init(&cli_args);
+    assert!(!is_json());
+
+    envmnt::remove(ERROR_FORMAT_ENV_VAR_NAME);
+}
+
+#[test]
+fn get_error_code_variant_name() {
+    assert_eq!(
+        get_error_code(&CargoMakeError::ExitCodeError(1)),
+        "ExitCodeError"
+    );
+    assert_eq!(
+        get_error_code(&CargoMakeError::ParseFileFailed(
+            "test".to_string(),
+            "test".to_string()
+        )),
+        "ParseFileFailed"
+    );
+}
+
+#[test]
+fn get_exit_code_in_range() {
+    assert_eq!(
+        get_exit_code(&CargoMakeError::ExitCodeError(1)),
+        ExitCode::from(105)
+    );
+}
+
+#[test]
+fn get_exit_code_out_of_range() {
+    assert_eq!(
+        get_exit_code(&CargoMakeError::NotFound("test".to_string())),
+        ExitCode::FAILURE
+    );
+}
+
+#[test]
+fn get_exit_code_exit_code_error() {
+    assert_eq!(
+        get_exit_code(&CargoMakeError::ExitCode(ExitCode::from(42))),
+        ExitCode::from(42)
+    );
+}
+
+#[test]
+fn map_error_to_output_not_found_json_only() {
+    let mut output = vec![];
+
+    let error = map_error_to_output(
+        CargoMakeError::NotFound("test task".to_string()),
+        &mut output,
+    );
+
+    let text = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let diagnostic: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(diagnostic["code"], "NotFound");
+    assert!(diagnostic["message"]
+        .as_str()
+        .unwrap()
+        .contains("test task"));
+
+    match error {
+        CargoMakeError::ExitCode(exit_code) => assert_eq!(exit_code, ExitCode::FAILURE),
+        _ => panic!("invalid error"),
+    }
+}
@@ -0,0 +1,95 @@
+//! # dry_run
+//!
+//! Prints the resolved commands and scripts of the flow tasks instead of invoking them.<br>
+//! The task conditions, env and platform overrides are evaluated as in a normal run.
+//!
+
+#[cfg(test)]
+#[path = "dry_run_test.rs"]
+mod dry_run_test;
+
+use crate::command;
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::installer;
+use crate::scriptengine;
+use crate::types::{CommandSpec, Step};
+
+/// Returns true if the flow runs in dry run mode (--dry-run cli flag)
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is_or("CARGO_MAKE_DRY_RUN", false)
+}
+
+fn format_argument(argument: &str) -> String {
+    if argument.is_empty() || argument.contains(char::is_whitespace) || argument.contains('"') {
+        format!("{:?}", argument)
+    } else {
+        argument.to_string()
+    }
+}
+
+fn format_command(command_spec: &CommandSpec) -> String {
+    let mut line = format_argument(&command_spec.command);
+
+    if let Some(ref args) = command_spec.args {
+        for arg in args {
+            line.push(' ');
+            line.push_str(&format_argument(arg));
+        }
+    }
+
+    line
+}
+
+/// Creates the report of the resolved task (with env already expanded) which would be invoked
+pub(crate) fn create_report(
+    step: &Step,
+    cwd: &str,
+    disable_install: bool,
+) -> Result<String, CargoMakeError> {
+    let mut report = format!("Task: {}\n", &step.name);
+    report.push_str(&format!("  Working Directory: {}\n", cwd));
+
+    if let Some(ref message) = step.config.confirm {
+        report.push_str(&format!(
+            "  Confirm: {}\n",
+            environment::expand_value(message)
+        ));
+    }
+
+    if !disable_install {
+        if let Some(description) = installer::get_install_description(&step.config) {
+            report.push_str(&format!("  Install: {}\n", description));
+        }
+    }
+
+    match step.config.script {
+        Some(ref script) => {
+            let runner = scriptengine::get_script_runner_name(&step.config)?.unwrap_or_default();
+            report.push_str(&format!("  Script ({}):\n", runner));
+
+            for line in scriptengine::get_script_text(script)? {
+                report.push_str(&format!("    {}\n", line));
+            }
+        }
+        None => {
+            if let Some(command_spec) = command::get_command_spec(step) {
+                report.push_str(&format!("  Command: {}\n", format_command(&command_spec)));
+            }
+        }
+    };
+
+    if let Some(ref commands) = step.config.commands {
+        for task_command in commands {
+            let _command_env = environment::CommandEnv::set(&task_command.env);
+            let command_step =
+                environment::expand_env(&command::create_command_step(step, task_command));
+
+            if let Some(command_spec) = command::get_command_spec(&command_step) {
+                report.push_str(&format!("  Command: {}\n", format_command(&command_spec)));
+            }
+        }
+    }
+
+    Ok(report)
+}
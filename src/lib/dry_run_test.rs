@@ -0,0 +1,155 @@
+use super::*;
+use crate::types::{InstallCrate, ScriptValue, Task, TaskCommand};
+use indexmap::IndexMap;
+
+#[test]
+fn format_argument_simple() {
+    assert_eq!(format_argument("--release"), "--release");
+}
+
+#[test]
+fn format_argument_whitespace() {
+    assert_eq!(format_argument("hello world"), "\"hello world\"");
+}
+
+#[test]
+fn format_argument_empty() {
+    assert_eq!(format_argument(""), "\"\"");
+}
+
+#[test]
+fn format_command_with_args() {
+    let command_spec = CommandSpec {
+        command: "cargo".to_string(),
+        args: Some(vec![
+            "build".to_string(),
+            "--features".to_string(),
+            "a b".to_string(),
+        ]),
+    };
+
+    assert_eq!(
+        format_command(&command_spec),
+        "cargo build --features \"a b\""
+    );
+}
+
+#[test]
+fn format_command_no_args() {
+    let command_spec = CommandSpec {
+        command: "ls".to_string(),
+        args: None,
+    };
+
+    assert_eq!(format_command(&command_spec), "ls");
+}
+
+#[test]
+fn create_report_command() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+    let step = Step {
+        name: "audit".to_string(),
+        config: task,
+    };
+
+    let report = create_report(&step, "/project", false).unwrap();
+
+    assert_eq!(
+        report,
+        "Task: audit\n  Working Directory: /project\n  Install: crate cargo-audit\n  Command: cargo audit\n"
+    );
+}
+
+#[test]
+fn create_report_install_disabled() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+    task.install_crate = Some(InstallCrate::Value("cargo-audit".to_string()));
+    let step = Step {
+        name: "audit".to_string(),
+        config: task,
+    };
+
+    let report = create_report(&step, "/project", true).unwrap();
+
+    assert_eq!(
+        report,
+        "Task: audit\n  Working Directory: /project\n  Command: cargo audit\n"
+    );
+}
+
+#[test]
+fn create_report_script() {
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::Text(vec![
+        "echo hello".to_string(),
+        "echo world".to_string(),
+    ]));
+    let step = Step {
+        name: "greet".to_string(),
+        config: task,
+    };
+
+    let report = create_report(&step, "/project", false).unwrap();
+
+    assert_eq!(
+        report,
+        "Task: greet\n  Working Directory: /project\n  Script (@duckscript):\n    echo hello\n    echo world\n"
+    );
+}
+
+#[test]
+fn create_report_confirm() {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.confirm = Some("Deploy?".to_string());
+    let step = Step {
+        name: "deploy".to_string(),
+        config: task,
+    };
+
+    let report = create_report(&step, "/project", false).unwrap();
+
+    assert_eq!(
+        report,
+        "Task: deploy\n  Working Directory: /project\n  Confirm: Deploy?\n  Command: echo\n"
+    );
+}
+
+#[test]
+fn create_report_commands() {
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_TEST_DRY_RUN_COMMAND_ENV".to_string(),
+        "value".to_string(),
+    );
+
+    let mut task = Task::new();
+    task.commands = Some(vec![
+        TaskCommand {
+            command: "echo".to_string(),
+            args: Some(vec!["${CARGO_MAKE_TEST_DRY_RUN_COMMAND_ENV}".to_string()]),
+            env: Some(env),
+        },
+        TaskCommand {
+            command: "echo".to_string(),
+            args: Some(vec!["done".to_string()]),
+            env: None,
+        },
+    ]);
+    let step = Step {
+        name: "multi".to_string(),
+        config: task,
+    };
+
+    let report = create_report(&step, "/project", false).unwrap();
+
+    assert_eq!(
+        report,
+        "Task: multi\n  Working Directory: /project\n  Command: echo value\n  Command: echo done\n"
+    );
+}
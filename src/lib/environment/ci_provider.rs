@@ -0,0 +1,113 @@
+//! # ci_provider
+//!
+//! Normalizes the detected CI vendor into a provider id and exposes the provider specific
+//! build information env vars.
+//!
+
+#[cfg(test)]
+#[path = "ci_provider_test.rs"]
+mod ci_provider_test;
+
+use ci_info::types::{CiInfo, Vendor};
+
+/// Returns true if the woodpecker pipeline is running on the codeberg CI
+fn is_codeberg_ci() -> bool {
+    ["CI_FORGE_URL", "CI_SYSTEM_URL"]
+        .iter()
+        .any(|key| envmnt::get_or(key, "").contains("codeberg.org"))
+}
+
+/// Returns the normalized provider id (lower case, words separated by dash)
+fn normalize_name(name: &str) -> String {
+    name.split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// Returns the CI provider id or None if not running in CI
+pub(crate) fn get_provider(ci_info: &CiInfo) -> Option<String> {
+    if !ci_info.ci {
+        return None;
+    }
+
+    match ci_info.vendor {
+        Some(Vendor::WoodpeckerCI) if is_codeberg_ci() => Some("codeberg-ci".to_string()),
+        _ => match ci_info.name {
+            Some(ref name) if !name.is_empty() => Some(normalize_name(name)),
+            _ => Some("unknown".to_string()),
+        },
+    }
+}
+
+fn get_github_build_url() -> Option<String> {
+    match (
+        get_optional_env("GITHUB_SERVER_URL"),
+        get_optional_env("GITHUB_REPOSITORY"),
+        get_optional_env("GITHUB_RUN_ID"),
+    ) {
+        (Some(server), Some(repository), Some(run_id)) => {
+            Some(format!("{}/{}/actions/runs/{}", server, repository, run_id))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the provider env var names holding the build number and build url
+fn get_build_env_keys(vendor: Vendor) -> (&'static str, Option<&'static str>) {
+    match vendor {
+        Vendor::Buildkite => ("BUILDKITE_BUILD_NUMBER", Some("BUILDKITE_BUILD_URL")),
+        Vendor::TeamCity => ("BUILD_NUMBER", None),
+        Vendor::Drone => ("DRONE_BUILD_NUMBER", Some("DRONE_BUILD_LINK")),
+        Vendor::WoodpeckerCI => ("CI_PIPELINE_NUMBER", Some("CI_PIPELINE_URL")),
+        Vendor::Bamboo => ("bamboo_buildNumber", Some("bamboo_resultsUrl")),
+        Vendor::GitLabCI => ("CI_PIPELINE_IID", Some("CI_PIPELINE_URL")),
+        Vendor::GitHubActions | Vendor::GiteaActions => ("GITHUB_RUN_NUMBER", None),
+        Vendor::CircleCI => ("CIRCLE_BUILD_NUM", Some("CIRCLE_BUILD_URL")),
+        Vendor::TravisCI => ("TRAVIS_BUILD_NUMBER", Some("TRAVIS_BUILD_WEB_URL")),
+        Vendor::AzurePipelines => ("BUILD_BUILDNUMBER", None),
+        Vendor::Jenkins => ("BUILD_NUMBER", Some("BUILD_URL")),
+        _ => ("", None),
+    }
+}
+
+fn get_optional_env(key: &str) -> Option<String> {
+    if key.is_empty() {
+        None
+    } else {
+        match envmnt::get_or(key, "") {
+            value if value.is_empty() => None,
+            value => Some(value),
+        }
+    }
+}
+
+/// Returns the build number and build url of the current CI build
+pub(crate) fn get_build_info(ci_info: &CiInfo) -> (Option<String>, Option<String>) {
+    match ci_info.vendor {
+        Some(vendor) if ci_info.ci => {
+            let (number_key, url_key) = get_build_env_keys(vendor);
+            let build_number = get_optional_env(number_key);
+            let build_url = match url_key {
+                Some(key) => get_optional_env(key),
+                None if vendor == Vendor::GitHubActions || vendor == Vendor::GiteaActions => {
+                    get_github_build_url()
+                }
+                None => None,
+            };
+
+            (build_number, build_url)
+        }
+        _ => (None, None),
+    }
+}
+
+/// Sets the CI provider env vars
+pub(crate) fn setup_env(ci_info: &CiInfo) {
+    let (build_number, build_url) = get_build_info(ci_info);
+
+    envmnt::set_or_remove("CARGO_MAKE_CI_PROVIDER", &get_provider(ci_info));
+    envmnt::set_or_remove("CARGO_MAKE_CI_BUILD_NUMBER", &build_number);
+    envmnt::set_or_remove("CARGO_MAKE_CI_BUILD_URL", &build_url);
+}
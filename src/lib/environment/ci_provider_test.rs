@@ -0,0 +1,127 @@
+use super::*;
+
+fn create_ci_info(vendor: Vendor, name: &str) -> CiInfo {
+    let mut ci_info = CiInfo::new();
+    ci_info.ci = true;
+    ci_info.vendor = Some(vendor);
+    ci_info.name = Some(name.to_string());
+
+    ci_info
+}
+
+#[test]
+fn normalize_name_single_word() {
+    assert_eq!(normalize_name("Buildkite"), "buildkite");
+}
+
+#[test]
+fn normalize_name_multiple_words() {
+    assert_eq!(normalize_name("Gitea Actions"), "gitea-actions");
+    assert_eq!(normalize_name(" Woodpecker  CI "), "woodpecker-ci");
+}
+
+#[test]
+fn get_provider_not_ci() {
+    assert!(get_provider(&CiInfo::new()).is_none());
+}
+
+#[test]
+fn get_provider_unknown() {
+    let mut ci_info = CiInfo::new();
+    ci_info.ci = true;
+
+    assert_eq!(get_provider(&ci_info).unwrap(), "unknown");
+}
+
+#[test]
+fn get_provider_vendors() {
+    for (vendor, name, provider) in [
+        (Vendor::Buildkite, "Buildkite", "buildkite"),
+        (Vendor::TeamCity, "TeamCity", "teamcity"),
+        (Vendor::Drone, "Drone", "drone"),
+        (Vendor::Bamboo, "Bamboo", "bamboo"),
+        (Vendor::GiteaActions, "Gitea Actions", "gitea-actions"),
+        (Vendor::GitHubActions, "GitHub Actions", "github-actions"),
+    ] {
+        assert_eq!(
+            get_provider(&create_ci_info(vendor, name)).unwrap(),
+            provider
+        );
+    }
+}
+
+#[test]
+fn get_provider_woodpecker_and_codeberg() {
+    let ci_info = create_ci_info(Vendor::WoodpeckerCI, "Woodpecker CI");
+
+    envmnt::remove("CI_FORGE_URL");
+    envmnt::remove("CI_SYSTEM_URL");
+    assert_eq!(get_provider(&ci_info).unwrap(), "woodpecker-ci");
+
+    envmnt::set("CI_FORGE_URL", "https://codeberg.org");
+    assert_eq!(get_provider(&ci_info).unwrap(), "codeberg-ci");
+    envmnt::remove("CI_FORGE_URL");
+}
+
+#[test]
+fn get_build_info_not_ci() {
+    let mut ci_info = create_ci_info(Vendor::Buildkite, "Buildkite");
+    ci_info.ci = false;
+
+    assert_eq!(get_build_info(&ci_info), (None, None));
+}
+
+#[test]
+fn get_build_info_buildkite() {
+    envmnt::set("BUILDKITE_BUILD_NUMBER", "12");
+    envmnt::set(
+        "BUILDKITE_BUILD_URL",
+        "https://buildkite.com/org/pipeline/builds/12",
+    );
+
+    let build_info = get_build_info(&create_ci_info(Vendor::Buildkite, "Buildkite"));
+
+    envmnt::remove("BUILDKITE_BUILD_NUMBER");
+    envmnt::remove("BUILDKITE_BUILD_URL");
+
+    assert_eq!(
+        build_info,
+        (
+            Some("12".to_string()),
+            Some("https://buildkite.com/org/pipeline/builds/12".to_string())
+        )
+    );
+}
+
+#[test]
+fn get_build_info_gitea_actions() {
+    let keys = [
+        "GITHUB_RUN_NUMBER",
+        "GITHUB_SERVER_URL",
+        "GITHUB_REPOSITORY",
+        "GITHUB_RUN_ID",
+    ];
+    let previous: Vec<Option<String>> = keys
+        .iter()
+        .map(|key| envmnt::get_or(key, "").into())
+        .collect();
+
+    envmnt::set("GITHUB_RUN_NUMBER", "3");
+    envmnt::set("GITHUB_SERVER_URL", "https://gitea.example.com");
+    envmnt::set("GITHUB_REPOSITORY", "org/repo");
+    envmnt::set("GITHUB_RUN_ID", "45");
+
+    let build_info = get_build_info(&create_ci_info(Vendor::GiteaActions, "Gitea Actions"));
+
+    for (key, value) in keys.iter().zip(previous) {
+        envmnt::set_or_remove(key, &value.filter(|value| !value.is_empty()));
+    }
+
+    assert_eq!(
+        build_info,
+        (
+            Some("3".to_string()),
+            Some("https://gitea.example.com/org/repo/actions/runs/45".to_string())
+        )
+    );
+}
@@ -0,0 +1,154 @@
+//! # expansion
+//!
+//! Expands env references (`${NAME}` and `${NAME:default}`) in a single pass over the value.<br>
+//! References may be nested inside the name or default value (for example `${PREFIX_${SUFFIX}}`
+//! or `${NAME:${DEFAULT_NAME}}`) and are resolved recursively.<br>
+//! Resolved values are inserted as is and never scanned again, so values containing `${` are
+//! not modified and can not cause expansion cycles.
+//!
+
+#[cfg(test)]
+#[path = "expansion_test.rs"]
+mod expansion_test;
+
+use std::env;
+
+/// The maximum amount of nested references within a single reference
+static MAX_NESTING_DEPTH: usize = 32;
+
+/// Returns the index of the closing bracket matching the reference content starting at the
+/// provided index (None if the reference is not closed).
+fn find_reference_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut nesting = 0;
+    let mut index = start;
+
+    while index < chars.len() {
+        if chars[index] == '$' && chars.get(index + 1) == Some(&'{') {
+            nesting += 1;
+            index += 2;
+        } else {
+            if chars[index] == '}' {
+                if nesting == 0 {
+                    return Some(index);
+                }
+
+                nesting -= 1;
+            }
+
+            index += 1;
+        }
+    }
+
+    None
+}
+
+/// Returns the index of the name/default separator which is not part of a nested reference
+fn find_default_separator(chars: &[char]) -> Option<usize> {
+    let mut nesting = 0;
+
+    for (index, current) in chars.iter().enumerate() {
+        match current {
+            '{' if index > 0 && chars[index - 1] == '$' => nesting += 1,
+            '}' if nesting > 0 => nesting -= 1,
+            ':' if nesting == 0 => return Some(index),
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains(|current: char| {
+            current == ' '
+                || current == '='
+                || current == '\n'
+                || current == '\t'
+                || current == '\r'
+        })
+}
+
+fn is_valid_default(default_value: &[char]) -> bool {
+    !default_value
+        .iter()
+        .any(|current| *current == '\n' || *current == '\t' || *current == '\r')
+}
+
+/// Resolves the reference content (the text between the brackets).<br>
+/// Returns None if the content is not a valid reference.
+fn resolve_reference(content: &[char], depth: usize) -> Option<String> {
+    if depth >= MAX_NESTING_DEPTH {
+        debug!("Env reference nesting is too deep, skipping expansion.");
+        return None;
+    }
+
+    let (name_chars, default_value) = match find_default_separator(content) {
+        Some(index) => (&content[..index], Some(&content[index + 1..])),
+        None => (content, None),
+    };
+
+    let name = expand_chars(name_chars, depth + 1);
+    if !is_valid_name(&name) {
+        return None;
+    }
+
+    match env::var(&name) {
+        Ok(value) => Some(value),
+        Err(_) => match default_value {
+            Some(default_value) => {
+                if is_valid_default(default_value) {
+                    Some(expand_chars(default_value, depth + 1))
+                } else {
+                    None
+                }
+            }
+            // undefined references are kept
+            None => Some(format!("${{{}}}", name)),
+        },
+    }
+}
+
+fn expand_chars(chars: &[char], depth: usize) -> String {
+    let mut output = String::with_capacity(chars.len());
+    let mut index = 0;
+
+    while index < chars.len() {
+        if chars[index] == '$' && chars.get(index + 1) == Some(&'{') {
+            let start = index + 2;
+
+            let resolved = find_reference_end(chars, start).and_then(|end| {
+                resolve_reference(&chars[start..end], depth).map(|value| (value, end + 1))
+            });
+
+            match resolved {
+                Some((value, next_index)) => {
+                    output.push_str(&value);
+                    index = next_index;
+                }
+                None => {
+                    // not a valid reference, keep the prefix as is and continue after it
+                    output.push_str("${");
+                    index = start;
+                }
+            }
+        } else {
+            output.push(chars[index]);
+            index += 1;
+        }
+    }
+
+    output
+}
+
+/// Expands all env references in the provided value.<br>
+/// Undefined references without a default value are kept as is.
+pub(crate) fn expand(value: &str) -> String {
+    if !value.contains("${") {
+        return value.to_string();
+    }
+
+    let chars: Vec<char> = value.chars().collect();
+
+    expand_chars(&chars, 0)
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+#[test]
+fn expand_no_references() {
+    assert_eq!(expand("no references $HOME"), "no references $HOME");
+}
+
+#[test]
+fn expand_defined() {
+    envmnt::set("EXPANSION_TEST_DEFINED", "value");
+
+    assert_eq!(
+        expand("1 ${EXPANSION_TEST_DEFINED} 2${EXPANSION_TEST_DEFINED}"),
+        "1 value 2value"
+    );
+}
+
+#[test]
+fn expand_undefined() {
+    envmnt::remove("EXPANSION_TEST_UNDEFINED");
+
+    assert_eq!(
+        expand("1 ${EXPANSION_TEST_UNDEFINED} 2"),
+        "1 ${EXPANSION_TEST_UNDEFINED} 2"
+    );
+}
+
+#[test]
+fn expand_default() {
+    envmnt::remove("EXPANSION_TEST_DEFAULT");
+
+    assert_eq!(
+        expand("${EXPANSION_TEST_DEFAULT:default value}"),
+        "default value"
+    );
+    assert_eq!(expand("${EXPANSION_TEST_DEFAULT:}"), "");
+}
+
+#[test]
+fn expand_default_not_used() {
+    envmnt::set("EXPANSION_TEST_DEFAULT_NOT_USED", "value");
+
+    assert_eq!(
+        expand("${EXPANSION_TEST_DEFAULT_NOT_USED:default}"),
+        "value"
+    );
+}
+
+#[test]
+fn expand_nested_name() {
+    envmnt::set("EXPANSION_TEST_NESTED_SUFFIX", "NAME");
+    envmnt::set("EXPANSION_TEST_NESTED_NAME", "value");
+
+    assert_eq!(
+        expand("${EXPANSION_TEST_NESTED_${EXPANSION_TEST_NESTED_SUFFIX}}"),
+        "value"
+    );
+}
+
+#[test]
+fn expand_nested_default() {
+    envmnt::remove("EXPANSION_TEST_NESTED_DEFAULT");
+    envmnt::set("EXPANSION_TEST_NESTED_DEFAULT_VALUE", "value");
+
+    assert_eq!(
+        expand("${EXPANSION_TEST_NESTED_DEFAULT:${EXPANSION_TEST_NESTED_DEFAULT_VALUE}:1}"),
+        "value:1"
+    );
+}
+
+#[test]
+fn expand_value_not_expanded_again() {
+    envmnt::set(
+        "EXPANSION_TEST_SELF_REFERENCE",
+        "${EXPANSION_TEST_SELF_REFERENCE}",
+    );
+
+    assert_eq!(
+        expand("${EXPANSION_TEST_SELF_REFERENCE}"),
+        "${EXPANSION_TEST_SELF_REFERENCE}"
+    );
+}
+
+#[test]
+fn expand_literal_prefix() {
+    envmnt::set("EXPANSION_TEST_LITERAL", "value");
+
+    assert_eq!(expand("${"), "${");
+    assert_eq!(expand("1${ 2"), "1${ 2");
+    assert_eq!(expand("${ ${EXPANSION_TEST_LITERAL} }"), "${ value }");
+    assert_eq!(expand("${}"), "${}");
+    assert_eq!(expand("$${EXPANSION_TEST_LITERAL}$"), "$value$");
+}
+
+#[test]
+fn expand_invalid_name() {
+    assert_eq!(expand("${EXPANSION TEST}"), "${EXPANSION TEST}");
+    assert_eq!(expand("${A=B}"), "${A=B}");
+}
+
+#[test]
+fn expand_too_deep() {
+    let mut value = "EXPANSION_TEST_TOO_DEEP".to_string();
+    for _ in 0..(MAX_NESTING_DEPTH + 1) {
+        value = format!("${{{}}}", value);
+    }
+
+    assert!(expand(&value).starts_with("${"));
+}
@@ -3,7 +3,11 @@
 //! Sets up the env vars before running the tasks.
 //!
 
+mod ci_provider;
 pub(crate) mod crateinfo;
+mod expansion;
+mod script_batch;
+mod watch_targets;
 
 #[cfg(test)]
 #[path = "mod_test.rs"]
@@ -12,6 +16,7 @@ mod mod_test;
 use crate::command;
 use crate::condition;
 use crate::error::CargoMakeError;
+use crate::functions;
 use crate::io;
 use crate::profile;
 use crate::scriptengine;
@@ -21,15 +26,52 @@ use crate::types::{
     EnvValuePathGlob, EnvValueScript, PackageInfo, ScriptValue, Step, Task, Workspace,
 };
 use ci_info::types::CiInfo;
-use envmnt::{ExpandOptions, ExpansionType};
 use fsio::path::from_path::FromPath;
 use git_info::types::GitInfo;
 use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
+use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use std::time::SystemTime;
 
+fn validate_env_script_exit_code(
+    key: &str,
+    exit_code: i32,
+    script: &Vec<String>,
+    stdout: &str,
+    stderr: &str,
+) {
+    if exit_code != 0 {
+        error!(
+            concat!(
+                "Error while evaluating script for env: {}, exit code: {}\n",
+                "Script:\n{:#?}\n",
+                "Stdout:\n{}\n",
+                "Stderr:\n{}\n"
+            ),
+            key, exit_code, script, stdout, stderr
+        );
+    }
+}
+
+/// Returns the last non empty line of the script output
+fn get_last_line(stdout: &str) -> String {
+    let mut lines: Vec<&str> = stdout.split("\n").collect();
+    lines.retain(|&line| line.len() > 0);
+
+    if lines.len() > 0 {
+        let line = lines[lines.len() - 1].to_string();
+
+        let line_str = str::replace(&line, "\r", "");
+
+        line_str.to_string()
+    } else {
+        "".to_string()
+    }
+}
+
 fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
     match command::run_script_get_output(&env_value.script, None, &vec![], true, Some(false)) {
         Ok(output) => {
@@ -37,17 +79,7 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
             let stdout = output.1;
             let stderr = output.2;
 
-            if exit_code != 0 {
-                error!(
-                    concat!(
-                        "Error while evaluating script for env: {}, exit code: {}\n",
-                        "Script:\n{:#?}\n",
-                        "Stdout:\n{}\n",
-                        "Stderr:\n{}\n"
-                    ),
-                    key, exit_code, env_value.script, &stdout, &stderr
-                );
-            }
+            validate_env_script_exit_code(key, exit_code, &env_value.script, &stdout, &stderr);
 
             debug!("Env script stdout:\n{}", &stdout);
 
@@ -59,18 +91,7 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
             if multi_line {
                 stdout.to_string()
             } else {
-                let mut lines: Vec<&str> = stdout.split("\n").collect();
-                lines.retain(|&line| line.len() > 0);
-
-                if lines.len() > 0 {
-                    let line = lines[lines.len() - 1].to_string();
-
-                    let line_str = str::replace(&line, "\r", "");
-
-                    line_str.to_string()
-                } else {
-                    "".to_string()
-                }
+                get_last_line(&stdout)
             }
         }
         _ => "".to_string(),
@@ -78,15 +99,18 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
 }
 
 pub(crate) fn expand_value(value: &str) -> String {
-    let mut options = ExpandOptions::new();
-    options.expansion_type = Some(ExpansionType::UnixBracketsWithDefaults);
-    options.default_to_empty = false;
-
-    envmnt::expand(&value, Some(options))
+    expansion::expand(value)
 }
 
 fn evaluate_and_set_env(key: &str, value: &str) {
-    let env_value = expand_value(&value);
+    let env_value = match functions::run_for_value(value) {
+        Ok(Some(output)) => output,
+        Ok(None) => expand_value(value),
+        Err(error) => {
+            error!("Unable to evaluate env: {} function, {}", &key, &error);
+            return;
+        }
+    };
 
     debug!("Setting Env: {} Value: {}", &key, &env_value);
     envmnt::set(&key, &env_value);
@@ -121,6 +145,21 @@ fn set_env_for_script(key: &str, env_value: &EnvValueScript) {
     }
 }
 
+/// Evaluates the pending env scripts (in a single shell invocation if possible) and
+/// clears the list.
+fn set_env_for_script_batch(scripts: &mut Vec<(&str, &EnvValueScript)>) {
+    if scripts.len() == 1 {
+        let (key, script_info) = scripts[0];
+        set_env_for_script(key, script_info);
+    } else if scripts.len() > 1 {
+        for (key, value) in script_batch::evaluate(scripts) {
+            evaluate_and_set_env(&key, &value);
+        }
+    }
+
+    scripts.clear();
+}
+
 fn set_env_for_decode_info(key: &str, decode_info: &EnvValueDecode) {
     let valid = match decode_info.condition {
         Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
@@ -166,9 +205,71 @@ fn set_env_for_path_glob(key: &str, path_glob: &EnvValuePathGlob) {
     set_env_for_list(key, &path_list);
 }
 
+fn get_profile_extends(profile_name: &str, sub_env: &IndexMap<String, EnvValue>) -> Option<String> {
+    match sub_env.get("extends") {
+        Some(EnvValue::Value(ref base_profile_name)) => Some(base_profile_name.to_string()),
+        Some(_) => {
+            error!(
+                "Invalid extends value for profile: {}, expected a profile name.",
+                profile_name
+            );
+            None
+        }
+        None => None,
+    }
+}
+
+/// Returns the profile env merged with the env of the profiles it extends (base profiles first)
+fn resolve_profile_env(
+    profile_name: &str,
+    sub_env: &IndexMap<String, EnvValue>,
+    env: &IndexMap<String, EnvValue>,
+) -> IndexMap<String, EnvValue> {
+    let mut chain = vec![profile_name.to_string()];
+    let mut profile_envs = vec![sub_env];
+
+    let mut current_name = profile_name.to_string();
+    let mut current_env = sub_env;
+    while let Some(base_profile_name) = get_profile_extends(&current_name, current_env) {
+        if chain.contains(&base_profile_name) {
+            chain.push(base_profile_name);
+            error!("Circular profile extends chain: {}", chain.join(" -> "));
+            break;
+        }
+
+        match env.get(&base_profile_name) {
+            Some(EnvValue::Profile(ref base_env)) => {
+                chain.push(base_profile_name.clone());
+                profile_envs.push(base_env);
+                current_name = base_profile_name;
+                current_env = base_env;
+            }
+            _ => {
+                error!(
+                    "Profile: {} extends unknown profile: {}",
+                    &current_name, &base_profile_name
+                );
+                break;
+            }
+        }
+    }
+
+    let mut profile_env = IndexMap::new();
+    for current_env in profile_envs.iter().rev() {
+        for (key, env_value) in current_env.iter() {
+            if key != "extends" {
+                profile_env.insert(key.to_string(), env_value.clone());
+            }
+        }
+    }
+
+    profile_env
+}
+
 fn set_env_for_profile(
     profile_name: &str,
     sub_env: &IndexMap<String, EnvValue>,
+    env: &IndexMap<String, EnvValue>,
     additional_profiles: Option<&Vec<String>>,
 ) {
     let current_profile_name = profile::get();
@@ -182,7 +283,8 @@ fn set_env_for_profile(
     if current_profile_name == profile_name_string || found {
         debug!("Setting Up Profile: {} Env.", &profile_name);
 
-        set_env_for_config(sub_env.clone(), None, false);
+        let profile_env = resolve_profile_env(profile_name, sub_env, env);
+        set_env_for_config(profile_env, None, false);
     }
 }
 
@@ -203,9 +305,27 @@ pub(crate) fn set_env_for_config(
 ) {
     debug!("Setting Up Env.");
 
+    let batch_env_scripts = script_batch::is_enabled();
+    let mut pending_scripts = vec![];
     for (key, env_value) in &env {
         debug!("Setting env: {} = {:#?}", &key, &env_value);
 
+        if batch_env_scripts {
+            if let EnvValue::Script(ref script_info) = *env_value {
+                if script_batch::is_batchable(script_info) {
+                    // the script needs the values of the pending scripts
+                    if script_batch::depends_on_any(script_info, &pending_scripts) {
+                        set_env_for_script_batch(&mut pending_scripts);
+                    }
+
+                    pending_scripts.push((key.as_str(), script_info));
+                    continue;
+                }
+            }
+
+            set_env_for_script_batch(&mut pending_scripts);
+        }
+
         match *env_value {
             EnvValue::Value(ref value) => evaluate_and_set_env(&key, value),
             EnvValue::Boolean(value) => set_env_for_bool(&key, value),
@@ -219,7 +339,7 @@ pub(crate) fn set_env_for_config(
             EnvValue::PathGlob(ref path_glob_info) => set_env_for_path_glob(&key, path_glob_info),
             EnvValue::Profile(ref sub_env) => {
                 if allow_sub_env {
-                    set_env_for_profile(&key, sub_env, additional_profiles)
+                    set_env_for_profile(key, sub_env, &env, additional_profiles)
                 }
             }
             EnvValue::Unset(ref value) => {
@@ -230,27 +350,99 @@ pub(crate) fn set_env_for_config(
         };
     }
 
+    set_env_for_script_batch(&mut pending_scripts);
+
     if allow_sub_env {
-        let profile_name = profile::get();
-
-        if env.contains_key(&profile_name) {
-            match env.get(&profile_name) {
-                Some(ref env_value) => {
-                    match *env_value {
-                        EnvValue::Profile(ref sub_env) => {
-                            set_env_for_profile(&profile_name, sub_env, None)
-                        }
-                        _ => (),
-                    };
-                }
-                None => (),
-            };
+        // the current profile is set again (followed by the additional cli profiles in order)
+        // so it overrides the additional profiles defined in the config section
+        let profile_names = profile::get_all();
+
+        for profile_name in &profile_names {
+            if let Some(EnvValue::Profile(ref sub_env)) = env.get(profile_name) {
+                set_env_for_profile(profile_name, sub_env, &env, Some(&profile_names))
+            }
+        }
+    }
+}
+
+fn get_env_vars() -> HashMap<String, String> {
+    env::vars_os()
+        .filter_map(
+            |(key, value)| match (key.into_string(), value.into_string()) {
+                (Ok(key), Ok(value)) => Some((key, value)),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+/// Loads the task env files and unloads them (restoring the previous values) once dropped
+pub(crate) struct TaskEnvFiles {
+    previous: Vec<(String, Option<String>)>,
+}
+
+impl TaskEnvFiles {
+    pub(crate) fn load(env_files: &Option<Vec<EnvFile>>) -> TaskEnvFiles {
+        let previous = match env_files {
+            Some(ref env_files) => {
+                let before = get_env_vars();
+
+                set_env_files_for_config(env_files.clone(), None);
+
+                get_env_vars()
+                    .into_iter()
+                    .filter(|(key, value)| before.get(key) != Some(value))
+                    .map(|(key, _)| {
+                        let value = before.get(&key).cloned();
+                        (key, value)
+                    })
+                    .collect()
+            }
+            None => vec![],
+        };
+
+        TaskEnvFiles { previous }
+    }
+}
+
+impl Drop for TaskEnvFiles {
+    fn drop(&mut self) {
+        for (key, value) in &self.previous {
+            envmnt::set_or_remove(key, value);
         }
     }
 }
 
-pub(crate) fn set_env_files(env_files: Vec<EnvFile>) {
-    set_env_files_for_config(env_files, None);
+/// Sets the command env vars and restores the previous values once dropped
+pub(crate) struct CommandEnv {
+    previous: Vec<(String, Option<String>)>,
+}
+
+impl CommandEnv {
+    pub(crate) fn set(env: &Option<IndexMap<String, String>>) -> CommandEnv {
+        let previous = match env {
+            Some(ref env) => env
+                .iter()
+                .map(|(key, value)| {
+                    let previous_value = env::var(key).ok();
+                    envmnt::set(key, expand_value(value));
+
+                    (key.to_string(), previous_value)
+                })
+                .collect(),
+            None => vec![],
+        };
+
+        CommandEnv { previous }
+    }
+}
+
+impl Drop for CommandEnv {
+    fn drop(&mut self) {
+        for (key, value) in self.previous.iter().rev() {
+            envmnt::set_or_remove(key, value);
+        }
+    }
 }
 
 fn set_env_files_for_config(
@@ -294,6 +486,32 @@ fn set_env_files_for_config(
     all_loaded
 }
 
+/// Returns the default .env file name followed by the current and additional profiles .env file names
+fn get_dotenv_file_names(additional_profiles: Option<&Vec<String>>) -> Vec<String> {
+    let mut file_names = vec![".env".to_string(), format!(".env.{}", profile::get())];
+
+    if let Some(profiles) = additional_profiles {
+        for profile_name in profiles {
+            let file_name = format!(".env.{}", profile_name);
+            if !file_names.contains(&file_name) {
+                file_names.push(file_name);
+            }
+        }
+    }
+
+    file_names
+}
+
+fn set_dotenv_files(additional_profiles: Option<&Vec<String>>) {
+    let working_directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", ".");
+
+    for file_name in get_dotenv_file_names(additional_profiles) {
+        if Path::new(&working_directory).join(&file_name).is_file() {
+            load_env_file(Some(file_name));
+        }
+    }
+}
+
 fn set_env_scripts(
     env_scripts: Vec<String>,
     cli_arguments: &Vec<String>,
@@ -337,13 +555,37 @@ fn initialize_env(config: &Config, cli_args: &Vec<String>) -> Result<(), CargoMa
         None => None,
     };
 
+    if config.config.load_dotenv_files.unwrap_or(true) {
+        set_dotenv_files(additional_profiles);
+    }
+
     set_env_files_for_config(config.env_files.clone(), additional_profiles);
 
+    script_batch::init(config);
+
     set_env_for_config(config.env.clone(), additional_profiles, true);
 
     set_env_scripts(config.env_scripts.clone(), cli_args)
 }
 
+/// Sets the env files and env vars provided via the cli again after the makefile env was
+/// loaded, so they take precedence over the makefile env, env files and profiles
+fn set_cli_env(cli_args: &CliArgs) {
+    if let Some(ref env_files) = cli_args.env_file {
+        for env_file in env_files {
+            load_env_file(Some(env_file.to_string()));
+        }
+    }
+
+    if let Some(ref env) = cli_args.env {
+        for env_pair in env {
+            if let Some((key, value)) = env_pair.split_once('=') {
+                envmnt::set(key, expand_value(value));
+            }
+        }
+    }
+}
+
 fn setup_env_for_duckscript() {
     let mut version = duckscript::version();
     envmnt::set("CARGO_MAKE_DUCKSCRIPT_VERSION", version);
@@ -439,7 +681,7 @@ fn setup_env_for_crate(home: Option<PathBuf>) -> Result<CrateInfo, CargoMakeErro
     Ok(crate_info_clone)
 }
 
-fn setup_env_for_git_repo() -> GitInfo {
+fn setup_env_for_git_repo(load_metadata: bool) -> GitInfo {
     let info = git_info::get();
     let git_info_clone = info.clone();
 
@@ -454,10 +696,52 @@ fn setup_env_for_git_repo() -> GitInfo {
         "CARGO_MAKE_GIT_HEAD_LAST_COMMIT_HASH_PREFIX",
         &info.head.last_commit_hash_short,
     );
+    envmnt::set_optional("CARGO_MAKE_GIT_SHA", &info.head.last_commit_hash);
+    envmnt::set_optional(
+        "CARGO_MAKE_GIT_SHORT_SHA",
+        &info.head.last_commit_hash_short,
+    );
+
+    // no commits or not a git repository
+    if load_metadata && info.head.last_commit_hash.is_some() {
+        setup_env_for_git_metadata();
+    }
 
     git_info_clone
 }
 
+/// Runs git with the provided arguments and returns the trimmed output (None if git failed)
+pub(crate) fn get_git_output(args: &[&str]) -> Option<String> {
+    match Command::new("git")
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Sets the git env vars which are not part of the git info and require running additional
+/// git commands (enabled via the load_git_metadata config)
+fn setup_env_for_git_metadata() {
+    let tag = get_git_output(&["describe", "--tags", "--exact-match", "HEAD"])
+        .filter(|tag| !tag.is_empty());
+    envmnt::set_or_remove("CARGO_MAKE_GIT_TAG", &tag);
+
+    if let Some(status) = get_git_output(&["status", "--porcelain"]) {
+        envmnt::set_bool("CARGO_MAKE_GIT_DIRTY", !status.is_empty());
+    }
+
+    envmnt::set_optional(
+        "CARGO_MAKE_GIT_COMMIT_TIMESTAMP",
+        &get_git_output(&["log", "-1", "--format=%ct"]),
+    );
+}
+
 fn setup_env_for_rust(home: Option<PathBuf>) -> RustInfo {
     let rustinfo = rust_info::get();
     let rust_info_clone = rustinfo.clone();
@@ -512,6 +796,7 @@ fn setup_env_for_ci() -> CiInfo {
     envmnt::set_bool("CARGO_MAKE_PR", ci_info_struct.pr.unwrap_or(false));
     envmnt::set_optional("CARGO_MAKE_CI_BRANCH_NAME", &ci_info_struct.branch_name);
     envmnt::set_optional("CARGO_MAKE_CI_VENDOR", &ci_info_struct.name);
+    ci_provider::setup_env(&ci_info_struct);
 
     ci_info_struct
 }
@@ -613,7 +898,7 @@ pub(crate) fn setup_env(
     let gitinfo = if config.config.skip_git_env_info.unwrap_or(false) {
         GitInfo::new()
     } else {
-        setup_env_for_git_repo()
+        setup_env_for_git_repo(config.config.load_git_metadata.unwrap_or(false))
     };
     time_summary::add(time_summary_vec, "[Setup Env - Git]", now);
 
@@ -639,8 +924,14 @@ pub(crate) fn setup_env(
     // load env vars
     now = SystemTime::now();
     initialize_env(config, &cli_args.arguments.clone().unwrap_or(vec![]))?;
+    set_cli_env(cli_args);
     time_summary::add(time_summary_vec, "[Setup Env - Vars]", now);
 
+    // map the changed files to the watch targets
+    now = SystemTime::now();
+    watch_targets::setup_env();
+    time_summary::add(time_summary_vec, "[Setup Env - Watch]", now);
+
     Ok(EnvInfo {
         rust_info: rustinfo,
         crate_info,
@@ -649,6 +940,8 @@ pub(crate) fn setup_env(
     })
 }
 
+static WORKSPACE_CWD_PREFIX: &str = "@workspace";
+
 fn set_workspace_cwd(directory_path: &Path, force: bool) {
     if force || !envmnt::exists("CARGO_MAKE_WORKSPACE_WORKING_DIRECTORY") {
         let directory_path_string: String = FromPath::from_path(directory_path);
@@ -712,6 +1005,36 @@ pub(crate) fn setup_cwd(cwd: Option<&str>) -> Option<PathBuf> {
     }
 }
 
+/// Returns the task working directory with all env vars expanded.<br>
+/// Values starting with the @workspace prefix are resolved from the workspace root directory.
+pub(crate) fn get_task_cwd(cwd: &str) -> String {
+    let expanded_cwd = expand_value(cwd);
+
+    match expanded_cwd.strip_prefix(WORKSPACE_CWD_PREFIX) {
+        Some(relative_path)
+            if relative_path.is_empty()
+                || relative_path.starts_with('/')
+                || relative_path.starts_with('\\') =>
+        {
+            let root_directory = envmnt::get_or(
+                "CARGO_MAKE_WORKSPACE_WORKING_DIRECTORY",
+                &envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "."),
+            );
+            let relative_path = relative_path.trim_start_matches(['/', '\\']);
+
+            if relative_path.is_empty() {
+                root_directory
+            } else {
+                Path::new(&root_directory)
+                    .join(relative_path)
+                    .to_string_lossy()
+                    .into_owned()
+            }
+        }
+        _ => expanded_cwd,
+    }
+}
+
 pub(crate) fn load_env_file(env_file: Option<String>) -> bool {
     load_env_file_with_base_directory(env_file, None, false)
 }
@@ -858,36 +1181,42 @@ fn expand_env_for_script_runner_arguments(task: &mut Task) {
     task.script_runner_args = updated_args;
 }
 
+fn expand_task_args(args: &[String], task_args: &[String]) -> Vec<String> {
+    let mut expanded_args = vec![];
+
+    for arg in args {
+        if arg == "${@}" {
+            expanded_args.extend(task_args.iter().cloned());
+        } else if arg.contains("${@}") {
+            for task_arg in task_args {
+                expanded_args.push(arg.replace("${@}", task_arg));
+            }
+        } else {
+            expanded_args.push(arg.clone());
+        }
+    }
+
+    expanded_args
+}
+
+/// Replaces the ${@} task arguments placeholder in the task args with the provided arguments
+pub(crate) fn apply_task_args(task: &mut Task, task_args: &[String]) {
+    task.args = task
+        .args
+        .as_ref()
+        .map(|args| expand_task_args(args, task_args));
+}
+
 fn expand_env_for_arguments(task: &mut Task) {
     // update args by replacing any env vars
     let updated_args = match task.args {
         Some(ref args) => {
-            let mut expanded_args = vec![];
-
             let task_args = match envmnt::get_list("CARGO_MAKE_TASK_ARGS") {
                 Some(list) => list,
                 None => vec![],
             };
 
-            for index in 0..args.len() {
-                if args[index].contains("${@}") {
-                    if task_args.len() > 0 {
-                        if args[index] == "${@}" {
-                            for arg_index in 0..task_args.len() {
-                                expanded_args.push(task_args[arg_index].clone());
-                            }
-                        } else {
-                            for arg_index in 0..task_args.len() {
-                                let value_string =
-                                    str::replace(&args[index], "${@}", &task_args[arg_index]);
-                                expanded_args.push(value_string);
-                            }
-                        }
-                    }
-                } else {
-                    expanded_args.push(args[index].clone());
-                }
-            }
+            let mut expanded_args = expand_task_args(args, &task_args);
 
             for index in 0..expanded_args.len() {
                 expanded_args[index] = expand_value(&expanded_args[index]);
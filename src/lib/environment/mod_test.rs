@@ -1,5 +1,6 @@
 use super::*;
 
+use crate::test;
 use crate::types::{ConfigSection, EnvFileInfo, EnvValueUnset, TaskCondition};
 use std::collections::HashMap;
 use std::env;
@@ -115,6 +116,19 @@ fn evaluate_and_set_env_none_with_default() {
     );
 }
 
+#[test]
+fn evaluate_and_set_env_function() {
+    envmnt::set("EVAL_SET_FUNCTION_SOURCE", "1.2.3");
+    evaluate_and_set_env(
+        "EVAL_SET_FUNCTION",
+        "@@replace(EVAL_SET_FUNCTION_SOURCE,.,_)",
+    );
+    assert_eq!(
+        envmnt::get_or_panic("EVAL_SET_FUNCTION"),
+        "1_2_3".to_string()
+    );
+}
+
 #[test]
 #[ignore]
 fn evaluate_and_set_env_exists() {
@@ -626,7 +640,7 @@ fn set_env_for_profile_none_not_found() {
         EnvValue::Boolean(true),
     );
 
-    set_env_for_profile("test_profile", &env, None);
+    set_env_for_profile("test_profile", &env, &IndexMap::new(), None);
 
     assert!(!envmnt::exists("TEST_PROFILE_NONE_NOT_FOUND"));
 }
@@ -643,6 +657,7 @@ fn set_env_for_profile_some_not_found() {
     set_env_for_profile(
         "test_profile",
         &env,
+        &IndexMap::new(),
         Some(&vec!["other_profile".to_string()]),
     );
 
@@ -658,6 +673,7 @@ fn set_env_for_profile_some_found() {
     set_env_for_profile(
         "test_profile",
         &env,
+        &IndexMap::new(),
         Some(&vec!["test_profile".to_string()]),
     );
 
@@ -665,6 +681,133 @@ fn set_env_for_profile_some_found() {
     assert!(envmnt::is("TEST_PROFILE_FOUND"));
 }
 
+#[test]
+fn resolve_profile_env_no_extends() {
+    let mut sub_env = IndexMap::new();
+    sub_env.insert("A".to_string(), EnvValue::Value("1".to_string()));
+
+    let profile_env = resolve_profile_env("prod", &sub_env, &IndexMap::new());
+
+    assert_eq!(profile_env.len(), 1);
+    match profile_env.get("A").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "1"),
+        _ => panic!("Invalid env type"),
+    };
+}
+
+#[test]
+fn resolve_profile_env_extends_chain() {
+    let mut base_env = IndexMap::new();
+    base_env.insert("A".to_string(), EnvValue::Value("base".to_string()));
+    base_env.insert("B".to_string(), EnvValue::Value("base".to_string()));
+    base_env.insert("C".to_string(), EnvValue::Value("base".to_string()));
+
+    let mut ci_env = IndexMap::new();
+    ci_env.insert("extends".to_string(), EnvValue::Value("base".to_string()));
+    ci_env.insert("B".to_string(), EnvValue::Value("ci".to_string()));
+    ci_env.insert("C".to_string(), EnvValue::Value("ci".to_string()));
+
+    let mut release_env = IndexMap::new();
+    release_env.insert("extends".to_string(), EnvValue::Value("ci".to_string()));
+    release_env.insert("C".to_string(), EnvValue::Value("release".to_string()));
+
+    let mut env = IndexMap::new();
+    env.insert("base".to_string(), EnvValue::Profile(base_env));
+    env.insert("ci".to_string(), EnvValue::Profile(ci_env));
+    env.insert(
+        "release".to_string(),
+        EnvValue::Profile(release_env.clone()),
+    );
+
+    let profile_env = resolve_profile_env("release", &release_env, &env);
+
+    assert_eq!(profile_env.len(), 3);
+    assert!(!profile_env.contains_key("extends"));
+    match profile_env.get("A").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "base"),
+        _ => panic!("Invalid env type"),
+    };
+    match profile_env.get("B").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "ci"),
+        _ => panic!("Invalid env type"),
+    };
+    match profile_env.get("C").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "release"),
+        _ => panic!("Invalid env type"),
+    };
+}
+
+#[test]
+#[should_panic]
+fn resolve_profile_env_extends_not_found() {
+    test::on_test_startup();
+
+    let mut sub_env = IndexMap::new();
+    sub_env.insert("extends".to_string(), EnvValue::Value("base".to_string()));
+
+    resolve_profile_env("prod", &sub_env, &IndexMap::new());
+}
+
+#[test]
+#[should_panic]
+fn resolve_profile_env_extends_circular() {
+    test::on_test_startup();
+
+    let mut first_env = IndexMap::new();
+    first_env.insert("extends".to_string(), EnvValue::Value("second".to_string()));
+
+    let mut second_env = IndexMap::new();
+    second_env.insert("extends".to_string(), EnvValue::Value("first".to_string()));
+
+    let mut env = IndexMap::new();
+    env.insert("first".to_string(), EnvValue::Profile(first_env.clone()));
+    env.insert("second".to_string(), EnvValue::Profile(second_env));
+
+    resolve_profile_env("first", &first_env, &env);
+}
+
+#[test]
+#[ignore]
+fn set_env_for_config_profile_extends() {
+    let profile_name = profile::get();
+
+    let mut base_env = IndexMap::new();
+    base_env.insert(
+        "SET_ENV_FOR_CONFIG_PROFILE_EXTENDS_BASE".to_string(),
+        EnvValue::Value("BASE".to_string()),
+    );
+    base_env.insert(
+        "SET_ENV_FOR_CONFIG_PROFILE_EXTENDS_OVERRIDE".to_string(),
+        EnvValue::Value("BASE".to_string()),
+    );
+
+    let mut profile_env = IndexMap::new();
+    profile_env.insert(
+        "extends".to_string(),
+        EnvValue::Value("extends-base".to_string()),
+    );
+    profile_env.insert(
+        "SET_ENV_FOR_CONFIG_PROFILE_EXTENDS_OVERRIDE".to_string(),
+        EnvValue::Value("PROFILE".to_string()),
+    );
+
+    let mut env = IndexMap::new();
+    env.insert("extends-base".to_string(), EnvValue::Profile(base_env));
+    env.insert(profile_name.clone(), EnvValue::Profile(profile_env));
+
+    set_env_for_config(env, None, true);
+
+    assert!(envmnt::is_equal(
+        "SET_ENV_FOR_CONFIG_PROFILE_EXTENDS_BASE",
+        "BASE"
+    ));
+    assert!(envmnt::is_equal(
+        "SET_ENV_FOR_CONFIG_PROFILE_EXTENDS_OVERRIDE",
+        "PROFILE"
+    ));
+    assert!(!envmnt::exists("extends"));
+}
+
 #[test]
 fn set_env_for_config_list() {
     envmnt::remove("SET_ENV_FOR_CONFIG_LIST_MATCH_TEST");
@@ -774,6 +917,66 @@ fn set_env_for_config_path_glob() {
     ));
 }
 
+#[test]
+#[ignore]
+fn set_env_for_config_multiple_profiles_override() {
+    let profile_name = profile::get();
+    profile::set("multi_first");
+    profile::set_all(&vec![
+        "multi_first".to_string(),
+        "multi_second".to_string(),
+        "multi_third".to_string(),
+    ]);
+
+    let mut first_env = IndexMap::new();
+    first_env.insert(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_ALL".to_string(),
+        EnvValue::Value("FIRST".to_string()),
+    );
+    first_env.insert(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_TWO".to_string(),
+        EnvValue::Value("FIRST".to_string()),
+    );
+
+    let mut second_env = IndexMap::new();
+    second_env.insert(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_ALL".to_string(),
+        EnvValue::Value("SECOND".to_string()),
+    );
+    second_env.insert(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_TWO".to_string(),
+        EnvValue::Value("SECOND".to_string()),
+    );
+
+    let mut third_env = IndexMap::new();
+    third_env.insert(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_ALL".to_string(),
+        EnvValue::Value("THIRD".to_string()),
+    );
+
+    let mut env = IndexMap::new();
+    env.insert("multi_third".to_string(), EnvValue::Profile(third_env));
+    env.insert("multi_second".to_string(), EnvValue::Profile(second_env));
+    env.insert("multi_first".to_string(), EnvValue::Profile(first_env));
+
+    set_env_for_config(
+        env,
+        Some(&vec!["multi_second".to_string(), "multi_third".to_string()]),
+        true,
+    );
+
+    profile::set(&profile_name);
+
+    assert!(envmnt::is_equal(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_ALL",
+        "THIRD"
+    ));
+    assert!(envmnt::is_equal(
+        "SET_ENV_FOR_CONFIG_MULTIPLE_PROFILES_TWO",
+        "SECOND"
+    ));
+}
+
 #[test]
 #[ignore]
 fn set_env_for_config_profile_override() {
@@ -803,6 +1006,130 @@ fn set_env_for_config_profile_override() {
     ));
 }
 
+#[test]
+fn apply_task_args_with_args() {
+    let mut task = Task::new();
+    task.args = Some(vec![
+        "build".to_string(),
+        "${@}".to_string(),
+        "--features=${@}".to_string(),
+        "${OTHER}".to_string(),
+    ]);
+
+    apply_task_args(&mut task, &["a".to_string(), "b".to_string()]);
+
+    assert_eq!(
+        task.args.unwrap(),
+        vec![
+            "build",
+            "a",
+            "b",
+            "--features=a",
+            "--features=b",
+            "${OTHER}"
+        ]
+    );
+}
+
+#[test]
+fn apply_task_args_empty() {
+    let mut task = Task::new();
+    task.args = Some(vec!["build".to_string(), "${@}".to_string()]);
+
+    apply_task_args(&mut task, &[]);
+
+    assert_eq!(task.args.unwrap(), vec!["build"]);
+}
+
+#[test]
+fn apply_task_args_no_args() {
+    let mut task = Task::new();
+
+    apply_task_args(&mut task, &["a".to_string()]);
+
+    assert!(task.args.is_none());
+}
+
+#[test]
+fn task_env_files_load_and_unload() {
+    let file = "./target/_temp/task_env_files/task.env";
+    fsio::file::write_text_file(
+        file,
+        "CARGO_MAKE_TEST_TASK_ENV_FILE_NEW=new\nCARGO_MAKE_TEST_TASK_ENV_FILE_EXISTING=new\n",
+    )
+    .unwrap();
+    envmnt::remove("CARGO_MAKE_TEST_TASK_ENV_FILE_NEW");
+    envmnt::set("CARGO_MAKE_TEST_TASK_ENV_FILE_EXISTING", "old");
+
+    {
+        let _env_files = TaskEnvFiles::load(&Some(vec![EnvFile::Path(file.to_string())]));
+
+        assert_eq!(
+            envmnt::get_or_panic("CARGO_MAKE_TEST_TASK_ENV_FILE_NEW"),
+            "new"
+        );
+        assert_eq!(
+            envmnt::get_or_panic("CARGO_MAKE_TEST_TASK_ENV_FILE_EXISTING"),
+            "new"
+        );
+    }
+
+    assert!(!envmnt::exists("CARGO_MAKE_TEST_TASK_ENV_FILE_NEW"));
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_TASK_ENV_FILE_EXISTING"),
+        "old"
+    );
+}
+
+#[test]
+fn task_env_files_none() {
+    let env_files = TaskEnvFiles::load(&None);
+
+    assert!(env_files.previous.is_empty());
+}
+
+#[test]
+fn command_env_set_and_restore() {
+    envmnt::remove("CARGO_MAKE_TEST_COMMAND_ENV_NEW");
+    envmnt::set("CARGO_MAKE_TEST_COMMAND_ENV_EXISTING", "old");
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_TEST_COMMAND_ENV_NEW".to_string(),
+        "${CARGO_MAKE_TEST_COMMAND_ENV_EXISTING}-new".to_string(),
+    );
+    env.insert(
+        "CARGO_MAKE_TEST_COMMAND_ENV_EXISTING".to_string(),
+        "new".to_string(),
+    );
+
+    {
+        let _command_env = CommandEnv::set(&Some(env));
+
+        assert_eq!(
+            envmnt::get_or_panic("CARGO_MAKE_TEST_COMMAND_ENV_NEW"),
+            "old-new"
+        );
+        assert_eq!(
+            envmnt::get_or_panic("CARGO_MAKE_TEST_COMMAND_ENV_EXISTING"),
+            "new"
+        );
+    }
+
+    assert!(!envmnt::exists("CARGO_MAKE_TEST_COMMAND_ENV_NEW"));
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_COMMAND_ENV_EXISTING"),
+        "old"
+    );
+}
+
+#[test]
+fn command_env_none() {
+    let command_env = CommandEnv::set(&None);
+
+    assert!(command_env.previous.is_empty());
+}
+
 #[test]
 #[ignore]
 fn set_env_files_for_config_files() {
@@ -1034,6 +1361,12 @@ fn initialize_env_all() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     initialize_env(&config, &vec![]).unwrap();
@@ -1069,6 +1402,12 @@ fn setup_env_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1100,6 +1439,12 @@ fn setup_env_skip_git() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1121,6 +1466,12 @@ fn setup_env_skip_rust() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1142,6 +1493,12 @@ fn setup_env_skip_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1191,6 +1548,12 @@ fn setup_env_cli_arguments() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     envmnt::set("CARGO_MAKE_TASK_ARGS", "EMPTY");
@@ -1213,6 +1576,12 @@ fn setup_env_values() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.env.insert(
         "MY_ENV_KEY".to_string(),
@@ -1244,6 +1613,12 @@ fn setup_env_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.env.insert(
         "MY_ENV_SCRIPT_KEY".to_string(),
@@ -1571,6 +1946,32 @@ fn setup_env_for_crate_workspace() {
     );
 }
 
+#[test]
+fn get_git_output_valid() {
+    let output = get_git_output(&["--version"]).unwrap();
+
+    assert!(output.starts_with("git version"));
+}
+
+#[test]
+fn get_git_output_error() {
+    assert!(get_git_output(&["not-a-git-command"]).is_none());
+}
+
+#[test]
+fn setup_env_for_git_metadata_values() {
+    let git_info = git_info::get();
+    if git_info.head.last_commit_hash.is_none() {
+        return;
+    }
+
+    setup_env_for_git_metadata();
+
+    assert!(envmnt::exists("CARGO_MAKE_GIT_DIRTY"));
+    let timestamp = envmnt::get_or_panic("CARGO_MAKE_GIT_COMMIT_TIMESTAMP");
+    assert!(timestamp.parse::<u64>().is_ok());
+}
+
 #[test]
 #[ignore]
 fn setup_env_for_git_repo_with_values() {
@@ -1578,8 +1979,18 @@ fn setup_env_for_git_repo_with_values() {
     envmnt::set("CARGO_MAKE_GIT_USER_NAME", "EMPTY");
     envmnt::set("CARGO_MAKE_GIT_USER_EMAIL", "EMPTY");
 
-    let git_info = setup_env_for_git_repo();
+    let git_info = setup_env_for_git_repo(false);
 
+    if let Some(ref last_commit_hash) = git_info.head.last_commit_hash {
+        assert_eq!(
+            &envmnt::get_or_panic("CARGO_MAKE_GIT_SHA"),
+            last_commit_hash
+        );
+        assert_eq!(
+            envmnt::get_or_panic("CARGO_MAKE_GIT_SHORT_SHA"),
+            git_info.head.last_commit_hash_short.unwrap()
+        );
+    }
     if git_info.current_branch.is_some() {
         assert_eq!(
             envmnt::get_or_panic("CARGO_MAKE_GIT_BRANCH"),
@@ -1933,6 +2344,12 @@ fn setup_env_for_project_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let crate_info = crateinfo::load().unwrap();
@@ -1962,6 +2379,12 @@ fn setup_env_for_project_workspace_with_main_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1986,6 +2409,12 @@ fn setup_env_for_project_workspace_no_main_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1999,3 +2428,141 @@ fn setup_env_for_project_workspace_no_main_crate() {
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "workspace1"));
     assert!(!envmnt::exists("CARGO_MAKE_PROJECT_VERSION"));
 }
+
+fn get_workspace_root_directory() -> String {
+    envmnt::get_or(
+        "CARGO_MAKE_WORKSPACE_WORKING_DIRECTORY",
+        &envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "."),
+    )
+}
+
+#[test]
+fn get_task_cwd_relative() {
+    envmnt::set("GET_TASK_CWD_RELATIVE_DIR", "generated");
+
+    let cwd = get_task_cwd("./${GET_TASK_CWD_RELATIVE_DIR}/docs");
+
+    assert_eq!(cwd, "./generated/docs");
+}
+
+#[test]
+fn get_task_cwd_workspace_root() {
+    let cwd = get_task_cwd("@workspace");
+
+    assert_eq!(cwd, get_workspace_root_directory());
+}
+
+#[test]
+fn get_task_cwd_workspace_relative() {
+    envmnt::set("GET_TASK_CWD_WORKSPACE_RELATIVE_DIR", "generated");
+
+    let cwd = get_task_cwd("@workspace/target/${GET_TASK_CWD_WORKSPACE_RELATIVE_DIR}");
+
+    let expected = Path::new(&get_workspace_root_directory())
+        .join("target/generated")
+        .to_string_lossy()
+        .into_owned();
+    assert_eq!(cwd, expected);
+}
+
+#[test]
+fn get_task_cwd_workspace_prefix_only() {
+    let cwd = get_task_cwd("@workspaces/test");
+
+    assert_eq!(cwd, "@workspaces/test");
+}
+
+#[test]
+fn set_cli_env_overrides_values() {
+    let file = "./target/_temp/set_cli_env/cli.env";
+    fsio::file::write_text_file(
+        file,
+        "CARGO_MAKE_TEST_SET_CLI_ENV_FILE=file\nCARGO_MAKE_TEST_SET_CLI_ENV_BOTH=file\n",
+    )
+    .unwrap();
+    envmnt::set("CARGO_MAKE_TEST_SET_CLI_ENV_FILE", "makefile");
+    envmnt::set("CARGO_MAKE_TEST_SET_CLI_ENV_BOTH", "makefile");
+    envmnt::set("CARGO_MAKE_TEST_SET_CLI_ENV_VALUE", "makefile");
+
+    let mut cli_args = CliArgs::new();
+    cli_args.env_file = Some(vec![file.to_string()]);
+    cli_args.env = Some(vec![
+        "CARGO_MAKE_TEST_SET_CLI_ENV_BOTH=cli".to_string(),
+        "CARGO_MAKE_TEST_SET_CLI_ENV_VALUE=a=b".to_string(),
+    ]);
+
+    set_cli_env(&cli_args);
+
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_SET_CLI_ENV_FILE"),
+        "file"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_SET_CLI_ENV_BOTH"),
+        "cli"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_SET_CLI_ENV_VALUE"),
+        "a=b"
+    );
+}
+
+#[test]
+fn get_dotenv_file_names_no_additional_profiles() {
+    let file_names = get_dotenv_file_names(None);
+
+    assert_eq!(
+        file_names,
+        vec![".env".to_string(), format!(".env.{}", profile::get())]
+    );
+}
+
+#[test]
+fn get_dotenv_file_names_with_additional_profiles() {
+    let profile_name = profile::get();
+
+    let file_names = get_dotenv_file_names(Some(&vec![
+        "second".to_string(),
+        profile_name.clone(),
+        "third".to_string(),
+    ]));
+
+    assert_eq!(
+        file_names,
+        vec![
+            ".env".to_string(),
+            format!(".env.{}", profile_name),
+            ".env.second".to_string(),
+            ".env.third".to_string()
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn set_dotenv_files_profile_overrides_default() {
+    let directory = "./target/_temp/set_dotenv_files";
+    fsio::file::write_text_file(
+        &format!("{}/.env", directory),
+        "CARGO_MAKE_TEST_DOTENV_DEFAULT=default\nCARGO_MAKE_TEST_DOTENV_BOTH=default\n",
+    )
+    .unwrap();
+    fsio::file::write_text_file(
+        &format!("{}/.env.{}", directory, profile::get()),
+        "CARGO_MAKE_TEST_DOTENV_BOTH=profile\n",
+    )
+    .unwrap();
+    envmnt::remove("CARGO_MAKE_TEST_DOTENV_DEFAULT");
+    envmnt::remove("CARGO_MAKE_TEST_DOTENV_BOTH");
+
+    let working_directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", ".");
+    envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", directory);
+    set_dotenv_files(Some(&vec!["missing".to_string()]));
+    envmnt::set("CARGO_MAKE_WORKING_DIRECTORY", working_directory);
+
+    assert!(envmnt::is_equal(
+        "CARGO_MAKE_TEST_DOTENV_DEFAULT",
+        "default"
+    ));
+    assert!(envmnt::is_equal("CARGO_MAKE_TEST_DOTENV_BOTH", "profile"));
+}
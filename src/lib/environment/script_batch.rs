@@ -0,0 +1,191 @@
+//! # script_batch
+//!
+//! Evaluates multiple env scripts in a single shell invocation in order to reduce the
+//! amount of spawned processes.<br>
+//! Each script is invoked in its own sub shell (or sub routine on windows) followed by a
+//! marker line holding the script index and exit code, which is used to split the output
+//! back into the separate env values.
+//!
+
+#[cfg(test)]
+#[path = "script_batch_test.rs"]
+mod script_batch_test;
+
+use crate::command;
+use crate::types::{Config, EnvValueScript};
+
+static ENV_VAR_NAME: &str = "CARGO_MAKE_BATCH_ENV_SCRIPTS";
+static MARKER: &str = "__CARGO_MAKE_ENV_SCRIPT_END__";
+
+#[derive(Debug, PartialEq)]
+/// Holds the output of a single script in the batch
+struct ScriptOutput {
+    exit_code: i32,
+    stdout: String,
+}
+
+/// Enables env script batching if requested by the config
+pub(crate) fn init(config: &Config) {
+    if config.config.batch_env_scripts.unwrap_or(false) {
+        envmnt::set_bool(ENV_VAR_NAME, true);
+    }
+}
+
+/// Returns true if env script batching is enabled (CARGO_MAKE_BATCH_ENV_SCRIPTS env var)
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is(ENV_VAR_NAME)
+}
+
+/// Returns true if the env script can be evaluated as part of a batch.<br>
+/// Scripts with conditions, multi line output or a custom runner (shebang) are
+/// evaluated separately.
+pub(crate) fn is_batchable(env_value: &EnvValueScript) -> bool {
+    let shebang = match env_value.script.first() {
+        Some(line) => line.trim_start().starts_with("#!"),
+        None => false,
+    };
+
+    env_value.condition.is_none() && !env_value.multi_line.unwrap_or(false) && !shebang
+}
+
+/// Returns true if the script might use any of the env vars defined by the provided scripts.<br>
+/// Unless explicitly defined via depends_on, any occurrence of the env var name in the script
+/// is considered a usage.
+pub(crate) fn depends_on_any(
+    env_value: &EnvValueScript,
+    scripts: &[(&str, &EnvValueScript)],
+) -> bool {
+    scripts.iter().any(|(key, _)| match env_value.depends_on {
+        Some(ref depends_on) => depends_on.iter().any(|name| name == key),
+        None => env_value.script.iter().any(|line| line.contains(key)),
+    })
+}
+
+#[cfg(not(windows))]
+fn create_script(scripts: &[(&str, &EnvValueScript)]) -> Vec<String> {
+    // failing commands should only stop the current script
+    let mut lines = vec!["set +e".to_string()];
+
+    for (index, (_, env_value)) in scripts.iter().enumerate() {
+        lines.push("(".to_string());
+        lines.push("set -e".to_string());
+        lines.extend(env_value.script.iter().cloned());
+        lines.push(")".to_string());
+        lines.push("CARGO_MAKE_ENV_SCRIPT_EXIT_CODE=$?".to_string());
+        lines.push("echo \"\"".to_string());
+        lines.push(format!(
+            "echo \"{} {} $CARGO_MAKE_ENV_SCRIPT_EXIT_CODE\"",
+            MARKER, index
+        ));
+    }
+
+    lines
+}
+
+#[cfg(windows)]
+fn create_script(scripts: &[(&str, &EnvValueScript)]) -> Vec<String> {
+    let mut lines = vec!["@echo off".to_string()];
+
+    for index in 0..scripts.len() {
+        // reset the error level
+        lines.push("ver > nul".to_string());
+        lines.push(format!("call :cargo_make_env_script_{}", index));
+        lines.push("echo.".to_string());
+        lines.push(format!("echo {} {} %ERRORLEVEL%", MARKER, index));
+    }
+    lines.push("goto :eof".to_string());
+
+    for (index, (_, env_value)) in scripts.iter().enumerate() {
+        lines.push(format!(":cargo_make_env_script_{}", index));
+        // restores the env and current directory when returning
+        lines.push("setlocal".to_string());
+        lines.extend(env_value.script.iter().cloned());
+        lines.push("goto :eof".to_string());
+    }
+
+    lines
+}
+
+/// Splits the batch output to the output of each script.<br>
+/// The output stops at the first missing script marker.
+fn parse_output(stdout: &str, count: usize) -> Vec<ScriptOutput> {
+    let mut outputs = vec![];
+    let mut section = vec![];
+
+    for line in stdout.split('\n') {
+        let trimmed_line = line.trim_end_matches('\r');
+
+        let marker_info = match trimmed_line.strip_prefix(MARKER) {
+            Some(value) => {
+                let values: Vec<&str> = value.split_whitespace().collect();
+                match values.as_slice() {
+                    [index, exit_code] => {
+                        match (index.parse::<usize>(), exit_code.parse::<i32>()) {
+                            (Ok(index), Ok(exit_code)) => Some((index, exit_code)),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            None => None,
+        };
+
+        match marker_info {
+            Some((index, exit_code)) if index == outputs.len() && index < count => {
+                outputs.push(ScriptOutput {
+                    exit_code,
+                    stdout: section.join("\n"),
+                });
+                section.clear();
+            }
+            _ => section.push(line),
+        }
+    }
+
+    outputs
+}
+
+/// Evaluates all provided env scripts and returns their env values.<br>
+/// Scripts which did not report back (for example, in case the entire batch was stopped)
+/// are evaluated separately.
+pub(crate) fn evaluate(scripts: &[(&str, &EnvValueScript)]) -> Vec<(String, String)> {
+    debug!(
+        "Evaluating {} env scripts in a single batch.",
+        scripts.len()
+    );
+
+    let script = create_script(scripts);
+    let (outputs, stderr) =
+        match command::run_script_get_output(&script, None, &vec![], true, Some(false)) {
+            Ok((_, stdout, stderr)) => (parse_output(&stdout, scripts.len()), stderr),
+            Err(error) => {
+                debug!("Unable to evaluate env scripts batch, {}", error);
+                (vec![], "".to_string())
+            }
+        };
+
+    let mut values = vec![];
+    for (index, (key, env_value)) in scripts.iter().enumerate() {
+        let value = match outputs.get(index) {
+            Some(output) => {
+                super::validate_env_script_exit_code(
+                    key,
+                    output.exit_code,
+                    &env_value.script,
+                    &output.stdout,
+                    &stderr,
+                );
+
+                debug!("Env script stdout:\n{}", &output.stdout);
+
+                super::get_last_line(&output.stdout)
+            }
+            None => super::evaluate_env_value(key, env_value),
+        };
+
+        values.push((key.to_string(), value));
+    }
+
+    values
+}
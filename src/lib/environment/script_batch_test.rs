@@ -0,0 +1,200 @@
+use super::*;
+use crate::types::{ConfigSection, TaskCondition};
+use indexmap::IndexMap;
+
+fn create_script(lines: Vec<&str>) -> EnvValueScript {
+    EnvValueScript {
+        script: lines.iter().map(|line| line.to_string()).collect(),
+        multi_line: None,
+        condition: None,
+        depends_on: None,
+    }
+}
+
+#[test]
+fn init_enabled() {
+    let mut config_section = ConfigSection::new();
+    config_section.batch_env_scripts = Some(true);
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    envmnt::remove(ENV_VAR_NAME);
+    init(&config);
+
+    assert!(is_enabled());
+    envmnt::remove(ENV_VAR_NAME);
+}
+
+#[test]
+fn is_batchable_valid() {
+    assert!(is_batchable(&create_script(vec!["echo test"])));
+}
+
+#[test]
+fn is_batchable_multi_line() {
+    let mut script = create_script(vec!["echo test"]);
+    script.multi_line = Some(true);
+
+    assert!(!is_batchable(&script));
+}
+
+#[test]
+fn is_batchable_condition() {
+    let mut script = create_script(vec!["echo test"]);
+    script.condition = Some(TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+    });
+
+    assert!(!is_batchable(&script));
+}
+
+#[test]
+fn is_batchable_shebang() {
+    assert!(!is_batchable(&create_script(vec![
+        "#!@duckscript",
+        "echo test"
+    ])));
+}
+
+#[test]
+fn depends_on_any_found_in_script() {
+    let first = create_script(vec!["echo test"]);
+    let second = create_script(vec!["echo ${BATCH_FIRST}"]);
+
+    assert!(depends_on_any(&second, &[("BATCH_FIRST", &first)]));
+}
+
+#[test]
+fn depends_on_any_not_found() {
+    let first = create_script(vec!["echo test"]);
+    let second = create_script(vec!["echo ${OTHER}"]);
+
+    assert!(!depends_on_any(&second, &[("BATCH_FIRST", &first)]));
+}
+
+#[test]
+fn depends_on_any_explicit() {
+    let first = create_script(vec!["echo test"]);
+    let mut second = create_script(vec!["echo ${BATCH_FIRST}"]);
+    second.depends_on = Some(vec!["OTHER".to_string()]);
+
+    assert!(!depends_on_any(&second, &[("BATCH_FIRST", &first)]));
+
+    second.depends_on = Some(vec!["BATCH_FIRST".to_string()]);
+
+    assert!(depends_on_any(&second, &[("BATCH_FIRST", &first)]));
+}
+
+#[test]
+fn parse_output_all_found() {
+    let stdout = format!("1\n\n{} 0 0\nline1\nline2\r\n\n{} 1 2\n", MARKER, MARKER);
+
+    let outputs = parse_output(&stdout, 2);
+
+    assert_eq!(
+        outputs,
+        vec![
+            ScriptOutput {
+                exit_code: 0,
+                stdout: "1\n".to_string()
+            },
+            ScriptOutput {
+                exit_code: 2,
+                stdout: "line1\nline2\r\n".to_string()
+            }
+        ]
+    );
+}
+
+#[test]
+fn parse_output_missing_marker() {
+    let stdout = format!("1\n{} 0 0\n2\n{} 2 0\n", MARKER, MARKER);
+
+    let outputs = parse_output(&stdout, 3);
+
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].stdout, "1");
+}
+
+#[test]
+fn parse_output_invalid_marker() {
+    let stdout = format!("{} 0\n{} a 0\n", MARKER, MARKER);
+
+    let outputs = parse_output(&stdout, 1);
+
+    assert!(outputs.is_empty());
+}
+
+#[test]
+fn evaluate_valid() {
+    let first = create_script(vec!["echo first"]);
+    let second = create_script(vec!["echo ignored", "echo second"]);
+    let third = create_script(vec![""]);
+
+    let values = evaluate(&[
+        ("BATCH_FIRST", &first),
+        ("BATCH_SECOND", &second),
+        ("BATCH_THIRD", &third),
+    ]);
+
+    assert_eq!(
+        values,
+        vec![
+            ("BATCH_FIRST".to_string(), "first".to_string()),
+            ("BATCH_SECOND".to_string(), "second".to_string()),
+            ("BATCH_THIRD".to_string(), "".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn evaluate_scripts_isolated() {
+    let first = create_script(vec!["cd ..", "BATCH_VALUE=first", "exit 0"]);
+    let second = create_script(vec!["echo \"${BATCH_VALUE}\"", "pwd"]);
+
+    let values = evaluate(&[("BATCH_FIRST", &first), ("BATCH_SECOND", &second)]);
+
+    assert_eq!(values[0].1, "");
+    assert_eq!(
+        values[1].1,
+        std::env::current_dir().unwrap().to_string_lossy()
+    );
+}
+
+#[test]
+#[should_panic]
+fn evaluate_error() {
+    let first = create_script(vec!["echo first"]);
+    let second = create_script(vec!["exit 1"]);
+
+    evaluate(&[("BATCH_FIRST", &first), ("BATCH_SECOND", &second)]);
+}
@@ -0,0 +1,219 @@
+//! # watch_targets
+//!
+//! Maps the files changed in watch mode to the owning workspace members and test targets,
+//! so the rerun only builds and tests the affected packages.
+//!
+
+#[cfg(test)]
+#[path = "watch_targets_test.rs"]
+mod watch_targets_test;
+
+use cargo_metadata::{MetadataCommand, TargetKind};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// The env vars holding the changed paths, set by cargo-watch (watchexec) for the rerun
+static CHANGED_PATHS_ENV_VARS: [&str; 6] = [
+    "WATCHEXEC_CREATED_PATH",
+    "WATCHEXEC_REMOVED_PATH",
+    "WATCHEXEC_RENAMED_PATH",
+    "WATCHEXEC_WRITTEN_PATH",
+    "WATCHEXEC_META_CHANGED_PATH",
+    "WATCHEXEC_OTHERWISE_CHANGED_PATH",
+];
+
+#[derive(Debug, Clone)]
+struct WatchPackage {
+    name: String,
+    directory: PathBuf,
+    /// (name, source file) of the integration test targets
+    test_targets: Vec<(String, PathBuf)>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct WatchTargets {
+    packages: Vec<String>,
+    members: Vec<String>,
+    tests: Vec<String>,
+}
+
+fn get_changed_files() -> Vec<PathBuf> {
+    let common_path = env::var_os("WATCHEXEC_COMMON_PATH").map(PathBuf::from);
+
+    let mut changed_files = vec![];
+    for env_var in CHANGED_PATHS_ENV_VARS.iter() {
+        if let Some(paths) = env::var_os(env_var) {
+            for path in env::split_paths(&paths) {
+                if path.as_os_str().is_empty() {
+                    continue;
+                }
+
+                let file = match common_path {
+                    Some(ref common_path) => common_path.join(path),
+                    None => path,
+                };
+                if !changed_files.contains(&file) {
+                    changed_files.push(file);
+                }
+            }
+        }
+    }
+
+    changed_files
+}
+
+fn load_packages() -> Option<(PathBuf, Vec<WatchPackage>)> {
+    let metadata = match MetadataCommand::new().no_deps().exec() {
+        Ok(metadata) => metadata,
+        Err(error) => {
+            debug!("Unable to load cargo metadata for watch targets: {}", error);
+            return None;
+        }
+    };
+
+    let packages = metadata
+        .workspace_packages()
+        .iter()
+        .filter_map(|package| {
+            let directory = package.manifest_path.parent()?;
+
+            Some(WatchPackage {
+                name: package.name.to_string(),
+                directory: directory.as_std_path().to_path_buf(),
+                test_targets: package
+                    .targets
+                    .iter()
+                    .filter(|target| target.is_kind(TargetKind::Test))
+                    .map(|target| {
+                        (
+                            target.name.to_string(),
+                            target.src_path.as_std_path().to_path_buf(),
+                        )
+                    })
+                    .collect(),
+            })
+        })
+        .collect();
+
+    Some((metadata.workspace_root.into_std_path_buf(), packages))
+}
+
+fn find_package<'a>(packages: &'a [WatchPackage], file: &Path) -> Option<&'a WatchPackage> {
+    packages
+        .iter()
+        .filter(|package| file.starts_with(&package.directory))
+        .max_by_key(|package| package.directory.components().count())
+}
+
+fn get_member_name(workspace_root: &Path, directory: &Path) -> String {
+    match directory.strip_prefix(workspace_root) {
+        Ok(relative) if !relative.as_os_str().is_empty() => {
+            relative.to_string_lossy().replace('\\', "/")
+        }
+        _ => ".".to_string(),
+    }
+}
+
+/// Returns the packages, workspace members and integration tests owning the changed files.<br>
+/// In case any of the files is not owned by a workspace package, no targets are returned so
+/// everything is rerun.<br>
+/// Integration tests are only returned in case a single package changed and all the changed
+/// files are integration test sources.
+fn get_targets(
+    workspace_root: &Path,
+    packages: &[WatchPackage],
+    changed_files: &[PathBuf],
+) -> Option<WatchTargets> {
+    if changed_files.is_empty() {
+        return None;
+    }
+
+    let mut targets = WatchTargets::default();
+    let mut only_tests = true;
+    for file in changed_files {
+        let package = match find_package(packages, file) {
+            Some(package) => package,
+            None => {
+                debug!("Changed file: {:?} is not owned by any package.", file);
+                return None;
+            }
+        };
+
+        if !targets.packages.contains(&package.name) {
+            targets.packages.push(package.name.to_string());
+            targets
+                .members
+                .push(get_member_name(workspace_root, &package.directory));
+        }
+
+        match package
+            .test_targets
+            .iter()
+            .find(|(_, src_path)| src_path == file)
+        {
+            Some((name, _)) => {
+                if !targets.tests.contains(name) {
+                    targets.tests.push(name.to_string());
+                }
+            }
+            None => only_tests = false,
+        }
+    }
+
+    if !only_tests || targets.packages.len() != 1 {
+        targets.tests.clear();
+    }
+
+    Some(targets)
+}
+
+fn set_env(targets: &WatchTargets) {
+    envmnt::set_list("CARGO_MAKE_WATCH_CHANGED_PACKAGES", &targets.packages);
+    envmnt::set_list("CARGO_MAKE_WATCH_CHANGED_MEMBERS", &targets.members);
+
+    let package_flags: Vec<String> = targets
+        .packages
+        .iter()
+        .map(|name| format!("-p {}", name))
+        .collect();
+    envmnt::set("CARGO_MAKE_WATCH_PACKAGE_FLAGS", package_flags.join(" "));
+
+    let test_flags: Vec<String> = targets
+        .tests
+        .iter()
+        .map(|name| format!("--test {}", name))
+        .collect();
+    envmnt::set("CARGO_MAKE_WATCH_TEST_FLAGS", test_flags.join(" "));
+
+    // explicitly defined members filter is not modified
+    if !envmnt::exists("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS") {
+        envmnt::set_list("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS", &targets.members);
+    }
+}
+
+/// Sets the targeted watch env vars in case the task is rerun by a targeted watch,
+/// based on the files changed since the previous run.
+pub(crate) fn setup_env() {
+    if !envmnt::is("CARGO_MAKE_WATCH_TARGETED")
+        || envmnt::exists("CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER")
+    {
+        return;
+    }
+
+    let changed_files = get_changed_files();
+    if changed_files.is_empty() {
+        return;
+    }
+
+    let targets = load_packages().and_then(|(workspace_root, packages)| {
+        get_targets(&workspace_root, &packages, &changed_files)
+    });
+
+    match targets {
+        Some(ref targets) => {
+            info!("Watch targets: {}", targets.packages.join(", "));
+            set_env(targets);
+        }
+        None => debug!("Unable to resolve watch targets, running all members."),
+    }
+}
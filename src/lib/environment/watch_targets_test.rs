@@ -0,0 +1,188 @@
+use super::*;
+
+fn create_packages() -> Vec<WatchPackage> {
+    vec![
+        WatchPackage {
+            name: "root".to_string(),
+            directory: PathBuf::from("/workspace"),
+            test_targets: vec![],
+        },
+        WatchPackage {
+            name: "core".to_string(),
+            directory: PathBuf::from("/workspace/crates/core"),
+            test_targets: vec![
+                (
+                    "api".to_string(),
+                    PathBuf::from("/workspace/crates/core/tests/api.rs"),
+                ),
+                (
+                    "cli".to_string(),
+                    PathBuf::from("/workspace/crates/core/tests/cli.rs"),
+                ),
+            ],
+        },
+        WatchPackage {
+            name: "utils".to_string(),
+            directory: PathBuf::from("/workspace/crates/utils"),
+            test_targets: vec![],
+        },
+    ]
+}
+
+fn get_targets_for_files(files: &[&str]) -> Option<WatchTargets> {
+    let changed_files: Vec<PathBuf> = files.iter().map(PathBuf::from).collect();
+
+    get_targets(Path::new("/workspace"), &create_packages(), &changed_files)
+}
+
+#[test]
+fn get_member_name_root() {
+    let name = get_member_name(Path::new("/workspace"), Path::new("/workspace"));
+
+    assert_eq!(name, ".");
+}
+
+#[test]
+fn get_member_name_nested() {
+    let name = get_member_name(Path::new("/workspace"), Path::new("/workspace/crates/core"));
+
+    assert_eq!(name, "crates/core");
+}
+
+#[test]
+fn get_targets_no_files() {
+    assert!(get_targets_for_files(&[]).is_none());
+}
+
+#[test]
+fn get_targets_not_owned() {
+    let targets = get_targets_for_files(&["/other/src/lib.rs"]);
+
+    assert!(targets.is_none());
+}
+
+#[test]
+fn get_targets_single_package() {
+    let targets = get_targets_for_files(&["/workspace/crates/utils/src/lib.rs"]).unwrap();
+
+    assert_eq!(
+        targets,
+        WatchTargets {
+            packages: vec!["utils".to_string()],
+            members: vec!["crates/utils".to_string()],
+            tests: vec![],
+        }
+    );
+}
+
+#[test]
+fn get_targets_nested_package_first() {
+    let targets = get_targets_for_files(&[
+        "/workspace/crates/core/src/lib.rs",
+        "/workspace/src/main.rs",
+        "/workspace/crates/core/src/types.rs",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        targets.packages,
+        vec!["core".to_string(), "root".to_string()]
+    );
+    assert_eq!(
+        targets.members,
+        vec!["crates/core".to_string(), ".".to_string()]
+    );
+    assert!(targets.tests.is_empty());
+}
+
+#[test]
+fn get_targets_tests_only() {
+    let targets = get_targets_for_files(&[
+        "/workspace/crates/core/tests/cli.rs",
+        "/workspace/crates/core/tests/api.rs",
+    ])
+    .unwrap();
+
+    assert_eq!(targets.packages, vec!["core".to_string()]);
+    assert_eq!(targets.tests, vec!["cli".to_string(), "api".to_string()]);
+}
+
+#[test]
+fn get_targets_tests_and_sources() {
+    let targets = get_targets_for_files(&[
+        "/workspace/crates/core/tests/cli.rs",
+        "/workspace/crates/core/src/lib.rs",
+    ])
+    .unwrap();
+
+    assert_eq!(targets.packages, vec!["core".to_string()]);
+    assert!(targets.tests.is_empty());
+}
+
+#[test]
+fn get_targets_tests_multiple_packages() {
+    let targets = get_targets_for_files(&[
+        "/workspace/crates/core/tests/cli.rs",
+        "/workspace/crates/utils/src/lib.rs",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        targets.packages,
+        vec!["core".to_string(), "utils".to_string()]
+    );
+    assert!(targets.tests.is_empty());
+}
+
+#[test]
+#[ignore]
+#[cfg(target_os = "linux")]
+fn get_changed_files_relative_to_common_path() {
+    envmnt::set("WATCHEXEC_COMMON_PATH", "/workspace");
+    envmnt::set(
+        "WATCHEXEC_WRITTEN_PATH",
+        "src/lib.rs:crates/core/src/lib.rs",
+    );
+    envmnt::set("WATCHEXEC_CREATED_PATH", "src/lib.rs");
+
+    let changed_files = get_changed_files();
+
+    envmnt::remove("WATCHEXEC_COMMON_PATH");
+    envmnt::remove("WATCHEXEC_WRITTEN_PATH");
+    envmnt::remove("WATCHEXEC_CREATED_PATH");
+
+    assert_eq!(
+        changed_files,
+        vec![
+            PathBuf::from("/workspace/src/lib.rs"),
+            PathBuf::from("/workspace/crates/core/src/lib.rs")
+        ]
+    );
+}
+
+#[test]
+#[ignore]
+fn set_env_targets() {
+    envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
+
+    set_env(&WatchTargets {
+        packages: vec!["core".to_string()],
+        members: vec!["crates/core".to_string()],
+        tests: vec!["api".to_string(), "cli".to_string()],
+    });
+
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_WATCH_PACKAGE_FLAGS"),
+        "-p core"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_WATCH_TEST_FLAGS"),
+        "--test api --test cli"
+    );
+    assert_eq!(
+        envmnt::get_list("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS").unwrap(),
+        vec!["crates/core".to_string()]
+    );
+
+    envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
+}
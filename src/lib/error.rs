@@ -19,7 +19,7 @@ pub enum CargoMakeError {
     #[strum(to_string = "Detected cycle while resolving alias {0}: {1}")]
     AliasCycle(String, String) = 101,
 
-    #[strum(to_string = "Circular reference found for task: {0:#?}")]
+    #[strum(to_string = "Circular reference found: {0}")]
     CircularReference(String) = 102,
 
     #[strum(to_string = "Unable to run, minimum required version is: {0}")]
@@ -46,6 +46,54 @@ pub enum CargoMakeError {
     #[strum(to_string = "Task {0:#?} is {1}")]
     TaskIs(String, &'static str) = 110,
 
+    #[strum(to_string = "Descriptor validation failed, found {0} issue(s).")]
+    ValidationFailed(usize) = 111,
+
+    #[strum(to_string = "Found {0} task(s) defined in multiple makefiles.")]
+    DuplicateTasks(usize) = 112,
+
+    #[strum(
+        to_string = "Task {0:#?} is private and cannot be referenced by task {1:#?} from a different namespace"
+    )]
+    PrivateTaskReference(String, String) = 113,
+
+    #[strum(to_string = "Invalid argument: {0}")]
+    InvalidArgument(String) = 114,
+
+    #[strum(to_string = "Task {0:#?} is not allowed by the execution policy: {1}")]
+    PolicyViolation(String, String) = 115,
+
+    #[strum(to_string = "Task {0:#?} did not produce the declared outputs: {1}")]
+    MissingTaskOutputs(String, String) = 116,
+
+    #[strum(
+        to_string = "Task {0:#?} was renamed to {1:#?} and can no longer be invoked by its old name"
+    )]
+    ExpiredTaskAlias(String, String) = 117,
+
+    #[strum(to_string = "Task {0:#?} was not confirmed")]
+    TaskNotConfirmed(String) = 118,
+
+    #[strum(to_string = "Missing value for input: {0}")]
+    MissingTaskInput(String) = 119,
+
+    #[strum(to_string = "Unable to run task {0:#?}, minimum required version is: {1}")]
+    TaskVersionTooOld(String, String) = 120,
+
+    #[strum(to_string = "Unable to update cargo-make, {0}")]
+    SelfUpdateFailed(String) = 121,
+
+    #[strum(to_string = "Found {0} benchmark regression(s) compared to baseline: {1}")]
+    BenchRegression(usize, String) = 122,
+
+    #[strum(
+        to_string = "Another flow is already running in this project ({0}), use --wait to wait for it or --no-lock to run anyway"
+    )]
+    RunLocked(String) = 123,
+
+    #[strum(to_string = "Unable to send notification, {0}")]
+    NotificationFailed(String) = 124,
+
     #[strum(to_string = "{0}")]
     NotFound(String) = 404,
 
@@ -74,7 +122,7 @@ pub enum CargoMakeError {
 }
 
 impl CargoMakeError {
-    fn discriminant(&self) -> u16 {
+    pub(crate) fn discriminant(&self) -> u16 {
         unsafe { *(self as *const Self as *const u16) }
     }
 }
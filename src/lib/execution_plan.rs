@@ -13,15 +13,154 @@ use crate::logger;
 use crate::profile;
 use crate::proxy_task::create_proxy_task;
 use crate::types::{
-    Config, CrateInfo, EnvValue, ExecutionPlan, ScriptValue, Step, Task, TaskIdentifier,
+    Config, CrateInfo, DependencyIdentifier, EnvValue, ExecutionPlan, ScriptValue, Step, Task,
+    TaskIdentifier,
 };
+use crate::version;
 use fsio::path::{get_basename, get_parent_directory};
 use glob::Pattern;
 use indexmap::IndexMap;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashSet;
 use std::env;
 use std::path::Path;
+use std::sync::Mutex;
+
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+static FORWARD_ALIAS_WARNINGS: Lazy<Mutex<HashSet<String>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Returns the edit distance (number of single character insertions, deletions
+/// or substitutions) between the two values.
+fn get_edit_distance(left: &str, right: &str) -> usize {
+    let right_chars: Vec<char> = right.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=right_chars.len()).collect();
+
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut current_row = vec![left_index + 1];
+
+        for (right_index, right_char) in right_chars.iter().enumerate() {
+            let substitution_cost = if left_char == *right_char { 0 } else { 1 };
+            let distance = (previous_row[right_index] + substitution_cost)
+                .min(previous_row[right_index + 1] + 1)
+                .min(current_row[right_index] + 1);
+            current_row.push(distance);
+        }
+
+        previous_row = current_row;
+    }
+
+    previous_row[right_chars.len()]
+}
+
+/// Returns up to 3 task names (including alias tasks) closest to the given name.
+pub(crate) fn get_similar_task_names(config: &Config, name: &str) -> Vec<String> {
+    let lowercase_name = name.to_lowercase();
+    let max_distance = lowercase_name.chars().count().div_ceil(3);
+
+    let mut candidates: Vec<(usize, &String)> = config
+        .tasks
+        .keys()
+        .filter_map(|task_name| {
+            let distance = get_edit_distance(&lowercase_name, &task_name.to_lowercase());
+
+            if distance <= max_distance {
+                Some((distance, task_name))
+            } else {
+                None
+            }
+        })
+        .collect();
+    candidates.sort();
+
+    candidates
+        .iter()
+        .take(3)
+        .map(|(_, task_name)| task_name.to_string())
+        .collect()
+}
+
+fn create_task_not_found_error(config: &Config, name: &str) -> CargoMakeError {
+    let similar_task_names = get_similar_task_names(config, name);
+
+    if similar_task_names.is_empty() {
+        CargoMakeError::NotFound(format!("Task {:#?} not found", name))
+    } else {
+        let suggestions: Vec<String> = similar_task_names
+            .iter()
+            .map(|task_name| format!("{:#?}", task_name))
+            .collect();
+
+        CargoMakeError::NotFound(format!(
+            "Task {:#?} not found, did you mean: {}?",
+            name,
+            suggestions.join(", ")
+        ))
+    }
+}
+
+fn is_forward_alias_expired(until: &Option<String>) -> bool {
+    match until {
+        Some(ref until) => {
+            version::is_same(until, VERSION, true, false)
+                || version::is_newer(until, VERSION, true, false)
+        }
+        None => false,
+    }
+}
+
+fn find_forwarding_task<'a>(config: &'a Config, name: &str) -> Option<(&'a String, &'a Task)> {
+    config
+        .tasks
+        .iter()
+        .find(|(_, task_config)| match task_config.forward_alias {
+            Some(ref forward_alias) => forward_alias.name == name,
+            None => false,
+        })
+}
+
+/// Returns true if the provided (old) task name is forwarded to another task.
+pub(crate) fn is_forwarded_task_name(config: &Config, name: &str) -> bool {
+    find_forwarding_task(config, name).is_some()
+}
+
+/// Returns the name of the task which forwards the provided (old) task name, if any.
+fn get_forwarded_task_name(config: &Config, name: &str) -> Result<Option<String>, CargoMakeError> {
+    let forwarded = find_forwarding_task(config, name);
+
+    match forwarded {
+        Some((task_name, task_config)) => {
+            let until = &task_config.forward_alias.as_ref().unwrap().until;
+
+            if is_forward_alias_expired(until) {
+                Err(CargoMakeError::ExpiredTaskAlias(
+                    name.to_string(),
+                    task_name.to_string(),
+                ))
+            } else {
+                let removal = match until {
+                    Some(ref until) => format!(" and will be removed in version {}", until),
+                    None => "".to_string(),
+                };
+                // the name is resolved multiple times while creating the execution plan
+                if FORWARD_ALIAS_WARNINGS
+                    .lock()
+                    .unwrap()
+                    .insert(name.to_string())
+                {
+                    warn!(
+                        "Task: {} is deprecated{} (replaced by: {})",
+                        name, removal, task_name
+                    );
+                }
+
+                Ok(Some(task_name.to_string()))
+            }
+        }
+        None => Ok(None),
+    }
+}
 
 /// Resolve aliases recursively until a task without alias is found.
 fn get_task_name_recursive(
@@ -45,10 +184,12 @@ fn get_task_name_recursive(
                 None => Ok(name.to_string()),
             }
         }
-        None => Err(CargoMakeError::NotFound(format!(
-            "Task {:#?} not found",
-            name
-        ))),
+        None => match get_forwarded_task_name(config, name)? {
+            Some(ref task_name) if !seen.contains(task_name) => {
+                get_task_name_recursive(config, task_name, seen)
+            }
+            _ => Err(create_task_not_found_error(config, name)),
+        },
     }
 }
 
@@ -117,10 +258,7 @@ fn get_optional_normalized_task(
 
             Ok(normalized_task)
         }
-        None => Err(CargoMakeError::NotFound(format!(
-            "Task {:#?} not found",
-            name
-        ))),
+        None => Err(create_task_not_found_error(config, name)),
     }
 }
 
@@ -216,10 +354,10 @@ fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
 
     let log_level = logger::get_log_level();
 
-    let profile_name = if envmnt::is_or("CARGO_MAKE_USE_WORKSPACE_PROFILE", true) {
-        profile::get()
+    let profile_names = if envmnt::is_or("CARGO_MAKE_USE_WORKSPACE_PROFILE", true) {
+        profile::get_all()
     } else {
-        profile::default_profile()
+        vec![profile::default_profile()]
     };
 
     let filtered_members = filter_workspace_members(&members);
@@ -247,8 +385,10 @@ fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
             make_line.push_str(&log_level);
             make_line.push_str(" --env CARGO_MAKE_CRATE_CURRENT_WORKSPACE_MEMBER=");
             make_line.push_str(&member_name);
-            make_line.push_str(" --profile ");
-            make_line.push_str(&profile_name);
+            for profile_name in &profile_names {
+                make_line.push_str(" --profile ");
+                make_line.push_str(profile_name);
+            }
             make_line.push_str(" -- ");
             make_line.push_str(&task);
 
@@ -330,13 +470,122 @@ fn is_workspace_flow(
     }
 }
 
+/// Returns the namespace of the task (empty for tasks without namespace)
+pub(crate) fn get_namespace(name: &str) -> &str {
+    match name.rsplit_once("::") {
+        Some((namespace, _)) => namespace,
+        None => "",
+    }
+}
+
+/// Returns the makefile which (last) defined the given task
+fn get_task_source_file(config: &Config, name: &str) -> String {
+    // only tasks loaded from external makefiles hold the makefile location
+    match config.tasks.get(name) {
+        Some(task) => task.get_makefile_path().unwrap_or("internal".to_string()),
+        None => "internal".to_string(),
+    }
+}
+
+/// Formats the dependency cycle path (a -> b -> a) with the makefiles defining each edge
+pub(crate) fn format_dependency_cycle(config: &Config, cycle: &[String]) -> String {
+    let edges: Vec<String> = cycle
+        .windows(2)
+        .map(|edge| {
+            format!(
+                "{} -> {} in {}",
+                &edge[0],
+                &edge[1],
+                get_task_source_file(config, &edge[0])
+            )
+        })
+        .collect();
+
+    format!("{} (defined by: {})", cycle.join(" -> "), edges.join(", "))
+}
+
+/// Returns the names of all tasks which are invoked before the given task.<br>
+/// Unlike the execution plan, this does not fail on missing tasks.
+fn get_dependency_edges(config: &Config, name: &str) -> Vec<String> {
+    let mut task = match config.tasks.get(name) {
+        Some(task) => task.clone().get_normalized_task(),
+        None => return vec![],
+    };
+
+    if let Some(alias) = task.get_alias() {
+        return vec![alias];
+    }
+
+    // dependencies may be inherited from the extended tasks
+    let mut extended_names = HashSet::new();
+    while task.dependencies.is_none() {
+        match task.extend {
+            Some(ref extended_name) if extended_names.insert(extended_name.to_string()) => {
+                task = match config.tasks.get(extended_name) {
+                    Some(extended_task) => extended_task.clone().get_normalized_task(),
+                    None => return vec![],
+                };
+            }
+            _ => return vec![],
+        }
+    }
+
+    task.dependencies
+        .unwrap_or_default()
+        .iter()
+        .filter(|dependency| match dependency {
+            // tasks from other makefiles are invoked in a new process
+            DependencyIdentifier::Definition(identifier) => identifier.path.is_none(),
+            DependencyIdentifier::Name(_) => true,
+        })
+        .map(|dependency| dependency.name().to_string())
+        .collect()
+}
+
+fn collect_dependency_cycles(
+    config: &Config,
+    name: &str,
+    parents: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    if let Some(position) = parents.iter().position(|parent| parent == name) {
+        let mut cycle = parents[position..].to_vec();
+        cycle.push(name.to_string());
+        cycles.push(cycle);
+        return;
+    }
+
+    if !visited.insert(name.to_string()) {
+        return;
+    }
+
+    parents.push(name.to_string());
+    for dependency in get_dependency_edges(config, name) {
+        collect_dependency_cycles(config, &dependency, parents, visited, cycles);
+    }
+    parents.pop();
+}
+
+/// Returns all dependency (and alias) cycles found in the descriptor
+pub(crate) fn find_dependency_cycles(config: &Config) -> Vec<Vec<String>> {
+    let mut visited = HashSet::new();
+    let mut cycles = vec![];
+
+    for name in config.tasks.keys() {
+        collect_dependency_cycles(config, name, &mut vec![], &mut visited, &mut cycles);
+    }
+
+    cycles
+}
+
 /// Creates an execution plan for the given step based on existing execution plan data
 fn create_for_step(
     config: &Config,
     task: &TaskIdentifier,
     steps: &mut Vec<Step>,
     task_names: &mut HashSet<String>,
-    root: bool,
+    parents: &mut Vec<String>,
     allow_private: bool,
     skip_tasks_pattern: Option<&Regex>,
 ) -> Result<(), CargoMakeError> {
@@ -361,7 +610,8 @@ fn create_for_step(
             (Some(path.to_string()), Some("Makefile.toml".to_string()))
         };
 
-        let mut proxy_task = create_proxy_task(&task.name, true, false, makefile, None);
+        let mut proxy_task =
+            create_proxy_task(&task.name, true, false, makefile, task.args.clone());
         proxy_task.cwd = working_directory;
 
         let step = Step {
@@ -376,7 +626,19 @@ fn create_for_step(
         return Ok(());
     }
 
-    let task_config = get_normalized_task(config, &task.name, true)?;
+    if let Some(position) = parents.iter().position(|name| *name == task.name) {
+        let mut cycle = parents[position..].to_vec();
+        cycle.push(task.name.clone());
+
+        return Err(CargoMakeError::CircularReference(format_dependency_cycle(
+            config, &cycle,
+        )));
+    }
+
+    let mut task_config = get_normalized_task(config, &task.name, true)?;
+    if let Some(ref args) = task.args {
+        environment::apply_task_args(&mut task_config, args);
+    }
 
     debug!("Normalized Task: {} config: {:#?}", &task, &task_config);
 
@@ -385,41 +647,69 @@ fn create_for_step(
         None => false,
     };
 
-    if allow_private || !is_private {
+    // private tasks can be referenced only by tasks in the same namespace
+    let parent = parents.last();
+    let referenced_privately = match parent {
+        Some(parent_name) => get_namespace(parent_name) == get_namespace(&task.name),
+        None => false,
+    };
+
+    if allow_private || !is_private || referenced_privately {
         let add = !task_config.disabled.unwrap_or(false);
 
         if add {
             match task_config.dependencies {
                 Some(ref dependencies) => {
+                    parents.push(task.name.clone());
+
                     for dependency in dependencies {
                         create_for_step(
                             &config,
                             &dependency.to_owned().into(),
                             steps,
                             task_names,
-                            false,
-                            true,
+                            parents,
+                            allow_private,
                             skip_tasks_pattern,
                         )?;
                     }
+
+                    parents.pop();
                 }
                 _ => debug!("No dependencies found for task: {}", &task),
             };
 
-            if !task_names.contains(&task.name) {
+            // the same task invoked with different arguments is a different step
+            let step_key = match task.args {
+                Some(ref args) => format!("{} {:?}", &task, args),
+                None => task.to_string(),
+            };
+            if !task_names.contains(&step_key) {
                 steps.push(Step {
                     name: task.to_string(),
                     config: task_config,
                 });
-                task_names.insert(task.to_string());
-            } else if root {
-                return Err(CargoMakeError::CircularReference(format!("{}", task)));
+                task_names.insert(step_key);
             }
         }
         Ok(())
     } else {
-        error!("Task {} is private", &task);
-        return Err(CargoMakeError::TaskIs(format!("{}", task), "private"));
+        match parent {
+            Some(parent_name) => {
+                error!(
+                    "Task {} is private and cannot be referenced by task {} from a different namespace",
+                    &task, parent_name
+                );
+                Err(CargoMakeError::PrivateTaskReference(
+                    task.to_string(),
+                    parent_name.to_string(),
+                ))
+            }
+            None => {
+                error!("Task {} is private", &task);
+                Err(CargoMakeError::TaskIs(format!("{}", task), "private"))
+            }
+        }
     }
 }
 
@@ -440,6 +730,48 @@ fn add_predefined_step(
     Ok(())
 }
 
+/// Removes all steps before the from step and all the skipped steps
+fn slice_flow_steps(
+    steps: Vec<Step>,
+    from_step: Option<&str>,
+    skip_steps: Option<&[String]>,
+) -> Result<Vec<Step>, CargoMakeError> {
+    let steps = match from_step {
+        Some(from_step) => match steps.iter().position(|step| step.name == from_step) {
+            Some(index) => {
+                for step in &steps[..index] {
+                    debug!("Skipping Task: {} (before: {})", &step.name, from_step);
+                }
+
+                steps.into_iter().skip(index).collect()
+            }
+            None => {
+                return Err(CargoMakeError::NotFound(format!(
+                    "Step {} not found in the flow.",
+                    from_step
+                )))
+            }
+        },
+        None => steps,
+    };
+
+    match skip_steps {
+        Some(skip_steps) => {
+            for skip_step in skip_steps {
+                if !steps.iter().any(|step| &step.name == skip_step) {
+                    warn!("Skipped step: {} not found in the flow.", skip_step);
+                }
+            }
+
+            Ok(steps
+                .into_iter()
+                .filter(|step| !skip_steps.contains(&step.name))
+                .collect())
+        }
+        None => Ok(steps),
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ExecutionPlanBuilder<'a> {
     pub config: &'a Config,
@@ -450,6 +782,8 @@ pub(crate) struct ExecutionPlanBuilder<'a> {
     pub sub_flow: bool,
     pub skip_tasks_pattern: Option<&'a Regex>,
     pub skip_init_end_tasks: bool,
+    pub from_step: Option<&'a str>,
+    pub skip_steps: Option<&'a [String]>,
 }
 
 impl<'a> ExecutionPlanBuilder<'a> {
@@ -463,6 +797,8 @@ impl<'a> ExecutionPlanBuilder<'a> {
             sub_flow: false,
             skip_tasks_pattern: None,
             skip_init_end_tasks: false,
+            from_step: None,
+            skip_steps: None,
         }
     }
 
@@ -476,6 +812,8 @@ impl<'a> ExecutionPlanBuilder<'a> {
             sub_flow,
             skip_tasks_pattern,
             skip_init_end_tasks,
+            from_step,
+            skip_steps,
         } = *self;
         let mut task_names = HashSet::new();
         let mut steps = Vec::new();
@@ -502,6 +840,8 @@ impl<'a> ExecutionPlanBuilder<'a> {
             };
         }
 
+        let flow_start_index = steps.len();
+
         let skip = match skip_tasks_pattern {
             Some(pattern) => pattern.is_match(task),
             None => false,
@@ -524,7 +864,7 @@ impl<'a> ExecutionPlanBuilder<'a> {
                     &TaskIdentifier::from_name(task),
                     &mut steps,
                     &mut task_names,
-                    true,
+                    &mut vec![],
                     allow_private,
                     skip_tasks_pattern,
                 )?;
@@ -533,6 +873,12 @@ impl<'a> ExecutionPlanBuilder<'a> {
             debug!("Skipping task: {} due to skip pattern.", &task);
         }
 
+        // the flow slicing only applies to the main flow (not init/end tasks and sub flows)
+        if !sub_flow {
+            let flow_steps = steps.split_off(flow_start_index);
+            steps.extend(slice_flow_steps(flow_steps, from_step, skip_steps)?);
+        }
+
         if !skip_init_end_tasks {
             // always add end task even if already executed due to some dependency
             match config.config.end_task {
@@ -1,7 +1,8 @@
 use super::*;
 use crate::descriptor;
 use crate::types::{
-    ConfigSection, DependencyIdentifier, PlatformOverrideTask, TaskWatchOptions, Workspace,
+    ConfigSection, DependencyIdentifier, ForwardAlias, PlatformOverrideTask, TaskWatchOptions,
+    Workspace,
 };
 
 #[test]
@@ -13,6 +14,12 @@ fn get_actual_task_name_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let name = get_actual_task_name(&config, "test");
@@ -26,6 +33,99 @@ fn get_actual_task_name_not_found() {
     );
 }
 
+#[test]
+fn get_actual_task_name_not_found_with_suggestions() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    config.tasks.insert("build".to_string(), Task::new());
+    config.tasks.insert("test".to_string(), Task::new());
+
+    let name = get_actual_task_name(&config, "biuld");
+
+    assert_eq!(
+        format!("{:?}", name.err().unwrap()),
+        format!(
+            "{:?}",
+            CargoMakeError::NotFound(String::from(
+                "Task \"biuld\" not found, did you mean: \"build\"?"
+            ))
+        )
+    );
+}
+
+#[test]
+fn get_edit_distance_values() {
+    assert_eq!(get_edit_distance("", ""), 0);
+    assert_eq!(get_edit_distance("build", "build"), 0);
+    assert_eq!(get_edit_distance("", "build"), 5);
+    assert_eq!(get_edit_distance("buld", "build"), 1);
+    assert_eq!(get_edit_distance("biuld", "build"), 2);
+    assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+}
+
+#[test]
+fn get_similar_task_names_sorted_and_limited() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    for name in ["test3", "test1", "test", "test2", "Test", "build"] {
+        config.tasks.insert(name.to_string(), Task::new());
+    }
+
+    let names = get_similar_task_names(&config, "test");
+
+    assert_eq!(names, vec!["Test", "test", "test1"]);
+}
+
+#[test]
+fn get_similar_task_names_none() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    config.tasks.insert("build".to_string(), Task::new());
+
+    let names = get_similar_task_names(&config, "format");
+
+    assert!(names.is_empty());
+}
+
 #[test]
 fn get_actual_task_name_no_alias() {
     let mut config = Config {
@@ -35,6 +135,12 @@ fn get_actual_task_name_no_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("test".to_string(), Task::new());
@@ -44,6 +150,91 @@ fn get_actual_task_name_no_alias() {
     assert_eq!(name.unwrap(), "test");
 }
 
+fn create_forward_alias_config(until: Option<&str>) -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut task = Task::new();
+    task.forward_alias = Some(ForwardAlias {
+        name: "old".to_string(),
+        until: until.map(|value| value.to_string()),
+    });
+    config.tasks.insert("new".to_string(), task);
+
+    config
+}
+
+#[test]
+fn get_actual_task_name_forward_alias() {
+    let config = create_forward_alias_config(None);
+
+    let name = get_actual_task_name(&config, "old");
+
+    assert_eq!(name.unwrap(), "new");
+}
+
+#[test]
+fn get_actual_task_name_forward_alias_not_expired() {
+    let config = create_forward_alias_config(Some("10000.0.0"));
+
+    let name = get_actual_task_name(&config, "old");
+
+    assert_eq!(name.unwrap(), "new");
+}
+
+#[test]
+fn get_actual_task_name_forward_alias_expired() {
+    let config = create_forward_alias_config(Some("0.1.0"));
+
+    let error = get_actual_task_name(&config, "old").unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::ExpiredTaskAlias(_, _)));
+    assert_eq!(
+        error.to_string(),
+        "Task \"old\" was renamed to \"new\" and can no longer be invoked by its old name"
+    );
+}
+
+#[test]
+fn get_actual_task_name_forward_alias_current_version() {
+    let config = create_forward_alias_config(Some(VERSION));
+
+    let error = get_actual_task_name(&config, "old").unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::ExpiredTaskAlias(_, _)));
+}
+
+#[test]
+fn get_actual_task_name_forward_alias_defined_task() {
+    let mut config = create_forward_alias_config(Some("0.1.0"));
+    config.tasks.insert("old".to_string(), Task::new());
+
+    let name = get_actual_task_name(&config, "old");
+
+    assert_eq!(name.unwrap(), "old");
+}
+
+#[test]
+fn is_forwarded_task_name_found() {
+    let config = create_forward_alias_config(None);
+
+    assert!(is_forwarded_task_name(&config, "old"));
+    assert!(!is_forwarded_task_name(&config, "new"));
+    assert!(!is_forwarded_task_name(&config, "other"));
+}
+
 #[test]
 fn get_actual_task_name_alias() {
     let mut config = Config {
@@ -53,6 +244,12 @@ fn get_actual_task_name_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -76,6 +273,12 @@ fn get_actual_task_name_alias_self_referential() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -95,6 +298,12 @@ fn get_actual_task_name_alias_circular() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task_a = Task::new();
@@ -118,6 +327,12 @@ fn get_actual_task_name_platform_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -486,6 +701,12 @@ fn is_workspace_flow_true_default() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -517,6 +738,12 @@ fn is_workspace_flow_false_in_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -548,6 +775,12 @@ fn is_workspace_flow_true_in_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -577,6 +810,12 @@ fn is_workspace_flow_true_in_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -605,6 +844,12 @@ fn is_workspace_flow_default_false_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -634,6 +879,12 @@ fn is_workspace_flow_true_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -663,6 +914,12 @@ fn is_workspace_flow_false_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -689,6 +946,12 @@ fn is_workspace_flow_task_not_defined() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let workspace_flow = is_workspace_flow(&config, "notfound", false, &crate_info, false);
@@ -710,6 +973,12 @@ fn is_workspace_flow_no_workspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -739,6 +1008,12 @@ fn is_workspace_flow_disabled_via_cli() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -768,6 +1043,12 @@ fn is_workspace_flow_disabled_via_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -788,6 +1069,12 @@ fn create_single() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -821,6 +1108,12 @@ fn create_single_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -855,6 +1148,12 @@ fn create_single_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -882,6 +1181,12 @@ fn create_single_allow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -904,6 +1209,78 @@ fn create_single_allow_private() {
     assert_eq!(execution_plan.steps[2].name, "end");
 }
 
+#[test]
+fn get_namespace_none() {
+    assert_eq!(get_namespace("build"), "");
+}
+
+#[test]
+fn get_namespace_nested() {
+    assert_eq!(get_namespace("a::b::build"), "a::b");
+}
+
+fn create_config_with_private_dependency(task_name: &str, dependency_name: &str) -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec![dependency_name.into()]);
+    config.tasks.insert(task_name.to_string(), task);
+
+    let mut dependency = Task::new();
+    dependency.private = Some(true);
+    config.tasks.insert(dependency_name.to_string(), dependency);
+
+    config
+}
+
+#[test]
+fn create_with_private_dependency_same_namespace() {
+    let config = create_config_with_private_dependency("ns::test", "ns::helper");
+
+    let execution_plan = ExecutionPlanBuilder::new(&config, "ns::test")
+        .build()
+        .unwrap();
+    assert_eq!(execution_plan.steps.len(), 2);
+    assert_eq!(execution_plan.steps[0].name, "ns::helper");
+    assert_eq!(execution_plan.steps[1].name, "ns::test");
+}
+
+#[test]
+#[should_panic]
+fn create_with_private_dependency_different_namespace() {
+    let config = create_config_with_private_dependency("test", "ns::helper");
+
+    ExecutionPlanBuilder::new(&config, "test").build().unwrap();
+}
+
+#[test]
+fn create_with_private_dependency_different_namespace_allow_private() {
+    let config = create_config_with_private_dependency("test", "ns::helper");
+
+    let execution_plan = ExecutionPlanBuilder {
+        allow_private: true,
+        ..ExecutionPlanBuilder::new(&config, "test")
+    }
+    .build()
+    .unwrap();
+    assert_eq!(execution_plan.steps.len(), 2);
+    assert_eq!(execution_plan.steps[0].name, "ns::helper");
+    assert_eq!(execution_plan.steps[1].name, "test");
+}
+
 #[test]
 fn create_with_dependencies() {
     let mut config_section = ConfigSection::new();
@@ -916,6 +1293,12 @@ fn create_with_dependencies() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -944,6 +1327,230 @@ fn create_with_dependencies() {
     assert_eq!(execution_plan.steps[3].name, "end");
 }
 
+fn create_steps(names: &[&str]) -> Vec<Step> {
+    names
+        .iter()
+        .map(|name| Step {
+            name: name.to_string(),
+            config: Task::new(),
+        })
+        .collect()
+}
+
+fn get_step_names(steps: &[Step]) -> Vec<String> {
+    steps.iter().map(|step| step.name.to_string()).collect()
+}
+
+#[test]
+fn slice_flow_steps_none() {
+    let steps = slice_flow_steps(create_steps(&["a", "b", "c"]), None, None).unwrap();
+
+    assert_eq!(get_step_names(&steps), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn slice_flow_steps_from() {
+    let steps = slice_flow_steps(create_steps(&["a", "b", "c"]), Some("b"), None).unwrap();
+
+    assert_eq!(get_step_names(&steps), vec!["b", "c"]);
+}
+
+#[test]
+fn slice_flow_steps_from_not_found() {
+    let result = slice_flow_steps(create_steps(&["a", "b", "c"]), Some("d"), None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn slice_flow_steps_skip() {
+    let skip_steps = vec!["b".to_string(), "d".to_string()];
+    let steps = slice_flow_steps(create_steps(&["a", "b", "c"]), None, Some(&skip_steps)).unwrap();
+
+    assert_eq!(get_step_names(&steps), vec!["a", "c"]);
+}
+
+#[test]
+fn create_with_from_and_skip_steps() {
+    let mut config_section = ConfigSection::new();
+    config_section.init_task = Some("init".to_string());
+    config_section.end_task = Some("end".to_string());
+    let mut config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    config.tasks.insert("init".to_string(), Task::new());
+    config.tasks.insert("end".to_string(), Task::new());
+    config.tasks.insert("build".to_string(), Task::new());
+    config.tasks.insert("test".to_string(), Task::new());
+    config.tasks.insert("audit".to_string(), Task::new());
+    config.tasks.insert("lint".to_string(), Task::new());
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec![
+        "build".into(),
+        "test".into(),
+        "audit".into(),
+        "lint".into(),
+    ]);
+    config.tasks.insert("ci-flow".to_string(), task);
+
+    let skip_steps = vec!["audit".to_string()];
+    let execution_plan = ExecutionPlanBuilder {
+        from_step: Some("test"),
+        skip_steps: Some(&skip_steps),
+        ..ExecutionPlanBuilder::new(&config, "ci-flow")
+    }
+    .build()
+    .unwrap();
+
+    assert_eq!(
+        get_step_names(&execution_plan.steps),
+        vec!["init", "test", "lint", "ci-flow", "end"]
+    );
+}
+
+#[test]
+fn create_sub_flow_ignores_from_step() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    config.tasks.insert("build".to_string(), Task::new());
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["build".into()]);
+    config.tasks.insert("test".to_string(), task);
+
+    let execution_plan = ExecutionPlanBuilder {
+        sub_flow: true,
+        from_step: Some("missing"),
+        ..ExecutionPlanBuilder::new(&config, "test")
+    }
+    .build()
+    .unwrap();
+
+    assert_eq!(get_step_names(&execution_plan.steps), vec!["build", "test"]);
+}
+
+#[test]
+fn create_with_circular_dependencies() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["b".into()]);
+    config.tasks.insert("a".to_string(), task);
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["c".into()]);
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./other.toml".to_string()),
+    );
+    task.env = Some(env);
+    config.tasks.insert("b".to_string(), task);
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["b".into()]);
+    config.tasks.insert("c".to_string(), task);
+
+    let error = ExecutionPlanBuilder::new(&config, "a").build().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Circular reference found: b -> c -> b (defined by: b -> c in ./other.toml, c -> b in internal)"
+    );
+}
+
+#[test]
+fn find_dependency_cycles_none() {
+    let config = descriptor::load_internal_descriptors(true, false, None, None).unwrap();
+
+    let cycles = find_dependency_cycles(&config);
+
+    assert!(cycles.is_empty());
+}
+
+#[test]
+fn find_dependency_cycles_found() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["b".into(), "missing".into()]);
+    config.tasks.insert("a".to_string(), task);
+
+    let mut task = Task::new();
+    task.extend = Some("base".to_string());
+    config.tasks.insert("b".to_string(), task);
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["a".into()]);
+    config.tasks.insert("base".to_string(), task);
+
+    let mut task = Task::new();
+    task.alias = Some("alias".to_string());
+    config.tasks.insert("alias".to_string(), task);
+
+    let cycles = find_dependency_cycles(&config);
+
+    assert_eq!(
+        cycles,
+        vec![
+            vec!["a".to_string(), "b".to_string(), "a".to_string()],
+            vec!["alias".to_string(), "alias".to_string()]
+        ]
+    );
+}
+
 #[test]
 fn create_with_foreign_dependencies_directory() {
     let mut config_section = ConfigSection::new();
@@ -956,6 +1563,12 @@ fn create_with_foreign_dependencies_directory() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -965,6 +1578,7 @@ fn create_with_foreign_dependencies_directory() {
     task.dependencies = Some(vec![DependencyIdentifier::Definition(TaskIdentifier {
         name: "task_dependency".to_string(),
         path: Some("./examples/workspace".to_string()),
+        args: None,
     })]);
 
     let task_dependency = Task::new();
@@ -1004,6 +1618,12 @@ fn create_with_foreign_dependencies_filename() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1013,6 +1633,7 @@ fn create_with_foreign_dependencies_filename() {
     task.dependencies = Some(vec![DependencyIdentifier::Definition(TaskIdentifier {
         name: "task_dependency".to_string(),
         path: Some("Cargo.toml".to_string()),
+        args: None,
     })]);
 
     let task_dependency = Task::new();
@@ -1052,6 +1673,12 @@ fn create_with_foreign_dependencies_file_and_directory() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1061,6 +1688,7 @@ fn create_with_foreign_dependencies_file_and_directory() {
     task.dependencies = Some(vec![DependencyIdentifier::Definition(TaskIdentifier {
         name: "task_dependency".to_string(),
         path: Some("./examples/cross-file.toml".to_string()),
+        args: None,
     })]);
 
     let task_dependency = Task::new();
@@ -1100,6 +1728,12 @@ fn create_with_dependencies_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1127,6 +1761,66 @@ fn create_with_dependencies_sub_flow() {
     assert_eq!(execution_plan.steps[1].name, "test");
 }
 
+#[test]
+fn create_with_dependencies_args() {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec![
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "build".to_string(),
+            path: None,
+            args: Some(vec!["--release".to_string()]),
+        }),
+        "build".into(),
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "build".to_string(),
+            path: None,
+            args: Some(vec!["--release".to_string()]),
+        }),
+    ]);
+
+    let mut build = Task::new();
+    build.command = Some("cargo".to_string());
+    build.args = Some(vec!["build".to_string(), "${@}".to_string()]);
+
+    config.tasks.insert("test".to_string(), task);
+    config.tasks.insert("build".to_string(), build);
+
+    let execution_plan = ExecutionPlanBuilder {
+        sub_flow: true,
+        ..ExecutionPlanBuilder::new(&config, "test")
+    }
+    .build()
+    .unwrap();
+
+    assert_eq!(execution_plan.steps.len(), 3);
+    assert_eq!(execution_plan.steps[0].name, "build");
+    assert_eq!(
+        execution_plan.steps[0].config.args,
+        Some(vec!["build".to_string(), "--release".to_string()])
+    );
+    assert_eq!(execution_plan.steps[1].name, "build");
+    assert_eq!(
+        execution_plan.steps[1].config.args,
+        Some(vec!["build".to_string(), "${@}".to_string()])
+    );
+    assert_eq!(execution_plan.steps[2].name, "test");
+}
+
 #[test]
 fn create_disabled_task_with_dependencies() {
     let mut config_section = ConfigSection::new();
@@ -1139,6 +1833,12 @@ fn create_disabled_task_with_dependencies() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1178,6 +1878,12 @@ fn create_with_dependencies_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1215,6 +1921,12 @@ fn create_platform_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -1246,6 +1958,11 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        on_error_task: None,
+        commands: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
     });
     task.windows = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1275,6 +1992,11 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        on_error_task: None,
+        commands: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
     });
     task.mac = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1304,6 +2026,11 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        on_error_task: None,
+        commands: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
     });
 
     config.tasks.insert("test".to_string(), task);
@@ -1329,6 +2056,12 @@ fn create_with_dependencies_and_skip_filter() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1371,6 +2104,12 @@ fn create_workspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let task = Task::new();
@@ -1401,6 +2140,12 @@ fn create_noworkspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let task = Task::new();
@@ -1430,6 +2175,7 @@ fn create_task_extends_empty_env_bug_verification() {
         true,
         None,
         false,
+        None,
     )
     .unwrap();
 
@@ -1548,6 +2294,11 @@ fn get_normalized_task_multi_extend() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        on_error_task: None,
+        commands: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
     };
 
     let mut task2 = Task::new();
@@ -1569,6 +2320,12 @@ fn get_normalized_task_multi_extend() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("1".to_string(), task1);
     config.tasks.insert("2".to_string(), task2);
@@ -1599,6 +2356,12 @@ fn get_normalized_task_simple() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.tasks.insert("1".to_string(), task1);
 
@@ -1622,6 +2385,12 @@ fn respect_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     config.tasks.insert("init".to_string(), Task::new());
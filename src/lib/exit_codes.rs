@@ -0,0 +1,152 @@
+//! # exit_codes
+//!
+//! Maps the flow failure classes (task failure, descriptor error, timeout, ...) to the
+//! process exit codes defined in the exit_codes config, so callers can tell why the flow failed.
+//!
+
+#[cfg(test)]
+#[path = "exit_codes_test.rs"]
+mod exit_codes_test;
+
+use crate::diagnostics;
+use crate::error::CargoMakeError;
+use crate::types::ExitCodesConfig;
+use once_cell::sync::Lazy;
+use std::cell::Cell;
+use std::process::ExitCode;
+use std::sync::RwLock;
+
+/// The classes of flow failures which can be mapped to exit codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FailureClass {
+    /// A task failed
+    TaskFailure,
+    /// The task conditions could not be validated
+    ConditionFailure,
+    /// The makefiles could not be loaded
+    DescriptorError,
+    /// A task dependency could not be installed
+    InstallFailure,
+    /// A command timed out
+    Timeout,
+}
+
+/// The exit codes configuration (global config extended by the makefile config)
+static EXIT_CODES: Lazy<RwLock<ExitCodesConfig>> =
+    Lazy::new(|| RwLock::new(ExitCodesConfig::default()));
+
+/// The class of the first failed task of the flow
+static FLOW_FAILURE_CLASS: Lazy<RwLock<Option<FailureClass>>> = Lazy::new(|| RwLock::new(None));
+
+thread_local! {
+    /// The class of the work currently running in this thread
+    static CURRENT_CLASS: Cell<Option<FailureClass>> = const { Cell::new(None) };
+    /// The class of the failure of the task running in this thread
+    static TASK_FAILURE_CLASS: Cell<Option<FailureClass>> = const { Cell::new(None) };
+}
+
+/// Sets the exit codes configuration, values defined in the provided config override the
+/// current values
+pub(crate) fn init(exit_codes: &Option<ExitCodesConfig>) {
+    if let Some(ref exit_codes) = exit_codes {
+        if let Ok(mut current) = EXIT_CODES.write() {
+            current.extend(exit_codes);
+        }
+    }
+}
+
+fn get_exit_code_for_class(exit_codes: &ExitCodesConfig, class: FailureClass) -> Option<u8> {
+    match class {
+        FailureClass::TaskFailure => exit_codes.task_failure,
+        FailureClass::ConditionFailure => exit_codes.condition_failure,
+        FailureClass::DescriptorError => exit_codes.descriptor_error,
+        FailureClass::InstallFailure => exit_codes.install_failure,
+        FailureClass::Timeout => exit_codes.timeout,
+    }
+}
+
+fn get_exit_code(class: FailureClass) -> Option<u8> {
+    match EXIT_CODES.read() {
+        Ok(exit_codes) => get_exit_code_for_class(&exit_codes, class),
+        Err(_) => None,
+    }
+}
+
+/// Marks the failure of the task running in the current thread with the provided class,
+/// unless it was already classified
+pub(crate) fn set_failure_class(class: FailureClass) {
+    TASK_FAILURE_CLASS.with(|current| {
+        if current.get().is_none() {
+            current.set(Some(class));
+        }
+    });
+}
+
+/// Clears the failure class of the task running in the current thread
+pub(crate) fn clear_failure_class() {
+    TASK_FAILURE_CLASS.with(|current| current.set(None));
+}
+
+/// Runs the provided function as the provided failure class, so any error it returns (or
+/// error log it prints) is reported with the matching exit code
+pub(crate) fn run_as<T, F: FnOnce() -> Result<T, CargoMakeError>>(
+    class: FailureClass,
+    function: F,
+) -> Result<T, CargoMakeError> {
+    let previous = CURRENT_CLASS.with(|current| current.replace(Some(class)));
+
+    let result = function();
+
+    CURRENT_CLASS.with(|current| current.set(previous));
+
+    if result.is_err() {
+        set_failure_class(class);
+    }
+
+    result
+}
+
+/// Records the failure class of the failed task running in the current thread as the flow
+/// failure class (only the first failed task of the flow is recorded)
+pub(crate) fn record_task_failure() {
+    let class = TASK_FAILURE_CLASS
+        .with(|current| current.take())
+        .unwrap_or(FailureClass::TaskFailure);
+
+    if let Ok(mut flow_class) = FLOW_FAILURE_CLASS.write() {
+        if flow_class.is_none() {
+            *flow_class = Some(class);
+        }
+    }
+}
+
+/// Returns the exit code used when the process exits due to an error log message
+pub(crate) fn get_error_exit_code() -> i32 {
+    let class = CURRENT_CLASS.with(|current| current.get());
+
+    match class.and_then(get_exit_code) {
+        Some(exit_code) => exit_code as i32,
+        None => 1,
+    }
+}
+
+fn get_failure_class() -> Option<FailureClass> {
+    let flow_class = match FLOW_FAILURE_CLASS.read() {
+        Ok(flow_class) => *flow_class,
+        Err(_) => None,
+    };
+
+    flow_class.or_else(|| TASK_FAILURE_CLASS.with(|current| current.get()))
+}
+
+/// Converts the error to the exit code defined for its failure class (if defined), printing
+/// the error as the original exit code is replaced
+pub(crate) fn map_error(error: CargoMakeError) -> CargoMakeError {
+    match get_failure_class().and_then(get_exit_code) {
+        Some(exit_code) => {
+            diagnostics::print_error(&error);
+            CargoMakeError::ExitCode(ExitCode::from(exit_code))
+        }
+        None => error,
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+#[test]
+fn get_exit_code_for_class_all() {
+    let exit_codes = ExitCodesConfig {
+        task_failure: Some(10),
+        condition_failure: Some(11),
+        descriptor_error: Some(12),
+        install_failure: Some(13),
+        timeout: Some(124),
+    };
+
+    assert_eq!(
+        get_exit_code_for_class(&exit_codes, FailureClass::TaskFailure),
+        Some(10)
+    );
+    assert_eq!(
+        get_exit_code_for_class(&exit_codes, FailureClass::ConditionFailure),
+        Some(11)
+    );
+    assert_eq!(
+        get_exit_code_for_class(&exit_codes, FailureClass::DescriptorError),
+        Some(12)
+    );
+    assert_eq!(
+        get_exit_code_for_class(&exit_codes, FailureClass::InstallFailure),
+        Some(13)
+    );
+    assert_eq!(
+        get_exit_code_for_class(&exit_codes, FailureClass::Timeout),
+        Some(124)
+    );
+}
+
+#[test]
+fn get_exit_code_for_class_undefined() {
+    let exit_codes = ExitCodesConfig::default();
+
+    assert!(get_exit_code_for_class(&exit_codes, FailureClass::TaskFailure).is_none());
+}
+
+#[test]
+fn run_as_ok() {
+    clear_failure_class();
+
+    let result = run_as(FailureClass::InstallFailure, || Ok(true));
+
+    assert!(result.unwrap());
+    assert!(TASK_FAILURE_CLASS.with(|current| current.get()).is_none());
+    assert!(CURRENT_CLASS.with(|current| current.get()).is_none());
+}
+
+#[test]
+fn run_as_error() {
+    clear_failure_class();
+
+    let result: Result<(), CargoMakeError> = run_as(FailureClass::InstallFailure, || {
+        assert_eq!(
+            CURRENT_CLASS.with(|current| current.get()),
+            Some(FailureClass::InstallFailure)
+        );
+        Err(CargoMakeError::ExitCodeError(1))
+    });
+
+    assert!(result.is_err());
+    assert_eq!(
+        TASK_FAILURE_CLASS.with(|current| current.get()),
+        Some(FailureClass::InstallFailure)
+    );
+    assert!(CURRENT_CLASS.with(|current| current.get()).is_none());
+
+    clear_failure_class();
+}
+
+#[test]
+fn run_as_nested_error() {
+    clear_failure_class();
+
+    let result: Result<(), CargoMakeError> = run_as(FailureClass::TaskFailure, || {
+        run_as(FailureClass::ConditionFailure, || {
+            Err(CargoMakeError::ExitCodeError(1))
+        })
+    });
+
+    assert!(result.is_err());
+    assert_eq!(
+        TASK_FAILURE_CLASS.with(|current| current.get()),
+        Some(FailureClass::ConditionFailure)
+    );
+
+    clear_failure_class();
+}
+
+#[test]
+fn set_failure_class_keeps_first() {
+    clear_failure_class();
+
+    set_failure_class(FailureClass::Timeout);
+    set_failure_class(FailureClass::TaskFailure);
+
+    assert_eq!(
+        TASK_FAILURE_CLASS.with(|current| current.get()),
+        Some(FailureClass::Timeout)
+    );
+
+    clear_failure_class();
+}
+
+#[test]
+fn get_error_exit_code_not_configured() {
+    assert_eq!(get_error_exit_code(), 1);
+}
@@ -0,0 +1,61 @@
+//! # date_func
+//!
+//! Takes a strftime style format and optionally the time zone (utc/local) and returns
+//! the current time formatted.
+//!
+
+#[cfg(test)]
+#[path = "date_func_test.rs"]
+mod date_func_test;
+
+use crate::error::CargoMakeError;
+use chrono::{DateTime, Local, TimeZone, Utc};
+use std::fmt::{Display, Write};
+
+fn format_date<Tz>(date: &DateTime<Tz>, format: &str) -> Result<String, CargoMakeError>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    let mut value = String::new();
+
+    match write!(value, "{}", date.format(format)) {
+        Ok(_) => Ok(value),
+        Err(_) => {
+            error!("Invalid date format: {}", format);
+            Err(CargoMakeError::MethodCallRestriction(
+                "date expects a valid strftime format.",
+            ))
+        }
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() > 2 {
+        return Err(CargoMakeError::Arity(
+            "date expects up to 2 arguments (format and optionally the time zone: utc/local)",
+        ));
+    }
+
+    let format = match function_args.first() {
+        Some(format) if !format.is_empty() => format.to_string(),
+        _ => "%Y-%m-%dT%H:%M:%S%:z".to_string(),
+    };
+    let time_zone = match function_args.get(1) {
+        Some(time_zone) => time_zone.to_lowercase(),
+        None => "local".to_string(),
+    };
+
+    let value = match time_zone.as_str() {
+        "utc" => format_date(&Utc::now(), &format)?,
+        "local" => format_date(&Local::now(), &format)?,
+        _ => {
+            error!("Invalid time zone provided, only utc or local are supported.");
+            return Err(CargoMakeError::MethodCallRestriction(
+                "Invalid time zone provided, only utc or local are supported.",
+            ));
+        }
+    };
+
+    Ok(vec![value])
+}
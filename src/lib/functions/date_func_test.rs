@@ -0,0 +1,56 @@
+use super::*;
+use chrono::NaiveDate;
+
+#[test]
+#[should_panic]
+fn date_invoke_invalid_too_many_args() {
+    invoke(&["%Y".to_string(), "utc".to_string(), "2".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn date_invoke_invalid_time_zone() {
+    invoke(&["%Y".to_string(), "bad".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn date_invoke_invalid_format() {
+    invoke(&["%Q".to_string()]).unwrap();
+}
+
+#[test]
+fn format_date_valid() {
+    let date = NaiveDate::from_ymd_opt(2024, 3, 7)
+        .unwrap()
+        .and_hms_opt(9, 5, 1)
+        .unwrap()
+        .and_utc();
+
+    assert_eq!(
+        format_date(&date, "%Y%m%d-%H%M%S").unwrap(),
+        "20240307-090501"
+    );
+}
+
+#[test]
+fn date_invoke_default_format() {
+    let output = invoke(&[]).unwrap();
+
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0].len(), 25);
+}
+
+#[test]
+fn date_invoke_utc() {
+    let output = invoke(&["%Y %Z".to_string(), "UTC".to_string()]).unwrap();
+
+    assert_eq!(output, vec![format!("{} UTC", Utc::now().format("%Y"))]);
+}
+
+#[test]
+fn date_invoke_local() {
+    let output = invoke(&["%Y".to_string(), "local".to_string()]).unwrap();
+
+    assert_eq!(output, vec![Local::now().format("%Y").to_string()]);
+}
@@ -0,0 +1,82 @@
+//! # file_contents_func
+//!
+//! Takes a file path and optionally the trim and/or base64 flags and returns the file
+//! contents (trimmed and/or base64 encoded).
+//! The value will be removed if empty.
+//!
+
+#[cfg(test)]
+#[path = "file_contents_func_test.rs"]
+mod file_contents_func_test;
+
+use crate::error::CargoMakeError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+fn encode_contents(
+    file: &str,
+    contents: Vec<u8>,
+    trim: bool,
+    base64: bool,
+) -> Result<String, CargoMakeError> {
+    if base64 {
+        let contents = if trim {
+            contents.trim_ascii().to_vec()
+        } else {
+            contents
+        };
+
+        return Ok(STANDARD.encode(contents));
+    }
+
+    match String::from_utf8(contents) {
+        Ok(text) if trim => Ok(text.trim().to_string()),
+        Ok(text) => Ok(text),
+        Err(_) => Err(CargoMakeError::InvalidArgument(format!(
+            "File: {} is not a valid UTF-8 text file, use the base64 flag to read binary files.",
+            file
+        ))),
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.is_empty() || function_args.len() > 3 {
+        return Err(CargoMakeError::Arity(
+            "file-contents expects up to 3 arguments (file path and optionally the trim/base64 flags)",
+        ));
+    }
+
+    let file = &function_args[0];
+    let mut trim = false;
+    let mut base64 = false;
+    for flag in &function_args[1..] {
+        match flag.as_str() {
+            "trim" => trim = true,
+            "base64" => base64 = true,
+            _ => {
+                error!(
+                    "Invalid file-contents flag: {}, only trim or base64 are supported.",
+                    flag
+                );
+                return Err(CargoMakeError::MethodCallRestriction(
+                    "Invalid file-contents flag provided, only trim or base64 are supported.",
+                ));
+            }
+        }
+    }
+
+    let contents = match fsio::file::read_file(file) {
+        Ok(contents) => contents,
+        Err(error) => {
+            error!("Unable to read file: {}, error: {}", file, error);
+            return Err(error.into());
+        }
+    };
+    let value = encode_contents(file, contents, trim, base64)?;
+
+    if value.is_empty() {
+        Ok(vec![])
+    } else {
+        Ok(vec![value])
+    }
+}
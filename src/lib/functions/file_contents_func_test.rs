@@ -0,0 +1,92 @@
+use super::*;
+use fsio::file::write_file;
+use fsio::path::as_path::AsPath;
+
+fn create_file(name: &str, contents: &[u8]) -> String {
+    let path = format!("./target/_temp/file_contents_func/{}", name);
+    write_file(&path, contents).unwrap();
+
+    path.as_path().to_string_lossy().into_owned()
+}
+
+#[test]
+#[should_panic]
+fn file_contents_invoke_invalid_no_args() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn file_contents_invoke_invalid_too_many_args() {
+    invoke(&[
+        "Cargo.toml".to_string(),
+        "trim".to_string(),
+        "base64".to_string(),
+        "trim".to_string(),
+    ])
+    .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn file_contents_invoke_invalid_flag() {
+    invoke(&["Cargo.toml".to_string(), "bad".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn file_contents_invoke_file_not_found() {
+    invoke(&["./target/_temp/file_contents_func/missing".to_string()]).unwrap();
+}
+
+#[test]
+fn file_contents_invoke_raw() {
+    let file = create_file("raw.txt", b"  1.2.3\n");
+
+    let output = invoke(&[file]).unwrap();
+
+    assert_eq!(output, vec!["  1.2.3\n"]);
+}
+
+#[test]
+fn file_contents_invoke_trim() {
+    let file = create_file("trim.txt", b"  1.2.3\n");
+
+    let output = invoke(&[file, "trim".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["1.2.3"]);
+}
+
+#[test]
+fn file_contents_invoke_empty() {
+    let file = create_file("empty.txt", b" \n");
+
+    let output = invoke(&[file, "trim".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
+
+#[test]
+fn file_contents_invoke_base64() {
+    let file = create_file("base64.bin", &[0, 159, 146, 150, 10]);
+
+    let output = invoke(&[file, "base64".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["AJ+Slgo="]);
+}
+
+#[test]
+fn file_contents_invoke_trim_and_base64() {
+    let file = create_file("trim_base64.txt", b"hello\n");
+
+    let output = invoke(&[file, "base64".to_string(), "trim".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["aGVsbG8="]);
+}
+
+#[test]
+fn encode_contents_invalid_utf8() {
+    let error = encode_contents("test", vec![0, 159, 146, 150], false, false).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::InvalidArgument(_)));
+}
@@ -0,0 +1,66 @@
+//! # glob_func
+//!
+//! Takes a glob pattern and optionally a root directory and returns all matching paths
+//! (sorted) as separate values.
+//! The value will be removed if no path matches.
+//!
+
+#[cfg(test)]
+#[path = "glob_func_test.rs"]
+mod glob_func_test;
+
+use crate::error::CargoMakeError;
+use fsio::path::from_path::FromPath;
+use glob::glob;
+
+fn get_pattern(function_args: &[String]) -> String {
+    let pattern = &function_args[0];
+
+    if function_args.len() == 1 || function_args[1].is_empty() {
+        pattern.to_string()
+    } else {
+        let root = function_args[1].replace("\\", "/");
+        format!("{}/{}", root.trim_end_matches('/'), pattern)
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.is_empty() || function_args.len() > 2 {
+        return Err(CargoMakeError::Arity(
+            "glob expects up to 2 arguments (glob pattern and optionally the root directory)",
+        ));
+    }
+
+    let pattern = get_pattern(function_args);
+
+    let paths = match glob(&pattern) {
+        Ok(paths) => paths,
+        Err(error) => {
+            error!(
+                "Error while running glob: {}, error: {:#?}",
+                &pattern, error
+            );
+            return Err(CargoMakeError::MethodCallRestriction(
+                "glob expects a valid glob pattern.",
+            ));
+        }
+    };
+
+    let mut path_list = vec![];
+    for entry in paths {
+        match entry {
+            Ok(path) => {
+                let value: String = FromPath::from_path(&path);
+                path_list.push(value.replace("\\", "/"));
+            }
+            Err(error) => warn!(
+                "Unable to read glob: {} entry, error: {:#?}",
+                &pattern, error
+            ),
+        }
+    }
+
+    path_list.sort();
+
+    Ok(path_list)
+}
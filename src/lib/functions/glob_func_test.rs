@@ -0,0 +1,84 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn glob_invoke_empty() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn glob_invoke_invalid_too_many_args() {
+    invoke(&["*".to_string(), ".".to_string(), "2".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn glob_invoke_invalid_pattern() {
+    invoke(&["src/***".to_string()]).unwrap();
+}
+
+#[test]
+fn get_pattern_no_root() {
+    assert_eq!(get_pattern(&["src/**/*.rs".to_string()]), "src/**/*.rs");
+}
+
+#[test]
+fn get_pattern_empty_root() {
+    assert_eq!(
+        get_pattern(&["src/**/*.rs".to_string(), "".to_string()]),
+        "src/**/*.rs"
+    );
+}
+
+#[test]
+fn get_pattern_with_root() {
+    assert_eq!(
+        get_pattern(&["**/*.rs".to_string(), "./src/".to_string()]),
+        "./src/**/*.rs"
+    );
+}
+
+#[test]
+fn glob_invoke_files() {
+    let output = invoke(&["src/lib/functions/glob_func*.rs".to_string()]).unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            "src/lib/functions/glob_func.rs",
+            "src/lib/functions/glob_func_test.rs"
+        ]
+    );
+}
+
+#[test]
+fn glob_invoke_with_root() {
+    let output = invoke(&["glob_func*.rs".to_string(), "src/lib/functions".to_string()]).unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            "src/lib/functions/glob_func.rs",
+            "src/lib/functions/glob_func_test.rs"
+        ]
+    );
+}
+
+#[test]
+fn glob_invoke_sorted() {
+    let output = invoke(&["src/lib/functions/*_func.rs".to_string()]).unwrap();
+
+    let mut sorted = output.clone();
+    sorted.sort();
+
+    assert!(output.len() > 1);
+    assert_eq!(output, sorted);
+}
+
+#[test]
+fn glob_invoke_no_match() {
+    let output = invoke(&["src/**/*.does_not_exist".to_string()]).unwrap();
+
+    assert!(output.is_empty());
+}
@@ -0,0 +1,108 @@
+//! # json_query_func
+//!
+//! Takes a JSON file path and a simple path expression (for example: package.authors[0])
+//! and returns the matching value.
+//! Arrays of simple values are returned as separate values and other arrays/objects
+//! are returned as JSON text.
+//! The value will be removed if the path does not exist.
+//!
+
+#[cfg(test)]
+#[path = "json_query_func_test.rs"]
+mod json_query_func_test;
+
+use crate::error::CargoMakeError;
+use serde_json::Value;
+
+fn get_path_segments(path: &str) -> Vec<String> {
+    path.replace('[', ".")
+        .replace(']', "")
+        .split('.')
+        .map(|segment| segment.trim().to_string())
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+fn get_simple_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.to_string()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the values found in the provided path (empty if the path does not exist)
+pub(crate) fn query(value: &Value, path: &str) -> Vec<String> {
+    let mut current = value;
+    for segment in get_path_segments(path) {
+        let next = match current {
+            Value::Object(map) => map.get(&segment),
+            Value::Array(items) => match segment.parse::<usize>() {
+                Ok(index) => items.get(index),
+                Err(_) => None,
+            },
+            _ => None,
+        };
+
+        current = match next {
+            Some(next) => next,
+            None => return vec![],
+        };
+    }
+
+    match current {
+        Value::Null => vec![],
+        Value::Array(items) => {
+            let values: Option<Vec<String>> = items.iter().map(get_simple_value).collect();
+
+            match values {
+                Some(values) => values,
+                None => vec![current.to_string()],
+            }
+        }
+        Value::Object(_) => vec![current.to_string()],
+        _ => get_simple_value(current).into_iter().collect(),
+    }
+}
+
+/// Reads the file text after validating the function arguments
+pub(crate) fn read_query_file(
+    function_name: &'static str,
+    function_args: &[String],
+) -> Result<String, CargoMakeError> {
+    if function_args.len() != 2 {
+        error!(
+            "{} expects 2 arguments (file path and query path)",
+            function_name
+        );
+        return Err(CargoMakeError::Arity(
+            "query functions expect 2 arguments (file path and query path)",
+        ));
+    }
+
+    let file = &function_args[0];
+    match fsio::file::read_text_file(file) {
+        Ok(text) => Ok(text),
+        Err(error) => {
+            error!("Unable to read file: {}, error: {}", file, error);
+            Err(error.into())
+        }
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    let text = read_query_file("json-query", function_args)?;
+
+    let value: Value = match serde_json::from_str(&text) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(CargoMakeError::ParseFileFailed(
+                function_args[0].to_string(),
+                error.to_string(),
+            ));
+        }
+    };
+
+    Ok(query(&value, &function_args[1]))
+}
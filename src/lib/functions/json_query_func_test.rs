@@ -0,0 +1,95 @@
+use super::*;
+use serde_json::json;
+
+fn create_value() -> Value {
+    json!({
+        "name": "test",
+        "version": 3,
+        "private": false,
+        "optional": null,
+        "keywords": ["build", "make"],
+        "authors": [{"name": "first"}, {"name": "second"}],
+        "nested": {"inner": {"value": 1.5}}
+    })
+}
+
+#[test]
+#[should_panic]
+fn json_query_invoke_invalid_no_args() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn json_query_invoke_invalid_one_arg() {
+    invoke(&["package.json".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn json_query_invoke_file_not_found() {
+    invoke(&["./bad.json".to_string(), "name".to_string()]).unwrap();
+}
+
+#[test]
+fn json_query_invoke_invalid_json() {
+    let error = invoke(&["Cargo.toml".to_string(), "package".to_string()]).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::ParseFileFailed(_, _)));
+}
+
+#[test]
+fn json_query_invoke_valid() {
+    let file = "./target/_temp/json_query_func/test.json";
+    fsio::file::write_text_file(file, &create_value().to_string()).unwrap();
+
+    let output = invoke(&[file.to_string(), "authors[1].name".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["second"]);
+}
+
+#[test]
+fn get_path_segments_dots_and_indexes() {
+    assert_eq!(
+        get_path_segments("a.b[0].c"),
+        vec![
+            "a".to_string(),
+            "b".to_string(),
+            "0".to_string(),
+            "c".to_string()
+        ]
+    );
+}
+
+#[test]
+fn query_simple_values() {
+    let value = create_value();
+
+    assert_eq!(query(&value, "name"), vec!["test"]);
+    assert_eq!(query(&value, "version"), vec!["3"]);
+    assert_eq!(query(&value, "private"), vec!["false"]);
+    assert_eq!(query(&value, "nested.inner.value"), vec!["1.5"]);
+    assert_eq!(query(&value, "keywords.1"), vec!["make"]);
+}
+
+#[test]
+fn query_arrays_and_objects() {
+    let value = create_value();
+
+    assert_eq!(query(&value, "keywords"), vec!["build", "make"]);
+    assert_eq!(
+        query(&value, "authors"),
+        vec![r#"[{"name":"first"},{"name":"second"}]"#]
+    );
+    assert_eq!(query(&value, "nested.inner"), vec![r#"{"value":1.5}"#]);
+}
+
+#[test]
+fn query_not_found() {
+    let value = create_value();
+
+    assert!(query(&value, "missing").is_empty());
+    assert!(query(&value, "optional").is_empty());
+    assert!(query(&value, "keywords[5]").is_empty());
+    assert!(query(&value, "name.inner").is_empty());
+}
@@ -0,0 +1,27 @@
+//! # lower_func
+//!
+//! Takes an environment variable name and returns its value in lower case.
+//! The value will be removed if empty.
+//!
+
+use crate::error::CargoMakeError;
+
+#[cfg(test)]
+#[path = "lower_func_test.rs"]
+mod lower_func_test;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "lower expects only 1 argument (environment variable name)",
+        ));
+    }
+
+    let value = envmnt::get_or(&function_args[0], "").to_lowercase();
+
+    if !value.is_empty() {
+        Ok(vec![value])
+    } else {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn lower_invoke_empty() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn lower_invoke_invalid_too_many_args() {
+    invoke(&["TEST".to_string(), "1".to_string()]).unwrap();
+}
+
+#[test]
+fn lower_invoke_exists_with_value() {
+    envmnt::set("TEST_LOWER_VALID", "ABC-deF");
+
+    let output = invoke(&["TEST_LOWER_VALID".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["abc-def"]);
+}
+
+#[test]
+fn lower_invoke_not_exists() {
+    let output = invoke(&["TEST_LOWER_NOT_EXISTS".to_string()]).unwrap();
+
+    assert_eq!(output.len(), 0);
+}
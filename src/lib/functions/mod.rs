@@ -7,14 +7,31 @@
 #[path = "mod_test.rs"]
 mod mod_test;
 
+mod date_func;
 mod decode_func;
+mod file_contents_func;
 mod getat_func;
+mod glob_func;
+mod json_query_func;
+mod lower_func;
+mod random_func;
 mod remove_empty_func;
+mod replace_func;
 mod split_func;
+mod toml_query_func;
 mod trim_func;
+mod upper_func;
+mod uuid_func;
 
 use crate::error::CargoMakeError;
 use crate::types::{Step, Task};
+use std::collections::HashMap;
+
+/// Functions which are evaluated only once per task invocation
+static CACHED_FUNCTIONS: [&str; 2] = ["random", "uuid"];
+
+/// Holds the output of the cached functions for a single task invocation
+type InvocationCache = HashMap<String, Vec<String>>;
 
 fn run_function(
     function_name: &str,
@@ -31,6 +48,16 @@ fn run_function(
         "trim" => trim_func::invoke(function_args),
         "getat" => Ok(getat_func::invoke(function_args)),
         "decode" => Ok(decode_func::invoke(function_args)),
+        "upper" => upper_func::invoke(function_args),
+        "lower" => lower_func::invoke(function_args),
+        "replace" => replace_func::invoke(function_args),
+        "glob" => glob_func::invoke(function_args),
+        "date" => date_func::invoke(function_args),
+        "uuid" => uuid_func::invoke(function_args),
+        "random" => random_func::invoke(function_args),
+        "file-contents" => file_contents_func::invoke(function_args),
+        "json-query" => json_query_func::invoke(function_args),
+        "toml-query" => toml_query_func::invoke(function_args),
         _ => {
             error!("Unknown function: {}", &function_name);
             Err(CargoMakeError::NotFound(format!(
@@ -77,7 +104,31 @@ fn get_function_arguments(function_string: &str) -> Option<Vec<String>> {
     }
 }
 
-fn evaluate_and_run(value: &str) -> Result<Vec<String>, CargoMakeError> {
+fn run_function_cached(
+    function_name: &str,
+    function_args: &Vec<String>,
+    cache: &mut InvocationCache,
+) -> Result<Vec<String>, CargoMakeError> {
+    if !CACHED_FUNCTIONS.contains(&function_name) {
+        return run_function(function_name, function_args);
+    }
+
+    let key = format!("{}({})", function_name, function_args.join(","));
+    match cache.get(&key) {
+        Some(output) => Ok(output.clone()),
+        None => {
+            let output = run_function(function_name, function_args)?;
+            cache.insert(key, output.clone());
+
+            Ok(output)
+        }
+    }
+}
+
+fn evaluate_and_run(
+    value: &str,
+    cache: &mut InvocationCache,
+) -> Result<Vec<String>, CargoMakeError> {
     let value_string = value.to_string();
 
     if value_string.starts_with("@@") {
@@ -90,7 +141,9 @@ fn evaluate_and_run(value: &str) -> Result<Vec<String>, CargoMakeError> {
                 let func_args_option = get_function_arguments(&function_string);
 
                 match func_args_option {
-                    Some(function_args) => run_function(&function_name, &function_args),
+                    Some(function_args) => {
+                        run_function_cached(&function_name, &function_args, cache)
+                    }
                     None => Ok(vec![value_string]),
                 }
             }
@@ -105,9 +158,10 @@ fn modify_arguments(task: &mut Task) -> Result<(), CargoMakeError> {
     task.args = match task.args {
         Some(ref args) => {
             let mut new_args = vec![];
+            let mut cache = InvocationCache::new();
 
             for index in 0..args.len() {
-                let result_args = evaluate_and_run(&args[index])?;
+                let result_args = evaluate_and_run(&args[index], &mut cache)?;
 
                 for result_index in 0..result_args.len() {
                     new_args.push(result_args[result_index].clone());
@@ -121,6 +175,18 @@ fn modify_arguments(task: &mut Task) -> Result<(), CargoMakeError> {
     Ok(())
 }
 
+/// Runs the function if the value is a function call and returns the output values
+/// joined by a space (None if the value is not a function call).
+pub(crate) fn run_for_value(value: &str) -> Result<Option<String>, CargoMakeError> {
+    let output = evaluate_and_run(value, &mut InvocationCache::new())?;
+
+    if output.len() == 1 && output[0] == value {
+        Ok(None)
+    } else {
+        Ok(Some(output.join(" ")))
+    }
+}
+
 pub(crate) fn run(step: &Step) -> Result<Step, CargoMakeError> {
     //clone data before modify
     let mut config = step.config.clone();
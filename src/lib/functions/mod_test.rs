@@ -64,6 +64,70 @@ fn run_function_trim() {
     assert_eq!(output.len(), 0);
 }
 
+#[test]
+fn run_function_upper() {
+    envmnt::set("TEST_MOD_UPPER_FUNC_MOD", "abc");
+
+    let output = run_function("upper", &vec!["TEST_MOD_UPPER_FUNC_MOD".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["ABC"]);
+}
+
+#[test]
+fn run_function_lower() {
+    envmnt::set("TEST_MOD_LOWER_FUNC_MOD", "ABC");
+
+    let output = run_function("lower", &vec!["TEST_MOD_LOWER_FUNC_MOD".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["abc"]);
+}
+
+#[test]
+fn run_function_replace() {
+    envmnt::set("TEST_MOD_REPLACE_FUNC_MOD", "a-b-c");
+
+    let output = run_function(
+        "replace",
+        &vec![
+            "TEST_MOD_REPLACE_FUNC_MOD".to_string(),
+            "-".to_string(),
+            "+".to_string(),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(output, vec!["a+b+c"]);
+}
+
+#[test]
+fn run_function_glob() {
+    let output =
+        run_function("glob", &vec!["src/lib/functions/glob_func*.rs".to_string()]).unwrap();
+
+    assert_eq!(
+        output,
+        vec![
+            "src/lib/functions/glob_func.rs",
+            "src/lib/functions/glob_func_test.rs"
+        ]
+    );
+}
+
+#[test]
+fn run_function_uuid() {
+    let output = run_function("uuid", &vec![]).unwrap();
+
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0].len(), 36);
+}
+
+#[test]
+fn run_function_random() {
+    let output = run_function("random", &vec!["2".to_string(), "2".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["2"]);
+}
+
 #[test]
 fn run_function_decode() {
     envmnt::set("TEST_MOD_DECODE_FUNC_MOD", "ci");
@@ -172,7 +236,11 @@ fn get_function_arguments_multiple_with_spaces() {
 fn evaluate_and_run_valid() {
     envmnt::set("TEST_MOD_RUN_FUNC_VALUE", "1 2 3 4");
 
-    let output = evaluate_and_run("@@split(TEST_MOD_RUN_FUNC_VALUE, )").unwrap();
+    let output = evaluate_and_run(
+        "@@split(TEST_MOD_RUN_FUNC_VALUE, )",
+        &mut InvocationCache::new(),
+    )
+    .unwrap();
 
     assert_eq!(output, vec!["1", "2", "3", "4"]);
 }
@@ -180,12 +248,12 @@ fn evaluate_and_run_valid() {
 #[test]
 #[should_panic]
 fn evaluate_and_run_unknown_function() {
-    evaluate_and_run("@@bad()").unwrap();
+    evaluate_and_run("@@bad()", &mut InvocationCache::new()).unwrap();
 }
 
 #[test]
 fn evaluate_and_run_no_function() {
-    let output = evaluate_and_run("value").unwrap();
+    let output = evaluate_and_run("value", &mut InvocationCache::new()).unwrap();
 
     assert_eq!(output, vec!["value"]);
 }
@@ -206,6 +274,40 @@ fn modify_arguments_with_functions() {
     assert_eq!(task.args.unwrap(), vec!["start", "1", "2", "3", "4", "end"]);
 }
 
+#[test]
+fn modify_arguments_cached_functions() {
+    let mut task = Task::new();
+    task.args = Some(vec![
+        "@@uuid()".to_string(),
+        "@@uuid()".to_string(),
+        "@@uuid(other)".to_string(),
+        "@@random(1000000)".to_string(),
+        "@@random(1000000)".to_string(),
+    ]);
+
+    modify_arguments(&mut task).unwrap();
+
+    let args = task.args.unwrap();
+    assert_eq!(args[0], args[1]);
+    assert_ne!(args[0], args[2]);
+    assert_eq!(args[3], args[4]);
+}
+
+#[test]
+fn run_cached_functions_per_invocation() {
+    let mut task = Task::new();
+    task.args = Some(vec!["@@uuid()".to_string()]);
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let first = run(&step).unwrap();
+    let second = run(&step).unwrap();
+
+    assert_ne!(first.config.args.unwrap(), second.config.args.unwrap());
+}
+
 #[test]
 fn run_with_functions() {
     envmnt::set("TEST_MOD_STEP_FUNC_VALUE", "1 2 3 4");
@@ -228,3 +330,26 @@ fn run_with_functions() {
         vec!["start", "1", "2", "3", "4", "end"]
     );
 }
+
+#[test]
+fn run_for_value_no_function() {
+    let output = run_for_value("${TEST}").unwrap();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn run_for_value_with_function() {
+    envmnt::set("TEST_MOD_RUN_FOR_VALUE", "1 2 3");
+
+    let output = run_for_value("@@split(TEST_MOD_RUN_FOR_VALUE, )").unwrap();
+
+    assert_eq!(output.unwrap(), "1 2 3");
+}
+
+#[test]
+fn run_for_value_with_function_removed_value() {
+    let output = run_for_value("@@remove-empty(TEST_MOD_RUN_FOR_VALUE_NOT_EXISTS)").unwrap();
+
+    assert_eq!(output.unwrap(), "");
+}
@@ -0,0 +1,50 @@
+//! # random_func
+//!
+//! Takes an upper bound (or a lower and upper bound) and returns a random integer
+//! within the bounds (inclusive).
+//!
+
+#[cfg(test)]
+#[path = "random_func_test.rs"]
+mod random_func_test;
+
+use crate::error::CargoMakeError;
+use rand::Rng;
+
+fn parse_bound(value: &str) -> Result<i64, CargoMakeError> {
+    match value.parse::<i64>() {
+        Ok(bound) => Ok(bound),
+        Err(_) => {
+            error!("Invalid random bound: {}", value);
+            Err(CargoMakeError::MethodCallRestriction(
+                "random expects integer bounds.",
+            ))
+        }
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    let (min, max) = match function_args.len() {
+        1 => (0, parse_bound(&function_args[0])?),
+        2 => (
+            parse_bound(&function_args[0])?,
+            parse_bound(&function_args[1])?,
+        ),
+        _ => {
+            return Err(CargoMakeError::Arity(
+                "random expects 1 or 2 arguments (optional lower bound and upper bound)",
+            ));
+        }
+    };
+
+    if min > max {
+        error!("Invalid random bounds: {} is greater than {}", min, max);
+        return Err(CargoMakeError::MethodCallRestriction(
+            "random expects the lower bound to not exceed the upper bound.",
+        ));
+    }
+
+    let value = rand::thread_rng().gen_range(min..=max);
+
+    Ok(vec![value.to_string()])
+}
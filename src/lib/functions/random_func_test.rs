@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn random_invoke_invalid_no_args() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn random_invoke_invalid_too_many_args() {
+    invoke(&["1".to_string(), "2".to_string(), "3".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn random_invoke_invalid_bound() {
+    invoke(&["abc".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn random_invoke_invalid_range() {
+    invoke(&["10".to_string(), "1".to_string()]).unwrap();
+}
+
+#[test]
+fn random_invoke_upper_bound() {
+    for _ in 0..20 {
+        let output = invoke(&["5".to_string()]).unwrap();
+
+        assert_eq!(output.len(), 1);
+        let value = output[0].parse::<i64>().unwrap();
+        assert!((0..=5).contains(&value));
+    }
+}
+
+#[test]
+fn random_invoke_lower_and_upper_bound() {
+    for _ in 0..20 {
+        let output = invoke(&["-3".to_string(), "3".to_string()]).unwrap();
+
+        let value = output[0].parse::<i64>().unwrap();
+        assert!((-3..=3).contains(&value));
+    }
+}
+
+#[test]
+fn random_invoke_single_value_range() {
+    let output = invoke(&["7".to_string(), "7".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["7".to_string()]);
+}
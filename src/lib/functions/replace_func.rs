@@ -0,0 +1,35 @@
+//! # replace_func
+//!
+//! Takes an environment variable name, a search string and a replacement string and returns
+//! the value with all occurrences of the search string replaced.
+//! The value will be removed if empty.
+//!
+
+use crate::error::CargoMakeError;
+
+#[cfg(test)]
+#[path = "replace_func_test.rs"]
+mod replace_func_test;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 3 {
+        return Err(CargoMakeError::Arity(
+            "replace expects only 3 arguments (environment variable name, search string, replacement string)",
+        ));
+    }
+
+    let from = &function_args[1];
+    if from.is_empty() {
+        return Err(CargoMakeError::MethodCallRestriction(
+            "replace expects a non empty search string.",
+        ));
+    }
+
+    let value = envmnt::get_or(&function_args[0], "").replace(from, &function_args[2]);
+
+    if !value.is_empty() {
+        Ok(vec![value])
+    } else {
+        Ok(vec![])
+    }
+}
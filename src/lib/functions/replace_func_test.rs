@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn replace_invoke_empty() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn replace_invoke_invalid_too_few_args() {
+    invoke(&["TEST".to_string(), "a".to_string()]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn replace_invoke_empty_search_string() {
+    invoke(&["TEST".to_string(), "".to_string(), "b".to_string()]).unwrap();
+}
+
+#[test]
+fn replace_invoke_exists_with_value() {
+    envmnt::set("TEST_REPLACE_VALID", "1.2.3");
+
+    let output = invoke(&[
+        "TEST_REPLACE_VALID".to_string(),
+        ".".to_string(),
+        "_".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["1_2_3"]);
+}
+
+#[test]
+fn replace_invoke_empty_replacement() {
+    envmnt::set("TEST_REPLACE_EMPTY_REPLACEMENT", "v1.2.3");
+
+    let output = invoke(&[
+        "TEST_REPLACE_EMPTY_REPLACEMENT".to_string(),
+        "v".to_string(),
+        "".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output, vec!["1.2.3"]);
+}
+
+#[test]
+fn replace_invoke_all_replaced() {
+    envmnt::set("TEST_REPLACE_ALL_REPLACED", "aaa");
+
+    let output = invoke(&[
+        "TEST_REPLACE_ALL_REPLACED".to_string(),
+        "a".to_string(),
+        "".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output.len(), 0);
+}
+
+#[test]
+fn replace_invoke_not_exists() {
+    let output = invoke(&[
+        "TEST_REPLACE_NOT_EXISTS".to_string(),
+        "a".to_string(),
+        "b".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(output.len(), 0);
+}
@@ -0,0 +1,49 @@
+//! # toml_query_func
+//!
+//! Takes a TOML file path and a simple path expression (for example: package.version)
+//! and returns the matching value (see json_query_func for the output rules).
+//!
+
+#[cfg(test)]
+#[path = "toml_query_func_test.rs"]
+mod toml_query_func_test;
+
+use super::json_query_func;
+use crate::error::CargoMakeError;
+use serde_json::{Map, Value};
+
+fn to_json_value(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(text) => Value::String(text),
+        toml::Value::Integer(number) => Value::from(number),
+        toml::Value::Float(number) => Value::from(number),
+        toml::Value::Boolean(flag) => Value::Bool(flag),
+        toml::Value::Datetime(datetime) => Value::String(datetime.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(to_json_value).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, to_json_value(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+    }
+}
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    let text = json_query_func::read_query_file("toml-query", function_args)?;
+
+    let value: toml::Value = match toml::from_str(&text) {
+        Ok(value) => value,
+        Err(error) => {
+            return Err(CargoMakeError::ParseFileFailed(
+                function_args[0].to_string(),
+                error.to_string(),
+            ));
+        }
+    };
+
+    Ok(json_query_func::query(
+        &to_json_value(value),
+        &function_args[1],
+    ))
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn toml_query_invoke_invalid_too_many_args() {
+    invoke(&[
+        "Cargo.toml".to_string(),
+        "package.name".to_string(),
+        "3".to_string(),
+    ])
+    .unwrap();
+}
+
+#[test]
+fn toml_query_invoke_invalid_toml() {
+    let file = "./target/_temp/toml_query_func/invalid.toml";
+    fsio::file::write_text_file(file, "[package\n").unwrap();
+
+    let error = invoke(&[file.to_string(), "package".to_string()]).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::ParseFileFailed(_, _)));
+}
+
+#[test]
+fn toml_query_invoke_cargo_toml() {
+    let output = invoke(&["Cargo.toml".to_string(), "package.name".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["cargo-make"]);
+}
+
+#[test]
+fn toml_query_invoke_values() {
+    let file = "./target/_temp/toml_query_func/test.toml";
+    fsio::file::write_text_file(
+        file,
+        r#"
+[package]
+version = "1.2.3"
+authors = ["first", "second"]
+released = 2024-03-07
+
+[[bin]]
+name = "app"
+"#,
+    )
+    .unwrap();
+
+    let run = |path: &str| invoke(&[file.to_string(), path.to_string()]).unwrap();
+
+    assert_eq!(run("package.version"), vec!["1.2.3"]);
+    assert_eq!(run("package.authors"), vec!["first", "second"]);
+    assert_eq!(run("package.released"), vec!["2024-03-07"]);
+    assert_eq!(run("bin[0].name"), vec!["app"]);
+    assert!(run("package.missing").is_empty());
+}
@@ -0,0 +1,27 @@
+//! # upper_func
+//!
+//! Takes an environment variable name and returns its value in upper case.
+//! The value will be removed if empty.
+//!
+
+use crate::error::CargoMakeError;
+
+#[cfg(test)]
+#[path = "upper_func_test.rs"]
+mod upper_func_test;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() != 1 {
+        return Err(CargoMakeError::Arity(
+            "upper expects only 1 argument (environment variable name)",
+        ));
+    }
+
+    let value = envmnt::get_or(&function_args[0], "").to_uppercase();
+
+    if !value.is_empty() {
+        Ok(vec![value])
+    } else {
+        Ok(vec![])
+    }
+}
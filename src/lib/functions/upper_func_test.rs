@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn upper_invoke_empty() {
+    invoke(&[]).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn upper_invoke_invalid_too_many_args() {
+    invoke(&["TEST".to_string(), "1".to_string()]).unwrap();
+}
+
+#[test]
+fn upper_invoke_exists_with_value() {
+    envmnt::set("TEST_UPPER_VALID", "abc-Def");
+
+    let output = invoke(&["TEST_UPPER_VALID".to_string()]).unwrap();
+
+    assert_eq!(output, vec!["ABC-DEF"]);
+}
+
+#[test]
+fn upper_invoke_not_exists() {
+    let output = invoke(&["TEST_UPPER_NOT_EXISTS".to_string()]).unwrap();
+
+    assert_eq!(output.len(), 0);
+}
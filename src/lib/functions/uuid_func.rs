@@ -0,0 +1,23 @@
+//! # uuid_func
+//!
+//! Returns a new random (version 4) UUID.
+//! The optional argument is only used as a label, so multiple different UUIDs can be
+//! generated for the same task invocation.
+//!
+
+#[cfg(test)]
+#[path = "uuid_func_test.rs"]
+mod uuid_func_test;
+
+use crate::error::CargoMakeError;
+use uuid::Uuid;
+
+pub(crate) fn invoke(function_args: &[String]) -> Result<Vec<String>, CargoMakeError> {
+    if function_args.len() > 1 {
+        return Err(CargoMakeError::Arity(
+            "uuid expects up to 1 argument (optional label)",
+        ));
+    }
+
+    Ok(vec![Uuid::new_v4().to_string()])
+}
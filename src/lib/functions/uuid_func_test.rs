@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+#[should_panic]
+fn uuid_invoke_invalid_too_many_args() {
+    invoke(&["1".to_string(), "2".to_string()]).unwrap();
+}
+
+#[test]
+fn uuid_invoke_no_args() {
+    let output = invoke(&[]).unwrap();
+
+    assert_eq!(output.len(), 1);
+    let uuid = Uuid::parse_str(&output[0]).unwrap();
+    assert_eq!(uuid.get_version_num(), 4);
+    assert_eq!(output[0], uuid.hyphenated().to_string());
+}
+
+#[test]
+fn uuid_invoke_with_label() {
+    let output = invoke(&["build".to_string()]).unwrap();
+
+    assert_eq!(output.len(), 1);
+    assert!(Uuid::parse_str(&output[0]).is_ok());
+}
+
+#[test]
+fn uuid_invoke_unique() {
+    let first = invoke(&[]).unwrap();
+    let second = invoke(&[]).unwrap();
+
+    assert_ne!(first, second);
+}
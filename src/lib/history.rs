@@ -0,0 +1,214 @@
+//! # history
+//!
+//! Records the task executions (name, duration, status, profile and git commit) in the local
+//! task history of the project, which is used by the stats command.
+//!
+
+#[cfg(test)]
+#[path = "history_test.rs"]
+mod history_test;
+
+use crate::dry_run;
+use crate::profile;
+use crate::storage;
+use crate::types::Config;
+use fsio::file::{append_text_file, read_text_file};
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static HISTORY_DIRECTORY: &str = "history";
+/// The minimal amount of outcome changes with identical inputs for a task to be flaky
+static MIN_OUTCOME_CHANGES: usize = 2;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Holds a single task execution
+pub(crate) struct HistoryEntry {
+    /// The task name
+    pub(crate) task: String,
+    /// The execution start time (seconds since epoch)
+    pub(crate) timestamp: u64,
+    /// The execution duration in milliseconds
+    pub(crate) duration: u128,
+    /// True if the task finished successfully
+    pub(crate) success: bool,
+    /// The active profile
+    pub(crate) profile: String,
+    /// The git commit hash (if available)
+    pub(crate) git_sha: Option<String>,
+}
+
+pub(crate) fn is_enabled(config: &Config) -> bool {
+    config.config.task_history.unwrap_or(false) && !dry_run::is_enabled()
+}
+
+fn get_history_directory() -> Option<PathBuf> {
+    storage::get_storage_directory(dirs_next::data_local_dir(), HISTORY_DIRECTORY, false)
+        .map(|directory| directory.join(HISTORY_DIRECTORY))
+}
+
+/// Returns the project key, based on the workspace root directory so all workspace members
+/// share the same history
+fn get_project_key() -> String {
+    let directory = match env::var("CARGO_MAKE_WORKSPACE_WORKING_DIRECTORY") {
+        Ok(directory) if !directory.is_empty() => directory,
+        _ => env::current_dir()
+            .map(|directory| directory.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+
+    get_directory_key(&directory)
+}
+
+/// Returns the storage key (file name without extension) of the provided directory
+pub(crate) fn get_directory_key(directory: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(directory.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+fn get_history_file_in_directory(directory: &Path) -> PathBuf {
+    directory.join(format!("{}.jsonl", get_project_key()))
+}
+
+/// Returns the history file of the current project
+pub(crate) fn get_history_file() -> Option<PathBuf> {
+    get_history_directory().map(|directory| get_history_file_in_directory(&directory))
+}
+
+fn create_entry(task: &str, start_time: SystemTime, success: bool) -> HistoryEntry {
+    let timestamp = start_time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    let duration = start_time
+        .elapsed()
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or(0);
+
+    HistoryEntry {
+        task: task.to_string(),
+        timestamp,
+        duration,
+        success,
+        profile: profile::get(),
+        git_sha: env::var("CARGO_MAKE_GIT_SHA")
+            .ok()
+            .filter(|value| !value.is_empty()),
+    }
+}
+
+fn append_entry(file: &PathBuf, entry: &HistoryEntry) {
+    let line = match serde_json::to_string(entry) {
+        Ok(line) => line,
+        Err(_) => return,
+    };
+
+    if let Err(error) = append_text_file(file, &format!("{}\n", line)) {
+        debug!("Unable to write task history file: {:?} {:?}", file, error);
+    }
+}
+
+/// Appends the task execution to the project task history (if enabled)
+pub(crate) fn record(config: &Config, task: &str, start_time: SystemTime, success: bool) {
+    if !is_enabled(config) {
+        return;
+    }
+
+    if let Some(file) = get_history_file() {
+        append_entry(&file, &create_entry(task, start_time, success));
+    }
+}
+
+/// Loads all history entries from the provided file (invalid lines are skipped)
+pub(crate) fn load(file: &PathBuf) -> Vec<HistoryEntry> {
+    let text = match read_text_file(file) {
+        Ok(text) => text,
+        Err(_) => return vec![],
+    };
+
+    text.lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds a task classified as flaky
+pub(crate) struct FlakyTask {
+    /// The task name
+    pub(crate) name: String,
+    /// The amount of runs with identical inputs where the task outcome alternated
+    pub(crate) runs: usize,
+    /// The amount of failed runs out of those runs
+    pub(crate) failures: usize,
+}
+
+/// Returns the tasks which both failed and succeeded with identical inputs (same git commit
+/// and profile), where the outcome alternated at least twice (for example pass, fail, pass).<br>
+/// Entries without a git commit are ignored since their inputs are unknown.
+pub(crate) fn find_flaky_tasks(entries: &[HistoryEntry]) -> Vec<FlakyTask> {
+    let mut groups: IndexMap<(&str, &str, &str), Vec<bool>> = IndexMap::new();
+    for entry in entries {
+        if let Some(ref git_sha) = entry.git_sha {
+            groups
+                .entry((&entry.task, git_sha, &entry.profile))
+                .or_default()
+                .push(entry.success);
+        }
+    }
+
+    let mut flaky_tasks: Vec<FlakyTask> = vec![];
+    for ((task, _, _), outcomes) in groups {
+        let changes = outcomes
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count();
+        if changes < MIN_OUTCOME_CHANGES {
+            continue;
+        }
+
+        let failures = outcomes.iter().filter(|success| !**success).count();
+        match flaky_tasks
+            .iter_mut()
+            .find(|flaky_task| flaky_task.name == task)
+        {
+            Some(flaky_task) => {
+                flaky_task.runs += outcomes.len();
+                flaky_task.failures += failures;
+            }
+            None => flaky_tasks.push(FlakyTask {
+                name: task.to_string(),
+                runs: outcomes.len(),
+                failures,
+            }),
+        }
+    }
+
+    flaky_tasks
+}
+
+/// Returns the flaky tasks of the project task history (if enabled)
+pub(crate) fn load_flaky_tasks(config: &Config) -> Vec<FlakyTask> {
+    if !is_enabled(config) {
+        return vec![];
+    }
+
+    match get_history_file() {
+        Some(file) => find_flaky_tasks(&load(&file)),
+        None => vec![],
+    }
+}
+
+/// Prints a warning for each flaky task which was invoked in the current flow
+pub(crate) fn print_flaky_summary(flaky_tasks: &[FlakyTask], invoked_tasks: &[String]) {
+    for flaky_task in flaky_tasks {
+        if invoked_tasks.contains(&flaky_task.name) {
+            warn!(
+                "Task: {} is flaky, it failed {} out of {} runs with identical inputs.",
+                &flaky_task.name, flaky_task.failures, flaky_task.runs
+            );
+        }
+    }
+}
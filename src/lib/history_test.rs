@@ -0,0 +1,223 @@
+use super::*;
+use crate::test;
+use crate::types::ConfigSection;
+use fsio::file::write_text_file;
+use indexmap::IndexMap;
+use std::time::Duration;
+
+fn create_config(task_history: Option<bool>) -> Config {
+    let mut config_section = ConfigSection::new();
+    config_section.task_history = task_history;
+
+    Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+fn create_test_entry(task: &str, duration: u128, success: bool) -> HistoryEntry {
+    HistoryEntry {
+        task: task.to_string(),
+        timestamp: 1000,
+        duration,
+        success,
+        profile: "development".to_string(),
+        git_sha: Some("abc".to_string()),
+    }
+}
+
+#[test]
+fn is_enabled_not_defined() {
+    assert!(!is_enabled(&create_config(None)));
+}
+
+#[test]
+fn is_enabled_false() {
+    assert!(!is_enabled(&create_config(Some(false))));
+}
+
+#[test]
+fn is_enabled_true() {
+    assert!(is_enabled(&create_config(Some(true))));
+}
+
+#[test]
+fn get_history_file_in_directory_jsonl() {
+    let file = get_history_file_in_directory(Path::new("history"));
+
+    assert!(file.starts_with("history"));
+    assert_eq!(file.extension().unwrap(), "jsonl");
+}
+
+#[test]
+fn create_entry_values() {
+    let start_time = SystemTime::now() - Duration::from_millis(50);
+
+    let entry = create_entry("build", start_time, false);
+
+    assert_eq!(entry.task, "build");
+    assert!(!entry.success);
+    assert!(entry.duration >= 50);
+    assert!(entry.timestamp > 0);
+    assert_eq!(entry.profile, profile::get());
+}
+
+#[test]
+fn load_file_not_found() {
+    let directory = test::get_temp_test_directory("history_load_file_not_found");
+
+    let entries = load(&directory.join("missing.jsonl"));
+
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn append_entry_and_load() {
+    let directory = test::get_temp_test_directory("history_append_entry_and_load");
+    let file = directory.join("history.jsonl");
+
+    let first = create_test_entry("build", 100, true);
+    let second = create_test_entry("test", 200, false);
+    append_entry(&file, &first);
+    append_entry(&file, &second);
+
+    let entries = load(&file);
+
+    assert_eq!(entries, vec![first, second]);
+}
+
+#[test]
+fn load_invalid_lines_skipped() {
+    let directory = test::get_temp_test_directory("history_load_invalid_lines_skipped");
+    let file = directory.join("history.jsonl");
+    write_text_file(
+        &file,
+        r#"{"task":"build","timestamp":1000,"duration":100,"success":true,"profile":"development","git_sha":null}
+not json
+{"task":"test"}
+"#,
+    )
+    .unwrap();
+
+    let entries = load(&file);
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].task, "build");
+    assert!(entries[0].git_sha.is_none());
+}
+
+fn create_flaky_test_entries(
+    task: &str,
+    git_sha: Option<&str>,
+    outcomes: &[bool],
+) -> Vec<HistoryEntry> {
+    outcomes
+        .iter()
+        .map(|success| {
+            let mut entry = create_test_entry(task, 100, *success);
+            entry.git_sha = git_sha.map(|value| value.to_string());
+            entry
+        })
+        .collect()
+}
+
+#[test]
+fn find_flaky_tasks_empty() {
+    assert!(find_flaky_tasks(&[]).is_empty());
+}
+
+#[test]
+fn find_flaky_tasks_single_outcome_change() {
+    let entries = create_flaky_test_entries("test", Some("abc"), &[true, true, false, false]);
+
+    assert!(find_flaky_tasks(&entries).is_empty());
+}
+
+#[test]
+fn find_flaky_tasks_alternating() {
+    let entries = create_flaky_test_entries("test", Some("abc"), &[true, false, true, true]);
+
+    let flaky_tasks = find_flaky_tasks(&entries);
+
+    assert_eq!(
+        flaky_tasks,
+        vec![FlakyTask {
+            name: "test".to_string(),
+            runs: 4,
+            failures: 1,
+        }]
+    );
+}
+
+#[test]
+fn find_flaky_tasks_different_commits() {
+    let mut entries = create_flaky_test_entries("test", Some("abc"), &[true, false]);
+    entries.extend(create_flaky_test_entries("test", Some("def"), &[true]));
+
+    assert!(find_flaky_tasks(&entries).is_empty());
+}
+
+#[test]
+fn find_flaky_tasks_different_profiles() {
+    let mut entries = create_flaky_test_entries("test", Some("abc"), &[true, false]);
+    let mut entry = create_test_entry("test", 100, true);
+    entry.profile = "ci".to_string();
+    entries.push(entry);
+
+    assert!(find_flaky_tasks(&entries).is_empty());
+}
+
+#[test]
+fn find_flaky_tasks_no_commit_ignored() {
+    let entries = create_flaky_test_entries("test", None, &[true, false, true]);
+
+    assert!(find_flaky_tasks(&entries).is_empty());
+}
+
+#[test]
+fn find_flaky_tasks_multiple_commits_merged() {
+    let mut entries = create_flaky_test_entries("test", Some("abc"), &[false, true, false]);
+    entries.extend(create_flaky_test_entries(
+        "build",
+        Some("abc"),
+        &[true, false, true],
+    ));
+    entries.extend(create_flaky_test_entries(
+        "test",
+        Some("def"),
+        &[true, false, true],
+    ));
+
+    let flaky_tasks = find_flaky_tasks(&entries);
+
+    assert_eq!(
+        flaky_tasks,
+        vec![
+            FlakyTask {
+                name: "test".to_string(),
+                runs: 6,
+                failures: 3,
+            },
+            FlakyTask {
+                name: "build".to_string(),
+                runs: 3,
+                failures: 1,
+            }
+        ]
+    );
+}
+
+#[test]
+fn load_flaky_tasks_disabled() {
+    assert!(load_flaky_tasks(&create_config(None)).is_empty());
+}
@@ -7,10 +7,11 @@
 #[path = "cargo_plugin_installer_test.rs"]
 mod cargo_plugin_installer_test;
 
+use crate::binary_lookup;
 use crate::command;
 use crate::error::CargoMakeError;
 use crate::installer::crate_version_check;
-use crate::toolchain::wrap_command;
+use crate::toolchain::{get_channel, wrap_command};
 use crate::types::ToolchainSpecifier;
 use std::process::Command;
 use strip_ansi_escapes::strip_str;
@@ -60,10 +61,10 @@ fn is_crate_in_list_output(crate_name: &str, output: &str) -> bool {
     false
 }
 
-fn is_crate_installed(
+fn get_installed_commands(
     toolchain: &Option<ToolchainSpecifier>,
     crate_name: &str,
-) -> Result<bool, CargoMakeError> {
+) -> Result<Option<String>, CargoMakeError> {
     debug!("Getting list of installed cargo commands.");
 
     let mut command_struct = match toolchain {
@@ -84,18 +85,38 @@ fn is_crate_installed(
             let exit_code = command::get_exit_code(Ok(output.status), false);
             command::validate_exit_code(exit_code)?;
 
-            let stdout = strip_str(String::from_utf8_lossy(&output.stdout));
-            let crate_name_trimmed = crate_name.trim();
-            Ok(is_crate_in_list_output(&crate_name_trimmed, &stdout)
-                || is_crate_in_list_output_legacy(&crate_name_trimmed, &stdout))
+            Ok(Some(strip_str(String::from_utf8_lossy(&output.stdout))))
         }
         Err(error) => {
             error!(
                 "Unable to check if crate is installed: {} {:#?}",
                 crate_name, &error
             );
-            Ok(false)
+            Ok(None)
+        }
+    }
+}
+
+fn is_crate_installed(
+    toolchain: &Option<ToolchainSpecifier>,
+    crate_name: &str,
+) -> Result<bool, CargoMakeError> {
+    let toolchain_key = match toolchain {
+        Some(ref toolchain) => get_channel(toolchain),
+        None => "".to_string(),
+    };
+
+    let output = binary_lookup::get_cargo_commands(&toolchain_key, || {
+        get_installed_commands(toolchain, crate_name)
+    })?;
+
+    match output {
+        Some(stdout) => {
+            let crate_name_trimmed = crate_name.trim();
+            Ok(is_crate_in_list_output(&crate_name_trimmed, &stdout)
+                || is_crate_in_list_output_legacy(&crate_name_trimmed, &stdout))
         }
+        None => Ok(false),
     }
 }
 
@@ -186,6 +207,7 @@ pub(crate) fn install_crate(
     if run_installation {
         let install_args =
             get_install_crate_args(crate_name, force, args, &min_version, install_command);
+        binary_lookup::invalidate();
 
         match toolchain {
             Some(ref toolchain_string) => {
@@ -7,6 +7,7 @@
 #[path = "crate_installer_test.rs"]
 mod crate_installer_test;
 
+use crate::binary_lookup;
 use crate::command;
 use crate::error::CargoMakeError;
 use crate::installer::crate_version_check;
@@ -64,6 +65,7 @@ fn invoke_cargo_install(
         },
     };
 
+    binary_lookup::invalidate();
     command::run_command(&command_spec.command, &command_spec.args, validate)?;
 
     if remove_lock {
@@ -15,6 +15,7 @@ pub(crate) mod rustup_component_installer;
 #[path = "mod_test.rs"]
 mod mod_test;
 
+use crate::binary_lookup;
 use crate::error::CargoMakeError;
 use crate::scriptengine;
 use crate::types::{FlowInfo, FlowState, InstallCrate, Task};
@@ -60,6 +61,36 @@ fn get_first_command_arg(task_config: &Task) -> Option<String> {
     }
 }
 
+/// Returns a description of the installation done (if needed) before invoking the task
+pub(crate) fn get_install_description(task_config: &Task) -> Option<String> {
+    match task_config.install_crate {
+        Some(InstallCrate::Enabled(false)) => None,
+        Some(InstallCrate::Value(ref crate_name)) => Some(format!("crate {}", crate_name)),
+        Some(InstallCrate::CargoPluginInfo(ref install_info)) => {
+            match get_cargo_plugin_info_from_command(task_config) {
+                Some((_, crate_name)) => Some(format!("crate {}", crate_name)),
+                None => install_info
+                    .crate_name
+                    .as_ref()
+                    .map(|crate_name| format!("crate {}", crate_name)),
+            }
+        }
+        Some(InstallCrate::CrateInfo(ref install_info)) => Some(format!(
+            "crate {} (binary: {})",
+            install_info.crate_name, install_info.binary
+        )),
+        Some(InstallCrate::RustupComponentInfo(ref install_info)) => Some(format!(
+            "rustup component {}",
+            install_info.rustup_component_name
+        )),
+        Some(InstallCrate::Enabled(true)) | None => match task_config.install_script {
+            Some(_) => Some("install script".to_string()),
+            None => get_cargo_plugin_info_from_command(task_config)
+                .map(|(_, crate_name)| format!("crate {}", crate_name)),
+        },
+    }
+}
+
 pub(crate) fn install(
     task_config: &Task,
     flow_info: &FlowInfo,
@@ -173,7 +204,7 @@ pub(crate) fn install(
                     Some(flow_info),
                     Some(flow_state),
                 )?;
-                ()
+                binary_lookup::invalidate();
             }
             None => match get_cargo_plugin_info_from_command(&task_config) {
                 Some((cargo_command, crate_name)) => {
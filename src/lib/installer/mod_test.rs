@@ -58,6 +58,103 @@ fn get_cargo_plugin_info_from_command_valid() {
     assert_eq!(crate_name, "cargo-test");
 }
 
+#[test]
+fn get_install_description_none() {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+
+    let description = get_install_description(&task);
+
+    assert!(description.is_none());
+}
+
+#[test]
+fn get_install_description_disabled() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+    task.install_crate = Some(InstallCrate::Enabled(false));
+
+    let description = get_install_description(&task);
+
+    assert!(description.is_none());
+}
+
+#[test]
+fn get_install_description_cargo_plugin_from_command() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+
+    let description = get_install_description(&task);
+
+    assert_eq!(description.unwrap(), "crate cargo-audit");
+}
+
+#[test]
+fn get_install_description_crate_name() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+    task.install_crate = Some(InstallCrate::Value("cargo-audit".to_string()));
+
+    let description = get_install_description(&task);
+
+    assert_eq!(description.unwrap(), "crate cargo-audit");
+}
+
+#[test]
+fn get_install_description_crate_info() {
+    let mut task = Task::new();
+    task.command = Some("wasm-pack".to_string());
+    task.install_crate = Some(InstallCrate::CrateInfo(InstallCrateInfo {
+        crate_name: "wasm-pack".to_string(),
+        binary: "wasm-pack".to_string(),
+        test_arg: TestArg {
+            inner: vec!["--version".to_string()],
+        },
+        rustup_component_name: None,
+        min_version: None,
+        version: None,
+        install_command: None,
+        force: None,
+    }));
+
+    let description = get_install_description(&task);
+
+    assert_eq!(description.unwrap(), "crate wasm-pack (binary: wasm-pack)");
+}
+
+#[test]
+fn get_install_description_rustup_component() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["fmt".to_string()]);
+    task.install_crate = Some(InstallCrate::RustupComponentInfo(
+        InstallRustupComponentInfo {
+            rustup_component_name: "rustfmt".to_string(),
+            binary: None,
+            test_arg: None,
+        },
+    ));
+
+    let description = get_install_description(&task);
+
+    assert_eq!(description.unwrap(), "rustup component rustfmt");
+}
+
+#[test]
+fn get_install_description_install_script() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["audit".to_string()]);
+    task.install_script = Some(ScriptValue::SingleLine("exit 0".to_string()));
+
+    let description = get_install_description(&task);
+
+    assert_eq!(description.unwrap(), "install script");
+}
+
 #[test]
 fn install_empty() {
     let task = Task::new();
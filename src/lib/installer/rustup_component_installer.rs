@@ -7,6 +7,7 @@
 #[path = "rustup_component_installer_test.rs"]
 mod rustup_component_installer_test;
 
+use crate::binary_lookup;
 use crate::command;
 use crate::toolchain::{get_channel, wrap_command};
 use crate::types::{InstallRustupComponentInfo, ToolchainSpecifier};
@@ -16,6 +17,21 @@ pub(crate) fn is_installed(
     toolchain: &Option<ToolchainSpecifier>,
     binary: &str,
     test_args: &[String],
+) -> bool {
+    let toolchain_key = match toolchain {
+        Some(ref toolchain) => get_channel(toolchain),
+        None => "".to_string(),
+    };
+
+    binary_lookup::is_installed(&toolchain_key, binary, test_args, || {
+        check_installed(toolchain, binary, test_args)
+    })
+}
+
+fn check_installed(
+    toolchain: &Option<ToolchainSpecifier>,
+    binary: &str,
+    test_args: &[String],
 ) -> bool {
     let mut command_struct = match toolchain {
         Some(ref toolchain_string) => {
@@ -74,6 +90,7 @@ pub(crate) fn invoke_rustup_install(
     };
 
     let result = command_spec.arg(&info.rustup_component_name).output();
+    binary_lookup::invalidate();
 
     match result {
         Ok(output) => {
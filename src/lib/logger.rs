@@ -7,12 +7,19 @@
 #[path = "logger_test.rs"]
 mod logger_test;
 
+use crate::ci_output;
+use crate::dashboard;
+use crate::diagnostics;
+use crate::exit_codes;
+use crate::output;
 use crate::recursion_level;
-use crate::types::FlowInfo;
-use colored::{ColoredString, Colorize};
+use crate::types::{ColorsConfig, FlowInfo};
+use colored::{Color, ColoredString, Colorize};
 use log::{Level, LevelFilter};
-use std::io::stdout;
+use once_cell::sync::Lazy;
+use std::cell::Cell;
 use std::process::exit;
+use std::sync::RwLock;
 
 #[derive(Debug, PartialEq)]
 /// The log levels
@@ -23,6 +30,40 @@ pub(crate) enum LogLevel {
     OFF,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+/// The colors used for the console output
+struct ColorTheme {
+    verbose: Color,
+    info: Color,
+    warn: Color,
+    error: Color,
+    task_name: Option<Color>,
+}
+
+impl Default for ColorTheme {
+    fn default() -> Self {
+        ColorTheme {
+            verbose: Color::Cyan,
+            info: Color::Green,
+            warn: Color::Yellow,
+            error: Color::Red,
+            task_name: None,
+        }
+    }
+}
+
+static COLOR_THEME: Lazy<RwLock<ColorTheme>> = Lazy::new(|| RwLock::new(ColorTheme::default()));
+
+/// The global log level (as defined by the cli arguments)
+static LEVEL_FILTER: Lazy<RwLock<LevelFilter>> = Lazy::new(|| RwLock::new(LevelFilter::Info));
+
+thread_local! {
+    /// The log level of the task running in the current thread (overrides the global level)
+    static TASK_LEVEL_FILTER: Cell<Option<LevelFilter>> = const { Cell::new(None) };
+    /// False while running functions which return their errors instead of exiting the process
+    static EXIT_ON_ERROR: Cell<bool> = const { Cell::new(true) };
+}
+
 /// The logger options used to initialize the logger
 pub struct LoggerOptions {
     /// Name to prefix each log message
@@ -42,8 +83,85 @@ pub(crate) fn get_level(level_name: &str) -> LogLevel {
     }
 }
 
+fn get_level_filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::VERBOSE => LevelFilter::Debug,
+        LogLevel::INFO => LevelFilter::Info,
+        LogLevel::ERROR => LevelFilter::Error,
+        LogLevel::OFF => LevelFilter::Off,
+    }
+}
+
+/// Returns the log level of the task log_level attribute value, which supports the cli log
+/// level names and the quiet/silent shorthands
+pub(crate) fn get_task_level(level_name: &str) -> Option<LogLevel> {
+    match level_name {
+        "verbose" => Some(LogLevel::VERBOSE),
+        "info" => Some(LogLevel::INFO),
+        "quiet" | "error" => Some(LogLevel::ERROR),
+        "silent" | "off" => Some(LogLevel::OFF),
+        _ => None,
+    }
+}
+
+/// Returns the log level used by the current thread
+fn get_current_level_filter() -> LevelFilter {
+    match TASK_LEVEL_FILTER.with(|filter| filter.get()) {
+        Some(filter) => filter,
+        None => match LEVEL_FILTER.read() {
+            Ok(filter) => *filter,
+            Err(_) => LevelFilter::Info,
+        },
+    }
+}
+
+/// Runs the provided function without exiting the process on error log messages, so
+/// long running processes (such as the daemon) can handle the returned errors
+pub(crate) fn run_without_error_exit<T, F: FnOnce() -> T>(function: F) -> T {
+    let previous = EXIT_ON_ERROR.with(|current| current.replace(false));
+
+    let result = function();
+
+    EXIT_ON_ERROR.with(|current| current.set(previous));
+    result
+}
+
+/// Runs the provided function with the task log level (if defined) used by the current thread
+/// and returns the function result
+pub(crate) fn run_with_task_level<T, F: FnOnce() -> T>(
+    level_name: &Option<String>,
+    function: F,
+) -> T {
+    let filter = match level_name {
+        Some(ref level_name) => match get_task_level(level_name) {
+            Some(level) => Some(get_level_filter(level)),
+            None => {
+                warn!("Unsupported task log level: {}", level_name);
+                None
+            }
+        },
+        None => None,
+    };
+
+    match filter {
+        Some(filter) => {
+            let previous = TASK_LEVEL_FILTER.with(|current| current.replace(Some(filter)));
+
+            let result = function();
+
+            TASK_LEVEL_FILTER.with(|current| current.set(previous));
+            result
+        }
+        None => function(),
+    }
+}
+
 /// Returns the current logger level name
 pub(crate) fn get_log_level() -> String {
+    if let Some(filter) = TASK_LEVEL_FILTER.with(|filter| filter.get()) {
+        return get_name_for_filter(&filter);
+    }
+
     if envmnt::is_equal("CARGO_MAKE_LOG_LEVEL", "off") {
         "off"
     } else if log_enabled!(Level::Debug) {
@@ -77,6 +195,78 @@ fn get_name_for_level(level: &Level) -> String {
     }
 }
 
+fn parse_color(value: &Option<String>, default_color: Color) -> Color {
+    match value {
+        Some(ref name) => match name.parse::<Color>() {
+            Ok(color) => color,
+            Err(_) => {
+                warn!("Unsupported color: {}", name);
+                default_color
+            }
+        },
+        None => default_color,
+    }
+}
+
+fn create_color_theme(colors: &ColorsConfig) -> ColorTheme {
+    let default_theme = ColorTheme::default();
+
+    ColorTheme {
+        verbose: parse_color(&colors.verbose, default_theme.verbose),
+        info: parse_color(&colors.info, default_theme.info),
+        warn: parse_color(&colors.warn, default_theme.warn),
+        error: parse_color(&colors.error, default_theme.error),
+        task_name: colors
+            .task_name
+            .as_ref()
+            .map(|_| parse_color(&colors.task_name, Color::White)),
+    }
+}
+
+fn get_color_theme() -> ColorTheme {
+    match COLOR_THEME.read() {
+        Ok(theme) => theme.clone(),
+        Err(_) => ColorTheme::default(),
+    }
+}
+
+/// Sets the console output colors (as defined in the [config.colors] section)
+pub(crate) fn set_colors(colors: &Option<ColorsConfig>) {
+    if let Some(ref colors_config) = colors {
+        let theme = create_color_theme(colors_config);
+
+        if let Ok(mut current_theme) = COLOR_THEME.write() {
+            *current_theme = theme;
+        }
+    }
+}
+
+/// Returns true if colors are disabled via the NO_COLOR env var (see https://no-color.org)
+pub(crate) fn is_color_disabled_by_env() -> bool {
+    !envmnt::get_or("NO_COLOR", "").is_empty()
+}
+
+/// Returns true if colors are forced via the CLICOLOR_FORCE env var
+pub(crate) fn is_color_forced_by_env() -> bool {
+    envmnt::exists("CLICOLOR_FORCE") && !envmnt::is_equal("CLICOLOR_FORCE", "0")
+}
+
+fn get_formatted_task_name(name: &str, use_color: bool, theme: &ColorTheme) -> String {
+    match theme.task_name {
+        Some(color) if use_color => name.color(color).to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Returns the task name colored based on the current color theme
+pub(crate) fn format_task_name(name: &str) -> String {
+    get_formatted_task_name(
+        name,
+        !envmnt::is("CARGO_MAKE_DISABLE_COLOR"),
+        &get_color_theme(),
+    )
+}
+
 fn get_formatted_name(name: &str, use_color: bool) -> ColoredString {
     if use_color {
         name.bold()
@@ -86,15 +276,23 @@ fn get_formatted_name(name: &str, use_color: bool) -> ColoredString {
 }
 
 fn get_formatted_log_level(level: &Level, use_color: bool) -> ColoredString {
+    get_formatted_log_level_for_theme(level, use_color, &get_color_theme())
+}
+
+fn get_formatted_log_level_for_theme(
+    level: &Level,
+    use_color: bool,
+    theme: &ColorTheme,
+) -> ColoredString {
     let mut level_name = get_name_for_level(&level);
     level_name = level_name.to_uppercase();
 
     if use_color {
         let fmt_value = match level {
-            Level::Debug => level_name.cyan(),
-            Level::Info => level_name.green(),
-            Level::Warn => level_name.yellow(),
-            Level::Error => level_name.red(),
+            Level::Debug => level_name.color(theme.verbose),
+            Level::Info => level_name.color(theme.info),
+            Level::Warn => level_name.color(theme.warn),
+            Level::Error => level_name.color(theme.error),
             _ => level_name.normal(),
         };
 
@@ -122,17 +320,16 @@ pub(crate) fn init(options: &LoggerOptions) {
 
     let level = get_level(level_name);
 
-    let log_level = match level {
-        LogLevel::VERBOSE => LevelFilter::Debug,
-        LogLevel::INFO => LevelFilter::Info,
-        LogLevel::ERROR => LevelFilter::Error,
-        LogLevel::OFF => LevelFilter::Off,
-    };
+    let log_level = get_level_filter(level);
     let level_name_value = get_name_for_filter(&log_level);
 
     envmnt::set("CARGO_MAKE_LOG_LEVEL", &level_name_value);
     envmnt::set_bool("CARGO_MAKE_DISABLE_COLOR", !color);
 
+    if color && is_color_forced_by_env() {
+        colored::control::set_override(true);
+    }
+
     let recursion_lvl = recursion_level::get();
     let recursion_level_log = if recursion_lvl == 0 {
         "".to_string()
@@ -145,31 +342,54 @@ pub(crate) fn init(options: &LoggerOptions) {
     let result = fern::Dispatch::new()
         .format(move |out, message, record| {
             let record_level = record.level();
+            let exit_on_error = EXIT_ON_ERROR.with(|current| current.get());
 
-            if cfg!(test) {
+            if cfg!(test) && exit_on_error {
                 if record_level == LevelFilter::Error {
                     panic!("test error flow: {}", message);
                 }
             }
 
+            let exit_process = record_level == Level::Error && exit_on_error;
+            if exit_process {
+                // the dashboard captures the output, so it is closed before the error is written
+                dashboard::abort();
+            }
+
             let record_level_fmt = get_formatted_log_level(&record_level, color);
 
             out.finish(format_args!(
-                "[{}]{} {} - {}",
-                &name_fmt, &recursion_level_log, &record_level_fmt, &message
+                "{}[{}]{} {} - {}",
+                output::get_line_prefix(),
+                &name_fmt,
+                &recursion_level_log,
+                &record_level_fmt,
+                &message
             ));
 
-            if record_level == Level::Error {
+            if exit_process {
+                ci_output::annotate_error(&message.to_string(), None, None);
+                diagnostics::report_error_log(&message.to_string());
+
                 warn!("Build Failed.");
 
-                exit(1);
+                exit(exit_codes::get_error_exit_code());
             }
         })
-        .level(log_level)
-        .chain(stdout())
+        // the level is filtered per thread as tasks may override it
+        .level(LevelFilter::Debug)
+        .filter(|metadata| metadata.level() <= get_current_level_filter())
+        .chain(fern::Output::call(|record| {
+            output::write_log_line(&record.args().to_string())
+        }))
         .apply();
 
-    if result.is_err() {
-        println!("Unable to setup logger.");
+    match result {
+        Ok(_) => {
+            if let Ok(mut filter) = LEVEL_FILTER.write() {
+                *filter = log_level;
+            }
+        }
+        Err(_) => println!("Unable to setup logger."),
     }
 }
@@ -30,6 +30,60 @@ fn get_level_other() {
     assert_eq!(level, LogLevel::INFO);
 }
 
+#[test]
+fn get_task_level_values() {
+    assert_eq!(get_task_level("verbose"), Some(LogLevel::VERBOSE));
+    assert_eq!(get_task_level("info"), Some(LogLevel::INFO));
+    assert_eq!(get_task_level("quiet"), Some(LogLevel::ERROR));
+    assert_eq!(get_task_level("error"), Some(LogLevel::ERROR));
+    assert_eq!(get_task_level("silent"), Some(LogLevel::OFF));
+    assert_eq!(get_task_level("off"), Some(LogLevel::OFF));
+    assert_eq!(get_task_level("test123"), None);
+}
+
+#[test]
+fn run_with_task_level_verbose() {
+    let level = run_with_task_level(&Some("verbose".to_string()), get_log_level);
+
+    assert_eq!(level, "verbose");
+    assert!(TASK_LEVEL_FILTER.with(|filter| filter.get()).is_none());
+}
+
+#[test]
+fn run_with_task_level_quiet() {
+    let level = run_with_task_level(&Some("quiet".to_string()), get_log_level);
+
+    assert_eq!(level, "error");
+}
+
+#[test]
+fn run_with_task_level_nested() {
+    let level = run_with_task_level(&Some("verbose".to_string()), || {
+        let nested_level = run_with_task_level(&Some("off".to_string()), get_log_level);
+        assert_eq!(nested_level, "off");
+
+        get_log_level()
+    });
+
+    assert_eq!(level, "verbose");
+}
+
+#[test]
+fn run_with_task_level_none() {
+    let level = run_with_task_level(&None, || TASK_LEVEL_FILTER.with(|filter| filter.get()));
+
+    assert!(level.is_none());
+}
+
+#[test]
+fn run_with_task_level_unsupported() {
+    let level = run_with_task_level(&Some("test123".to_string()), || {
+        TASK_LEVEL_FILTER.with(|filter| filter.get())
+    });
+
+    assert!(level.is_none());
+}
+
 #[test]
 fn get_name_for_level_verbose() {
     let level = get_name_for_level(&Level::Debug);
@@ -191,3 +245,112 @@ fn update_disable_color_env_var() {
 
     assert!(envmnt::is("CARGO_MAKE_DISABLE_COLOR"));
 }
+
+#[test]
+fn get_formatted_log_level_custom_theme() {
+    let theme = ColorTheme {
+        info: Color::BrightBlue,
+        ..ColorTheme::default()
+    };
+    let output = get_formatted_log_level_for_theme(&Level::Info, true, &theme);
+
+    assert_eq!("INFO".bright_blue().bold(), output);
+}
+
+#[test]
+fn create_color_theme_empty() {
+    let theme = create_color_theme(&ColorsConfig::default());
+
+    assert_eq!(theme, ColorTheme::default());
+}
+
+#[test]
+fn create_color_theme_all_values() {
+    let theme = create_color_theme(&ColorsConfig {
+        verbose: Some("white".to_string()),
+        info: Some("bright green".to_string()),
+        warn: Some("magenta".to_string()),
+        error: Some("bright red".to_string()),
+        task_name: Some("blue".to_string()),
+    });
+
+    assert_eq!(
+        theme,
+        ColorTheme {
+            verbose: Color::White,
+            info: Color::BrightGreen,
+            warn: Color::Magenta,
+            error: Color::BrightRed,
+            task_name: Some(Color::Blue),
+        }
+    );
+}
+
+#[test]
+fn create_color_theme_invalid_value() {
+    let theme = create_color_theme(&ColorsConfig {
+        info: Some("not a color".to_string()),
+        ..ColorsConfig::default()
+    });
+
+    assert_eq!(theme.info, Color::Green);
+}
+
+#[test]
+fn get_formatted_task_name_no_theme_color() {
+    let output = get_formatted_task_name("build", true, &ColorTheme::default());
+
+    assert_eq!(output, "build");
+}
+
+#[test]
+fn get_formatted_task_name_with_color() {
+    let theme = ColorTheme {
+        task_name: Some(Color::Blue),
+        ..ColorTheme::default()
+    };
+    let output = get_formatted_task_name("build", true, &theme);
+
+    assert_eq!(output, "build".blue().to_string());
+}
+
+#[test]
+fn get_formatted_task_name_color_disabled() {
+    let theme = ColorTheme {
+        task_name: Some(Color::Blue),
+        ..ColorTheme::default()
+    };
+    let output = get_formatted_task_name("build", false, &theme);
+
+    assert_eq!(output, "build");
+}
+
+#[test]
+#[ignore]
+fn is_color_disabled_by_env_values() {
+    envmnt::remove("NO_COLOR");
+    assert!(!is_color_disabled_by_env());
+
+    envmnt::set("NO_COLOR", "");
+    assert!(!is_color_disabled_by_env());
+
+    envmnt::set("NO_COLOR", "1");
+    assert!(is_color_disabled_by_env());
+
+    envmnt::remove("NO_COLOR");
+}
+
+#[test]
+#[ignore]
+fn is_color_forced_by_env_values() {
+    envmnt::remove("CLICOLOR_FORCE");
+    assert!(!is_color_forced_by_env());
+
+    envmnt::set("CLICOLOR_FORCE", "0");
+    assert!(!is_color_forced_by_env());
+
+    envmnt::set("CLICOLOR_FORCE", "1");
+    assert!(is_color_forced_by_env());
+
+    envmnt::remove("CLICOLOR_FORCE");
+}
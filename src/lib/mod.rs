@@ -51,30 +51,57 @@ mod test;
 // make types public for docs
 pub mod types;
 
+mod bench;
+mod binary_lookup;
 mod cache;
+mod ci_output;
 pub mod cli;
 pub mod cli_commands;
+mod cli_overrides;
 pub mod cli_parser;
 mod command;
 pub mod completion;
 mod condition;
 pub mod config;
+mod container;
+mod daemon;
+mod dashboard;
 mod descriptor;
+mod diagnostics;
+mod dry_run;
 mod environment;
 pub mod error;
 mod execution_plan;
+mod exit_codes;
 mod functions;
+mod history;
 mod installer;
 mod io;
 mod legacy;
 pub mod logger;
+mod nix;
+mod notification;
+mod output;
+mod perf;
 mod plugin;
+mod policy;
 mod profile;
+mod progress;
+mod prompt;
 mod proxy_task;
 mod recursion_level;
+mod run_lock;
 pub mod runner;
 mod scriptengine;
+mod status_summary;
+mod step_outputs;
 mod storage;
+mod task_args;
+mod task_inputs;
+mod task_outputs;
+mod task_tags;
+mod task_throttle;
+mod tee_output;
 mod time_summary;
 mod toolchain;
 pub mod validator;
@@ -0,0 +1,45 @@
+//! # nix
+//!
+//! Wraps task commands and scripts with nix develop, so they run inside the environment
+//! declared by the project flake.
+//!
+
+#[cfg(test)]
+#[path = "nix_test.rs"]
+mod nix_test;
+
+use crate::types::{CommandSpec, NixShellValue, Task};
+
+/// Returns the flake reference used to run the task (None if nix is not used)
+pub(crate) fn get_flake(task: &Task) -> Option<String> {
+    match task.nix_shell {
+        Some(NixShellValue::Enabled(true)) => Some(".".to_string()),
+        Some(NixShellValue::Flake(ref flake)) if !flake.trim().is_empty() => {
+            Some(flake.trim().to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the nix develop arguments which run the provided command in the flake environment
+fn get_develop_args(flake: &str, command: &str) -> Vec<String> {
+    vec![
+        "develop".to_string(),
+        flake.to_string(),
+        "--command".to_string(),
+        command.to_string(),
+    ]
+}
+
+/// Wraps the command (and its args) with nix develop
+pub(crate) fn wrap_command(flake: &str, command_spec: CommandSpec) -> CommandSpec {
+    let mut args = get_develop_args(flake, &command_spec.command);
+    if let Some(command_args) = command_spec.args {
+        args.extend(command_args);
+    }
+
+    CommandSpec {
+        command: "nix".to_string(),
+        args: Some(args),
+    }
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn get_flake_not_defined() {
+    assert!(get_flake(&Task::new()).is_none());
+}
+
+#[test]
+fn get_flake_disabled() {
+    let mut task = Task::new();
+    task.nix_shell = Some(NixShellValue::Enabled(false));
+
+    assert!(get_flake(&task).is_none());
+}
+
+#[test]
+fn get_flake_enabled() {
+    let mut task = Task::new();
+    task.nix_shell = Some(NixShellValue::Enabled(true));
+
+    assert_eq!(get_flake(&task).unwrap(), ".");
+}
+
+#[test]
+fn get_flake_reference() {
+    let mut task = Task::new();
+    task.nix_shell = Some(NixShellValue::Flake(".#ci".to_string()));
+
+    assert_eq!(get_flake(&task).unwrap(), ".#ci");
+}
+
+#[test]
+fn wrap_command_with_args() {
+    let command_spec = wrap_command(
+        ".#ci",
+        CommandSpec {
+            command: "cargo".to_string(),
+            args: Some(vec!["build".to_string()]),
+        },
+    );
+
+    assert_eq!(command_spec.command, "nix");
+    assert_eq!(
+        command_spec.args.unwrap(),
+        vec!["develop", ".#ci", "--command", "cargo", "build"]
+    );
+}
+
+#[test]
+fn wrap_command_no_args() {
+    let command_spec = wrap_command(
+        ".",
+        CommandSpec {
+            command: "cargo".to_string(),
+            args: None,
+        },
+    );
+
+    assert_eq!(
+        command_spec.args.unwrap(),
+        vec!["develop", ".", "--command", "cargo"]
+    );
+}
@@ -0,0 +1,264 @@
+//! # notification
+//!
+//! Notifies the flow owners once the flow is done (desktop notification and webhook posts),
+//! based on the makefile notifications section.
+//!
+
+#[cfg(test)]
+#[path = "notification_test.rs"]
+mod notification_test;
+
+use crate::dry_run;
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::profile;
+use crate::recursion_level;
+use crate::types::{
+    Config, NotificationTrigger, NotificationsConfig, WebhookConfig, WebhookFormat,
+};
+use serde_json::{json, Value};
+use std::env;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+/// The maximum time to wait for each webhook response
+static WEBHOOK_TIMEOUT_SECONDS: &str = "10";
+
+#[derive(Debug, Clone, PartialEq)]
+/// Holds the flow result which is notified
+struct FlowResult {
+    /// The project name (if available)
+    project: Option<String>,
+    /// The requested task
+    task: String,
+    success: bool,
+    duration: Duration,
+    /// The error summary (if the flow failed)
+    error: Option<String>,
+}
+
+pub(crate) fn merge_notifications_config(
+    base: Option<NotificationsConfig>,
+    extended: Option<NotificationsConfig>,
+) -> Option<NotificationsConfig> {
+    match base {
+        Some(mut base_notifications) => match extended {
+            Some(ref extended_notifications) => {
+                base_notifications.extend(extended_notifications);
+                Some(base_notifications)
+            }
+            None => Some(base_notifications),
+        },
+        None => extended,
+    }
+}
+
+fn should_notify(trigger: Option<NotificationTrigger>, success: bool) -> bool {
+    match trigger.unwrap_or(NotificationTrigger::Always) {
+        NotificationTrigger::Always => true,
+        NotificationTrigger::Success => success,
+        NotificationTrigger::Failure => !success,
+    }
+}
+
+/// Returns true if the flow ran long enough to be notified
+fn is_min_duration_reached(config: &NotificationsConfig, duration: Duration) -> bool {
+    match config.min_duration {
+        Some(min_duration) => duration.as_secs() >= min_duration,
+        None => true,
+    }
+}
+
+fn get_webhooks(config: &NotificationsConfig, success: bool) -> Vec<WebhookConfig> {
+    config
+        .webhooks
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|webhook| should_notify(webhook.on.or(config.on), success))
+        .collect()
+}
+
+fn get_project_name() -> Option<String> {
+    let project_name = envmnt::get_or(
+        "CARGO_MAKE_PROJECT_NAME",
+        &envmnt::get_or("CARGO_MAKE_CRATE_NAME", ""),
+    );
+
+    if project_name.is_empty() {
+        env::current_dir().ok().and_then(|directory| {
+            directory
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        })
+    } else {
+        Some(project_name)
+    }
+}
+
+fn get_status(result: &FlowResult) -> &'static str {
+    if result.success {
+        "succeeded"
+    } else {
+        "failed"
+    }
+}
+
+fn create_title(result: &FlowResult) -> String {
+    let status = get_status(result);
+
+    match result.project {
+        Some(ref project) => format!("cargo-make: {} {}", project, status),
+        None => format!("cargo-make: flow {}", status),
+    }
+}
+
+fn create_message(result: &FlowResult) -> String {
+    let mut message = format!(
+        "Task: {} {} in {:.2} seconds.",
+        &result.task,
+        get_status(result),
+        result.duration.as_secs_f64()
+    );
+
+    if let Some(ref error) = result.error {
+        message.push('\n');
+        message.push_str(error);
+    }
+
+    message
+}
+
+fn create_payload(format: WebhookFormat, result: &FlowResult) -> Value {
+    match format {
+        WebhookFormat::Slack => json!({
+            "text": format!("*{}*\n{}", create_title(result), create_message(result))
+        }),
+        WebhookFormat::Discord => json!({
+            "content": format!("**{}**\n{}", create_title(result), create_message(result))
+        }),
+        WebhookFormat::Json => json!({
+            "project": result.project,
+            "task": result.task,
+            "success": result.success,
+            "duration": result.duration.as_millis() as u64,
+            "error": result.error,
+            "profile": profile::get(),
+        }),
+    }
+}
+
+fn run_tool(command: &str, args: &[&str]) -> Result<(), CargoMakeError> {
+    debug!("Execute Command: {} {:?}", command, args);
+
+    let output = Command::new(command).args(args).output().map_err(|error| {
+        CargoMakeError::NotificationFailed(format!("unable to run: {}, {}", command, error))
+    })?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(CargoMakeError::NotificationFailed(format!(
+            "{} failed, {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+fn post_webhook(url: &str, payload: &Value) -> Result<(), CargoMakeError> {
+    let payload = payload.to_string();
+
+    run_tool(
+        "curl",
+        &[
+            "-sSf",
+            "--max-time",
+            WEBHOOK_TIMEOUT_SECONDS,
+            "-X",
+            "POST",
+            "-H",
+            "Content-Type: application/json",
+            "-d",
+            &payload,
+            url,
+        ],
+    )
+}
+
+fn show_desktop_notification(title: &str, message: &str) -> Result<(), CargoMakeError> {
+    if cfg!(windows) {
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+            $icon = New-Object System.Windows.Forms.NotifyIcon; \
+            $icon.Icon = [System.Drawing.SystemIcons]::Information; \
+            $icon.Visible = $true; \
+            $icon.ShowBalloonTip(10000, '{}', '{}', 'None'); \
+            Start-Sleep -Seconds 5; \
+            $icon.Dispose()",
+            title.replace('\'', "''"),
+            message.replace('\'', "''")
+        );
+
+        run_tool("powershell", &["-NoProfile", "-Command", &script])
+    } else if cfg!(target_os = "macos") {
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+        let script = format!(
+            "display notification \"{}\" with title \"{}\"",
+            escape(message),
+            escape(title)
+        );
+
+        run_tool("osascript", &["-e", &script])
+    } else {
+        run_tool("notify-send", &[title, message])
+    }
+}
+
+fn send(config: &NotificationsConfig, result: &FlowResult) {
+    if !is_min_duration_reached(config, result.duration) {
+        debug!("Flow duration is below the notifications min duration.");
+        return;
+    }
+
+    if config.desktop.unwrap_or(false) && should_notify(config.on, result.success) {
+        if let Err(error) =
+            show_desktop_notification(&create_title(result), &create_message(result))
+        {
+            warn!("{}", error);
+        }
+    }
+
+    for webhook in get_webhooks(config, result.success) {
+        // urls usually hold secrets so they are provided via env vars
+        let url = environment::expand_value(&webhook.url);
+        let payload = create_payload(webhook.format.unwrap_or(WebhookFormat::Json), result);
+
+        if let Err(error) = post_webhook(&url, &payload) {
+            warn!("{}", error);
+        }
+    }
+}
+
+/// Sends the notifications (if configured) of the finished flow.<br>
+/// Nested cargo-make invocations do not notify, as their flow is part of the top level flow.
+pub(crate) fn notify(config: &Config, task: &str, start_time: SystemTime, error: Option<String>) {
+    let notifications = match config.notifications {
+        Some(ref notifications) => notifications,
+        None => return,
+    };
+
+    if !recursion_level::is_top() || dry_run::is_enabled() {
+        return;
+    }
+
+    let result = FlowResult {
+        project: get_project_name(),
+        task: task.to_string(),
+        success: error.is_none(),
+        duration: start_time.elapsed().unwrap_or_default(),
+        error,
+    };
+
+    send(notifications, &result);
+}
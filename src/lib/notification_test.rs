@@ -0,0 +1,172 @@
+use super::*;
+
+fn create_result(success: bool) -> FlowResult {
+    FlowResult {
+        project: Some("myproject".to_string()),
+        task: "build".to_string(),
+        success,
+        duration: Duration::from_millis(12340),
+        error: if success {
+            None
+        } else {
+            Some("Task: test failed - Exit Code Error: 1".to_string())
+        },
+    }
+}
+
+fn create_webhook(url: &str, on: Option<NotificationTrigger>) -> WebhookConfig {
+    WebhookConfig {
+        url: url.to_string(),
+        format: None,
+        on,
+    }
+}
+
+#[test]
+fn merge_notifications_config_none() {
+    let output = merge_notifications_config(None, None);
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn merge_notifications_config_base_only() {
+    let mut base = NotificationsConfig::new();
+    base.desktop = Some(true);
+
+    let output = merge_notifications_config(Some(base.clone()), None);
+
+    assert_eq!(output, Some(base));
+}
+
+#[test]
+fn merge_notifications_config_both() {
+    let mut base = NotificationsConfig::new();
+    base.desktop = Some(true);
+    base.min_duration = Some(60);
+    let mut extended = NotificationsConfig::new();
+    extended.on = Some(NotificationTrigger::Failure);
+    extended.webhooks = Some(vec![create_webhook("https://example.com", None)]);
+
+    let output = merge_notifications_config(Some(base), Some(extended)).unwrap();
+
+    assert_eq!(output.on, Some(NotificationTrigger::Failure));
+    assert_eq!(output.min_duration, Some(60));
+    assert_eq!(output.desktop, Some(true));
+    assert_eq!(output.webhooks.unwrap().len(), 1);
+}
+
+#[test]
+fn should_notify_triggers() {
+    assert!(should_notify(None, true));
+    assert!(should_notify(None, false));
+    assert!(should_notify(Some(NotificationTrigger::Always), false));
+    assert!(should_notify(Some(NotificationTrigger::Success), true));
+    assert!(!should_notify(Some(NotificationTrigger::Success), false));
+    assert!(!should_notify(Some(NotificationTrigger::Failure), true));
+    assert!(should_notify(Some(NotificationTrigger::Failure), false));
+}
+
+#[test]
+fn is_min_duration_reached_values() {
+    let mut config = NotificationsConfig::new();
+    assert!(is_min_duration_reached(&config, Duration::from_secs(0)));
+
+    config.min_duration = Some(60);
+    assert!(!is_min_duration_reached(&config, Duration::from_secs(59)));
+    assert!(is_min_duration_reached(&config, Duration::from_secs(60)));
+}
+
+#[test]
+fn get_webhooks_filtered() {
+    let mut config = NotificationsConfig::new();
+    config.on = Some(NotificationTrigger::Failure);
+    config.webhooks = Some(vec![
+        create_webhook("default", None),
+        create_webhook("always", Some(NotificationTrigger::Always)),
+        create_webhook("success", Some(NotificationTrigger::Success)),
+    ]);
+
+    let urls = |success| -> Vec<String> {
+        get_webhooks(&config, success)
+            .into_iter()
+            .map(|webhook| webhook.url)
+            .collect()
+    };
+
+    assert_eq!(urls(true), vec!["always", "success"]);
+    assert_eq!(urls(false), vec!["default", "always"]);
+}
+
+#[test]
+fn get_webhooks_none() {
+    let config = NotificationsConfig::new();
+
+    assert!(get_webhooks(&config, true).is_empty());
+}
+
+#[test]
+fn create_title_values() {
+    let mut result = create_result(true);
+    assert_eq!(create_title(&result), "cargo-make: myproject succeeded");
+
+    result.success = false;
+    result.project = None;
+    assert_eq!(create_title(&result), "cargo-make: flow failed");
+}
+
+#[test]
+fn create_message_success() {
+    let message = create_message(&create_result(true));
+
+    assert_eq!(message, "Task: build succeeded in 12.34 seconds.");
+}
+
+#[test]
+fn create_message_failure() {
+    let message = create_message(&create_result(false));
+
+    assert_eq!(
+        message,
+        "Task: build failed in 12.34 seconds.\nTask: test failed - Exit Code Error: 1"
+    );
+}
+
+#[test]
+fn create_payload_slack() {
+    let payload = create_payload(WebhookFormat::Slack, &create_result(true));
+
+    assert_eq!(
+        payload,
+        json!({"text": "*cargo-make: myproject succeeded*\nTask: build succeeded in 12.34 seconds."})
+    );
+}
+
+#[test]
+fn create_payload_discord() {
+    let payload = create_payload(WebhookFormat::Discord, &create_result(true));
+
+    assert_eq!(
+        payload,
+        json!({"content": "**cargo-make: myproject succeeded**\nTask: build succeeded in 12.34 seconds."})
+    );
+}
+
+#[test]
+fn create_payload_json() {
+    let payload = create_payload(WebhookFormat::Json, &create_result(false));
+
+    assert_eq!(payload["project"], "myproject");
+    assert_eq!(payload["task"], "build");
+    assert_eq!(payload["success"], false);
+    assert_eq!(payload["duration"], 12340);
+    assert_eq!(payload["error"], "Task: test failed - Exit Code Error: 1");
+    assert!(payload["profile"].is_string());
+}
+
+#[test]
+fn notify_not_configured() {
+    let config = Config::default();
+
+    notify(&config, "build", SystemTime::now(), None);
+}
@@ -0,0 +1,206 @@
+//! # output
+//!
+//! Central output writer.<br>
+//! Console lines emitted by cargo-make (logs and task output) pass through this layer
+//! so they can be decorated with a timestamp and the owning task name.
+//!
+
+#[cfg(test)]
+#[path = "output_test.rs"]
+mod output_test;
+
+use crate::dashboard;
+use crate::progress;
+use crate::tee_output;
+use crate::types::{Config, StripAnsiMode};
+use std::env;
+use std::io::{stderr, stdout, BufRead, BufReader, IsTerminal, Read, Write};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use strip_ansi_escapes::strip_str;
+
+static TIMESTAMP_ENV_VAR_NAME: &str = "CARGO_MAKE_LINE_PREFIX_TIMESTAMP";
+static TASK_NAME_ENV_VAR_NAME: &str = "CARGO_MAKE_LINE_PREFIX_TASK_NAME";
+static STRIP_ANSI_ENV_VAR_NAME: &str = "CARGO_MAKE_STRIP_ANSI";
+
+pub(crate) fn init(config: &Config) {
+    if config.config.line_prefix_timestamp.unwrap_or(false) {
+        envmnt::set_bool(TIMESTAMP_ENV_VAR_NAME, true);
+    }
+    if config.config.line_prefix_task_name.unwrap_or(false) {
+        envmnt::set_bool(TASK_NAME_ENV_VAR_NAME, true);
+    }
+
+    let strip_ansi = match config.config.strip_ansi {
+        Some(StripAnsiMode::Always) => true,
+        Some(StripAnsiMode::Auto) => !stdout().is_terminal(),
+        Some(StripAnsiMode::Never) | None => false,
+    };
+    if strip_ansi {
+        envmnt::set_bool(STRIP_ANSI_ENV_VAR_NAME, true);
+    }
+
+    if let Some(width) = config.config.output_width {
+        envmnt::set("COLUMNS", width.to_string());
+    }
+}
+
+pub(crate) fn is_line_prefix_enabled() -> bool {
+    envmnt::is(TIMESTAMP_ENV_VAR_NAME) || envmnt::is(TASK_NAME_ENV_VAR_NAME)
+}
+
+pub(crate) fn is_strip_ansi_enabled() -> bool {
+    envmnt::is(STRIP_ANSI_ENV_VAR_NAME)
+}
+
+/// Returns true if the process output must be forwarded line by line via the output writer
+pub(crate) fn is_forwarding_enabled() -> bool {
+    is_line_prefix_enabled()
+        || is_strip_ansi_enabled()
+        || dashboard::is_active()
+        || tee_output::is_enabled()
+        || progress::is_forwarding_required()
+}
+
+/// Removes the ANSI escape sequences from the line.<br>
+/// Progress bars redraw the same line using carriage returns, so only the last state is kept.
+fn strip_control_sequences(line: &str) -> String {
+    let last_state = match line.rfind('\r') {
+        Some(index) => &line[index + 1..],
+        None => line,
+    };
+
+    strip_str(last_state)
+}
+
+/// Formats the time of day (UTC) as HH:MM:SS.mmm
+fn format_timestamp(since_epoch: Duration) -> String {
+    let seconds_of_day = since_epoch.as_secs() % 86400;
+
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+fn create_line_prefix(timestamp: Option<Duration>, task_name: Option<String>) -> String {
+    let mut prefix = String::new();
+
+    if let Some(since_epoch) = timestamp {
+        prefix.push('[');
+        prefix.push_str(&format_timestamp(since_epoch));
+        prefix.push(']');
+    }
+
+    if let Some(name) = task_name {
+        prefix.push('[');
+        prefix.push_str(&name);
+        prefix.push(']');
+    }
+
+    if !prefix.is_empty() {
+        prefix.push(' ');
+    }
+
+    prefix
+}
+
+/// Returns the prefix for the next emitted line (empty if line prefixes are disabled)
+pub(crate) fn get_line_prefix() -> String {
+    let timestamp = if envmnt::is(TIMESTAMP_ENV_VAR_NAME) {
+        SystemTime::now().duration_since(UNIX_EPOCH).ok()
+    } else {
+        None
+    };
+
+    let task_name = if envmnt::is(TASK_NAME_ENV_VAR_NAME) {
+        env::var("CARGO_MAKE_CURRENT_TASK_NAME").ok()
+    } else {
+        None
+    };
+
+    create_line_prefix(timestamp, task_name)
+}
+
+/// Writes a single line to the stdout (or stderr) with the configured line prefix
+pub(crate) fn write_line(line: &str, to_stderr: bool) {
+    tee_output::write_line(line);
+
+    if dashboard::capture_line(line) {
+        return;
+    }
+
+    let text = format!("{}{}\n", get_line_prefix(), line);
+
+    // output errors (for example closed pipes) are ignored like in println
+    let result = progress::run_cleared(|| {
+        if to_stderr {
+            stderr().write_all(text.as_bytes())
+        } else {
+            stdout().write_all(text.as_bytes())
+        }
+    });
+
+    if result.is_err() {
+        debug!("Unable to write output line.");
+    }
+}
+
+/// Writes a log line (already formatted by the logger) to the stdout, unless it is captured
+/// by the dashboard
+pub(crate) fn write_log_line(line: &str) {
+    if dashboard::capture_line(line) {
+        return;
+    }
+
+    progress::run_cleared(|| {
+        let mut output = stdout().lock();
+        if writeln!(output, "{}", line).is_err() {
+            // nothing to report as the log output itself is not available
+            return;
+        }
+        output.flush().unwrap_or(());
+    });
+}
+
+/// Forwards all lines read from the provided reader (usually a child process pipe)
+/// to the console via the output writer.
+pub(crate) fn forward_lines<R: Read + Send + 'static>(
+    reader: R,
+    to_stderr: bool,
+) -> JoinHandle<()> {
+    // the lines are owned by the task of the calling thread
+    let branch = dashboard::get_current_branch();
+    let task_file = tee_output::get_current_file();
+    let strip_ansi = is_strip_ansi_enabled();
+    let forwarded_output = progress::ForwardedOutput::start();
+
+    thread::spawn(move || {
+        let _forwarded_output = forwarded_output;
+        dashboard::set_current_branch(branch);
+        tee_output::set_current_file(task_file);
+        let mut buffered = BufReader::new(reader);
+        let mut buffer = vec![];
+
+        loop {
+            buffer.clear();
+            match buffered.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buffer);
+                    let line = line.trim_end_matches(['\n', '\r']);
+
+                    if strip_ansi {
+                        write_line(&strip_control_sequences(line), to_stderr);
+                    } else {
+                        write_line(line, to_stderr);
+                    }
+                }
+            }
+        }
+    })
+}
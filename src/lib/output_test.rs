@@ -0,0 +1,102 @@
+use super::*;
+
+#[test]
+fn format_timestamp_start_of_day() {
+    assert_eq!(
+        format_timestamp(Duration::from_secs(86400 * 3)),
+        "00:00:00.000"
+    );
+}
+
+#[test]
+fn format_timestamp_with_millis() {
+    let since_epoch = Duration::from_millis(((13 * 3600 + 5 * 60 + 9) * 1000) + 42);
+
+    assert_eq!(format_timestamp(since_epoch), "13:05:09.042");
+}
+
+#[test]
+fn create_line_prefix_none() {
+    assert_eq!(create_line_prefix(None, None), "");
+}
+
+#[test]
+fn create_line_prefix_timestamp() {
+    assert_eq!(
+        create_line_prefix(Some(Duration::from_secs(61)), None),
+        "[00:01:01.000] "
+    );
+}
+
+#[test]
+fn create_line_prefix_task_name() {
+    assert_eq!(
+        create_line_prefix(None, Some("build".to_string())),
+        "[build] "
+    );
+}
+
+#[test]
+fn create_line_prefix_all() {
+    assert_eq!(
+        create_line_prefix(Some(Duration::from_secs(61)), Some("build".to_string())),
+        "[00:01:01.000][build] "
+    );
+}
+
+#[test]
+fn strip_control_sequences_no_sequences() {
+    assert_eq!(strip_control_sequences("test 123"), "test 123");
+}
+
+#[test]
+fn strip_control_sequences_colors() {
+    assert_eq!(
+        strip_control_sequences("\x1b[1m\x1b[32mCompiling\x1b[0m test v0.1.0"),
+        "Compiling test v0.1.0"
+    );
+}
+
+#[test]
+fn strip_control_sequences_progress() {
+    assert_eq!(
+        strip_control_sequences("\x1b[2K 10%\r\x1b[2K 50%\r\x1b[2K 100%"),
+        " 100%"
+    );
+}
+
+#[test]
+#[ignore]
+fn init_from_config() {
+    envmnt::remove(TIMESTAMP_ENV_VAR_NAME);
+    envmnt::remove(TASK_NAME_ENV_VAR_NAME);
+
+    let mut config = Config::default();
+    init(&config);
+    assert!(!is_line_prefix_enabled());
+
+    config.config.line_prefix_task_name = Some(true);
+    init(&config);
+    assert!(is_line_prefix_enabled());
+    assert!(!envmnt::is(TIMESTAMP_ENV_VAR_NAME));
+
+    envmnt::remove(TASK_NAME_ENV_VAR_NAME);
+}
+
+#[test]
+#[ignore]
+fn init_strip_ansi_always() {
+    envmnt::remove(STRIP_ANSI_ENV_VAR_NAME);
+
+    let mut config = Config::default();
+    config.config.strip_ansi = Some(StripAnsiMode::Never);
+    init(&config);
+    assert!(!is_strip_ansi_enabled());
+
+    config.config.strip_ansi = Some(StripAnsiMode::Always);
+    init(&config);
+    assert!(is_strip_ansi_enabled());
+    assert!(is_forwarding_enabled());
+
+    envmnt::remove(STRIP_ANSI_ENV_VAR_NAME);
+}
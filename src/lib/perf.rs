@@ -0,0 +1,101 @@
+//! # perf
+//!
+//! Collects the time spent in the internal phases of cargo-make (descriptor loading, env setup,
+//! condition evaluation, installation checks and tasks) and prints it at the end of the flow.
+//!
+
+#[cfg(test)]
+#[path = "perf_test.rs"]
+mod perf_test;
+
+use crate::types::CliArgs;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+static ENV_VAR_NAME: &str = "CARGO_MAKE_PERF";
+
+#[derive(Debug, Default, Clone, PartialEq)]
+/// Holds the accumulated time of a single phase
+struct PhaseInfo {
+    /// Total time spent in the phase
+    duration: Duration,
+    /// Amount of times the phase was invoked
+    count: u32,
+}
+
+static PHASES: Lazy<Mutex<IndexMap<String, PhaseInfo>>> = Lazy::new(|| Mutex::new(IndexMap::new()));
+
+/// Enables the internal profiling if requested via the --perf cli flag
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.perf {
+        envmnt::set_bool(ENV_VAR_NAME, true);
+    }
+}
+
+/// Returns true if internal profiling is enabled (CARGO_MAKE_PERF env var)
+pub(crate) fn is_enabled() -> bool {
+    envmnt::is(ENV_VAR_NAME)
+}
+
+fn add_duration(phase: &str, duration: Duration) {
+    let mut phases = PHASES.lock().unwrap();
+
+    let phase_info = phases.entry(phase.to_string()).or_default();
+    phase_info.duration += duration;
+    phase_info.count += 1;
+}
+
+/// Adds the time passed since the provided start time to the phase (if profiling is enabled)
+pub(crate) fn add(phase: &str, start_time: SystemTime) {
+    if is_enabled() {
+        if let Ok(elapsed) = start_time.elapsed() {
+            add_duration(phase, elapsed);
+        }
+    }
+}
+
+/// Runs the provided action and adds its duration to the phase (if profiling is enabled)
+pub(crate) fn measure<T, F>(phase: &str, action: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let start_time = SystemTime::now();
+    let output = action();
+    add(phase, start_time);
+
+    output
+}
+
+fn get_report_lines(phases: &IndexMap<String, PhaseInfo>) -> Vec<String> {
+    let max_name_size = phases.keys().map(|name| name.len()).max().unwrap_or(0);
+
+    phases
+        .iter()
+        .map(|(name, phase_info)| {
+            let name_gap = format!("{: <1$}", "", max_name_size - name.len() + 3);
+
+            format!(
+                "{}:{}{:.2} ms ({} calls)",
+                name,
+                name_gap,
+                phase_info.duration.as_secs_f64() * 1000.0,
+                phase_info.count
+            )
+        })
+        .collect()
+}
+
+/// Prints the collected phases timing in the order they were first invoked
+pub(crate) fn print() {
+    if is_enabled() {
+        let phases = PHASES.lock().unwrap();
+
+        info!("==================Performance===================");
+        for line in get_report_lines(&phases) {
+            info!("{}", line);
+        }
+        info!("================================================");
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+#[test]
+fn init_enabled() {
+    let mut cli_args = CliArgs::new();
+    cli_args.perf = true;
+
+    envmnt::remove(ENV_VAR_NAME);
+    init(&cli_args);
+
+    assert!(is_enabled());
+    envmnt::remove(ENV_VAR_NAME);
+}
+
+#[test]
+fn init_disabled() {
+    envmnt::remove(ENV_VAR_NAME);
+    init(&CliArgs::new());
+
+    assert!(!is_enabled());
+}
+
+#[test]
+fn add_duration_accumulated() {
+    add_duration("add_duration_accumulated", Duration::from_millis(2));
+    add_duration("add_duration_accumulated", Duration::from_millis(3));
+
+    let phases = PHASES.lock().unwrap();
+    let phase_info = phases.get("add_duration_accumulated").unwrap();
+
+    assert_eq!(phase_info.duration, Duration::from_millis(5));
+    assert_eq!(phase_info.count, 2);
+}
+
+#[test]
+fn measure_returns_output() {
+    let output = measure("measure_returns_output", || 10);
+
+    assert_eq!(output, 10);
+}
+
+#[test]
+fn get_report_lines_aligned() {
+    let mut phases = IndexMap::new();
+    phases.insert(
+        "Load Descriptor".to_string(),
+        PhaseInfo {
+            duration: Duration::from_micros(1500),
+            count: 1,
+        },
+    );
+    phases.insert(
+        "Task: build".to_string(),
+        PhaseInfo {
+            duration: Duration::from_millis(20),
+            count: 2,
+        },
+    );
+
+    let lines = get_report_lines(&phases);
+
+    assert_eq!(
+        lines,
+        vec![
+            "Load Descriptor:   1.50 ms (1 calls)".to_string(),
+            "Task: build:       20.00 ms (2 calls)".to_string(),
+        ]
+    );
+}
@@ -202,6 +202,7 @@ fn setup_script_globals_for_task(context: &mut Context, step: &Step, script: &mu
         Some(value) => match value {
             DeprecationInfo::Boolean(value) => value,
             DeprecationInfo::Message(_) => true,
+            DeprecationInfo::Details(_) => true,
         },
         None => false,
     };
@@ -60,6 +60,12 @@ fn get_plugin_no_plugins_config() {
             env_scripts: vec![],
             tasks: IndexMap::new(),
             plugins: None,
+            cross: None,
+            coverage: None,
+            clippy: None,
+            audit: None,
+            bench: None,
+            notifications: None,
         },
         "test",
     );
@@ -80,6 +86,12 @@ fn get_plugin_not_found() {
                 aliases: None,
                 plugins: IndexMap::new(),
             }),
+            cross: None,
+            coverage: None,
+            clippy: None,
+            audit: None,
+            bench: None,
+            notifications: None,
         },
         "test",
     );
@@ -108,6 +120,12 @@ fn get_plugin_found() {
                 aliases: None,
                 plugins,
             }),
+            cross: None,
+            coverage: None,
+            clippy: None,
+            audit: None,
+            bench: None,
+            notifications: None,
         },
         "test",
     );
@@ -141,6 +159,12 @@ fn get_plugin_found_with_alias() {
                 aliases: Some(aliases),
                 plugins,
             }),
+            cross: None,
+            coverage: None,
+            clippy: None,
+            audit: None,
+            bench: None,
+            notifications: None,
         },
         "test",
     );
@@ -498,9 +522,28 @@ fn run_task_invoked_valid() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: None,
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let mut flow_info = create_empty_flow_info();
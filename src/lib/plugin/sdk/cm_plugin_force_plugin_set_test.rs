@@ -74,6 +74,8 @@ set_env FORCE_PLUGIN_SET_AND_CLEAR_FLOW_TEST_SET_4 1
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
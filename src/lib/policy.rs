@@ -0,0 +1,125 @@
+//! # policy
+//!
+//! Enforces the user level execution policy (denied script runners, allowed/denied commands
+//! and confirmation of destructive tasks) before tasks are invoked.
+//!
+
+#[cfg(test)]
+#[path = "policy_test.rs"]
+mod policy_test;
+
+use crate::error::CargoMakeError;
+use crate::prompt;
+use crate::scriptengine;
+use crate::types::{ExecutionPolicy, GlobalConfig, Step};
+use once_cell::sync::Lazy;
+use std::path::Path;
+use std::sync::Mutex;
+
+static POLICY: Lazy<Mutex<Option<ExecutionPolicy>>> = Lazy::new(|| Mutex::new(None));
+
+/// Sets the execution policy defined in the global config
+pub(crate) fn init(global_config: &GlobalConfig) {
+    *POLICY.lock().unwrap() = global_config.policy.clone();
+}
+
+/// Returns the binary name (file name without extension) of the command or script runner
+fn get_binary_name(value: &str) -> String {
+    match Path::new(value.trim()).file_stem() {
+        Some(name) => name.to_string_lossy().into_owned(),
+        None => value.trim().to_string(),
+    }
+}
+
+fn is_listed(list: &Option<Vec<String>>, name: &str) -> bool {
+    match list {
+        Some(ref values) => values.iter().any(|value| get_binary_name(value) == name),
+        None => false,
+    }
+}
+
+/// Asks the user to confirm running the destructive task (fails closed without a terminal)
+fn confirm(task: &str) -> bool {
+    if !prompt::is_interactive() {
+        warn!(
+            "Task: {} is destructive and cannot be confirmed without an interactive terminal.",
+            task
+        );
+        return false;
+    }
+
+    prompt::confirm(&format!("Task: {} is destructive, continue?", task))
+}
+
+fn validate_command(
+    policy: &ExecutionPolicy,
+    task: &str,
+    command: &str,
+) -> Result<(), CargoMakeError> {
+    let name = get_binary_name(command);
+
+    if is_listed(&policy.deny_commands, &name) {
+        Err(CargoMakeError::PolicyViolation(
+            task.to_string(),
+            format!("command {} is denied", &name),
+        ))
+    } else if policy.allow_commands.is_some() && !is_listed(&policy.allow_commands, &name) {
+        Err(CargoMakeError::PolicyViolation(
+            task.to_string(),
+            format!("command {} is not in the allowed commands", &name),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_with_policy<F>(
+    policy: &ExecutionPolicy,
+    step: &Step,
+    confirm_task: F,
+) -> Result<(), CargoMakeError>
+where
+    F: Fn(&str) -> bool,
+{
+    if let Some(script_runner) = scriptengine::get_script_runner_name(&step.config)? {
+        let name = get_binary_name(&script_runner);
+
+        if is_listed(&policy.deny_script_runners, &name) {
+            return Err(CargoMakeError::PolicyViolation(
+                step.name.to_string(),
+                format!("script runner {} is denied", &name),
+            ));
+        }
+
+        // internal engines do not spawn a process
+        if !script_runner.starts_with("@") {
+            validate_command(policy, &step.name, &script_runner)?;
+        }
+    }
+
+    if let Some(ref command) = step.config.command {
+        validate_command(policy, &step.name, command)?;
+    }
+
+    if step.config.destructive.unwrap_or(false)
+        && policy.confirm_destructive.unwrap_or(false)
+        && !confirm_task(&step.name)
+    {
+        return Err(CargoMakeError::PolicyViolation(
+            step.name.to_string(),
+            "destructive task was not confirmed".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the (expanded) step against the execution policy
+pub(crate) fn validate(step: &Step) -> Result<(), CargoMakeError> {
+    let policy = POLICY.lock().unwrap().clone();
+
+    match policy {
+        Some(ref policy) => validate_with_policy(policy, step, confirm),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,162 @@
+use super::*;
+use crate::types::{ScriptValue, Task};
+
+fn create_step(task: Task) -> Step {
+    Step {
+        name: "test".to_string(),
+        config: task,
+    }
+}
+
+fn create_command_step(command: &str) -> Step {
+    let mut task = Task::new();
+    task.command = Some(command.to_string());
+
+    create_step(task)
+}
+
+fn create_script_step(script_runner: Option<&str>, script: &str) -> Step {
+    let mut task = Task::new();
+    task.script_runner = script_runner.map(|value| value.to_string());
+    task.script = Some(ScriptValue::Text(
+        script.lines().map(|line| line.to_string()).collect(),
+    ));
+
+    create_step(task)
+}
+
+fn no_confirm(_task: &str) -> bool {
+    panic!("confirmation should not be requested");
+}
+
+#[test]
+fn get_binary_name_simple() {
+    assert_eq!(get_binary_name("cargo"), "cargo");
+}
+
+#[test]
+fn get_binary_name_path() {
+    assert_eq!(get_binary_name("/usr/bin/python3"), "python3");
+}
+
+#[test]
+fn get_binary_name_extension() {
+    assert_eq!(get_binary_name("cmd.exe"), "cmd");
+}
+
+#[test]
+fn validate_with_policy_empty() {
+    let policy = ExecutionPolicy::default();
+
+    validate_with_policy(&policy, &create_command_step("rm"), no_confirm).unwrap();
+}
+
+#[test]
+fn validate_with_policy_denied_command() {
+    let policy = ExecutionPolicy {
+        deny_commands: Some(vec!["rm".to_string()]),
+        ..Default::default()
+    };
+
+    validate_with_policy(&policy, &create_command_step("cargo"), no_confirm).unwrap();
+    let error =
+        validate_with_policy(&policy, &create_command_step("/bin/rm"), no_confirm).unwrap_err();
+    assert!(matches!(error, CargoMakeError::PolicyViolation(_, _)));
+}
+
+#[test]
+fn validate_with_policy_allowed_commands() {
+    let policy = ExecutionPolicy {
+        allow_commands: Some(vec!["cargo".to_string(), "sh".to_string()]),
+        ..Default::default()
+    };
+
+    validate_with_policy(&policy, &create_command_step("cargo"), no_confirm).unwrap();
+    validate_with_policy(
+        &policy,
+        &create_script_step(Some("sh"), "echo test"),
+        no_confirm,
+    )
+    .unwrap();
+    assert!(validate_with_policy(&policy, &create_command_step("curl"), no_confirm).is_err());
+    assert!(validate_with_policy(
+        &policy,
+        &create_script_step(Some("python"), "print('test')"),
+        no_confirm
+    )
+    .is_err());
+}
+
+#[test]
+fn validate_with_policy_allowed_commands_internal_runner() {
+    let policy = ExecutionPolicy {
+        allow_commands: Some(vec!["cargo".to_string()]),
+        ..Default::default()
+    };
+
+    validate_with_policy(
+        &policy,
+        &create_script_step(Some("@duckscript"), "echo test"),
+        no_confirm,
+    )
+    .unwrap();
+}
+
+#[test]
+fn validate_with_policy_denied_script_runner() {
+    let policy = ExecutionPolicy {
+        deny_script_runners: Some(vec!["@duckscript".to_string(), "python".to_string()]),
+        ..Default::default()
+    };
+
+    validate_with_policy(
+        &policy,
+        &create_script_step(Some("sh"), "echo test"),
+        no_confirm,
+    )
+    .unwrap();
+    assert!(validate_with_policy(
+        &policy,
+        &create_script_step(Some("@duckscript"), "echo test"),
+        no_confirm
+    )
+    .is_err());
+    assert!(validate_with_policy(
+        &policy,
+        &create_script_step(None, "#!/usr/bin/env python\nprint('test')"),
+        no_confirm
+    )
+    .is_err());
+}
+
+#[test]
+fn validate_with_policy_destructive_not_enforced() {
+    let policy = ExecutionPolicy::default();
+    let mut step = create_command_step("rm");
+    step.config.destructive = Some(true);
+
+    validate_with_policy(&policy, &step, no_confirm).unwrap();
+}
+
+#[test]
+fn validate_with_policy_destructive_confirmed() {
+    let policy = ExecutionPolicy {
+        confirm_destructive: Some(true),
+        ..Default::default()
+    };
+    let mut step = create_command_step("rm");
+    step.config.destructive = Some(true);
+
+    validate_with_policy(&policy, &step, |_| true).unwrap();
+    assert!(validate_with_policy(&policy, &step, |_| false).is_err());
+}
+
+#[test]
+fn validate_with_policy_not_destructive() {
+    let policy = ExecutionPolicy {
+        confirm_destructive: Some(true),
+        ..Default::default()
+    };
+
+    validate_with_policy(&policy, &create_command_step("rm"), no_confirm).unwrap();
+}
@@ -7,9 +7,12 @@
 #[path = "profile_test.rs"]
 mod profile_test;
 
+use indexmap::IndexMap;
+
 static PROFILE_ENV_KEY: &str = "CARGO_MAKE_PROFILE";
 static DEFAULT_PROFILE_ENV_KEY: &str = "CARGO_MAKE_DEFAULT_PROFILE";
 static ADDITIONAL_PROFILES_ENV_KEY: &str = "CARGO_MAKE_ADDITIONAL_PROFILES";
+static PROFILES_ENV_KEY: &str = "CARGO_MAKE_PROFILES";
 static DEFAULT_PROFILE: &str = "development";
 
 fn normalize_profile(profile: &str) -> String {
@@ -56,3 +59,36 @@ pub(crate) fn set_additional(profiles: &Vec<String>) {
 
     envmnt::set_list(ADDITIONAL_PROFILES_ENV_KEY, &nomralized_profiles);
 }
+
+/// Sets the ordered list of profiles provided via the cli (the first one is the current profile)
+pub(crate) fn set_all(profiles: &Vec<String>) {
+    let mut unique_profiles = vec![];
+
+    for profile in normalize_additional_profiles(profiles) {
+        if !unique_profiles.contains(&profile) {
+            unique_profiles.push(profile);
+        }
+    }
+
+    envmnt::set_list(PROFILES_ENV_KEY, &unique_profiles);
+}
+
+/// Returns the ordered list of profiles provided via the cli, later profiles env blocks
+/// override the earlier ones
+pub(crate) fn get_all() -> Vec<String> {
+    let profile = get();
+
+    match envmnt::get_list(PROFILES_ENV_KEY) {
+        // the list is only relevant if it was set for the current profile
+        Some(profiles) if profiles.first() == Some(&profile) => profiles,
+        _ => vec![profile],
+    }
+}
+
+/// Returns the value mapped to the last provided profile which is found in the values map
+pub(crate) fn get_value_for_profiles(values: &IndexMap<String, String>) -> Option<String> {
+    get_all()
+        .iter()
+        .rev()
+        .find_map(|profile| values.get(profile).cloned())
+}
@@ -111,3 +111,58 @@ fn set_additional_multiple() {
     let output = envmnt::get_or_panic("CARGO_MAKE_ADDITIONAL_PROFILES");
     assert_eq!(output, "test1;test2".to_string());
 }
+
+#[test]
+#[ignore]
+fn set_all_multiple() {
+    envmnt::remove("CARGO_MAKE_PROFILES");
+    set("ci");
+    set_all(&vec![
+        "  CI  ".to_string(),
+        "gpu".to_string(),
+        "ci".to_string(),
+    ]);
+    let output = envmnt::get_or_panic("CARGO_MAKE_PROFILES");
+    assert_eq!(output, "ci;gpu".to_string());
+
+    assert_eq!(get_all(), vec!["ci".to_string(), "gpu".to_string()]);
+}
+
+#[test]
+#[ignore]
+fn get_all_not_defined() {
+    envmnt::remove("CARGO_MAKE_PROFILES");
+    envmnt::set("CARGO_MAKE_PROFILE", "test123");
+    let output = get_all();
+    assert_eq!(output, vec!["test123".to_string()]);
+}
+
+#[test]
+#[ignore]
+fn get_all_other_profile() {
+    set_all(&vec!["ci".to_string(), "gpu".to_string()]);
+    set("test123");
+    let output = get_all();
+    assert_eq!(output, vec!["test123".to_string()]);
+}
+
+#[test]
+#[ignore]
+fn get_value_for_profiles_last_profile_wins() {
+    let mut values = IndexMap::new();
+    values.insert("ci".to_string(), "ci-flow".to_string());
+    values.insert("gpu".to_string(), "gpu-flow".to_string());
+
+    set("ci");
+    set_all(&vec!["ci".to_string(), "gpu".to_string()]);
+    assert_eq!(get_value_for_profiles(&values).unwrap(), "gpu-flow");
+
+    set_all(&vec!["ci".to_string(), "nightly".to_string()]);
+    assert_eq!(get_value_for_profiles(&values).unwrap(), "ci-flow");
+
+    set("development");
+    set_all(&vec!["development".to_string()]);
+    assert!(get_value_for_profiles(&values).is_none());
+
+    envmnt::remove("CARGO_MAKE_PROFILES");
+}
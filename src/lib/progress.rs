@@ -0,0 +1,212 @@
+//! # progress
+//!
+//! Renders an opt-in progress line (spinner, task name and elapsed time) while tasks are running.<br>
+//! The child processes output is forwarded via the output writer while the progress line is
+//! enabled, so the progress line is only drawn while such output is forwarded and it is cleared
+//! before each output line.
+//!
+
+#[cfg(test)]
+#[path = "progress_test.rs"]
+mod progress_test;
+
+use crate::dashboard;
+use crate::recursion_level;
+use crate::types::{CliArgs, Config};
+use once_cell::sync::Lazy;
+use std::io::{stderr, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+static FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+static REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Default)]
+struct ProgressState {
+    /// Stack of the currently running tasks (sub tasks are pushed on top of their parent)
+    tasks: Vec<(String, Instant)>,
+    worker: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+    /// The amount of child process outputs currently forwarded via the output writer
+    forwarded_outputs: usize,
+    /// True while the progress line is drawn
+    visible: bool,
+}
+
+static STATE: Lazy<Mutex<ProgressState>> = Lazy::new(|| Mutex::new(ProgressState::default()));
+
+pub(crate) fn is_progress_enabled() -> bool {
+    envmnt::is("CARGO_MAKE_PROGRESS")
+}
+
+pub(crate) fn init(config: &Config, cli_args: &CliArgs) {
+    if config.config.progress.unwrap_or(false) || cli_args.progress {
+        envmnt::set_bool("CARGO_MAKE_PROGRESS", true);
+    }
+}
+
+fn should_render() -> bool {
+    // forked cargo-make processes are rendered by the top level process
+    // the dashboard shows the running tasks itself
+    is_progress_enabled()
+        && recursion_level::is_top()
+        && stderr().is_terminal()
+        && !dashboard::is_active()
+}
+
+fn format_progress_line(frame: usize, name: &str, elapsed: Duration) -> String {
+    format!(
+        "{} Running Task: {} ({:.1}s)",
+        FRAMES[frame % FRAMES.len()],
+        name,
+        elapsed.as_secs_f64()
+    )
+}
+
+fn render(line: &str) {
+    let mut output = stderr();
+    // errors are ignored as the progress line is purely cosmetic
+    if write!(output, "\r\x1b[2K{}", line).is_ok() {
+        output.flush().unwrap_or(());
+    }
+}
+
+fn clear(state: &mut ProgressState) {
+    if state.visible {
+        render("");
+        state.visible = false;
+    }
+}
+
+fn run_worker(stop: Arc<AtomicBool>) {
+    let mut frame = 0;
+
+    while !stop.load(Ordering::SeqCst) {
+        if let Ok(mut state) = STATE.lock() {
+            // output which is not forwarded is written directly to the terminal and would be
+            // appended to the progress line
+            let line = match state.tasks.last() {
+                Some((name, start_time)) if state.forwarded_outputs > 0 => {
+                    Some(format_progress_line(frame, name, start_time.elapsed()))
+                }
+                _ => None,
+            };
+
+            match line {
+                Some(line) => {
+                    render(&line);
+                    state.visible = true;
+                }
+                None => clear(&mut state),
+            }
+        }
+
+        frame += 1;
+        thread::park_timeout(REFRESH_INTERVAL);
+    }
+
+    if let Ok(mut state) = STATE.lock() {
+        clear(&mut state);
+    }
+}
+
+/// Returns true if the child processes output must be forwarded via the output writer, so it
+/// is not mixed with the progress line
+pub(crate) fn is_forwarding_required() -> bool {
+    should_render()
+}
+
+/// Runs the provided function (which writes output to the terminal) after clearing the progress
+/// line and returns the function result
+pub(crate) fn run_cleared<T, F: FnOnce() -> T>(function: F) -> T {
+    match STATE.lock() {
+        Ok(mut state) => {
+            clear(&mut state);
+            function()
+        }
+        Err(_) => function(),
+    }
+}
+
+/// Marks a child process output as forwarded until this value is dropped.
+pub(crate) struct ForwardedOutput {}
+
+impl ForwardedOutput {
+    pub(crate) fn start() -> ForwardedOutput {
+        if let Ok(mut state) = STATE.lock() {
+            state.forwarded_outputs += 1;
+        }
+
+        ForwardedOutput {}
+    }
+}
+
+impl Drop for ForwardedOutput {
+    fn drop(&mut self) {
+        if let Ok(mut state) = STATE.lock() {
+            state.forwarded_outputs = state.forwarded_outputs.saturating_sub(1);
+
+            if state.forwarded_outputs == 0 {
+                clear(&mut state);
+            }
+        }
+    }
+}
+
+/// Shows the progress line for a task until this value is dropped.
+pub(crate) struct ProgressIndicator {
+    enabled: bool,
+}
+
+impl ProgressIndicator {
+    pub(crate) fn start(name: &str) -> ProgressIndicator {
+        let enabled = should_render();
+
+        if enabled {
+            if let Ok(mut state) = STATE.lock() {
+                state.tasks.push((name.to_string(), Instant::now()));
+
+                if state.worker.is_none() {
+                    let stop = Arc::new(AtomicBool::new(false));
+                    let worker_stop = stop.clone();
+                    let handle = thread::spawn(move || run_worker(worker_stop));
+
+                    state.worker = Some((stop, handle));
+                }
+            }
+        }
+
+        ProgressIndicator { enabled }
+    }
+}
+
+impl Drop for ProgressIndicator {
+    fn drop(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        let worker = match STATE.lock() {
+            Ok(mut state) => {
+                state.tasks.pop();
+
+                if state.tasks.is_empty() {
+                    state.worker.take()
+                } else {
+                    None
+                }
+            }
+            Err(_) => None,
+        };
+
+        if let Some((stop, handle)) = worker {
+            stop.store(true, Ordering::SeqCst);
+            handle.thread().unpark();
+            if handle.join().is_err() {
+                debug!("Progress indicator thread panicked.");
+            }
+        }
+    }
+}
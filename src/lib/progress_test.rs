@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn format_progress_line_first_frame() {
+    let line = format_progress_line(0, "build", Duration::from_millis(1500));
+
+    assert_eq!(line, "⠋ Running Task: build (1.5s)");
+}
+
+#[test]
+fn format_progress_line_frame_wraps() {
+    let line = format_progress_line(11, "test", Duration::from_secs(62));
+
+    assert_eq!(line, "⠙ Running Task: test (62.0s)");
+}
+
+#[test]
+#[ignore]
+fn init_from_cli_args() {
+    envmnt::remove("CARGO_MAKE_PROGRESS");
+    let config = Config::default();
+    let mut cli_args = CliArgs::new();
+
+    init(&config, &cli_args);
+    assert!(!is_progress_enabled());
+
+    cli_args.progress = true;
+    init(&config, &cli_args);
+    assert!(is_progress_enabled());
+
+    envmnt::remove("CARGO_MAKE_PROGRESS");
+}
+
+#[test]
+#[ignore]
+fn init_from_config() {
+    envmnt::remove("CARGO_MAKE_PROGRESS");
+    let mut config = Config::default();
+    config.config.progress = Some(true);
+
+    init(&config, &CliArgs::new());
+    assert!(is_progress_enabled());
+
+    envmnt::remove("CARGO_MAKE_PROGRESS");
+}
+
+#[test]
+fn run_cleared_result() {
+    let value = run_cleared(|| 5);
+
+    assert_eq!(value, 5);
+    assert!(!STATE.lock().unwrap().visible);
+}
+
+#[test]
+#[ignore]
+fn forwarded_output_count() {
+    let forwarded_output = ForwardedOutput::start();
+    assert_eq!(STATE.lock().unwrap().forwarded_outputs, 1);
+
+    drop(forwarded_output);
+    assert_eq!(STATE.lock().unwrap().forwarded_outputs, 0);
+}
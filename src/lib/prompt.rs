@@ -0,0 +1,90 @@
+//! # prompt
+//!
+//! Interactive prompts, such as the confirmation requested before invoking tasks and the
+//! task input values.
+//!
+
+#[cfg(test)]
+#[path = "prompt_test.rs"]
+mod prompt_test;
+
+use crate::error::CargoMakeError;
+use crate::types::ConfigSection;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+/// Returns true if the user can be prompted (stdin is an interactive terminal)
+pub(crate) fn is_interactive() -> bool {
+    io::stdin().is_terminal()
+}
+
+fn is_confirmed(answer: &str) -> bool {
+    let answer = answer.trim().to_lowercase();
+    answer == "y" || answer == "yes"
+}
+
+/// Prints the message and returns the (trimmed) line entered by the user
+pub(crate) fn read_line(message: &str) -> Option<String> {
+    print!("{} ", message);
+    if io::stdout().flush().is_err() {
+        return None;
+    }
+
+    let mut answer = String::new();
+    match io::stdin().lock().read_line(&mut answer) {
+        Ok(_) => Some(answer.trim().to_string()),
+        Err(_) => None,
+    }
+}
+
+/// Prints the message and reads the user yes/no answer (defaults to no)
+pub(crate) fn confirm(message: &str) -> bool {
+    match read_line(&format!("{} [y/N]", message)) {
+        Some(answer) => is_confirmed(&answer),
+        None => false,
+    }
+}
+
+/// Returns true if confirmation prompts are automatically accepted (--yes cli flag or
+/// CI environment with the auto_confirm_on_ci config)
+fn is_auto_confirmed(config: &ConfigSection) -> bool {
+    envmnt::is_or("CARGO_MAKE_ASSUME_YES", false)
+        || (config.auto_confirm_on_ci.unwrap_or(false) && ci_info::is_ci())
+}
+
+fn confirm_task_with<F>(
+    config: &ConfigSection,
+    task: &str,
+    message: &str,
+    confirm_message: F,
+) -> Result<(), CargoMakeError>
+where
+    F: Fn(&str) -> bool,
+{
+    if is_auto_confirmed(config) {
+        info!("Task: {} confirmed automatically.", task);
+        Ok(())
+    } else if confirm_message(message) {
+        Ok(())
+    } else {
+        Err(CargoMakeError::TaskNotConfirmed(task.to_string()))
+    }
+}
+
+/// Asks the user to confirm invoking the task (fails closed without a terminal)
+pub(crate) fn confirm_task(
+    config: &ConfigSection,
+    task: &str,
+    message: &str,
+) -> Result<(), CargoMakeError> {
+    confirm_task_with(config, task, message, |message| {
+        if is_interactive() {
+            confirm(message)
+        } else {
+            warn!(
+                "Task: {} requires confirmation and cannot be confirmed without an interactive terminal (use --yes to confirm).",
+                task
+            );
+            false
+        }
+    })
+}
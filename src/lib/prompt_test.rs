@@ -0,0 +1,55 @@
+use super::*;
+
+fn no_confirm(_message: &str) -> bool {
+    panic!("confirmation should not be requested");
+}
+
+#[test]
+fn is_confirmed_values() {
+    assert!(is_confirmed("y\n"));
+    assert!(is_confirmed(" YES "));
+    assert!(!is_confirmed("\n"));
+    assert!(!is_confirmed("no"));
+}
+
+#[test]
+fn confirm_task_with_confirmed() {
+    let config = ConfigSection::new();
+
+    confirm_task_with(&config, "test", "Continue?", |message| {
+        assert_eq!(message, "Continue?");
+        true
+    })
+    .unwrap();
+}
+
+#[test]
+fn confirm_task_with_not_confirmed() {
+    let config = ConfigSection::new();
+
+    let error = confirm_task_with(&config, "test", "Continue?", |_| false).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::TaskNotConfirmed(_)));
+}
+
+#[test]
+#[ignore]
+fn confirm_task_with_assume_yes() {
+    let config = ConfigSection::new();
+
+    envmnt::set_bool("CARGO_MAKE_ASSUME_YES", true);
+    let output = confirm_task_with(&config, "test", "Continue?", no_confirm);
+    envmnt::remove("CARGO_MAKE_ASSUME_YES");
+
+    output.unwrap();
+}
+
+#[test]
+fn confirm_task_with_auto_confirm_on_ci() {
+    let mut config = ConfigSection::new();
+    config.auto_confirm_on_ci = Some(true);
+
+    let output = confirm_task_with(&config, "test", "Continue?", |_| false);
+
+    assert_eq!(output.is_ok(), ci_info::is_ci());
+}
@@ -34,6 +34,10 @@ pub(crate) fn create_proxy_task(
         profile_arg.to_string(),
     ];
 
+    for additional_profile_name in profile::get_all().iter().skip(1) {
+        args.push(format!("--profile={}", additional_profile_name));
+    }
+
     if allow_private {
         args.push("--allow-private".to_string());
     }
@@ -43,6 +47,7 @@ pub(crate) fn create_proxy_task(
     }
 
     //get makefile location
+    let current_makefile = makefile.is_none();
     let makefile_path_option = match makefile {
         Some(makefile_path) => Some(makefile_path),
         None => match env::var("CARGO_MAKE_MAKEFILE_PATH") {
@@ -57,6 +62,16 @@ pub(crate) fn create_proxy_task(
         }
     };
 
+    // the additional makefiles are merged over the current makefile only
+    if current_makefile {
+        if let Some(additional_makefiles) = envmnt::get_list("CARGO_MAKE_ADDITIONAL_MAKEFILES") {
+            for additional_makefile in additional_makefiles {
+                args.push("--makefile".to_string());
+                args.push(additional_makefile);
+            }
+        }
+    }
+
     args.push(task.to_string());
 
     if let Some(cli_args_vec) = cli_args {
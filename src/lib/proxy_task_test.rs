@@ -93,6 +93,41 @@ fn create_proxy_task_with_makefile_argument() {
     assert_eq!(args[7], "some_task");
 }
 
+#[test]
+#[ignore]
+#[cfg(target_os = "linux")]
+fn create_proxy_task_with_additional_makefiles() {
+    envmnt::set("CARGO_MAKE_MAKEFILE_PATH", "base.toml");
+    envmnt::set_list(
+        "CARGO_MAKE_ADDITIONAL_MAKEFILES",
+        &vec!["overrides.toml".to_string(), "ci.toml".to_string()],
+    );
+    let task = create_proxy_task("some_task", false, false, None, None);
+    let external_task = create_proxy_task(
+        "some_task",
+        false,
+        false,
+        Some("external.toml".to_string()),
+        None,
+    );
+    envmnt::remove("CARGO_MAKE_ADDITIONAL_MAKEFILES");
+
+    let args = task.args.unwrap();
+    assert_eq!(args.len(), 12);
+    assert_eq!(args[5], "--makefile");
+    assert_eq!(args[6], "base.toml");
+    assert_eq!(args[7], "--makefile");
+    assert_eq!(args[8], "overrides.toml");
+    assert_eq!(args[9], "--makefile");
+    assert_eq!(args[10], "ci.toml");
+    assert_eq!(args[11], "some_task");
+
+    let args = external_task.args.unwrap();
+    assert_eq!(args.len(), 8);
+    assert_eq!(args[6], "external.toml");
+    assert_eq!(args[7], "some_task");
+}
+
 #[test]
 #[ignore]
 #[cfg(target_os = "linux")]
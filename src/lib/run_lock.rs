@@ -0,0 +1,102 @@
+//! # run_lock
+//!
+//! Prevents concurrent flows in the same project, using a lock file under the target directory
+//! which is held by the top level cargo-make process until the flow is done.
+//!
+
+#[cfg(test)]
+#[path = "run_lock_test.rs"]
+mod run_lock_test;
+
+use crate::error::CargoMakeError;
+use crate::recursion_level;
+use crate::types::{CliArgs, Config};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+static LOCK_FILE: &str = "run.lock";
+
+#[derive(Debug)]
+/// Holds the project run lock, which is released once dropped
+pub(crate) struct RunLock {
+    _file: File,
+}
+
+fn is_enabled(config: &Config, cli_args: &CliArgs) -> bool {
+    config.config.run_lock.unwrap_or(false) && !cli_args.disable_run_lock
+}
+
+fn get_lock_file() -> PathBuf {
+    let target_directory = envmnt::get_or("CARGO_MAKE_CRATE_TARGET_DIRECTORY", "target");
+
+    Path::new(&target_directory)
+        .join("cargo-make")
+        .join(LOCK_FILE)
+}
+
+/// Returns the description of the flow holding the lock (as written by the lock owner)
+fn read_owner(file: &mut File) -> String {
+    let mut owner = String::new();
+    match file.read_to_string(&mut owner) {
+        Ok(_) if !owner.trim().is_empty() => owner.trim().to_string(),
+        _ => "unknown process".to_string(),
+    }
+}
+
+fn lock(file_path: &Path, task: &str, wait: bool) -> Result<Option<RunLock>, CargoMakeError> {
+    if let Some(directory) = file_path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(file_path)?;
+
+    match file.try_lock() {
+        Ok(_) => (),
+        Err(TryLockError::WouldBlock) => {
+            let owner = read_owner(&mut file);
+
+            if !wait {
+                return Err(CargoMakeError::RunLocked(owner));
+            }
+
+            info!("Waiting for the running flow to finish ({}).", owner);
+            file.lock()?;
+        }
+        Err(TryLockError::Error(error)) => {
+            // file systems without lock support should not block the flow
+            warn!(
+                "Unable to lock the run lock file: {:?}, running without lock, {}",
+                file_path, error
+            );
+            return Ok(None);
+        }
+    };
+
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(format!("pid: {}, task: {}", process::id(), task).as_bytes())?;
+
+    Ok(Some(RunLock { _file: file }))
+}
+
+/// Acquires the project run lock (if enabled) for the flow of the provided task.<br>
+/// Nested cargo-make invocations (for example workspace members) run under the lock of the top
+/// level process and do not acquire it again.
+pub(crate) fn acquire(
+    config: &Config,
+    cli_args: &CliArgs,
+    task: &str,
+) -> Result<Option<RunLock>, CargoMakeError> {
+    if !is_enabled(config, cli_args) || !recursion_level::is_top() {
+        return Ok(None);
+    }
+
+    lock(&get_lock_file(), task, cli_args.wait_for_run_lock)
+}
@@ -0,0 +1,94 @@
+use super::*;
+use crate::test;
+use crate::types::ConfigSection;
+use indexmap::IndexMap;
+
+fn create_config(run_lock: Option<bool>) -> Config {
+    let mut config_section = ConfigSection::new();
+    config_section.run_lock = run_lock;
+
+    Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    }
+}
+
+#[test]
+fn is_enabled_not_defined() {
+    assert!(!is_enabled(&create_config(None), &CliArgs::new()));
+}
+
+#[test]
+fn is_enabled_true() {
+    assert!(is_enabled(&create_config(Some(true)), &CliArgs::new()));
+}
+
+#[test]
+fn is_enabled_disabled_by_cli() {
+    let mut cli_args = CliArgs::new();
+    cli_args.disable_run_lock = true;
+
+    assert!(!is_enabled(&create_config(Some(true)), &cli_args));
+}
+
+#[test]
+fn acquire_disabled() {
+    let run_lock = acquire(&create_config(None), &CliArgs::new(), "test").unwrap();
+
+    assert!(run_lock.is_none());
+}
+
+#[test]
+fn lock_writes_owner() {
+    let directory = test::get_temp_test_directory("run_lock_lock_writes_owner");
+    let file_path = directory.join("cargo-make").join(LOCK_FILE);
+
+    let run_lock = lock(&file_path, "build", false).unwrap();
+
+    assert!(run_lock.is_some());
+    let owner = fsio::file::read_text_file(&file_path).unwrap();
+    assert_eq!(owner, format!("pid: {}, task: build", process::id()));
+}
+
+#[test]
+fn lock_already_locked() {
+    let directory = test::get_temp_test_directory("run_lock_lock_already_locked");
+    let file_path = directory.join(LOCK_FILE);
+
+    let run_lock = lock(&file_path, "build", false).unwrap();
+    assert!(run_lock.is_some());
+
+    let result = lock(&file_path, "test", false);
+
+    match result {
+        Err(CargoMakeError::RunLocked(owner)) => {
+            assert_eq!(owner, format!("pid: {}, task: build", process::id()))
+        }
+        _ => panic!("expected run locked error"),
+    }
+}
+
+#[test]
+fn lock_released_on_drop() {
+    let directory = test::get_temp_test_directory("run_lock_lock_released_on_drop");
+    let file_path = directory.join(LOCK_FILE);
+
+    let run_lock = lock(&file_path, "build", false).unwrap();
+    drop(run_lock);
+
+    let run_lock = lock(&file_path, "test", false).unwrap();
+
+    assert!(run_lock.is_some());
+    let owner = fsio::file::read_text_file(&file_path).unwrap();
+    assert_eq!(owner, format!("pid: {}, task: test", process::id()));
+}
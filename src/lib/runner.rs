@@ -13,6 +13,8 @@
 mod runner_test;
 
 use std::cell::RefCell;
+use std::io;
+use std::mem;
 use std::rc::Rc;
 use std::thread;
 use std::time::SystemTime;
@@ -20,24 +22,43 @@ use std::time::SystemTime;
 use indexmap::IndexMap;
 use regex::Regex;
 
+use crate::ci_output;
 use crate::command;
 use crate::condition;
+use crate::dashboard;
+use crate::diagnostics;
+use crate::dry_run;
 use crate::environment;
 use crate::error::CargoMakeError;
 use crate::execution_plan::ExecutionPlanBuilder;
+use crate::exit_codes::{self, FailureClass};
 use crate::functions;
+use crate::history;
 use crate::installer;
 use crate::logger;
+use crate::notification;
+use crate::output;
+use crate::perf;
 use crate::plugin::runner::run_task as run_task_plugin;
+use crate::policy;
 use crate::profile;
+use crate::progress;
+use crate::prompt;
 use crate::proxy_task::create_proxy_task;
 use crate::scriptengine;
+use crate::status_summary;
+use crate::step_outputs;
+use crate::task_inputs;
+use crate::task_outputs;
+use crate::task_throttle;
+use crate::tee_output;
 use crate::time_summary;
 use crate::types::{
-    CliArgs, Config, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo, FlowState,
-    MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step, Task,
-    TaskWatchOptions,
+    CliArgs, Config, ConfigSection, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo,
+    FlowState, MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step,
+    StepStatus, Task, TaskWatchOptions,
 };
+use crate::version;
 
 fn do_in_task_working_directory<F>(step: &Step, mut action: F) -> Result<(), CargoMakeError>
 where
@@ -45,7 +66,7 @@ where
 {
     let revert_directory = match step.config.cwd {
         Some(ref cwd) => {
-            let expanded_cwd = environment::expand_value(cwd);
+            let expanded_cwd = environment::get_task_cwd(cwd);
 
             if expanded_cwd.len() > 0 {
                 let directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
@@ -83,7 +104,9 @@ pub(crate) fn validate_condition(
         Ok(valid)
     };
 
-    do_in_task_working_directory(&step, do_validate)?;
+    perf::measure("Conditions", || {
+        do_in_task_working_directory(step, do_validate)
+    })?;
 
     Ok(valid)
 }
@@ -183,6 +206,59 @@ fn run_forked_task(
     }
 }
 
+/// Runs all tasks in parallel while connecting the stdout of each task commands/scripts to the
+/// stdin of the next task commands/scripts
+fn run_piped_tasks(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    names: &[String],
+) -> Result<bool, CargoMakeError> {
+    if names.is_empty() {
+        return Ok(false);
+    }
+
+    let parent_branch = dashboard::get_current_branch();
+    let mut input = None;
+    let mut threads = vec![];
+
+    for (index, name) in names.iter().enumerate() {
+        let (output, next_input) = if index + 1 < names.len() {
+            let (reader, writer) = io::pipe()?;
+            (Some(writer), Some(reader))
+        } else {
+            (None, None)
+        };
+        let stage_input = mem::replace(&mut input, next_input);
+
+        let mut sub_flow_info = flow_info.clone();
+        sub_flow_info.task = name.to_string();
+        // we do not support merging changes back to parent
+        let cloned_flow_state = flow_state.borrow().clone();
+        threads.push(thread::spawn(move || -> Result<(), CargoMakeError> {
+            dashboard::set_current_branch(parent_branch);
+
+            command::pipe_io(stage_input, output, || {
+                run_flow(
+                    &sub_flow_info,
+                    Rc::new(RefCell::new(cloned_flow_state)),
+                    true,
+                )
+            })
+        }));
+    }
+
+    // all stages are waited for, as the next stages read until the previous stages end
+    let mut result = Ok(true);
+    for task_thread in threads {
+        let stage_result = task_thread.join().unwrap();
+        if result.is_ok() {
+            result = stage_result.map(|_| true);
+        }
+    }
+
+    result
+}
+
 /// runs a sub task and returns true/false based if a sub task was actually invoked
 fn run_sub_task_and_report(
     flow_info: &FlowInfo,
@@ -206,18 +282,31 @@ fn run_sub_task_and_report(
         RunTaskInfo::Routing(ref routing_info) => {
             get_sub_task_info_for_routing_info(&flow_info, routing_info)?
         }
+        RunTaskInfo::Pipe(ref pipe) => return run_piped_tasks(flow_info, flow_state, &pipe.pipe),
+    };
+    let task_args = match sub_task {
+        RunTaskInfo::Details(ref details) => details.args.clone(),
+        _ => None,
     };
 
     if task_names.is_some() {
         let names = task_names.unwrap();
         let mut threads = vec![];
 
+        let _dashboard = if parallel {
+            dashboard::Dashboard::start(&names)
+        } else {
+            None
+        };
+        let parent_branch = dashboard::get_current_branch();
+
         // clean up task only supported for forked tasks
         if !fork && cleanup_task.is_some() {
             error!("Invalid task, cannot use cleanup_task without fork.");
         }
 
-        for name in names {
+        for (index, name) in names.into_iter().enumerate() {
+            let sub_task_args = task_args.clone();
             let task_run_fn = move |flow_info: &FlowInfo,
                                     flow_state: Rc<RefCell<FlowState>>,
                                     fork: bool,
@@ -225,6 +314,9 @@ fn run_sub_task_and_report(
                   -> Result<(), CargoMakeError> {
                 let mut sub_flow_info = flow_info.clone();
                 sub_flow_info.task = name;
+                if sub_task_args.is_some() {
+                    sub_flow_info.cli_arguments = sub_task_args;
+                }
 
                 if fork {
                     run_forked_task(&sub_flow_info, flow_state, cleanup_task)
@@ -238,13 +330,22 @@ fn run_sub_task_and_report(
                 // we do not support merging changes back to parent
                 let cloned_flow_state = flow_state.borrow().clone();
                 let cloned_cleanup_task = cleanup_task.clone();
+                let dashboard_branch = _dashboard.as_ref().map(|_| index);
                 threads.push(thread::spawn(move || -> Result<(), CargoMakeError> {
-                    task_run_fn(
+                    dashboard::set_current_branch(dashboard_branch.or(parent_branch));
+
+                    let result = task_run_fn(
                         &run_flow_info,
                         Rc::new(RefCell::new(cloned_flow_state)),
                         fork,
                         &cloned_cleanup_task,
-                    )
+                    );
+
+                    if let Some(branch) = dashboard_branch {
+                        dashboard::branch_finished(branch, result.is_ok());
+                    }
+
+                    result
                 }));
             } else {
                 task_run_fn(&flow_info, flow_state.clone(), fork, &cleanup_task)?;
@@ -325,6 +426,67 @@ fn should_watch(task: &Task) -> bool {
     }
 }
 
+/// Runs the task commands in order, each with its own env which is set only while it runs
+fn run_task_commands(step: &Step) -> Result<(), CargoMakeError> {
+    if let Some(ref commands) = step.config.commands {
+        for task_command in commands {
+            let _command_env = environment::CommandEnv::set(&task_command.env);
+
+            let command_step = command::create_command_step(step, task_command);
+            let command_step = functions::run(&command_step)?;
+            let command_step = environment::expand_env(&command_step);
+
+            command::run(&command_step)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the task script or command(s)
+fn run_task_actions(
+    step: &Step,
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+) -> Result<(), CargoMakeError> {
+    // run script
+    let script_runner_done = scriptengine::invoke(&step.config, flow_info, flow_state)?;
+
+    // run command
+    if !script_runner_done {
+        command::run(step)?;
+        run_task_commands(step)?;
+    };
+
+    Ok(())
+}
+
+/// Runs the on error task flow of the failed task.<br>
+/// The failed task error is returned by the caller even if the on error flow fails as well.
+fn run_task_on_error_flow(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    task: &str,
+    on_error_task: &str,
+) {
+    info!(
+        "Task: {} failed, running on error task: {}",
+        task, on_error_task
+    );
+
+    let mut error_flow_info = flow_info.clone();
+    error_flow_info.task = on_error_task.to_string();
+    error_flow_info.from_step = None;
+    error_flow_info.skip_steps = None;
+
+    if let Err(error) = run_flow(&error_flow_info, flow_state, true) {
+        warn!("On error task: {} failed, {}", on_error_task, error);
+    }
+
+    // the failure is reported for the original task
+    envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", task);
+}
+
 pub(crate) fn run_task(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
@@ -334,7 +496,81 @@ pub(crate) fn run_task(
         plugins_enabled: true,
     };
 
-    run_task_with_options(flow_info, flow_state, step, &options)
+    let retries = if flow_state.borrow().flaky_tasks.contains(&step.name) {
+        flow_info.config.config.flaky_task_retries.unwrap_or(0)
+    } else {
+        0
+    };
+
+    dashboard::step_started(&step.name);
+
+    let step_start_time = SystemTime::now();
+    let status_summary_index = flow_state.borrow().status_summary.len();
+    let mut attempt = 0;
+    let mut step_status;
+    let result = loop {
+        let start_time = SystemTime::now();
+        let time_summary_size = flow_state.borrow().time_summary.len();
+        status_summary::take_step_status();
+        exit_codes::clear_failure_class();
+        let result = perf::measure(&format!("Task: {}", step.name), || {
+            logger::run_with_task_level(&step.config.log_level, || {
+                exit_codes::run_as(FailureClass::TaskFailure, || {
+                    run_task_with_options(flow_info, flow_state.clone(), step, &options)
+                })
+            })
+        });
+
+        // skipped tasks are not added to the time summary and are not recorded
+        let invoked = flow_state.borrow().time_summary.len() > time_summary_size;
+        if result.is_err() || invoked {
+            history::record(&flow_info.config, &step.name, start_time, result.is_ok());
+        }
+
+        // empty tasks are not added to the status summary
+        step_status = match (&result, status_summary::take_step_status()) {
+            (Err(_), _) => Some((StepStatus::Failed, None)),
+            (Ok(_), Some(status)) => Some(status),
+            (Ok(_), None) if invoked => Some((StepStatus::Success, None)),
+            (Ok(_), None) => None,
+        };
+
+        match result {
+            Err(ref error) if attempt < retries => {
+                attempt += 1;
+                warn!(
+                    "Flaky task: {} failed ({}), retrying (attempt {} of {}).",
+                    &step.name, error, attempt, retries
+                );
+            }
+            _ => break result,
+        }
+    };
+
+    dashboard::step_finished(&step.name, result.is_ok());
+
+    if result.is_err() {
+        exit_codes::record_task_failure();
+    }
+
+    if let Some((status, skip_reason)) = step_status {
+        status_summary::insert(
+            &mut flow_state.borrow_mut().status_summary,
+            status_summary_index,
+            &step.name,
+            status,
+            step_start_time,
+            skip_reason,
+        );
+    }
+
+    match (result, &step.config.on_error_task) {
+        (Err(error), Some(on_error_task)) => {
+            run_task_on_error_flow(flow_info, flow_state, &step.name, on_error_task);
+            Err(error)
+        }
+        (result, _) => result,
+    }
 }
 
 pub(crate) fn run_task_with_options(
@@ -345,8 +581,8 @@ pub(crate) fn run_task_with_options(
 ) -> Result<(), CargoMakeError> {
     let start_time = SystemTime::now();
 
-    // if a plugin is handling the task execution flow
-    if run_task_plugin(flow_info, flow_state.clone(), step, options) {
+    // if a plugin is handling the task execution flow (plugins are not invoked in dry run)
+    if !dry_run::is_enabled() && run_task_plugin(flow_info, flow_state.clone(), step, options) {
         time_summary::add(
             &mut flow_state.borrow_mut().time_summary,
             &step.name,
@@ -362,15 +598,29 @@ pub(crate) fn run_task_with_options(
         };
         envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
 
-        if validate_condition(
-            &flow_info,
-            &environment::expand_condition_script_runner_arguments(&step),
-        )? {
+        let outputs_up_to_date = task_outputs::is_up_to_date(&step.config);
+        let throttle_skip_reason = if outputs_up_to_date {
+            None
+        } else {
+            task_throttle::get_skip_reason(&step.name, &step.config)
+        };
+
+        if !outputs_up_to_date
+            && throttle_skip_reason.is_none()
+            && exit_codes::run_as(FailureClass::ConditionFailure, || {
+                validate_condition(
+                    flow_info,
+                    &environment::expand_condition_script_runner_arguments(step),
+                )
+            })?
+        {
             if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
-                debug!("Running Task: {}", &step.name);
+                debug!("Running Task: {}", logger::format_task_name(&step.name));
             } else {
-                info!("Running Task: {}", &step.name);
+                info!("Running Task: {}", logger::format_task_name(&step.name));
             }
+            let _task_group = ci_output::TaskGroup::start(&step.name);
+            let _progress = progress::ProgressIndicator::start(&step.name);
 
             if !step.config.is_valid() {
                 error!(
@@ -379,6 +629,8 @@ pub(crate) fn run_task_with_options(
                 );
             }
 
+            version::validate_task_min_version(step)?;
+
             let deprecated_info = step.config.deprecated.clone();
             match deprecated_info {
                 Some(deprecated) => match deprecated {
@@ -394,6 +646,20 @@ pub(crate) fn run_task_with_options(
 
                         ()
                     }
+                    DeprecationInfo::Details(ref details) => {
+                        let message = match details.message {
+                            Some(ref message) => format!(" - {}", message),
+                            None => "".to_string(),
+                        };
+
+                        match details.replaced_by {
+                            Some(ref replaced_by) => warn!(
+                                "Task: {} is deprecated{} (replaced by: {})",
+                                &step.name, message, replaced_by
+                            ),
+                            None => warn!("Task: {} is deprecated{}", &step.name, message),
+                        };
+                    }
                 },
                 None => (),
             };
@@ -401,10 +667,8 @@ pub(crate) fn run_task_with_options(
             //get profile
             let profile_name = profile::get();
 
-            match step.config.env_files {
-                Some(ref env_files) => environment::set_env_files(env_files.clone()),
-                None => (),
-            };
+            // task env files are unloaded once the task is done
+            let _env_files = environment::TaskEnvFiles::load(&step.config.env_files);
             match step.config.env {
                 Some(ref env) => environment::set_env(env.clone()),
                 None => (),
@@ -415,7 +679,39 @@ pub(crate) fn run_task_with_options(
 
             // modify step using env and functions
             let mut updated_step = functions::run(&step)?;
+            if let Some(ref cli_arguments) = flow_info.cli_arguments {
+                environment::apply_task_args(&mut updated_step.config, cli_arguments);
+            }
             updated_step = environment::expand_env(&updated_step);
+            step_outputs::expand_script(&mut updated_step.config);
+            if updated_step.config.nix_shell.is_none() {
+                updated_step.config.nix_shell = flow_info.config.config.nix_shell.clone();
+            }
+            policy::validate(&updated_step)?;
+
+            if dry_run::is_enabled() {
+                do_in_task_working_directory(step, || -> Result<bool, CargoMakeError> {
+                    let cwd = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
+                    let disable_install = flow_info.config.config.disable_install.unwrap_or(false);
+                    print!(
+                        "{}",
+                        dry_run::create_report(&updated_step, &cwd, disable_install)?
+                    );
+                    Ok(true)
+                })?;
+
+                // sub flows are printed as well
+                if let Some(ref sub_task) = step.config.run_task {
+                    run_sub_task(flow_info, flow_state, sub_task)?;
+                }
+
+                return Ok(());
+            }
+
+            if let Some(ref message) = updated_step.config.confirm {
+                let message = environment::expand_value(message);
+                prompt::confirm_task(&flow_info.config.config, &step.name, &message)?;
+            }
 
             let watch = should_watch(&step.config);
 
@@ -427,9 +723,13 @@ pub(crate) fn run_task_with_options(
                     step.config.watch.clone(),
                 )?;
             } else {
-                do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
-                    installer::install(&updated_step.config, flow_info, flow_state.clone())?;
-                    Ok(true)
+                perf::measure("Installation Checks", || {
+                    do_in_task_working_directory(step, || -> Result<bool, CargoMakeError> {
+                        exit_codes::run_as(FailureClass::InstallFailure, || {
+                            installer::install(&updated_step.config, flow_info, flow_state.clone())
+                        })?;
+                        Ok(true)
+                    })
                 })?;
 
                 match step.config.run_task {
@@ -441,20 +741,46 @@ pub(crate) fn run_task_with_options(
                         );
 
                         run_sub_task(&flow_info, flow_state, sub_task)?;
+                        task_outputs::validate(&step.name, &step.config)?;
+                        task_throttle::record(&step.name, &step.config);
                     }
                     None => {
                         do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
-                            // run script
-                            let script_runner_done = scriptengine::invoke(
-                                &updated_step.config,
-                                flow_info,
-                                flow_state.clone(),
-                            )?;
-
-                            // run command
-                            if !script_runner_done {
-                                command::run(&updated_step)?;
+                            let outputs_file = step_outputs::OutputsFile::create()?;
+
+                            let run_actions = || -> Result<(), CargoMakeError> {
+                                match updated_step.config.env_capture {
+                                    Some(ref env_capture) => {
+                                        let (result, stdout) = command::capture_stdout(|| {
+                                            run_task_actions(
+                                                &updated_step,
+                                                flow_info,
+                                                flow_state.clone(),
+                                            )
+                                        });
+                                        result?;
+
+                                        envmnt::set(env_capture, stdout.trim());
+                                        Ok(())
+                                    }
+                                    None => run_task_actions(
+                                        &updated_step,
+                                        flow_info,
+                                        flow_state.clone(),
+                                    ),
+                                }
                             };
+
+                            tee_output::tee_task(&step.name, || {
+                                match updated_step.config.output {
+                                    Some(ref redirection) => {
+                                        command::redirect_output(redirection, run_actions)?
+                                    }
+                                    None => run_actions(),
+                                }
+                            })?;
+
+                            outputs_file.collect(&step.name)?;
                             Ok(true)
                         })?;
 
@@ -463,9 +789,17 @@ pub(crate) fn run_task_with_options(
                             &step.name,
                             start_time,
                         );
+                        task_outputs::validate(&step.name, &step.config)?;
+                        task_throttle::record(&step.name, &step.config);
                     }
                 };
             }
+        } else if outputs_up_to_date {
+            info!("Skipping Task: {} (outputs are up to date)", &step.name);
+            status_summary::set_skipped("outputs are up to date");
+        } else if let Some(ref skip_reason) = throttle_skip_reason {
+            info!("Skipping Task: {} ({})", &step.name, skip_reason);
+            status_summary::set_skipped(skip_reason);
         } else {
             let fail_message = match step.config.condition {
                 Some(ref condition) => match condition.fail_message {
@@ -480,6 +814,12 @@ pub(crate) fn run_task_with_options(
             } else {
                 info!("Skipping Task: {} {}", &step.name, &fail_message);
             }
+
+            if fail_message.is_empty() {
+                status_summary::set_skipped("conditions not met");
+            } else {
+                status_summary::set_skipped(&fail_message);
+            }
         }
     } else {
         debug!("Ignoring Empty Task: {}", &step.name);
@@ -508,7 +848,6 @@ fn create_watch_task(task: &str, options: Option<TaskWatchOptions>, flow_info: &
         "CARGO_MAKE_DISABLE_WATCH".to_string(),
         EnvValue::Value("true".to_string()),
     );
-    task_config.env = Some(env_map);
 
     let make_args = task_config.args.unwrap();
     let mut make_command = String::new();
@@ -572,6 +911,15 @@ fn create_watch_task(task: &str, options: Option<TaskWatchOptions>, flow_info: &
                     }
                 }
 
+                if let Some(option_value) = watch_options.targeted {
+                    if option_value {
+                        env_map.insert(
+                            "CARGO_MAKE_WATCH_TARGETED".to_string(),
+                            EnvValue::Value("true".to_string()),
+                        );
+                    }
+                }
+
                 match watch_options.watch {
                     Some(paths) => {
                         for watch_path in paths {
@@ -589,6 +937,7 @@ fn create_watch_task(task: &str, options: Option<TaskWatchOptions>, flow_info: &
     watch_args.extend_from_slice(&["-x".to_string(), make_command.to_string()]);
 
     task_config.args = Some(watch_args);
+    task_config.env = Some(env_map);
 
     task_config
 }
@@ -607,19 +956,41 @@ pub(crate) fn run_flow(
         sub_flow,
         skip_tasks_pattern: flow_info.skip_tasks_pattern.as_ref(),
         skip_init_end_tasks: flow_info.skip_init_end_tasks,
+        from_step: flow_info.from_step.as_deref(),
+        skip_steps: flow_info.skip_steps.as_deref(),
         ..ExecutionPlanBuilder::new(&flow_info.config, &flow_info.task)
     }
     .build()?;
     debug!("Created execution plan: {:#?}", &execution_plan);
+    dashboard::add_steps(
+        &execution_plan
+            .steps
+            .iter()
+            .map(|step| step.name.to_string())
+            .collect::<Vec<String>>(),
+    );
+
+    task_inputs::setup_env(&execution_plan)?;
 
     run_task_flow(&flow_info, flow_state, &execution_plan)?;
 
     Ok(())
 }
 
+/// Returns the on error task of the last active profile which defines one in the
+/// profile_on_error_tasks config, otherwise the global on_error_task
+fn get_on_error_task(config: &ConfigSection) -> Option<String> {
+    config
+        .profile_on_error_tasks
+        .as_ref()
+        .and_then(profile::get_value_for_profiles)
+        .or(config.on_error_task.clone())
+}
+
 fn run_protected_flow(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
+    start_time: SystemTime,
 ) -> Result<(), CargoMakeError> {
     let proxy_task = create_proxy_task(
         &flow_info.task,
@@ -632,18 +1003,25 @@ fn run_protected_flow(
     let exit_code = command::run_command(&proxy_task.command.unwrap(), &proxy_task.args, false)?;
 
     if exit_code != 0 {
-        match flow_info.config.config.on_error_task {
-            Some(ref on_error_task) => {
-                let mut error_flow_info = flow_info.clone();
-                error_flow_info.disable_on_error = true;
-                error_flow_info.task = on_error_task.clone();
+        let error_message = format!("Task error detected, exit code: {}", &exit_code);
+        notification::notify(
+            &flow_info.config,
+            &flow_info.task,
+            start_time,
+            Some(error_message.clone()),
+        );
 
-                run_flow(&error_flow_info, flow_state, false)?;
-            }
-            _ => (),
-        };
+        if let Some(on_error_task) = get_on_error_task(&flow_info.config.config) {
+            let mut error_flow_info = flow_info.clone();
+            error_flow_info.disable_on_error = true;
+            error_flow_info.task = on_error_task;
+            error_flow_info.from_step = None;
+            error_flow_info.skip_steps = None;
+
+            run_flow(&error_flow_info, flow_state, false)?;
+        }
 
-        error!("Task error detected, exit code: {}", &exit_code);
+        error!("{}", &error_message);
     }
     Ok(())
 }
@@ -662,6 +1040,12 @@ pub fn run(
     time_summary_vec: Vec<(String, u128)>,
 ) -> Result<(), CargoMakeError> {
     time_summary::init(&config, &cli_args);
+    status_summary::init(&config, cli_args);
+    task_throttle::init(cli_args);
+    progress::init(&config, cli_args);
+    dashboard::init(cli_args);
+    output::init(&config);
+    tee_output::init(&config);
 
     let skip_tasks_pattern = match cli_args.skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
@@ -683,17 +1067,57 @@ pub fn run(
         allow_private: cli_args.allow_private,
         skip_init_end_tasks: cli_args.skip_init_end_tasks,
         skip_tasks_pattern,
+        from_step: cli_args.from_step.clone(),
+        skip_steps: cli_args.skip_steps.clone(),
         cli_arguments: cli_args.arguments.clone(),
     };
+    let flaky_tasks = history::load_flaky_tasks(&flow_info.config);
+
     let mut flow_state = FlowState::new();
     flow_state.time_summary = time_summary_vec;
+    flow_state.flaky_tasks = flaky_tasks
+        .iter()
+        .map(|flaky_task| flaky_task.name.to_string())
+        .collect();
 
     let flow_state_rc = Rc::new(RefCell::new(flow_state));
 
-    if flow_info.disable_on_error || flow_info.config.config.on_error_task.is_none() {
-        run_flow(&flow_info, flow_state_rc.clone(), false)?;
-    } else {
-        run_protected_flow(&flow_info, flow_state_rc.clone())?;
+    let flow_result =
+        if flow_info.disable_on_error || get_on_error_task(&flow_info.config.config).is_none() {
+            run_flow(&flow_info, flow_state_rc.clone(), false)
+        } else {
+            run_protected_flow(&flow_info, flow_state_rc.clone(), start_time)
+        };
+    if !flaky_tasks.is_empty() {
+        let mut invoked_tasks: Vec<String> = flow_state_rc
+            .borrow()
+            .time_summary
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        if flow_result.is_err() {
+            invoked_tasks.push(envmnt::get_or("CARGO_MAKE_CURRENT_TASK_NAME", task));
+        }
+
+        history::print_flaky_summary(&flaky_tasks, &invoked_tasks);
+    }
+
+    if let Err(error) = flow_result {
+        let task_name = envmnt::get_or("CARGO_MAKE_CURRENT_TASK_NAME", task);
+        let error_message = format!("Task: {} failed - {}", &task_name, &error);
+        ci_output::annotate_error(&error_message, None, None);
+        let message = error.to_string();
+        diagnostics::report_error(
+            &diagnostics::get_error_code(&error),
+            &message,
+            None,
+            None,
+            Some(&task_name),
+        );
+        status_summary::print(&flow_state_rc.borrow().status_summary);
+        notification::notify(&flow_info.config, task, start_time, Some(error_message));
+
+        return Err(error);
     }
 
     let time_string = match start_time.elapsed() {
@@ -705,8 +1129,11 @@ pub fn run(
     };
 
     time_summary::print(&flow_state_rc.borrow().time_summary);
+    status_summary::print(&flow_state_rc.borrow().status_summary);
 
     info!("Build Done{}.", &time_string);
 
+    notification::notify(&flow_info.config, task, start_time, None);
+
     Ok(())
 }
@@ -3,8 +3,8 @@ use std::env::VarError;
 use super::*;
 use crate::test;
 use crate::types::{
-    ConditionScriptValue, ConfigSection, CrateInfo, EnvFile, RunTaskDetails, ScriptValue,
-    TaskCondition,
+    ConditionScriptValue, ConfigSection, CrateInfo, EnvFile, RunTaskDetails, RunTaskPipe,
+    ScriptValue, StepStatusInfo, TaskCondition,
 };
 use cfg_if::cfg_if;
 use git_info::types::GitInfo;
@@ -24,6 +24,12 @@ fn run_flow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -45,6 +51,8 @@ fn run_flow_private() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -61,6 +69,12 @@ fn run_flow_private_skipped() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -82,6 +96,8 @@ fn run_flow_private_skipped() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: Some(Regex::new("test").unwrap()),
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -98,6 +114,12 @@ fn run_flow_private_sub_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -119,6 +141,8 @@ fn run_flow_private_sub_task() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -135,6 +159,12 @@ fn run_flow_allow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
 
     let mut task = Task::new();
@@ -156,6 +186,8 @@ fn run_flow_allow_private() {
         allow_private: true,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -173,6 +205,12 @@ fn run_task_bad_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -188,6 +226,8 @@ fn run_task_bad_script() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -213,6 +253,12 @@ fn run_task_script_with_args_error() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -228,6 +274,8 @@ fn run_task_script_with_args_error() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: Some(vec!["1".to_string()]),
     };
 
@@ -252,6 +300,12 @@ fn run_task_script_with_args_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -267,6 +321,8 @@ fn run_task_script_with_args_valid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: Some(vec!["0".to_string()]),
     };
 
@@ -290,6 +346,12 @@ fn run_task_command() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -305,6 +367,8 @@ fn run_task_command() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -352,6 +416,12 @@ fn run_task_bad_command_valid_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -367,6 +437,8 @@ fn run_task_bad_command_valid_script() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -391,6 +463,12 @@ fn run_task_no_command_valid_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -406,6 +484,8 @@ fn run_task_no_command_valid_script() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -436,6 +516,12 @@ fn run_task_bad_run_task_valid_command() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -451,6 +537,8 @@ fn run_task_bad_run_task_valid_command() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -482,6 +570,12 @@ fn run_task_valid_run_task() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -497,6 +591,8 @@ fn run_task_valid_run_task() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -521,6 +617,12 @@ fn run_task_invalid_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -536,6 +638,8 @@ fn run_task_invalid_task() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -560,6 +664,12 @@ fn run_task_set_env_file() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -575,6 +685,8 @@ fn run_task_set_env_file() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -615,6 +727,12 @@ fn run_task_set_env() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -630,6 +748,8 @@ fn run_task_set_env() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -655,6 +775,117 @@ fn run_task_set_env() {
     assert_eq!(envmnt::get_or_panic("TEST_RUN_TASK_SET_ENV"), "VALID");
 }
 
+#[test]
+fn run_task_env_capture() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo \"  captured value  \"".to_string()
+    ]));
+    task.env_capture = Some("TEST_RUN_TASK_ENV_CAPTURE".to_string());
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::remove("TEST_RUN_TASK_ENV_CAPTURE");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        envmnt::get_or_panic("TEST_RUN_TASK_ENV_CAPTURE"),
+        "captured value"
+    );
+}
+
+#[test]
+fn run_task_step_outputs() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo \"value=output value\" >> \"$CARGO_MAKE_OUTPUT\"".to_string(),
+    ]));
+
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    envmnt::remove("tasks.test.outputs.value");
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        envmnt::get_or_panic("tasks.test.outputs.value"),
+        "output value"
+    );
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -666,6 +897,12 @@ fn run_task_cwd_no_such_dir() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -681,6 +918,8 @@ fn run_task_cwd_no_such_dir() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -705,6 +944,12 @@ fn run_task_cwd_dir_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -720,6 +965,8 @@ fn run_task_cwd_dir_exists() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -744,6 +991,12 @@ fn run_task_cwd_env_expansion() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -759,6 +1012,8 @@ fn run_task_cwd_env_expansion() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -783,6 +1038,12 @@ fn run_task_deprecated_message() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -798,6 +1059,8 @@ fn run_task_deprecated_message() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -845,6 +1108,12 @@ fn run_task_deprecated_flag() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -860,6 +1129,8 @@ fn run_task_deprecated_flag() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -919,6 +1190,8 @@ fn run_task_failed_condition_script_doesnt_change_env() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1193,6 +1466,7 @@ fn create_watch_task_with_makefile_and_empty_object_options() {
         no_git_ignore: None,
         why: None,
         watch: None,
+        targeted: None,
     };
 
     let flow_info = test::create_empty_flow_info();
@@ -1245,6 +1519,7 @@ fn create_watch_task_with_makefile_and_all_object_options_and_cli_args() {
         no_git_ignore: Some(true),
         why: Some(true),
         watch: Some(vec!["dir1".to_string(), "dir2".to_string()]),
+        targeted: None,
     };
 
     let mut flow_info = test::create_empty_flow_info();
@@ -1308,6 +1583,7 @@ fn create_watch_task_with_makefile_and_all_object_options() {
         no_git_ignore: Some(true),
         why: Some(true),
         watch: Some(vec!["dir1".to_string(), "dir2".to_string()]),
+        targeted: Some(true),
     };
 
     let flow_info = test::create_empty_flow_info();
@@ -1323,6 +1599,16 @@ fn create_watch_task_with_makefile_and_all_object_options() {
     assert_eq!(install_crate_args[0], "--version");
     assert_eq!(install_crate_args[1], "100.200.300.400");
 
+    match task
+        .env
+        .as_ref()
+        .unwrap()
+        .get("CARGO_MAKE_WATCH_TARGETED")
+        .unwrap()
+    {
+        EnvValue::Value(value) => assert_eq!(value, "true"),
+        _ => panic!("CARGO_MAKE_WATCH_TARGETED not defined."),
+    };
     match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
         EnvValue::Value(value) => assert_eq!(value, "true"),
         _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
@@ -1370,6 +1656,7 @@ fn create_watch_task_with_makefile_and_false_object_options() {
         no_git_ignore: Some(false),
         why: Some(false),
         watch: None,
+        targeted: Some(false),
     };
 
     let flow_info = test::create_empty_flow_info();
@@ -1383,6 +1670,11 @@ fn create_watch_task_with_makefile_and_false_object_options() {
     assert!(task.install_crate_args.is_some());
     assert_eq!(task.install_crate_args.unwrap().len(), 2);
 
+    assert!(!task
+        .env
+        .as_ref()
+        .unwrap()
+        .contains_key("CARGO_MAKE_WATCH_TARGETED"));
     match task.env.unwrap().get("CARGO_MAKE_DISABLE_WATCH").unwrap() {
         EnvValue::Value(value) => assert_eq!(value, "true"),
         _ => panic!("CARGO_MAKE_DISABLE_WATCH not defined."),
@@ -1444,6 +1736,12 @@ fn run_sub_task_and_report_for_name() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1459,6 +1757,8 @@ fn run_sub_task_and_report_for_name() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1491,6 +1791,12 @@ fn run_sub_task_and_report_for_name_not_found() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1506,6 +1812,8 @@ fn run_sub_task_and_report_for_name_not_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1535,6 +1843,12 @@ fn run_sub_task_and_report_for_details_single() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1550,6 +1864,8 @@ fn run_sub_task_and_report_for_details_single() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1558,6 +1874,7 @@ fn run_sub_task_and_report_for_details_single() {
         fork: Some(false),
         parallel: None,
         cleanup_task: None,
+        args: None,
     });
 
     let output = run_sub_task_and_report(
@@ -1587,6 +1904,12 @@ fn run_sub_task_and_report_for_details_multiple() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1602,6 +1925,8 @@ fn run_sub_task_and_report_for_details_multiple() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1610,6 +1935,7 @@ fn run_sub_task_and_report_for_details_multiple() {
         fork: Some(false),
         parallel: None,
         cleanup_task: None,
+        args: None,
     });
 
     let output = run_sub_task_and_report(
@@ -1638,6 +1964,12 @@ fn run_sub_task_and_report_routing_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1653,6 +1985,8 @@ fn run_sub_task_and_report_routing_empty() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1668,6 +2002,116 @@ fn run_sub_task_and_report_routing_empty() {
     assert!(!output);
 }
 
+#[test]
+fn run_sub_task_and_report_pipe_empty() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec!["echo test".to_string()]));
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("test".to_string(), task);
+
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    let sub_task = RunTaskInfo::Pipe(RunTaskPipe { pipe: vec![] });
+
+    let output = run_sub_task_and_report(
+        &flow_info,
+        Rc::new(RefCell::new(FlowState::new())),
+        &sub_task,
+    )
+    .unwrap();
+
+    assert!(!output);
+}
+
+#[test]
+#[ignore]
+fn run_sub_task_and_report_pipe() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec!["echo test".to_string()]));
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("test1".to_string(), task.clone());
+    tasks.insert("test2".to_string(), task.clone());
+
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    let sub_task = RunTaskInfo::Pipe(RunTaskPipe {
+        pipe: vec!["test1".to_string(), "test2".to_string()],
+    });
+
+    let output = run_sub_task_and_report(
+        &flow_info,
+        Rc::new(RefCell::new(FlowState::new())),
+        &sub_task,
+    )
+    .unwrap();
+
+    assert!(output);
+}
+
 #[test]
 #[ignore]
 fn run_sub_task_and_report_routing_no_condition() {
@@ -1684,6 +2128,12 @@ fn run_sub_task_and_report_routing_no_condition() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1699,6 +2149,8 @@ fn run_sub_task_and_report_routing_no_condition() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1738,6 +2190,12 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1753,6 +2211,8 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1811,6 +2271,12 @@ fn run_sub_task_and_report_routing_not_found() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1826,6 +2292,8 @@ fn run_sub_task_and_report_routing_not_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1864,6 +2332,12 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1879,6 +2353,8 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1887,6 +2363,7 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         fork: Some(false),
         parallel: None,
         cleanup_task: Some("test".to_string()),
+        args: None,
     });
 
     run_sub_task_and_report(
@@ -1906,6 +2383,12 @@ fn get_sub_task_info_for_routing_info_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1921,6 +2404,8 @@ fn get_sub_task_info_for_routing_info_empty() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -1942,6 +2427,12 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -1957,6 +2448,8 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2007,6 +2500,12 @@ fn get_sub_task_info_for_routing_info_condition_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2022,6 +2521,8 @@ fn get_sub_task_info_for_routing_info_condition_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2072,6 +2573,12 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2087,6 +2594,8 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2137,6 +2646,12 @@ fn get_sub_task_info_for_routing_info_script_not_met() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2152,6 +2667,8 @@ fn get_sub_task_info_for_routing_info_script_not_met() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2184,6 +2701,12 @@ fn get_sub_task_info_for_routing_info_script_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2199,6 +2722,8 @@ fn get_sub_task_info_for_routing_info_script_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2231,6 +2756,12 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2246,6 +2777,8 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2307,6 +2840,12 @@ fn get_sub_task_info_for_routing_info_default() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2322,6 +2861,8 @@ fn get_sub_task_info_for_routing_info_default() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2392,6 +2933,12 @@ fn get_sub_task_info_for_routing_info_multiple() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2407,6 +2954,8 @@ fn get_sub_task_info_for_routing_info_multiple() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2486,6 +3035,12 @@ fn get_sub_task_info_for_routing_info_fork_false() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2501,6 +3056,8 @@ fn get_sub_task_info_for_routing_info_fork_false() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2551,6 +3108,12 @@ fn get_sub_task_info_for_routing_info_fork_true() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2566,6 +3129,8 @@ fn get_sub_task_info_for_routing_info_fork_true() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2616,6 +3181,12 @@ fn create_fork_step_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2631,6 +3202,8 @@ fn create_fork_step_valid() {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2698,6 +3271,12 @@ fn run_flow_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     let flow_info = FlowInfo {
         config,
@@ -2713,6 +3292,8 @@ fn run_flow_skip_init_end_tasks() {
         allow_private: false,
         skip_init_end_tasks: true,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     };
 
@@ -2730,3 +3311,285 @@ fn run_flow_skip_init_end_tasks() {
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
 }
+
+#[test]
+#[ignore]
+#[cfg(target_os = "linux")]
+fn run_task_on_error_task() {
+    envmnt::remove("CARGO_MAKE_TEST_RUNNER_ON_ERROR_TASK");
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_TEST_RUNNER_ON_ERROR_TASK".to_string(),
+        EnvValue::Value("invoked".to_string()),
+    );
+    let mut on_error_task = Task::new();
+    on_error_task.command = Some("echo".to_string());
+    on_error_task.env = Some(env);
+
+    let mut tasks = IndexMap::new();
+    tasks.insert("catch".to_string(), on_error_task);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec!["exit 1".to_string()]));
+    task.on_error_task = Some("catch".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step);
+
+    assert!(result.is_err());
+    assert_eq!(
+        envmnt::get_or_panic("CARGO_MAKE_TEST_RUNNER_ON_ERROR_TASK"),
+        "invoked"
+    );
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_CURRENT_TASK_NAME"), "test");
+}
+
+fn run_flaky_task(retries: Option<u32>, flaky: bool, directory_name: &str) -> bool {
+    let directory = test::get_temp_test_directory(directory_name);
+    let marker = directory.join("marker");
+
+    let mut config_section = ConfigSection::new();
+    config_section.flaky_task_retries = retries;
+    let config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+    let flow_info = FlowInfo {
+        config,
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+
+    // fails only on the first invocation
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![format!(
+        "test -f {0} || {{ touch {0}; exit 1; }}",
+        marker.to_string_lossy()
+    )]));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let mut flow_state = FlowState::new();
+    if flaky {
+        flow_state.flaky_tasks = vec!["test".to_string()];
+    }
+
+    run_task(&flow_info, Rc::new(RefCell::new(flow_state)), &step).is_ok()
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_task_flaky_task_retried() {
+    assert!(run_flaky_task(Some(1), true, "runner_flaky_task_retried"));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_task_flaky_task_no_retries() {
+    assert!(!run_flaky_task(None, true, "runner_flaky_task_no_retries"));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_task_not_flaky_task_not_retried() {
+    assert!(!run_flaky_task(
+        Some(1),
+        false,
+        "runner_not_flaky_task_not_retried"
+    ));
+}
+
+#[test]
+fn get_on_error_task_none() {
+    let config_section = ConfigSection::new();
+
+    assert!(get_on_error_task(&config_section).is_none());
+}
+
+#[test]
+#[ignore]
+fn get_on_error_task_profiles() {
+    let mut profile_on_error_tasks = IndexMap::new();
+    profile_on_error_tasks.insert("ci".to_string(), "upload-logs".to_string());
+    profile_on_error_tasks.insert("gpu".to_string(), "dump-gpu-state".to_string());
+    let mut config_section = ConfigSection::new();
+    config_section.on_error_task = Some("print-hint".to_string());
+    config_section.profile_on_error_tasks = Some(profile_on_error_tasks);
+
+    profile::set("development");
+    profile::set_all(&vec!["development".to_string()]);
+    assert_eq!(get_on_error_task(&config_section).unwrap(), "print-hint");
+
+    profile::set("ci");
+    profile::set_all(&vec!["ci".to_string()]);
+    assert_eq!(get_on_error_task(&config_section).unwrap(), "upload-logs");
+
+    profile::set_all(&vec!["ci".to_string(), "gpu".to_string()]);
+    assert_eq!(
+        get_on_error_task(&config_section).unwrap(),
+        "dump-gpu-state"
+    );
+
+    envmnt::remove("CARGO_MAKE_PROFILES");
+    profile::set(&profile::default_profile());
+}
+
+fn run_task_get_status_summary(task: Task) -> (bool, Vec<StepStatusInfo>) {
+    let flow_info = FlowInfo {
+        config: Config::default(),
+        task: "test".to_string(),
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
+        cli_arguments: None,
+    };
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let flow_state = Rc::new(RefCell::new(FlowState::new()));
+    let result = run_task(&flow_info, flow_state.clone(), &step);
+
+    let status_summary = flow_state.borrow().status_summary.clone();
+    (result.is_ok(), status_summary)
+}
+
+#[test]
+fn run_task_status_summary_success() {
+    let mut task = Task::new();
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::SingleLine("echo test".to_string()));
+
+    let (success, status_summary) = run_task_get_status_summary(task);
+
+    assert!(success);
+    assert_eq!(status_summary.len(), 1);
+    assert_eq!(status_summary[0].name, "test");
+    assert_eq!(status_summary[0].status, StepStatus::Success);
+    assert!(status_summary[0].skip_reason.is_none());
+}
+
+#[test]
+fn run_task_status_summary_skipped() {
+    let mut task = Task::new();
+    task.condition_script = Some(ConditionScriptValue::SingleLine("exit 1".to_string()));
+    task.script_runner = Some("@duckscript".to_string());
+    task.script = Some(ScriptValue::SingleLine("echo test".to_string()));
+
+    let (success, status_summary) = run_task_get_status_summary(task);
+
+    assert!(success);
+    assert_eq!(status_summary.len(), 1);
+    assert_eq!(status_summary[0].status, StepStatus::Skipped);
+    assert_eq!(
+        status_summary[0].skip_reason,
+        Some("conditions not met".to_string())
+    );
+}
+
+#[test]
+fn run_task_status_summary_ignored_error() {
+    let mut task = Task::new();
+    task.ignore_errors = Some(true);
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["badcommand".to_string()]);
+
+    let (success, status_summary) = run_task_get_status_summary(task);
+
+    assert!(success);
+    assert_eq!(status_summary.len(), 1);
+    assert_eq!(status_summary[0].status, StepStatus::IgnoredError);
+}
+
+#[test]
+fn run_task_status_summary_failed() {
+    let mut task = Task::new();
+    task.command = Some("cargo".to_string());
+    task.args = Some(vec!["badcommand".to_string()]);
+
+    let (success, status_summary) = run_task_get_status_summary(task);
+
+    assert!(!success);
+    assert_eq!(status_summary.len(), 1);
+    assert_eq!(status_summary[0].status, StepStatus::Failed);
+}
+
+#[test]
+fn run_task_status_summary_empty_task() {
+    let (success, status_summary) = run_task_get_status_summary(Task::new());
+
+    assert!(success);
+    assert!(status_summary.is_empty());
+}
@@ -0,0 +1,38 @@
+//! # cm_bench_record
+//!
+//! Enables to store and compare the benchmark results from within duckscript.
+//!
+
+use crate::bench;
+use crate::types::FlowInfo;
+use duckscript::types::command::{Command, CommandInvocationContext, CommandResult};
+
+#[derive(Clone)]
+pub(crate) struct CommandImpl {
+    flow_info: FlowInfo,
+}
+
+impl Command for CommandImpl {
+    fn name(&self) -> String {
+        "cm_bench_record".to_string()
+    }
+
+    fn clone_and_box(&self) -> Box<dyn Command> {
+        Box::new((*self).clone())
+    }
+
+    fn run(&self, context: CommandInvocationContext) -> CommandResult {
+        let output = context.arguments.join("\n");
+
+        match bench::record(self.flow_info.config.bench.as_ref(), &output) {
+            Ok(_) => CommandResult::Continue(Some("true".to_string())),
+            Err(error) => CommandResult::Error(error.to_string()),
+        }
+    }
+}
+
+pub(crate) fn create(flow_info: &FlowInfo) -> Box<dyn Command> {
+    Box::new(CommandImpl {
+        flow_info: flow_info.clone(),
+    })
+}
@@ -3,6 +3,7 @@
 //! Loads the cargo-make duckscript SDK.
 //!
 
+mod cm_bench_record;
 mod cm_run_task;
 
 use crate::types::{FlowInfo, FlowState};
@@ -18,6 +19,7 @@ pub(crate) fn load(
     flow_state_option: Option<Rc<RefCell<FlowState>>>,
 ) -> Result<(), ScriptError> {
     if let (Some(flow_info), Some(flow_state)) = (flow_info_option, flow_state_option) {
+        commands.set(cm_bench_record::create(flow_info))?;
         commands.set(cm_run_task::create(flow_info, flow_state))?;
     }
 
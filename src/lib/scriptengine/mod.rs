@@ -15,14 +15,28 @@ mod shell_to_batch;
 #[path = "mod_test.rs"]
 mod mod_test;
 
+use crate::command;
+use crate::container;
 use crate::environment;
 use crate::error::CargoMakeError;
 use crate::io;
+use crate::nix;
+use crate::status_summary;
 use crate::toolchain;
-use crate::types::{FlowInfo, FlowState, ScriptValue, Task};
+use crate::types::{CommandSpec, FlowInfo, FlowState, ScriptCommand, ScriptValue, Task};
 use std::cell::RefCell;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Duration;
+
+#[cfg(target_os = "windows")]
+static DEFAULT_OS_RUNNER: &str = "cmd.exe";
+#[cfg(not(target_os = "windows"))]
+static DEFAULT_OS_RUNNER: &str = "sh";
+#[cfg(target_os = "windows")]
+static DEFAULT_OS_EXTENSION: &str = "cmd";
+#[cfg(not(target_os = "windows"))]
+static DEFAULT_OS_EXTENSION: &str = "sh";
 
 #[derive(Debug, Clone, PartialEq)]
 /// The currently supported engine types
@@ -86,6 +100,10 @@ pub(crate) fn get_script_text(script: &ScriptValue) -> Result<Vec<String>, Cargo
 
             Ok(script_lines)
         }
+        ScriptValue::Commands(commands) => Ok(commands
+            .iter()
+            .map(|script_command| script_command.cmd.clone())
+            .collect()),
     }
 }
 
@@ -157,6 +175,109 @@ pub(crate) fn get_engine_type(
     }
 }
 
+/// Returns the script runner which invokes the task script (None if the task has no script).<br>
+/// Internal engines are returned with their @ prefix (for example @duckscript) and for
+/// env based shebang lines (#!/usr/bin/env python) the interpreter is returned.
+pub(crate) fn get_script_runner_name(task: &Task) -> Result<Option<String>, CargoMakeError> {
+    let script = match task.script {
+        Some(ref script) => script,
+        None => return Ok(None),
+    };
+
+    let runner = match get_engine_type(script, &task.script_runner, &task.script_extension)? {
+        EngineType::OS | EngineType::Generic => task
+            .script_runner
+            .clone()
+            .unwrap_or(DEFAULT_OS_RUNNER.to_string()),
+        EngineType::Duckscript => "@duckscript".to_string(),
+        EngineType::Rust => "@rust".to_string(),
+        EngineType::Shell2Batch => "@shell".to_string(),
+        EngineType::Shebang => {
+            let shebang = shebang_script::get_shebang(&get_script_text(script)?);
+            let runner = shebang.runner.unwrap_or_default();
+
+            match shebang.arguments {
+                Some(ref arguments) if runner.ends_with("/env") && !arguments.is_empty() => {
+                    arguments[0].to_string()
+                }
+                _ => runner,
+            }
+        }
+        EngineType::Unsupported => return Ok(None),
+    };
+
+    Ok(Some(runner))
+}
+
+/// The script runner, runner args and script extension
+type ScriptRunnerInfo = (Option<String>, Option<Vec<String>>, Option<String>);
+
+/// Returns the script runner, runner args and extension which run the script via nix develop
+/// and/or inside a container.<br>
+/// Internal engines (such as duckscript) are not wrapped.
+fn get_wrapped_script_runner(
+    script: &ScriptValue,
+    task: &Task,
+) -> Result<ScriptRunnerInfo, CargoMakeError> {
+    let unwrapped = (
+        task.script_runner.clone(),
+        task.script_runner_args.clone(),
+        task.script_extension.clone(),
+    );
+
+    let flake = nix::get_flake(task);
+    if flake.is_none() && task.container.is_none() {
+        return Ok(unwrapped);
+    }
+
+    let expanded_script_runner = task
+        .script_runner
+        .as_ref()
+        .map(|value| environment::expand_value(value));
+    let engine_type = get_engine_type(script, &expanded_script_runner, &task.script_extension)?;
+
+    let (runner, runner_args) = match engine_type {
+        EngineType::OS => (
+            expanded_script_runner.unwrap_or(DEFAULT_OS_RUNNER.to_string()),
+            None,
+        ),
+        EngineType::Generic => (
+            expanded_script_runner.unwrap(),
+            task.script_runner_args.clone(),
+        ),
+        EngineType::Shebang => {
+            let shebang = shebang_script::get_shebang(&get_script_text(script)?);
+            (shebang.runner.unwrap(), shebang.arguments)
+        }
+        _ => {
+            warn!("Internal script runners do not support nix_shell and container, running script as is.");
+            return Ok(unwrapped);
+        }
+    };
+
+    let mut command_spec = CommandSpec {
+        command: runner,
+        args: runner_args,
+    };
+    if let Some(flake) = flake {
+        command_spec = nix::wrap_command(&flake, command_spec);
+    }
+    if let Some(ref container_info) = task.container {
+        command_spec = container::wrap_command(container_info, command_spec);
+    }
+
+    let extension = task
+        .script_extension
+        .clone()
+        .unwrap_or(DEFAULT_OS_EXTENSION.to_string());
+
+    Ok((
+        Some(command_spec.command),
+        command_spec.args,
+        Some(extension),
+    ))
+}
+
 pub(crate) fn invoke(
     task: &Task,
     flow_info: &FlowInfo,
@@ -175,11 +296,14 @@ pub(crate) fn invoke(
                 None => (false, None),
             };
 
+            let (script_runner, script_runner_args, script_extension) =
+                get_wrapped_script_runner(script, task)?;
+
             let output = invoke_script_in_flow_context(
                 script,
-                task.script_runner.clone(),
-                task.script_runner_args.clone(),
-                task.script_extension.clone(),
+                script_runner,
+                script_runner_args,
+                script_extension,
                 validate,
                 Some(flow_info),
                 Some(flow_state),
@@ -192,6 +316,10 @@ pub(crate) fn invoke(
                 }
             }
 
+            if !validate && matches!(output, Ok(false)) {
+                status_summary::set_ignored_error();
+            }
+
             output
         }
         None => Ok(false),
@@ -247,6 +375,114 @@ pub(crate) fn invoke_script_pre_flow(
     )
 }
 
+/// Invokes a single script command, retrying it in case it fails
+fn invoke_script_command(
+    script_command: &ScriptCommand,
+    script_runner: Option<String>,
+    script_runner_args: Option<Vec<String>>,
+    script_extension: Option<String>,
+    flow_info: Option<&FlowInfo>,
+    flow_state: Option<Rc<RefCell<FlowState>>>,
+    cli_arguments: &Vec<String>,
+) -> Result<bool, CargoMakeError> {
+    let script = ScriptValue::SingleLine(script_command.cmd.clone());
+    let attempts = script_command.retries.unwrap_or(0) + 1;
+
+    for attempt in 1..=attempts {
+        if attempt > 1 {
+            warn!(
+                "Retrying command: {} (attempt {} of {})",
+                &script_command.cmd, attempt, attempts
+            );
+        }
+
+        let success = match script_command.timeout {
+            Some(timeout) => {
+                let engine_type = get_engine_type(&script, &script_runner, &script_extension)?;
+
+                if engine_type == EngineType::OS {
+                    command::with_script_timeout(Duration::from_secs(timeout), || {
+                        invoke_script(
+                            &script,
+                            script_runner.clone(),
+                            script_runner_args.clone(),
+                            script_extension.clone(),
+                            false,
+                            flow_info,
+                            flow_state.clone(),
+                            cli_arguments,
+                        )
+                    })?
+                } else {
+                    warn!("Command timeout is only supported for OS scripts.");
+                    invoke_script(
+                        &script,
+                        script_runner.clone(),
+                        script_runner_args.clone(),
+                        script_extension.clone(),
+                        false,
+                        flow_info,
+                        flow_state.clone(),
+                        cli_arguments,
+                    )?
+                }
+            }
+            None => invoke_script(
+                &script,
+                script_runner.clone(),
+                script_runner_args.clone(),
+                script_extension.clone(),
+                false,
+                flow_info,
+                flow_state.clone(),
+                cli_arguments,
+            )?,
+        };
+
+        if success {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Invokes each script command separately based on its own error handling attributes.<br>
+/// Stops at the first failed command which does not ignore errors and returns false.
+fn invoke_script_commands(
+    commands: &[ScriptCommand],
+    script_runner: Option<String>,
+    script_runner_args: Option<Vec<String>>,
+    script_extension: Option<String>,
+    flow_info: Option<&FlowInfo>,
+    flow_state: Option<Rc<RefCell<FlowState>>>,
+    cli_arguments: &Vec<String>,
+) -> Result<bool, CargoMakeError> {
+    for script_command in commands {
+        let success = invoke_script_command(
+            script_command,
+            script_runner.clone(),
+            script_runner_args.clone(),
+            script_extension.clone(),
+            flow_info,
+            flow_state.clone(),
+            cli_arguments,
+        )?;
+
+        if !success {
+            if script_command.ignore_errors.unwrap_or(false) {
+                warn!("Ignoring failed command: {}", &script_command.cmd);
+                status_summary::set_ignored_error();
+            } else {
+                warn!("Command failed: {}", &script_command.cmd);
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
 fn invoke_script(
     script: &ScriptValue,
     script_runner: Option<String>,
@@ -261,6 +497,24 @@ fn invoke_script(
         Some(ref value) => Some(environment::expand_value(value)),
         None => None,
     };
+    if let ScriptValue::Commands(ref commands) = script {
+        let success = invoke_script_commands(
+            commands,
+            expanded_script_runner,
+            script_runner_args,
+            script_extension,
+            flow_info,
+            flow_state,
+            cli_arguments,
+        )?;
+
+        if validate && !success {
+            return Err(CargoMakeError::ExitCodeValidation);
+        }
+
+        return Ok(success);
+    }
+
     let engine_type = get_engine_type(script, &expanded_script_runner, &script_extension)?;
 
     match engine_type {
@@ -1,6 +1,6 @@
 use super::*;
 use crate::test;
-use crate::types::{FileScriptValue, ScriptSections};
+use crate::types::{FileScriptValue, ScriptCommand, ScriptSections};
 
 #[test]
 fn get_script_text_single_line() {
@@ -442,3 +442,119 @@ fn invoke_shell_to_batch_runner_with_env_expansion_no_env() {
 
     assert!(output);
 }
+
+#[test]
+fn get_script_text_commands() {
+    let output = get_script_text(&ScriptValue::Commands(vec![
+        ScriptCommand {
+            cmd: "echo 1".to_string(),
+            ..Default::default()
+        },
+        ScriptCommand {
+            cmd: "echo 2".to_string(),
+            ignore_errors: Some(true),
+            ..Default::default()
+        },
+    ]))
+    .unwrap();
+
+    assert_eq!(output, vec!["echo 1", "echo 2"]);
+}
+
+#[test]
+fn invoke_commands_ignore_errors() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Commands(vec![
+        ScriptCommand {
+            cmd: "exit 1".to_string(),
+            ignore_errors: Some(true),
+            ..Default::default()
+        },
+        ScriptCommand {
+            cmd: "echo test".to_string(),
+            ..Default::default()
+        },
+    ]));
+
+    let output = invoke(
+        &task,
+        &test::create_empty_flow_info(),
+        Rc::new(RefCell::new(FlowState::new())),
+    )
+    .unwrap();
+
+    assert!(output);
+}
+
+#[test]
+#[should_panic]
+fn invoke_commands_error() {
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Commands(vec![ScriptCommand {
+        cmd: "exit 1".to_string(),
+        ..Default::default()
+    }]));
+
+    invoke(
+        &task,
+        &test::create_empty_flow_info(),
+        Rc::new(RefCell::new(FlowState::new())),
+    )
+    .unwrap();
+}
+
+#[test]
+fn invoke_commands_error_task_ignore_errors() {
+    let mut task = Task::new();
+    task.ignore_errors = Some(true);
+    task.script = Some(ScriptValue::Commands(vec![ScriptCommand {
+        cmd: "exit 1".to_string(),
+        ..Default::default()
+    }]));
+
+    let output = invoke(
+        &task,
+        &test::create_empty_flow_info(),
+        Rc::new(RefCell::new(FlowState::new())),
+    )
+    .unwrap();
+
+    assert!(!output);
+}
+
+#[test]
+fn invoke_script_command_retries() {
+    if test::is_linux() {
+        let directory = test::get_temp_test_directory("invoke_script_command_retries");
+        let marker = directory.join("marker");
+        let script_command = ScriptCommand {
+            cmd: format!(
+                "test -f {0} || (touch {0}; exit 1)",
+                marker.to_string_lossy()
+            ),
+            retries: Some(1),
+            ..Default::default()
+        };
+
+        let output =
+            invoke_script_command(&script_command, None, None, None, None, None, &vec![]).unwrap();
+
+        assert!(output);
+    }
+}
+
+#[test]
+fn invoke_script_command_timeout() {
+    if test::is_linux() {
+        let script_command = ScriptCommand {
+            cmd: "sleep 5".to_string(),
+            timeout: Some(1),
+            ..Default::default()
+        };
+
+        let output =
+            invoke_script_command(&script_command, None, None, None, None, None, &vec![]).unwrap();
+
+        assert!(!output);
+    }
+}
@@ -0,0 +1,117 @@
+//! # status_summary
+//!
+//! Records the status of each flow step and prints out the status summary at the end of the
+//! flow.
+//!
+
+#[cfg(test)]
+#[path = "status_summary_test.rs"]
+mod status_summary_test;
+
+use crate::types::{CliArgs, Config, StepStatus, StepStatusInfo};
+use std::cell::RefCell;
+use std::time::SystemTime;
+
+thread_local! {
+    /// The status (and skip reason) reported by the step running in the current thread
+    static STEP_STATUS: RefCell<Option<(StepStatus, Option<String>)>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn is_status_summary_enabled() -> bool {
+    envmnt::is("CARGO_MAKE_PRINT_STATUS_SUMMARY")
+}
+
+pub(crate) fn init(config: &Config, cli_args: &CliArgs) {
+    if config.config.status_summary.unwrap_or(false) || cli_args.print_status_summary {
+        envmnt::set_bool("CARGO_MAKE_PRINT_STATUS_SUMMARY", true);
+    }
+}
+
+/// Marks the step running in the current thread as skipped
+pub(crate) fn set_skipped(reason: &str) {
+    STEP_STATUS
+        .with(|status| status.replace(Some((StepStatus::Skipped, Some(reason.to_string())))));
+}
+
+/// Marks the step running in the current thread as failed with ignored errors
+pub(crate) fn set_ignored_error() {
+    STEP_STATUS.with(|status| status.replace(Some((StepStatus::IgnoredError, None))));
+}
+
+/// Returns and clears the status reported by the step running in the current thread
+pub(crate) fn take_step_status() -> Option<(StepStatus, Option<String>)> {
+    STEP_STATUS.with(|status| status.take())
+}
+
+pub(crate) fn insert(
+    status_summary: &mut Vec<StepStatusInfo>,
+    index: usize,
+    name: &str,
+    status: StepStatus,
+    start_time: SystemTime,
+    skip_reason: Option<String>,
+) {
+    let duration = match start_time.elapsed() {
+        Ok(elapsed) => elapsed.as_millis(),
+        Err(_) => 0,
+    };
+
+    // sub flow steps are done before their parent step so the parent is placed before them
+    let index = index.min(status_summary.len());
+    status_summary.insert(
+        index,
+        StepStatusInfo {
+            name: name.to_string(),
+            status,
+            duration,
+            skip_reason,
+        },
+    );
+}
+
+fn get_status_name(status: StepStatus) -> &'static str {
+    match status {
+        StepStatus::Success => "SUCCESS",
+        StepStatus::Failed => "FAILED",
+        StepStatus::Skipped => "SKIPPED",
+        StepStatus::IgnoredError => "IGNORED ERROR",
+    }
+}
+
+fn create_lines(status_summary: &[StepStatusInfo]) -> Vec<String> {
+    let max_name_size = status_summary
+        .iter()
+        .map(|entry| entry.name.len())
+        .max()
+        .unwrap_or(0);
+
+    status_summary
+        .iter()
+        .map(|entry| {
+            let seconds = entry.duration as f64 / 1000.0;
+            let skip_reason = match entry.skip_reason {
+                Some(ref reason) if !reason.is_empty() => format!(" ({})", reason),
+                _ => "".to_string(),
+            };
+
+            format!(
+                "{:<name_size$}   {:<13}   {:.2} seconds{}",
+                entry.name,
+                get_status_name(entry.status),
+                seconds,
+                skip_reason,
+                name_size = max_name_size
+            )
+        })
+        .collect()
+}
+
+pub(crate) fn print(status_summary: &[StepStatusInfo]) {
+    if is_status_summary_enabled() && !status_summary.is_empty() {
+        info!("=================Status Summary=================");
+        for line in create_lines(status_summary) {
+            info!("{}", line);
+        }
+        info!("================================================");
+    }
+}
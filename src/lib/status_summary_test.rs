@@ -0,0 +1,118 @@
+use super::*;
+
+fn create_info(
+    name: &str,
+    status: StepStatus,
+    duration: u128,
+    skip_reason: Option<&str>,
+) -> StepStatusInfo {
+    StepStatusInfo {
+        name: name.to_string(),
+        status,
+        duration,
+        skip_reason: skip_reason.map(|reason| reason.to_string()),
+    }
+}
+
+#[test]
+fn create_lines_empty() {
+    let lines = create_lines(&[]);
+
+    assert!(lines.is_empty());
+}
+
+#[test]
+fn create_lines_all_statuses() {
+    let lines = create_lines(&[
+        create_info("build", StepStatus::Success, 12340, None),
+        create_info("test", StepStatus::Failed, 1020, None),
+        create_info("docs", StepStatus::Skipped, 0, Some("conditions not met")),
+        create_info("lint", StepStatus::IgnoredError, 500, None),
+    ]);
+
+    assert_eq!(
+        lines,
+        vec![
+            "build   SUCCESS         12.34 seconds",
+            "test    FAILED          1.02 seconds",
+            "docs    SKIPPED         0.00 seconds (conditions not met)",
+            "lint    IGNORED ERROR   0.50 seconds",
+        ]
+    );
+}
+
+#[test]
+fn insert_sub_flow_order() {
+    let mut status_summary = vec![];
+
+    insert(
+        &mut status_summary,
+        0,
+        "child",
+        StepStatus::Success,
+        SystemTime::now(),
+        None,
+    );
+    insert(
+        &mut status_summary,
+        0,
+        "parent",
+        StepStatus::Success,
+        SystemTime::now(),
+        None,
+    );
+    insert(
+        &mut status_summary,
+        5,
+        "next",
+        StepStatus::Skipped,
+        SystemTime::now(),
+        Some("test".to_string()),
+    );
+
+    let names: Vec<&str> = status_summary
+        .iter()
+        .map(|entry| entry.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["parent", "child", "next"]);
+    assert_eq!(status_summary[2].skip_reason, Some("test".to_string()));
+}
+
+#[test]
+fn take_step_status_skipped() {
+    set_skipped("test");
+
+    let status = take_step_status();
+
+    assert_eq!(
+        status,
+        Some((StepStatus::Skipped, Some("test".to_string())))
+    );
+    assert!(take_step_status().is_none());
+}
+
+#[test]
+fn take_step_status_ignored_error() {
+    set_ignored_error();
+
+    let status = take_step_status();
+
+    assert_eq!(status, Some((StepStatus::IgnoredError, None)));
+}
+
+#[test]
+#[ignore]
+fn init_from_cli_args() {
+    envmnt::remove("CARGO_MAKE_PRINT_STATUS_SUMMARY");
+    let config = Config::default();
+    let mut cli_args = CliArgs::new();
+
+    init(&config, &cli_args);
+    assert!(!is_status_summary_enabled());
+
+    cli_args.print_status_summary = true;
+    init(&config, &cli_args);
+    assert!(is_status_summary_enabled());
+
+    envmnt::remove("CARGO_MAKE_PRINT_STATUS_SUMMARY");
+}
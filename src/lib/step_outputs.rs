@@ -0,0 +1,174 @@
+//! # step_outputs
+//!
+//! Collects the key/value outputs which tasks write to the file referenced by the
+//! CARGO_MAKE_OUTPUT env var and exposes them to the following tasks as
+//! `tasks.<task>.outputs.<key>` env vars.
+//!
+
+#[cfg(test)]
+#[path = "step_outputs_test.rs"]
+mod step_outputs_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::{ScriptValue, Task};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::path::PathBuf;
+
+/// The env var holding the path of the file the task writes its outputs to
+static OUTPUT_FILE_ENV: &str = "CARGO_MAKE_OUTPUT";
+/// Matches the output references in script text: ${tasks.<task>.outputs.<key>}
+static REFERENCE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\$\{(tasks\.[^{}\s]+\.outputs\.[^{}\s]+)\}").unwrap());
+
+fn get_env_key(task_name: &str, key: &str) -> String {
+    format!("tasks.{}.outputs.{}", task_name, key)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.chars().all(|character| {
+            character.is_ascii_alphanumeric() || character == '_' || character == '-'
+        })
+}
+
+/// Parses the outputs file content, which holds a single `key=value` output per line or
+/// multi line values using the `key<<DELIMITER` syntax (ending with a line holding only the
+/// delimiter)
+fn parse_outputs(text: &str) -> Vec<(String, String)> {
+    let mut outputs = vec![];
+    let mut lines = text.lines();
+
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // the multi line syntax is used if the delimiter marker comes before any '='
+        let multi_line = match (line.find("<<"), line.find('=')) {
+            (Some(marker_index), Some(equals_index)) => marker_index < equals_index,
+            (Some(_), None) => true,
+            _ => false,
+        };
+
+        let (key, value) = if multi_line {
+            let (key, delimiter) = line.split_once("<<").unwrap_or((line, ""));
+            let delimiter = delimiter.trim();
+
+            let mut value_lines = vec![];
+            let mut closed = false;
+            for value_line in lines.by_ref() {
+                if value_line == delimiter {
+                    closed = true;
+                    break;
+                }
+                value_lines.push(value_line);
+            }
+            if !closed {
+                warn!(
+                    "Output: {} is missing the closing delimiter: {}",
+                    key, delimiter
+                );
+            }
+
+            (key, value_lines.join("\n"))
+        } else {
+            match line.split_once('=') {
+                Some((key, value)) => (key, value.to_string()),
+                None => {
+                    warn!("Invalid output line: {}, expected key=value", line);
+                    continue;
+                }
+            }
+        };
+
+        let key = key.trim();
+        if is_valid_key(key) {
+            outputs.push((key.to_string(), value));
+        } else {
+            warn!("Invalid output name: {}", key);
+        }
+    }
+
+    outputs
+}
+
+/// Holds the outputs file of the running task, which is deleted once dropped
+pub(crate) struct OutputsFile {
+    file: String,
+    /// The outputs file of the parent task (for tasks invoked by other tasks)
+    previous: Option<String>,
+}
+
+impl OutputsFile {
+    /// Creates an empty outputs file and exposes its path to the task via the env
+    pub(crate) fn create() -> Result<OutputsFile, CargoMakeError> {
+        let file = io::create_text_file("", "txt")?;
+        let previous = envmnt::get_set(OUTPUT_FILE_ENV, &file);
+
+        Ok(OutputsFile { file, previous })
+    }
+
+    /// Reads the outputs written by the task and sets them as env vars
+    pub(crate) fn collect(&self, task_name: &str) -> Result<(), CargoMakeError> {
+        let text = io::read_text_file(&PathBuf::from(&self.file))?;
+
+        for (key, value) in parse_outputs(&text) {
+            let env_key = get_env_key(task_name, &key);
+            debug!("Setting Output: {} Value: {}", &env_key, &value);
+            envmnt::set(&env_key, &value);
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for OutputsFile {
+    fn drop(&mut self) {
+        io::delete_file(&self.file);
+        envmnt::set_or_remove(OUTPUT_FILE_ENV, &self.previous);
+    }
+}
+
+/// Replaces the output references (${tasks.<task>.outputs.<key>}) in the provided text,
+/// undefined outputs are kept as is
+fn expand_references(text: &str) -> String {
+    REFERENCE_REGEX
+        .replace_all(text, |captures: &Captures| {
+            envmnt::get_or(&captures[1], &captures[0])
+        })
+        .to_string()
+}
+
+/// Replaces the output references in the task script.<br>
+/// Other env references are kept as they are part of the script language.
+pub(crate) fn expand_script(task: &mut Task) {
+    let script = match task.script {
+        Some(ref mut script) => script,
+        None => return,
+    };
+
+    match script {
+        ScriptValue::SingleLine(ref mut text) => *text = expand_references(text),
+        ScriptValue::Text(ref mut lines) => {
+            for line in lines.iter_mut() {
+                *line = expand_references(line);
+            }
+        }
+        ScriptValue::Sections(ref mut sections) => {
+            for text in [&mut sections.pre, &mut sections.main, &mut sections.post]
+                .into_iter()
+                .flatten()
+            {
+                *text = expand_references(text);
+            }
+        }
+        ScriptValue::Commands(ref mut commands) => {
+            for command in commands.iter_mut() {
+                command.cmd = expand_references(&command.cmd);
+            }
+        }
+        ScriptValue::File(_) => (),
+    }
+}
@@ -0,0 +1,216 @@
+use super::*;
+use crate::types::{ScriptCommand, ScriptSections};
+use std::path::Path;
+
+fn to_outputs(values: &[(&str, &str)]) -> Vec<(String, String)> {
+    values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn get_env_key_value() {
+    assert_eq!(
+        get_env_key("build", "artifact_path"),
+        "tasks.build.outputs.artifact_path"
+    );
+}
+
+#[test]
+fn is_valid_key_values() {
+    assert!(is_valid_key("artifact_path"));
+    assert!(is_valid_key("version-2"));
+    assert!(!is_valid_key(""));
+    assert!(!is_valid_key("bad key"));
+    assert!(!is_valid_key("bad.key"));
+}
+
+#[test]
+fn parse_outputs_empty() {
+    assert!(parse_outputs("").is_empty());
+    assert!(parse_outputs("\n  \n").is_empty());
+}
+
+#[test]
+fn parse_outputs_values() {
+    let outputs = parse_outputs("first=1\n\nsecond = a=b\nempty=\n");
+
+    assert_eq!(
+        outputs,
+        to_outputs(&[("first", "1"), ("second", " a=b"), ("empty", "")])
+    );
+}
+
+#[test]
+fn parse_outputs_multi_line() {
+    let outputs = parse_outputs("notes<<EOF\nline 1\nline=2\nEOF\nnext=value");
+
+    assert_eq!(
+        outputs,
+        to_outputs(&[("notes", "line 1\nline=2"), ("next", "value")])
+    );
+}
+
+#[test]
+fn parse_outputs_multi_line_not_closed() {
+    let outputs = parse_outputs("notes<<EOF\nline 1\nline 2");
+
+    assert_eq!(outputs, to_outputs(&[("notes", "line 1\nline 2")]));
+}
+
+#[test]
+fn parse_outputs_marker_in_value() {
+    let outputs = parse_outputs("command=cat <<EOF");
+
+    assert_eq!(outputs, to_outputs(&[("command", "cat <<EOF")]));
+}
+
+#[test]
+fn parse_outputs_invalid_lines() {
+    let outputs = parse_outputs("no value\nbad key=1\n=1\nvalid=yes");
+
+    assert_eq!(outputs, to_outputs(&[("valid", "yes")]));
+}
+
+#[test]
+fn outputs_file_collect() {
+    envmnt::remove(OUTPUT_FILE_ENV);
+    envmnt::remove("tasks.step_outputs_test.outputs.path");
+
+    let file = {
+        let outputs_file = OutputsFile::create().unwrap();
+        let file = envmnt::get_or_panic(OUTPUT_FILE_ENV);
+        assert!(Path::new(&file).exists());
+
+        fsio::file::write_text_file(&file, "path=target/release\n").unwrap();
+        outputs_file.collect("step_outputs_test").unwrap();
+
+        file
+    };
+
+    assert_eq!(
+        envmnt::get_or_panic("tasks.step_outputs_test.outputs.path"),
+        "target/release"
+    );
+    assert!(!Path::new(&file).exists());
+    assert!(!envmnt::exists(OUTPUT_FILE_ENV));
+}
+
+#[test]
+fn outputs_file_nested() {
+    envmnt::remove(OUTPUT_FILE_ENV);
+
+    let parent = OutputsFile::create().unwrap();
+    let parent_file = envmnt::get_or_panic(OUTPUT_FILE_ENV);
+    {
+        let _child = OutputsFile::create().unwrap();
+
+        assert_ne!(envmnt::get_or_panic(OUTPUT_FILE_ENV), parent_file);
+    }
+
+    assert_eq!(envmnt::get_or_panic(OUTPUT_FILE_ENV), parent_file);
+
+    drop(parent);
+    assert!(!envmnt::exists(OUTPUT_FILE_ENV));
+}
+
+#[test]
+fn expand_references_values() {
+    envmnt::set("tasks.expand_test.outputs.version", "1.2.3");
+    envmnt::set("EXPAND_REFERENCES_TEST", "value");
+
+    let output = expand_references(
+        "echo ${tasks.expand_test.outputs.version} ${tasks.expand_test.outputs.missing} ${EXPAND_REFERENCES_TEST} ${VAR:-default}",
+    );
+
+    assert_eq!(
+        output,
+        "echo 1.2.3 ${tasks.expand_test.outputs.missing} ${EXPAND_REFERENCES_TEST} ${VAR:-default}"
+    );
+}
+
+#[test]
+fn expand_script_none() {
+    let mut task = Task::new();
+
+    expand_script(&mut task);
+
+    assert!(task.script.is_none());
+}
+
+#[test]
+fn expand_script_text() {
+    envmnt::set("tasks.expand_script_test.outputs.name", "test");
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Text(vec![
+        "echo ${tasks.expand_script_test.outputs.name}".to_string(),
+        "echo ${HOME}".to_string(),
+    ]));
+
+    expand_script(&mut task);
+
+    match task.script.unwrap() {
+        ScriptValue::Text(lines) => assert_eq!(lines, vec!["echo test", "echo ${HOME}"]),
+        _ => panic!("Invalid script value."),
+    }
+}
+
+#[test]
+fn expand_script_single_line() {
+    envmnt::set("tasks.expand_script_test.outputs.name", "test");
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::SingleLine(
+        "echo ${tasks.expand_script_test.outputs.name}".to_string(),
+    ));
+
+    expand_script(&mut task);
+
+    match task.script.unwrap() {
+        ScriptValue::SingleLine(text) => assert_eq!(text, "echo test"),
+        _ => panic!("Invalid script value."),
+    }
+}
+
+#[test]
+fn expand_script_sections() {
+    envmnt::set("tasks.expand_script_test.outputs.name", "test");
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Sections(ScriptSections {
+        pre: Some("pre ${tasks.expand_script_test.outputs.name}".to_string()),
+        main: None,
+        post: Some("post ${tasks.expand_script_test.outputs.name}".to_string()),
+    }));
+
+    expand_script(&mut task);
+
+    match task.script.unwrap() {
+        ScriptValue::Sections(sections) => {
+            assert_eq!(sections.pre.unwrap(), "pre test");
+            assert!(sections.main.is_none());
+            assert_eq!(sections.post.unwrap(), "post test");
+        }
+        _ => panic!("Invalid script value."),
+    }
+}
+
+#[test]
+fn expand_script_commands() {
+    envmnt::set("tasks.expand_script_test.outputs.name", "test");
+
+    let mut task = Task::new();
+    task.script = Some(ScriptValue::Commands(vec![ScriptCommand {
+        cmd: "echo ${tasks.expand_script_test.outputs.name}".to_string(),
+        ..Default::default()
+    }]));
+
+    expand_script(&mut task);
+
+    match task.script.unwrap() {
+        ScriptValue::Commands(commands) => assert_eq!(commands[0].cmd, "echo test"),
+        _ => panic!("Invalid script value."),
+    }
+}
@@ -0,0 +1,256 @@
+//! # task_args
+//!
+//! Maps the arguments provided after the task name into env vars, based on the
+//! named arguments declared in the task args schema.
+//!
+
+#[cfg(test)]
+#[path = "task_args_test.rs"]
+mod task_args_test;
+
+use crate::error::CargoMakeError;
+use crate::types::{Config, Task, TaskArgument, TaskArgumentType};
+use indexmap::IndexMap;
+
+fn get_type(argument: &TaskArgument) -> TaskArgumentType {
+    argument.arg_type.unwrap_or(TaskArgumentType::String)
+}
+
+/// Returns the argument type name as defined in the schema
+pub(crate) fn get_type_name(argument: &TaskArgument) -> &'static str {
+    match get_type(argument) {
+        TaskArgumentType::String => "string",
+        TaskArgumentType::Bool => "bool",
+        TaskArgumentType::Number => "number",
+    }
+}
+
+/// Returns true if the argument is provided by position and not as a flag
+pub(crate) fn is_positional(argument: &TaskArgument) -> bool {
+    argument.positional.unwrap_or(false)
+}
+
+/// Returns the name of the env var holding the argument value
+pub(crate) fn get_env_name(name: &str, argument: &TaskArgument) -> String {
+    match argument.env {
+        Some(ref env) => env.to_string(),
+        None => format!(
+            "CARGO_MAKE_TASK_ARG_{}",
+            name.to_uppercase().replace('-', "_")
+        ),
+    }
+}
+
+/// Returns the args schema of the provided task (if defined)
+pub(crate) fn get_schema<'a>(
+    config: &'a Config,
+    task: &str,
+) -> Option<&'a IndexMap<String, TaskArgument>> {
+    match config.tasks.get(task) {
+        Some(task_config) => task_config.args_schema.as_ref(),
+        None => None,
+    }
+}
+
+/// Returns true if the task arguments request the task help
+pub(crate) fn is_help_requested(arguments: &Option<Vec<String>>) -> bool {
+    match arguments {
+        Some(ref values) => values
+            .iter()
+            .take_while(|value| *value != "--")
+            .any(|value| value == "--help" || value == "-h"),
+        None => false,
+    }
+}
+
+fn validate_value(name: &str, argument: &TaskArgument, value: &str) -> Result<(), CargoMakeError> {
+    let valid = match get_type(argument) {
+        TaskArgumentType::String => true,
+        TaskArgumentType::Bool => value == "true" || value == "false",
+        TaskArgumentType::Number => value.parse::<f64>().is_ok(),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(CargoMakeError::InvalidArgument(format!(
+            "{} expects a {} value, found: {}",
+            name,
+            get_type_name(argument),
+            value
+        )))
+    }
+}
+
+/// Parses the task arguments based on the schema and returns the env vars to set
+pub(crate) fn parse(
+    schema: &IndexMap<String, TaskArgument>,
+    arguments: &[String],
+) -> Result<Vec<(String, String)>, CargoMakeError> {
+    let positional_names: Vec<&String> = schema
+        .iter()
+        .filter(|(_, argument)| is_positional(argument))
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut values = IndexMap::new();
+    let mut positional_index = 0;
+    let mut flags_ended = false;
+    let mut iter = arguments.iter();
+    while let Some(value) = iter.next() {
+        if !flags_ended && value == "--" {
+            flags_ended = true;
+        } else if !flags_ended && value.starts_with("--") {
+            let (name, inline_value) = match value[2..].split_once('=') {
+                Some((name, inline_value)) => (name, Some(inline_value.to_string())),
+                None => (&value[2..], None),
+            };
+
+            let argument = match schema.get(name) {
+                Some(argument) if !is_positional(argument) => argument,
+                _ => {
+                    return Err(CargoMakeError::InvalidArgument(format!(
+                        "Unknown task argument: --{}",
+                        name
+                    )));
+                }
+            };
+
+            let flag_value = match inline_value {
+                Some(inline_value) => inline_value,
+                None if get_type(argument) == TaskArgumentType::Bool => "true".to_string(),
+                None => match iter.next() {
+                    Some(next_value) => next_value.to_string(),
+                    None => {
+                        return Err(CargoMakeError::InvalidArgument(format!(
+                            "Missing value for task argument: --{}",
+                            name
+                        )));
+                    }
+                },
+            };
+
+            values.insert(name.to_string(), flag_value);
+        } else {
+            match positional_names.get(positional_index) {
+                Some(name) => {
+                    values.insert(name.to_string(), value.to_string());
+                    positional_index += 1;
+                }
+                None => {
+                    return Err(CargoMakeError::InvalidArgument(format!(
+                        "Unexpected task argument: {}",
+                        value
+                    )));
+                }
+            }
+        }
+    }
+
+    let mut env = vec![];
+    for (name, argument) in schema {
+        let value = match values.shift_remove(name).or(argument.default.clone()) {
+            Some(value) => value,
+            None if get_type(argument) == TaskArgumentType::Bool => "false".to_string(),
+            None if argument.required.unwrap_or(false) => {
+                return Err(CargoMakeError::InvalidArgument(format!(
+                    "Missing required task argument: {}",
+                    name
+                )));
+            }
+            None => continue,
+        };
+
+        validate_value(name, argument, &value)?;
+
+        env.push((get_env_name(name, argument), value));
+    }
+
+    Ok(env)
+}
+
+/// Returns the command line usage of the argument, for example: --name <value>
+pub(crate) fn get_usage(name: &str, argument: &TaskArgument) -> String {
+    if is_positional(argument) {
+        format!("<{}>", name)
+    } else if get_type(argument) == TaskArgumentType::Bool {
+        format!("--{}", name)
+    } else {
+        format!("--{} <value>", name)
+    }
+}
+
+/// Returns true if the task defines help content (arguments or examples)
+pub(crate) fn has_help(task_config: &Task) -> bool {
+    task_config.args_schema.is_some() || task_config.examples.is_some()
+}
+
+/// Returns the task help text, listing the task arguments and examples
+pub(crate) fn get_help(task: &str, task_config: &Task) -> String {
+    let mut help = format!("Task: {}\n", task);
+    if let Some(ref description) = task_config.description {
+        help.push_str(&format!("{}\n", description));
+    }
+
+    if let Some(ref schema) = task_config.args_schema {
+        help.push_str("\nArguments:\n");
+
+        let usages: Vec<String> = schema
+            .iter()
+            .map(|(name, argument)| get_usage(name, argument))
+            .collect();
+        let width = usages.iter().map(|usage| usage.len()).max().unwrap_or(0);
+
+        for ((name, argument), usage) in schema.iter().zip(usages.iter()) {
+            let mut details = vec![get_type_name(argument).to_string()];
+            if let Some(ref default) = argument.default {
+                details.push(format!("default: {}", default));
+            } else if argument.required.unwrap_or(false) {
+                details.push("required".to_string());
+            }
+            details.push(format!("env: {}", get_env_name(name, argument)));
+
+            let text = match argument.help {
+                Some(ref text) => format!("{} ", text),
+                None => "".to_string(),
+            };
+
+            help.push_str(&format!(
+                "  {:width$}    {}[{}]\n",
+                usage,
+                text,
+                details.join(", "),
+                width = width
+            ));
+        }
+    }
+
+    if let Some(ref examples) = task_config.examples {
+        help.push_str("\nExamples:\n");
+        for example in examples {
+            help.push_str(&format!("  {}\n", example));
+        }
+    }
+
+    help
+}
+
+/// Parses the task arguments (if the task defines an args schema) and sets the matching env vars
+pub(crate) fn setup_env(
+    config: &Config,
+    task: &str,
+    arguments: &Option<Vec<String>>,
+) -> Result<(), CargoMakeError> {
+    if let Some(schema) = get_schema(config, task) {
+        let values = match arguments {
+            Some(ref values) => values.as_slice(),
+            None => &[],
+        };
+
+        for (key, value) in parse(schema, values)? {
+            envmnt::set(&key, &value);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use super::*;
+use crate::types::{ConfigSection, Task};
+
+fn create_schema() -> IndexMap<String, TaskArgument> {
+    let mut schema = IndexMap::new();
+    schema.insert(
+        "target".to_string(),
+        TaskArgument {
+            positional: Some(true),
+            required: Some(true),
+            help: Some("The build target".to_string()),
+            ..Default::default()
+        },
+    );
+    schema.insert(
+        "release".to_string(),
+        TaskArgument {
+            arg_type: Some(TaskArgumentType::Bool),
+            help: Some("Build in release mode".to_string()),
+            ..Default::default()
+        },
+    );
+    schema.insert(
+        "name".to_string(),
+        TaskArgument {
+            default: Some("app".to_string()),
+            env: Some("APP_NAME".to_string()),
+            ..Default::default()
+        },
+    );
+    schema.insert(
+        "jobs".to_string(),
+        TaskArgument {
+            arg_type: Some(TaskArgumentType::Number),
+            ..Default::default()
+        },
+    );
+
+    schema
+}
+
+fn to_args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn get_env_name_default() {
+    assert_eq!(
+        get_env_name("dry-run", &TaskArgument::default()),
+        "CARGO_MAKE_TASK_ARG_DRY_RUN"
+    );
+}
+
+#[test]
+fn is_help_requested_values() {
+    assert!(!is_help_requested(&None));
+    assert!(!is_help_requested(&Some(to_args(&["a", "--release"]))));
+    assert!(is_help_requested(&Some(to_args(&["a", "--help"]))));
+    assert!(is_help_requested(&Some(to_args(&["-h"]))));
+    assert!(!is_help_requested(&Some(to_args(&["--", "--help"]))));
+}
+
+#[test]
+fn parse_defaults() {
+    let env = parse(&create_schema(), &to_args(&["x86"])).unwrap();
+
+    assert_eq!(
+        env,
+        vec![
+            ("CARGO_MAKE_TASK_ARG_TARGET".to_string(), "x86".to_string()),
+            (
+                "CARGO_MAKE_TASK_ARG_RELEASE".to_string(),
+                "false".to_string()
+            ),
+            ("APP_NAME".to_string(), "app".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_all_values() {
+    let env = parse(
+        &create_schema(),
+        &to_args(&["--release", "--name", "foo", "--jobs=4", "arm"]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        env,
+        vec![
+            ("CARGO_MAKE_TASK_ARG_TARGET".to_string(), "arm".to_string()),
+            (
+                "CARGO_MAKE_TASK_ARG_RELEASE".to_string(),
+                "true".to_string()
+            ),
+            ("APP_NAME".to_string(), "foo".to_string()),
+            ("CARGO_MAKE_TASK_ARG_JOBS".to_string(), "4".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_positional_after_separator() {
+    let env = parse(&create_schema(), &to_args(&["--", "--target"])).unwrap();
+
+    assert_eq!(env[0].1, "--target");
+}
+
+#[test]
+fn parse_missing_required() {
+    let error = parse(&create_schema(), &to_args(&["--release"])).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::InvalidArgument(_)));
+}
+
+#[test]
+fn parse_unknown_flag() {
+    assert!(parse(&create_schema(), &to_args(&["x86", "--bad"])).is_err());
+    assert!(parse(&create_schema(), &to_args(&["--target", "x86"])).is_err());
+}
+
+#[test]
+fn parse_unexpected_positional() {
+    assert!(parse(&create_schema(), &to_args(&["x86", "arm"])).is_err());
+}
+
+#[test]
+fn parse_missing_flag_value() {
+    assert!(parse(&create_schema(), &to_args(&["x86", "--name"])).is_err());
+}
+
+#[test]
+fn parse_invalid_types() {
+    assert!(parse(&create_schema(), &to_args(&["x86", "--jobs", "many"])).is_err());
+    assert!(parse(&create_schema(), &to_args(&["x86", "--release=yes"])).is_err());
+}
+
+#[test]
+fn get_help_valid() {
+    let mut task = Task::new();
+    task.description = Some("Builds the project".to_string());
+    task.args_schema = Some(create_schema());
+
+    let help = get_help("build", &task);
+
+    assert_eq!(
+        help,
+        r#"Task: build
+Builds the project
+
+Arguments:
+  <target>          The build target [string, required, env: CARGO_MAKE_TASK_ARG_TARGET]
+  --release         Build in release mode [bool, env: CARGO_MAKE_TASK_ARG_RELEASE]
+  --name <value>    [string, default: app, env: APP_NAME]
+  --jobs <value>    [number, env: CARGO_MAKE_TASK_ARG_JOBS]
+"#
+    );
+}
+
+#[test]
+fn get_help_with_examples() {
+    let mut task = Task::new();
+    task.examples = Some(vec![
+        "cargo make release --minor".to_string(),
+        "cargo make release --major".to_string(),
+    ]);
+
+    let help = get_help("release", &task);
+
+    assert_eq!(
+        help,
+        r#"Task: release
+
+Examples:
+  cargo make release --minor
+  cargo make release --major
+"#
+    );
+}
+
+#[test]
+fn has_help_values() {
+    let mut task = Task::new();
+    assert!(!has_help(&task));
+
+    task.examples = Some(vec!["cargo make test".to_string()]);
+    assert!(has_help(&task));
+
+    task.examples = None;
+    task.args_schema = Some(create_schema());
+    assert!(has_help(&task));
+}
+
+#[test]
+fn setup_env_with_schema() {
+    let mut task = Task::new();
+    let mut schema = IndexMap::new();
+    schema.insert("mode".to_string(), TaskArgument::default());
+    task.args_schema = Some(schema);
+    let mut tasks = IndexMap::new();
+    tasks.insert("test-task-args".to_string(), task);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
+    };
+
+    setup_env(
+        &config,
+        "test-task-args",
+        &Some(to_args(&["--mode", "fast"])),
+    )
+    .unwrap();
+
+    assert_eq!(envmnt::get_or_panic("CARGO_MAKE_TASK_ARG_MODE"), "fast");
+}
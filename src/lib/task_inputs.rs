@@ -0,0 +1,115 @@
+//! # task_inputs
+//!
+//! Requests the task input values from the user when the flow starts and stores them in
+//! the input env vars.
+//!
+
+#[cfg(test)]
+#[path = "task_inputs_test.rs"]
+mod task_inputs_test;
+
+use crate::error::CargoMakeError;
+use crate::prompt;
+use crate::types::{ExecutionPlan, TaskInput, TaskInputValidation};
+use crate::version;
+
+/// The number of times an invalid value is requested again before failing
+static MAX_ATTEMPTS: usize = 3;
+
+fn get_validation_name(validation: TaskInputValidation) -> &'static str {
+    match validation {
+        TaskInputValidation::Semver => "a semantic version",
+        TaskInputValidation::Number => "a number",
+        TaskInputValidation::Bool => "true or false",
+        TaskInputValidation::NonEmpty => "a non empty value",
+    }
+}
+
+fn is_valid(validation: &Option<TaskInputValidation>, value: &str) -> bool {
+    match validation {
+        Some(TaskInputValidation::Semver) => version::parse(value, false).is_ok(),
+        Some(TaskInputValidation::Number) => value.parse::<f64>().is_ok(),
+        Some(TaskInputValidation::Bool) => value == "true" || value == "false",
+        Some(TaskInputValidation::NonEmpty) => !value.is_empty(),
+        None => true,
+    }
+}
+
+fn validate(input: &TaskInput, value: &str) -> Result<(), CargoMakeError> {
+    match input.validate {
+        Some(validation) if !is_valid(&input.validate, value) => {
+            Err(CargoMakeError::InvalidArgument(format!(
+                "Invalid value for input: {}, expected {}, found: {}",
+                &input.env,
+                get_validation_name(validation),
+                value
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn get_prompt(input: &TaskInput) -> String {
+    let text = input.prompt.as_ref().unwrap_or(&input.env);
+
+    match input.default {
+        Some(ref default) => format!("{} [{}]:", text, default),
+        None => format!("{}:", text),
+    }
+}
+
+fn get_value_with<F>(
+    input: &TaskInput,
+    interactive: bool,
+    mut read_value: F,
+) -> Result<String, CargoMakeError>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    // values already defined (for example in CI) are not requested
+    let existing_value = envmnt::get_or(&input.env, "");
+    if !existing_value.is_empty() {
+        validate(input, &existing_value)?;
+        return Ok(existing_value);
+    }
+
+    if interactive {
+        let message = get_prompt(input);
+
+        for _ in 0..MAX_ATTEMPTS {
+            let value = match read_value(&message) {
+                Some(ref value) if value.is_empty() => input.default.clone().unwrap_or_default(),
+                Some(value) => value,
+                None => break,
+            };
+
+            match validate(input, &value) {
+                Ok(_) => return Ok(value),
+                Err(error) => warn!("{}", error),
+            }
+        }
+    }
+
+    match input.default {
+        Some(ref default) => {
+            validate(input, default)?;
+            Ok(default.to_string())
+        }
+        None => Err(CargoMakeError::MissingTaskInput(input.env.to_string())),
+    }
+}
+
+/// Requests the inputs of all the tasks in the execution plan and sets the input env vars
+pub(crate) fn setup_env(execution_plan: &ExecutionPlan) -> Result<(), CargoMakeError> {
+    for step in &execution_plan.steps {
+        if let Some(ref inputs) = step.config.inputs {
+            for input in inputs {
+                let value = get_value_with(input, prompt::is_interactive(), prompt::read_line)?;
+
+                envmnt::set(&input.env, &value);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use super::*;
+use crate::types::{Step, Task};
+
+fn create_input(
+    env: &str,
+    default: Option<&str>,
+    validate: Option<TaskInputValidation>,
+) -> TaskInput {
+    TaskInput {
+        env: env.to_string(),
+        prompt: None,
+        default: default.map(|value| value.to_string()),
+        validate,
+    }
+}
+
+fn no_read(_message: &str) -> Option<String> {
+    panic!("input should not be requested");
+}
+
+#[test]
+fn is_valid_values() {
+    assert!(is_valid(&None, ""));
+    assert!(is_valid(&Some(TaskInputValidation::Semver), "1.2.3"));
+    assert!(!is_valid(&Some(TaskInputValidation::Semver), "1.2"));
+    assert!(is_valid(&Some(TaskInputValidation::Number), "-1.5"));
+    assert!(!is_valid(&Some(TaskInputValidation::Number), "one"));
+    assert!(is_valid(&Some(TaskInputValidation::Bool), "false"));
+    assert!(!is_valid(&Some(TaskInputValidation::Bool), "yes"));
+    assert!(is_valid(&Some(TaskInputValidation::NonEmpty), "value"));
+    assert!(!is_valid(&Some(TaskInputValidation::NonEmpty), ""));
+}
+
+#[test]
+fn get_prompt_values() {
+    let mut input = create_input("RELEASE_VERSION", None, None);
+    assert_eq!(get_prompt(&input), "RELEASE_VERSION:");
+
+    input.prompt = Some("Version to release".to_string());
+    assert_eq!(get_prompt(&input), "Version to release:");
+
+    input.default = Some("1.0.0".to_string());
+    assert_eq!(get_prompt(&input), "Version to release [1.0.0]:");
+}
+
+#[test]
+fn get_value_with_existing_env() {
+    envmnt::set("TASK_INPUTS_TEST_EXISTING", "1.2.3");
+    let input = create_input(
+        "TASK_INPUTS_TEST_EXISTING",
+        None,
+        Some(TaskInputValidation::Semver),
+    );
+
+    let value = get_value_with(&input, true, no_read).unwrap();
+
+    assert_eq!(value, "1.2.3");
+}
+
+#[test]
+fn get_value_with_existing_env_invalid() {
+    envmnt::set("TASK_INPUTS_TEST_EXISTING_INVALID", "latest");
+    let input = create_input(
+        "TASK_INPUTS_TEST_EXISTING_INVALID",
+        None,
+        Some(TaskInputValidation::Semver),
+    );
+
+    let error = get_value_with(&input, true, no_read).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::InvalidArgument(_)));
+}
+
+#[test]
+fn get_value_with_interactive() {
+    let input = create_input("TASK_INPUTS_TEST_INTERACTIVE", None, None);
+
+    let value = get_value_with(&input, true, |message| {
+        assert_eq!(message, "TASK_INPUTS_TEST_INTERACTIVE:");
+        Some("value".to_string())
+    })
+    .unwrap();
+
+    assert_eq!(value, "value");
+}
+
+#[test]
+fn get_value_with_interactive_default() {
+    let input = create_input("TASK_INPUTS_TEST_INTERACTIVE_DEFAULT", Some("1.0.0"), None);
+
+    let value = get_value_with(&input, true, |_| Some("".to_string())).unwrap();
+
+    assert_eq!(value, "1.0.0");
+}
+
+#[test]
+fn get_value_with_interactive_retry() {
+    let input = create_input(
+        "TASK_INPUTS_TEST_INTERACTIVE_RETRY",
+        None,
+        Some(TaskInputValidation::Number),
+    );
+    let mut answers = vec!["3".to_string(), "three".to_string()];
+
+    let value = get_value_with(&input, true, |_| answers.pop()).unwrap();
+
+    assert_eq!(value, "3");
+    assert!(answers.is_empty());
+}
+
+#[test]
+fn get_value_with_interactive_all_invalid() {
+    let input = create_input(
+        "TASK_INPUTS_TEST_INTERACTIVE_ALL_INVALID",
+        None,
+        Some(TaskInputValidation::NonEmpty),
+    );
+    let mut attempts = 0;
+
+    let error = get_value_with(&input, true, |_| {
+        attempts += 1;
+        Some("".to_string())
+    })
+    .unwrap_err();
+
+    assert_eq!(attempts, MAX_ATTEMPTS);
+    assert!(matches!(error, CargoMakeError::MissingTaskInput(_)));
+}
+
+#[test]
+fn get_value_with_not_interactive_default() {
+    let input = create_input("TASK_INPUTS_TEST_NOT_INTERACTIVE_DEFAULT", Some("2"), None);
+
+    let value = get_value_with(&input, false, no_read).unwrap();
+
+    assert_eq!(value, "2");
+}
+
+#[test]
+fn get_value_with_not_interactive_missing() {
+    let input = create_input("TASK_INPUTS_TEST_NOT_INTERACTIVE_MISSING", None, None);
+
+    let error = get_value_with(&input, false, no_read).unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::MissingTaskInput(_)));
+}
+
+#[test]
+fn setup_env_existing_values() {
+    envmnt::set("TASK_INPUTS_TEST_SETUP_ENV", "true");
+    let mut task = Task::new();
+    task.inputs = Some(vec![create_input(
+        "TASK_INPUTS_TEST_SETUP_ENV",
+        None,
+        Some(TaskInputValidation::Bool),
+    )]);
+    let execution_plan = ExecutionPlan {
+        steps: vec![Step {
+            name: "test".to_string(),
+            config: task,
+        }],
+    };
+
+    setup_env(&execution_plan).unwrap();
+
+    assert!(envmnt::is_equal("TASK_INPUTS_TEST_SETUP_ENV", "true"));
+}
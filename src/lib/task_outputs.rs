@@ -0,0 +1,111 @@
+//! # task_outputs
+//!
+//! Checks the declared task outputs, skipping tasks which outputs are newer than their
+//! sources and verifying the outputs were produced once the task is done.
+//!
+
+#[cfg(test)]
+#[path = "task_outputs_test.rs"]
+mod task_outputs_test;
+
+use crate::environment;
+use crate::error::CargoMakeError;
+use crate::types::Task;
+use fsio::path::from_path::FromPath;
+use glob::glob;
+
+/// Returns all files matching the glob pattern (after env expansion)
+fn get_files(glob_pattern: &str) -> Vec<String> {
+    let glob_pattern = environment::expand_value(glob_pattern);
+
+    match glob(&glob_pattern) {
+        Ok(paths) => paths
+            .filter_map(|entry| entry.ok())
+            .filter(|path| path.is_file())
+            .map(|path| FromPath::from_path(&path))
+            .collect(),
+        Err(error) => {
+            warn!(
+                "Unable to fetch paths for glob: {} {:#?}",
+                &glob_pattern, &error
+            );
+            vec![]
+        }
+    }
+}
+
+fn get_modified_times(files: &[String]) -> Option<Vec<u128>> {
+    files
+        .iter()
+        .map(|file| fsio::path::get_last_modified_time(file).ok())
+        .collect()
+}
+
+/// Returns true if all declared outputs exist and are newer than all the sources
+pub(crate) fn is_up_to_date(task: &Task) -> bool {
+    let (sources, outputs) = match (&task.sources, &task.outputs) {
+        (Some(sources), Some(outputs)) if !outputs.is_empty() => (sources, outputs),
+        _ => return false,
+    };
+
+    let mut output_files = vec![];
+    for glob_pattern in outputs {
+        let files = get_files(glob_pattern);
+        if files.is_empty() {
+            return false;
+        }
+        output_files.extend(files);
+    }
+
+    let source_files: Vec<String> = sources
+        .iter()
+        .flat_map(|glob_pattern| get_files(glob_pattern))
+        .collect();
+
+    match (
+        get_modified_times(&source_files),
+        get_modified_times(&output_files),
+    ) {
+        (Some(source_times), Some(output_times)) => {
+            let latest_source = source_times.into_iter().max().unwrap_or(0);
+            let oldest_output = output_times.into_iter().min().unwrap_or(0);
+
+            latest_source <= oldest_output
+        }
+        _ => false,
+    }
+}
+
+/// Verifies the task produced all of its declared outputs and reports them
+pub(crate) fn validate(task_name: &str, task: &Task) -> Result<(), CargoMakeError> {
+    let outputs = match task.outputs {
+        Some(ref outputs) => outputs,
+        None => return Ok(()),
+    };
+
+    let mut missing = vec![];
+    let mut produced = vec![];
+    for glob_pattern in outputs {
+        let files = get_files(glob_pattern);
+
+        if files.is_empty() {
+            missing.push(glob_pattern.to_string());
+        } else {
+            produced.extend(files);
+        }
+    }
+
+    if missing.is_empty() {
+        info!(
+            "Task: {} produced outputs: {}",
+            task_name,
+            produced.join(", ")
+        );
+        Ok(())
+    } else {
+        Err(CargoMakeError::MissingTaskOutputs(
+            task_name.to_string(),
+            missing.join(", "),
+        ))
+    }
+}
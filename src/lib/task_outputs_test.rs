@@ -0,0 +1,92 @@
+use super::*;
+use std::fs::File;
+use std::time::{Duration, SystemTime};
+
+fn create_file(path: &str, age_in_seconds: u64) {
+    fsio::file::write_text_file(path, "test").unwrap();
+
+    let modified = SystemTime::now() - Duration::from_secs(age_in_seconds);
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(modified)
+        .unwrap();
+}
+
+fn create_task(directory: &str) -> Task {
+    let mut task = Task::new();
+    task.sources = Some(vec![format!("{}/src/*.rs", directory)]);
+    task.outputs = Some(vec![format!("{}/out/*.bin", directory)]);
+
+    task
+}
+
+#[test]
+fn is_up_to_date_not_defined() {
+    let mut task = Task::new();
+    assert!(!is_up_to_date(&task));
+
+    task.outputs = Some(vec!["./Cargo.toml".to_string()]);
+    assert!(!is_up_to_date(&task));
+}
+
+#[test]
+fn is_up_to_date_missing_outputs() {
+    let directory = "./target/_temp/task_outputs/missing";
+    create_file(&format!("{}/src/lib.rs", directory), 100);
+
+    assert!(!is_up_to_date(&create_task(directory)));
+}
+
+#[test]
+fn is_up_to_date_outputs_newer() {
+    let directory = "./target/_temp/task_outputs/newer";
+    create_file(&format!("{}/src/lib.rs", directory), 100);
+    create_file(&format!("{}/out/app.bin", directory), 50);
+    create_file(&format!("{}/out/lib.bin", directory), 10);
+
+    assert!(is_up_to_date(&create_task(directory)));
+}
+
+#[test]
+fn is_up_to_date_source_newer() {
+    let directory = "./target/_temp/task_outputs/older";
+    create_file(&format!("{}/src/lib.rs", directory), 20);
+    create_file(&format!("{}/out/app.bin", directory), 50);
+    create_file(&format!("{}/out/lib.bin", directory), 10);
+
+    assert!(!is_up_to_date(&create_task(directory)));
+}
+
+#[test]
+fn validate_no_outputs() {
+    validate("test", &Task::new()).unwrap();
+}
+
+#[test]
+fn validate_outputs_found() {
+    let directory = "./target/_temp/task_outputs/found";
+    create_file(&format!("{}/out/app.bin", directory), 0);
+
+    validate("test", &create_task(directory)).unwrap();
+}
+
+#[test]
+fn validate_outputs_missing() {
+    let mut task = create_task("./target/_temp/task_outputs/not_found");
+    task.outputs = Some(vec![
+        "./Cargo.toml".to_string(),
+        "./target/_temp/task_outputs/not_found/*.bin".to_string(),
+    ]);
+
+    let error = validate("test", &task).unwrap_err();
+
+    match error {
+        CargoMakeError::MissingTaskOutputs(name, missing) => {
+            assert_eq!(name, "test");
+            assert_eq!(missing, "./target/_temp/task_outputs/not_found/*.bin");
+        }
+        _ => panic!("invalid error"),
+    }
+}
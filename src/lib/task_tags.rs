@@ -0,0 +1,72 @@
+//! # task_tags
+//!
+//! Selects the tasks to run (or skip) based on the task tags provided via the cli.
+//!
+
+#[cfg(test)]
+#[path = "task_tags_test.rs"]
+mod task_tags_test;
+
+use crate::error::CargoMakeError;
+use crate::types::{Config, DependencyIdentifier, Task};
+
+/// The name of the task generated to run all the tagged tasks
+pub(crate) static TAGGED_TASKS_NAME: &str = "cargo_make_run_tagged_tasks";
+
+fn has_tag(task: &Task, tags: &[String]) -> bool {
+    match task.tags {
+        Some(ref task_tags) => task_tags.iter().any(|tag| tags.contains(tag)),
+        None => false,
+    }
+}
+
+/// Returns the names of all tasks with any of the provided tags (in makefile order)
+pub(crate) fn get_tagged_tasks(config: &Config, tags: &[String]) -> Vec<String> {
+    config
+        .tasks
+        .iter()
+        .filter(|(_, task)| has_tag(task, tags))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Disables all tasks with the skip tags and, if tags are provided, adds a task which
+/// depends on all the tagged tasks and returns its name
+pub(crate) fn apply(
+    config: &mut Config,
+    tags: &Option<Vec<String>>,
+    skip_tags: &Option<Vec<String>>,
+) -> Result<Option<String>, CargoMakeError> {
+    if let Some(ref skip_tags) = skip_tags {
+        for (name, task) in config.tasks.iter_mut() {
+            if has_tag(task, skip_tags) {
+                debug!("Skipping Task: {} (tagged)", name);
+                task.disabled = Some(true);
+            }
+        }
+    }
+
+    match tags {
+        Some(ref tags) => {
+            let names: Vec<String> = get_tagged_tasks(config, tags)
+                .into_iter()
+                .filter(|name| !config.tasks[name].disabled.unwrap_or(false))
+                .collect();
+
+            if names.is_empty() {
+                return Err(CargoMakeError::NotFound(format!(
+                    "No tasks found with tags: {}",
+                    tags.join(", ")
+                )));
+            }
+
+            let mut task = Task::new();
+            task.description = Some(format!("Runs all tasks tagged: {}", tags.join(", ")));
+            task.dependencies = Some(names.into_iter().map(DependencyIdentifier::Name).collect());
+            config.tasks.insert(TAGGED_TASKS_NAME.to_string(), task);
+
+            Ok(Some(TAGGED_TASKS_NAME.to_string()))
+        }
+        None => Ok(None),
+    }
+}
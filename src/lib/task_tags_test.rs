@@ -0,0 +1,121 @@
+use super::*;
+
+fn create_tagged_task(tags: &[&str]) -> Task {
+    let mut task = Task::new();
+    task.tags = Some(tags.iter().map(|tag| tag.to_string()).collect());
+    task
+}
+
+fn create_config() -> Config {
+    let mut config = Config::default();
+    config
+        .tasks
+        .insert("lint".to_string(), create_tagged_task(&["ci"]));
+    config.tasks.insert("build".to_string(), Task::new());
+    config
+        .tasks
+        .insert("test".to_string(), create_tagged_task(&["ci", "slow"]));
+    config
+        .tasks
+        .insert("bench".to_string(), create_tagged_task(&["slow"]));
+    config
+}
+
+fn get_dependencies(config: &Config, name: &str) -> Vec<String> {
+    config.tasks[name]
+        .dependencies
+        .clone()
+        .unwrap()
+        .iter()
+        .map(|dependency| match dependency {
+            DependencyIdentifier::Name(name) => name.to_string(),
+            DependencyIdentifier::Definition(identifier) => identifier.name.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn get_tagged_tasks_found() {
+    let config = create_config();
+
+    let names = get_tagged_tasks(&config, &["ci".to_string()]);
+    assert_eq!(names, vec!["lint", "test"]);
+
+    let names = get_tagged_tasks(&config, &["slow".to_string(), "ci".to_string()]);
+    assert_eq!(names, vec!["lint", "test", "bench"]);
+}
+
+#[test]
+fn get_tagged_tasks_not_found() {
+    let config = create_config();
+
+    let names = get_tagged_tasks(&config, &["release".to_string()]);
+    assert!(names.is_empty());
+}
+
+#[test]
+fn apply_no_tags() {
+    let mut config = create_config();
+
+    let output = apply(&mut config, &None, &None).unwrap();
+
+    assert!(output.is_none());
+    assert_eq!(config.tasks.len(), 4);
+    assert!(config.tasks.values().all(|task| task.disabled.is_none()));
+}
+
+#[test]
+fn apply_tags() {
+    let mut config = create_config();
+
+    let output = apply(&mut config, &Some(vec!["ci".to_string()]), &None).unwrap();
+
+    assert_eq!(output.unwrap(), TAGGED_TASKS_NAME);
+    assert_eq!(
+        get_dependencies(&config, TAGGED_TASKS_NAME),
+        vec!["lint", "test"]
+    );
+}
+
+#[test]
+fn apply_skip_tags() {
+    let mut config = create_config();
+
+    let output = apply(&mut config, &None, &Some(vec!["slow".to_string()])).unwrap();
+
+    assert!(output.is_none());
+    assert!(config.tasks["lint"].disabled.is_none());
+    assert!(config.tasks["build"].disabled.is_none());
+    assert!(config.tasks["test"].disabled.unwrap());
+    assert!(config.tasks["bench"].disabled.unwrap());
+}
+
+#[test]
+fn apply_tags_and_skip_tags() {
+    let mut config = create_config();
+
+    let output = apply(
+        &mut config,
+        &Some(vec!["ci".to_string()]),
+        &Some(vec!["slow".to_string()]),
+    )
+    .unwrap();
+
+    assert_eq!(output.unwrap(), TAGGED_TASKS_NAME);
+    assert_eq!(get_dependencies(&config, TAGGED_TASKS_NAME), vec!["lint"]);
+}
+
+#[test]
+fn apply_tags_not_found() {
+    let mut config = create_config();
+
+    let error = apply(
+        &mut config,
+        &Some(vec!["slow".to_string()]),
+        &Some(vec!["slow".to_string()]),
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, CargoMakeError::NotFound(_)));
+    assert!(!config.tasks.contains_key(TAGGED_TASKS_NAME));
+}
@@ -0,0 +1,164 @@
+//! # task_throttle
+//!
+//! Skips tasks which already ran successfully within their minimal interval (min_interval
+//! attribute) and records the last successful run time of those tasks.
+//!
+
+#[cfg(test)]
+#[path = "task_throttle_test.rs"]
+mod task_throttle_test;
+
+use crate::dry_run;
+use crate::history;
+use crate::storage;
+use crate::types::{CliArgs, Task};
+use fsio::file::{read_text_file, write_text_file};
+use indexmap::IndexMap;
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static THROTTLE_DIRECTORY: &str = "throttle";
+
+pub(crate) fn init(cli_args: &CliArgs) {
+    if cli_args.force {
+        envmnt::set_bool("CARGO_MAKE_IGNORE_MIN_INTERVAL", true);
+    }
+}
+
+fn is_forced() -> bool {
+    envmnt::is("CARGO_MAKE_IGNORE_MIN_INTERVAL")
+}
+
+/// Parses the interval (a number followed by an optional s, m, h, d or w unit) to seconds
+fn parse_interval(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, multiplier) = match value.char_indices().last()? {
+        (index, 's') => (&value[..index], 1),
+        (index, 'm') => (&value[..index], 60),
+        (index, 'h') => (&value[..index], 60 * 60),
+        (index, 'd') => (&value[..index], 24 * 60 * 60),
+        (index, 'w') => (&value[..index], 7 * 24 * 60 * 60),
+        _ => (value, 1),
+    };
+
+    number
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(|number| number * multiplier)
+}
+
+fn format_interval(seconds: u64) -> String {
+    if seconds >= 24 * 60 * 60 {
+        format!(
+            "{}d {}h",
+            seconds / (24 * 60 * 60),
+            seconds % (24 * 60 * 60) / (60 * 60)
+        )
+    } else if seconds >= 60 * 60 {
+        format!("{}h {}m", seconds / (60 * 60), seconds % (60 * 60) / 60)
+    } else if seconds >= 60 {
+        format!("{}m {}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+fn get_current_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Returns the last runs file of the current directory, so each workspace member has its own
+/// last runs
+fn get_last_runs_file() -> Option<PathBuf> {
+    let directory = env::current_dir()
+        .map(|directory| directory.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    storage::get_storage_directory(dirs_next::data_local_dir(), THROTTLE_DIRECTORY, false).map(
+        |storage_directory| {
+            storage_directory
+                .join(THROTTLE_DIRECTORY)
+                .join(format!("{}.json", history::get_directory_key(&directory)))
+        },
+    )
+}
+
+fn load_last_runs(file: &PathBuf) -> IndexMap<String, u64> {
+    read_text_file(file)
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn store_last_runs(file: &PathBuf, last_runs: &IndexMap<String, u64>) {
+    let text = match serde_json::to_string_pretty(last_runs) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+
+    if let Err(error) = write_text_file(file, &text) {
+        debug!(
+            "Unable to write task last runs file: {:?} {:?}",
+            file, error
+        );
+    }
+}
+
+/// Returns the seconds since the last successful run in case it is within the interval
+fn get_elapsed_within_interval(interval: u64, last_run: Option<u64>, now: u64) -> Option<u64> {
+    let elapsed = now.saturating_sub(last_run?);
+
+    if elapsed < interval {
+        Some(elapsed)
+    } else {
+        None
+    }
+}
+
+/// Returns the skip reason in case the task already ran successfully within its minimal interval
+pub(crate) fn get_skip_reason(task_name: &str, task: &Task) -> Option<String> {
+    let min_interval = task.min_interval.as_ref()?;
+    if is_forced() {
+        return None;
+    }
+
+    let interval = match parse_interval(min_interval) {
+        Some(interval) => interval,
+        None => {
+            warn!(
+                "Invalid min_interval: {} defined for task: {}",
+                min_interval, task_name
+            );
+            return None;
+        }
+    };
+
+    let last_run =
+        get_last_runs_file().and_then(|file| load_last_runs(&file).get(task_name).copied());
+
+    get_elapsed_within_interval(interval, last_run, get_current_time()).map(|elapsed| {
+        format!(
+            "last successful run was {} ago, min interval is {}, use --force to run it",
+            format_interval(elapsed),
+            min_interval
+        )
+    })
+}
+
+/// Records the successful run time of tasks which define a minimal interval
+pub(crate) fn record(task_name: &str, task: &Task) {
+    if task.min_interval.is_none() || dry_run::is_enabled() {
+        return;
+    }
+
+    if let Some(file) = get_last_runs_file() {
+        let mut last_runs = load_last_runs(&file);
+        last_runs.insert(task_name.to_string(), get_current_time());
+        store_last_runs(&file, &last_runs);
+    }
+}
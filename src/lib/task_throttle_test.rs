@@ -0,0 +1,100 @@
+use super::*;
+use crate::test;
+
+#[test]
+fn parse_interval_no_unit() {
+    assert_eq!(parse_interval("30").unwrap(), 30);
+}
+
+#[test]
+fn parse_interval_seconds() {
+    assert_eq!(parse_interval("45s").unwrap(), 45);
+}
+
+#[test]
+fn parse_interval_minutes() {
+    assert_eq!(parse_interval("30m").unwrap(), 30 * 60);
+}
+
+#[test]
+fn parse_interval_hours() {
+    assert_eq!(parse_interval(" 24h ").unwrap(), 24 * 60 * 60);
+}
+
+#[test]
+fn parse_interval_days() {
+    assert_eq!(parse_interval("7d").unwrap(), 7 * 24 * 60 * 60);
+}
+
+#[test]
+fn parse_interval_weeks() {
+    assert_eq!(parse_interval("2w").unwrap(), 2 * 7 * 24 * 60 * 60);
+}
+
+#[test]
+fn parse_interval_invalid() {
+    assert!(parse_interval("").is_none());
+    assert!(parse_interval("h").is_none());
+    assert!(parse_interval("abc").is_none());
+    assert!(parse_interval("-1h").is_none());
+    assert!(parse_interval("1y").is_none());
+}
+
+#[test]
+fn format_interval_values() {
+    assert_eq!(format_interval(5), "5s");
+    assert_eq!(format_interval(125), "2m 5s");
+    assert_eq!(format_interval(2 * 60 * 60 + 5 * 60), "2h 5m");
+    assert_eq!(format_interval(3 * 24 * 60 * 60 + 4 * 60 * 60), "3d 4h");
+}
+
+#[test]
+fn get_elapsed_within_interval_never_ran() {
+    assert!(get_elapsed_within_interval(60, None, 1000).is_none());
+}
+
+#[test]
+fn get_elapsed_within_interval_within() {
+    assert_eq!(
+        get_elapsed_within_interval(60, Some(950), 1000).unwrap(),
+        50
+    );
+}
+
+#[test]
+fn get_elapsed_within_interval_expired() {
+    assert!(get_elapsed_within_interval(60, Some(940), 1000).is_none());
+}
+
+#[test]
+fn get_skip_reason_no_min_interval() {
+    assert!(get_skip_reason("test", &Task::new()).is_none());
+}
+
+#[test]
+fn get_skip_reason_invalid_min_interval() {
+    let mut task = Task::new();
+    task.min_interval = Some("bad".to_string());
+
+    assert!(get_skip_reason("test", &task).is_none());
+}
+
+#[test]
+fn store_and_load_last_runs() {
+    let directory = test::get_temp_test_directory("task_throttle_store_and_load_last_runs");
+    let file = directory.join("last_runs.json");
+
+    let mut last_runs = IndexMap::new();
+    last_runs.insert("audit".to_string(), 1000);
+    last_runs.insert("outdated".to_string(), 2000);
+    store_last_runs(&file, &last_runs);
+
+    assert_eq!(load_last_runs(&file), last_runs);
+}
+
+#[test]
+fn load_last_runs_file_not_found() {
+    let directory = test::get_temp_test_directory("task_throttle_load_last_runs_file_not_found");
+
+    assert!(load_last_runs(&directory.join("last_runs.json")).is_empty());
+}
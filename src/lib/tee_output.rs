@@ -0,0 +1,165 @@
+//! # tee_output
+//!
+//! Writes the combined output (stdout and stderr) of each task to a per task file under the
+//! target directory while it is still printed to the console, so the output of any step can be
+//! inspected once the flow is done.
+//!
+
+#[cfg(test)]
+#[path = "tee_output_test.rs"]
+mod tee_output_test;
+
+use crate::types::Config;
+use chrono::Local;
+use std::cell::RefCell;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex};
+
+/// Holds the output directory of the current flow (shared with forked cargo-make processes)
+static DIRECTORY_ENV_VAR_NAME: &str = "CARGO_MAKE_TEE_OUTPUT_DIRECTORY";
+
+/// The task output file, created once the task writes its first output line
+pub(crate) struct TaskFile {
+    path: PathBuf,
+    file: Mutex<Option<File>>,
+}
+
+thread_local! {
+    /// The output file of the task running in the current thread
+    static CURRENT_FILE: RefCell<Option<Arc<TaskFile>>> = const { RefCell::new(None) };
+}
+
+fn create_flow_id() -> String {
+    format!("{}-{}", Local::now().format("%Y%m%d-%H%M%S"), process::id())
+}
+
+fn create_directory_path(flow_id: &str) -> PathBuf {
+    let target_directory = envmnt::get_or("CARGO_MAKE_CRATE_TARGET_DIRECTORY", "target");
+    let directory = Path::new(&target_directory)
+        .join("cargo-make")
+        .join("output")
+        .join(flow_id);
+
+    // tasks may change the working directory
+    match env::current_dir() {
+        Ok(cwd) => cwd.join(directory),
+        Err(_) => directory,
+    }
+}
+
+pub(crate) fn init(config: &Config) {
+    // forked cargo-make processes write to the directory of the top level flow
+    if config.config.tee_output.unwrap_or(false) && !envmnt::exists(DIRECTORY_ENV_VAR_NAME) {
+        let directory = create_directory_path(&create_flow_id());
+        info!("Task output files directory: {}", directory.display());
+
+        envmnt::set(DIRECTORY_ENV_VAR_NAME, &directory);
+    }
+}
+
+pub(crate) fn is_enabled() -> bool {
+    envmnt::exists(DIRECTORY_ENV_VAR_NAME)
+}
+
+/// Returns the task output file name (namespace separators and other special characters are
+/// replaced)
+fn get_file_name(task_name: &str) -> String {
+    let name: String = task_name
+        .chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() || character == '-' || character == '_' {
+                character
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("{}.log", name)
+}
+
+impl TaskFile {
+    fn new(directory: &Path, task_name: &str) -> TaskFile {
+        TaskFile {
+            path: directory.join(get_file_name(task_name)),
+            file: Mutex::new(None),
+        }
+    }
+
+    /// Opens the task output file, the output of repeated task invocations in the same flow is
+    /// appended
+    fn open(&self) -> io::Result<File> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+    }
+
+    fn write_line(&self, line: &str) -> io::Result<()> {
+        let mut file = match self.file.lock() {
+            Ok(file) => file,
+            Err(_) => return Ok(()),
+        };
+
+        if file.is_none() {
+            *file = Some(self.open()?);
+        }
+        match file.as_mut() {
+            Some(file) => writeln!(file, "{}", line),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Runs the provided function while the forwarded output lines are written to the provided
+/// file and returns the function result
+fn run_with_file<T, F: FnOnce() -> T>(file: Option<Arc<TaskFile>>, function: F) -> T {
+    let previous = CURRENT_FILE.with(|current| current.replace(file));
+
+    let result = function();
+
+    CURRENT_FILE.with(|current| current.replace(previous));
+    result
+}
+
+/// Runs the provided function while the task output is also written to the task output file
+/// (if enabled) and returns the function result
+pub(crate) fn tee_task<T, F: FnOnce() -> T>(task_name: &str, function: F) -> T {
+    match env::var(DIRECTORY_ENV_VAR_NAME) {
+        Ok(directory) => {
+            let file = TaskFile::new(Path::new(&directory), task_name);
+            run_with_file(Some(Arc::new(file)), function)
+        }
+        Err(_) => function(),
+    }
+}
+
+/// Returns the output file of the task running in the current thread
+pub(crate) fn get_current_file() -> Option<Arc<TaskFile>> {
+    CURRENT_FILE.with(|current| current.borrow().clone())
+}
+
+/// Sets the output file for the current thread (used by the output forwarding threads)
+pub(crate) fn set_current_file(file: Option<Arc<TaskFile>>) {
+    CURRENT_FILE.with(|current| current.replace(file));
+}
+
+/// Writes the output line to the output file of the task running in the current thread
+pub(crate) fn write_line(line: &str) {
+    if let Some(file) = get_current_file() {
+        if let Err(error) = file.write_line(line) {
+            debug!(
+                "Unable to write to the task output file: {:?}, {}",
+                &file.path, error
+            );
+        }
+    }
+}
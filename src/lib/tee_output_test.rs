@@ -0,0 +1,71 @@
+use super::*;
+use crate::output;
+use regex::Regex;
+use std::io::Cursor;
+
+#[test]
+fn create_flow_id_format() {
+    let flow_id = create_flow_id();
+
+    let regex = Regex::new(r"^\d{8}-\d{6}-\d+$").unwrap();
+    assert!(regex.is_match(&flow_id), "{}", flow_id);
+}
+
+#[test]
+fn create_directory_path_under_target() {
+    let directory = create_directory_path("flow");
+
+    assert!(directory.is_absolute());
+    assert!(directory.ends_with(Path::new("cargo-make").join("output").join("flow")));
+}
+
+#[test]
+fn get_file_name_simple() {
+    assert_eq!(get_file_name("build-all_1"), "build-all_1.log");
+}
+
+#[test]
+fn get_file_name_special_characters() {
+    assert_eq!(get_file_name("ns::build"), "ns__build.log");
+    assert_eq!(get_file_name("a b/c"), "a_b_c.log");
+}
+
+#[test]
+fn write_line_no_current_file() {
+    write_line("not written");
+
+    assert!(get_current_file().is_none());
+}
+
+#[test]
+fn run_with_file_writes_lines() {
+    let directory = Path::new("./target/_temp/tee_output");
+    let file_path = directory.join("ns__task.log");
+    fs::remove_file(&file_path).unwrap_or(());
+
+    let file = TaskFile::new(directory, "ns::task");
+    let result = run_with_file(Some(Arc::new(file)), || {
+        write_line("first");
+        // forwarded process output lines are written by the forwarding thread
+        output::forward_lines(Cursor::new(b"second\n".to_vec()), false)
+            .join()
+            .unwrap();
+
+        true
+    });
+
+    assert!(result);
+    assert!(get_current_file().is_none());
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "first\nsecond\n");
+}
+
+#[test]
+fn run_with_file_no_output() {
+    let directory = Path::new("./target/_temp/tee_output");
+    let file_path = directory.join("no_output.log");
+    fs::remove_file(&file_path).unwrap_or(());
+
+    run_with_file(Some(Arc::new(TaskFile::new(directory, "no_output"))), || ());
+
+    assert!(!file_path.exists());
+}
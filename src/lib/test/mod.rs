@@ -133,6 +133,12 @@ pub(crate) fn create_empty_flow_info() -> FlowInfo {
             env_scripts: vec![],
             tasks: IndexMap::new(),
             plugins: None,
+            cross: None,
+            coverage: None,
+            clippy: None,
+            audit: None,
+            bench: None,
+            notifications: None,
         },
         task: "test".to_string(),
         env_info: EnvInfo {
@@ -146,6 +152,8 @@ pub(crate) fn create_empty_flow_info() -> FlowInfo {
         allow_private: false,
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
+        from_step: None,
+        skip_steps: None,
         cli_arguments: None,
     }
 }
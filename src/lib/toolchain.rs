@@ -7,6 +7,7 @@
 #[path = "toolchain_test.rs"]
 mod toolchain_test;
 
+use crate::binary_lookup;
 use crate::environment::expand_value;
 use crate::types::{CommandSpec, ToolchainSpecifier};
 use semver::{Prerelease, Version};
@@ -113,6 +114,17 @@ fn check_toolchain(toolchain: &ToolchainSpecifier) {
 }
 
 pub(crate) fn get_cargo_binary_path(toolchain: &ToolchainSpecifier) -> Option<String> {
+    // the lookup also validates the min version
+    let key = format!(
+        "{}:{}",
+        get_channel(toolchain),
+        toolchain.min_version().unwrap_or("")
+    );
+
+    binary_lookup::get_cargo_binary_path(&key, || lookup_cargo_binary_path(toolchain))
+}
+
+fn lookup_cargo_binary_path(toolchain: &ToolchainSpecifier) -> Option<String> {
     let command_spec = wrap_command(
         toolchain,
         "rustup",
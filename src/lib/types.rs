@@ -83,10 +83,17 @@ pub struct CliArgs {
     pub command: String,
     /// The external Makefile.toml path
     pub build_file: Option<String>,
+    /// Additional Makefile.toml paths provided by repeating the makefile argument, merged over
+    /// the build file (in order)
+    pub additional_build_files: Option<Vec<String>>,
     /// The task to invoke
     pub task: String,
+    /// True if no task was provided and the default task name is used
+    pub default_task: bool,
     /// The profile name
     pub profile: Option<String>,
+    /// Additional profile names provided by repeating the profile argument (in order)
+    pub additional_profiles: Option<Vec<String>>,
     /// Log level name
     pub log_level: String,
     /// Disables colorful output
@@ -97,8 +104,8 @@ pub struct CliArgs {
     pub cwd: Option<String>,
     /// Environment variables
     pub env: Option<Vec<String>>,
-    /// Environment variables file
-    pub env_file: Option<String>,
+    /// Environment variables files (loaded in order)
+    pub env_file: Option<Vec<String>>,
     /// Prevent workspace support
     pub disable_workspace: bool,
     /// Prevent on error flow even if defined in config section
@@ -109,10 +116,24 @@ pub struct CliArgs {
     pub skip_init_end_tasks: bool,
     /// Skip tasks that match the provided pattern
     pub skip_tasks_pattern: Option<String>,
+    /// Only run the tasks with the provided tags
+    pub tags: Option<Vec<String>>,
+    /// Skip the tasks with the provided tags
+    pub skip_tags: Option<Vec<String>>,
+    /// Task attribute overrides (tasks.<task>.<attribute>=<value>) applied after loading the makefiles
+    pub overrides: Option<Vec<String>>,
+    /// Start the flow at the provided step (skipping all previous steps)
+    pub from_step: Option<String>,
+    /// Skip the provided flow steps
+    pub skip_steps: Option<Vec<String>>,
     /// Only print the execution plan
     pub print_only: bool,
+    /// Print the resolved commands and scripts of the flow without invoking them
+    pub dry_run: bool,
     /// List all known steps
     pub list_all_steps: bool,
+    /// List all known steps as a tree grouped by category and namespace
+    pub list_category_tree: bool,
     /// List steps for a given category
     pub list_category_steps: Option<String>,
     /// Diff flows
@@ -121,16 +142,42 @@ pub struct CliArgs {
     pub disable_check_for_updates: bool,
     /// Allows access unsupported experimental predefined tasks
     pub experimental: bool,
+    /// Fail on unknown keys in the makefiles
+    pub strict: bool,
+    /// Automatically confirm the task confirmation prompts
+    pub assume_yes: bool,
     /// additional command line arguments
     pub arguments: Option<Vec<String>>,
     /// Output format
     pub output_format: String,
     /// Output file name
     pub output_file: Option<String>,
+    /// The format of the reported errors (human or json)
+    pub error_format: String,
+    /// Export the resolved task env to the provided dotenv/JSON file instead of invoking the task
+    pub export_env: Option<String>,
+    /// Answer the provided JSON query request (or the requests read from stdin if '-')
+    pub query: Option<String>,
     /// Print time summary at end of the flow
     pub print_time_summary: bool,
+    /// Print the status of each step at end of the flow
+    pub print_status_summary: bool,
     /// Hide any minor tasks such as pre/post hooks
     pub hide_uninteresting: bool,
+    /// Show a progress line for the currently running task
+    pub progress: bool,
+    /// Print the time spent in the internal cargo-make phases
+    pub perf: bool,
+    /// Run the tasks even if they ran successfully within their minimal interval
+    pub force: bool,
+    /// Wait for the running flow to finish in case the project run lock is held
+    pub wait_for_run_lock: bool,
+    /// Prevent the project run lock even if enabled in the config section
+    pub disable_run_lock: bool,
+    /// Run the daemon which serves the loaded makefiles to the later invocations
+    pub daemon: bool,
+    /// Render a live dashboard while parallel tasks are running
+    pub ui: bool,
 }
 
 impl CliArgs {
@@ -139,8 +186,11 @@ impl CliArgs {
         CliArgs {
             command: "".to_string(),
             build_file: None,
+            additional_build_files: None,
             task: "default".to_string(),
+            default_task: false,
             profile: None,
+            additional_profiles: None,
             log_level: "info".to_string(),
             disable_color: false,
             completion: None,
@@ -152,17 +202,37 @@ impl CliArgs {
             allow_private: false,
             skip_init_end_tasks: false,
             skip_tasks_pattern: None,
+            tags: None,
+            skip_tags: None,
+            overrides: None,
+            from_step: None,
+            skip_steps: None,
             print_only: false,
+            dry_run: false,
             list_all_steps: false,
+            list_category_tree: false,
             list_category_steps: None,
             diff_execution_plan: false,
             disable_check_for_updates: false,
             experimental: false,
+            strict: false,
+            assume_yes: false,
             arguments: None,
             output_format: "default".to_string(),
+            error_format: "human".to_string(),
             output_file: None,
+            export_env: None,
+            query: None,
             print_time_summary: false,
+            print_status_summary: false,
             hide_uninteresting: false,
+            progress: false,
+            perf: false,
+            force: false,
+            wait_for_run_lock: false,
+            disable_run_lock: false,
+            daemon: false,
+            ui: false,
         }
     }
 }
@@ -172,6 +242,23 @@ pub(crate) struct RunTaskOptions {
     pub(crate) plugins_enabled: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+/// The options used when printing the execution plan without running it
+pub struct PrintStepsOptions<'a> {
+    /// The output format (default, short-description, json or yaml)
+    pub output_format: &'a str,
+    /// True to ignore the workspace members
+    pub disable_workspace: bool,
+    /// The pattern of the task names to skip
+    pub skip_tasks_pattern: Option<&'a str>,
+    /// True to skip the init and end tasks
+    pub skip_init_end_tasks: bool,
+    /// The step to start the execution plan from
+    pub from_step: Option<&'a str>,
+    /// The steps to remove from the execution plan
+    pub skip_steps: Option<&'a [String]>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 /// Holds persisted data used by cargo-make
 pub struct Cache {
@@ -180,6 +267,10 @@ pub struct Cache {
     pub file_name: Option<String>,
     /// Holds last update check with returned no updates result
     pub last_update_check: Option<u64>,
+    /// Holds the latest published version found by the last update check
+    pub latest_version: Option<String>,
+    /// Holds the cargo binary path per toolchain (see CARGO_MAKE_BINARY_LOOKUP_CACHE)
+    pub cargo_paths: Option<IndexMap<String, String>>,
 }
 
 impl Cache {
@@ -203,8 +294,16 @@ pub struct GlobalConfig {
     pub default_task_name: Option<String>,
     /// Update check minimum time from the previous check (always, daily, weekly, monthly)
     pub update_check_minimum_interval: Option<String>,
+    /// Registry index url searched by the update check instead of crates.io (for example a mirror)
+    pub update_check_index: Option<String>,
+    /// Releases API url used by the self-update command instead of the GitHub releases API
+    pub self_update_url: Option<String>,
     /// True to search for project root in parent directories if current cwd is not a project root
     pub search_project_root: Option<bool>,
+    /// Execution policy applied to all tasks
+    pub policy: Option<ExecutionPolicy>,
+    /// The process exit codes used for each failure class (overridden by the makefile config)
+    pub exit_codes: Option<ExitCodesConfig>,
 }
 
 impl GlobalConfig {
@@ -217,6 +316,19 @@ impl GlobalConfig {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the user level execution policy which restricts what tasks may run
+pub struct ExecutionPolicy {
+    /// Script runners (for example python or @duckscript) tasks are not allowed to use
+    pub deny_script_runners: Option<Vec<String>>,
+    /// If defined, tasks may only spawn the listed commands (and script runners)
+    pub allow_commands: Option<Vec<String>>,
+    /// Commands (and script runners) tasks are not allowed to spawn
+    pub deny_commands: Option<Vec<String>>,
+    /// True to require interactive confirmation before running tasks marked as destructive
+    pub confirm_destructive: Option<bool>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 /// Holds crate workspace info, see <http://doc.crates.io/manifest.html#the-workspace-section>
 pub struct Workspace {
@@ -330,17 +442,51 @@ pub struct FlowInfo {
     pub skip_init_end_tasks: bool,
     /// Skip tasks that match the provided pattern
     pub skip_tasks_pattern: Option<Regex>,
+    /// Start the flow at the provided step (skipping all previous steps)
+    pub from_step: Option<String>,
+    /// Skip the provided flow steps
+    pub skip_steps: Option<Vec<String>>,
     /// additional command line arguments
     pub cli_arguments: Option<Vec<String>>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The outcome of a single flow step
+pub enum StepStatus {
+    /// The step ran successfully
+    Success,
+    /// The step failed
+    Failed,
+    /// The step was skipped (for example due to its conditions)
+    Skipped,
+    /// The step failed but its errors are ignored
+    IgnoredError,
+}
+
+#[derive(Debug, Clone)]
+/// Holds the status of a flow step for the status summary
+pub struct StepStatusInfo {
+    /// The step name
+    pub name: String,
+    /// The step outcome
+    pub status: StepStatus,
+    /// The step duration in milliseconds
+    pub duration: u128,
+    /// The reason the step was skipped
+    pub skip_reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 /// Holds mutable flow state
 pub struct FlowState {
     /// timing info for summary
     pub time_summary: Vec<(String, u128)>,
+    /// the status of each step for the status summary
+    pub status_summary: Vec<StepStatusInfo>,
     /// forced plugin name
     pub forced_plugin: Option<String>,
+    /// the tasks classified as flaky by the task history
+    pub flaky_tasks: Vec<String>,
 }
 
 impl FlowState {
@@ -882,6 +1028,8 @@ pub struct RunTaskDetails {
     pub parallel: Option<bool>,
     /// Cleanup task name
     pub cleanup_task: Option<String>,
+    /// The task arguments used instead of the command line arguments
+    pub args: Option<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -903,6 +1051,13 @@ pub struct RunTaskRoutingInfo {
     pub condition_script_runner_args: Option<Vec<String>>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Holds the run task pipe information
+pub struct RunTaskPipe {
+    /// The task names, the stdout of each task is connected to the stdin of the next task
+    pub pipe: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 /// Run task info
@@ -913,6 +1068,8 @@ pub enum RunTaskInfo {
     Details(RunTaskDetails),
     /// Task conditional selector
     Routing(Vec<RunTaskRoutingInfo>),
+    /// Tasks running together with their stdout/stdin connected
+    Pipe(RunTaskPipe),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -930,6 +1087,8 @@ pub struct WatchOptions {
     pub why: Option<bool>,
     /// Select which files/folders to watch
     pub watch: Option<Vec<String>>,
+    /// Only rerun the workspace members and tests owning the changed files
+    pub targeted: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -1000,6 +1159,8 @@ impl PartialEq for WatchOptions {
             false
         };
 
+        same = same && self.targeted == other.targeted;
+
         if same {
             match self.watch {
                 Some(ref value) => match other.watch {
@@ -1042,6 +1203,24 @@ impl PartialEq for TaskWatchOptions {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Holds the deprecation message and replacement task
+pub struct DeprecationDetails {
+    /// Deprecation message
+    pub message: Option<String>,
+    /// The task name which should be used instead of the deprecated task
+    pub replaced_by: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Holds the old name of a renamed task which is forwarded to the new task
+pub struct ForwardAlias {
+    /// The old task name
+    pub name: String,
+    /// The cargo-make version from which the old task name can no longer be invoked
+    pub until: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 /// Holds deprecation info such as true/false/message
@@ -1050,6 +1229,8 @@ pub enum DeprecationInfo {
     Boolean(bool),
     /// Deprecation message
     Message(String),
+    /// Deprecation message and replacement task
+    Details(DeprecationDetails),
 }
 
 impl PartialEq for DeprecationInfo {
@@ -1063,6 +1244,20 @@ impl PartialEq for DeprecationInfo {
                 DeprecationInfo::Message(other_message) => message == other_message,
                 _ => false,
             },
+            DeprecationInfo::Details(details) => match other {
+                DeprecationInfo::Details(other_details) => details == other_details,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl DeprecationInfo {
+    /// Returns the replacement task name (if defined)
+    pub fn get_replaced_by(&self) -> Option<String> {
+        match self {
+            DeprecationInfo::Details(details) => details.replaced_by.clone(),
+            _ => None,
         }
     }
 }
@@ -1087,6 +1282,30 @@ pub struct ScriptSections {
     pub post: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+/// A single command of a multi command task
+pub struct TaskCommand {
+    /// The command to execute
+    pub command: String,
+    /// The command args
+    pub args: Option<Vec<String>>,
+    /// The env vars set only while the command is invoked
+    pub env: Option<IndexMap<String, String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+/// A single script command with its own error handling
+pub struct ScriptCommand {
+    /// The command line
+    pub cmd: String,
+    /// True to continue the script in case the command fails
+    pub ignore_errors: Option<bool>,
+    /// The command timeout in seconds
+    pub timeout: Option<u64>,
+    /// The amount of times the command is retried in case it fails
+    pub retries: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 /// Script value (text, file name, ...)
@@ -1099,6 +1318,8 @@ pub enum ScriptValue {
     File(FileScriptValue),
     /// Script content split to multiple parts to enable fine tuned extension
     Sections(ScriptSections),
+    /// The script commands, each invoked separately with its own error handling
+    Commands(Vec<ScriptCommand>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -1182,12 +1403,143 @@ pub struct Task {
     pub dependencies: Option<Vec<DependencyIdentifier>>,
     /// The rust toolchain used to invoke the command or install the needed crates/components
     pub toolchain: Option<ToolchainSpecifier>,
+    /// The minimum cargo-make version required to run the task
+    pub min_version: Option<String>,
+    /// The name of the task to run in case this task fails (the error is still propagated afterwards)
+    pub on_error_task: Option<String>,
+    /// The commands invoked in order (each with its own args and env)
+    pub commands: Option<Vec<TaskCommand>>,
+    /// Values requested from the user when the flow starts and stored in env vars
+    pub inputs: Option<Vec<TaskInput>>,
+    /// Confirmation message the user must accept (interactively) before the task is invoked
+    pub confirm: Option<String>,
+    /// Example invocations of the task shown in the task help and list output
+    pub examples: Option<Vec<String>>,
+    /// The old task name which is forwarded to this task (with a deprecation warning)
+    pub forward_alias: Option<ForwardAlias>,
+    /// The task tags used to select tasks via the --tag and --skip-tag cli options
+    pub tags: Option<Vec<String>>,
+    /// The source files globs used to check if the task outputs are up to date
+    pub sources: Option<Vec<String>>,
+    /// The output files globs produced by the task (the task is skipped if all outputs are newer than the sources)
+    pub outputs: Option<Vec<String>>,
+    /// The minimal interval between the task runs (for example: 30m, 24h or 7d), the task is skipped if it already ran successfully within this interval
+    pub min_interval: Option<String>,
+    /// The env var which is set to the trimmed stdout of the task command/script once the task is done
+    pub env_capture: Option<String>,
+    /// Redirects the stdout/stderr of the task command/script (for example to a log file)
+    pub output: Option<TaskOutputRedirection>,
+    /// The log level used while the task runs (quiet, verbose or any of the cli log levels)
+    pub log_level: Option<String>,
+    /// The named task arguments (flags and positional parameters) mapped to env vars
+    pub args_schema: Option<IndexMap<String, TaskArgument>>,
+    /// if true, the task is destructive and requires confirmation when enforced by the execution policy
+    pub destructive: Option<bool>,
+    /// Runs the command/script inside a container with the working directory mounted
+    pub container: Option<ContainerInfo>,
+    /// Runs the command/script inside the nix develop environment of the flake (true for the current directory flake or the flake reference)
+    pub nix_shell: Option<NixShellValue>,
     /// override task if runtime OS is Linux (takes precedence over alias)
     pub linux: Option<PlatformOverrideTask>,
     /// override task if runtime OS is Windows (takes precedence over alias)
     pub windows: Option<PlatformOverrideTask>,
     /// override task if runtime OS is Mac (takes precedence over alias)
     pub mac: Option<PlatformOverrideTask>,
+    /// override task if running in a continuous integration environment (applied after the platform override)
+    pub ci: Option<PlatformOverrideTask>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// Holds the task stdout/stderr redirection
+pub struct TaskOutputRedirection {
+    /// The stdout target: inherit (default), null or a file path
+    pub stdout: Option<String>,
+    /// The stderr target: inherit (default), null or a file path
+    pub stderr: Option<String>,
+    /// True to append to the output files instead of overwriting them (default false)
+    pub append: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// Holds the container used to run the task command/script
+pub struct ContainerInfo {
+    /// The container image
+    pub image: String,
+    /// The container engine binary (defaults to the CARGO_MAKE_CONTAINER_ENGINE env var or docker)
+    pub engine: Option<String>,
+    /// Additional volume mounts (using the engine syntax, for example ~/.cargo/registry:/usr/local/cargo/registry)
+    pub mounts: Option<Vec<String>>,
+    /// Env vars which are passed from the current environment into the container
+    pub env_passthrough: Option<Vec<String>>,
+    /// Additional container run arguments
+    pub run_args: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// The type of a named task argument
+pub enum TaskArgumentType {
+    /// Any text value
+    String,
+    /// A flag (true if provided without a value)
+    Bool,
+    /// An integer or decimal number
+    Number,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds a named task argument declared in the task args schema
+pub struct TaskArgument {
+    /// The argument type (defaults to string)
+    #[serde(rename = "type")]
+    pub arg_type: Option<TaskArgumentType>,
+    /// The argument help text
+    pub help: Option<String>,
+    /// The default value used if the argument was not provided
+    pub default: Option<String>,
+    /// True if the argument must be provided (ignored if a default value is defined)
+    pub required: Option<bool>,
+    /// True if the argument is provided by position instead of by a --name flag
+    pub positional: Option<bool>,
+    /// The env var set with the argument value (defaults to CARGO_MAKE_TASK_ARG_<NAME>)
+    pub env: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// The validation applied to a task input value
+pub enum TaskInputValidation {
+    /// A semantic version (for example 1.2.3)
+    Semver,
+    /// An integer or decimal number
+    Number,
+    /// true or false
+    Bool,
+    /// Any text value which is not empty
+    NonEmpty,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds a value requested from the user when the flow starts and stored in an env var
+pub struct TaskInput {
+    /// The env var set with the input value (not prompted if already defined)
+    pub env: String,
+    /// The prompt text (defaults to the env var name)
+    pub prompt: Option<String>,
+    /// The default value used if no value is provided
+    pub default: Option<String>,
+    /// The validation applied to the input value
+    pub validate: Option<TaskInputValidation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+/// Nix develop environment used to run the task command/script
+pub enum NixShellValue {
+    /// True to use the flake in the current working directory
+    Enabled(bool),
+    /// The flake reference (for example .#ci or github:owner/repo)
+    Flake(String),
 }
 
 /// A toolchain, defined either as a string (following the rustup syntax)
@@ -1303,6 +1655,8 @@ pub struct TaskIdentifier {
     pub name: String,
     /// The path to the makefile the task resides in
     pub path: Option<String>,
+    /// The task arguments used to expand the task ${@} placeholder
+    pub args: Option<Vec<String>>,
 }
 
 impl std::fmt::Display for TaskIdentifier {
@@ -1320,6 +1674,7 @@ impl TaskIdentifier {
         Self {
             name: name.to_string(),
             path: None,
+            args: None,
         }
     }
 }
@@ -1328,7 +1683,11 @@ impl Into<TaskIdentifier> for DependencyIdentifier {
     fn into(self) -> TaskIdentifier {
         match self {
             DependencyIdentifier::Definition(identifier) => identifier,
-            DependencyIdentifier::Name(name) => TaskIdentifier { name, path: None },
+            DependencyIdentifier::Name(name) => TaskIdentifier {
+                name,
+                path: None,
+                args: None,
+            },
         }
     }
 }
@@ -1440,6 +1799,15 @@ impl Task {
 
                                 RunTaskInfo::Routing(routing_info_vector)
                             }
+                            RunTaskInfo::Pipe(mut run_task_pipe) => {
+                                run_task_pipe.pipe = run_task_pipe
+                                    .pipe
+                                    .iter()
+                                    .map(|name| get_namespaced_task_name(namespace, name))
+                                    .collect();
+
+                                RunTaskInfo::Pipe(run_task_pipe)
+                            }
                         };
 
                         self.run_task = Some(run_task);
@@ -1673,6 +2041,114 @@ impl Task {
             self.toolchain = None;
         }
 
+        if task.min_version.is_some() {
+            self.min_version = task.min_version.clone();
+        } else if override_values {
+            self.min_version = None;
+        }
+
+        if task.on_error_task.is_some() {
+            self.on_error_task = task.on_error_task.clone();
+        } else if override_values {
+            self.on_error_task = None;
+        }
+
+        if task.commands.is_some() {
+            self.commands = task.commands.clone();
+        } else if override_values {
+            self.commands = None;
+        }
+
+        if task.inputs.is_some() {
+            self.inputs = task.inputs.clone();
+        } else if override_values {
+            self.inputs = None;
+        }
+
+        if task.confirm.is_some() {
+            self.confirm = task.confirm.clone();
+        } else if override_values {
+            self.confirm = None;
+        }
+
+        if task.examples.is_some() {
+            self.examples = task.examples.clone();
+        } else if override_values {
+            self.examples = None;
+        }
+
+        if task.forward_alias.is_some() {
+            self.forward_alias = task.forward_alias.clone();
+        } else if override_values {
+            self.forward_alias = None;
+        }
+
+        if task.tags.is_some() {
+            self.tags = task.tags.clone();
+        } else if override_values {
+            self.tags = None;
+        }
+
+        if task.outputs.is_some() {
+            self.outputs = task.outputs.clone();
+        } else if override_values {
+            self.outputs = None;
+        }
+
+        if task.sources.is_some() {
+            self.sources = task.sources.clone();
+        } else if override_values {
+            self.sources = None;
+        }
+
+        if task.min_interval.is_some() {
+            self.min_interval = task.min_interval.clone();
+        } else if override_values {
+            self.min_interval = None;
+        }
+
+        if task.env_capture.is_some() {
+            self.env_capture = task.env_capture.clone();
+        } else if override_values {
+            self.env_capture = None;
+        }
+
+        if task.output.is_some() {
+            self.output = task.output.clone();
+        } else if override_values {
+            self.output = None;
+        }
+
+        if task.log_level.is_some() {
+            self.log_level = task.log_level.clone();
+        } else if override_values {
+            self.log_level = None;
+        }
+
+        if task.args_schema.is_some() {
+            self.args_schema = task.args_schema.clone();
+        } else if override_values {
+            self.args_schema = None;
+        }
+
+        if task.destructive.is_some() {
+            self.destructive = task.destructive;
+        } else if override_values {
+            self.destructive = None;
+        }
+
+        if task.container.is_some() {
+            self.container = task.container.clone();
+        } else if override_values {
+            self.container = None;
+        }
+
+        if task.nix_shell.is_some() {
+            self.nix_shell = task.nix_shell.clone();
+        } else if override_values {
+            self.nix_shell = None;
+        }
+
         if task.linux.is_some() {
             self.linux = task.linux.clone();
         } else if override_values {
@@ -1690,6 +2166,12 @@ impl Task {
         } else if override_values {
             self.mac = None;
         }
+
+        if task.ci.is_some() {
+            self.ci = task.ci.clone();
+        } else if override_values {
+            self.ci = None;
+        }
     }
 
     /// Returns true if the task ignore_errors attribute is defined and true
@@ -1728,55 +2210,114 @@ impl Task {
         }
     }
 
-    /// Returns a new task based on the override information and current platform.
+    /// Returns the CI override task definition if running in a continuous integration environment.
+    fn get_ci_override(self: &Task, ci: bool) -> Option<PlatformOverrideTask> {
+        match self.ci {
+            Some(ref value) if ci => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns a new task based on the provided override task (already extended with this task values).
+    fn apply_override(self: &Task, override_task: &PlatformOverrideTask) -> Task {
+        Task {
+            clear: self.clear.clone(),
+            description: self.description.clone(),
+            category: self.category.clone(),
+            disabled: override_task.disabled.clone(),
+            private: override_task.private.clone(),
+            deprecated: override_task.deprecated.clone(),
+            extend: override_task.extend.clone(),
+            workspace: self.workspace.clone(),
+            plugin: override_task.plugin.clone(),
+            watch: override_task.watch.clone(),
+            condition: override_task.condition.clone(),
+            condition_script: override_task.condition_script.clone(),
+            condition_script_runner_args: override_task.condition_script_runner_args.clone(),
+            ignore_errors: override_task.ignore_errors.clone(),
+            force: override_task.force.clone(),
+            env_files: override_task.env_files.clone(),
+            env: override_task.env.clone(),
+            cwd: override_task.cwd.clone(),
+            alias: None,
+            linux_alias: None,
+            windows_alias: None,
+            mac_alias: None,
+            install_crate: override_task.install_crate.clone(),
+            install_crate_args: override_task.install_crate_args.clone(),
+            install_script: override_task.install_script.clone(),
+            command: override_task.command.clone(),
+            args: override_task.args.clone(),
+            script: override_task.script.clone(),
+            script_runner: override_task.script_runner.clone(),
+            script_runner_args: override_task.script_runner_args.clone(),
+            script_extension: override_task.script_extension.clone(),
+            run_task: override_task.run_task.clone(),
+            dependencies: override_task.dependencies.clone(),
+            toolchain: override_task.toolchain.clone(),
+            min_version: self.min_version.clone(),
+            on_error_task: override_task.on_error_task.clone(),
+            commands: override_task.commands.clone(),
+            inputs: self.inputs.clone(),
+            confirm: self.confirm.clone(),
+            examples: self.examples.clone(),
+            forward_alias: self.forward_alias.clone(),
+            tags: self.tags.clone(),
+            outputs: self.outputs.clone(),
+            sources: self.sources.clone(),
+            min_interval: self.min_interval.clone(),
+            env_capture: self.env_capture.clone(),
+            output: self.output.clone(),
+            log_level: self.log_level.clone(),
+            args_schema: self.args_schema.clone(),
+            destructive: override_task.destructive,
+            container: override_task.container.clone(),
+            nix_shell: override_task.nix_shell.clone(),
+            linux: None,
+            windows: None,
+            mac: None,
+            ci: None,
+        }
+    }
+
+    /// Returns a new task based on the override information, current platform and CI environment.<br>
+    /// The CI override is applied on top of the platform override.
     pub fn get_normalized_task(self: &mut Task) -> Task {
-        match self.get_override() {
+        let ci = envmnt::is_or("CARGO_MAKE_CI", ci_info::is_ci());
+        self.get_normalized_task_for_ci(ci)
+    }
+
+    fn get_normalized_task_for_ci(self: &mut Task, ci: bool) -> Task {
+        let mut normalized_task = match self.get_override() {
             Some(ref mut override_task) => {
                 override_task.extend(self);
 
-                Task {
-                    clear: self.clear.clone(),
-                    description: self.description.clone(),
-                    category: self.category.clone(),
-                    disabled: override_task.disabled.clone(),
-                    private: override_task.private.clone(),
-                    deprecated: override_task.deprecated.clone(),
-                    extend: override_task.extend.clone(),
-                    workspace: self.workspace.clone(),
-                    plugin: override_task.plugin.clone(),
-                    watch: override_task.watch.clone(),
-                    condition: override_task.condition.clone(),
-                    condition_script: override_task.condition_script.clone(),
-                    condition_script_runner_args: override_task
-                        .condition_script_runner_args
-                        .clone(),
-                    ignore_errors: override_task.ignore_errors.clone(),
-                    force: override_task.force.clone(),
-                    env_files: override_task.env_files.clone(),
-                    env: override_task.env.clone(),
-                    cwd: override_task.cwd.clone(),
-                    alias: None,
-                    linux_alias: None,
-                    windows_alias: None,
-                    mac_alias: None,
-                    install_crate: override_task.install_crate.clone(),
-                    install_crate_args: override_task.install_crate_args.clone(),
-                    install_script: override_task.install_script.clone(),
-                    command: override_task.command.clone(),
-                    args: override_task.args.clone(),
-                    script: override_task.script.clone(),
-                    script_runner: override_task.script_runner.clone(),
-                    script_runner_args: override_task.script_runner_args.clone(),
-                    script_extension: override_task.script_extension.clone(),
-                    run_task: override_task.run_task.clone(),
-                    dependencies: override_task.dependencies.clone(),
-                    toolchain: override_task.toolchain.clone(),
-                    linux: None,
-                    windows: None,
-                    mac: None,
-                }
+                let mut task = self.apply_override(override_task);
+                task.ci = self.ci.clone();
+                task
             }
             None => self.clone(),
+        };
+
+        match normalized_task.get_ci_override(ci) {
+            Some(ref mut override_task) => {
+                override_task.extend(&mut normalized_task);
+
+                normalized_task.apply_override(override_task)
+            }
+            None => normalized_task,
+        }
+    }
+
+    /// Returns the makefile which (last) defined this task.<br>
+    /// Tasks defined in the internal core makefiles do not hold this information.
+    pub fn get_makefile_path(self: &Task) -> Option<String> {
+        match self.env {
+            Some(ref env) => match env.get("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE") {
+                Some(EnvValue::Value(ref value)) => Some(value.to_string()),
+                _ => None,
+            },
+            None => None,
         }
     }
 
@@ -1813,13 +2354,16 @@ impl Task {
         let mut actions_count = 0;
 
         if self.run_task.is_some() {
-            actions_count = actions_count + 1;
+            actions_count += 1;
         }
         if self.command.is_some() {
-            actions_count = actions_count + 1;
+            actions_count += 1;
         }
         if self.script.is_some() {
-            actions_count = actions_count + 1;
+            actions_count += 1;
+        }
+        if self.commands.is_some() {
+            actions_count += 1;
         }
 
         actions_count
@@ -1945,6 +2489,16 @@ pub struct PlatformOverrideTask {
     pub dependencies: Option<Vec<DependencyIdentifier>>,
     /// The rust toolchain used to invoke the command or install the needed crates/components
     pub toolchain: Option<ToolchainSpecifier>,
+    /// The name of the task to run in case this task fails (the error is still propagated afterwards)
+    pub on_error_task: Option<String>,
+    /// The commands invoked in order (each with its own args and env)
+    pub commands: Option<Vec<TaskCommand>>,
+    /// if true, the task is destructive and requires confirmation when enforced by the execution policy
+    pub destructive: Option<bool>,
+    /// Runs the command/script inside a container with the working directory mounted
+    pub container: Option<ContainerInfo>,
+    /// Runs the command/script inside the nix develop environment of the flake (true for the current directory flake or the flake reference)
+    pub nix_shell: Option<NixShellValue>,
 }
 
 impl PlatformOverrideTask {
@@ -2066,6 +2620,26 @@ impl PlatformOverrideTask {
             if self.toolchain.is_none() && task.toolchain.is_some() {
                 self.toolchain = task.toolchain.clone();
             }
+
+            if self.on_error_task.is_none() && task.on_error_task.is_some() {
+                self.on_error_task = task.on_error_task.clone();
+            }
+
+            if self.commands.is_none() && task.commands.is_some() {
+                self.commands = task.commands.clone();
+            }
+
+            if self.destructive.is_none() && task.destructive.is_some() {
+                self.destructive = task.destructive;
+            }
+
+            if self.container.is_none() && task.container.is_some() {
+                self.container = task.container.clone();
+            }
+
+            if self.nix_shell.is_none() && task.nix_shell.is_some() {
+                self.nix_shell = task.nix_shell.clone();
+            }
         }
     }
 }
@@ -2125,6 +2699,86 @@ impl ModifyConfig {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// Holds the core tasks filter properties
+pub struct CoreTasksConfig {
+    /// The core task groups to load (build, test, docs, coverage, publish, git, wasm), all groups are loaded if not defined
+    pub groups: Option<Vec<String>>,
+    /// Core tasks with names matching any of the regex patterns are not loaded
+    pub skip_patterns: Option<Vec<String>>,
+    /// If set to some value, all core tasks are modified to: namespace::name (same as modify_core_tasks)
+    pub namespace: Option<String>,
+    /// If true and no namespace is defined, all core tasks are modified to: core::name
+    pub namespaced: Option<bool>,
+    /// If true, the namespaced core tasks are also available via aliases with their original names
+    /// (unless a task with that name is defined in the makefile)
+    pub aliases: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// Holds the colors used for the console output (for example: red, bright blue)
+pub struct ColorsConfig {
+    /// The color of the verbose log level
+    pub verbose: Option<String>,
+    /// The color of the info log level
+    pub info: Option<String>,
+    /// The color of the warn log level
+    pub warn: Option<String>,
+    /// The color of the error log level
+    pub error: Option<String>,
+    /// The color of the task names
+    pub task_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// Holds the task name validation policy (unset values use the default policy)
+pub struct TaskNameValidationConfig {
+    /// The maximum task name length (in characters)
+    pub max_length: Option<usize>,
+    /// True to allow unicode alphanumeric characters (by default only ASCII is allowed)
+    pub allow_unicode: Option<bool>,
+    /// The characters allowed in addition to the alphanumeric characters (default: -_)
+    pub allowed_extra_characters: Option<String>,
+    /// The namespace separator (default: ::)
+    pub namespace_separator: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the process exit codes used for each failure class (unset classes keep the default exit code)
+pub struct ExitCodesConfig {
+    /// The exit code used when a task fails
+    pub task_failure: Option<u8>,
+    /// The exit code used when the task conditions could not be validated
+    pub condition_failure: Option<u8>,
+    /// The exit code used when the makefiles could not be loaded
+    pub descriptor_error: Option<u8>,
+    /// The exit code used when a task dependency (crate, component, ...) could not be installed
+    pub install_failure: Option<u8>,
+    /// The exit code used when a command timed out
+    pub timeout: Option<u8>,
+}
+
+impl ExitCodesConfig {
+    /// Copies all defined values from the provided config
+    pub fn extend(self: &mut ExitCodesConfig, extended: &ExitCodesConfig) {
+        if extended.task_failure.is_some() {
+            self.task_failure = extended.task_failure;
+        }
+        if extended.condition_failure.is_some() {
+            self.condition_failure = extended.condition_failure;
+        }
+        if extended.descriptor_error.is_some() {
+            self.descriptor_error = extended.descriptor_error;
+        }
+        if extended.install_failure.is_some() {
+            self.install_failure = extended.install_failure;
+        }
+        if extended.timeout.is_some() {
+            self.timeout = extended.timeout;
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// Unstable cargo-make feature
@@ -2147,19 +2801,39 @@ impl UnstableFeature {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Defines when the ANSI escape sequences are stripped from the task output
+pub enum StripAnsiMode {
+    /// Strip the escape sequences only when the output is not a terminal (for example CI logs)
+    Auto,
+    /// Always strip the escape sequences
+    Always,
+    /// Never strip the escape sequences (default)
+    Never,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 /// Holds the configuration found in the makefile toml config section.
 pub struct ConfigSection {
     /// If true, the default core tasks will not be loaded
     pub skip_core_tasks: Option<bool>,
+    /// If true, only the core tasks reachable from the invoked task will be loaded
+    pub lazy_load_core_tasks: Option<bool>,
     /// Modify core tasks config
     pub modify_core_tasks: Option<ModifyConfig>,
+    /// Filters the loaded core tasks
+    pub core_tasks: Option<CoreTasksConfig>,
     /// Init task name which will be invoked at the start of every run
     pub init_task: Option<String>,
     /// End task name which will be invoked at the end of every run
     pub end_task: Option<String>,
     /// The name of the task to run in case of any error during the invocation of the flow
     pub on_error_task: Option<String>,
+    /// Profile name to the on error task name which replaces the on_error_task while the profile is active
+    pub profile_on_error_tasks: Option<IndexMap<String, String>>,
+    /// Profile name to the task name which is invoked while the profile is active and no task is provided
+    pub profile_default_tasks: Option<IndexMap<String, String>>,
     /// The name of the task which runs legacy migration flows
     pub legacy_migration_task: Option<String>,
     /// Additional profile names to load
@@ -2174,14 +2848,57 @@ pub struct ConfigSection {
     pub skip_rust_env_info: Option<bool>,
     /// do not load current crate env info (save on perf)
     pub skip_crate_env_info: Option<bool>,
+    /// True to evaluate consecutive env scripts in a single shell invocation (save on perf)
+    pub batch_env_scripts: Option<bool>,
+    /// The default nix develop environment for all tasks (see the task nix_shell attribute)
+    pub nix_shell: Option<NixShellValue>,
     /// True to reduce console output for non CI execution
     pub reduce_output: Option<bool>,
     /// True to print time summary at the end of the flow
     pub time_summary: Option<bool>,
+    /// True to print the status of each step at the end of the flow
+    pub status_summary: Option<bool>,
+    /// True to record the task executions in the local task history (see cargo make stats)
+    pub task_history: Option<bool>,
+    /// The amount of times the tasks classified as flaky by the task history are retried in case they fail
+    pub flaky_task_retries: Option<u32>,
+    /// True to prevent concurrent flows in the same project using a lock file
+    pub run_lock: Option<bool>,
+    /// True to show a progress line for the currently running task (TTY only)
+    pub progress: Option<bool>,
+    /// True to prefix each output line with a timestamp (UTC time of day)
+    pub line_prefix_timestamp: Option<bool>,
+    /// True to prefix each output line with the name of the task which emitted it
+    pub line_prefix_task_name: Option<bool>,
+    /// True to also write the combined output of each task to a file under target/cargo-make/output/<flow id>
+    pub tee_output: Option<bool>,
+    /// Defines when ANSI color/cursor escape sequences are stripped from the task output (default never)
+    pub strip_ansi: Option<StripAnsiMode>,
+    /// The terminal width (COLUMNS) reported to the task commands/scripts
+    pub output_width: Option<u32>,
+    /// The console output colors
+    pub colors: Option<ColorsConfig>,
+    /// The task name validation policy
+    pub task_name_validation: Option<TaskNameValidationConfig>,
+    /// The process exit codes used for each failure class
+    pub exit_codes: Option<ExitCodesConfig>,
+    /// True to fail on unknown keys in the makefile instead of only printing a warning
+    pub strict: Option<bool>,
+    /// True to report tasks which are defined in multiple makefiles (including the core makefiles)
+    pub report_duplicate_tasks: Option<bool>,
     /// Automatically load cargo aliases as cargo-make tasks
     pub load_cargo_aliases: Option<bool>,
+    /// Automatically load the .env and .env.<profile> files from the working directory (default true)
+    pub load_dotenv_files: Option<bool>,
+    /// Load the git tag, dirty state and commit timestamp env vars (default false) which
+    /// require running additional git commands
+    pub load_git_metadata: Option<bool>,
+    /// Path to a justfile whose recipes are loaded as cargo-make tasks
+    pub import_just: Option<String>,
     /// If true (default false) disable all automatic/defined installation instructions
     pub disable_install: Option<bool>,
+    /// True to automatically confirm the task confirmation prompts when running in a CI environment
+    pub auto_confirm_on_ci: Option<bool>,
     /// The project information member (used by workspaces)
     pub main_project_member: Option<String>,
     /// Invoked while loading the descriptor file but before loading any extended descriptor
@@ -2227,6 +2944,18 @@ impl ConfigSection {
                     ));
                 }
 
+                if let Some(ref mut profile_on_error_tasks) = self.profile_on_error_tasks {
+                    for task_name in profile_on_error_tasks.values_mut() {
+                        *task_name = get_namespaced_task_name(namespace, task_name);
+                    }
+                }
+
+                if let Some(ref mut profile_default_tasks) = self.profile_default_tasks {
+                    for task_name in profile_default_tasks.values_mut() {
+                        *task_name = get_namespaced_task_name(namespace, task_name);
+                    }
+                }
+
                 if self.legacy_migration_task.is_some() {
                     self.legacy_migration_task = Some(get_namespaced_task_name(
                         namespace,
@@ -2248,10 +2977,18 @@ impl ConfigSection {
             self.skip_core_tasks = extended.skip_core_tasks.clone();
         }
 
+        if extended.lazy_load_core_tasks.is_some() {
+            self.lazy_load_core_tasks = extended.lazy_load_core_tasks;
+        }
+
         if extended.modify_core_tasks.is_some() {
             self.modify_core_tasks = extended.modify_core_tasks.clone();
         }
 
+        if extended.core_tasks.is_some() {
+            self.core_tasks = extended.core_tasks.clone();
+        }
+
         if extended.init_task.is_some() {
             self.init_task = extended.init_task.clone();
         }
@@ -2264,6 +3001,14 @@ impl ConfigSection {
             self.on_error_task = extended.on_error_task.clone();
         }
 
+        if extended.profile_on_error_tasks.is_some() {
+            self.profile_on_error_tasks = extended.profile_on_error_tasks.clone();
+        }
+
+        if extended.profile_default_tasks.is_some() {
+            self.profile_default_tasks = extended.profile_default_tasks.clone();
+        }
+
         if extended.legacy_migration_task.is_some() {
             self.legacy_migration_task = extended.legacy_migration_task.clone();
         }
@@ -2292,6 +3037,14 @@ impl ConfigSection {
             self.skip_crate_env_info = extended.skip_crate_env_info.clone();
         }
 
+        if extended.batch_env_scripts.is_some() {
+            self.batch_env_scripts = extended.batch_env_scripts;
+        }
+
+        if extended.nix_shell.is_some() {
+            self.nix_shell = extended.nix_shell.clone();
+        }
+
         if extended.reduce_output.is_some() {
             self.reduce_output = extended.reduce_output.clone();
         }
@@ -2300,14 +3053,90 @@ impl ConfigSection {
             self.time_summary = extended.time_summary.clone();
         }
 
+        if extended.status_summary.is_some() {
+            self.status_summary = extended.status_summary;
+        }
+
+        if extended.task_history.is_some() {
+            self.task_history = extended.task_history;
+        }
+
+        if extended.flaky_task_retries.is_some() {
+            self.flaky_task_retries = extended.flaky_task_retries;
+        }
+
+        if extended.run_lock.is_some() {
+            self.run_lock = extended.run_lock;
+        }
+
+        if extended.progress.is_some() {
+            self.progress = extended.progress;
+        }
+
+        if extended.line_prefix_timestamp.is_some() {
+            self.line_prefix_timestamp = extended.line_prefix_timestamp;
+        }
+
+        if extended.line_prefix_task_name.is_some() {
+            self.line_prefix_task_name = extended.line_prefix_task_name;
+        }
+
+        if extended.tee_output.is_some() {
+            self.tee_output = extended.tee_output;
+        }
+
+        if extended.strip_ansi.is_some() {
+            self.strip_ansi = extended.strip_ansi;
+        }
+
+        if extended.output_width.is_some() {
+            self.output_width = extended.output_width;
+        }
+
+        if extended.colors.is_some() {
+            self.colors = extended.colors.clone();
+        }
+
+        if extended.task_name_validation.is_some() {
+            self.task_name_validation = extended.task_name_validation.clone();
+        }
+
+        if extended.exit_codes.is_some() {
+            self.exit_codes = extended.exit_codes.clone();
+        }
+
+        if extended.strict.is_some() {
+            self.strict = extended.strict;
+        }
+
+        if extended.report_duplicate_tasks.is_some() {
+            self.report_duplicate_tasks = extended.report_duplicate_tasks;
+        }
+
         if extended.load_cargo_aliases.is_some() {
             self.load_cargo_aliases = extended.load_cargo_aliases.clone();
         }
 
+        if extended.load_dotenv_files.is_some() {
+            self.load_dotenv_files = extended.load_dotenv_files;
+        }
+
+        if extended.load_git_metadata.is_some() {
+            self.load_git_metadata = extended.load_git_metadata;
+        }
+
+        if extended.import_just.is_some() {
+            self.import_just = extended.import_just.clone();
+        }
+
         if extended.disable_install.is_some() {
             self.disable_install = extended.disable_install.clone();
         }
 
+        if extended.auto_confirm_on_ci.is_some() {
+            self.auto_confirm_on_ci = extended.auto_confirm_on_ci;
+        }
+
         if extended.main_project_member.is_some() {
             self.main_project_member = extended.main_project_member.clone();
         }
@@ -2373,6 +3202,313 @@ impl ConfigSection {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Defines how the cross compilation tasks build the targets
+pub enum CrossMode {
+    /// Use cross if it is installed, otherwise use cargo
+    Auto,
+    /// Always use cross (installing it if needed)
+    Cross,
+    /// Always use cargo (installing the rust target if needed)
+    Cargo,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the cross compilation configuration found in the makefile toml cross section.
+pub struct CrossConfig {
+    /// The target triples to create the cross compilation tasks for
+    pub targets: Option<Vec<String>>,
+    /// Defines if cross or cargo is used to build the targets (defaults to auto)
+    pub mode: Option<CrossMode>,
+    /// If true, the targets are built in release mode
+    pub release: Option<bool>,
+    /// The directory the built binaries are copied to (per target sub directory)
+    pub artifacts_directory: Option<String>,
+}
+
+impl CrossConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> CrossConfig {
+        Default::default()
+    }
+
+    /// Copies values from the cross config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other cross config to copy from
+    pub fn extend(self: &mut CrossConfig, extended: &CrossConfig) {
+        if extended.targets.is_some() {
+            self.targets = extended.targets.clone();
+        }
+
+        if extended.mode.is_some() {
+            self.mode = extended.mode;
+        }
+
+        if extended.release.is_some() {
+            self.release = extended.release;
+        }
+
+        if extended.artifacts_directory.is_some() {
+            self.artifacts_directory = extended.artifacts_directory.clone();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+/// Defines the tool used by the coverage tasks
+pub enum CoverageProvider {
+    /// cargo-llvm-cov
+    LlvmCov,
+    /// cargo-tarpaulin
+    Tarpaulin,
+    /// grcov (based on the rust llvm instrumentation)
+    Grcov,
+    /// kcov (linux and mac only)
+    Kcov,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Defines the coverage report format
+pub enum CoverageFormat {
+    /// lcov.info file
+    Lcov,
+    /// html directory
+    Html,
+    /// cobertura.xml file
+    Cobertura,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the coverage configuration found in the makefile toml coverage section.
+pub struct CoverageConfig {
+    /// The tool used by the coverage tasks (defaults to kcov)
+    pub provider: Option<CoverageProvider>,
+    /// The report formats to generate (defaults to lcov)
+    pub formats: Option<Vec<CoverageFormat>>,
+    /// The directory the reports are written to (defaults to target/coverage)
+    pub output_directory: Option<String>,
+}
+
+impl CoverageConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> CoverageConfig {
+        Default::default()
+    }
+
+    /// Copies values from the coverage config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other coverage config to copy from
+    pub fn extend(self: &mut CoverageConfig, extended: &CoverageConfig) {
+        if extended.provider.is_some() {
+            self.provider = extended.provider;
+        }
+
+        if extended.formats.is_some() {
+            self.formats = extended.formats.clone();
+        }
+
+        if extended.output_directory.is_some() {
+            self.output_directory = extended.output_directory.clone();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the clippy configuration found in the makefile toml clippy section.
+pub struct ClippyConfig {
+    /// Additional cargo clippy arguments (for example --all-targets)
+    pub args: Option<Vec<String>>,
+    /// The lints to deny (for example warnings or clippy::pedantic)
+    pub deny: Option<Vec<String>>,
+    /// The lints to warn on
+    pub warn: Option<Vec<String>>,
+    /// The lints to allow
+    pub allow: Option<Vec<String>>,
+}
+
+impl ClippyConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> ClippyConfig {
+        Default::default()
+    }
+
+    /// Copies values from the clippy config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other clippy config to copy from
+    pub fn extend(self: &mut ClippyConfig, extended: &ClippyConfig) {
+        if extended.args.is_some() {
+            self.args = extended.args.clone();
+        }
+
+        if extended.deny.is_some() {
+            self.deny = extended.deny.clone();
+        }
+
+        if extended.warn.is_some() {
+            self.warn = extended.warn.clone();
+        }
+
+        if extended.allow.is_some() {
+            self.allow = extended.allow.clone();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the audit configuration found in the makefile toml audit section.
+pub struct AuditConfig {
+    /// The advisory IDs to ignore (for example RUSTSEC-2020-0071)
+    pub ignore: Option<Vec<String>>,
+    /// The warning kinds to treat as errors (for example warnings, unmaintained or yanked)
+    pub deny: Option<Vec<String>>,
+}
+
+impl AuditConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> AuditConfig {
+        Default::default()
+    }
+
+    /// Copies values from the audit config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other audit config to copy from
+    pub fn extend(self: &mut AuditConfig, extended: &AuditConfig) {
+        if extended.ignore.is_some() {
+            self.ignore = extended.ignore.clone();
+        }
+
+        if extended.deny.is_some() {
+            self.deny = extended.deny.clone();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// Holds the benchmarks configuration found in the makefile toml bench section.
+pub struct BenchConfig {
+    /// The git ref (branch, tag or commit) which stored results are compared against
+    pub baseline: Option<String>,
+    /// The maximum allowed slowdown in percents (defaults to 10)
+    pub threshold: Option<f64>,
+    /// The directory the results are stored in (defaults to .cargo-make/bench)
+    pub directory: Option<String>,
+}
+
+impl BenchConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> BenchConfig {
+        Default::default()
+    }
+
+    /// Copies values from the bench config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other bench config to copy from
+    pub fn extend(self: &mut BenchConfig, extended: &BenchConfig) {
+        if extended.baseline.is_some() {
+            self.baseline = extended.baseline.clone();
+        }
+
+        if extended.threshold.is_some() {
+            self.threshold = extended.threshold;
+        }
+
+        if extended.directory.is_some() {
+            self.directory = extended.directory.clone();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Defines which flow results trigger a notification
+pub enum NotificationTrigger {
+    /// Notify on both success and failure
+    Always,
+    /// Notify only when the flow succeeded
+    Success,
+    /// Notify only when the flow failed
+    Failure,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Defines the payload format of a notification webhook
+pub enum WebhookFormat {
+    /// Slack incoming webhook message
+    Slack,
+    /// Discord webhook message
+    Discord,
+    /// Generic JSON object holding the flow result
+    Json,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// Holds a webhook which is posted the flow result
+pub struct WebhookConfig {
+    /// The webhook url (env vars are expanded)
+    pub url: String,
+    /// The payload format (defaults to json)
+    pub format: Option<WebhookFormat>,
+    /// Overrides the notifications trigger for this webhook
+    pub on: Option<NotificationTrigger>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// Holds the notifications configuration found in the makefile toml notifications section.
+pub struct NotificationsConfig {
+    /// Which flow results trigger the notifications (defaults to always)
+    pub on: Option<NotificationTrigger>,
+    /// Only notify if the flow ran at least the provided amount of seconds
+    pub min_duration: Option<u64>,
+    /// Show a desktop notification
+    pub desktop: Option<bool>,
+    /// The webhooks to post the flow result to
+    pub webhooks: Option<Vec<WebhookConfig>>,
+}
+
+impl NotificationsConfig {
+    /// Creates and returns a new instance.
+    pub fn new() -> NotificationsConfig {
+        Default::default()
+    }
+
+    /// Copies values from the notifications config into self.
+    ///
+    /// # Arguments
+    ///
+    /// * `extended` - The other notifications config to copy from
+    pub fn extend(self: &mut NotificationsConfig, extended: &NotificationsConfig) {
+        if extended.on.is_some() {
+            self.on = extended.on;
+        }
+
+        if extended.min_duration.is_some() {
+            self.min_duration = extended.min_duration;
+        }
+
+        if extended.desktop.is_some() {
+            self.desktop = extended.desktop;
+        }
+
+        if extended.webhooks.is_some() {
+            self.webhooks = extended.webhooks.clone();
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 /// Holds the entire configuration such as task definitions and env vars
 pub struct Config {
@@ -2388,6 +3524,18 @@ pub struct Config {
     pub tasks: IndexMap<String, Task>,
     /// All plugin definitions
     pub plugins: Option<Plugins>,
+    /// Cross compilation config
+    pub cross: Option<CrossConfig>,
+    /// Coverage config
+    pub coverage: Option<CoverageConfig>,
+    /// Clippy config
+    pub clippy: Option<ClippyConfig>,
+    /// Audit config
+    pub audit: Option<AuditConfig>,
+    /// Benchmarks config
+    pub bench: Option<BenchConfig>,
+    /// Notifications config
+    pub notifications: Option<NotificationsConfig>,
 }
 
 impl Config {
@@ -2432,6 +3580,18 @@ pub struct ExternalConfig {
     pub tasks: Option<IndexMap<String, Task>>,
     /// All plugin definitions
     pub plugins: Option<Plugins>,
+    /// Cross compilation config
+    pub cross: Option<CrossConfig>,
+    /// Coverage config
+    pub coverage: Option<CoverageConfig>,
+    /// Clippy config
+    pub clippy: Option<ClippyConfig>,
+    /// Audit config
+    pub audit: Option<AuditConfig>,
+    /// Benchmarks config
+    pub bench: Option<BenchConfig>,
+    /// Notifications config
+    pub notifications: Option<NotificationsConfig>,
 }
 
 impl ExternalConfig {
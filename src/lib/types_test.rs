@@ -172,6 +172,7 @@ fn cli_args_new() {
     assert!(cli_args.build_file.is_none());
     assert_eq!(cli_args.task, "default");
     assert!(cli_args.profile.is_none());
+    assert!(cli_args.additional_profiles.is_none());
     assert_eq!(cli_args.log_level, "info");
     assert!(!cli_args.disable_color);
     assert!(cli_args.cwd.is_none());
@@ -189,9 +190,15 @@ fn cli_args_new() {
     assert!(!cli_args.experimental);
     assert!(cli_args.arguments.is_none());
     assert_eq!(cli_args.output_format, "default");
+    assert_eq!(cli_args.error_format, "human");
     assert!(cli_args.output_file.is_none());
+    assert!(cli_args.export_env.is_none());
     assert!(!cli_args.print_time_summary);
+    assert!(!cli_args.print_status_summary);
     assert!(!cli_args.hide_uninteresting);
+    assert!(!cli_args.progress);
+    assert!(!cli_args.perf);
+    assert!(!cli_args.strict);
 }
 
 #[test]
@@ -212,6 +219,7 @@ fn global_config_new() {
     assert!(global_config.update_check_minimum_interval.is_none());
     assert!(!global_config.search_project_root.unwrap());
     assert!(global_config.disable_color.is_none());
+    assert!(global_config.exit_codes.is_none());
 }
 
 #[test]
@@ -220,6 +228,7 @@ fn cache_new() {
 
     assert!(cache.file_name.is_none());
     assert!(cache.last_update_check.is_none());
+    assert!(cache.cargo_paths.is_none());
 }
 
 #[test]
@@ -1834,9 +1843,28 @@ fn task_extend_both_have_misc_data() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: None,
         windows: None,
         mac: None,
+        ci: None,
     };
 
     base.extend(&extended);
@@ -1936,9 +1964,28 @@ fn task_extend_extended_have_all_fields() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: None,
         toolchain: None,
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: None,
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let mut env = IndexMap::new();
@@ -2000,6 +2047,28 @@ fn task_extend_extended_have_all_fields() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: Some("24h".to_string()),
+        env_capture: Some("RESULT".to_string()),
+        output: Some(TaskOutputRedirection {
+            stdout: Some("build.log".to_string()),
+            stderr: None,
+            append: Some(true),
+        }),
+        log_level: Some("quiet".to_string()),
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -2050,6 +2119,11 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2101,6 +2175,11 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2152,7 +2231,13 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
+        ci: None,
     };
 
     base.extend(&extended);
@@ -2238,6 +2323,10 @@ fn task_extend_extended_have_all_fields() {
     assert_eq!(run_task_name, "task2".to_string());
     assert_eq!(base.dependencies.unwrap().len(), 1);
     assert_eq!(base.toolchain.unwrap(), "toolchain".into());
+    assert_eq!(base.min_interval.unwrap(), "24h");
+    assert_eq!(base.env_capture.unwrap(), "RESULT");
+    assert_eq!(base.output.unwrap().stdout.unwrap(), "build.log");
+    assert_eq!(base.log_level.unwrap(), "quiet");
     assert!(base.linux.unwrap().clear.unwrap());
     assert!(!base.windows.unwrap().clear.unwrap());
     assert!(base.mac.unwrap().clear.is_none());
@@ -2308,6 +2397,24 @@ fn task_extend_clear_with_no_data() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -2358,6 +2465,11 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2409,6 +2521,11 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2460,7 +2577,13 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
+        ci: None,
     };
 
     let mut extended = Task::new();
@@ -2569,6 +2692,24 @@ fn task_extend_clear_with_all_data() {
         run_task: Some(RunTaskInfo::Name("task2".to_string())),
         dependencies: Some(vec!["A".into()]),
         toolchain: Some("toolchain".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(true),
             install_crate: Some(InstallCrate::Value("my crate2".to_string())),
@@ -2619,6 +2760,11 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2670,6 +2816,11 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2721,7 +2872,13 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
+        ci: None,
     };
 
     base.extend(&extended);
@@ -2765,6 +2922,28 @@ fn task_extend_clear_with_all_data() {
     assert!(base.mac.is_some());
 }
 
+#[test]
+fn task_get_makefile_path_none() {
+    let task = Task::new();
+
+    let path = task.get_makefile_path();
+    assert!(path.is_none());
+}
+
+#[test]
+fn task_get_makefile_path_defined() {
+    let mut task = Task::new();
+    let mut env = IndexMap::new();
+    env.insert(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./Makefile.toml".to_string()),
+    );
+    task.env = Some(env);
+
+    let path = task.get_makefile_path();
+    assert_eq!(path.unwrap(), "./Makefile.toml");
+}
+
 #[test]
 fn task_get_alias_all_none() {
     let task = Task::new();
@@ -2837,6 +3016,24 @@ fn task_get_normalized_task_undefined() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain2".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         description: Some("description".to_string()),
         category: Some("category".to_string()),
         workspace: Some(false),
@@ -2844,6 +3041,7 @@ fn task_get_normalized_task_undefined() {
         linux: None,
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -2990,6 +3188,24 @@ fn task_get_normalized_task_with_override_no_clear() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: Some(PlatformOverrideTask {
             clear: None,
             install_crate: Some(InstallCrate::Value("linux_crate".to_string())),
@@ -3053,9 +3269,15 @@ fn task_get_normalized_task_with_override_no_clear() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3216,6 +3438,24 @@ fn task_get_normalized_task_with_override_clear_false() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         linux: Some(PlatformOverrideTask {
             clear: Some(false),
             install_crate: Some(InstallCrate::Value("linux_crate".to_string())),
@@ -3286,9 +3526,15 @@ fn task_get_normalized_task_with_override_clear_false() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3442,6 +3688,24 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         description: None,
         category: None,
         workspace: None,
@@ -3474,9 +3738,15 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3616,6 +3886,24 @@ fn task_get_normalized_task_with_override_clear_true() {
         run_task: Some(RunTaskInfo::Name("task1".to_string())),
         dependencies: Some(vec!["1".into()]),
         toolchain: Some("toolchain1".into()),
+        min_version: None,
+        on_error_task: None,
+        commands: None,
+        inputs: None,
+        confirm: None,
+        examples: None,
+        forward_alias: None,
+        tags: None,
+        outputs: None,
+        sources: None,
+        min_interval: None,
+        env_capture: None,
+        output: None,
+        log_level: None,
+        args_schema: None,
+        destructive: None,
+        container: None,
+        nix_shell: None,
         description: Some("description".to_string()),
         category: Some("category".to_string()),
         workspace: Some(false),
@@ -3648,9 +3936,15 @@ fn task_get_normalized_task_with_override_clear_true() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            on_error_task: None,
+            commands: None,
+            destructive: None,
+            container: None,
+            nix_shell: None,
         }),
         windows: None,
         mac: None,
+        ci: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3893,6 +4187,7 @@ fn task_is_actionable_with_watch_options() {
         no_git_ignore: None,
         why: None,
         watch: None,
+        targeted: None,
     }));
 
     assert!(task.is_actionable());
@@ -3903,10 +4198,13 @@ fn config_section_new() {
     let config = ConfigSection::new();
 
     assert!(config.skip_core_tasks.is_none());
+    assert!(config.lazy_load_core_tasks.is_none());
     assert!(config.modify_core_tasks.is_none());
     assert!(config.init_task.is_none());
     assert!(config.end_task.is_none());
     assert!(config.on_error_task.is_none());
+    assert!(config.profile_on_error_tasks.is_none());
+    assert!(config.profile_default_tasks.is_none());
     assert!(config.legacy_migration_task.is_none());
     assert!(config.additional_profiles.is_none());
     assert!(config.min_version.is_none());
@@ -3914,10 +4212,31 @@ fn config_section_new() {
     assert!(config.skip_git_env_info.is_none());
     assert!(config.skip_rust_env_info.is_none());
     assert!(config.skip_crate_env_info.is_none());
+    assert!(config.batch_env_scripts.is_none());
+    assert!(config.nix_shell.is_none());
     assert!(config.reduce_output.is_none());
     assert!(config.time_summary.is_none());
+    assert!(config.status_summary.is_none());
+    assert!(config.task_history.is_none());
+    assert!(config.run_lock.is_none());
+    assert!(config.flaky_task_retries.is_none());
+    assert!(config.progress.is_none());
+    assert!(config.strict.is_none());
+    assert!(config.report_duplicate_tasks.is_none());
+    assert!(config.line_prefix_timestamp.is_none());
+    assert!(config.colors.is_none());
+    assert!(config.task_name_validation.is_none());
+    assert!(config.exit_codes.is_none());
+    assert!(config.line_prefix_task_name.is_none());
+    assert!(config.tee_output.is_none());
+    assert!(config.strip_ansi.is_none());
+    assert!(config.output_width.is_none());
     assert!(config.load_cargo_aliases.is_none());
+    assert!(config.load_dotenv_files.is_none());
+    assert!(config.load_git_metadata.is_none());
+    assert!(config.import_just.is_none());
     assert!(config.disable_install.is_none());
+    assert!(config.auto_confirm_on_ci.is_none());
     assert!(config.main_project_member.is_none());
     assert!(config.load_script.is_none());
     assert!(config.linux_load_script.is_none());
@@ -3925,19 +4244,240 @@ fn config_section_new() {
     assert!(config.mac_load_script.is_none());
 }
 
+#[test]
+fn cross_config_extend_all_values() {
+    let mut base = CrossConfig::new();
+    base.targets = Some(vec!["aarch64-unknown-linux-gnu".to_string()]);
+    base.mode = Some(CrossMode::Auto);
+    base.release = Some(false);
+    base.artifacts_directory = Some("target/cross".to_string());
+
+    let mut extended = CrossConfig::new();
+    extended.targets = Some(vec!["x86_64-pc-windows-gnu".to_string()]);
+    extended.mode = Some(CrossMode::Cross);
+    extended.release = Some(true);
+    extended.artifacts_directory = Some("dist".to_string());
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn cross_config_extend_no_values() {
+    let mut base = CrossConfig::new();
+    base.targets = Some(vec!["aarch64-unknown-linux-gnu".to_string()]);
+    base.mode = Some(CrossMode::Cargo);
+
+    base.extend(&CrossConfig::new());
+
+    assert_eq!(
+        base.targets.unwrap(),
+        vec!["aarch64-unknown-linux-gnu".to_string()]
+    );
+    assert_eq!(base.mode.unwrap(), CrossMode::Cargo);
+    assert!(base.release.is_none());
+    assert!(base.artifacts_directory.is_none());
+}
+
+#[test]
+fn coverage_config_extend_all_values() {
+    let mut base = CoverageConfig::new();
+    base.provider = Some(CoverageProvider::Kcov);
+    base.formats = Some(vec![CoverageFormat::Lcov]);
+    base.output_directory = Some("target/coverage".to_string());
+
+    let mut extended = CoverageConfig::new();
+    extended.provider = Some(CoverageProvider::LlvmCov);
+    extended.formats = Some(vec![CoverageFormat::Html, CoverageFormat::Cobertura]);
+    extended.output_directory = Some("reports".to_string());
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn coverage_config_extend_no_values() {
+    let mut base = CoverageConfig::new();
+    base.provider = Some(CoverageProvider::Grcov);
+
+    base.extend(&CoverageConfig::new());
+
+    assert_eq!(base.provider.unwrap(), CoverageProvider::Grcov);
+    assert!(base.formats.is_none());
+    assert!(base.output_directory.is_none());
+}
+
+#[test]
+fn coverage_config_deserialize() {
+    let coverage_config: CoverageConfig = toml::from_str(
+        r#"
+        provider = "llvm-cov"
+        formats = ["lcov", "html", "cobertura"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(coverage_config.provider.unwrap(), CoverageProvider::LlvmCov);
+    assert_eq!(
+        coverage_config.formats.unwrap(),
+        vec![
+            CoverageFormat::Lcov,
+            CoverageFormat::Html,
+            CoverageFormat::Cobertura
+        ]
+    );
+}
+
+#[test]
+fn clippy_config_extend_all_values() {
+    let mut base = ClippyConfig::new();
+    base.args = Some(vec!["--all-targets".to_string()]);
+    base.deny = Some(vec!["warnings".to_string()]);
+    base.warn = Some(vec!["clippy::pedantic".to_string()]);
+    base.allow = Some(vec!["clippy::too_many_arguments".to_string()]);
+
+    let mut extended = ClippyConfig::new();
+    extended.args = Some(vec!["--all-features".to_string()]);
+    extended.deny = Some(vec!["clippy::all".to_string()]);
+    extended.warn = Some(vec![]);
+    extended.allow = Some(vec!["clippy::module_name_repetitions".to_string()]);
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn clippy_config_extend_no_values() {
+    let mut base = ClippyConfig::new();
+    base.deny = Some(vec!["warnings".to_string()]);
+
+    base.extend(&ClippyConfig::new());
+
+    assert_eq!(base.deny.unwrap(), vec!["warnings".to_string()]);
+    assert!(base.args.is_none());
+}
+
+#[test]
+fn audit_config_extend_all_values() {
+    let mut base = AuditConfig::new();
+    base.ignore = Some(vec!["RUSTSEC-2020-0071".to_string()]);
+    base.deny = Some(vec!["warnings".to_string()]);
+
+    let mut extended = AuditConfig::new();
+    extended.ignore = Some(vec!["RUSTSEC-2021-0145".to_string()]);
+    extended.deny = Some(vec!["yanked".to_string()]);
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn audit_config_extend_no_values() {
+    let mut base = AuditConfig::new();
+    base.ignore = Some(vec!["RUSTSEC-2020-0071".to_string()]);
+
+    base.extend(&AuditConfig::new());
+
+    assert_eq!(base.ignore.unwrap(), vec!["RUSTSEC-2020-0071".to_string()]);
+    assert!(base.deny.is_none());
+}
+
+#[test]
+fn bench_config_extend_all_values() {
+    let mut base = BenchConfig::new();
+    base.baseline = Some("main".to_string());
+    base.threshold = Some(5.0);
+    base.directory = Some(".cargo-make/bench".to_string());
+
+    let mut extended = BenchConfig::new();
+    extended.baseline = Some("v1.0.0".to_string());
+    extended.threshold = Some(15.5);
+    extended.directory = Some("target/bench".to_string());
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn bench_config_extend_no_values() {
+    let mut base = BenchConfig::new();
+    base.threshold = Some(5.0);
+
+    base.extend(&BenchConfig::new());
+
+    assert_eq!(base.threshold.unwrap(), 5.0);
+    assert!(base.baseline.is_none());
+    assert!(base.directory.is_none());
+}
+
+#[test]
+fn notifications_config_extend_all_values() {
+    let mut base = NotificationsConfig::new();
+    base.on = Some(NotificationTrigger::Always);
+    base.min_duration = Some(10);
+    base.desktop = Some(false);
+    base.webhooks = Some(vec![]);
+
+    let mut extended = NotificationsConfig::new();
+    extended.on = Some(NotificationTrigger::Failure);
+    extended.min_duration = Some(60);
+    extended.desktop = Some(true);
+    extended.webhooks = Some(vec![WebhookConfig {
+        url: "${SLACK_WEBHOOK_URL}".to_string(),
+        format: Some(WebhookFormat::Slack),
+        on: None,
+    }]);
+
+    base.extend(&extended);
+
+    assert_eq!(base, extended);
+}
+
+#[test]
+fn notifications_config_extend_no_values() {
+    let mut base = NotificationsConfig::new();
+    base.desktop = Some(true);
+
+    base.extend(&NotificationsConfig::new());
+
+    assert_eq!(base.desktop, Some(true));
+    assert!(base.on.is_none());
+    assert!(base.min_duration.is_none());
+    assert!(base.webhooks.is_none());
+}
+
 #[test]
 fn config_section_extend_all_values() {
     let mut base = ConfigSection::new();
     let mut extended = ConfigSection::new();
 
     base.skip_core_tasks = Some(true);
+    base.lazy_load_core_tasks = Some(true);
     base.modify_core_tasks = Some(ModifyConfig {
         private: Some(true),
         namespace: Some("base".to_string()),
     });
+    base.core_tasks = Some(CoreTasksConfig {
+        groups: Some(vec!["build".to_string()]),
+        skip_patterns: Some(vec!["^base-".to_string()]),
+        namespace: None,
+        namespaced: Some(true),
+        aliases: Some(true),
+    });
     base.init_task = Some("base_init".to_string());
     base.end_task = Some("base_end".to_string());
     base.on_error_task = Some("base_err".to_string());
+    let mut base_profile_on_error_tasks = IndexMap::new();
+    base_profile_on_error_tasks.insert("ci".to_string(), "base_ci_err".to_string());
+    base.profile_on_error_tasks = Some(base_profile_on_error_tasks);
+    let mut base_profile_default_tasks = IndexMap::new();
+    base_profile_default_tasks.insert("ci".to_string(), "base_ci_flow".to_string());
+    base.profile_default_tasks = Some(base_profile_default_tasks);
     base.legacy_migration_task = Some("base_legacy".to_string());
     base.additional_profiles = Some(vec!["b1".to_string(), "b2".to_string()]);
     base.min_version = Some("1.0.0".to_string());
@@ -3945,10 +4485,36 @@ fn config_section_extend_all_values() {
     base.skip_git_env_info = Some(true);
     base.skip_rust_env_info = Some(true);
     base.skip_crate_env_info = Some(true);
+    base.batch_env_scripts = Some(true);
+    base.nix_shell = Some(NixShellValue::Enabled(true));
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.status_summary = Some(true);
+    base.task_history = Some(true);
+    base.run_lock = Some(true);
+    base.flaky_task_retries = Some(1);
+    base.progress = Some(true);
+    base.strict = Some(true);
+    base.report_duplicate_tasks = Some(true);
+    base.line_prefix_timestamp = Some(true);
+    base.line_prefix_task_name = Some(true);
+    base.tee_output = Some(true);
+    base.strip_ansi = Some(StripAnsiMode::Always);
+    base.output_width = Some(120);
     base.load_cargo_aliases = Some(true);
+    base.load_dotenv_files = Some(true);
+    base.load_git_metadata = Some(true);
+    base.import_just = Some("base".to_string());
     base.disable_install = Some(true);
+    base.auto_confirm_on_ci = Some(true);
+    base.task_name_validation = Some(TaskNameValidationConfig {
+        max_length: Some(10),
+        ..TaskNameValidationConfig::default()
+    });
+    base.exit_codes = Some(ExitCodesConfig {
+        task_failure: Some(10),
+        ..ExitCodesConfig::default()
+    });
     base.load_script = Some(ScriptValue::Text(vec!["base_info".to_string()]));
     base.linux_load_script = Some(ScriptValue::Text(vec![
         "linux".to_string(),
@@ -3964,13 +4530,27 @@ fn config_section_extend_all_values() {
     ]));
 
     extended.skip_core_tasks = Some(false);
+    extended.lazy_load_core_tasks = Some(false);
     extended.modify_core_tasks = Some(ModifyConfig {
         private: Some(false),
         namespace: Some("extended".to_string()),
     });
+    extended.core_tasks = Some(CoreTasksConfig {
+        groups: Some(vec!["test".to_string(), "docs".to_string()]),
+        skip_patterns: Some(vec!["^extended-".to_string()]),
+        namespace: Some("core".to_string()),
+        namespaced: Some(false),
+        aliases: None,
+    });
     extended.init_task = Some("extended_init".to_string());
     extended.end_task = Some("extended_end".to_string());
     extended.on_error_task = Some("extended_err".to_string());
+    let mut extended_profile_on_error_tasks = IndexMap::new();
+    extended_profile_on_error_tasks.insert("ci".to_string(), "extended_ci_err".to_string());
+    extended.profile_on_error_tasks = Some(extended_profile_on_error_tasks);
+    let mut extended_profile_default_tasks = IndexMap::new();
+    extended_profile_default_tasks.insert("ci".to_string(), "extended_ci_flow".to_string());
+    extended.profile_default_tasks = Some(extended_profile_default_tasks);
     extended.legacy_migration_task = Some("extended_legacy".to_string());
     extended.additional_profiles = Some(vec!["e1".to_string(), "e2".to_string()]);
     extended.min_version = Some("2.0.0".to_string());
@@ -3979,10 +4559,36 @@ fn config_section_extend_all_values() {
     extended.skip_git_env_info = Some(false);
     extended.skip_rust_env_info = Some(false);
     extended.skip_crate_env_info = Some(false);
+    extended.batch_env_scripts = Some(false);
+    extended.nix_shell = Some(NixShellValue::Flake(".#ci".to_string()));
     extended.reduce_output = Some(false);
     extended.time_summary = Some(false);
+    extended.status_summary = Some(false);
+    extended.task_history = Some(false);
+    extended.run_lock = Some(false);
+    extended.flaky_task_retries = Some(2);
+    extended.progress = Some(false);
+    extended.strict = Some(false);
+    extended.report_duplicate_tasks = Some(false);
+    extended.line_prefix_timestamp = Some(false);
+    extended.line_prefix_task_name = Some(false);
+    extended.tee_output = Some(false);
+    extended.strip_ansi = Some(StripAnsiMode::Auto);
+    extended.output_width = Some(80);
     extended.load_cargo_aliases = Some(false);
+    extended.load_dotenv_files = Some(false);
+    extended.load_git_metadata = Some(false);
+    extended.import_just = Some("extended".to_string());
     extended.disable_install = Some(false);
+    extended.auto_confirm_on_ci = Some(false);
+    extended.task_name_validation = Some(TaskNameValidationConfig {
+        max_length: Some(20),
+        ..TaskNameValidationConfig::default()
+    });
+    extended.exit_codes = Some(ExitCodesConfig {
+        task_failure: Some(20),
+        ..ExitCodesConfig::default()
+    });
     extended.load_script = Some(ScriptValue::Text(vec![
         "extended_info".to_string(),
         "arg2".to_string(),
@@ -3994,12 +4600,33 @@ fn config_section_extend_all_values() {
     base.extend(&mut extended);
 
     assert!(!base.skip_core_tasks.unwrap());
+    assert!(!base.lazy_load_core_tasks.unwrap());
     let modify_core_tasks = base.modify_core_tasks.unwrap();
     assert!(!modify_core_tasks.private.unwrap());
     assert_eq!(modify_core_tasks.namespace.unwrap(), "extended".to_string());
+    let core_tasks = base.core_tasks.unwrap();
+    assert_eq!(
+        core_tasks.groups.unwrap(),
+        vec!["test".to_string(), "docs".to_string()]
+    );
+    assert_eq!(
+        core_tasks.skip_patterns.unwrap(),
+        vec!["^extended-".to_string()]
+    );
+    assert_eq!(core_tasks.namespace.unwrap(), "core".to_string());
+    assert!(!core_tasks.namespaced.unwrap());
+    assert!(core_tasks.aliases.is_none());
     assert_eq!(base.init_task.unwrap(), "extended_init".to_string());
     assert_eq!(base.end_task.unwrap(), "extended_end".to_string());
     assert_eq!(base.on_error_task.unwrap(), "extended_err".to_string());
+    assert_eq!(
+        base.profile_on_error_tasks.unwrap().get("ci").unwrap(),
+        "extended_ci_err"
+    );
+    assert_eq!(
+        base.profile_default_tasks.unwrap().get("ci").unwrap(),
+        "extended_ci_flow"
+    );
     assert_eq!(
         base.legacy_migration_task.unwrap(),
         "extended_legacy".to_string()
@@ -4013,10 +4640,33 @@ fn config_section_extend_all_values() {
     assert!(!base.skip_git_env_info.unwrap());
     assert!(!base.skip_rust_env_info.unwrap());
     assert!(!base.skip_crate_env_info.unwrap());
+    assert!(!base.batch_env_scripts.unwrap());
+    assert_eq!(
+        base.nix_shell.unwrap(),
+        NixShellValue::Flake(".#ci".to_string())
+    );
     assert!(!base.reduce_output.unwrap());
     assert!(!base.time_summary.unwrap());
+    assert!(!base.status_summary.unwrap());
+    assert!(!base.task_history.unwrap());
+    assert!(!base.run_lock.unwrap());
+    assert_eq!(base.flaky_task_retries.unwrap(), 2);
+    assert!(!base.progress.unwrap());
+    assert!(!base.strict.unwrap());
+    assert!(!base.report_duplicate_tasks.unwrap());
+    assert!(!base.line_prefix_timestamp.unwrap());
+    assert!(!base.line_prefix_task_name.unwrap());
+    assert!(!base.tee_output.unwrap());
+    assert_eq!(base.strip_ansi.unwrap(), StripAnsiMode::Auto);
+    assert_eq!(base.output_width.unwrap(), 80);
     assert!(!base.load_cargo_aliases.unwrap());
+    assert!(!base.load_dotenv_files.unwrap());
+    assert!(!base.load_git_metadata.unwrap());
+    assert_eq!(base.import_just.unwrap(), "extended");
     assert!(!base.disable_install.unwrap());
+    assert!(!base.auto_confirm_on_ci.unwrap());
+    assert_eq!(base.task_name_validation.unwrap().max_length.unwrap(), 20);
+    assert_eq!(base.exit_codes.unwrap().task_failure.unwrap(), 20);
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 1);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 1);
@@ -4029,6 +4679,7 @@ fn config_section_extend_no_values() {
     let mut extended = ConfigSection::new();
 
     base.skip_core_tasks = Some(true);
+    base.lazy_load_core_tasks = Some(true);
     base.modify_core_tasks = Some(ModifyConfig {
         private: Some(true),
         namespace: Some("base".to_string()),
@@ -4043,10 +4694,28 @@ fn config_section_extend_no_values() {
     base.skip_git_env_info = Some(true);
     base.skip_rust_env_info = Some(true);
     base.skip_crate_env_info = Some(true);
+    base.batch_env_scripts = Some(true);
+    base.nix_shell = Some(NixShellValue::Enabled(true));
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.status_summary = Some(true);
+    base.task_history = Some(true);
+    base.run_lock = Some(true);
+    base.flaky_task_retries = Some(1);
+    base.progress = Some(true);
+    base.strict = Some(true);
+    base.report_duplicate_tasks = Some(true);
+    base.line_prefix_timestamp = Some(true);
+    base.line_prefix_task_name = Some(true);
+    base.tee_output = Some(true);
+    base.strip_ansi = Some(StripAnsiMode::Always);
+    base.output_width = Some(120);
     base.load_cargo_aliases = Some(true);
+    base.load_dotenv_files = Some(true);
+    base.load_git_metadata = Some(true);
+    base.import_just = Some("base".to_string());
     base.disable_install = Some(true);
+    base.auto_confirm_on_ci = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
         "arg2".to_string(),
@@ -4067,6 +4736,7 @@ fn config_section_extend_no_values() {
     base.extend(&mut extended);
 
     assert!(base.skip_core_tasks.unwrap());
+    assert!(base.lazy_load_core_tasks.unwrap());
     let modify_core_tasks = base.modify_core_tasks.unwrap();
     assert!(modify_core_tasks.private.unwrap());
     assert_eq!(modify_core_tasks.namespace.unwrap(), "base".to_string());
@@ -4086,10 +4756,28 @@ fn config_section_extend_no_values() {
     assert!(base.skip_git_env_info.unwrap());
     assert!(base.skip_rust_env_info.unwrap());
     assert!(base.skip_crate_env_info.unwrap());
+    assert!(base.batch_env_scripts.unwrap());
+    assert_eq!(base.nix_shell.unwrap(), NixShellValue::Enabled(true));
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
+    assert!(base.status_summary.unwrap());
+    assert!(base.task_history.unwrap());
+    assert!(base.run_lock.unwrap());
+    assert_eq!(base.flaky_task_retries.unwrap(), 1);
+    assert!(base.progress.unwrap());
+    assert!(base.strict.unwrap());
+    assert!(base.report_duplicate_tasks.unwrap());
+    assert!(base.line_prefix_timestamp.unwrap());
+    assert!(base.line_prefix_task_name.unwrap());
+    assert!(base.tee_output.unwrap());
+    assert_eq!(base.strip_ansi.unwrap(), StripAnsiMode::Always);
+    assert_eq!(base.output_width.unwrap(), 120);
     assert!(base.load_cargo_aliases.unwrap());
+    assert!(base.load_dotenv_files.unwrap());
+    assert!(base.load_git_metadata.unwrap());
+    assert_eq!(base.import_just.unwrap(), "base");
     assert!(base.disable_install.unwrap());
+    assert!(base.auto_confirm_on_ci.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 2);
@@ -4102,6 +4790,7 @@ fn config_section_extend_some_values() {
     let mut extended = ConfigSection::new();
 
     base.skip_core_tasks = Some(true);
+    base.lazy_load_core_tasks = Some(true);
     base.modify_core_tasks = Some(ModifyConfig {
         private: Some(true),
         namespace: Some("base".to_string()),
@@ -4116,10 +4805,28 @@ fn config_section_extend_some_values() {
     base.skip_git_env_info = Some(true);
     base.skip_rust_env_info = Some(true);
     base.skip_crate_env_info = Some(true);
+    base.batch_env_scripts = Some(true);
+    base.nix_shell = Some(NixShellValue::Enabled(true));
     base.reduce_output = Some(true);
     base.time_summary = Some(true);
+    base.status_summary = Some(true);
+    base.task_history = Some(true);
+    base.run_lock = Some(true);
+    base.flaky_task_retries = Some(1);
+    base.progress = Some(true);
+    base.strict = Some(true);
+    base.report_duplicate_tasks = Some(true);
+    base.line_prefix_timestamp = Some(true);
+    base.line_prefix_task_name = Some(true);
+    base.tee_output = Some(true);
+    base.strip_ansi = Some(StripAnsiMode::Always);
+    base.output_width = Some(120);
     base.load_cargo_aliases = Some(true);
+    base.load_dotenv_files = Some(true);
+    base.load_git_metadata = Some(true);
+    base.import_just = Some("base".to_string());
     base.disable_install = Some(true);
+    base.auto_confirm_on_ci = Some(true);
     base.load_script = Some(ScriptValue::Text(vec![
         "base_info".to_string(),
         "arg2".to_string(),
@@ -4138,11 +4845,13 @@ fn config_section_extend_some_values() {
     ]));
 
     extended.skip_core_tasks = Some(false);
+    extended.lazy_load_core_tasks = Some(false);
     extended.init_task = Some("extended_init".to_string());
 
     base.extend(&mut extended);
 
     assert!(!base.skip_core_tasks.unwrap());
+    assert!(!base.lazy_load_core_tasks.unwrap());
     let modify_core_tasks = base.modify_core_tasks.unwrap();
     assert!(modify_core_tasks.private.unwrap());
     assert_eq!(modify_core_tasks.namespace.unwrap(), "base".to_string());
@@ -4162,10 +4871,28 @@ fn config_section_extend_some_values() {
     assert!(base.skip_git_env_info.unwrap());
     assert!(base.skip_rust_env_info.unwrap());
     assert!(base.skip_crate_env_info.unwrap());
+    assert!(base.batch_env_scripts.unwrap());
+    assert_eq!(base.nix_shell.unwrap(), NixShellValue::Enabled(true));
     assert!(base.reduce_output.unwrap());
     assert!(base.time_summary.unwrap());
+    assert!(base.status_summary.unwrap());
+    assert!(base.task_history.unwrap());
+    assert!(base.run_lock.unwrap());
+    assert_eq!(base.flaky_task_retries.unwrap(), 1);
+    assert!(base.progress.unwrap());
+    assert!(base.strict.unwrap());
+    assert!(base.report_duplicate_tasks.unwrap());
+    assert!(base.line_prefix_timestamp.unwrap());
+    assert!(base.line_prefix_task_name.unwrap());
+    assert!(base.tee_output.unwrap());
+    assert_eq!(base.strip_ansi.unwrap(), StripAnsiMode::Always);
+    assert_eq!(base.output_width.unwrap(), 120);
     assert!(base.load_cargo_aliases.unwrap());
+    assert!(base.load_dotenv_files.unwrap());
+    assert!(base.load_git_metadata.unwrap());
+    assert_eq!(base.import_just.unwrap(), "base");
     assert!(base.disable_install.unwrap());
+    assert!(base.auto_confirm_on_ci.unwrap());
     assert_eq!(get_script_as_vec(base.load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.linux_load_script).len(), 2);
     assert_eq!(get_script_as_vec(base.windows_load_script).len(), 2);
@@ -4431,6 +5158,7 @@ fn task_apply_run_task_details_single_modify_namespace() {
         fork: None,
         parallel: None,
         cleanup_task: None,
+        args: None,
     }));
 
     task.apply(&modify_config);
@@ -4464,6 +5192,7 @@ fn task_apply_run_task_details_multiple_modify_namespace() {
         fork: None,
         parallel: None,
         cleanup_task: None,
+        args: None,
     }));
 
     task.apply(&modify_config);
@@ -4562,6 +5291,49 @@ fn task_apply_run_task_routing_info_multiple_modify_namespace() {
     assert!(task.dependencies.is_none());
 }
 
+#[test]
+fn task_apply_run_task_pipe_modify_namespace() {
+    let modify_config = ModifyConfig {
+        private: None,
+        namespace: Some("default".to_string()),
+    };
+
+    let mut task = Task::new();
+    task.run_task = Some(RunTaskInfo::Pipe(RunTaskPipe {
+        pipe: vec!["run_task1".to_string(), "run_task2".to_string()],
+    }));
+
+    task.apply(&modify_config);
+
+    let pipe = match task.run_task.unwrap() {
+        RunTaskInfo::Pipe(pipe) => pipe.pipe,
+        _ => panic!("Invalid run task value."),
+    };
+    assert_eq!(
+        pipe,
+        vec![
+            "default::run_task1".to_string(),
+            "default::run_task2".to_string()
+        ]
+    );
+}
+
+#[test]
+fn task_deserialize_run_task_pipe() {
+    let task: Task = toml::from_str(
+        r#"
+        run_task = { pipe = ["generate", "transform", "upload"] }
+        "#,
+    )
+    .unwrap();
+
+    let pipe = match task.run_task.unwrap() {
+        RunTaskInfo::Pipe(pipe) => pipe.pipe,
+        _ => panic!("Invalid run task value."),
+    };
+    assert_eq!(pipe, vec!["generate", "transform", "upload"]);
+}
+
 #[test]
 fn config_section_apply_config_empty_modify_empty() {
     let modify_config = ModifyConfig {
@@ -4633,6 +5405,44 @@ fn config_section_apply_config_with_values_modify_namespace() {
     );
 }
 
+#[test]
+fn config_section_apply_config_profile_on_error_tasks_modify_namespace() {
+    let modify_config = ModifyConfig {
+        private: None,
+        namespace: Some("config_ns".to_string()),
+    };
+    let mut profile_on_error_tasks = IndexMap::new();
+    profile_on_error_tasks.insert("ci".to_string(), "upload-logs".to_string());
+    let mut config_section = ConfigSection::new();
+    config_section.profile_on_error_tasks = Some(profile_on_error_tasks);
+    config_section.apply(&modify_config);
+
+    let profile_on_error_tasks = config_section.profile_on_error_tasks.unwrap();
+    assert_eq!(
+        profile_on_error_tasks.get("ci").unwrap(),
+        "config_ns::upload-logs"
+    );
+}
+
+#[test]
+fn config_section_apply_config_profile_default_tasks_modify_namespace() {
+    let modify_config = ModifyConfig {
+        private: None,
+        namespace: Some("config_ns".to_string()),
+    };
+    let mut profile_default_tasks = IndexMap::new();
+    profile_default_tasks.insert("ci".to_string(), "ci-flow".to_string());
+    let mut config_section = ConfigSection::new();
+    config_section.profile_default_tasks = Some(profile_default_tasks);
+    config_section.apply(&modify_config);
+
+    let profile_default_tasks = config_section.profile_default_tasks.unwrap();
+    assert_eq!(
+        profile_default_tasks.get("ci").unwrap(),
+        "config_ns::ci-flow"
+    );
+}
+
 #[test]
 fn config_apply_modify_empty() {
     let modify_config = ModifyConfig {
@@ -4652,6 +5462,12 @@ fn config_apply_modify_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.apply(&modify_config);
 
@@ -4680,6 +5496,12 @@ fn config_apply_modify_all() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        cross: None,
+        coverage: None,
+        clippy: None,
+        audit: None,
+        bench: None,
+        notifications: None,
     };
     config.apply(&modify_config);
 
@@ -4737,6 +5559,49 @@ fn deprecation_info_partial_eq_diff_type() {
     assert!(value1 != value2);
 }
 
+#[test]
+fn deprecation_info_partial_eq_same_details() {
+    let value1 = DeprecationInfo::Details(DeprecationDetails {
+        message: Some("test".to_string()),
+        replaced_by: Some("other".to_string()),
+    });
+    let value2 = value1.clone();
+
+    assert_eq!(value1, value2);
+}
+
+#[test]
+fn deprecation_info_partial_eq_diff_details() {
+    let value1 = DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: Some("other1".to_string()),
+    });
+    let value2 = DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: Some("other2".to_string()),
+    });
+
+    assert!(value1 != value2);
+}
+
+#[test]
+fn deprecation_info_get_replaced_by_none() {
+    assert!(DeprecationInfo::Boolean(true).get_replaced_by().is_none());
+    assert!(DeprecationInfo::Message("test".to_string())
+        .get_replaced_by()
+        .is_none());
+}
+
+#[test]
+fn deprecation_info_get_replaced_by_defined() {
+    let value = DeprecationInfo::Details(DeprecationDetails {
+        message: None,
+        replaced_by: Some("other".to_string()),
+    });
+
+    assert_eq!(value.get_replaced_by().unwrap(), "other");
+}
+
 #[test]
 fn unstable_feature_to_env_name() {
     let output = UnstableFeature::CtrlCHandling.to_env_name();
@@ -4807,3 +5672,212 @@ fn get_condition_type_group_or() {
 
     assert_eq!(condition_type, ConditionType::GroupOr);
 }
+
+fn create_task_with_ci_override() -> Task {
+    toml::from_str(
+        r#"
+        command = "cargo"
+        args = ["test"]
+        env = { "BASE" = "1" }
+
+        [linux]
+        args = ["test", "--platform"]
+
+        [windows]
+        args = ["test", "--platform"]
+
+        [mac]
+        args = ["test", "--platform"]
+
+        [ci]
+        env = { "CI_ONLY" = "1" }
+        "#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn task_get_normalized_task_ci_override_not_ci() {
+    let mut task = create_task_with_ci_override();
+    task.linux = None;
+    task.windows = None;
+    task.mac = None;
+
+    let normalized_task = task.get_normalized_task_for_ci(false);
+
+    assert_eq!(normalized_task.args.unwrap(), vec!["test"]);
+    let env = normalized_task.env.unwrap();
+    assert!(env.contains_key("BASE"));
+    assert!(!env.contains_key("CI_ONLY"));
+}
+
+#[test]
+fn task_get_normalized_task_ci_override_ci() {
+    let mut task = create_task_with_ci_override();
+    task.linux = None;
+    task.windows = None;
+    task.mac = None;
+
+    let normalized_task = task.get_normalized_task_for_ci(true);
+
+    assert!(normalized_task.ci.is_none());
+    assert_eq!(normalized_task.command.unwrap(), "cargo");
+    assert_eq!(normalized_task.args.unwrap(), vec!["test"]);
+    let env = normalized_task.env.unwrap();
+    assert!(env.contains_key("CI_ONLY"));
+}
+
+#[test]
+fn task_get_normalized_task_ci_override_with_platform_override() {
+    let mut task = create_task_with_ci_override();
+
+    let normalized_task = task.get_normalized_task_for_ci(true);
+
+    assert!(normalized_task.ci.is_none());
+    assert!(normalized_task.linux.is_none());
+    assert_eq!(normalized_task.args.unwrap(), vec!["test", "--platform"]);
+    let env = normalized_task.env.unwrap();
+    assert!(env.contains_key("CI_ONLY"));
+}
+
+#[test]
+fn task_get_normalized_task_ci_override_clear() {
+    let mut task = create_task_with_ci_override();
+    task.linux = None;
+    task.windows = None;
+    task.mac = None;
+    let mut ci_task = task.ci.clone().unwrap();
+    ci_task.clear = Some(true);
+    ci_task.command = Some("echo".to_string());
+    task.ci = Some(ci_task);
+
+    let normalized_task = task.get_normalized_task_for_ci(true);
+
+    assert_eq!(normalized_task.command.unwrap(), "echo");
+    assert!(normalized_task.args.is_none());
+}
+
+#[test]
+fn task_deserialize_dependency_and_run_task_args() {
+    let task: Task = toml::from_str(
+        r#"
+dependencies = ["clean", { name = "build", args = ["--release"] }]
+run_task = { name = "test", args = ["unit"] }
+"#,
+    )
+    .unwrap();
+
+    let dependencies = task.dependencies.unwrap();
+    assert_eq!(dependencies[0], "clean".into());
+    assert_eq!(
+        dependencies[1],
+        DependencyIdentifier::Definition(TaskIdentifier {
+            name: "build".to_string(),
+            path: None,
+            args: Some(vec!["--release".to_string()]),
+        })
+    );
+
+    match task.run_task.unwrap() {
+        RunTaskInfo::Details(details) => {
+            assert_eq!(details.args, Some(vec!["unit".to_string()]))
+        }
+        _ => panic!("invalid run task info"),
+    };
+}
+
+#[test]
+fn script_value_deserialize_commands() {
+    let task: Task = toml::from_str(
+        r#"
+        script = [
+            { cmd = "cargo fmt --check", ignore_errors = true },
+            { cmd = "cargo test", timeout = 600, retries = 2 },
+        ]
+        "#,
+    )
+    .unwrap();
+
+    if let Some(ScriptValue::Commands(commands)) = task.script {
+        assert_eq!(commands.len(), 2);
+        assert_eq!(
+            commands[0],
+            ScriptCommand {
+                cmd: "cargo fmt --check".to_string(),
+                ignore_errors: Some(true),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            commands[1],
+            ScriptCommand {
+                cmd: "cargo test".to_string(),
+                timeout: Some(600),
+                retries: Some(2),
+                ..Default::default()
+            }
+        );
+    } else {
+        panic!("invalid script type");
+    }
+}
+
+#[test]
+fn task_is_valid_both_command_and_commands() {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.commands = Some(vec![TaskCommand {
+        command: "echo".to_string(),
+        ..Default::default()
+    }]);
+
+    assert!(!task.is_valid());
+}
+
+#[test]
+fn task_commands_deserialize() {
+    let task: Task = toml::from_str(
+        r#"
+        commands = [
+            { command = "cargo", args = ["build"] },
+            { command = "cargo", args = ["test"], env = { RUST_BACKTRACE = "1" } },
+        ]
+        "#,
+    )
+    .unwrap();
+
+    let commands = task.commands.clone().unwrap();
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[0].command, "cargo");
+    assert_eq!(commands[0].args, Some(vec!["build".to_string()]));
+    assert!(commands[0].env.is_none());
+    assert_eq!(commands[1].env.as_ref().unwrap()["RUST_BACKTRACE"], "1");
+    assert!(task.is_actionable());
+}
+
+#[test]
+fn exit_codes_config_extend() {
+    let mut base = ExitCodesConfig {
+        task_failure: Some(10),
+        condition_failure: Some(11),
+        ..ExitCodesConfig::default()
+    };
+    let extended = ExitCodesConfig {
+        task_failure: Some(20),
+        timeout: Some(124),
+        ..ExitCodesConfig::default()
+    };
+
+    base.extend(&extended);
+
+    assert_eq!(
+        base,
+        ExitCodesConfig {
+            task_failure: Some(20),
+            condition_failure: Some(11),
+            descriptor_error: None,
+            install_failure: None,
+            timeout: Some(124),
+        }
+    );
+}
@@ -7,11 +7,81 @@
 #[path = "validator_test.rs"]
 mod validator_test;
 
+use crate::cli_parser;
+use crate::types::TaskNameValidationConfig;
+use once_cell::sync::Lazy;
 use std::fmt;
 
 /// Maximum allowed length for a task name
 const MAX_TASK_NAME_LENGTH: usize = 256;
 
+/// The cargo-make CLI flag names (without the leading --), taken from the CLI spec
+static CLI_FLAG_NAMES: Lazy<Vec<String>> = Lazy::new(cli_parser::get_flag_names);
+
+/// Prefixes used by cargo and cargo-make environment variables
+static RESERVED_ENV_PREFIXES: [&str; 2] = ["CARGO_MAKE_", "CARGO_"];
+
+/// The task name validation policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationPolicy {
+    /// Maximum allowed length (in characters)
+    pub max_length: usize,
+    /// True to allow unicode alphanumeric characters, otherwise only ASCII is allowed
+    pub allow_unicode: bool,
+    /// Characters allowed in addition to the alphanumeric characters
+    /// (not allowed at the start or end of the name or of a namespace part)
+    pub allowed_extra_characters: String,
+    /// The namespace separator (empty to disable namespaces)
+    pub namespace_separator: String,
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        ValidationPolicy {
+            max_length: MAX_TASK_NAME_LENGTH,
+            allow_unicode: false,
+            allowed_extra_characters: "-_".to_string(),
+            namespace_separator: "::".to_string(),
+        }
+    }
+}
+
+impl ValidationPolicy {
+    /// Creates the policy from the makefile config (unset values use the default policy)
+    pub fn from_config(config: &TaskNameValidationConfig) -> ValidationPolicy {
+        let mut policy = ValidationPolicy::default();
+
+        if let Some(max_length) = config.max_length {
+            policy.max_length = max_length;
+        }
+        if let Some(allow_unicode) = config.allow_unicode {
+            policy.allow_unicode = allow_unicode;
+        }
+        if let Some(ref allowed_extra_characters) = config.allowed_extra_characters {
+            policy.allowed_extra_characters = allowed_extra_characters.to_string();
+        }
+        if let Some(ref namespace_separator) = config.namespace_separator {
+            policy.namespace_separator = namespace_separator.to_string();
+        }
+
+        policy
+    }
+
+    fn is_extra_character(&self, character: char) -> bool {
+        self.allowed_extra_characters.contains(character)
+    }
+
+    fn is_valid_character(&self, character: char) -> bool {
+        let alphanumeric = if self.allow_unicode {
+            character.is_alphanumeric()
+        } else {
+            character.is_ascii_alphanumeric()
+        };
+
+        alphanumeric || self.is_extra_character(character)
+    }
+}
+
 /// Represents validation errors for task names
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TaskNameValidationError {
@@ -37,6 +107,12 @@ pub enum TaskNameValidationError {
     InvalidNamespacePartLeading { part: String, character: char },
     /// A namespace part has invalid trailing character
     InvalidNamespacePartTrailing { part: String, character: char },
+    /// Task name is identical to a cargo-make CLI flag name
+    ConflictsWithCliFlag { flag: String },
+    /// Task name differs from a predefined core task name only by case or separators
+    ConflictsWithCoreTask { core_task: String },
+    /// Task name starts with a prefix reserved for environment variables
+    ReservedEnvironmentPrefix { prefix: String },
 }
 
 impl fmt::Display for TaskNameValidationError {
@@ -70,7 +146,10 @@ impl fmt::Display for TaskNameValidationError {
                 )
             }
             TaskNameValidationError::ConsecutiveNamespaceSeparators => {
-                write!(f, "Task name cannot contain consecutive namespace separators (:::)")
+                write!(
+                    f,
+                    "Task name cannot contain consecutive namespace separators (:::)"
+                )
             }
             TaskNameValidationError::LeadingNamespaceSeparator => {
                 write!(f, "Task name cannot start with namespace separator (::)")
@@ -78,7 +157,10 @@ impl fmt::Display for TaskNameValidationError {
             TaskNameValidationError::TrailingNamespaceSeparator => {
                 write!(f, "Task name cannot end with namespace separator (::)")
             }
-            TaskNameValidationError::InvalidCharacter { character, position } => {
+            TaskNameValidationError::InvalidCharacter {
+                character,
+                position,
+            } => {
                 write!(
                     f,
                     "Invalid character '{}' at position {} (only ASCII alphanumeric, hyphens, underscores, and '::' are allowed)",
@@ -99,6 +181,23 @@ impl fmt::Display for TaskNameValidationError {
                     part, character
                 )
             }
+            TaskNameValidationError::ConflictsWithCliFlag { flag } => {
+                write!(f, "Task name conflicts with the CLI flag: --{}", flag)
+            }
+            TaskNameValidationError::ConflictsWithCoreTask { core_task } => {
+                write!(
+                    f,
+                    "Task name conflicts with the core task: {} (only case or separators differ)",
+                    core_task
+                )
+            }
+            TaskNameValidationError::ReservedEnvironmentPrefix { prefix } => {
+                write!(
+                    f,
+                    "Task name cannot start with '{}' (reserved for environment variables)",
+                    prefix
+                )
+            }
         }
     }
 }
@@ -139,16 +238,48 @@ impl std::error::Error for TaskNameValidationError {}
 /// assert!(validate_task_name_with_error("task-").is_err());
 /// ```
 pub fn validate_task_name_with_error(name: &str) -> Result<(), TaskNameValidationError> {
+    validate_task_name_with_policy(name, &ValidationPolicy::default())
+}
+
+/// Validates a task name according to the provided validation policy.
+///
+/// The rules are the same as in [`validate_task_name_with_error`] with the maximum length,
+/// the allowed characters and the namespace separator taken from the policy.
+///
+/// # Arguments
+///
+/// * `name` - The task name to validate
+/// * `policy` - The validation policy
+///
+/// # Examples
+///
+/// ```
+/// use cli::validator::{validate_task_name_with_policy, ValidationPolicy};
+///
+/// // kebab case only
+/// let policy = ValidationPolicy {
+///     allowed_extra_characters: "-".to_string(),
+///     ..ValidationPolicy::default()
+/// };
+///
+/// assert!(validate_task_name_with_policy("my-task", &policy).is_ok());
+/// assert!(validate_task_name_with_policy("my_task", &policy).is_err());
+/// ```
+pub fn validate_task_name_with_policy(
+    name: &str,
+    policy: &ValidationPolicy,
+) -> Result<(), TaskNameValidationError> {
     // Check if empty
     if name.is_empty() {
         return Err(TaskNameValidationError::Empty);
     }
 
     // Check length
-    if name.len() > MAX_TASK_NAME_LENGTH {
+    let length = name.chars().count();
+    if length > policy.max_length {
         return Err(TaskNameValidationError::TooLong {
-            length: name.len(),
-            max: MAX_TASK_NAME_LENGTH,
+            length,
+            max: policy.max_length,
         });
     }
 
@@ -157,47 +288,54 @@ pub fn validate_task_name_with_error(name: &str) -> Result<(), TaskNameValidatio
         return Err(TaskNameValidationError::InvalidWhitespace);
     }
 
-    // Check for leading or trailing hyphen/underscore
+    // Check for leading or trailing extra characters (hyphen/underscore by default)
     if let Some(first_char) = name.chars().next() {
-        if first_char == '-' || first_char == '_' {
+        if policy.is_extra_character(first_char) {
             return Err(TaskNameValidationError::InvalidLeadingCharacter {
                 character: first_char,
             });
         }
     }
     if let Some(last_char) = name.chars().last() {
-        if last_char == '-' || last_char == '_' {
+        if policy.is_extra_character(last_char) {
             return Err(TaskNameValidationError::InvalidTrailingCharacter {
                 character: last_char,
             });
         }
     }
 
-    // Check for consecutive namespace separators
-    if name.contains("::::") || name.contains(":::") {
-        return Err(TaskNameValidationError::ConsecutiveNamespaceSeparators);
-    }
+    let separator = policy.namespace_separator.as_str();
+    let parts: Vec<&str> = match separator.chars().next() {
+        Some(separator_first_char) => {
+            // Check for consecutive namespace separators
+            if name.contains(&format!("{}{}", separator, separator_first_char)) {
+                return Err(TaskNameValidationError::ConsecutiveNamespaceSeparators);
+            }
 
-    // Check for leading or trailing namespace separator
-    if name.starts_with("::") {
-        return Err(TaskNameValidationError::LeadingNamespaceSeparator);
-    }
-    if name.ends_with("::") {
-        return Err(TaskNameValidationError::TrailingNamespaceSeparator);
-    }
+            // Check for leading or trailing namespace separator
+            if name.starts_with(separator) {
+                return Err(TaskNameValidationError::LeadingNamespaceSeparator);
+            }
+            if name.ends_with(separator) {
+                return Err(TaskNameValidationError::TrailingNamespaceSeparator);
+            }
 
-    // Split by namespace separator and validate each part
-    let parts: Vec<&str> = name.split("::").collect();
+            name.split(separator).collect()
+        }
+        // namespaces are disabled
+        None => vec![name],
+    };
 
+    // Validate each namespace part
     for part in parts {
-        // Each part must not be empty (already handled by :: checks above, but double-check)
+        // Each part must not be empty (already handled by separator checks above, but double-check)
         if part.is_empty() {
             return Err(TaskNameValidationError::ConsecutiveNamespaceSeparators);
         }
 
-        // Check that each part contains only valid characters (ASCII alphanumeric, hyphen, underscore)
+        // Check that each part contains only valid characters
         for (idx, ch) in part.chars().enumerate() {
-            if !ch.is_ascii_alphanumeric() && ch != '-' && ch != '_' {
+            if !policy.is_valid_character(ch) {
                 // Calculate actual position in full string
                 let position = name.find(part).unwrap_or(0) + idx;
                 return Err(TaskNameValidationError::InvalidCharacter {
@@ -207,9 +345,9 @@ pub fn validate_task_name_with_error(name: &str) -> Result<(), TaskNameValidatio
             }
         }
 
-        // Check that each part doesn't start or end with hyphen or underscore
+        // Check that each part doesn't start or end with an extra character
         if let Some(first_char) = part.chars().next() {
-            if first_char == '-' || first_char == '_' {
+            if policy.is_extra_character(first_char) {
                 return Err(TaskNameValidationError::InvalidNamespacePartLeading {
                     part: part.to_string(),
                     character: first_char,
@@ -217,7 +355,7 @@ pub fn validate_task_name_with_error(name: &str) -> Result<(), TaskNameValidatio
             }
         }
         if let Some(last_char) = part.chars().last() {
-            if last_char == '-' || last_char == '_' {
+            if policy.is_extra_character(last_char) {
                 return Err(TaskNameValidationError::InvalidNamespacePartTrailing {
                     part: part.to_string(),
                     character: last_char,
@@ -233,6 +371,67 @@ pub fn validate_task_name(name: &str) -> bool {
     validate_task_name_with_error(name).is_ok()
 }
 
+fn normalize_task_name(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+/// Validates that a task name does not conflict with cargo-make reserved names.
+///
+/// A task name must not:
+/// - Be identical to a cargo-make CLI flag name (for example `print-steps`)
+/// - Differ from a predefined core task name only by case or by `-`/`_` separators
+///   (identical names are valid as they override the core task)
+/// - Start with a prefix reserved for environment variables (`CARGO_MAKE_`, `CARGO_`)
+///
+/// # Arguments
+///
+/// * `name` - The task name to validate
+/// * `core_task_names` - The predefined core task names
+///
+/// # Examples
+///
+/// ```
+/// use cli::validator::validate_task_name_conflicts;
+///
+/// let core_task_names = vec!["ci-flow"];
+///
+/// assert!(validate_task_name_conflicts("ci-flow", &core_task_names).is_ok());
+/// assert!(validate_task_name_conflicts("ci_flow", &core_task_names).is_err());
+/// assert!(validate_task_name_conflicts("print-steps", &core_task_names).is_err());
+/// assert!(validate_task_name_conflicts("CARGO_MAKE_BUILD", &core_task_names).is_err());
+/// ```
+pub fn validate_task_name_conflicts(
+    name: &str,
+    core_task_names: &[&str],
+) -> Result<(), TaskNameValidationError> {
+    if let Some(flag) = CLI_FLAG_NAMES.iter().find(|flag| **flag == name) {
+        return Err(TaskNameValidationError::ConflictsWithCliFlag {
+            flag: flag.to_string(),
+        });
+    }
+
+    let normalized_name = normalize_task_name(name);
+    if let Some(core_task) = core_task_names
+        .iter()
+        .find(|core_task| **core_task != name && normalize_task_name(core_task) == normalized_name)
+    {
+        return Err(TaskNameValidationError::ConflictsWithCoreTask {
+            core_task: core_task.to_string(),
+        });
+    }
+
+    if let Some(prefix) = RESERVED_ENV_PREFIXES
+        .iter()
+        .find(|prefix| name.starts_with(*prefix))
+    {
+        return Err(TaskNameValidationError::ReservedEnvironmentPrefix {
+            prefix: prefix.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,7 +579,10 @@ mod tests {
         let result = validate_task_name_with_error("task@name");
         assert!(result.is_err());
         match result.unwrap_err() {
-            TaskNameValidationError::InvalidCharacter { character, position } => {
+            TaskNameValidationError::InvalidCharacter {
+                character,
+                position,
+            } => {
                 assert_eq!(character, '@');
                 assert_eq!(position, 4);
             }
@@ -393,7 +595,10 @@ mod tests {
         let result = validate_task_name_with_error("task name");
         assert!(result.is_err());
         match result.unwrap_err() {
-            TaskNameValidationError::InvalidCharacter { character, position } => {
+            TaskNameValidationError::InvalidCharacter {
+                character,
+                position,
+            } => {
                 assert_eq!(character, ' ');
                 assert_eq!(position, 4);
             }
@@ -633,11 +838,11 @@ mod tests {
         // Single character valid
         assert!(validate_task_name("a"));
         assert!(validate_task_name("1"));
-        
+
         // Single invalid characters
         assert!(!validate_task_name("-"));
         assert!(!validate_task_name("_"));
-        
+
         // Minimum valid combinations
         assert!(validate_task_name("a1"));
         assert!(validate_task_name("a-b"));
@@ -651,7 +856,7 @@ mod tests {
         assert!(validate_task_name("a::b"));
         assert!(validate_task_name("a1::b2"));
         assert!(validate_task_name("abc::def::ghi"));
-        
+
         // Invalid namespace combinations
         assert!(!validate_task_name("a::"));
         assert!(!validate_task_name("::b"));
@@ -686,4 +891,4 @@ mod tests {
         assert!(validate_wrapper("valid-name").is_ok());
         assert!(validate_wrapper("").is_err());
     }
-}
\ No newline at end of file
+}
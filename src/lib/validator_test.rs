@@ -128,7 +128,7 @@ fn validate_task_name_edge_cases() {
     assert!(validate_task_name("a1"));
     assert!(validate_task_name("a-b"));
     assert!(validate_task_name("a_b"));
-    
+
     // Invalid edge cases
     assert!(!validate_task_name("-"));
     assert!(!validate_task_name("_"));
@@ -143,7 +143,7 @@ fn validate_task_name_namespace_edge_cases() {
     // Valid namespace combinations
     assert!(validate_task_name("a::b"));
     assert!(validate_task_name("a1::b2"));
-    
+
     // Invalid namespace combinations
     assert!(!validate_task_name("a::"));
     assert!(!validate_task_name("::b"));
@@ -169,4 +169,195 @@ fn validate_task_name_whitespace_variations() {
     assert!(!validate_task_name("\n"));
     assert!(!validate_task_name("task\nname"));
     assert!(!validate_task_name("task\tname"));
-}
\ No newline at end of file
+}
+#[test]
+fn validate_task_name_conflicts_valid() {
+    let core_task_names = vec!["ci-flow", "build"];
+
+    assert!(validate_task_name_conflicts("my-task", &core_task_names).is_ok());
+    assert!(validate_task_name_conflicts("build", &core_task_names).is_ok());
+    assert!(validate_task_name_conflicts("ci-flow", &core_task_names).is_ok());
+    assert!(validate_task_name_conflicts("cargo-build", &core_task_names).is_ok());
+    assert!(validate_task_name_conflicts("ns::help", &core_task_names).is_ok());
+}
+
+#[test]
+fn validate_task_name_conflicts_cli_flag() {
+    let result = validate_task_name_conflicts("print-steps", &[]);
+
+    assert_eq!(
+        result.unwrap_err(),
+        TaskNameValidationError::ConflictsWithCliFlag {
+            flag: "print-steps".to_string()
+        }
+    );
+}
+
+#[test]
+fn validate_task_name_conflicts_cli_flag_names() {
+    for name in ["env-file", "list-all-steps", "skip-tasks", "daemon", "ui"] {
+        let result = validate_task_name_conflicts(name, &[]);
+
+        assert_eq!(
+            result.unwrap_err(),
+            TaskNameValidationError::ConflictsWithCliFlag {
+                flag: name.to_string()
+            }
+        );
+    }
+
+    for name in ["envfile", "list-steps", "skip-tasks-pattern"] {
+        assert!(validate_task_name_conflicts(name, &[]).is_ok());
+    }
+}
+
+#[test]
+fn validate_task_name_conflicts_core_task() {
+    let core_task_names = vec!["ci-flow"];
+
+    for name in ["ci_flow", "CI-Flow", "Ci_Flow"] {
+        let result = validate_task_name_conflicts(name, &core_task_names);
+
+        assert_eq!(
+            result.unwrap_err(),
+            TaskNameValidationError::ConflictsWithCoreTask {
+                core_task: "ci-flow".to_string()
+            }
+        );
+    }
+}
+
+#[test]
+fn validate_task_name_conflicts_env_prefix() {
+    let result = validate_task_name_conflicts("CARGO_MAKE_BUILD", &[]);
+    assert_eq!(
+        result.unwrap_err(),
+        TaskNameValidationError::ReservedEnvironmentPrefix {
+            prefix: "CARGO_MAKE_".to_string()
+        }
+    );
+
+    let result = validate_task_name_conflicts("CARGO_BUILD", &[]);
+    assert_eq!(
+        result.unwrap_err(),
+        TaskNameValidationError::ReservedEnvironmentPrefix {
+            prefix: "CARGO_".to_string()
+        }
+    );
+}
+
+#[test]
+fn validate_task_name_conflicts_display() {
+    let error = TaskNameValidationError::ConflictsWithCliFlag {
+        flag: "help".to_string(),
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "Task name conflicts with the CLI flag: --help"
+    );
+}
+
+#[test]
+fn validate_task_name_with_policy_default() {
+    let policy = ValidationPolicy::default();
+
+    assert!(validate_task_name_with_policy("my_task", &policy).is_ok());
+    assert!(validate_task_name_with_policy("ns::my-task", &policy).is_ok());
+    assert!(validate_task_name_with_policy("tâche", &policy).is_err());
+    assert!(validate_task_name_with_policy("ns.task", &policy).is_err());
+}
+
+#[test]
+fn validate_task_name_with_policy_kebab_only() {
+    let policy = ValidationPolicy {
+        allowed_extra_characters: "-".to_string(),
+        ..ValidationPolicy::default()
+    };
+
+    assert!(validate_task_name_with_policy("my-task", &policy).is_ok());
+    assert_eq!(
+        validate_task_name_with_policy("my_task", &policy).unwrap_err(),
+        TaskNameValidationError::InvalidCharacter {
+            character: '_',
+            position: 2
+        }
+    );
+}
+
+#[test]
+fn validate_task_name_with_policy_unicode() {
+    let policy = ValidationPolicy {
+        allow_unicode: true,
+        max_length: 5,
+        ..ValidationPolicy::default()
+    };
+
+    assert!(validate_task_name_with_policy("tâche", &policy).is_ok());
+    assert!(validate_task_name_with_policy("задача", &policy).is_err());
+    assert!(validate_task_name_with_policy("task-🚀", &policy).is_err());
+}
+
+#[test]
+fn validate_task_name_with_policy_custom_separator() {
+    let policy = ValidationPolicy {
+        namespace_separator: ".".to_string(),
+        ..ValidationPolicy::default()
+    };
+
+    assert!(validate_task_name_with_policy("ns.task", &policy).is_ok());
+    assert!(validate_task_name_with_policy("ns::task", &policy).is_err());
+    assert_eq!(
+        validate_task_name_with_policy("ns..task", &policy).unwrap_err(),
+        TaskNameValidationError::ConsecutiveNamespaceSeparators
+    );
+    assert_eq!(
+        validate_task_name_with_policy(".task", &policy).unwrap_err(),
+        TaskNameValidationError::LeadingNamespaceSeparator
+    );
+    assert_eq!(
+        validate_task_name_with_policy("ns.-task", &policy).unwrap_err(),
+        TaskNameValidationError::InvalidNamespacePartLeading {
+            part: "-task".to_string(),
+            character: '-'
+        }
+    );
+}
+
+#[test]
+fn validate_task_name_with_policy_no_namespaces() {
+    let policy = ValidationPolicy {
+        namespace_separator: "".to_string(),
+        ..ValidationPolicy::default()
+    };
+
+    assert!(validate_task_name_with_policy("task", &policy).is_ok());
+    assert!(validate_task_name_with_policy("ns::task", &policy).is_err());
+}
+
+#[test]
+fn validation_policy_from_config_empty() {
+    let policy = ValidationPolicy::from_config(&TaskNameValidationConfig::default());
+
+    assert_eq!(policy, ValidationPolicy::default());
+}
+
+#[test]
+fn validation_policy_from_config_all_values() {
+    let policy = ValidationPolicy::from_config(&TaskNameValidationConfig {
+        max_length: Some(10),
+        allow_unicode: Some(true),
+        allowed_extra_characters: Some("-".to_string()),
+        namespace_separator: Some("/".to_string()),
+    });
+
+    assert_eq!(
+        policy,
+        ValidationPolicy {
+            max_length: 10,
+            allow_unicode: true,
+            allowed_extra_characters: "-".to_string(),
+            namespace_separator: "/".to_string(),
+        }
+    );
+}
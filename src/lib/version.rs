@@ -10,12 +10,15 @@ mod version_test;
 
 use crate::cache;
 use crate::command;
-use crate::types::{Cache, CliArgs, GlobalConfig};
+use crate::error::CargoMakeError;
+use crate::types::{Cache, CliArgs, GlobalConfig, Step};
 use semver::Version;
-use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 static VERSION: &str = env!("CARGO_PKG_VERSION");
+static UPDATE_CHECK_TIMEOUT_SECONDS: u64 = 5;
 
 fn get_version_from_output(line: &str) -> Option<String> {
     let parts: Vec<&str> = line.split(' ').collect();
@@ -30,15 +33,49 @@ fn get_version_from_output(line: &str) -> Option<String> {
     }
 }
 
-fn get_latest_version() -> Option<String> {
-    let result = Command::new("cargo")
-        .arg("search")
-        .arg("cargo-make")
-        .arg("--limit=1")
-        .output();
+/// Returns the command output, the command is killed in case it did not finish before the timeout
+fn get_output_with_timeout(mut command: Command, timeout: Duration) -> Option<Output> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    let start_time = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => return child.wait_with_output().ok(),
+            Ok(None) if start_time.elapsed() < timeout => thread::sleep(Duration::from_millis(50)),
+            _ => {
+                debug!("Update check did not finish in time.");
+                if let Err(error) = child.kill() {
+                    debug!("Unable to stop update check, {}", error);
+                }
+
+                return None;
+            }
+        }
+    }
+}
+
+fn create_search_command(global_config: &GlobalConfig) -> Command {
+    let mut command = Command::new("cargo");
+    command.arg("search").arg("cargo-make").arg("--limit=1");
+
+    if let Some(ref index) = global_config.update_check_index {
+        command.arg("--index").arg(index);
+    }
+
+    command
+}
+
+fn get_latest_version(global_config: &GlobalConfig) -> Option<String> {
+    let command = create_search_command(global_config);
+    let result =
+        get_output_with_timeout(command, Duration::from_secs(UPDATE_CHECK_TIMEOUT_SECONDS));
 
     match result {
-        Ok(output) => {
+        Some(output) => {
             let exit_code = command::get_exit_code(Ok(output.status), false);
             if exit_code == 0 {
                 let stdout = String::from_utf8_lossy(&output.stdout);
@@ -66,7 +103,10 @@ fn get_latest_version() -> Option<String> {
     }
 }
 
-fn parse(version_string: &str, allow_partial_version_string: bool) -> Result<Version, ()> {
+pub(crate) fn parse(
+    version_string: &str,
+    allow_partial_version_string: bool,
+) -> Result<Version, ()> {
     match Version::parse(version_string) {
         Ok(version) => Ok(version),
         Err(_) => {
@@ -145,6 +185,16 @@ pub(crate) fn is_newer_found(version_string: &str) -> bool {
     is_newer(&VERSION, &version_string, false, false)
 }
 
+/// Fails if the current version is older than the min_version defined by the task
+pub(crate) fn validate_task_min_version(step: &Step) -> Result<(), CargoMakeError> {
+    match step.config.min_version {
+        Some(ref min_version) if is_newer_found(min_version) => Err(
+            CargoMakeError::TaskVersionTooOld(step.name.clone(), min_version.to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
 fn print_notification(latest_string: &str) {
     warn!("#####################################################################");
     warn!("#                                                                   #");
@@ -222,12 +272,20 @@ fn should_check_overdue(global_config: &GlobalConfig) -> bool {
     }
 }
 
-fn should_check_for_args(cli_args: &CliArgs, global_config: &GlobalConfig, is_ci: bool) -> bool {
+/// Returns true if cargo is configured to not access the network
+fn is_offline() -> bool {
+    envmnt::is_or("CARGO_NET_OFFLINE", false)
+}
+
+fn is_check_enabled(cli_args: &CliArgs, is_ci: bool) -> bool {
     // only run check for updates if we are not in a CI env and user didn't ask to skip the check
     !cli_args.disable_check_for_updates
         && !is_ci
         && !envmnt::is_or("CARGO_MAKE_DISABLE_UPDATE_CHECK", false)
-        && should_check_overdue(&global_config)
+}
+
+fn should_check_for_args(cli_args: &CliArgs, global_config: &GlobalConfig, is_ci: bool) -> bool {
+    is_check_enabled(cli_args, is_ci) && !is_offline() && should_check_overdue(global_config)
 }
 
 pub(crate) fn should_check(cli_args: &CliArgs, global_config: &GlobalConfig) -> bool {
@@ -235,22 +293,35 @@ pub(crate) fn should_check(cli_args: &CliArgs, global_config: &GlobalConfig) ->
     should_check_for_args(cli_args, global_config, ci_info::is_ci())
 }
 
-pub(crate) fn check() {
-    let latest = get_latest_version();
+fn notify_if_newer(latest: &Option<String>) {
+    if let Some(value) = latest {
+        if is_newer_found(value) {
+            print_notification(value);
+        }
+    }
+}
+
+pub(crate) fn check(global_config: &GlobalConfig) {
+    let latest = get_latest_version(global_config);
 
     let mut cache_data = cache::load();
     let now = get_now_as_seconds();
     if now > 0 {
         cache_data.last_update_check = Some(now);
+        // failed checks (for example due to timeout) keep the previously found version
+        if latest.is_some() {
+            cache_data.latest_version = latest.clone();
+        }
         cache::store(&cache_data);
     }
 
-    match latest {
-        Some(value) => {
-            if is_newer_found(&value) {
-                print_notification(&value);
-            }
-        }
-        None => (),
+    notify_if_newer(&latest);
+}
+
+/// Prints the newer version notification based on the cached result of the last update check,
+/// used while the minimum interval between checks did not pass yet
+pub(crate) fn check_cached(cli_args: &CliArgs) {
+    if is_check_enabled(cli_args, ci_info::is_ci()) {
+        notify_if_newer(&cache::load().latest_version);
     }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::Task;
 
 #[test]
 fn print_notification_simple() {
@@ -180,7 +181,36 @@ fn is_newer_found_older_minor_newer_patch() {
 
 #[test]
 fn check_full() {
-    check();
+    check(&GlobalConfig::new());
+}
+
+#[test]
+fn create_search_command_default() {
+    let command = create_search_command(&GlobalConfig::new());
+
+    let args: Vec<_> = command.get_args().collect();
+    assert_eq!(command.get_program(), "cargo");
+    assert_eq!(args, vec!["search", "cargo-make", "--limit=1"]);
+}
+
+#[test]
+fn create_search_command_with_index() {
+    let mut global_config = GlobalConfig::new();
+    global_config.update_check_index = Some("sparse+https://mirror.example.com/index/".to_string());
+
+    let command = create_search_command(&global_config);
+
+    let args: Vec<_> = command.get_args().collect();
+    assert_eq!(
+        args,
+        vec![
+            "search",
+            "cargo-make",
+            "--limit=1",
+            "--index",
+            "sparse+https://mirror.example.com/index/"
+        ]
+    );
 }
 
 #[test]
@@ -438,3 +468,96 @@ fn should_check_for_args_in_ci() {
     let check = should_check_for_args(&cli_args, &global_config, true);
     assert!(!check);
 }
+
+#[test]
+#[ignore]
+fn should_check_for_args_offline() {
+    let env_value = envmnt::is_or("CARGO_NET_OFFLINE", false);
+    envmnt::set_bool("CARGO_NET_OFFLINE", true);
+
+    let mut global_config = GlobalConfig::new();
+    global_config.update_check_minimum_interval = Some("always".to_string());
+    let cli_args = CliArgs::new();
+
+    let check = should_check_for_args(&cli_args, &global_config, false);
+
+    envmnt::set_bool("CARGO_NET_OFFLINE", env_value);
+
+    assert!(!check);
+}
+
+#[test]
+fn is_check_enabled_cli_disabled() {
+    let mut cli_args = CliArgs::new();
+    cli_args.disable_check_for_updates = true;
+
+    assert!(!is_check_enabled(&cli_args, false));
+    assert!(!is_check_enabled(&CliArgs::new(), true));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn get_output_with_timeout_done() {
+    let mut command = Command::new("echo");
+    command.arg("test");
+
+    let output = get_output_with_timeout(command, Duration::from_secs(5)).unwrap();
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "test");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn get_output_with_timeout_expired() {
+    let mut command = Command::new("sleep");
+    command.arg("5");
+
+    let output = get_output_with_timeout(command, Duration::from_millis(100));
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn validate_task_min_version_none() {
+    let step = Step {
+        name: "test".to_string(),
+        config: Task::new(),
+    };
+
+    validate_task_min_version(&step).unwrap();
+}
+
+#[test]
+fn validate_task_min_version_older() {
+    let mut task = Task::new();
+    task.min_version = Some("0.1.0".to_string());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    validate_task_min_version(&step).unwrap();
+}
+
+#[test]
+fn validate_task_min_version_newer() {
+    let current = env!("CARGO_PKG_VERSION");
+    let version = Version::parse(current).unwrap();
+    let min_version = format!("{}.0.0", version.major + 1);
+    let mut task = Task::new();
+    task.min_version = Some(min_version.clone());
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let result = validate_task_min_version(&step);
+
+    match result {
+        Err(CargoMakeError::TaskVersionTooOld(name, version)) => {
+            assert_eq!(name, "test");
+            assert_eq!(version, min_version);
+        }
+        _ => panic!("Invalid result"),
+    }
+}